@@ -0,0 +1,107 @@
+//! Compares checking the size of a 200 input transaction after every added input by
+//! serializing it (full_size()) with the incrementally maintained current_size_estimate(),
+//! as well as coin selection over 200 UTxOs which needs the fee of each input it adds.
+
+use cml_chain::address::EnterpriseAddress;
+use cml_chain::builders::input_builder::{InputBuilderResult, SingleInputBuilder};
+use cml_chain::builders::output_builder::TransactionOutputBuilder;
+use cml_chain::builders::tx_builder::{
+    CoinSelectionStrategyCIP2, TransactionBuilder, TransactionBuilderConfigBuilder,
+};
+use cml_chain::certs::StakeCredential;
+use cml_chain::fees::LinearFee;
+use cml_chain::plutus::ExUnitPrices;
+use cml_chain::transaction::{TransactionInput, TransactionOutput};
+use cml_chain::{SubCoin, Value};
+use cml_crypto::{Ed25519KeyHash, TransactionHash};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+const INPUTS: u64 = 200;
+
+fn tx_builder() -> TransactionBuilder {
+    TransactionBuilder::new(
+        TransactionBuilderConfigBuilder::default()
+            .fee_algo(LinearFee::new(44, 155381, 15))
+            .pool_deposit(500000000)
+            .key_deposit(2000000)
+            .max_value_size(5000)
+            .max_tx_size(65536)
+            .coins_per_utxo_byte(4310)
+            .ex_unit_prices(ExUnitPrices::new(
+                SubCoin::new(577, 10000),
+                SubCoin::new(721, 10000000),
+            ))
+            .collateral_percentage(150)
+            .max_collateral_inputs(3)
+            .build()
+            .unwrap(),
+    )
+}
+
+fn inputs() -> Vec<InputBuilderResult> {
+    (0..INPUTS)
+        .map(|i| {
+            let address = EnterpriseAddress::new(
+                0,
+                StakeCredential::new_pub_key(Ed25519KeyHash::from([(i % 50) as u8; 28])),
+            )
+            .to_address();
+            SingleInputBuilder::new(
+                TransactionInput::new(TransactionHash::from([(i % 256) as u8; 32]), i),
+                TransactionOutput::new(address, Value::from(2_000_000 + i), None, None),
+            )
+            .payment_key()
+            .unwrap()
+        })
+        .collect()
+}
+
+fn bench_size_checks(c: &mut Criterion) {
+    let inputs = inputs();
+    let mut group = c.benchmark_group("200 inputs");
+    group.sample_size(10);
+    group.bench_function("full_size after each input", |b| {
+        b.iter(|| {
+            let mut builder = tx_builder();
+            builder.set_fee(0);
+            for input in inputs.iter() {
+                builder.add_input(input.clone()).unwrap();
+                black_box(builder.full_size().unwrap());
+            }
+        })
+    });
+    group.bench_function("current_size_estimate after each input", |b| {
+        b.iter(|| {
+            let mut builder = tx_builder();
+            builder.set_fee(0);
+            for input in inputs.iter() {
+                builder.add_input(input.clone()).unwrap();
+                black_box(builder.current_size_estimate().unwrap());
+            }
+        })
+    });
+    group.bench_function("select_utxos largest first", |b| {
+        b.iter(|| {
+            let mut builder = tx_builder();
+            let output = TransactionOutputBuilder::new()
+                .with_address(inputs[0].utxo_info.address().clone())
+                .next()
+                .unwrap()
+                .with_value((INPUTS - 50) * 2_000_000)
+                .build()
+                .unwrap();
+            builder.add_output(output).unwrap();
+            for input in inputs.iter() {
+                builder.add_utxo(input.clone());
+            }
+            builder
+                .select_utxos(CoinSelectionStrategyCIP2::LargestFirst)
+                .unwrap();
+            black_box(builder.current_size_estimate().unwrap());
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_size_checks);
+criterion_main!(benches);
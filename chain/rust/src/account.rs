@@ -0,0 +1,419 @@
+//! CIP-1852 wallet account: key/address derivation below an account node
+//! (m/1852'/1815'/account') and finding/signing with the key behind one of its addresses.
+
+use std::collections::BTreeMap;
+
+use cml_crypto::{
+    Bip32PrivateKey, Bip32PublicKey, Ed25519KeyHash, RawBytesEncoding, TransactionHash,
+};
+
+use crate::{
+    address::{Address, BaseAddress, EnterpriseAddress, RewardAddress},
+    certs::StakeCredential,
+    crypto::{utils::make_vkey_witness, Vkeywitness},
+    transaction::TransactionBody,
+};
+
+#[cfg(not(feature = "used_from_wasm"))]
+use noop_proc_macro::wasm_bindgen;
+#[cfg(feature = "used_from_wasm")]
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// How many indices of each role are searched when looking for the key behind an address.
+/// This is the address gap limit from CIP-1852 (inherited from BIP-44).
+pub const DEFAULT_ACCOUNT_LOOKAHEAD: u32 = 20;
+
+#[derive(Debug, thiserror::Error)]
+pub enum AccountError {
+    #[error("Key derivation: {0}")]
+    Derivation(String),
+    #[error("Account was created from a public key and can't sign")]
+    NoPrivateKey,
+    #[error("No key of this account (within the lookahead) is the payment key of {0:?}")]
+    KeyNotFound(Box<Address>),
+}
+
+/// The chain (3rd) level below a CIP-1852 account node
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum KeyRole {
+    /// Receiving addresses given out to others
+    External,
+    /// Change addresses
+    Internal,
+    Stake,
+}
+
+impl KeyRole {
+    pub fn derivation_index(&self) -> u32 {
+        match self {
+            Self::External => 0,
+            Self::Internal => 1,
+            Self::Stake => 2,
+        }
+    }
+}
+
+/// A wallet account i.e. the m/1852'/1815'/account' node.
+/// Created from a private key it can also sign, created from a public key it can only derive
+/// addresses and recognize them.
+pub struct Account {
+    private_key: Option<Bip32PrivateKey>,
+    public_key: Bip32PublicKey,
+    lookahead: u32,
+}
+
+impl Account {
+    /// {key} must be the account node (m/1852'/1815'/account') not the root key
+    pub fn from_private_key(key: Bip32PrivateKey) -> Self {
+        Self {
+            public_key: key.to_public(),
+            private_key: Some(key),
+            lookahead: DEFAULT_ACCOUNT_LOOKAHEAD,
+        }
+    }
+
+    /// {key} must be the account node (m/1852'/1815'/account') e.g. an exported account xpub
+    pub fn from_public_key(key: Bip32PublicKey) -> Self {
+        Self {
+            private_key: None,
+            public_key: key,
+            lookahead: DEFAULT_ACCOUNT_LOOKAHEAD,
+        }
+    }
+
+    /// Derives the account node m/1852'/1815'/{account_index}' from a root key
+    pub fn from_root_key(root_key: &Bip32PrivateKey, account_index: u32) -> Self {
+        Self::from_private_key(
+            root_key
+                .derive(harden(1852))
+                .derive(harden(1815))
+                .derive(harden(account_index)),
+        )
+    }
+
+    /// How many indices per role are searched to find the key behind an address.
+    /// Defaults to DEFAULT_ACCOUNT_LOOKAHEAD.
+    pub fn set_lookahead(&mut self, lookahead: u32) {
+        self.lookahead = lookahead;
+    }
+
+    pub fn lookahead(&self) -> u32 {
+        self.lookahead
+    }
+
+    pub fn public_key(&self) -> &Bip32PublicKey {
+        &self.public_key
+    }
+
+    pub fn private_key(&self) -> Option<&Bip32PrivateKey> {
+        self.private_key.as_ref()
+    }
+
+    /// m/1852'/1815'/account'/{role}/{index}
+    pub fn derive_public(&self, role: KeyRole, index: u32) -> Result<Bip32PublicKey, AccountError> {
+        self.public_key
+            .derive(role.derivation_index())
+            .and_then(|key| key.derive(index))
+            .map_err(|e| AccountError::Derivation(e.to_string()))
+    }
+
+    /// m/1852'/1815'/account'/{role}/{index}
+    pub fn derive_private(
+        &self,
+        role: KeyRole,
+        index: u32,
+    ) -> Result<Bip32PrivateKey, AccountError> {
+        self.private_key
+            .as_ref()
+            .map(|key| key.derive(role.derivation_index()).derive(index))
+            .ok_or(AccountError::NoPrivateKey)
+    }
+
+    pub fn key_hash(&self, role: KeyRole, index: u32) -> Result<Ed25519KeyHash, AccountError> {
+        self.derive_public(role, index)
+            .map(|key| key.to_raw_key().hash())
+    }
+
+    /// Base address of the {payment_role}/{payment_index} payment key and {stake_index} stake key
+    pub fn base_address(
+        &self,
+        network: u8,
+        payment_role: KeyRole,
+        payment_index: u32,
+        stake_index: u32,
+    ) -> Result<BaseAddress, AccountError> {
+        Ok(BaseAddress::new(
+            network,
+            StakeCredential::new_pub_key(self.key_hash(payment_role, payment_index)?),
+            StakeCredential::new_pub_key(self.key_hash(KeyRole::Stake, stake_index)?),
+        ))
+    }
+
+    pub fn enterprise_address(
+        &self,
+        network: u8,
+        payment_role: KeyRole,
+        payment_index: u32,
+    ) -> Result<EnterpriseAddress, AccountError> {
+        Ok(EnterpriseAddress::new(
+            network,
+            StakeCredential::new_pub_key(self.key_hash(payment_role, payment_index)?),
+        ))
+    }
+
+    pub fn reward_address(
+        &self,
+        network: u8,
+        stake_index: u32,
+    ) -> Result<RewardAddress, AccountError> {
+        Ok(RewardAddress::new(
+            network,
+            StakeCredential::new_pub_key(self.key_hash(KeyRole::Stake, stake_index)?),
+        ))
+    }
+
+    /// Finds the role/index of {key_hash} by searching the first lookahead() indices of each role
+    pub fn find_key(&self, key_hash: &Ed25519KeyHash) -> Option<(KeyRole, u32)> {
+        self.known_keys().remove(key_hash)
+    }
+
+    /// Whether the address's payment key (the stake key for reward addresses) is one of this
+    /// account's keys within the lookahead. Script and Byron addresses never belong to an account.
+    pub fn owns_address(&self, address: &Address) -> bool {
+        payment_key_hash(address).is_some_and(|hash| self.find_key(hash).is_some())
+    }
+
+    /// The addresses in {body} that belong to this account (see owns_address()) in the order they
+    /// appear: outputs, the collateral return and withdrawals, without duplicates.
+    /// Inputs aren't included as their addresses are only known from the UTxOs they spend.
+    pub fn owned_addresses(&self, body: &TransactionBody) -> Vec<Address> {
+        let known_keys = self.known_keys();
+        let mut owned: Vec<Address> = Vec::new();
+        let outputs = body
+            .outputs
+            .iter()
+            .chain(body.collateral_return.iter())
+            .map(|output| output.address().clone());
+        let withdrawals = body
+            .withdrawals
+            .iter()
+            .flat_map(|withdrawals| withdrawals.keys())
+            .map(|reward_account| reward_account.clone().to_address());
+        for address in outputs.chain(withdrawals) {
+            if payment_key_hash(&address).is_some_and(|hash| known_keys.contains_key(hash))
+                && !owned.contains(&address)
+            {
+                owned.push(address);
+            }
+        }
+        owned
+    }
+
+    /// Signs {tx_hash} with the payment key of {address} (the stake key for reward addresses
+    /// i.e. for withdrawals). Errors if the key isn't within the lookahead or this account
+    /// was created from a public key.
+    pub fn sign_for_address(
+        &self,
+        tx_hash: &TransactionHash,
+        address: &Address,
+    ) -> Result<Vkeywitness, AccountError> {
+        if self.private_key.is_none() {
+            return Err(AccountError::NoPrivateKey);
+        }
+        let (role, index) = payment_key_hash(address)
+            .and_then(|hash| self.find_key(hash))
+            .ok_or_else(|| AccountError::KeyNotFound(Box::new(address.clone())))?;
+        let key = self.derive_private(role, index)?;
+        Ok(make_vkey_witness(tx_hash, &key.to_raw_key()))
+    }
+
+    fn known_keys(&self) -> BTreeMap<Ed25519KeyHash, (KeyRole, u32)> {
+        let mut keys = BTreeMap::new();
+        for role in [KeyRole::External, KeyRole::Internal, KeyRole::Stake] {
+            // only the role node is derived once, each index is then a single soft derivation
+            if let Ok(role_key) = self.public_key.derive(role.derivation_index()) {
+                for index in 0..self.lookahead {
+                    if let Ok(key) = role_key.derive(index) {
+                        keys.insert(key.to_raw_key().hash(), (role, index));
+                    }
+                }
+            }
+        }
+        keys
+    }
+}
+
+impl Clone for Account {
+    fn clone(&self) -> Self {
+        Self {
+            // Bip32PrivateKey doesn't implement Clone so copy it via its bytes
+            private_key: self.private_key.as_ref().map(|key| {
+                Bip32PrivateKey::from_raw_bytes(key.to_raw_bytes())
+                    .expect("round-trip of a valid key")
+            }),
+            public_key: self.public_key.clone(),
+            lookahead: self.lookahead,
+        }
+    }
+}
+
+impl std::fmt::Debug for Account {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Account")
+            .field("public_key", &self.public_key.to_bech32())
+            .field("can_sign", &self.private_key.is_some())
+            .field("lookahead", &self.lookahead)
+            .finish()
+    }
+}
+
+fn harden(index: u32) -> u32 {
+    index | 0x80_00_00_00
+}
+
+// by convention the key inside reward addresses is their payment credential
+fn payment_key_hash(address: &Address) -> Option<&Ed25519KeyHash> {
+    match address.payment_cred() {
+        Some(StakeCredential::PubKey { hash, .. }) => Some(hash),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        genesis::network_info::NetworkInfo,
+        transaction::{TransactionInput, TransactionOutput},
+        Withdrawals,
+    };
+
+    fn test_account() -> Account {
+        // test walk nut penalty hip pave soap entry language right filter choice
+        let entropy = [
+            0xdf, 0x9e, 0xd2, 0x5e, 0xd1, 0x46, 0xbf, 0x43, 0x33, 0x6a, 0x5d, 0x7c, 0xf7, 0x39,
+            0x59, 0x94,
+        ];
+        Account::from_root_key(&Bip32PrivateKey::from_bip39_entropy(&entropy, &[]), 0)
+    }
+
+    #[test]
+    fn first_external_address() {
+        let account = test_account();
+        let mainnet = NetworkInfo::mainnet().network_id();
+        let testnet = NetworkInfo::testnet().network_id();
+        // same as cardano-addresses for this mnemonic
+        assert_eq!(
+            account
+                .base_address(mainnet, KeyRole::External, 0, 0)
+                .unwrap()
+                .to_address()
+                .to_bech32(None)
+                .unwrap(),
+            "addr1qx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer3jcu5d8ps7zex2k2xt3uqxgjqnnj83ws8lhrn648jjxtwqfjkjv7"
+        );
+        assert_eq!(
+            account
+                .base_address(testnet, KeyRole::External, 0, 0)
+                .unwrap()
+                .to_address()
+                .to_bech32(None)
+                .unwrap(),
+            "addr_test1qz2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzer3jcu5d8ps7zex2k2xt3uqxgjqnnj83ws8lhrn648jjxtwq2ytjqp"
+        );
+        assert_eq!(
+            account
+                .enterprise_address(mainnet, KeyRole::External, 0)
+                .unwrap()
+                .to_address()
+                .to_bech32(None)
+                .unwrap(),
+            "addr1vx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzers66hrl8"
+        );
+        let reward = account.reward_address(mainnet, 0).unwrap();
+        assert_eq!(
+            reward.payment,
+            account
+                .base_address(mainnet, KeyRole::External, 0, 0)
+                .unwrap()
+                .stake
+        );
+
+        // a public key only account derives the same addresses
+        let public = Account::from_public_key(account.public_key().clone());
+        assert_eq!(
+            public
+                .base_address(mainnet, KeyRole::Internal, 3, 0)
+                .unwrap()
+                .to_address(),
+            account
+                .base_address(mainnet, KeyRole::Internal, 3, 0)
+                .unwrap()
+                .to_address()
+        );
+        // hardened indices can't be derived from the public key
+        assert!(matches!(
+            public.key_hash(KeyRole::External, harden(0)),
+            Err(AccountError::Derivation(_))
+        ));
+    }
+
+    #[test]
+    fn sign_and_recognize_addresses() {
+        let account = test_account();
+        let public = Account::from_public_key(account.public_key().clone());
+        let network = NetworkInfo::mainnet().network_id();
+        let tx_hash = TransactionHash::from([0x11; 32]);
+        let change = account
+            .base_address(network, KeyRole::Internal, 5, 0)
+            .unwrap()
+            .to_address();
+        let reward = account.reward_address(network, 0).unwrap().to_address();
+        let beyond_lookahead = account
+            .enterprise_address(network, KeyRole::External, DEFAULT_ACCOUNT_LOOKAHEAD)
+            .unwrap()
+            .to_address();
+        let foreign = EnterpriseAddress::new(
+            network,
+            StakeCredential::new_pub_key(Ed25519KeyHash::from([0x22; 28])),
+        )
+        .to_address();
+
+        for (address, role, index) in [
+            (&change, KeyRole::Internal, 5),
+            (&reward, KeyRole::Stake, 0),
+        ] {
+            let witness = account.sign_for_address(&tx_hash, address).unwrap();
+            let key = account.derive_public(role, index).unwrap().to_raw_key();
+            assert_eq!(witness.vkey, key);
+            assert!(key.verify(tx_hash.to_raw_bytes(), &witness.ed25519_signature));
+            assert!(public.owns_address(address));
+        }
+        assert!(matches!(
+            public.sign_for_address(&tx_hash, &change),
+            Err(AccountError::NoPrivateKey)
+        ));
+        assert!(!account.owns_address(&foreign));
+        assert!(matches!(
+            account.sign_for_address(&tx_hash, &foreign),
+            Err(AccountError::KeyNotFound(_))
+        ));
+        assert!(!account.owns_address(&beyond_lookahead));
+        let mut extended = account.clone();
+        extended.set_lookahead(DEFAULT_ACCOUNT_LOOKAHEAD + 1);
+        assert!(extended.owns_address(&beyond_lookahead));
+
+        let output =
+            |address: &Address| TransactionOutput::new(address.clone(), 1.into(), None, None);
+        let mut body = TransactionBody::new(
+            vec![TransactionInput::new(tx_hash, 0)].into(),
+            vec![output(&foreign), output(&change), output(&change)],
+            0,
+        );
+        let mut withdrawals = Withdrawals::new();
+        withdrawals.insert(RewardAddress::from_address(&reward).unwrap(), 1);
+        body.withdrawals = Some(withdrawals);
+        assert_eq!(public.owned_addresses(&body), vec![change, reward]);
+    }
+}
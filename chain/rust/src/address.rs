@@ -18,7 +18,7 @@ use crate::certs::StakeCredential;
 
 use cml_core::{
     error::{DeserializeError, DeserializeFailure},
-    serialization::{Deserialize, LenEncoding, Serialize, StringEncoding},
+    serialization::{Deserialize, LenEncoding, Serialize, StringEncoding, StripEncodings},
     CertificateIndex, Slot, TransactionIndex,
 };
 
@@ -77,7 +77,7 @@ pub enum AddressError {
     WrongKind(AddressKind),
 }
 
-#[derive(Debug, Clone, Eq, Ord, PartialEq, PartialOrd, Hash)]
+#[derive(Debug, Clone, Eq, Ord, PartialEq, PartialOrd, Hash, StripEncodings)]
 pub enum Address {
     Base(BaseAddress),
     Ptr(PointerAddress),
@@ -536,7 +536,7 @@ impl Address {
     }
 }
 
-#[derive(Debug, Clone, Derivative)]
+#[derive(Debug, Clone, Derivative, StripEncodings)]
 #[derivative(Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct BaseAddress {
     pub network: u8,
@@ -590,7 +590,7 @@ impl From<BaseAddress> for Address {
     }
 }
 
-#[derive(Debug, Clone, Derivative)]
+#[derive(Debug, Clone, Derivative, StripEncodings)]
 #[derivative(Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct EnterpriseAddress {
     pub network: u8,
@@ -644,7 +644,7 @@ impl From<EnterpriseAddress> for Address {
 
 pub type RewardAccount = RewardAddress;
 
-#[derive(Debug, Clone, Derivative)]
+#[derive(Debug, Clone, Derivative, StripEncodings)]
 #[derivative(Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct RewardAddress {
     pub network: u8,
@@ -741,7 +741,7 @@ impl JsonSchema for RewardAddress {
     }
 }
 
-#[derive(Debug, Clone, Eq, Ord, PartialEq, PartialOrd, Hash)]
+#[derive(Debug, Clone, Eq, Ord, PartialEq, PartialOrd, Hash, StripEncodings)]
 pub struct Pointer {
     slot: num_bigint::BigUint,
     tx_index: num_bigint::BigUint,
@@ -773,7 +773,7 @@ impl Pointer {
     }
 }
 
-#[derive(Debug, Clone, Derivative)]
+#[derive(Debug, Clone, Derivative, StripEncodings)]
 #[derivative(Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct PointerAddress {
     pub network: u8,
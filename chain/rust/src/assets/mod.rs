@@ -9,11 +9,12 @@ pub use utils::*; //{Value, AssetBundle, Mint, MultiAsset, Coin, PositiveCoin, N
 
 use cbor_encodings::AssetNameEncoding;
 use cml_core::error::*;
+use cml_core::serialization::StripEncodings;
 
 use std::convert::TryFrom;
 
 /// Use TryFrom<&str> / TryInto<&str> for utf8 text conversion and RawBytesEncoding for direct bytes access
-#[derive(Clone, Debug, derivative::Derivative)]
+#[derive(Clone, Debug, derivative::Derivative, StripEncodings)]
 #[derivative(Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct AssetName {
     pub inner: Vec<u8>,
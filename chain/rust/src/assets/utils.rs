@@ -1,12 +1,16 @@
 use crate::PolicyId;
+use bech32::{FromBase32, ToBase32};
 use cbor_event::{de::Deserializer, se::Serializer};
 use cml_core::{
     error::{DeserializeError, DeserializeFailure, Key},
     ordered_hash_map::OrderedHashMap,
-    serialization::{fit_sz, CBORReadLen, Deserialize, LenEncoding, Serialize, StringEncoding},
+    serialization::{
+        canonical_key_cmp, fit_sz, CBORReadLen, Deserialize, LenEncoding, Serialize,
+        StringEncoding, StripEncodings,
+    },
     ArithmeticError,
 };
-use cml_crypto::{RawBytesEncoding, ScriptHash};
+use cml_crypto::{blake2b160, RawBytesEncoding, ScriptHash};
 use std::io::{BufRead, Seek, Write};
 use std::{
     cmp::PartialOrd,
@@ -19,15 +23,210 @@ use super::AssetName;
 
 pub type Coin = u64;
 
-/// This should technically now allow 0 but this would make the API harder to use.
-/// At least for now we'll keep it as Coin (aka u64) as an alias with this comment here.
-/// Later on it could potentially be redone to its own struct.
-pub type NonZeroInt64 = i64;
+/// Lovelace in 1 ADA
+pub const LOVELACE_PER_ADA: Coin = 1_000_000;
 
-/// This should technically now allow 0 but this would make the API harder to use.
-/// At least for now we'll keep it as Coin (aka u64) as an alias with this comment here.
-/// Later on it could potentially be redone to its own struct.
-pub type PositiveCoin = Coin;
+/// Formats lovelace as ADA with up to 6 decimals and no trailing zeros e.g. 1500000 -> "1.5".
+/// Done on integers so there are no float rounding errors.
+pub fn coin_to_ada_string(coin: Coin) -> String {
+    let (ada, lovelace) = (coin / LOVELACE_PER_ADA, coin % LOVELACE_PER_ADA);
+    if lovelace == 0 {
+        ada.to_string()
+    } else {
+        let decimals = format!("{:06}", lovelace);
+        format!("{}.{}", ada, decimals.trim_end_matches('0'))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum AdaStringError {
+    #[error("Invalid ADA amount \"{0}\"")]
+    Invalid(String),
+    #[error("ADA amount \"{0}\" has more than 6 decimals")]
+    TooManyDecimals(String),
+    #[error("ADA amount \"{0}\" doesn't fit in a Coin")]
+    Overflow(String),
+}
+
+/// Parses an ADA amount with up to 6 decimals (e.g. "1.5") into lovelace, without floats.
+/// Inverse of coin_to_ada_string().
+pub fn coin_from_ada_string(ada: &str) -> Result<Coin, AdaStringError> {
+    let (whole, decimals) = match ada.split_once('.') {
+        Some((whole, decimals)) if !decimals.is_empty() => (whole, decimals),
+        Some(_) => return Err(AdaStringError::Invalid(ada.to_owned())),
+        None => (ada, ""),
+    };
+    if whole.is_empty() || !(whole.bytes().chain(decimals.bytes())).all(|b| b.is_ascii_digit()) {
+        return Err(AdaStringError::Invalid(ada.to_owned()));
+    }
+    if decimals.len() > 6 {
+        return Err(AdaStringError::TooManyDecimals(ada.to_owned()));
+    }
+    let overflow = || AdaStringError::Overflow(ada.to_owned());
+    let whole: Coin = whole.parse().map_err(|_| overflow())?;
+    let lovelace: Coin = format!("{:0<6}", decimals).parse().unwrap();
+    whole
+        .checked_mul(LOVELACE_PER_ADA)
+        .and_then(|whole| whole.checked_add(lovelace))
+        .ok_or_else(overflow)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("{0} can't be zero")]
+pub struct ZeroValueError(pub(crate) &'static str);
+
+/// int64 that can't be 0 (nonZeroInt64 in the CDDL) e.g. a Conway mint/burn amount.
+/// Serializes (CBOR/JSON) exactly like the plain i64.
+///
+/// Mint itself is kept as an AssetBundle<i64> so that arithmetic on it can go through 0
+/// but a Conway tx body won't (de)serialize with a 0 mint amount.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(try_from = "i64", into = "i64")]
+pub struct NonZeroInt64(i64);
+
+impl NonZeroInt64 {
+    pub fn try_new(value: i64) -> Result<Self, ZeroValueError> {
+        if value == 0 {
+            Err(ZeroValueError("NonZeroInt64"))
+        } else {
+            Ok(Self(value))
+        }
+    }
+
+    pub fn get(&self) -> i64 {
+        self.0
+    }
+
+    /// None on overflow or if the result would be 0
+    pub fn checked_add(&self, rhs: Self) -> Option<Self> {
+        self.0
+            .checked_add(rhs.0)
+            .and_then(|sum| Self::try_new(sum).ok())
+    }
+
+    /// None on overflow or if the result would be 0
+    pub fn checked_sub(&self, rhs: Self) -> Option<Self> {
+        self.0
+            .checked_sub(rhs.0)
+            .and_then(|diff| Self::try_new(diff).ok())
+    }
+}
+
+impl TryFrom<i64> for NonZeroInt64 {
+    type Error = ZeroValueError;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        Self::try_new(value)
+    }
+}
+
+impl From<NonZeroInt64> for i64 {
+    fn from(value: NonZeroInt64) -> Self {
+        value.0
+    }
+}
+
+impl std::fmt::Display for NonZeroInt64 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl schemars::JsonSchema for NonZeroInt64 {
+    fn schema_name() -> String {
+        String::from("NonZeroInt64")
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        i64::json_schema(gen)
+    }
+
+    fn is_referenceable() -> bool {
+        i64::is_referenceable()
+    }
+}
+
+/// Coin that can't be 0 (positive_coin in the CDDL) e.g. a Conway treasury donation.
+/// Serializes (CBOR/JSON) exactly like the plain Coin (u64).
+///
+/// MultiAsset is kept as an AssetBundle<Coin> so that arithmetic on it can go through 0.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    StripEncodings,
+)]
+#[serde(try_from = "u64", into = "u64")]
+pub struct PositiveCoin(Coin);
+
+impl PositiveCoin {
+    pub fn try_new(value: Coin) -> Result<Self, ZeroValueError> {
+        if value == 0 {
+            Err(ZeroValueError("PositiveCoin"))
+        } else {
+            Ok(Self(value))
+        }
+    }
+
+    pub fn get(&self) -> Coin {
+        self.0
+    }
+
+    /// None on overflow
+    pub fn checked_add(&self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).map(Self)
+    }
+
+    /// None on underflow or if the result would be 0
+    pub fn checked_sub(&self, rhs: Self) -> Option<Self> {
+        self.0
+            .checked_sub(rhs.0)
+            .and_then(|diff| Self::try_new(diff).ok())
+    }
+}
+
+impl TryFrom<Coin> for PositiveCoin {
+    type Error = ZeroValueError;
+
+    fn try_from(value: Coin) -> Result<Self, Self::Error> {
+        Self::try_new(value)
+    }
+}
+
+impl From<PositiveCoin> for Coin {
+    fn from(value: PositiveCoin) -> Self {
+        value.0
+    }
+}
+
+impl std::fmt::Display for PositiveCoin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl schemars::JsonSchema for PositiveCoin {
+    fn schema_name() -> String {
+        String::from("PositiveCoin")
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        Coin::json_schema(gen)
+    }
+
+    fn is_referenceable() -> bool {
+        Coin::is_referenceable()
+    }
+}
 
 #[derive(Debug, thiserror::Error)]
 pub enum AssetArithmeticError {
@@ -65,9 +264,130 @@ impl RawBytesEncoding for AssetName {
     }
 }
 
+#[derive(Debug, thiserror::Error)]
+pub enum AssetFingerprintError {
+    #[error("Bech32: {0}")]
+    Bech32(#[from] bech32::Error),
+    #[error("Invalid fingerprint prefix \"{0}\", expected \"asset\"")]
+    InvalidPrefix(String),
+    #[error("Invalid fingerprint length: {0} bytes, expected 20")]
+    InvalidLength(usize),
+}
+
+const ASSET_FINGERPRINT_PREFIX: &str = "asset";
+
+/// CIP-14 asset fingerprint: bech32 (asset prefix) of blake2b-160(policy_id || asset_name)
+/// e.g. asset1rjklcrnsdzqp65wjgrg55sy9723kw09mlgvlc3
+pub fn asset_fingerprint(policy_id: &PolicyId, asset_name: &AssetName) -> String {
+    let mut bytes = policy_id.to_raw_bytes().to_vec();
+    bytes.extend_from_slice(asset_name.to_raw_bytes());
+    // only fails with an invalid prefix
+    bech32::encode(ASSET_FINGERPRINT_PREFIX, blake2b160(&bytes).to_base32()).unwrap()
+}
+
+/// Decodes a CIP-14 asset fingerprint back into its blake2b-160 hash.
+/// The fingerprint is a one-way hash so this can't recover the policy id / asset name
+/// but it can be used to look up assets indexed by this hash.
+pub fn from_fingerprint(fingerprint: &str) -> Result<[u8; 20], AssetFingerprintError> {
+    let (prefix, data) = bech32::decode(fingerprint)?;
+    if prefix != ASSET_FINGERPRINT_PREFIX {
+        return Err(AssetFingerprintError::InvalidPrefix(prefix));
+    }
+    let bytes = Vec::<u8>::from_base32(&data)?;
+    bytes
+        .as_slice()
+        .try_into()
+        .map_err(|_| AssetFingerprintError::InvalidLength(bytes.len()))
+}
+
+/// CIP-67 asset name label prefix: 4 bytes of the form [0000 | 16 bit label | 8 bit CRC-8 | 0000]
+/// e.g. label 222 is encoded as 000de140
+pub struct AssetNameLabel;
+
+impl AssetNameLabel {
+    /// CIP-68 reference NFT holding the metadata datum
+    pub const REFERENCE_NFT: u16 = 100;
+    /// CIP-68 user NFT
+    pub const USER_NFT: u16 = 222;
+    /// CIP-68 user FT
+    pub const USER_FT: u16 = 333;
+    /// CIP-68 user RFT
+    pub const USER_RFT: u16 = 444;
+
+    /// Encodes {label} as the 4 byte CIP-67 prefix (big endian, with checksum)
+    pub fn encode(label: u16) -> [u8; 4] {
+        let checksum = crc8(&label.to_be_bytes());
+        ((u32::from(label) << 12) | (u32::from(checksum) << 4)).to_be_bytes()
+    }
+
+    /// Splits {asset_name} into its CIP-67 label and the remaining name bytes.
+    /// Returns None if the name does not start with a well-formed label (padding or checksum mismatch).
+    pub fn decode(asset_name: &AssetName) -> Option<(u16, Vec<u8>)> {
+        let bytes = asset_name.to_raw_bytes();
+        if bytes.len() < 4 {
+            return None;
+        }
+        let prefix = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        let label = (prefix >> 12) as u16;
+        if Self::encode(label) != prefix.to_be_bytes() {
+            return None;
+        }
+        Some((label, bytes[4..].to_vec()))
+    }
+}
+
+/// CRC-8 (polynomial 0x07, zero init) as used by the CIP-67 label checksum
+fn crc8(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0u8, |crc, byte| {
+        (0..8).fold(crc ^ byte, |crc, _| {
+            if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+            } else {
+                crc << 1
+            }
+        })
+    })
+}
+
+impl AssetName {
+    /// Prefixes {name} with the CIP-67 label {label}. {name} can be at most 28 bytes.
+    pub fn new_with_label(label: u16, name: &[u8]) -> Result<Self, DeserializeError> {
+        let mut bytes = AssetNameLabel::encode(label).to_vec();
+        bytes.extend_from_slice(name);
+        Self::new(bytes)
+    }
+
+    /// CIP-68 reference NFT (label 100) for {name}
+    pub fn new_cip68_reference(name: &[u8]) -> Result<Self, DeserializeError> {
+        Self::new_with_label(AssetNameLabel::REFERENCE_NFT, name)
+    }
+
+    /// CIP-68 user NFT (label 222) for {name}
+    pub fn new_cip68_user_nft(name: &[u8]) -> Result<Self, DeserializeError> {
+        Self::new_with_label(AssetNameLabel::USER_NFT, name)
+    }
+
+    /// CIP-68 user FT (label 333) for {name}
+    pub fn new_cip68_user_ft(name: &[u8]) -> Result<Self, DeserializeError> {
+        Self::new_with_label(AssetNameLabel::USER_FT, name)
+    }
+
+    /// CIP-68 user RFT (label 444) for {name}
+    pub fn new_cip68_user_rft(name: &[u8]) -> Result<Self, DeserializeError> {
+        Self::new_with_label(AssetNameLabel::USER_RFT, name)
+    }
+}
+
 /// Bundle of assets within range of T, grouped by PolicyID then AssetName
 #[derive(
-    Clone, Default, PartialEq, Hash, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+    Clone,
+    Default,
+    PartialEq,
+    Hash,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    StripEncodings,
 )]
 pub struct AssetBundle<T>(OrderedHashMap<PolicyId, OrderedHashMap<AssetName, T>>);
 
@@ -118,6 +438,35 @@ impl<T> From<OrderedHashMap<PolicyId, OrderedHashMap<AssetName, T>>> for AssetBu
     }
 }
 
+impl<T> AssetBundle<T> {
+    /// Number of policies in the bundle
+    pub fn policy_count(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Number of assets in the bundle across all policies
+    pub fn asset_count(&self) -> usize {
+        self.0.values().map(|assets| assets.len()).sum()
+    }
+
+    /// All assets under {policy_id}, if any
+    pub fn get_assets(&self, policy_id: &PolicyId) -> Option<&OrderedHashMap<AssetName, T>> {
+        self.0.get(policy_id)
+    }
+
+    /// Iterates over all assets as (policy_id, asset_name, value) in bundle order
+    pub fn iter_flat(&self) -> impl Iterator<Item = (PolicyId, AssetName, T)> + '_
+    where
+        T: Copy,
+    {
+        self.0.iter().flat_map(|(policy_id, assets)| {
+            assets
+                .iter()
+                .map(move |(asset_name, value)| (*policy_id, asset_name.clone(), *value))
+        })
+    }
+}
+
 impl<T> std::ops::Deref for AssetBundle<T> {
     type Target = OrderedHashMap<PolicyId, OrderedHashMap<AssetName, T>>;
 
@@ -207,6 +556,11 @@ where
                 }
             }
         }
+        // also drop entries that were already 0 on the left hand side
+        bundle.retain(|_, assets| {
+            assets.retain(|_, amount| !amount.is_zero());
+            !assets.is_empty()
+        });
         bundle
     }
 }
@@ -232,6 +586,33 @@ where
             .copied()
     }
 
+    /// Get the value of policy_id:asset_name, or zero if it doesn't exist.
+    pub fn get_asset(&self, policy_id: &PolicyId, asset_name: &AssetName) -> T {
+        self.get(policy_id, asset_name).unwrap_or_else(T::zero)
+    }
+
+    /// Set the value of policy_id:asset_name to value, removing the asset (and the policy if it
+    /// has no other assets) when value is zero so the bundle never holds zero quantities.
+    /// Returns the previous value, or zero if it didn't exist
+    pub fn set_asset(&mut self, policy_id: PolicyId, asset_name: AssetName, value: T) -> T {
+        if !value.is_zero() {
+            return self
+                .set(policy_id, asset_name, value)
+                .unwrap_or_else(T::zero);
+        }
+        let previous = match self.0.get_mut(&policy_id) {
+            Some(assets) => {
+                let previous = assets.remove(&asset_name);
+                if assets.is_empty() {
+                    self.0.remove(&policy_id);
+                }
+                previous
+            }
+            None => None,
+        };
+        previous.unwrap_or_else(T::zero)
+    }
+
     /// Adds to bundles together, checking value bounds.
     /// Does not modify self, and instead returns the result.
     pub fn checked_add(&self, rhs: &Self) -> Result<Self, AssetArithmeticError> {
@@ -306,9 +687,9 @@ where
     }
 }
 
-pub type Mint = AssetBundle<NonZeroInt64>;
+pub type Mint = AssetBundle<i64>;
 
-pub type MultiAsset = AssetBundle<PositiveCoin>;
+pub type MultiAsset = AssetBundle<Coin>;
 
 impl Mint {
     fn as_multiasset(&self, is_positive: bool) -> MultiAsset {
@@ -340,25 +721,101 @@ impl Mint {
     pub fn as_negative_multiasset(&self) -> MultiAsset {
         self.as_multiasset(false)
     }
+
+    /// e.g. "2 assets (d5e6bf05… HOSKY: +100, 1a2b3c4d… 000de140…: -1)" with at most
+    /// {max_assets} assets listed and shortened policy ids / asset names. Used by Display.
+    pub fn format_compact(&self, max_assets: usize) -> String {
+        format_assets(self, Some(max_assets), |amount| format!("{:+}", amount))
+    }
+
+    /// Same as format_compact() but listing all assets with their full policy ids / asset names
+    pub fn format_full(&self) -> String {
+        format_assets(self, None, |amount| format!("{:+}", amount))
+    }
+}
+
+impl std::fmt::Display for Mint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.format_compact(DISPLAY_MAX_ASSETS))
+    }
+}
+
+/// How many assets Display lists for Value / Mint before truncating
+const DISPLAY_MAX_ASSETS: usize = 10;
+
+/// "N assets (policy name: amount, ...)" with {max_assets} = None for the full format
+fn format_assets<T>(
+    bundle: &AssetBundle<T>,
+    max_assets: Option<usize>,
+    format_amount: impl Fn(&T) -> String,
+) -> String {
+    let shorten = |s: String| match max_assets {
+        Some(_) if s.chars().count() > 8 => format!("{}…", s.chars().take(8).collect::<String>()),
+        _ => s,
+    };
+    let assets = bundle
+        .iter()
+        .flat_map(|(policy_id, assets)| {
+            assets
+                .iter()
+                .map(move |(name, amount)| (policy_id, name, amount))
+        })
+        .collect::<Vec<_>>();
+    let mut formatted = format!(
+        "{} asset{}",
+        assets.len(),
+        if assets.len() == 1 { "" } else { "s" }
+    );
+    if assets.is_empty() {
+        return formatted;
+    }
+    let shown = max_assets.unwrap_or(assets.len()).min(assets.len());
+    let mut entries = assets[..shown]
+        .iter()
+        .map(|(policy_id, name, amount)| {
+            let name = match std::str::from_utf8(name.to_raw_bytes()) {
+                Ok("") => "\"\"".to_owned(),
+                Ok(text) if !text.chars().any(char::is_control) => text.to_owned(),
+                _ => hex::encode(name.to_raw_bytes()),
+            };
+            format!(
+                "{} {}: {}",
+                shorten(policy_id.to_hex()),
+                shorten(name),
+                format_amount(amount)
+            )
+        })
+        .collect::<Vec<_>>();
+    if shown < assets.len() {
+        entries.push(format!("…{} more", assets.len() - shown));
+    }
+    formatted.push_str(&format!(" ({})", entries.join(", ")));
+    formatted
 }
 
 // note: we purposefully don't derive or implement Ord for Value to avoid potentially confusing
 // orderings that don't obey Cardano semantics i.e. if x >= y then input x can cover cost y
 // If you need to use Value or something in a tree map please consider using a hash map instead.
 #[derive(
-    Clone, Debug, derivative::Derivative, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+    Clone,
+    Debug,
+    derivative::Derivative,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    StripEncodings,
 )]
-#[derivative(Hash, Eq, PartialEq, PartialOrd)]
+#[derivative(Hash, Eq, PartialEq)]
 pub struct Value {
-    pub coin: PositiveCoin,
+    pub coin: Coin,
     pub multiasset: MultiAsset,
     #[serde(skip)]
-    #[derivative(Hash = "ignore", PartialEq = "ignore", PartialOrd = "ignore")]
+    #[derivative(Hash = "ignore", PartialEq = "ignore")]
     pub encodings: Option<ValueEncoding>,
 }
 
 impl Value {
-    pub fn new(coin: PositiveCoin, multiasset: MultiAsset) -> Self {
+    pub fn new(coin: Coin, multiasset: MultiAsset) -> Self {
         Self {
             coin,
             multiasset,
@@ -410,6 +867,8 @@ impl Value {
         })
     }
 
+    /// Subtracts ADA and assets flooring each at 0 (assets only in rhs are ignored).
+    /// Assets with a resulting quantity of 0 are removed.
     pub fn clamped_sub(&self, rhs: &Value) -> Value {
         let coin = self.coin.clamped_sub(&rhs.coin);
         let multiasset = self.multiasset.clamped_sub(&rhs.multiasset);
@@ -419,55 +878,135 @@ impl Value {
             encodings: None,
         }
     }
+
+    /// e.g. "1.5 ADA + 2 assets (d5e6bf05… HOSKY: 100, 1a2b3c4d… 000de140…: 1)" with at most
+    /// {max_assets} assets listed and shortened policy ids / asset names. Used by Display.
+    pub fn format_compact(&self, max_assets: usize) -> String {
+        self.format(Some(max_assets))
+    }
+
+    /// Same as format_compact() but listing all assets with their full policy ids / asset names
+    pub fn format_full(&self) -> String {
+        self.format(None)
+    }
+
+    fn format(&self, max_assets: Option<usize>) -> String {
+        let ada = format!("{} ADA", coin_to_ada_string(self.coin));
+        if self.multiasset.values().all(|assets| assets.is_empty()) {
+            ada
+        } else {
+            format!(
+                "{} + {}",
+                ada,
+                format_assets(&self.multiasset, max_assets, |amount| amount.to_string())
+            )
+        }
+    }
+
+    /// Whether this value covers {other} i.e. it has at least as much ADA and as much of every
+    /// asset in {other} (missing assets count as 0) e.g. for checking if inputs cover a cost.
+    pub fn geq(&self, other: &Value) -> bool {
+        self.coin >= other.coin
+            && other.multiasset.iter().all(|(policy_id, assets)| {
+                assets.iter().all(|(asset_name, amount)| {
+                    self.multiasset.get_asset(policy_id, asset_name) >= *amount
+                })
+            })
+    }
+
+    /// Compares the amounts of ADA and every asset (missing assets count as 0).
+    /// Returns None if they are incomparable i.e. some amounts are greater and some are less.
+    /// This is also what the PartialOrd impl uses, apart from values that are Equal here
+    /// but not == (explicit 0 amounts or a different entry order).
+    pub fn compare(&self, rhs: &Value) -> Option<std::cmp::Ordering> {
+        use std::cmp::Ordering;
+        match (
+            self.coin.cmp(&rhs.coin),
+            self.multiasset.compare(&rhs.multiasset)?,
+        ) {
+            (coin, Ordering::Equal) => Some(coin),
+            (Ordering::Equal, multiasset) => Some(multiasset),
+            (coin, multiasset) if coin == multiasset => Some(coin),
+            _ => None,
+        }
+    }
+}
+
+impl<T> AssetBundle<T>
+where
+    T: num::CheckedAdd + num::CheckedSub + num::Zero + num::Bounded + Copy + Clone + PartialOrd,
+{
+    /// Compares the amounts of every asset, assuming that if a pair (pid, aname)
+    /// is not in the bundle then it has an amount of 0.
+    /// Returns None if they are incomparable i.e. some amounts are greater and some are less.
+    /// Unlike the PartialOrd impl this is Equal for bundles that only differ in explicit 0
+    /// amounts or entry order, which are still != to each other.
+    pub fn compare(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        use std::cmp::Ordering;
+        let mut ordering = Ordering::Equal;
+        let entries = self
+            .iter()
+            .chain(other.iter())
+            .flat_map(|(pid, assets)| assets.keys().map(move |aname| (pid, aname)));
+        for (pid, aname) in entries {
+            match self
+                .get_asset(pid, aname)
+                .partial_cmp(&other.get_asset(pid, aname))?
+            {
+                Ordering::Equal => (),
+                asset_ordering if ordering == Ordering::Equal => ordering = asset_ordering,
+                asset_ordering if asset_ordering != ordering => return None,
+                _ => (),
+            }
+        }
+        Some(ordering)
+    }
 }
 
 // deriving PartialOrd doesn't work in a way that's useful , as the
 // implementation of PartialOrd for BTreeMap compares keys by their order,
 // i.e, is equivalent to comparing the iterators of (pid, Assets).
 // that would mean that: v1 < v2 if the min_pid(v1) < min_pid(v2)
-// this function instead compares amounts, assuming that if a pair (pid, aname)
-// is not in the MultiAsset then it has an amount of 0
+// this function instead compares amounts (see AssetBundle::compare()) but to stay
+// consistent with PartialEq it is only Equal when the bundles are ==
 impl<T> PartialOrd for AssetBundle<T>
 where
-    T: num::CheckedAdd + num::CheckedSub + num::Zero + num::Bounded + Copy + Clone + PartialOrd,
+    T: num::CheckedAdd
+        + num::CheckedSub
+        + num::Zero
+        + num::Bounded
+        + Copy
+        + Clone
+        + PartialOrd
+        + PartialEq,
 {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        // idea: if (a-b) > 0 for some asset, then a > b for at least some asset
-        fn is_all_zeros<T>(lhs: &AssetBundle<T>, rhs: &AssetBundle<T>) -> bool
-        where
-            T: num::CheckedAdd
-                + num::CheckedSub
-                + num::Zero
-                + num::Bounded
-                + Copy
-                + Clone
-                + PartialOrd,
-        {
-            for (pid, assets) in lhs.0.iter() {
-                for (aname, amount) in assets.iter() {
-                    match amount
-                        .checked_sub(&rhs.get(pid, aname).unwrap_or(T::zero()))
-                        .and_then(|o| o.partial_cmp(&T::zero()))
-                    {
-                        Some(std::cmp::Ordering::Equal) => (),
-                        _ => return false,
-                    }
-                }
-            }
-            true
+        match self.compare(other)? {
+            std::cmp::Ordering::Equal if self != other => None,
+            ordering => Some(ordering),
         }
+    }
+}
 
-        match (is_all_zeros(self, other), is_all_zeros(other, self)) {
-            (true, true) => Some(std::cmp::Ordering::Equal),
-            (true, false) => Some(std::cmp::Ordering::Less),
-            (false, true) => Some(std::cmp::Ordering::Greater),
-            (false, false) => None,
+/// Same as Value::compare() so x >= y means x covers y, except that values which only
+/// differ in explicit 0 amounts (or entry order) are not == so they're incomparable here.
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        match self.compare(other)? {
+            std::cmp::Ordering::Equal if self != other => None,
+            ordering => Some(ordering),
         }
     }
 }
 
-impl From<PositiveCoin> for Value {
-    fn from(coin: PositiveCoin) -> Self {
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.format_compact(DISPLAY_MAX_ASSETS))
+    }
+}
+
+impl From<Coin> for Value {
+    fn from(coin: Coin) -> Self {
         Self {
             coin,
             multiasset: AssetBundle::default(),
@@ -559,10 +1098,7 @@ impl Serialize for Value {
                 .collect::<Result<Vec<(Vec<u8>, &_, &_)>, cbor_event::Error>>()?;
             if force_canonical {
                 key_order.sort_by(|(lhs_bytes, _, _), (rhs_bytes, _, _)| {
-                    match lhs_bytes.len().cmp(&rhs_bytes.len()) {
-                        std::cmp::Ordering::Equal => lhs_bytes.cmp(rhs_bytes),
-                        diff_ord => diff_ord,
-                    }
+                    canonical_key_cmp(lhs_bytes, rhs_bytes)
                 });
             }
             for (key_bytes, key, value) in key_order {
@@ -586,10 +1122,7 @@ impl Serialize for Value {
                     .collect::<Result<Vec<(Vec<u8>, &_, &_)>, cbor_event::Error>>()?;
                 if force_canonical {
                     key_order.sort_by(|(lhs_bytes, _, _), (rhs_bytes, _, _)| {
-                        match lhs_bytes.len().cmp(&rhs_bytes.len()) {
-                            std::cmp::Ordering::Equal => lhs_bytes.cmp(rhs_bytes),
-                            diff_ord => diff_ord,
-                        }
+                        canonical_key_cmp(lhs_bytes, rhs_bytes)
                     });
                 }
                 for (key_bytes, key, value) in key_order {
@@ -784,3 +1317,493 @@ pub struct ValueEncoding {
     // we created this from since you can have an empty multiasset map but still use the MA format
     pub use_multiasset_format: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cmp::Ordering;
+
+    proptest::proptest! {
+        #[test]
+        fn value_roundtrip(value in crate::testing::arb_value()) {
+            crate::testing::roundtrip_cbor(&value)?;
+            crate::testing::roundtrip_json(&value)?;
+        }
+
+        #[test]
+        fn value_add_sub(
+            lhs in crate::testing::arb_value(),
+            rhs in crate::testing::arb_value(),
+        ) {
+            if let Ok(sum) = lhs.checked_add(&rhs) {
+                proptest::prop_assert_eq!(sum.checked_sub(&rhs).unwrap(), lhs.clone());
+                proptest::prop_assert_eq!(sum.clamped_sub(&lhs), rhs);
+            }
+        }
+    }
+
+    #[test]
+    fn non_zero_newtypes() {
+        assert!(NonZeroInt64::try_new(0).is_err());
+        assert!(PositiveCoin::try_from(0).is_err());
+        let minus_one = NonZeroInt64::try_new(-1).unwrap();
+        let one = NonZeroInt64::try_from(1).unwrap();
+        assert_eq!(minus_one.checked_add(one), None);
+        assert_eq!(one.checked_add(one).map(i64::from), Some(2));
+        assert_eq!(
+            NonZeroInt64::try_new(i64::MAX).unwrap().checked_add(one),
+            None
+        );
+        let five = PositiveCoin::try_new(5).unwrap();
+        assert_eq!(five.checked_sub(five), None);
+        assert_eq!(five.checked_add(five).map(|c| c.get()), Some(10));
+        assert_eq!(Coin::from(five), 5);
+        // same JSON as the underlying integers, rejecting 0
+        assert_eq!(serde_json::to_string(&minus_one).unwrap(), "-1");
+        assert_eq!(serde_json::from_str::<PositiveCoin>("5").unwrap(), five);
+        assert!(serde_json::from_str::<NonZeroInt64>("0").is_err());
+        assert!(serde_json::from_str::<PositiveCoin>("0").is_err());
+    }
+
+    #[test]
+    fn cip67_labels() {
+        // test vectors from CIP-67
+        let vectors = [
+            (0, "00000000"),
+            (1, "00001070"),
+            (23, "00017650"),
+            (99, "000632e0"),
+            (533, "00215410"),
+            (2000, "007d0550"),
+            (4567, "011d7690"),
+            (11111, "02b670b0"),
+            (49328, "0c0b0f40"),
+            (65535, "0ffff240"),
+        ];
+        for (label, prefix) in vectors.iter() {
+            assert_eq!(hex::encode(AssetNameLabel::encode(*label)), *prefix);
+            let asset_name = AssetName::new(hex::decode(prefix).unwrap()).unwrap();
+            assert_eq!(
+                AssetNameLabel::decode(&asset_name),
+                Some((*label, Vec::new()))
+            );
+        }
+
+        let user_nft = AssetName::new_cip68_user_nft(b"SpaceBud").unwrap();
+        assert_eq!(
+            hex::encode(user_nft.to_raw_bytes()),
+            "000de1405370616365427564"
+        );
+        assert_eq!(
+            AssetNameLabel::decode(&user_nft),
+            Some((AssetNameLabel::USER_NFT, b"SpaceBud".to_vec()))
+        );
+        let reference = AssetName::new_cip68_reference(b"SpaceBud").unwrap();
+        assert_eq!(hex::encode(&reference.to_raw_bytes()[..4]), "000643b0");
+        assert_eq!(
+            hex::encode(AssetNameLabel::encode(AssetNameLabel::USER_FT)),
+            "0014df10"
+        );
+        assert_eq!(
+            hex::encode(AssetNameLabel::encode(AssetNameLabel::USER_RFT)),
+            "001bc280"
+        );
+        // bad checksum / padding / too short
+        for bad in ["000de150", "100de140", "000de141", "000de1"].iter() {
+            let asset_name = AssetName::new(hex::decode(bad).unwrap()).unwrap();
+            assert_eq!(AssetNameLabel::decode(&asset_name), None);
+        }
+        assert!(AssetName::new_cip68_user_nft(&[0u8; 29]).is_err());
+    }
+
+    #[test]
+    fn cip14_asset_fingerprints() {
+        // test vectors from CIP-14
+        let vectors = [
+            (
+                "7eae28af2208be856f7a119668ae52a49b73725e326dc16579dcc373",
+                "",
+                "asset1rjklcrnsdzqp65wjgrg55sy9723kw09mlgvlc3",
+            ),
+            (
+                "7eae28af2208be856f7a119668ae52a49b73725e326dc16579dcc37e",
+                "",
+                "asset1nl0puwxmhas8fawxp8nx4e2q3wekg969n2auw3",
+            ),
+            (
+                "1e349c9bdea19fd6c147626a5260bc44b71635f398b67c59881df209",
+                "",
+                "asset1uyuxku60yqe57nusqzjx38aan3f2wq6s93f6ea",
+            ),
+            (
+                "7eae28af2208be856f7a119668ae52a49b73725e326dc16579dcc373",
+                "504154415445",
+                "asset13n25uv0yaf5kus35fm2k86cqy60z58d9xmde92",
+            ),
+            (
+                "1e349c9bdea19fd6c147626a5260bc44b71635f398b67c59881df209",
+                "504154415445",
+                "asset1hv4p5tv2a837mzqrst04d0dcptdjmluqvdx9k3",
+            ),
+            (
+                "1e349c9bdea19fd6c147626a5260bc44b71635f398b67c59881df209",
+                "7eae28af2208be856f7a119668ae52a49b73725e326dc16579dcc373",
+                "asset1aqrdypg669jgazruv5ah07nuyqe0wxjhe2el6f",
+            ),
+            (
+                "7eae28af2208be856f7a119668ae52a49b73725e326dc16579dcc373",
+                "1e349c9bdea19fd6c147626a5260bc44b71635f398b67c59881df209",
+                "asset17jd78wukhtrnmjh3fngzasxm8rck0l2r4hhyyt",
+            ),
+            (
+                "7eae28af2208be856f7a119668ae52a49b73725e326dc16579dcc373",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "asset1pkpwyknlvul7az0xx8czhl60pyel45rpje4z8w",
+            ),
+        ];
+        for (policy_id, asset_name, fingerprint) in vectors.iter() {
+            let policy_id = PolicyId::from_hex(policy_id).unwrap();
+            let asset_name = AssetName::new(hex::decode(asset_name).unwrap()).unwrap();
+            assert_eq!(asset_fingerprint(&policy_id, &asset_name), *fingerprint);
+            let mut bytes = policy_id.to_raw_bytes().to_vec();
+            bytes.extend_from_slice(asset_name.to_raw_bytes());
+            assert_eq!(from_fingerprint(fingerprint).unwrap(), blake2b160(&bytes));
+        }
+        assert!(matches!(
+            from_fingerprint("addr1rjklcrnsdzqp65wjgrg55sy9723kw09mlgvlc3"),
+            Err(AssetFingerprintError::Bech32(_))
+        ));
+        assert!(matches!(
+            from_fingerprint(&bech32::encode("pool", [0u8; 20].to_base32()).unwrap()),
+            Err(AssetFingerprintError::InvalidPrefix(_))
+        ));
+    }
+
+    #[test]
+    fn value_compare() {
+        let policy_id = PolicyId::from([0xaa; 28]);
+        let asset_name = AssetName::new(b"token".to_vec()).unwrap();
+        let mut tokens = MultiAsset::new();
+        tokens.set(policy_id, asset_name, 10);
+        let ada_only = Value::from(1_000_000);
+        let with_tokens = Value::new(1_000_000, tokens.clone());
+        let more_ada_with_tokens = Value::new(2_000_000, tokens);
+        assert_eq!(ada_only.compare(&ada_only), Some(Ordering::Equal));
+        assert_eq!(ada_only.compare(&with_tokens), Some(Ordering::Less));
+        assert_eq!(with_tokens.compare(&ada_only), Some(Ordering::Greater));
+        assert_eq!(
+            with_tokens.compare(&more_ada_with_tokens),
+            Some(Ordering::Less)
+        );
+        // more ADA but fewer tokens
+        assert_eq!(Value::from(2_000_000).compare(&with_tokens), None);
+        assert_eq!(Value::from(2_000_000).partial_cmp(&with_tokens), None);
+        // neither comparison operator holds for incomparable values
+        let more_ada = Value::from(2_000_000);
+        assert_eq!(
+            (more_ada >= with_tokens, more_ada < with_tokens),
+            (false, false)
+        );
+        assert!(more_ada_with_tokens > with_tokens);
+        assert!(more_ada_with_tokens.geq(&with_tokens));
+        assert!(more_ada_with_tokens.geq(&ada_only));
+        assert!(!ada_only.geq(&with_tokens));
+        assert!(!Value::from(2_000_000).geq(&with_tokens));
+        assert!(!with_tokens.geq(&Value::from(2_000_000)));
+
+        // fewer (but not zero) of the same token
+        let mut fewer_tokens = MultiAsset::new();
+        fewer_tokens.set(policy_id, AssetName::new(b"token".to_vec()).unwrap(), 4);
+        let with_fewer_tokens = Value::new(1_000_000, fewer_tokens);
+        assert_eq!(
+            with_tokens.compare(&with_fewer_tokens),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            with_fewer_tokens.compare(&with_tokens),
+            Some(Ordering::Less)
+        );
+        assert!(with_tokens.geq(&with_fewer_tokens));
+        assert!(!with_fewer_tokens.geq(&with_tokens));
+    }
+
+    #[test]
+    fn value_compare_zero_amounts() {
+        let (policy_a, policy_b) = (PolicyId::from([0xaa; 28]), PolicyId::from([0xbb; 28]));
+        let asset_name = AssetName::try_from("a").unwrap();
+        let mut tokens = MultiAsset::new();
+        tokens.set(policy_a, asset_name.clone(), 10);
+        // same tokens plus another policy explicitly holding 0
+        let mut tokens_with_zero = tokens.clone();
+        tokens_with_zero.set(policy_b, asset_name.clone(), 0);
+        let value = Value::new(1_000_000, tokens);
+        let value_with_zero = Value::new(1_000_000, tokens_with_zero.clone());
+        assert!(value.geq(&value_with_zero));
+        assert!(value_with_zero.geq(&value));
+        assert_eq!(value.compare(&value_with_zero), Some(Ordering::Equal));
+        assert_eq!(value_with_zero.compare(&value), Some(Ordering::Equal));
+        // PartialOrd only says Equal for == values
+        assert_ne!(value, value_with_zero);
+        assert_eq!(value.partial_cmp(&value_with_zero), None);
+        assert_eq!(value_with_zero.partial_cmp(&value), None);
+        assert_eq!(
+            value_with_zero.partial_cmp(&value_with_zero.clone()),
+            Some(Ordering::Equal)
+        );
+
+        let ada_only_with_zero = Value::new(1_000_000, {
+            let mut zero = MultiAsset::new();
+            zero.set(policy_b, asset_name, 0);
+            zero
+        });
+        assert!(!ada_only_with_zero.has_multiassets());
+        assert!(!ada_only_with_zero.is_zero());
+        assert!(Value::new(0, ada_only_with_zero.multiasset.clone()).is_zero());
+        assert!(Value::zero().is_zero());
+        assert!(value_with_zero.has_multiassets());
+        assert!(value_with_zero.geq(&ada_only_with_zero));
+        assert!(!ada_only_with_zero.geq(&value_with_zero));
+        assert_eq!(
+            ada_only_with_zero.compare(&Value::from(1_000_000)),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(
+            ada_only_with_zero.partial_cmp(&Value::from(1_000_000)),
+            None
+        );
+        assert_eq!(
+            ada_only_with_zero.partial_cmp(&Value::from(999_999)),
+            Some(Ordering::Greater)
+        );
+
+        // { policy: { name: 0 } } against an empty bundle
+        let mut zero = MultiAsset::new();
+        zero.set(policy_b, AssetName::try_from("a").unwrap(), 0);
+        let empty = MultiAsset::new();
+        assert_eq!(zero.compare(&empty), Some(Ordering::Equal));
+        assert_ne!(zero, empty);
+        assert_eq!(zero.partial_cmp(&empty), None);
+        assert_eq!(empty.partial_cmp(&zero), None);
+        assert_eq!((zero >= empty, zero <= empty), (false, false));
+        // a == b iff partial_cmp is Equal
+        for (a, b) in [(&zero, &zero), (&empty, &empty), (&zero, &empty)] {
+            assert_eq!(a == b, a.partial_cmp(b) == Some(Ordering::Equal));
+        }
+    }
+
+    #[test]
+    fn value_checked_arithmetic() {
+        let policy_id = PolicyId::from([0xaa; 28]);
+        let (a, b) = (
+            AssetName::try_from("a").unwrap(),
+            AssetName::try_from("b").unwrap(),
+        );
+        let value = |coin: Coin, assets: &[(&AssetName, u64)]| {
+            let mut multiasset = MultiAsset::new();
+            for (name, amount) in assets {
+                multiasset.set(policy_id, (*name).clone(), *amount);
+            }
+            Value::new(coin, multiasset)
+        };
+        // assets on one side only are carried over
+        assert_eq!(
+            value(1, &[(&a, 5)])
+                .checked_add(&value(2, &[(&b, 7)]))
+                .unwrap(),
+            value(3, &[(&a, 5), (&b, 7)])
+        );
+        assert!(matches!(
+            Value::from(u64::MAX).checked_add(&Value::from(1)),
+            Err(AssetArithmeticError::Arithmetic(
+                ArithmeticError::IntegerOverflow
+            ))
+        ));
+        assert!(matches!(
+            value(0, &[(&a, u64::MAX)]).checked_add(&value(0, &[(&a, 1)])),
+            Err(AssetArithmeticError::Arithmetic(
+                ArithmeticError::IntegerOverflow
+            ))
+        ));
+
+        assert_eq!(
+            value(3, &[(&a, 5), (&b, 7)])
+                .checked_sub(&value(1, &[(&a, 5)]))
+                .unwrap(),
+            value(2, &[(&b, 7)])
+        );
+        assert!(matches!(
+            Value::from(1).checked_sub(&Value::from(2)),
+            Err(AssetArithmeticError::Arithmetic(
+                ArithmeticError::IntegerUnderflow
+            ))
+        ));
+        assert!(matches!(
+            value(1, &[(&a, 1)]).checked_sub(&value(0, &[(&a, 2)])),
+            Err(AssetArithmeticError::Arithmetic(
+                ArithmeticError::IntegerUnderflow
+            ))
+        ));
+        assert!(matches!(
+            value(1, &[(&a, 1)]).checked_sub(&value(0, &[(&b, 1)])),
+            Err(AssetArithmeticError::AssetDoesntExist(_))
+        ));
+
+        // floors at 0 per asset, ignores assets only in rhs and drops 0 quantities
+        let clamped = value(1, &[(&a, 1), (&b, 0)]).clamped_sub(&value(
+            5,
+            &[(&a, u64::MAX), (&AssetName::try_from("c").unwrap(), 1)],
+        ));
+        assert_eq!(clamped, Value::zero());
+        assert!(clamped.multiasset.is_empty());
+        assert_eq!(
+            value(u64::MAX, &[(&a, u64::MAX), (&b, 3)]).clamped_sub(&value(1, &[(&b, 1)])),
+            value(u64::MAX - 1, &[(&a, u64::MAX), (&b, 2)])
+        );
+    }
+
+    #[test]
+    fn multiasset_accessors() {
+        let (policy_a, policy_b) = (PolicyId::from([0xaa; 28]), PolicyId::from([0xbb; 28]));
+        let (a, b) = (
+            AssetName::try_from("a").unwrap(),
+            AssetName::try_from("b").unwrap(),
+        );
+        let mut multiasset = MultiAsset::new();
+        assert_eq!(multiasset.policy_count(), 0);
+        assert_eq!(multiasset.asset_count(), 0);
+        assert_eq!(multiasset.get_asset(&policy_a, &a), 0);
+
+        assert_eq!(multiasset.set_asset(policy_a, a.clone(), 5), 0);
+        assert_eq!(multiasset.set_asset(policy_a, a.clone(), 6), 5);
+        assert_eq!(multiasset.set_asset(policy_a, b.clone(), 7), 0);
+        assert_eq!(multiasset.set_asset(policy_b, a.clone(), 1), 0);
+        assert_eq!(multiasset.policy_count(), 2);
+        assert_eq!(multiasset.asset_count(), 3);
+        assert_eq!(multiasset.get_asset(&policy_a, &a), 6);
+        assert_eq!(multiasset.get_asset(&policy_b, &b), 0);
+        assert_eq!(multiasset.get_assets(&policy_a).unwrap().len(), 2);
+        assert!(multiasset.get_assets(&PolicyId::from([0xcc; 28])).is_none());
+        assert_eq!(
+            multiasset.iter_flat().collect::<Vec<_>>(),
+            vec![
+                (policy_a, a.clone(), 6),
+                (policy_a, b.clone(), 7),
+                (policy_b, a.clone(), 1),
+            ]
+        );
+
+        // setting zero removes the asset and then the emptied policy
+        assert_eq!(multiasset.set_asset(policy_b, a.clone(), 0), 1);
+        assert_eq!(multiasset.set_asset(policy_b, b.clone(), 0), 0);
+        assert_eq!(multiasset.policy_count(), 1);
+        assert!(multiasset.get_assets(&policy_b).is_none());
+        assert_eq!(multiasset.set_asset(policy_a, a, 0), 6);
+        assert_eq!(multiasset.asset_count(), 1);
+
+        let mut mint = Mint::new();
+        mint.set_asset(policy_a, b.clone(), -3);
+        assert_eq!(mint.get_asset(&policy_a, &b), -3);
+        assert_eq!(
+            mint.iter_flat().collect::<Vec<_>>(),
+            vec![(policy_a, b, -3)]
+        );
+    }
+
+    #[test]
+    fn ada_strings() {
+        for (coin, ada) in [
+            (0, "0"),
+            (1, "0.000001"),
+            (1_500_000, "1.5"),
+            (2_000_000, "2"),
+            (1_234_567, "1.234567"),
+            (u64::MAX, "18446744073709.551615"),
+        ] {
+            assert_eq!(coin_to_ada_string(coin), ada);
+            assert_eq!(coin_from_ada_string(ada), Ok(coin));
+        }
+        assert_eq!(coin_from_ada_string("0.10"), Ok(100_000));
+        assert_eq!(coin_from_ada_string("007"), Ok(7_000_000));
+        for invalid in ["", ".5", "1.", "-1", "1e6", "1,5", " 1", "1.2.3"] {
+            assert!(matches!(
+                coin_from_ada_string(invalid),
+                Err(AdaStringError::Invalid(_))
+            ));
+        }
+        assert!(matches!(
+            coin_from_ada_string("1.0000001"),
+            Err(AdaStringError::TooManyDecimals(_))
+        ));
+        assert!(matches!(
+            coin_from_ada_string("18446744073709.551616"),
+            Err(AdaStringError::Overflow(_))
+        ));
+        assert!(matches!(
+            coin_from_ada_string("99999999999999999999"),
+            Err(AdaStringError::Overflow(_))
+        ));
+    }
+
+    #[test]
+    fn value_display() {
+        assert_eq!(Value::from(1_500_000).to_string(), "1.5 ADA");
+        let policy_id = PolicyId::from([0xd5; 28]);
+        let mut multiasset = MultiAsset::new();
+        multiasset.set(policy_id, AssetName::try_from("HOSKY").unwrap(), 100);
+        multiasset.set(
+            policy_id,
+            AssetName::new(hex::decode("000de1404e4654").unwrap()).unwrap(),
+            1,
+        );
+        let value = Value::new(1_500_000, multiasset.clone());
+        assert_eq!(
+            value.to_string(),
+            "1.5 ADA + 2 assets (d5d5d5d5… HOSKY: 100, d5d5d5d5… 000de140…: 1)"
+        );
+        assert_eq!(
+            value.format_full(),
+            format!(
+                "1.5 ADA + 2 assets ({0} HOSKY: 100, {0} 000de1404e4654: 1)",
+                policy_id.to_hex()
+            )
+        );
+        // 12 assets: Display lists 10 of them
+        for i in 0..10u8 {
+            multiasset.set(
+                PolicyId::from([i; 28]),
+                AssetName::new(vec![b'a' + i]).unwrap(),
+                u64::MAX,
+            );
+        }
+        let value = Value::new(1, multiasset);
+        let display = value.to_string();
+        assert!(display.starts_with("0.000001 ADA + 12 assets ("));
+        assert!(display.ends_with(", …2 more)"));
+        assert_eq!(display.matches(": ").count(), 10);
+        assert!(value
+            .format_compact(1)
+            .ends_with("assets (d5d5d5d5… HOSKY: 100, …11 more)"));
+        assert_eq!(value.format_full().matches(": ").count(), 12);
+        assert!(value.format_full().contains("j: 18446744073709551615"));
+    }
+
+    #[test]
+    fn mint_display() {
+        assert_eq!(Mint::new().to_string(), "0 assets");
+        let mut mint = Mint::new();
+        mint.set(
+            PolicyId::from([0xaa; 28]),
+            AssetName::try_from("a").unwrap(),
+            5,
+        );
+        mint.set(
+            PolicyId::from([0xbb; 28]),
+            AssetName::try_from("").unwrap(),
+            -1,
+        );
+        assert_eq!(
+            mint.to_string(),
+            "2 assets (aaaaaaaa… a: +5, bbbbbbbb… \"\": -1)"
+        );
+    }
+}
@@ -17,10 +17,12 @@ pub struct ConwayFormatAuxDataEncoding {
     pub plutus_v2_scripts_key_encoding: Option<cbor_event::Sz>,
     pub plutus_v3_scripts_encoding: LenEncoding,
     pub plutus_v3_scripts_key_encoding: Option<cbor_event::Sz>,
+    pub original_bytes: Option<Vec<u8>>,
 }
 
 #[derive(Clone, Debug, Default)]
 pub struct ShelleyMAFormatAuxDataEncoding {
     pub len_encoding: LenEncoding,
     pub auxiliary_scripts_encoding: LenEncoding,
+    pub original_bytes: Option<Vec<u8>>,
 }
@@ -4,7 +4,10 @@ use crate::json::metadatums::{
 use cbor_event::{de::Deserializer, se::Serializer};
 use cml_core::{
     error::{DeserializeError, DeserializeFailure},
-    serialization::{fit_sz, Deserialize, LenEncoding, Serialize, StringEncoding},
+    serialization::{
+        canonical_key_cmp, fit_sz, Deserialize, LenEncoding, Serialize, StringEncoding,
+        StripEncodings,
+    },
     Int,
 };
 use derivative::Derivative;
@@ -18,7 +21,15 @@ pub const METADATA_MAX_LEN: usize = 64;
 /// Collection of TransactionMetadatums indexed by TransactionMetadatumLabels
 /// Handles the extremely rare edge-case of in previous generations allowing
 /// duplicate metadatum labels.
-#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    StripEncodings,
+)]
 pub struct Metadata {
     pub entries: Vec<(TransactionMetadatumLabel, TransactionMetadatum)>,
     #[serde(skip)]
@@ -73,6 +84,7 @@ impl Metadata {
 pub struct MetadataEncoding {
     pub len_encoding: LenEncoding,
     pub label_encodings: Vec<cbor_event::Sz>,
+    pub original_bytes: Option<Vec<u8>>,
 }
 
 impl Serialize for Metadata {
@@ -104,10 +116,7 @@ impl Serialize for Metadata {
         }
         if force_canonical {
             key_order.sort_by(|(lhs_bytes, _, _), (rhs_bytes, _, _)| {
-                match lhs_bytes.len().cmp(&rhs_bytes.len()) {
-                    std::cmp::Ordering::Equal => lhs_bytes.cmp(rhs_bytes),
-                    diff_ord => diff_ord,
-                }
+                canonical_key_cmp(lhs_bytes, rhs_bytes)
             });
         }
         for (key_bytes, _key, value) in key_order {
@@ -146,6 +155,7 @@ impl Deserialize for Metadata {
             encodings: Some(MetadataEncoding {
                 len_encoding,
                 label_encodings,
+                original_bytes: None,
             }),
         })
     }
@@ -153,7 +163,7 @@ impl Deserialize for Metadata {
 
 /// Handles the extremely rare (2 total instances on mainnet) edge-case of in
 /// previous generations allowing duplicate metadatum keys.
-#[derive(Clone, Debug, Default, Derivative)]
+#[derive(Clone, Debug, Default, Derivative, StripEncodings)]
 #[derivative(Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct MetadatumMap {
     pub entries: Vec<(TransactionMetadatum, TransactionMetadatum)>,
@@ -236,10 +246,7 @@ impl Serialize for MetadatumMap {
             .collect::<Result<Vec<(Vec<u8>, &_, &_)>, cbor_event::Error>>()?;
         if force_canonical {
             key_order.sort_by(|(lhs_bytes, _, _), (rhs_bytes, _, _)| {
-                match lhs_bytes.len().cmp(&rhs_bytes.len()) {
-                    std::cmp::Ordering::Equal => lhs_bytes.cmp(rhs_bytes),
-                    diff_ord => diff_ord,
-                }
+                canonical_key_cmp(lhs_bytes, rhs_bytes)
             });
         }
         for (key_bytes, _key, value) in key_order {
@@ -274,7 +281,7 @@ impl Deserialize for MetadatumMap {
     }
 }
 
-#[derive(Clone, Debug, Derivative)]
+#[derive(Clone, Debug, Derivative, StripEncodings)]
 #[derivative(
     Eq,
     PartialEq,
@@ -607,4 +614,81 @@ mod tests {
         let roundtrip_str = serde_json::to_string(&metadatum).unwrap();
         assert_eq!(json_str, roundtrip_str);
     }
+
+    #[test]
+    fn metadatum_map_canonical_key_order() {
+        use cml_core::serialization::{with_canonical_key_order, CanonicalKeyOrder};
+        let long_text = "a long text key over 23 bytes";
+        let int_key = TransactionMetadatum::new_int(Int::new_uint(1000));
+        let short_text_key = TransactionMetadatum::new_text("a".to_owned()).unwrap();
+        let long_text_key = TransactionMetadatum::new_text(long_text.to_owned()).unwrap();
+        let int_key_hex = "1903e8";
+        let short_text_key_hex = "6161";
+        let long_text_key_hex = format!("781d{}", hex::encode(long_text));
+
+        // int key and long text key: both orderings agree
+        let mut map = MetadatumMap::new();
+        map.set(
+            long_text_key.clone(),
+            TransactionMetadatum::new_int(0u64.into()),
+        );
+        map.set(int_key.clone(), TransactionMetadatum::new_int(1u64.into()));
+        let metadatum = TransactionMetadatum::new_map(map.clone());
+        assert_eq!(
+            hex::encode(metadatum.to_cbor_bytes()),
+            format!("a2{}00{}01", long_text_key_hex, int_key_hex)
+        );
+        let canonical = format!("a2{}01{}00", int_key_hex, long_text_key_hex);
+        assert_eq!(hex::encode(metadatum.to_canonical_cbor_bytes()), canonical);
+        assert_eq!(
+            hex::encode(
+                metadatum.to_canonical_cbor_bytes_with_key_order(CanonicalKeyOrder::LengthFirst)
+            ),
+            canonical
+        );
+
+        // a short text key sorts after the int key bytewise (0x19 < 0x61) but before it when
+        // shorter keys come first (2 < 3 bytes)
+        map.set(short_text_key, TransactionMetadatum::new_int(2u64.into()));
+        let metadatum = TransactionMetadatum::new_map(map);
+        assert_eq!(
+            hex::encode(metadatum.to_canonical_cbor_bytes()),
+            format!(
+                "a3{}01{}02{}00",
+                int_key_hex, short_text_key_hex, long_text_key_hex
+            )
+        );
+        let legacy = format!(
+            "a3{}02{}01{}00",
+            short_text_key_hex, int_key_hex, long_text_key_hex
+        );
+        assert_eq!(
+            hex::encode(
+                metadatum.to_canonical_cbor_bytes_with_key_order(CanonicalKeyOrder::LengthFirst)
+            ),
+            legacy
+        );
+        assert_eq!(
+            with_canonical_key_order(CanonicalKeyOrder::LengthFirst, || hex::encode(
+                metadatum.to_canonical_cbor_bytes()
+            )),
+            legacy
+        );
+        // the ordering only applies within the closure
+        assert_eq!(
+            hex::encode(metadatum.to_canonical_cbor_bytes()),
+            format!(
+                "a3{}01{}02{}00",
+                int_key_hex, short_text_key_hex, long_text_key_hex
+            )
+        );
+        // non-canonical serialization keeps the insertion order
+        assert_eq!(
+            hex::encode(metadatum.to_cbor_bytes()),
+            format!(
+                "a3{}00{}01{}02",
+                long_text_key_hex, int_key_hex, short_text_key_hex
+            )
+        );
+    }
 }
@@ -9,10 +9,13 @@ pub mod utils;
 use crate::plutus::{PlutusV1Script, PlutusV2Script, PlutusV3Script};
 use crate::transaction::NativeScript;
 use cbor_encodings::{ConwayFormatAuxDataEncoding, ShelleyMAFormatAuxDataEncoding};
+use cml_core::serialization::StripEncodings;
 
 pub use metadata::*;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub enum AuxiliaryData {
     Shelley(ShelleyFormatAuxData),
     ShelleyMA(ShelleyMAFormatAuxData),
@@ -33,7 +36,9 @@ impl AuxiliaryData {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ConwayFormatAuxData {
     pub metadata: Option<Metadata>,
     pub native_scripts: Option<Vec<NativeScript>>,
@@ -65,7 +70,9 @@ impl Default for ConwayFormatAuxData {
 
 pub type ShelleyFormatAuxData = Metadata;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ShelleyMAFormatAuxData {
     pub transaction_metadata: Metadata,
     pub auxiliary_scripts: Vec<NativeScript>,
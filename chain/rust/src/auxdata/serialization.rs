@@ -506,6 +506,7 @@ impl Deserialize for ConwayFormatAuxData {
                     plutus_v2_scripts_encoding,
                     plutus_v3_scripts_key_encoding,
                     plutus_v3_scripts_encoding,
+                    original_bytes: None,
                 }),
             })
         })()
@@ -592,6 +593,7 @@ impl Deserialize for ShelleyMAFormatAuxData {
                 encodings: Some(ShelleyMAFormatAuxDataEncoding {
                     len_encoding,
                     auxiliary_scripts_encoding,
+                    original_bytes: None,
                 }),
             })
         })()
@@ -1,4 +1,5 @@
 use crate::auxdata::metadata::Metadata;
+use cml_core::serialization::OriginalBytes;
 
 use crate::{
     plutus::{PlutusV1Script, PlutusV2Script},
@@ -7,11 +8,30 @@ use crate::{
 
 use super::{AuxiliaryData, ConwayFormatAuxData, ShelleyMAFormatAuxData};
 
+/// The era-specific format an AuxiliaryData is encoded in.
+/// Changing formats changes the bytes and thus the auxiliary_data_hash.
+/// In the wasm bindings this is AuxiliaryDataKind (see AuxiliaryData.kind()) which converts
+/// to and from this type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AuxiliaryDataFormat {
+    Shelley,
+    ShelleyMA,
+    Conway,
+}
+
 impl AuxiliaryData {
     pub fn new() -> Self {
         Self::new_shelley(Metadata::new())
     }
 
+    pub fn format(&self) -> AuxiliaryDataFormat {
+        match self {
+            Self::Shelley(_) => AuxiliaryDataFormat::Shelley,
+            Self::ShelleyMA(_) => AuxiliaryDataFormat::ShelleyMA,
+            Self::Conway(_) => AuxiliaryDataFormat::Conway,
+        }
+    }
+
     pub fn metadata(&self) -> Option<&Metadata> {
         match self {
             Self::Shelley(shelley) => Some(shelley),
@@ -194,6 +214,39 @@ impl AuxiliaryData {
     }
 }
 
+impl OriginalBytes for AuxiliaryData {
+    fn original_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::Shelley(shelley) => shelley.encodings.as_ref()?.original_bytes.as_deref(),
+            Self::ShelleyMA(shelley_ma) => shelley_ma.encodings.as_ref()?.original_bytes.as_deref(),
+            Self::Conway(conway) => conway.encodings.as_ref()?.original_bytes.as_deref(),
+        }
+    }
+
+    fn set_original_bytes(&mut self, original_bytes: Option<Vec<u8>>) {
+        match self {
+            Self::Shelley(shelley) => {
+                shelley
+                    .encodings
+                    .get_or_insert_with(Default::default)
+                    .original_bytes = original_bytes
+            }
+            Self::ShelleyMA(shelley_ma) => {
+                shelley_ma
+                    .encodings
+                    .get_or_insert_with(Default::default)
+                    .original_bytes = original_bytes
+            }
+            Self::Conway(conway) => {
+                conway
+                    .encodings
+                    .get_or_insert_with(Default::default)
+                    .original_bytes = original_bytes
+            }
+        }
+    }
+}
+
 impl Default for AuxiliaryData {
     fn default() -> Self {
         Self::new()
@@ -11,6 +11,8 @@ pub struct BlockEncoding {
     pub transaction_witness_sets_encoding: LenEncoding,
     pub auxiliary_data_set_encoding: LenEncoding,
     pub auxiliary_data_set_key_encodings: BTreeMap<u16, Option<cbor_event::Sz>>,
+    /// position of each Block::unparsed_auxiliary_data entry within the auxiliary_data_set map
+    pub unparsed_auxiliary_data_positions: Vec<usize>,
     pub invalid_transactions_encoding: LenEncoding,
     pub invalid_transactions_elem_encodings: Vec<Option<cbor_event::Sz>>,
 }
@@ -30,6 +32,7 @@ pub struct HeaderBodyEncoding {
 #[derive(Clone, Debug, Default)]
 pub struct HeaderEncoding {
     pub len_encoding: LenEncoding,
+    pub original_bytes: Option<Vec<u8>>,
 }
 
 #[derive(Clone, Debug, Default)]
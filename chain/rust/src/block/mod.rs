@@ -3,6 +3,7 @@
 
 pub mod cbor_encodings;
 pub mod serialization;
+pub mod utils;
 
 use super::TransactionIndex;
 use crate::auxdata::AuxiliaryData;
@@ -15,14 +16,21 @@ use cbor_encodings::{
     ProtocolVersionEncoding,
 };
 use cml_core::ordered_hash_map::OrderedHashMap;
+use cml_core::serialization::StripEncodings;
 use std::collections::BTreeMap;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct Block {
     pub header: Header,
     pub transaction_bodies: Vec<TransactionBody>,
     pub transaction_witness_sets: Vec<TransactionWitnessSet>,
     pub auxiliary_data_set: OrderedHashMap<TransactionIndex, AuxiliaryData>,
+    /// auxiliary_data_set entries that are well-formed CBOR but failed to deserialize.
+    /// These are re-serialized as-is. See UnparsedAuxiliaryData
+    #[serde(default)]
+    pub unparsed_auxiliary_data: Vec<UnparsedAuxiliaryData>,
     pub invalid_transactions: Vec<TransactionIndex>,
     #[serde(skip)]
     pub encodings: Option<BlockEncoding>,
@@ -41,13 +49,30 @@ impl Block {
             transaction_bodies,
             transaction_witness_sets,
             auxiliary_data_set,
+            unparsed_auxiliary_data: Vec::new(),
             invalid_transactions,
             encodings: None,
         }
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+/// An auxiliary_data_set entry of a block that failed to deserialize e.g. metadata with non-UTF8 text.
+/// Deserializing the rest of the block continues past it and the exact bytes are kept
+/// so the block still re-serializes (and hashes) the same.
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
+pub struct UnparsedAuxiliaryData {
+    pub transaction_index: TransactionIndex,
+    /// The CBOR bytes of the auxiliary data
+    pub bytes: Vec<u8>,
+    /// Why deserializing it as AuxiliaryData failed
+    pub error: String,
+}
+
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct Header {
     pub header_body: HeaderBody,
     pub body_signature: KESSignature,
@@ -65,7 +90,9 @@ impl Header {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct HeaderBody {
     pub block_number: u64,
     pub slot: u64,
@@ -111,7 +138,9 @@ impl HeaderBody {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct OperationalCert {
     pub hot_vkey: KESVkey,
     pub sequence_number: u64,
@@ -138,11 +167,26 @@ impl OperationalCert {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone,
+    Debug,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    derivative::Derivative,
+    StripEncodings,
+)]
+#[derivative(Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct ProtocolVersion {
     pub major: u64,
     pub minor: u64,
     #[serde(skip)]
+    #[derivative(
+        PartialEq = "ignore",
+        Ord = "ignore",
+        PartialOrd = "ignore",
+        Hash = "ignore"
+    )]
     pub encodings: Option<ProtocolVersionEncoding>,
 }
 
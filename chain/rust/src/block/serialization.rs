@@ -8,7 +8,7 @@ use cbor_event::se::Serializer;
 use cml_core::error::*;
 use cml_core::serialization::*;
 use cml_crypto::RawBytesEncoding;
-use std::io::{BufRead, Seek, Write};
+use std::io::{BufRead, Seek, SeekFrom, Write};
 
 impl Serialize for Block {
     fn serialize<'se, W: Write>(
@@ -59,37 +59,71 @@ impl Serialize for Block {
                 .as_ref()
                 .map(|encs| encs.auxiliary_data_set_encoding)
                 .unwrap_or_default()
-                .to_len_sz(self.auxiliary_data_set.len() as u64, force_canonical),
+                .to_len_sz(
+                    (self.auxiliary_data_set.len() + self.unparsed_auxiliary_data.len()) as u64,
+                    force_canonical,
+                ),
         )?;
+        enum AuxiliaryDataEntry<'a> {
+            Parsed(&'a AuxiliaryData),
+            Unparsed(&'a [u8]),
+        }
+        let auxiliary_data_set_key_bytes = |k: &u16| -> cbor_event::Result<Vec<u8>> {
+            let mut buf = cbor_event::se::Serializer::new_vec();
+            let auxiliary_data_set_key_encoding = self
+                .encodings
+                .as_ref()
+                .and_then(|encs| encs.auxiliary_data_set_key_encodings.get(k))
+                .cloned()
+                .unwrap_or_default();
+            buf.write_unsigned_integer_sz(
+                *k as u64,
+                fit_sz(*k as u64, auxiliary_data_set_key_encoding, force_canonical),
+            )?;
+            Ok(buf.finalize())
+        };
         let mut key_order = self
             .auxiliary_data_set
             .iter()
             .map(|(k, v)| {
-                let mut buf = cbor_event::se::Serializer::new_vec();
-                let auxiliary_data_set_key_encoding = self
-                    .encodings
-                    .as_ref()
-                    .and_then(|encs| encs.auxiliary_data_set_key_encodings.get(k))
-                    .cloned()
-                    .unwrap_or_default();
-                buf.write_unsigned_integer_sz(
-                    *k as u64,
-                    fit_sz(*k as u64, auxiliary_data_set_key_encoding, force_canonical),
-                )?;
-                Ok((buf.finalize(), k, v))
+                Ok((
+                    auxiliary_data_set_key_bytes(k)?,
+                    k,
+                    AuxiliaryDataEntry::Parsed(v),
+                ))
             })
-            .collect::<Result<Vec<(Vec<u8>, &_, &_)>, cbor_event::Error>>()?;
+            .collect::<Result<Vec<(Vec<u8>, &_, _)>, cbor_event::Error>>()?;
+        // put unparsed entries back where they were read from
+        for (i, unparsed) in self.unparsed_auxiliary_data.iter().enumerate() {
+            let position = self
+                .encodings
+                .as_ref()
+                .and_then(|encs| encs.unparsed_auxiliary_data_positions.get(i))
+                .map_or(key_order.len(), |pos| (*pos).min(key_order.len()));
+            key_order.insert(
+                position,
+                (
+                    auxiliary_data_set_key_bytes(&unparsed.transaction_index)?,
+                    &unparsed.transaction_index,
+                    AuxiliaryDataEntry::Unparsed(&unparsed.bytes),
+                ),
+            );
+        }
         if force_canonical {
             key_order.sort_by(|(lhs_bytes, _, _), (rhs_bytes, _, _)| {
-                match lhs_bytes.len().cmp(&rhs_bytes.len()) {
-                    std::cmp::Ordering::Equal => lhs_bytes.cmp(rhs_bytes),
-                    diff_ord => diff_ord,
-                }
+                canonical_key_cmp(lhs_bytes, rhs_bytes)
             });
         }
         for (key_bytes, _key, value) in key_order {
             serializer.write_raw_bytes(&key_bytes)?;
-            value.serialize(serializer, force_canonical)?;
+            match value {
+                AuxiliaryDataEntry::Parsed(auxiliary_data) => {
+                    auxiliary_data.serialize(serializer, force_canonical)?;
+                }
+                AuxiliaryDataEntry::Unparsed(bytes) => {
+                    serializer.write_raw_bytes(bytes)?;
+                }
+            }
         }
         self.encodings
             .as_ref()
@@ -134,13 +168,22 @@ impl Serialize for Block {
 
 impl Deserialize for Block {
     fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
+        Self::deserialize_opt_keep_original(raw, false)
+    }
+}
+
+impl DeserializeKeepOriginal for Block {
+    fn deserialize_opt_keep_original<R: BufRead + Seek>(
+        raw: &mut Deserializer<R>,
+        keep_original: bool,
+    ) -> Result<Self, DeserializeError> {
         let len = raw.array_sz()?;
         let len_encoding: LenEncoding = len.into();
         let mut read_len = CBORReadLen::new(len);
         read_len.read_elems(5)?;
         read_len.finish()?;
         (|| -> Result<_, DeserializeError> {
-            let header = Header::deserialize(raw).map_err(|e: DeserializeError| e.annotate("header"))?;
+            let header = Header::deserialize_maybe_keep_original(raw, keep_original).map_err(|e: DeserializeError| e.annotate("header"))?;
             let (transaction_bodies, transaction_bodies_encoding) = (|| -> Result<_, DeserializeError> {
                 let mut transaction_bodies_arr = Vec::new();
                 let len = raw.array_sz()?;
@@ -150,7 +193,7 @@ impl Deserialize for Block {
                         assert_eq!(raw.special()?, cbor_event::Special::Break);
                         break;
                     }
-                    transaction_bodies_arr.push(TransactionBody::deserialize(raw)?);
+                    transaction_bodies_arr.push(TransactionBody::deserialize_maybe_keep_original(raw, keep_original)?);
                 }
                 Ok((transaction_bodies_arr, transaction_bodies_encoding))
             })().map_err(|e| e.annotate("transaction_bodies"))?;
@@ -167,22 +210,39 @@ impl Deserialize for Block {
                 }
                 Ok((transaction_witness_sets_arr, transaction_witness_sets_encoding))
             })().map_err(|e| e.annotate("transaction_witness_sets"))?;
+            let mut unparsed_auxiliary_data = Vec::new();
+            let mut unparsed_auxiliary_data_positions = Vec::new();
             let (auxiliary_data_set, auxiliary_data_set_encoding, auxiliary_data_set_key_encodings) = (|| -> Result<_, DeserializeError> {
                 let mut auxiliary_data_set_table = OrderedHashMap::new();
                 let auxiliary_data_set_len = raw.map_sz()?;
                 let auxiliary_data_set_encoding = auxiliary_data_set_len.into();
                 let mut auxiliary_data_set_key_encodings = BTreeMap::new();
-                while match auxiliary_data_set_len { cbor_event::LenSz::Len(n, _) => (auxiliary_data_set_table.len() as u64) < n, cbor_event::LenSz::Indefinite => true, } {
+                while match auxiliary_data_set_len { cbor_event::LenSz::Len(n, _) => ((auxiliary_data_set_table.len() + unparsed_auxiliary_data.len()) as u64) < n, cbor_event::LenSz::Indefinite => true, } {
                     if raw.cbor_type()? == cbor_event::Type::Special {
                         assert_eq!(raw.special()?, cbor_event::Special::Break);
                         break;
                     }
                     let (auxiliary_data_set_key, auxiliary_data_set_key_encoding) = raw.unsigned_integer_sz().map(|(x, enc)| (x as u16, Some(enc)))?;
-                    let auxiliary_data_set_value = AuxiliaryData::deserialize(raw)?;
-                    if auxiliary_data_set_table.insert(auxiliary_data_set_key, auxiliary_data_set_value).is_some() {
+                    if auxiliary_data_set_key_encodings.insert(auxiliary_data_set_key, auxiliary_data_set_key_encoding).is_some() {
                         return Err(DeserializeFailure::DuplicateKey(Key::Str(String::from("some complicated/unsupported type"))).into());
                     }
-                    auxiliary_data_set_key_encodings.insert(auxiliary_data_set_key, auxiliary_data_set_key_encoding);
+                    // a single malformed entry (e.g. pathological metadata) must not make the whole block unreadable
+                    let initial_position = raw.as_mut_ref().stream_position().unwrap();
+                    match AuxiliaryData::deserialize(raw) {
+                        Ok(auxiliary_data_set_value) => {
+                            auxiliary_data_set_table.insert(auxiliary_data_set_key, auxiliary_data_set_value);
+                        }
+                        Err(e) => {
+                            raw.as_mut_ref().seek(SeekFrom::Start(initial_position)).unwrap();
+                            let bytes = read_cbor_item_bytes(raw)?;
+                            unparsed_auxiliary_data_positions.push(auxiliary_data_set_table.len() + unparsed_auxiliary_data.len());
+                            unparsed_auxiliary_data.push(UnparsedAuxiliaryData {
+                                transaction_index: auxiliary_data_set_key,
+                                bytes,
+                                error: e.to_string(),
+                            });
+                        }
+                    }
                 }
                 Ok((auxiliary_data_set_table, auxiliary_data_set_encoding, auxiliary_data_set_key_encodings))
             })().map_err(|e| e.annotate("auxiliary_data_set"))?;
@@ -214,6 +274,7 @@ impl Deserialize for Block {
                 transaction_bodies,
                 transaction_witness_sets,
                 auxiliary_data_set,
+                unparsed_auxiliary_data,
                 invalid_transactions,
                 encodings: Some(BlockEncoding {
                     len_encoding,
@@ -221,6 +282,7 @@ impl Deserialize for Block {
                     transaction_witness_sets_encoding,
                     auxiliary_data_set_encoding,
                     auxiliary_data_set_key_encodings,
+                    unparsed_auxiliary_data_positions,
                     invalid_transactions_encoding,
                     invalid_transactions_elem_encodings,
                 }),
@@ -274,7 +336,10 @@ impl Deserialize for Header {
             Ok(Header {
                 header_body,
                 body_signature,
-                encodings: Some(HeaderEncoding { len_encoding }),
+                encodings: Some(HeaderEncoding {
+                    len_encoding,
+                    original_bytes: None,
+                }),
             })
         })()
         .map_err(|e| e.annotate("Header"))
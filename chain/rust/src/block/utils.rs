@@ -0,0 +1,28 @@
+use cml_crypto::NonceHash;
+
+use cml_core::serialization::OriginalBytes;
+
+use super::{Header, HeaderBody};
+
+impl OriginalBytes for Header {
+    fn original_bytes(&self) -> Option<&[u8]> {
+        self.encodings
+            .as_ref()
+            .and_then(|encs| encs.original_bytes.as_deref())
+    }
+
+    fn set_original_bytes(&mut self, original_bytes: Option<Vec<u8>>) {
+        self.encodings
+            .get_or_insert_with(Default::default)
+            .original_bytes = original_bytes;
+    }
+}
+
+impl HeaderBody {
+    /// This block's contribution to the evolving epoch nonce.
+    /// Praos (Babbage onwards) headers only have the one `vrf_result` certificate so the nonce
+    /// is derived from its "N" domain-separated output. See VRFCert::praos_nonce()
+    pub fn vrf_output_to_nonce(&self) -> NonceHash {
+        self.vrf_result.praos_nonce()
+    }
+}
@@ -5,7 +5,10 @@ use std::collections::HashSet;
 use super::witness_builder::{NativeScriptWitnessInfo, RequiredWitnessSet};
 
 use crate::{
-    certs::{Certificate, StakeCredential},
+    certs::{
+        utils::{PoolParamsValidationError, UrlError},
+        Certificate, StakeCredential,
+    },
     RequiredSigners,
 };
 
@@ -20,6 +23,10 @@ pub enum CertBuilderError {
     ExpectedScriptHash(Certificate),
     #[error("Missing the following witnesses for the certificate: {0:?}")]
     MissingWitnesses(RequiredWitnessSet),
+    #[error("Invalid pool params: {0}")]
+    InvalidPoolParams(#[from] PoolParamsValidationError),
+    #[error("Invalid anchor URL: {0}")]
+    InvalidAnchorUrl(#[from] UrlError),
 }
 
 // comes from witsVKeyNeeded in the Ledger spec
@@ -221,14 +228,63 @@ pub struct CertificateBuilderResult {
 #[derive(Clone)]
 pub struct SingleCertificateBuilder {
     cert: Certificate,
+    strict_urls: bool,
 }
 
 impl SingleCertificateBuilder {
     pub fn new(cert: Certificate) -> Self {
-        Self { cert }
+        Self {
+            cert,
+            strict_urls: true,
+        }
+    }
+
+    /// By default payment_key(), native_script() and plutus_script() check relay DNS names,
+    /// pool metadata URLs and anchor URLs with DNSName::validate_strict() / Url::validate_strict().
+    /// This turns those checks off e.g. to re-submit on-chain data that doesn't pass them.
+    pub fn skip_url_validation(mut self) -> Self {
+        self.strict_urls = false;
+        self
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn validate_urls(&self) -> Result<(), CertBuilderError> {
+        if !self.strict_urls {
+            return Ok(());
+        }
+        let anchor = match &self.cert {
+            Certificate::PoolRegistration(registration) => {
+                registration.pool_params.validate_urls()?;
+                None
+            }
+            Certificate::RegDrepCert(cert) => cert.anchor.as_ref(),
+            Certificate::UpdateDrepCert(cert) => cert.anchor.as_ref(),
+            Certificate::ResignCommitteeColdCert(cert) => cert.anchor.as_ref(),
+            _ => None,
+        };
+        if let Some(anchor) = anchor {
+            anchor.anchor_url.validate_strict()?;
+        }
+        Ok(())
+    }
+
+    /// Like new() but also validates {cert} against the network it will be submitted to.
+    /// For pool registrations this checks the reward account network and that relays don't
+    /// have empty DNS names. See PoolParams::validate_network() and PoolParams::validate_relays()
+    #[allow(clippy::result_large_err)]
+    pub fn new_for_network(
+        cert: Certificate,
+        network_id: NetworkId,
+    ) -> Result<Self, CertBuilderError> {
+        if let Certificate::PoolRegistration(registration) = &cert {
+            registration.pool_params.validate_network(network_id)?;
+            registration.pool_params.validate_relays()?;
+        }
+        Ok(Self::new(cert))
     }
 
     /// note: particularly useful for StakeRegistration which doesn't require witnessing
+    /// note: doesn't check URLs as any certificate containing them requires witnessing
     pub fn skip_witness(self) -> CertificateBuilderResult {
         let mut required_wits = RequiredWitnessSet::default();
         cert_required_wits(&self.cert, &mut required_wits);
@@ -242,6 +298,7 @@ impl SingleCertificateBuilder {
 
     #[allow(clippy::result_large_err)]
     pub fn payment_key(self) -> Result<CertificateBuilderResult, CertBuilderError> {
+        self.validate_urls()?;
         let mut required_wits = RequiredWitnessSet::default();
         cert_required_wits(&self.cert, &mut required_wits);
 
@@ -263,6 +320,7 @@ impl SingleCertificateBuilder {
         native_script: NativeScript,
         witness_info: NativeScriptWitnessInfo,
     ) -> Result<CertificateBuilderResult, CertBuilderError> {
+        self.validate_urls()?;
         let mut required_wits = RequiredWitnessSet::default();
         cert_required_wits(&self.cert, &mut required_wits);
         let mut required_wits_left = required_wits.clone();
@@ -300,6 +358,7 @@ impl SingleCertificateBuilder {
         partial_witness: PartialPlutusWitness,
         required_signers: RequiredSigners,
     ) -> Result<CertificateBuilderResult, CertBuilderError> {
+        self.validate_urls()?;
         let mut required_wits = RequiredWitnessSet::default();
         required_signers
             .iter()
@@ -0,0 +1,71 @@
+use std::collections::BTreeMap;
+
+use crate::plutus::{ExUnits, RedeemerKey, RedeemerTag};
+use crate::transaction::Transaction;
+
+use super::tx_builder::TransactionUnspentOutput;
+
+#[derive(Debug, thiserror::Error)]
+pub enum EvalError {
+    #[error("No ExUnits available for redeemer {0:?} at index {1}")]
+    MissingExUnits(RedeemerTag, u64),
+    #[error("Script evaluation failed: {0}")]
+    ScriptFailure(String),
+}
+
+/// Script evaluation backend used to compute the ExUnits of a transaction's redeemers.
+/// CML does not ship a plutus interpreter so this is the hook for external evaluators
+/// e.g. uplc bindings or a remote service like Ogmios.
+pub trait TxEvaluator {
+    /// Evaluates all scripts in {tx}.
+    /// {tx} is a draft with dummy ExUnits and script_data_hash.
+    /// {resolved_inputs} contains the UTXOs for all inputs, collateral and reference inputs.
+    /// Must return the ExUnits for every redeemer in the draft tx.
+    fn evaluate(
+        &self,
+        tx: &Transaction,
+        resolved_inputs: &[TransactionUnspentOutput],
+    ) -> Result<BTreeMap<RedeemerKey, ExUnits>, EvalError>;
+}
+
+/// Evaluator that does no script execution and simply applies the ExUnits given to it.
+/// Useful when the budgets are already known (e.g. from a previous evaluation) or for tests.
+#[derive(Clone, Debug, Default)]
+pub struct FixedExUnitsEvaluator {
+    ex_units: BTreeMap<RedeemerKey, ExUnits>,
+}
+
+impl FixedExUnitsEvaluator {
+    pub fn new(ex_units: BTreeMap<RedeemerKey, ExUnits>) -> Self {
+        Self { ex_units }
+    }
+
+    pub fn set(&mut self, tag: RedeemerTag, index: u64, ex_units: ExUnits) {
+        self.ex_units.insert(RedeemerKey::new(tag, index), ex_units);
+    }
+}
+
+impl TxEvaluator for FixedExUnitsEvaluator {
+    fn evaluate(
+        &self,
+        tx: &Transaction,
+        _resolved_inputs: &[TransactionUnspentOutput],
+    ) -> Result<BTreeMap<RedeemerKey, ExUnits>, EvalError> {
+        let redeemers = tx
+            .witness_set
+            .redeemers
+            .clone()
+            .map(|redeemers| redeemers.to_flat_format())
+            .unwrap_or_default();
+        redeemers
+            .into_iter()
+            .map(|redeemer| {
+                let key = RedeemerKey::new(redeemer.tag, redeemer.index);
+                self.ex_units
+                    .get(&key)
+                    .map(|ex_units| (key, ex_units.clone()))
+                    .ok_or(EvalError::MissingExUnits(redeemer.tag, redeemer.index))
+            })
+            .collect()
+    }
+}
@@ -122,7 +122,7 @@ impl SingleInputBuilder {
         required_signers: RequiredSigners,
         datum: PlutusData,
     ) -> Result<InputBuilderResult, InputBuilderError> {
-        self.plutus_script_inner(partial_witness, required_signers, Some(datum))
+        self.plutus_script_inner(partial_witness, required_signers, Some(datum), false)
     }
 
     pub fn plutus_script_inline_datum(
@@ -130,7 +130,18 @@ impl SingleInputBuilder {
         partial_witness: PartialPlutusWitness,
         required_signers: RequiredSigners,
     ) -> Result<InputBuilderResult, InputBuilderError> {
-        self.plutus_script_inner(partial_witness, required_signers, None)
+        self.plutus_script_inner(partial_witness, required_signers, None, false)
+    }
+
+    /// For script inputs locked by a datum hash whose datum is the inline datum of a reference input.
+    /// The datum is resolved when the result is added to a TransactionBuilder, so the reference input
+    /// must be added to it first via add_reference_input().
+    pub fn plutus_script_reference_datum(
+        self,
+        partial_witness: PartialPlutusWitness,
+        required_signers: RequiredSigners,
+    ) -> Result<InputBuilderResult, InputBuilderError> {
+        self.plutus_script_inner(partial_witness, required_signers, None, true)
     }
 
     fn plutus_script_inner(
@@ -138,6 +149,7 @@ impl SingleInputBuilder {
         partial_witness: PartialPlutusWitness,
         required_signers: RequiredSigners,
         datum: Option<PlutusData>,
+        datum_by_reference: bool,
     ) -> Result<InputBuilderResult, InputBuilderError> {
         let mut required_wits = required_wits_from_required_signers(&required_signers);
         input_required_wits(&self.utxo_info, &mut required_wits);
@@ -155,6 +167,10 @@ impl SingleInputBuilder {
                 .plutus_data
                 .remove(&hash_plutus_data(datum));
         }
+        if datum_by_reference {
+            // checked by the tx builder against its reference inputs
+            required_wits_left.plutus_data.clear();
+        }
 
         if required_wits_left.len() > 0 {
             return Err(InputBuilderError::MissingWitnesses(Box::new(
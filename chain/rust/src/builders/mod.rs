@@ -1,11 +1,16 @@
 pub mod certificate_builder;
+pub mod evaluator;
 pub mod input_builder;
 pub mod mint_builder;
 pub mod output_builder;
 pub mod proposal_builder;
 pub mod redeemer_builder;
+pub mod script_purpose;
 pub mod tx_builder;
-mod utils;
+pub mod utils;
+pub mod utxo_ledger;
 pub mod vote_builder;
 pub mod withdrawal_builder;
 pub mod witness_builder;
+
+pub use script_purpose::script_purposes;
@@ -1,6 +1,6 @@
 use crate::{
-    crypto::hash::hash_plutus_data, governance::ProposalProcedure, plutus::PlutusData,
-    transaction::NativeScript, RequiredSigners,
+    certs::utils::UrlError, crypto::hash::hash_plutus_data, governance::ProposalProcedure,
+    plutus::PlutusData, transaction::NativeScript, RequiredSigners,
 };
 
 use super::{
@@ -19,6 +19,8 @@ pub enum ProposalBuilderError {
     ProposalIsKeyHash,
     #[error("Missing the following witnesses for the input: {0:?}")]
     MissingWitnesses(Box<RequiredWitnessSet>),
+    #[error("Invalid anchor URL: {0}")]
+    InvalidAnchorUrl(#[from] UrlError),
 }
 
 #[derive(Clone, Debug, Default)]
@@ -31,6 +33,7 @@ pub struct ProposalBuilderResult {
 #[derive(Clone, Debug)]
 pub struct ProposalBuilder {
     result: ProposalBuilderResult,
+    strict_urls: bool,
 }
 
 impl Default for ProposalBuilder {
@@ -43,13 +46,29 @@ impl ProposalBuilder {
     pub fn new() -> Self {
         Self {
             result: ProposalBuilderResult::default(),
+            strict_urls: true,
         }
     }
 
+    /// By default proposals are checked with ProposalProcedure::validate_urls() when added.
+    /// This turns that check off e.g. to re-submit on-chain data that doesn't pass it.
+    pub fn skip_url_validation(mut self) -> Self {
+        self.strict_urls = false;
+        self
+    }
+
+    fn validate_urls(&self, proposal: &ProposalProcedure) -> Result<(), ProposalBuilderError> {
+        if self.strict_urls {
+            proposal.validate_urls()?;
+        }
+        Ok(())
+    }
+
     pub fn with_proposal(
         mut self,
         proposal: ProposalProcedure,
     ) -> Result<Self, ProposalBuilderError> {
+        self.validate_urls(&proposal)?;
         if proposal.gov_action.script_hash().is_some() {
             return Err(ProposalBuilderError::ProposalIsScript);
         }
@@ -65,6 +84,7 @@ impl ProposalBuilder {
         native_script: NativeScript,
         witness_info: NativeScriptWitnessInfo,
     ) -> Result<Self, ProposalBuilderError> {
+        self.validate_urls(&proposal)?;
         if let Some(script_hash) = proposal.gov_action.script_hash() {
             if *script_hash != native_script.hash() {
                 let mut err_req_wits = RequiredWitnessSet::new();
@@ -116,6 +136,7 @@ impl ProposalBuilder {
         required_signers: RequiredSigners,
         datum: Option<PlutusData>,
     ) -> Result<Self, ProposalBuilderError> {
+        self.validate_urls(&proposal)?;
         let mut required_wits = required_wits_from_required_signers(&required_signers);
         if let Some(script_hash) = proposal.gov_action.script_hash() {
             required_wits.add_script_hash(*script_hash);
@@ -1,7 +1,8 @@
 use super::{
     certificate_builder::CertificateBuilderResult, input_builder::InputBuilderResult,
     mint_builder::MintBuilderResult, proposal_builder::ProposalBuilderResult,
-    vote_builder::VoteBuilderResult, withdrawal_builder::WithdrawalBuilderResult,
+    script_purpose::LedgerOrderedVoter, vote_builder::VoteBuilderResult,
+    withdrawal_builder::WithdrawalBuilderResult, witness_builder::InputAggregateWitnessData,
 };
 use crate::{
     address::RewardAddress,
@@ -9,6 +10,7 @@ use crate::{
     transaction::TransactionInput,
     PolicyId,
 };
+use cml_crypto::ScriptHash;
 use std::{collections::BTreeMap, fmt::Debug};
 
 #[derive(Clone, Copy, PartialOrd, Ord, Debug, PartialEq, Eq, Hash)]
@@ -99,9 +101,12 @@ pub struct RedeemerSetBuilder {
     // list of certificates inside the transaction
     cert: Vec<Option<UntaggedRedeemerPlaceholder>>,
 
+    // proposals are indexed by their position in the (full, unfiltered) list of proposal procedures
     proposals: Vec<Option<UntaggedRedeemerPlaceholder>>,
 
-    votes: Vec<Option<UntaggedRedeemerPlaceholder>>,
+    // voters are indexed in the ledger's order on Voter (see script_purposes()) among all
+    // voters of the voting procedures, including the key hash ones
+    votes: BTreeMap<LedgerOrderedVoter, Option<UntaggedRedeemerPlaceholder>>,
 }
 
 impl RedeemerSetBuilder {
@@ -114,6 +119,43 @@ impl RedeemerSetBuilder {
             && self.mint.is_empty()
             && self.reward.is_empty()
             && self.cert.is_empty()
+            && self.proposals.is_empty()
+            && self.votes.is_empty()
+    }
+
+    /// Whether any script (as opposed to key-witnessed) item was added that needs a redeemer
+    pub(crate) fn has_redeemers(&self) -> bool {
+        self.spend
+            .values()
+            .chain(self.mint.values())
+            .chain(self.reward.values())
+            .chain(self.cert.iter())
+            .chain(self.proposals.iter())
+            .chain(self.votes.values())
+            .any(Option::is_some)
+    }
+
+    /// Keys of all redeemers that don't have their ExUnits set yet
+    pub fn missing_ex_units(&self) -> Vec<RedeemerWitnessKey> {
+        fn add_missing<'a>(
+            keys: &mut Vec<RedeemerWitnessKey>,
+            tag: RedeemerTag,
+            entries: impl Iterator<Item = &'a Option<UntaggedRedeemerPlaceholder>>,
+        ) {
+            for (index, entry) in entries.enumerate() {
+                if let Some(UntaggedRedeemerPlaceholder::JustData(_)) = entry {
+                    keys.push(RedeemerWitnessKey::new(tag, index as u64));
+                }
+            }
+        }
+        let mut keys = Vec::new();
+        add_missing(&mut keys, RedeemerTag::Spend, self.spend.values());
+        add_missing(&mut keys, RedeemerTag::Mint, self.mint.values());
+        add_missing(&mut keys, RedeemerTag::Reward, self.reward.values());
+        add_missing(&mut keys, RedeemerTag::Cert, self.cert.iter());
+        add_missing(&mut keys, RedeemerTag::Proposing, self.proposals.iter());
+        add_missing(&mut keys, RedeemerTag::Voting, self.votes.values());
+        keys
     }
 
     /// note: will override existing value if called twice with the same key
@@ -155,7 +197,7 @@ impl RedeemerSetBuilder {
                 )));
             }
             RedeemerTag::Voting => {
-                let entry = self.votes.get_mut(key.index as usize).unwrap();
+                let entry = self.votes.iter_mut().nth(key.index as usize).unwrap().1;
                 *entry = Some(UntaggedRedeemerPlaceholder::Full(UntaggedRedeemer::new(
                     entry.as_ref().unwrap().data().clone(),
                     ex_units,
@@ -231,24 +273,34 @@ impl RedeemerSetBuilder {
     }
 
     pub fn add_proposal(&mut self, result: &ProposalBuilderResult) {
-        for aggregate_witness in &result.aggregate_witnesses {
-            if let Some(data) = aggregate_witness.redeemer_plutus_data() {
-                self.proposals
-                    .push(Some(UntaggedRedeemerPlaceholder::JustData(data.clone())));
-            } else {
-                self.proposals.push(None);
-            }
+        // only script proposals have an aggregate witness and they were added in the same order
+        let mut aggregate_witnesses = result.aggregate_witnesses.iter();
+        for proposal in &result.proposals {
+            let redeemer_data = proposal
+                .gov_action
+                .script_hash()
+                .and_then(|_| aggregate_witnesses.next())
+                .and_then(|data| data.redeemer_plutus_data());
+            self.proposals.push(
+                redeemer_data.map(|data| UntaggedRedeemerPlaceholder::JustData(data.clone())),
+            );
         }
     }
 
     pub fn add_vote(&mut self, result: &VoteBuilderResult) {
-        for aggregate_witness in &result.aggregate_witnesses {
-            if let Some(data) = aggregate_witness.redeemer_plutus_data() {
-                self.votes
-                    .push(Some(UntaggedRedeemerPlaceholder::JustData(data.clone())));
-            } else {
-                self.votes.push(None);
-            }
+        for voter in result.votes.keys() {
+            // a script voter has one redeemer no matter how many actions it votes on
+            let redeemer_data = voter.script_hash().and_then(|script_hash| {
+                result
+                    .aggregate_witnesses
+                    .iter()
+                    .find(|data| aggregate_witness_script_hash(data) == *script_hash)
+                    .and_then(|data| data.redeemer_plutus_data())
+            });
+            self.votes.insert(
+                LedgerOrderedVoter(voter.clone()),
+                redeemer_data.map(|data| UntaggedRedeemerPlaceholder::JustData(data.clone())),
+            );
         }
     }
 
@@ -288,7 +340,7 @@ impl RedeemerSetBuilder {
         self.remove_placeholders_and_tag(
             &mut redeemers,
             RedeemerTag::Voting,
-            &mut self.votes.iter().map(|entry| (&(), entry)),
+            &mut self.votes.iter(),
             default_to_dummy_exunits,
         )?;
 
@@ -347,6 +399,13 @@ impl RedeemerSetBuilder {
     }
 }
 
+fn aggregate_witness_script_hash(data: &InputAggregateWitnessData) -> ScriptHash {
+    match data {
+        InputAggregateWitnessData::NativeScript(script, _) => script.hash(),
+        InputAggregateWitnessData::PlutusScript(witness, _, _) => witness.script.hash(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -416,11 +475,18 @@ mod tests {
 
         builder.add_spend(&input_result);
 
+        assert_eq!(
+            builder.missing_ex_units(),
+            vec![RedeemerWitnessKey::new(RedeemerTag::Spend, 0)]
+        );
+
         builder.update_ex_units(
             RedeemerWitnessKey::new(RedeemerTag::Spend, 0),
             ExUnits::new(10, 10),
         );
 
+        assert!(builder.missing_ex_units().is_empty());
+
         let redeemers = builder.build(false).unwrap().to_flat_format();
 
         assert_eq!(redeemers.len(), 1);
@@ -0,0 +1,399 @@
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet},
+};
+
+use crate::{
+    address::RewardAddress,
+    certs::{Certificate, StakeCredential},
+    governance::{ProposalProcedure, Voter},
+    plutus::{RedeemerKey, RedeemerTag},
+    transaction::{TransactionBody, TransactionInput},
+    PolicyId,
+};
+use cml_crypto::{RawBytesEncoding, ScriptHash};
+
+use super::{
+    certificate_builder::cert_required_wits, tx_builder::TransactionUnspentOutput,
+    witness_builder::RequiredWitnessSet,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ScriptPurposeError {
+    #[error("Input {0:?} was not found in the resolved inputs")]
+    MissingInput(TransactionInput),
+}
+
+/// The item of a transaction that a script is run for
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug)]
+pub enum ScriptPurposeItem {
+    Spending(TransactionInput),
+    Minting(PolicyId),
+    Certifying(Certificate),
+    Rewarding(RewardAddress),
+    Voting(Voter),
+    Proposing(ProposalProcedure),
+}
+
+/// A script that must be run for a transaction along with the tag and index the ledger
+/// expects for its redeemer
+#[derive(Clone, Debug)]
+pub struct ScriptPurpose {
+    pub redeemer_key: RedeemerKey,
+    pub item: ScriptPurposeItem,
+    pub script_hash: ScriptHash,
+}
+
+impl ScriptPurpose {
+    fn new(
+        tag: RedeemerTag,
+        index: usize,
+        item: ScriptPurposeItem,
+        script_hash: ScriptHash,
+    ) -> Self {
+        Self {
+            redeemer_key: RedeemerKey::new(tag, index as u64),
+            item,
+            script_hash,
+        }
+    }
+}
+
+/// All script purposes of {tx_body} in the order the ledger indexes redeemers:
+/// * Spend: by (transaction_id, index) of all inputs
+/// * Mint: by policy id
+/// * Cert: by position among all certificates
+/// * Reward: by reward account (network then credential, scripts first)
+/// * Voting: by voter (committee, DRep then pool, scripts first within each)
+/// * Proposing: by position among all proposals
+///
+/// Indices count every item of their kind, including key-witnessed ones, so they can be
+/// compared directly against the redeemers of a transaction.
+/// {resolved_inputs} must contain the UTXOs of all of the body's (spent) inputs.
+pub fn script_purposes(
+    tx_body: &TransactionBody,
+    resolved_inputs: &[TransactionUnspentOutput],
+) -> Result<Vec<ScriptPurpose>, ScriptPurposeError> {
+    let mut purposes = Vec::new();
+
+    let resolved = resolved_inputs
+        .iter()
+        .map(|utxo| (&utxo.input, &utxo.output))
+        .collect::<BTreeMap<_, _>>();
+    let inputs = tx_body.inputs.iter().collect::<BTreeSet<_>>();
+    for (index, input) in inputs.into_iter().enumerate() {
+        let output = resolved
+            .get(input)
+            .ok_or_else(|| ScriptPurposeError::MissingInput(input.clone()))?;
+        if let Some(StakeCredential::Script { hash, .. }) = output.address().payment_cred() {
+            purposes.push(ScriptPurpose::new(
+                RedeemerTag::Spend,
+                index,
+                ScriptPurposeItem::Spending(input.clone()),
+                *hash,
+            ));
+        }
+    }
+
+    if let Some(mint) = &tx_body.mint {
+        let policies = mint.keys().collect::<BTreeSet<_>>();
+        for (index, policy_id) in policies.into_iter().enumerate() {
+            purposes.push(ScriptPurpose::new(
+                RedeemerTag::Mint,
+                index,
+                ScriptPurposeItem::Minting(*policy_id),
+                *policy_id,
+            ));
+        }
+    }
+
+    if let Some(certs) = &tx_body.certs {
+        for (index, cert) in certs.iter().enumerate() {
+            let mut required_wits = RequiredWitnessSet::default();
+            cert_required_wits(cert, &mut required_wits);
+            if let Some(script_hash) = required_wits.scripts.iter().next() {
+                purposes.push(ScriptPurpose::new(
+                    RedeemerTag::Cert,
+                    index,
+                    ScriptPurposeItem::Certifying(cert.clone()),
+                    *script_hash,
+                ));
+            }
+        }
+    }
+
+    if let Some(withdrawals) = &tx_body.withdrawals {
+        let reward_accounts = withdrawals.keys().collect::<BTreeSet<_>>();
+        for (index, reward_account) in reward_accounts.into_iter().enumerate() {
+            if let StakeCredential::Script { hash, .. } = &reward_account.payment {
+                purposes.push(ScriptPurpose::new(
+                    RedeemerTag::Reward,
+                    index,
+                    ScriptPurposeItem::Rewarding(reward_account.clone()),
+                    *hash,
+                ));
+            }
+        }
+    }
+
+    if let Some(voting_procedures) = &tx_body.voting_procedures {
+        let voters = voting_procedures
+            .keys()
+            .cloned()
+            .map(LedgerOrderedVoter)
+            .collect::<BTreeSet<_>>();
+        for (index, LedgerOrderedVoter(voter)) in voters.into_iter().enumerate() {
+            if let Some(script_hash) = voter.script_hash() {
+                purposes.push(ScriptPurpose::new(
+                    RedeemerTag::Voting,
+                    index,
+                    ScriptPurposeItem::Voting(voter.clone()),
+                    *script_hash,
+                ));
+            }
+        }
+    }
+
+    if let Some(proposals) = &tx_body.proposal_procedures {
+        for (index, proposal) in proposals.iter().enumerate() {
+            if let Some(script_hash) = proposal.gov_action.script_hash() {
+                purposes.push(ScriptPurpose::new(
+                    RedeemerTag::Proposing,
+                    index,
+                    ScriptPurposeItem::Proposing(proposal.clone()),
+                    *script_hash,
+                ));
+            }
+        }
+    }
+
+    Ok(purposes)
+}
+
+/// Voter ordered the way the ledger indexes Voting redeemers.
+/// Also used by RedeemerSetBuilder so builder and script_purposes() can't disagree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct LedgerOrderedVoter(pub Voter);
+
+impl Ord for LedgerOrderedVoter {
+    fn cmp(&self, other: &Self) -> Ordering {
+        voter_ledger_order(&self.0).cmp(&voter_ledger_order(&other.0))
+    }
+}
+
+impl PartialOrd for LedgerOrderedVoter {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// the ledger's Voter is ordered committee < DRep < pool and its credentials put scripts
+// before key hashes, unlike the variant order of Voter here
+fn voter_ledger_order(voter: &Voter) -> (u8, u8, &[u8]) {
+    match voter {
+        Voter::ConstitutionalCommitteeHotScriptHash { script_hash, .. } => {
+            (0, 0, script_hash.to_raw_bytes())
+        }
+        Voter::ConstitutionalCommitteeHotKeyHash {
+            ed25519_key_hash, ..
+        } => (0, 1, ed25519_key_hash.to_raw_bytes()),
+        Voter::DRepScriptHash { script_hash, .. } => (1, 0, script_hash.to_raw_bytes()),
+        Voter::DRepKeyHash {
+            ed25519_key_hash, ..
+        } => (1, 1, ed25519_key_hash.to_raw_bytes()),
+        Voter::StakingPoolKeyHash {
+            ed25519_key_hash, ..
+        } => (2, 1, ed25519_key_hash.to_raw_bytes()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::address::EnterpriseAddress;
+    use crate::assets::{AssetName, Mint};
+    use crate::certs::Url;
+    use crate::governance::{Anchor, GovAction, GovActionId, Vote, VotingProcedure};
+    use crate::transaction::TransactionOutput;
+    use crate::{OrderedHashMap, ProtocolParamUpdate, Value};
+    use cml_crypto::{AnchorDocHash, Ed25519KeyHash, TransactionHash};
+
+    fn keys(purposes: &[ScriptPurpose]) -> Vec<(RedeemerTag, u64, ScriptHash)> {
+        purposes
+            .iter()
+            .map(|purpose| {
+                (
+                    purpose.redeemer_key.tag,
+                    purpose.redeemer_key.index,
+                    purpose.script_hash,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn ledger_purpose_order() {
+        let key_cred = StakeCredential::new_pub_key(Ed25519KeyHash::from([0x00; 28]));
+        let (script_a, script_b) = (ScriptHash::from([0xaa; 28]), ScriptHash::from([0xbb; 28]));
+        let script_cred = StakeCredential::new_script(script_b);
+        let input = |tx: u8, index| TransactionInput::new(TransactionHash::from([tx; 32]), index);
+        let utxo = |input: TransactionInput, cred: &StakeCredential| TransactionUnspentOutput {
+            input,
+            output: TransactionOutput::new(
+                EnterpriseAddress::new(0, cred.clone()).to_address(),
+                Value::from(1_000_000),
+                None,
+                None,
+            ),
+        };
+        let anchor = Anchor::new(
+            Url::new("https://example.com".to_owned()).unwrap(),
+            AnchorDocHash::from([5; 32]),
+        );
+
+        // inputs out of order: the key input sorts first
+        let mut body = TransactionBody::new(
+            vec![input(2, 0), input(1, 1), input(1, 0)].into(),
+            vec![],
+            0,
+        );
+        let resolved = [
+            utxo(input(1, 0), &key_cred),
+            utxo(input(1, 1), &StakeCredential::new_script(script_a)),
+            utxo(input(2, 0), &script_cred),
+        ];
+        // policies out of order
+        let mut mint = Mint::new();
+        mint.set(script_b, AssetName::new(b"b".to_vec()).unwrap(), 1);
+        mint.set(script_a, AssetName::new(b"a".to_vec()).unwrap(), -1);
+        body.mint = Some(mint);
+        // script stake registrations (without deposit) don't need a witness
+        body.certs = Some(
+            vec![
+                Certificate::new_stake_registration(script_cred.clone()),
+                Certificate::new_stake_delegation(key_cred.clone(), Ed25519KeyHash::from([1; 28])),
+                Certificate::new_unreg_cert(script_cred.clone(), 2_000_000),
+            ]
+            .into(),
+        );
+        // script credentials come before key hashes despite the key's smaller hash
+        let mut withdrawals = OrderedHashMap::new();
+        withdrawals.insert(RewardAddress::new(0, key_cred.clone()), 1);
+        withdrawals.insert(RewardAddress::new(0, script_cred.clone()), 1);
+        body.withdrawals = Some(withdrawals);
+        // committee < DRep (script < key) < pool
+        let mut votes = OrderedHashMap::new();
+        votes.insert(
+            GovActionId::new(TransactionHash::from([3; 32]), 0),
+            VotingProcedure::new(Vote::Yes, None),
+        );
+        let mut voting_procedures = OrderedHashMap::new();
+        for voter in [
+            Voter::new_d_rep_key_hash(Ed25519KeyHash::from([0x00; 28])),
+            Voter::new_constitutional_committee_hot_key_hash(Ed25519KeyHash::from([0xff; 28])),
+            Voter::new_d_rep_script_hash(script_a),
+        ] {
+            voting_procedures.insert(voter, votes.clone());
+        }
+        body.voting_procedures = Some(voting_procedures);
+        body.proposal_procedures = Some(
+            vec![
+                ProposalProcedure::new(
+                    1,
+                    RewardAddress::new(0, key_cred.clone()),
+                    GovAction::new_info_action(),
+                    anchor.clone(),
+                ),
+                ProposalProcedure::new(
+                    1,
+                    RewardAddress::new(0, key_cred),
+                    GovAction::new_parameter_change_action(
+                        None,
+                        ProtocolParamUpdate::new(),
+                        Some(script_b),
+                    ),
+                    anchor,
+                ),
+            ]
+            .into(),
+        );
+
+        let purposes = script_purposes(&body, &resolved).unwrap();
+        assert_eq!(
+            keys(&purposes),
+            vec![
+                (RedeemerTag::Spend, 1, script_a),
+                (RedeemerTag::Spend, 2, script_b),
+                (RedeemerTag::Mint, 0, script_a),
+                (RedeemerTag::Mint, 1, script_b),
+                (RedeemerTag::Cert, 2, script_b),
+                (RedeemerTag::Reward, 0, script_b),
+                (RedeemerTag::Voting, 1, script_a),
+                (RedeemerTag::Proposing, 1, script_b),
+            ]
+        );
+        assert!(matches!(
+            &purposes[0].item,
+            ScriptPurposeItem::Spending(spent) if *spent == input(1, 1)
+        ));
+        assert!(matches!(
+            &purposes[6].item,
+            ScriptPurposeItem::Voting(Voter::DRepScriptHash { .. })
+        ));
+
+        assert!(matches!(
+            script_purposes(&body, &resolved[1..]),
+            Err(ScriptPurposeError::MissingInput(missing)) if missing == input(1, 0)
+        ));
+    }
+
+    #[test]
+    fn mainnet_redeemer_indices() {
+        use crate::address::Address;
+        use crate::crypto::hash::tests::MAINNET_PLUTUS_V1_SPEND_TX;
+        use crate::transaction::Transaction;
+        use cml_core::serialization::Deserialize;
+
+        let tx = Transaction::from_cbor_bytes(&hex::decode(MAINNET_PLUTUS_V1_SPEND_TX).unwrap())
+            .unwrap();
+        // the spent UTXO was locked at the SpaceBudz market script
+        let resolved = tx
+            .body
+            .inputs
+            .iter()
+            .map(|input| TransactionUnspentOutput {
+                input: input.clone(),
+                output: TransactionOutput::new(
+                    Address::from_bech32(
+                        "addr1wx468s53gytznzs5dt6hmq2kk9vr7xplcpwq4fywa9d7cug7fd0ed",
+                    )
+                    .unwrap(),
+                    Value::from(70_000_000),
+                    None,
+                    None,
+                ),
+            })
+            .collect::<Vec<_>>();
+
+        let purposes = script_purposes(&tx.body, &resolved).unwrap();
+        let redeemer_keys = tx
+            .witness_set
+            .redeemers
+            .clone()
+            .unwrap()
+            .to_flat_format()
+            .iter()
+            .map(|redeemer| RedeemerKey::new(redeemer.tag, redeemer.index))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            purposes
+                .iter()
+                .map(|purpose| purpose.redeemer_key.clone())
+                .collect::<Vec<_>>(),
+            redeemer_keys
+        );
+        let scripts = tx.witness_set.all_scripts();
+        assert_eq!(purposes[0].script_hash, scripts[0].hash());
+    }
+}
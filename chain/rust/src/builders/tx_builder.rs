@@ -1,5 +1,6 @@
 use super::certificate_builder::*;
-use super::input_builder::InputBuilderResult;
+use super::evaluator::{EvalError, TxEvaluator};
+use super::input_builder::{InputBuilderError, InputBuilderResult};
 use super::mint_builder::MintBuilderResult;
 use super::output_builder::{OutputBuilderError, SingleOutputBuilderResult};
 use super::proposal_builder::ProposalBuilderResult;
@@ -12,37 +13,43 @@ use super::witness_builder::merge_fake_witness;
 use super::witness_builder::PlutusScriptWitness;
 use super::witness_builder::RequiredWitnessSet;
 use super::witness_builder::TransactionWitnessSetBuilder;
-use super::witness_builder::{InputAggregateWitnessData, WitnessBuilderError};
-use crate::address::Address;
+use super::witness_builder::{
+    check_native_script_signers, InputAggregateWitnessData, NativeScriptWitnessInfo,
+    WitnessBuilderError,
+};
+use crate::address::{Address, RewardAddress};
 use crate::assets::MultiAsset;
-use crate::assets::{AssetArithmeticError, Mint};
+use crate::assets::{AssetArithmeticError, AssetBundle, Mint};
 use crate::auxdata::AuxiliaryData;
 use crate::builders::output_builder::TransactionOutputBuilder;
 use crate::certs::{Certificate, Credential};
-use crate::crypto::hash::{calc_script_data_hash, hash_auxiliary_data, ScriptDataHashError};
+use crate::crypto::hash::{
+    calc_script_data_hash, hash_auxiliary_data, hash_plutus_data, ScriptDataHashError,
+};
 use crate::crypto::{BootstrapWitness, Vkeywitness};
 use crate::deposit::{internal_get_deposit, internal_get_implicit_input};
 use crate::fees::LinearFee;
+use crate::genesis::network_info::SlotConfig;
 use crate::governance::{ProposalProcedure, VotingProcedures};
 use crate::min_ada::min_ada_required;
 use crate::plutus::{CostModels, ExUnits, Language};
 use crate::plutus::{PlutusData, Redeemers};
 use crate::transaction::{
-    DatumOption, ScriptRef, Transaction, TransactionBody, TransactionInput, TransactionOutput,
-    TransactionWitnessSet,
+    DatumOption, NativeScript, ScriptRef, Transaction, TransactionBody, TransactionInput,
+    TransactionOutput, TransactionWitnessSet,
 };
 use crate::{assets::AssetName, Coin, ExUnitPrices, NetworkId, PolicyId, Value, Withdrawals};
 use cbor_event::{de::Deserializer, se::Serializer};
 use cml_core::ordered_hash_map::OrderedHashMap;
-use cml_core::serialization::{CBORReadLen, Deserialize};
+use cml_core::serialization::{fit_sz, sz_header_len, CBORReadLen, Deserialize, StripEncodings};
 use cml_core::{ArithmeticError, DeserializeError, DeserializeFailure, Slot};
 use cml_crypto::{Ed25519KeyHash, ScriptDataHash, ScriptHash, Serialize};
 use num::Zero;
 use rand::Rng;
+use std::cell::Cell;
 use std::collections::{BTreeSet, HashMap};
 use std::convert::TryInto;
 use std::io::{BufRead, Seek, Write};
-use std::ops::DerefMut;
 
 #[cfg(not(feature = "used_from_wasm"))]
 use noop_proc_macro::wasm_bindgen;
@@ -54,7 +61,7 @@ use wasm_bindgen::prelude::wasm_bindgen;
  * This is not used on-chain anywhere but is useful for the builders
  * as well as interfacing with CIP30 (same name as there)
  */
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, StripEncodings)]
 pub struct TransactionUnspentOutput {
     pub input: TransactionInput,
     pub output: TransactionOutput,
@@ -107,12 +114,39 @@ impl Deserialize for TransactionUnspentOutput {
 struct WitnessBuilders {
     pub witness_set_builder: TransactionWitnessSetBuilder,
     pub fake_required_witnesses: RequiredWitnessSet,
+    // AssumeWorst native scripts. Their signer count depends on the validity interval so it's computed on build
+    pub assume_worst_native_scripts: Vec<NativeScript>,
+    // native scripts witnessed by NativeScriptWitnessInfo::SpecificSigners, checked on build
+    pub specific_signer_native_scripts: Vec<(NativeScript, Vec<Ed25519KeyHash>)>,
     pub redeemer_set_builder: RedeemerSetBuilder,
 }
 impl WitnessBuilders {
+    fn add_fake_witness_data(&mut self, data: &InputAggregateWitnessData) {
+        match data {
+            InputAggregateWitnessData::NativeScript(
+                script,
+                NativeScriptWitnessInfo::AssumeWorst,
+            ) => self.assume_worst_native_scripts.push(script.clone()),
+            InputAggregateWitnessData::NativeScript(
+                script,
+                NativeScriptWitnessInfo::SpecificSigners(signers),
+            ) => {
+                self.specific_signer_native_scripts
+                    .push((script.clone(), signers.clone()));
+                self.fake_required_witnesses
+                    .add_input_aggregate_fake_witness_data(data);
+            }
+            _ => self
+                .fake_required_witnesses
+                .add_input_aggregate_fake_witness_data(data),
+        }
+    }
+
     fn merge_data(
         &self,
         include_fake: bool,
+        validity_start: Option<Slot>,
+        ttl: Option<Slot>,
     ) -> Result<TransactionWitnessSetBuilder, WitnessBuilderError> {
         // add redeemers
         let redeemers = self.redeemer_set_builder.build(true)?;
@@ -123,7 +157,15 @@ impl WitnessBuilders {
             .for_each(|r| witness_set_clone.add_redeemer(r));
 
         if include_fake {
-            merge_fake_witness(&mut witness_set_clone, &self.fake_required_witnesses);
+            let mut fake_required_witnesses = self.fake_required_witnesses.clone();
+            self.assume_worst_native_scripts.iter().for_each(|script| {
+                fake_required_witnesses.add_native_script_fake_witnesses_within(
+                    script,
+                    validity_start,
+                    ttl,
+                )
+            });
+            merge_fake_witness(&mut witness_set_clone, &fake_required_witnesses);
             let own_requirements = witness_set_clone.required_wits.clone();
             merge_fake_witness(&mut witness_set_clone, &own_requirements);
         }
@@ -131,17 +173,51 @@ impl WitnessBuilders {
         Ok(witness_set_clone)
     }
 
+    /// Checks the signers given via NativeScriptWitnessInfo::SpecificSigners can satisfy their scripts
+    // same (large) WitnessBuilderError as witness_builder::check_native_script_signers()
+    #[allow(clippy::result_large_err)]
+    fn check_native_script_signers(
+        &self,
+        validity_start: Option<Slot>,
+        ttl: Option<Slot>,
+    ) -> Result<(), WitnessBuilderError> {
+        self.specific_signer_native_scripts
+            .iter()
+            .try_for_each(|(script, signers)| {
+                check_native_script_signers(script, signers, validity_start, ttl)
+            })
+    }
+
     /// build with fake witnesses to estimate tx fee cost
-    pub fn build_fake(&self) -> Result<TransactionWitnessSet, WitnessBuilderError> {
-        self.merge_data(true).map(|wit_builder| wit_builder.build())
+    /// The validity interval is used to only count signers that can satisfy native scripts within it
+    pub fn build_fake(
+        &self,
+        validity_start: Option<Slot>,
+        ttl: Option<Slot>,
+    ) -> Result<TransactionWitnessSet, WitnessBuilderError> {
+        self.merge_data(true, validity_start, ttl)
+            .map(|wit_builder| wit_builder.build())
     }
 
     /// build without including fake witnesses used for fee estimation. Allows missing witnesses
     pub fn build_unchecked(&self) -> Result<TransactionWitnessSetBuilder, WitnessBuilderError> {
-        self.merge_data(false)
+        self.merge_data(false, None, None)
     }
 }
 
+/// How much current_size_estimate() can be over the size of the built tx: while no fee is set
+/// it's counted as the largest (9 byte) CBOR uint instead of the 1 byte of a zero fee
+pub const SIZE_ESTIMATE_MAX_DELTA: usize = 8;
+
+// size of the fake witness used for each required vkey: [vkey (2 + 32 bytes), signature (2 + 64 bytes)]
+const FAKE_VKEY_WITNESS_LEN: usize = 1 + 34 + 66;
+
+// how many bytes the canonical length header of a collection grows by when going from len to len + added
+fn len_header_growth(len: usize, added: usize) -> usize {
+    let header_len = |n: usize| sz_header_len(fit_sz(n as u64, None, true));
+    header_len(len + added) - header_len(len)
+}
+
 // tx_body must be the result of building from tx_builder
 // constructs the rest of the Transaction using fake witness data of the correct length
 // for use in calculating the size of the final Transaction
@@ -149,9 +225,12 @@ fn fake_full_tx(
     tx_builder: &TransactionBuilder,
     body: TransactionBody,
 ) -> Result<Transaction, TxBuilderError> {
+    let witness_set = tx_builder
+        .witness_builders
+        .build_fake(body.validity_interval_start, body.ttl)?;
     Ok(Transaction::new(
         body,
-        tx_builder.witness_builders.build_fake()?,
+        witness_set,
         true,
         tx_builder.auxiliary_data.clone(),
     ))
@@ -174,6 +253,8 @@ pub enum TxBuilderConfigField {
 pub enum TxBuilderError {
     #[error("Witness build failed: {0}")]
     WitnessBuildFailed(#[from] WitnessBuilderError),
+    #[error("Input build failed: {0}")]
+    InputBuildFailed(#[from] InputBuilderError),
     #[error("Redeem build failed: {0}")]
     RedeemerBuildFailed(#[from] RedeemerBuilderError),
     #[error("Output build failed: {0}")]
@@ -216,6 +297,47 @@ pub enum TxBuilderError {
     ScriptDatumHashFailed(#[from] ScriptDataHashError),
     #[error("Duplicate Mint Asset: {0:?}:{1:?}")]
     DuplicateMint(PolicyId, AssetName),
+    #[error("Mint Asset has a 0 amount: {0:?}:{1:?}")]
+    ZeroMint(PolicyId, AssetName),
+    #[error("Script evaluation failed: {0}")]
+    EvaluationFailed(#[from] EvalError),
+    #[error("Input {0:?} can't be both spent and referenced")]
+    SpentAndReferenced(TransactionInput),
+    #[error("max_tx_ex_units must be set in the config to use {0:?} provisional ExUnits")]
+    MaxTxExUnitsNotSet(ProvisionalExUnitsStrategy),
+    #[error("Collateral input {0:?} contains native assets which the PureAdaOnly collateral policy doesn't allow")]
+    CollateralHasTokens(TransactionInput),
+    #[error("Insufficient collateral. Required: {0}, Found: {1}")]
+    InsufficientCollateral(Coin, Coin),
+    #[error("Addresses not on the transaction's network {0}: {1:?}")]
+    NetworkIdMismatch(u64, Vec<NetworkIdMismatch>),
+    #[error("Address {0:?} has no payment key hash to require a signature from")]
+    RequiredSignerNotKeyHash(Box<Address>),
+    #[error("Validity window is empty (0 slots)")]
+    EmptyValidityWindow,
+    #[error("Validity window of {0} slots exceeds the max of {1} slots")]
+    ValidityWindowTooLong(u64, u64),
+}
+
+/// Part of a transaction whose address is on a different network than the body's network_id
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NetworkIdMismatch {
+    /// Output at this index
+    Output(usize, Address),
+    Withdrawal(RewardAddress),
+    CollateralReturn(Address),
+}
+
+/// How build_for_evaluation() fills in the ExUnits of redeemers which don't have any set yet.
+/// Without one of these the draft tx uses dummy (maximum-size) ExUnits.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProvisionalExUnitsStrategy {
+    /// Split the config's max_tx_ex_units evenly across the redeemers missing ExUnits
+    EvenSplit,
+    /// Give every redeemer missing ExUnits the config's full max_tx_ex_units
+    Max,
+    /// Give every redeemer missing ExUnits these ExUnits
+    Fixed(ExUnits),
 }
 
 fn min_fee(tx_builder: &TransactionBuilder) -> Result<Coin, TxBuilderError> {
@@ -280,19 +402,118 @@ pub enum CoinSelectionStrategyCIP2 {
     RandomImproveMultiAsset,
 }
 
+/// Signed per-asset difference between what a transaction needs and what it already has.
+/// Positive amounts still need to be covered by inputs and negative ones are already in excess
+/// (e.g. minted tokens that aren't sent anywhere yet) and would end up as change.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ValueDelta {
+    pub coin: i128,
+    pub multiasset: AssetBundle<i128>,
+}
+
+impl ValueDelta {
+    /// {required} - {available} per ADA/asset. Assets that cancel out are omitted.
+    pub fn new(required: &Value, available: &Value) -> Self {
+        let mut summed = AssetBundle::<i128>::new();
+        for (sign, value) in [(1i128, required), (-1i128, available)] {
+            for (policy_id, assets) in value.multiasset.iter() {
+                for (asset_name, amount) in assets.iter() {
+                    let current = summed.get(policy_id, asset_name).unwrap_or(0);
+                    summed.set(
+                        *policy_id,
+                        asset_name.clone(),
+                        current + sign * i128::from(*amount),
+                    );
+                }
+            }
+        }
+        let mut multiasset = AssetBundle::<i128>::new();
+        for (policy_id, assets) in summed.iter() {
+            for (asset_name, amount) in assets.iter() {
+                if *amount != 0 {
+                    multiasset.set(*policy_id, asset_name.clone(), *amount);
+                }
+            }
+        }
+        Self {
+            coin: i128::from(required.coin) - i128::from(available.coin),
+            multiasset,
+        }
+    }
+
+    /// The positive part i.e. what still needs to be selected
+    pub fn to_select(&self) -> Value {
+        self.part(1)
+    }
+
+    /// The negated negative part i.e. what is in excess of what is needed
+    pub fn surplus(&self) -> Value {
+        self.part(-1)
+    }
+
+    /// Whether nothing (more) needs to be selected
+    pub fn is_covered(&self) -> bool {
+        self.coin <= 0
+            && self
+                .multiasset
+                .values()
+                .all(|assets| assets.values().all(|amount| *amount <= 0))
+    }
+
+    fn part(&self, sign: i128) -> Value {
+        // both sides of the delta came from u64 amounts so each side's part fits in a u64
+        let clamp = |amount: i128| -> u64 { (sign * amount).max(0).try_into().unwrap_or(u64::MAX) };
+        let mut multiasset = MultiAsset::new();
+        for (policy_id, assets) in self.multiasset.iter() {
+            for (asset_name, amount) in assets.iter() {
+                let amount = clamp(*amount);
+                if amount > 0 {
+                    multiasset.set(*policy_id, asset_name.clone(), amount);
+                }
+            }
+        }
+        Value::new(clamp(self.coin), multiasset)
+    }
+}
+
+/// How collateral containing native assets is handled by add_collateral()/select_collateral()
+/// and whether the collateral return is calculated automatically.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CollateralPolicy {
+    /// Collateral inputs with native assets are rejected so no collateral return is ever needed
+    PureAdaOnly,
+    /// If no collateral return was set, one is added at build time returning all the native assets
+    /// and any ADA above the required collateral (collateral_percentage of the fee) to the
+    /// address of the first collateral input.
+    AutoReturn,
+    /// No implicit handling. Any collateral return must be set with set_collateral_return()
+    #[default]
+    Manual,
+}
+
 #[derive(Clone, Debug)]
 pub struct TransactionBuilderConfig {
     fee_algo: LinearFee,
-    pool_deposit: u64,            // protocol parameter
-    key_deposit: u64,             // protocol parameter
-    max_value_size: u32,          // protocol parameter
-    max_tx_size: u32,             // protocol parameter
-    coins_per_utxo_byte: Coin,    // protocol parameter
-    ex_unit_prices: ExUnitPrices, // protocol parameter
-    cost_models: CostModels,      // protocol parameter
-    _collateral_percentage: u32,  // protocol parameter
-    max_collateral_inputs: u32,   // protocol parameter
+    pool_deposit: u64,                // protocol parameter
+    key_deposit: u64,                 // protocol parameter
+    max_value_size: u32,              // protocol parameter
+    max_tx_size: u32,                 // protocol parameter
+    coins_per_utxo_byte: Coin,        // protocol parameter
+    ex_unit_prices: ExUnitPrices,     // protocol parameter
+    cost_models: CostModels,          // protocol parameter
+    collateral_percentage: u32,       // protocol parameter
+    max_collateral_inputs: u32,       // protocol parameter
+    max_tx_ex_units: Option<ExUnits>, // protocol parameter
     prefer_pure_change: bool,
+    collateral_policy: CollateralPolicy,
+    max_validity_window: Option<u64>,
+}
+
+impl TransactionBuilderConfig {
+    pub fn coins_per_utxo_byte(&self) -> Coin {
+        self.coins_per_utxo_byte
+    }
 }
 
 #[derive(Clone, Debug, Default)]
@@ -307,7 +528,10 @@ pub struct TransactionBuilderConfigBuilder {
     cost_models: Option<CostModels>,      // protocol parameter
     collateral_percentage: Option<u32>,   // protocol parameter
     max_collateral_inputs: Option<u32>,   // protocol parameter
+    max_tx_ex_units: Option<ExUnits>,     // protocol parameter
     prefer_pure_change: bool,
+    collateral_policy: CollateralPolicy,
+    max_validity_window: Option<u64>,
 }
 
 impl TransactionBuilderConfigBuilder {
@@ -351,6 +575,12 @@ impl TransactionBuilderConfigBuilder {
         self
     }
 
+    /// Optional. Defaults to CollateralPolicy::Manual
+    pub fn collateral_policy(mut self, collateral_policy: CollateralPolicy) -> Self {
+        self.collateral_policy = collateral_policy;
+        self
+    }
+
     pub fn ex_unit_prices(mut self, ex_unit_prices: ExUnitPrices) -> Self {
         self.ex_unit_prices = Some(ex_unit_prices);
         self
@@ -371,6 +601,19 @@ impl TransactionBuilderConfigBuilder {
         self
     }
 
+    /// Optional. Only needed for the EvenSplit/Max provisional ExUnits strategies.
+    pub fn max_tx_ex_units(mut self, max_tx_ex_units: ExUnits) -> Self {
+        self.max_tx_ex_units = Some(max_tx_ex_units);
+        self
+    }
+
+    /// Optional. Max length in slots of windows set by set_validity_window_relative() and
+    /// set_validity_end_in(). Unlimited by default.
+    pub fn max_validity_window(mut self, max_validity_window: u64) -> Self {
+        self.max_validity_window = Some(max_validity_window);
+        self
+    }
+
     pub fn build(self) -> Result<TransactionBuilderConfig, TxBuilderError> {
         Ok(TransactionBuilderConfig {
             fee_algo: self.fee_algo.ok_or(TxBuilderError::UninitializedField(
@@ -403,13 +646,16 @@ impl TransactionBuilderConfigBuilder {
             } else {
                 CostModels::default()
             },
-            _collateral_percentage: self.collateral_percentage.ok_or(
+            collateral_percentage: self.collateral_percentage.ok_or(
                 TxBuilderError::UninitializedField(TxBuilderConfigField::CollateralPercentage),
             )?,
             max_collateral_inputs: self.max_collateral_inputs.ok_or(
                 TxBuilderError::UninitializedField(TxBuilderConfigField::MaxCollateralInputs),
             )?,
+            max_tx_ex_units: self.max_tx_ex_units,
             prefer_pure_change: self.prefer_pure_change,
+            collateral_policy: self.collateral_policy,
+            max_validity_window: self.max_validity_window,
         })
     }
 }
@@ -435,6 +681,10 @@ pub struct TransactionBuilder {
     utxos: Vec<InputBuilderResult>,
     collateral_return: Option<TransactionOutput>,
     reference_inputs: Option<Vec<TransactionUnspentOutput>>,
+    provisional_ex_units: Option<ProvisionalExUnitsStrategy>,
+    // size of the full tx as of the last exact computation plus the inputs/outputs added since.
+    // None when something was changed that can't be accounted for incrementally.
+    size_estimate: Cell<Option<usize>>,
 }
 
 impl TransactionBuilder {
@@ -445,15 +695,38 @@ impl TransactionBuilder {
     /// Adding a change output must be called after via TransactionBuilder::add_change_if_needed()
     /// This function, diverging from CIP2, takes into account fees and will attempt to add additional
     /// inputs to cover the minimum fees. This does not, however, set the txbuilder's fee.
+    /// The target is the positive part of required_value_to_select() so e.g. tokens covered by
+    /// the mint or ADA covered by withdrawals or already added inputs aren't selected again.
     pub fn select_utxos(
         &mut self,
         strategy: CoinSelectionStrategyCIP2,
     ) -> Result<(), TxBuilderError> {
-        let available_inputs = self.utxos.clone();
-        let mut input_total = self.get_total_input()?;
-        let mut output_total = self
-            .get_total_output()?
-            .checked_add(&Value::from(self.min_fee(false)?))?;
+        let target = self.required_value_to_select()?.to_select();
+        self.select_utxos_for_target(strategy, &target)
+    }
+
+    /// Same as select_utxos() but selects inputs covering {target} (plus the fees of the added
+    /// inputs) instead of what the builder computes is still needed.
+    /// UTxOs that were added as reference inputs are skipped.
+    pub fn select_utxos_for_target(
+        &mut self,
+        strategy: CoinSelectionStrategyCIP2,
+        target: &Value,
+    ) -> Result<(), TxBuilderError> {
+        // a reference input can't also be spent so it's never a candidate
+        let reference_inputs = self.reference_inputs.as_deref().unwrap_or_default();
+        let available_inputs = self
+            .utxos
+            .iter()
+            .filter(|utxo| {
+                !reference_inputs
+                    .iter()
+                    .any(|ref_input| ref_input.input == utxo.input)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut input_total = Value::zero();
+        let mut output_total = target.clone();
         match strategy {
             CoinSelectionStrategyCIP2::LargestFirst => {
                 self.cip2_largest_first_by(
@@ -465,11 +738,7 @@ impl TransactionBuilder {
                 )?;
             }
             CoinSelectionStrategyCIP2::RandomImprove => {
-                if self
-                    .outputs
-                    .iter()
-                    .any(|output| output.amount().has_multiassets())
-                {
+                if output_total.has_multiassets() {
                     return Err(TxBuilderError::RandomImproveCantContainMultiasset);
                 }
                 let mut rng = rand::thread_rng();
@@ -653,6 +922,11 @@ impl TransactionBuilder {
             .cloned()
             .collect::<Vec<TransactionOutput>>();
         outputs.sort_by_key(|output| by(output.amount()).expect("filtered above"));
+        // {output_total} starts at the target so what is already covered (e.g. by mint, withdrawals
+        // or prior inputs) isn't associated. Amounts picked up by inputs selected for other assets
+        // in this selection are deliberately not subtracted: as in CIP-2 each asset is selected
+        // for separately, which leaves enough ADA for the change holding the leftover tokens.
+        let mut remaining = by(output_total).unwrap_or_else(u64::zero);
         for output in outputs.iter().rev() {
             // TODO: how should we adapt this to inputs being associated when running for other assets?
             // if we do these two phases for each asset and don't take into account the other runs for other assets
@@ -670,7 +944,8 @@ impl TransactionBuilder {
             // It might make sense to diverge further and not consider it per-output and to instead just match against
             // the sum of all outputs as one single value.
             let mut added = u64::zero();
-            let needed = by(output.amount()).unwrap();
+            let needed = by(output.amount()).unwrap().min(remaining);
+            remaining -= needed;
             while added < needed {
                 if relevant_indices.is_empty() {
                     return Err(TxBuilderError::UTxOBalanceInsufficient(
@@ -694,6 +969,7 @@ impl TransactionBuilder {
                     .push(i);
             }
         }
+        outputs.retain(|output| associated_indices.contains_key(output));
         if !relevant_indices.is_empty() {
             // Phase 2: Improvement
             for output in outputs.iter_mut() {
@@ -740,8 +1016,43 @@ impl TransactionBuilder {
 
     pub fn add_input(&mut self, mut result: InputBuilderResult) -> Result<(), TxBuilderError> {
         if let Some(reference_inputs) = &self.reference_inputs {
+            if reference_inputs
+                .iter()
+                .any(|ref_input| ref_input.input == result.input)
+            {
+                return Err(TxBuilderError::SpentAndReferenced(result.input));
+            }
             result.required_wits.remove_ref_scripts(reference_inputs);
+            result.required_wits.remove_ref_datums(reference_inputs);
+            if let Some(InputAggregateWitnessData::PlutusScript(_, _, datum)) =
+                &mut result.aggregate_witness
+            {
+                // already available to the script via the reference input
+                if datum.as_ref().is_some_and(|datum| {
+                    self.reference_datums()
+                        .iter()
+                        .any(|ref_datum| hash_plutus_data(ref_datum) == hash_plutus_data(datum))
+                }) {
+                    *datum = None;
+                }
+            }
+        }
+        if let Some(InputAggregateWitnessData::PlutusScript(_, _, None)) = &result.aggregate_witness
+        {
+            // datum hashes not resolved by a reference input would otherwise only fail on-chain
+            if result
+                .utxo_info
+                .datum_hash()
+                .is_some_and(|hash| result.required_wits.plutus_data.contains(hash))
+            {
+                let missing = RequiredWitnessSet {
+                    plutus_data: result.required_wits.plutus_data.clone(),
+                    ..Default::default()
+                };
+                return Err(WitnessBuilderError::MissingWitnesses(missing).into());
+            }
         }
+        let size_delta = self.input_size_delta(&result);
         if let Some(script_ref) = result.utxo_info.script_ref() {
             self.witness_builders
                 .witness_set_builder
@@ -763,9 +1074,7 @@ impl TransactionBuilder {
             self.witness_builders
                 .witness_set_builder
                 .add_input_aggregate_real_witness_data(&data);
-            self.witness_builders
-                .fake_required_witnesses
-                .add_input_aggregate_fake_witness_data(&data);
+            self.witness_builders.add_fake_witness_data(&data);
             if let InputAggregateWitnessData::PlutusScript(script_witness, required_signers, _) =
                 data
             {
@@ -810,6 +1119,7 @@ impl TransactionBuilder {
                 .unwrap();
             }
         }
+        self.apply_size_delta(size_delta);
         Ok(())
     }
 
@@ -819,6 +1129,15 @@ impl TransactionBuilder {
 
     /// calculates how much the fee would increase if you added a given output
     pub fn fee_for_input(&self, result: &InputBuilderResult) -> Result<Coin, TxBuilderError> {
+        // key-witnessed inputs only add a known amount of bytes so there's no need to build the tx twice
+        if let Some(size_delta) = self.input_size_delta(result) {
+            let size = self.current_size_estimate()?;
+            if size + size_delta <= self.config.max_tx_size as usize {
+                return (size_delta as u64)
+                    .checked_mul(self.config.fee_algo.coefficient)
+                    .ok_or_else(|| ArithmeticError::IntegerOverflow.into());
+            }
+        }
         let mut self_copy = self.clone();
 
         // we need some value for these for it to be a a valid transaction
@@ -828,7 +1147,7 @@ impl TransactionBuilder {
 
         let fee_before = min_fee(&self_copy)?;
 
-        self_copy.add_input(result.clone()).unwrap();
+        self_copy.add_input(result.clone())?;
         let fee_after = min_fee(&self_copy)?;
         fee_after
             .checked_sub(fee_before)
@@ -836,14 +1155,24 @@ impl TransactionBuilder {
     }
 
     /// Add a reference input. Must be called BEFORE adding anything (inputs, certs, etc) that refer to this reference input.
-    pub fn add_reference_input(&mut self, utxo: TransactionUnspentOutput) {
-        let reference_inputs = match self.reference_inputs.as_mut() {
-            None => {
-                self.reference_inputs = Some(Vec::<TransactionUnspentOutput>::new());
-                self.reference_inputs.as_mut().unwrap()
-            }
-            Some(inputs) => inputs,
-        };
+    /// The resolved output is kept so its reference script and inline datum are known to the builder.
+    /// Adding the same reference input twice has no effect.
+    /// Errors if the input is already being spent by this tx as the ledger doesn't allow both.
+    pub fn add_reference_input(
+        &mut self,
+        utxo: TransactionUnspentOutput,
+    ) -> Result<(), TxBuilderError> {
+        self.invalidate_size_estimate();
+        if self.inputs.iter().any(|input| input.input == utxo.input) {
+            return Err(TxBuilderError::SpentAndReferenced(utxo.input));
+        }
+        let reference_inputs = self.reference_inputs.get_or_insert_with(Vec::new);
+        if reference_inputs
+            .iter()
+            .any(|ref_input| ref_input.input == utxo.input)
+        {
+            return Ok(());
+        }
 
         if let Some(script_ref) = utxo.output.script_ref() {
             self.witness_builders
@@ -854,6 +1183,52 @@ impl TransactionBuilder {
         }
 
         reference_inputs.push(utxo);
+        Ok(())
+    }
+
+    pub fn get_reference_inputs(&self) -> Vec<TransactionUnspentOutput> {
+        self.reference_inputs.clone().unwrap_or_default()
+    }
+
+    /// Scripts available to this tx via its reference inputs
+    pub fn reference_scripts(&self) -> Vec<ScriptRef> {
+        self.reference_inputs
+            .iter()
+            .flatten()
+            .filter_map(|utxo| utxo.output.script_ref().cloned())
+            .collect()
+    }
+
+    /// Inline datums available to this tx via its reference inputs
+    pub fn reference_datums(&self) -> Vec<PlutusData> {
+        self.reference_inputs
+            .iter()
+            .flatten()
+            .filter_map(|utxo| match utxo.output.datum() {
+                Some(DatumOption::Datum { datum, .. }) => Some(datum),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Reference inputs whose inline datum is the datum of an input spent by this tx.
+    /// These datums are resolved by the ledger from the reference input so they are left out of
+    /// the witness set.
+    pub fn referenced_datums(&self) -> Vec<TransactionUnspentOutput> {
+        self.reference_inputs
+            .iter()
+            .flatten()
+            .filter(|utxo| match utxo.output.datum() {
+                Some(DatumOption::Datum { datum, .. }) => {
+                    let hash = hash_plutus_data(&datum);
+                    self.inputs
+                        .iter()
+                        .any(|input| input.output.datum_hash() == Some(&hash))
+                }
+                _ => false,
+            })
+            .cloned()
+            .collect()
     }
 
     /// Add explicit output via a TransactionOutput object
@@ -876,10 +1251,16 @@ impl TransactionBuilder {
                 min_ada,
             ))
         } else {
-            if let Some(datum) = builder_result.communication_datum {
-                self.witness_builders
-                    .witness_set_builder
-                    .add_plutus_datum(datum);
+            match builder_result.communication_datum {
+                Some(datum) => {
+                    self.invalidate_size_estimate();
+                    self.witness_builders
+                        .witness_set_builder
+                        .add_plutus_datum(datum);
+                }
+                None => self.apply_size_delta(Some(
+                    output.cbor_len() + len_header_growth(self.outputs.len(), 1),
+                )),
             }
             self.outputs.push(output);
             Ok(())
@@ -908,18 +1289,58 @@ impl TransactionBuilder {
     }
 
     pub fn set_fee(&mut self, fee: Coin) {
+        self.invalidate_size_estimate();
         self.fee = Some(fee)
     }
 
     pub fn set_ttl(&mut self, ttl: Slot) {
+        self.invalidate_size_estimate();
         self.ttl = Some(ttl)
     }
 
     pub fn set_validity_start_interval(&mut self, validity_start_interval: Slot) {
+        self.invalidate_size_estimate();
         self.validity_start_interval = Some(validity_start_interval)
     }
 
+    /// Sets validity_start_interval to {current_slot} and ttl to {current_slot} + {length_slots}.
+    /// As per the ledger the start is inclusive and the ttl exclusive so the tx is valid in
+    /// exactly {length_slots} slots: current_slot..=current_slot + length_slots - 1.
+    /// Errors if the window is empty or longer than the config's max_validity_window.
+    pub fn set_validity_window_relative(
+        &mut self,
+        current_slot: Slot,
+        length_slots: u64,
+    ) -> Result<(), TxBuilderError> {
+        if length_slots == 0 {
+            return Err(TxBuilderError::EmptyValidityWindow);
+        }
+        if let Some(max) = self.config.max_validity_window {
+            if length_slots > max {
+                return Err(TxBuilderError::ValidityWindowTooLong(length_slots, max));
+            }
+        }
+        let ttl = current_slot
+            .checked_add(length_slots)
+            .ok_or(ArithmeticError::IntegerOverflow)?;
+        self.set_validity_start_interval(current_slot);
+        self.set_ttl(ttl);
+        Ok(())
+    }
+
+    /// Same as set_validity_window_relative() with the window being the whole slots that fit in
+    /// {seconds} as per {slot_config}. Errors if that's less than a slot.
+    pub fn set_validity_end_in(
+        &mut self,
+        seconds: u64,
+        slot_config: &SlotConfig,
+        current_slot: Slot,
+    ) -> Result<(), TxBuilderError> {
+        self.set_validity_window_relative(current_slot, slot_config.slots_in(seconds))
+    }
+
     pub fn add_cert(&mut self, mut result: CertificateBuilderResult) {
+        self.invalidate_size_estimate();
         if let Some(reference_inputs) = &self.reference_inputs {
             result.required_wits.remove_ref_scripts(reference_inputs);
         }
@@ -932,9 +1353,7 @@ impl TransactionBuilder {
             self.witness_builders
                 .witness_set_builder
                 .add_input_aggregate_real_witness_data(&data);
-            self.witness_builders
-                .fake_required_witnesses
-                .add_input_aggregate_fake_witness_data(&data);
+            self.witness_builders.add_fake_witness_data(&data);
             if let InputAggregateWitnessData::PlutusScript(_, required_signers, _) = data {
                 required_signers
                     .iter()
@@ -947,6 +1366,7 @@ impl TransactionBuilder {
     }
 
     pub fn add_proposal(&mut self, mut result: ProposalBuilderResult) {
+        self.invalidate_size_estimate();
         if let Some(reference_inputs) = &self.reference_inputs {
             result.required_wits.remove_ref_scripts(reference_inputs);
         }
@@ -964,9 +1384,7 @@ impl TransactionBuilder {
             self.witness_builders
                 .witness_set_builder
                 .add_input_aggregate_real_witness_data(&data);
-            self.witness_builders
-                .fake_required_witnesses
-                .add_input_aggregate_fake_witness_data(&data);
+            self.witness_builders.add_fake_witness_data(&data);
             if let InputAggregateWitnessData::PlutusScript(_, required_signers, _) = data {
                 required_signers
                     .iter()
@@ -979,6 +1397,7 @@ impl TransactionBuilder {
     }
 
     pub fn add_vote(&mut self, mut result: VoteBuilderResult) {
+        self.invalidate_size_estimate();
         if let Some(reference_inputs) = &self.reference_inputs {
             result.required_wits.remove_ref_scripts(reference_inputs);
         }
@@ -992,9 +1411,7 @@ impl TransactionBuilder {
             self.witness_builders
                 .witness_set_builder
                 .add_input_aggregate_real_witness_data(&data);
-            self.witness_builders
-                .fake_required_witnesses
-                .add_input_aggregate_fake_witness_data(&data);
+            self.witness_builders.add_fake_witness_data(&data);
             if let InputAggregateWitnessData::PlutusScript(_, required_signers, _) = data {
                 required_signers
                     .iter()
@@ -1011,6 +1428,7 @@ impl TransactionBuilder {
     }
 
     pub fn add_withdrawal(&mut self, mut result: WithdrawalBuilderResult) {
+        self.invalidate_size_estimate();
         if let Some(reference_inputs) = &self.reference_inputs {
             result.required_wits.remove_ref_scripts(reference_inputs);
         }
@@ -1028,9 +1446,7 @@ impl TransactionBuilder {
             self.witness_builders
                 .witness_set_builder
                 .add_input_aggregate_real_witness_data(&data);
-            self.witness_builders
-                .fake_required_witnesses
-                .add_input_aggregate_fake_witness_data(&data);
+            self.witness_builders.add_fake_witness_data(&data);
             if let InputAggregateWitnessData::PlutusScript(_, required_signers, _) = data {
                 required_signers
                     .iter()
@@ -1046,11 +1462,15 @@ impl TransactionBuilder {
         self.auxiliary_data.clone()
     }
 
+    /// Sets the auxiliary data as-is, keeping its format (Shelley / ShelleyMA / Conway)
+    /// and encoding details so that the auxiliary_data_hash matches the original bytes.
     pub fn set_auxiliary_data(&mut self, new_aux_data: AuxiliaryData) {
+        self.invalidate_size_estimate();
         self.auxiliary_data = Some(new_aux_data)
     }
 
     pub fn add_auxiliary_data(&mut self, new_aux_data: AuxiliaryData) {
+        self.invalidate_size_estimate();
         match self.auxiliary_data.as_mut() {
             Some(data) => {
                 data.add(new_aux_data);
@@ -1062,6 +1482,14 @@ impl TransactionBuilder {
     }
 
     pub fn add_mint(&mut self, mut result: MintBuilderResult) -> Result<(), TxBuilderError> {
+        // nonZeroInt64 in the CDDL so the body would fail to serialize
+        if let Some((asset_name, _)) = result.assets.iter().find(|(_, amount)| **amount == 0) {
+            return Err(TxBuilderError::ZeroMint(
+                result.policy_id,
+                asset_name.clone(),
+            ));
+        }
+        self.invalidate_size_estimate();
         if let Some(reference_inputs) = &self.reference_inputs {
             result.required_wits.remove_ref_scripts(reference_inputs);
         }
@@ -1069,27 +1497,28 @@ impl TransactionBuilder {
         self.witness_builders
             .witness_set_builder
             .add_required_wits(result.required_wits.clone());
-        let mut mint = self.mint.take().unwrap_or_default();
-        let combined_assets = mint.deref_mut().entry(result.policy_id).or_default();
-        for (asset_name, asset_value) in result.assets.iter() {
-            if combined_assets
-                .insert(asset_name.clone(), *asset_value)
-                .is_some()
-            {
-                return Err(TxBuilderError::DuplicateMint(
-                    result.policy_id,
-                    asset_name.clone(),
-                ));
-            }
+        let combined_assets = self
+            .mint
+            .get_or_insert_with(Mint::default)
+            .entry(result.policy_id)
+            .or_default();
+        // check before inserting so a duplicate doesn't leave the mint partially updated
+        if let Some(asset_name) = result
+            .assets
+            .keys()
+            .find(|asset_name| combined_assets.contains_key(*asset_name))
+        {
+            return Err(TxBuilderError::DuplicateMint(
+                result.policy_id,
+                asset_name.clone(),
+            ));
         }
-        self.mint = Some(mint);
+        combined_assets.extend(result.assets.iter().map(|(k, v)| (k.clone(), *v)));
         if let Some(data) = result.aggregate_witness {
             self.witness_builders
                 .witness_set_builder
                 .add_input_aggregate_real_witness_data(&data);
-            self.witness_builders
-                .fake_required_witnesses
-                .add_input_aggregate_fake_witness_data(&data);
+            self.witness_builders.add_fake_witness_data(&data);
             if let InputAggregateWitnessData::PlutusScript(_, required_signers, _) = data {
                 required_signers
                     .iter()
@@ -1125,13 +1554,21 @@ impl TransactionBuilder {
             utxos: Vec::new(),
             collateral_return: None,
             reference_inputs: None,
+            provisional_ex_units: None,
+            size_estimate: Cell::new(None),
         }
     }
 
     pub fn add_collateral(&mut self, mut result: InputBuilderResult) -> Result<(), TxBuilderError> {
+        self.invalidate_size_estimate();
         if result.aggregate_witness.is_some() {
             return Err(TxBuilderError::CollateralMustBePayment);
         }
+        if self.config.collateral_policy == CollateralPolicy::PureAdaOnly
+            && result.utxo_info.amount().has_multiassets()
+        {
+            return Err(TxBuilderError::CollateralHasTokens(result.input));
+        }
         if let Some(reference_inputs) = &self.reference_inputs {
             result.required_wits.remove_ref_scripts(reference_inputs);
         }
@@ -1157,9 +1594,7 @@ impl TransactionBuilder {
             self.witness_builders
                 .witness_set_builder
                 .add_input_aggregate_real_witness_data(&data);
-            self.witness_builders
-                .fake_required_witnesses
-                .add_input_aggregate_fake_witness_data(&data);
+            self.witness_builders.add_fake_witness_data(&data);
             if let InputAggregateWitnessData::PlutusScript(_, required_signers, _) = data {
                 required_signers
                     .iter()
@@ -1174,6 +1609,7 @@ impl TransactionBuilder {
     }
 
     pub fn add_required_signer(&mut self, hash: Ed25519KeyHash) {
+        self.invalidate_size_estimate();
         let mut set = RequiredWitnessSet::new();
         set.add_vkey_key_hash(hash);
         self.witness_builders
@@ -1192,7 +1628,31 @@ impl TransactionBuilder {
         }
     }
 
+    /// Requires a signature from the payment key of this address (see Address::payment_cred()).
+    /// Errors for script and Byron addresses.
+    pub fn add_required_signers_from_address(
+        &mut self,
+        address: &Address,
+    ) -> Result<(), TxBuilderError> {
+        match address.payment_cred() {
+            Some(Credential::PubKey { hash, .. }) => {
+                self.add_required_signer(*hash);
+                Ok(())
+            }
+            _ => Err(TxBuilderError::RequiredSignerNotKeyHash(Box::new(
+                address.clone(),
+            ))),
+        }
+    }
+
+    /// The key hashes the tx body will list as required signers. Each is only included once
+    /// (and only needs one witness) no matter how often it was added.
+    pub fn required_signers(&self) -> Vec<Ed25519KeyHash> {
+        self.required_signers.iter().flatten().copied().collect()
+    }
+
     pub fn set_network_id(&mut self, network_id: NetworkId) {
+        self.invalidate_size_estimate();
         self.network_id = Some(network_id)
     }
 
@@ -1200,6 +1660,50 @@ impl TransactionBuilder {
         self.network_id
     }
 
+    /// Checks that the outputs, withdrawals and collateral return all use the network set
+    /// with set_network_id() (if any) as the ledger rejects the tx otherwise.
+    /// This is also checked when building the body.
+    pub fn validate_network_id(&self) -> Result<(), TxBuilderError> {
+        let network = match self.network_id {
+            Some(network_id) => network_id.network,
+            None => return Ok(()),
+        };
+        // byron addresses with an unknown protocol magic can't be checked
+        let wrong_network = |address: &Address| {
+            address
+                .network_id()
+                .is_ok_and(|address_network| u64::from(address_network) != network)
+        };
+        let mut mismatches = self
+            .outputs
+            .iter()
+            .enumerate()
+            .filter(|(_, output)| wrong_network(output.address()))
+            .map(|(i, output)| NetworkIdMismatch::Output(i, output.address().clone()))
+            .collect::<Vec<_>>();
+        if let Some(withdrawals) = &self.withdrawals {
+            mismatches.extend(
+                withdrawals
+                    .keys()
+                    .filter(|reward_address| u64::from(reward_address.network) != network)
+                    .cloned()
+                    .map(NetworkIdMismatch::Withdrawal),
+            );
+        }
+        if let Some(collateral_return) = &self.collateral_return {
+            if wrong_network(collateral_return.address()) {
+                mismatches.push(NetworkIdMismatch::CollateralReturn(
+                    collateral_return.address().clone(),
+                ));
+            }
+        }
+        if mismatches.is_empty() {
+            Ok(())
+        } else {
+            Err(TxBuilderError::NetworkIdMismatch(network, mismatches))
+        }
+    }
+
     /// does not include refunds or withdrawals
     pub fn get_explicit_input(&self) -> Result<Value, TxBuilderError> {
         self.inputs
@@ -1234,6 +1738,15 @@ impl TransactionBuilder {
             .unwrap_or((Value::zero(), Value::zero()))
     }
 
+    /// What the inputs selected from the added utxos still need to cover: outputs + fee (without
+    /// any further inputs) + deposits + burn - mint - withdrawals - refunds - already added inputs.
+    pub fn required_value_to_select(&self) -> Result<ValueDelta, TxBuilderError> {
+        let required = self
+            .get_total_output()?
+            .checked_add(&Value::from(self.min_fee(false)?))?;
+        Ok(ValueDelta::new(&required, &self.get_total_input()?))
+    }
+
     /// Return explicit input plus implicit input plus mint
     pub fn get_total_input(&self) -> Result<Value, TxBuilderError> {
         let (mint_value, _) = self.get_mint_as_values();
@@ -1277,9 +1790,124 @@ impl TransactionBuilder {
     }
 
     pub fn set_collateral_return(&mut self, output: TransactionOutput) {
+        self.invalidate_size_estimate();
         self.collateral_return = Some(output);
     }
 
+    /// Adds collateral from {candidates} (largest ADA amount first) until it covers {required}.
+    /// Script inputs are skipped as are inputs with native assets under CollateralPolicy::PureAdaOnly.
+    /// Under CollateralPolicy::AutoReturn pure ADA inputs are preferred to avoid returning assets.
+    pub fn select_collateral(
+        &mut self,
+        candidates: &[InputBuilderResult],
+        required: Coin,
+    ) -> Result<(), TxBuilderError> {
+        let policy = self.config.collateral_policy;
+        let mut candidates = candidates
+            .iter()
+            .filter(|candidate| {
+                candidate.aggregate_witness.is_none()
+                    && !(policy == CollateralPolicy::PureAdaOnly
+                        && candidate.utxo_info.amount().has_multiassets())
+            })
+            .collect::<Vec<_>>();
+        candidates.sort_by_key(|candidate| {
+            let amount = candidate.utxo_info.amount();
+            (
+                policy == CollateralPolicy::AutoReturn && amount.has_multiassets(),
+                std::cmp::Reverse(amount.coin),
+            )
+        });
+        let mut found = self.collateral_coin()?;
+        for candidate in candidates {
+            if found >= required {
+                break;
+            }
+            if self.collateral.iter().flatten().count()
+                >= self.config.max_collateral_inputs as usize
+            {
+                break;
+            }
+            self.add_collateral(candidate.clone())?;
+            found = found
+                .checked_add(candidate.utxo_info.amount().coin)
+                .ok_or(ArithmeticError::IntegerOverflow)?;
+        }
+        if found < required {
+            return Err(TxBuilderError::InsufficientCollateral(required, found));
+        }
+        Ok(())
+    }
+
+    fn collateral_coin(&self) -> Result<Coin, TxBuilderError> {
+        self.collateral
+            .iter()
+            .flatten()
+            .try_fold(Coin::zero(), |acc, next| {
+                acc.checked_add(next.output.amount().coin)
+                    .ok_or(ArithmeticError::IntegerOverflow)
+            })
+            .map_err(Into::into)
+    }
+
+    /// collateral_percentage of {fee} rounded up
+    fn required_collateral(&self, fee: Coin) -> Coin {
+        let required = (fee as u128 * self.config.collateral_percentage as u128).div_ceil(100);
+        required.try_into().unwrap_or(Coin::MAX)
+    }
+
+    /// The collateral return and total collateral fields of the body for the given {fee}
+    fn collateral_return_and_total(
+        &self,
+        fee: Coin,
+    ) -> Result<(Option<TransactionOutput>, Option<Coin>), TxBuilderError> {
+        let collateral = match self.collateral.as_ref() {
+            Some(collateral)
+                if self.collateral_return.is_none()
+                    && self.config.collateral_policy == CollateralPolicy::AutoReturn =>
+            {
+                collateral
+            }
+            _ => {
+                return Ok((
+                    self.collateral_return.clone(),
+                    self.calc_collateral_total()?,
+                ))
+            }
+        };
+        let input_sum = collateral.iter().try_fold(Value::zero(), |acc, next| {
+            acc.checked_add(next.output.amount())
+        })?;
+        let required = self.required_collateral(fee);
+        let (return_coin, total) = match input_sum.coin.checked_sub(required) {
+            Some(return_coin) => (return_coin, required),
+            // min_fee() uses the maximum fee so we use the largest possible return and total
+            // so the size (and thus the fee) is never underestimated
+            None if fee == u64::MAX => (input_sum.coin, input_sum.coin),
+            None => {
+                return Err(TxBuilderError::InsufficientCollateral(
+                    required,
+                    input_sum.coin,
+                ))
+            }
+        };
+        let collateral_return = TransactionOutput::new(
+            collateral[0].output.address().clone(),
+            Value::new(return_coin, input_sum.multiasset.clone()),
+            None,
+            None,
+        );
+        let min_ada = min_ada_required(&collateral_return, self.config.coins_per_utxo_byte)?;
+        if return_coin < min_ada {
+            if input_sum.has_multiassets() {
+                return Err(TxBuilderError::InsufficientADAForAssets);
+            }
+            // not worth returning so the whole collateral is at stake
+            return Ok((None, None));
+        }
+        Ok((Some(collateral_return), Some(total)))
+    }
+
     fn calc_collateral_total(&self) -> Result<Option<Coin>, TxBuilderError> {
         match self.collateral_return.as_ref() {
             None => Ok(None),
@@ -1302,6 +1930,14 @@ impl TransactionBuilder {
 
     fn build_and_size(&self) -> Result<(TransactionBody, usize), TxBuilderError> {
         let fee = self.fee.ok_or(TxBuilderError::FeeNotSpecified)?;
+        self.build_and_size_with_fee(fee)
+    }
+
+    fn build_and_size_with_fee(
+        &self,
+        fee: Coin,
+    ) -> Result<(TransactionBody, usize), TxBuilderError> {
+        let (collateral_return, total_collateral) = self.collateral_return_and_total(fee)?;
 
         let redeemers = self.witness_builders.redeemer_set_builder.build(true)?;
         let has_dummy_exunit = redeemers
@@ -1311,7 +1947,19 @@ impl TransactionBuilder {
             .any(|redeemer| redeemer.ex_units == ExUnits::dummy());
 
         let script_data_hash = match self.witness_builders.redeemer_set_builder.is_empty() {
-            true => None,
+            // datums without redeemers (e.g. from paying to a script with a communication datum)
+            // still need a script_data_hash but no language views as no scripts are executed
+            true => calc_script_data_hash(
+                &redeemers,
+                &self
+                    .witness_builders
+                    .witness_set_builder
+                    .get_plutus_datum()
+                    .into(),
+                &self.config.cost_models,
+                &[],
+                None,
+            )?,
             // dummy exunits use large values
             // to avoid users accidentally spending all their ADA in tx fees,
             // we make that dummy exunits set a dummy script_data_hash to ensure the tx fails if submitted to a node
@@ -1391,8 +2039,8 @@ impl TransactionBuilder {
                 .as_ref()
                 .map(|set| set.iter().cloned().collect::<Vec<_>>().into()),
             network_id: self.network_id,
-            collateral_return: self.collateral_return.clone(),
-            total_collateral: self.calc_collateral_total()?,
+            collateral_return,
+            total_collateral,
             reference_inputs: self.reference_inputs.as_ref().map(|inputs| {
                 inputs
                     .iter()
@@ -1447,6 +2095,69 @@ impl TransactionBuilder {
         self.build_and_size().map(|r| r.1)
     }
 
+    /// Size in bytes the transaction would have if built now, including the witnesses still needed.
+    /// Unlike full_size() this doesn't serialize the whole tx every time: the exact size is computed
+    /// once and then kept up to date as key-witnessed inputs and outputs are added. Anything else
+    /// (e.g. script inputs, certs, setting the fee) causes the next call to compute it exactly again.
+    /// This is never smaller than the built tx and at most SIZE_ESTIMATE_MAX_DELTA bytes larger.
+    /// It can be used without a fee set. build() still checks the exact size against max_tx_size.
+    pub fn current_size_estimate(&self) -> Result<usize, TxBuilderError> {
+        match self.size_estimate.get() {
+            Some(size) => Ok(size),
+            None => {
+                let fee = self.fee.unwrap_or(u64::MAX);
+                let (_, size) = self.build_and_size_with_fee(fee)?;
+                self.size_estimate.set(Some(size));
+                Ok(size)
+            }
+        }
+    }
+
+    fn invalidate_size_estimate(&mut self) {
+        self.size_estimate.set(None);
+    }
+
+    fn apply_size_delta(&mut self, size_delta: Option<usize>) {
+        let size_estimate = self.size_estimate.get_mut();
+        *size_estimate = size_estimate.and_then(|size| Some(size + size_delta?));
+    }
+
+    /// How many bytes adding this input would add to the tx, if it can be known without building it.
+    /// This is the case for inputs only needing a vkey witness when no other witnesses complicate things.
+    fn input_size_delta(&self, result: &InputBuilderResult) -> Option<usize> {
+        let required_wits = &result.required_wits;
+        let witness_set_builder = &self.witness_builders.witness_set_builder;
+        let required_vkeys = &witness_set_builder.required_wits.vkeys;
+        if result.aggregate_witness.is_some()
+            || result.utxo_info.script_ref().is_some()
+            || required_wits.len() != required_wits.vkeys.len()
+            || !required_wits.script_refs.is_empty()
+            // redeemer indices depend on the inputs
+            || self.witness_builders.redeemer_set_builder.has_redeemers()
+            // fake witnesses are only added for required vkeys without a real one, so any
+            // real or other fake ones would need to be compared against
+            || !witness_set_builder.vkeys.is_empty()
+            || !witness_set_builder.bootstraps.is_empty()
+            || self.witness_builders.fake_required_witnesses.len() != 0
+            || !self.witness_builders.assume_worst_native_scripts.is_empty()
+            // the vkey witness field would be added to the witness set
+            || required_vkeys.is_empty()
+        {
+            return None;
+        }
+        let new_vkeys = required_wits
+            .vkeys
+            .iter()
+            .filter(|vkey| !required_vkeys.contains(vkey))
+            .count();
+        Some(
+            result.input.cbor_len()
+                + len_header_growth(self.inputs.len(), 1)
+                + new_vkeys * FAKE_VKEY_WITNESS_LEN
+                + len_header_growth(required_vkeys.len(), new_vkeys),
+        )
+    }
+
     pub fn output_sizes(&self) -> Vec<usize> {
         self.outputs
             .iter()
@@ -1456,6 +2167,7 @@ impl TransactionBuilder {
 
     /// Returns object the body of the new transaction
     fn build_body(&self) -> Result<TransactionBody, TxBuilderError> {
+        self.validate_network_id()?;
         let (body, full_tx_size) = self.build_and_size()?;
         if full_tx_size > self.config.max_tx_size as usize {
             Err(TxBuilderError::MaxTxSizeExceeded(
@@ -1478,7 +2190,10 @@ impl TransactionBuilder {
     ) -> Result<TxRedeemerBuilder, TxBuilderError> {
         // First we finish change selection
 
+        self.witness_builders
+            .check_native_script_signers(self.validity_start_interval, self.ttl)?;
         let mut tx = self.clone();
+        tx.apply_provisional_ex_units()?;
         choose_change_selection_algo(algo)(&mut tx, change_address, false)?;
 
         Ok(TxRedeemerBuilder {
@@ -1496,6 +2211,8 @@ impl TransactionBuilder {
         algo: ChangeSelectionAlgo,
         change_address: &Address,
     ) -> Result<SignedTxBuilder, TxBuilderError> {
+        self.witness_builders
+            .check_native_script_signers(self.validity_start_interval, self.ttl)?;
         // First we finish change selection
         choose_change_selection_algo(algo)(self, change_address, true)?;
 
@@ -1508,15 +2225,73 @@ impl TransactionBuilder {
         })
     }
 
+    // TODO: switch from ChangeSelectionAlgo to ChangeSelectionBuilder
+    /// Builds the transaction using {evaluator} to compute the ExUnits of all redeemers.
+    /// This builds a draft tx for evaluation, sets the resulting ExUnits then does the final
+    /// build (including fee/change calculation) to move to the step where real witnesses can be added.
+    /// NOTE: is_valid set to true
+    pub fn build_with_evaluator(
+        &mut self,
+        evaluator: &dyn TxEvaluator,
+        algo: ChangeSelectionAlgo,
+        change_address: &Address,
+    ) -> Result<SignedTxBuilder, TxBuilderError> {
+        let draft_tx = self
+            .build_for_evaluation(algo, change_address)?
+            .draft_tx()?;
+        let resolved_inputs = self
+            .inputs
+            .iter()
+            .chain(self.collateral.iter().flatten())
+            .chain(self.reference_inputs.iter().flatten())
+            .cloned()
+            .collect::<Vec<_>>();
+        for (key, ex_units) in evaluator.evaluate(&draft_tx, &resolved_inputs)? {
+            self.set_exunits(RedeemerWitnessKey::new(key.tag, key.index), ex_units);
+        }
+        self.build(algo, change_address)
+    }
+
     /// used to override the exunit values initially provided when adding inputs
     pub fn set_exunits(&mut self, redeemer: RedeemerWitnessKey, ex_units: ExUnits) {
+        self.invalidate_size_estimate();
         self.witness_builders
             .redeemer_set_builder
             .update_ex_units(redeemer, ex_units);
     }
 
-    /// warning: sum of all parts of a transaction must equal 0. You cannot just set the fee to the min value and forget about it
-    /// warning: min_fee may be slightly larger than the actual minimum fee (ex: a few lovelaces)
+    /// Sets how build_for_evaluation() fills in redeemers that don't have ExUnits yet.
+    /// Useful for evaluators that reject the default dummy ExUnits.
+    pub fn set_provisional_ex_units(&mut self, strategy: ProvisionalExUnitsStrategy) {
+        self.invalidate_size_estimate();
+        self.provisional_ex_units = Some(strategy);
+    }
+
+    fn apply_provisional_ex_units(&mut self) -> Result<(), TxBuilderError> {
+        let strategy = match &self.provisional_ex_units {
+            Some(strategy) => strategy,
+            None => return Ok(()),
+        };
+        let missing = self
+            .witness_builders
+            .redeemer_set_builder
+            .missing_ex_units();
+        let ex_units = match (strategy, &self.config.max_tx_ex_units) {
+            (ProvisionalExUnitsStrategy::Fixed(ex_units), _) => {
+                vec![ex_units.clone(); missing.len()]
+            }
+            (ProvisionalExUnitsStrategy::EvenSplit, Some(max)) => max.split_evenly(missing.len()),
+            (ProvisionalExUnitsStrategy::Max, Some(max)) => vec![max.clone(); missing.len()],
+            (_, None) => return Err(TxBuilderError::MaxTxExUnitsNotSet(strategy.clone())),
+        };
+        for (key, ex_units) in missing.into_iter().zip(ex_units) {
+            self.set_exunits(key, ex_units);
+        }
+        Ok(())
+    }
+
+    /// warning: sum of all parts of a transaction must equal 0. You cannot just set the fee to the min value and forget about it
+    /// warning: min_fee may be slightly larger than the actual minimum fee (ex: a few lovelaces)
     /// this is done to simplify the library code, but can be fixed later
     pub fn min_fee(&self, script_calulation: bool) -> Result<Coin, TxBuilderError> {
         if !script_calulation {
@@ -1570,7 +2345,8 @@ impl TxRedeemerBuilder {
         Ok(Transaction::new(
             self.draft_body.clone(),
             // Side note: redeemer indices are calculated every time witness builder is built
-            self.witness_builders.build_fake()?,
+            self.witness_builders
+                .build_fake(self.draft_body.validity_interval_start, self.draft_body.ttl)?,
             true,
             self.auxiliary_data.clone(),
         ))
@@ -1617,9 +2393,12 @@ impl SignedTxBuilder {
      * Builds the final transaction and checks that all witnesses are there
      */
     pub fn build_checked(self) -> Result<Transaction, WitnessBuilderError> {
+        let witness_set = self
+            .witness_set
+            .try_build_within(self.body.validity_interval_start, self.body.ttl)?;
         Ok(Transaction::new(
             self.body,
-            self.witness_set.try_build()?,
+            witness_set,
             self.is_valid,
             self.auxiliary_data,
         ))
@@ -1675,9 +2454,18 @@ impl SignedTxBuilder {
     pub fn auxiliary_data(&self) -> Option<AuxiliaryData> {
         self.auxiliary_data.clone()
     }
+
+    /// Whether the body's auxiliary_data_hash matches the auxiliary data (or neither are present)
+    pub fn verify_auxiliary_data(&self) -> bool {
+        match &self.auxiliary_data {
+            Some(auxiliary_data) => self.body.verify_auxiliary_data(auxiliary_data),
+            None => self.body.auxiliary_data_hash.is_none(),
+        }
+    }
 }
 
 #[wasm_bindgen]
+#[derive(Clone, Copy, Debug)]
 pub enum ChangeSelectionAlgo {
     Default,
 }
@@ -1716,13 +2504,14 @@ pub fn add_change_if_needed(
     let output_total = builder.get_total_output()?;
 
     use std::cmp::Ordering;
-    match &input_total.partial_cmp(&output_total.checked_add(&Value::from(fee))?) {
+    match &input_total.compare(&output_total.checked_add(&Value::from(fee))?) {
         Some(Ordering::Equal) => {
             // recall: min_fee assumed the fee was the maximum possible so we definitely have enough input to cover whatever fee it ends up being
             builder.set_fee(input_total.checked_sub(&output_total)?.coin);
             Ok(false)
         }
-        Some(Ordering::Less) => Err(TxBuilderError::UTxOBalanceInsufficient(
+        // None: the inputs lack some token the outputs need even if they have more of others
+        Some(Ordering::Less) | None => Err(TxBuilderError::UTxOBalanceInsufficient(
             input_total.clone(),
             output_total.clone(),
         )),
@@ -1874,7 +2663,7 @@ pub fn add_change_if_needed(
                     builder.config.coins_per_utxo_byte,
                 )?;
                 while let Some(Ordering::Greater) =
-                    change_left.multiasset.partial_cmp(&MultiAsset::default())
+                    change_left.multiasset.compare(&MultiAsset::default())
                 {
                     let nft_changes = pack_nfts_for_change(
                         builder.config.max_value_size,
@@ -2028,7 +2817,6 @@ pub fn add_change_if_needed(
                 }
             }
         }
-        None => Err(TxBuilderError::MissingInputOrOutput),
     }
 }
 
@@ -2039,12 +2827,14 @@ mod tests {
 
     use cml_core::Int;
     use cml_crypto::{
-        Bip32PrivateKey, Bip32PublicKey, DatumHash, Deserialize, PrivateKey, RawBytesEncoding,
-        TransactionHash,
+        blake2b256, AuxiliaryDataHash, Bip32PrivateKey, Bip32PublicKey, DatumHash, Deserialize,
+        PrivateKey, RawBytesEncoding, TransactionHash,
     };
 
     use crate::address::{Address, BaseAddress, EnterpriseAddress, Pointer, PointerAddress};
+    use crate::auxdata::utils::AuxiliaryDataFormat;
     use crate::auxdata::{Metadata, MetadatumMap, TransactionMetadatum, TransactionMetadatumLabel};
+    use crate::builders::evaluator::FixedExUnitsEvaluator;
     use crate::builders::witness_builder::{PartialPlutusWitness, PlutusScriptWitness};
     use crate::builders::{
         input_builder::SingleInputBuilder, mint_builder::SingleMintBuilder,
@@ -2054,8 +2844,8 @@ mod tests {
     use crate::certs::StakeCredential;
     use crate::crypto::hash::hash_transaction;
     use crate::crypto::utils::make_vkey_witness;
-    use crate::genesis::network_info::{plutus_alonzo_cost_models, NetworkInfo};
-    use crate::plutus::{PlutusScript, PlutusV1Script, PlutusV2Script, RedeemerTag};
+    use crate::genesis::network_info::{plutus_alonzo_cost_models, NetworkInfo, SlotConfig};
+    use crate::plutus::{PlutusScript, PlutusV1Script, PlutusV2Script, RedeemerKey, RedeemerTag};
     use crate::transaction::NativeScript;
     use crate::{Script, SubCoin};
 
@@ -2801,6 +3591,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn build_tx_inputs_missing_output_token() {
+        let mut tx_builder = create_tx_builder_with_fee(create_linear_fee(0, 1));
+        let (_, _, addr_net_0) = create_account();
+        let mut multiasset = MultiAsset::new();
+        multiasset.set(
+            PolicyId::from([0u8; 28]),
+            AssetName::new(vec![0u8, 1, 2, 3]).unwrap(),
+            60,
+        );
+
+        // plenty of ADA but none of the token the output sends
+        tx_builder
+            .add_input(
+                SingleInputBuilder::new(
+                    TransactionInput::new(genesis_id(), 0),
+                    TransactionOutput::new(addr_net_0.clone(), Value::from(10_000_000), None, None),
+                )
+                .payment_key()
+                .unwrap(),
+            )
+            .unwrap();
+        tx_builder
+            .add_output(
+                TransactionOutputBuilder::new()
+                    .with_address(addr_net_0.clone())
+                    .next()
+                    .unwrap()
+                    .with_value(Value::new(2_000_000, multiasset))
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap();
+
+        assert!(matches!(
+            tx_builder.add_change_if_needed_for_tests(&addr_net_0),
+            Err(TxBuilderError::UTxOBalanceInsufficient(_, _))
+        ));
+    }
+
     #[test]
     fn build_tx_with_native_assets_change() {
         let mut tx_builder = create_tx_builder_with_fee(create_linear_fee(0, 1));
@@ -3570,6 +4400,45 @@ mod tests {
         assert_eq!(3u8, tx.inputs[1].transaction_id.to_raw_bytes()[0]);
     }
 
+    #[test]
+    fn tx_builder_cip2_skips_reference_inputs() {
+        for strategy in [
+            CoinSelectionStrategyCIP2::LargestFirst,
+            CoinSelectionStrategyCIP2::RandomImprove,
+        ] {
+            let mut tx_builder = create_tx_builder_with_fee(create_linear_fee(1, 0));
+            tx_builder
+                .add_output(
+                    TransactionOutputBuilder::new()
+                        .with_address(
+                            Address::from_bech32(
+                                "addr1vyy6nhfyks7wdu3dudslys37v252w2nwhv0fw2nfawemmnqs6l44z",
+                            )
+                            .unwrap(),
+                        )
+                        .next()
+                        .unwrap()
+                        .with_value(3000)
+                        .build()
+                        .unwrap(),
+                )
+                .unwrap();
+            // the largest UTxO is also referenced so it can't be spent
+            let referenced = make_input(0u8, Value::from(8000));
+            tx_builder
+                .add_reference_input(TransactionUnspentOutput::new(
+                    referenced.input.clone(),
+                    referenced.utxo_info.clone(),
+                ))
+                .unwrap();
+            tx_builder.add_utxo(referenced);
+            tx_builder.add_utxo(make_input(1u8, Value::from(4000)));
+            tx_builder.select_utxos(strategy).unwrap();
+            let inputs = tx_builder.get_explicit_input().unwrap();
+            assert_eq!(inputs.coin, 4000);
+        }
+    }
+
     #[test]
     fn tx_builder_cip2_largest_first_static_fees() {
         // we have a = 0 so we know adding inputs/outputs doesn't change the fee so we can analyze more
@@ -3825,6 +4694,55 @@ mod tests {
         assert!(input_total >= output_value);
     }
 
+    #[test]
+    fn tx_builder_select_utxos_mint_covers_output_tokens() {
+        let (min_script, policy_id) = mint_script_and_policy(0);
+        let name = AssetName::new(vec![0u8, 1, 2, 3]).unwrap();
+        let mut output_ma = MultiAsset::new();
+        output_ma.set(policy_id, name.clone(), 1234);
+        for strategy in [
+            CoinSelectionStrategyCIP2::RandomImprove,
+            CoinSelectionStrategyCIP2::RandomImproveMultiAsset,
+            CoinSelectionStrategyCIP2::LargestFirstMultiAsset,
+        ] {
+            let mut tx_builder = create_tx_builder_with_fee(create_linear_fee(0, 0));
+            tx_builder
+                .add_output(SingleOutputBuilderResult::new(TransactionOutput::new(
+                    Address::from_bech32(
+                        "addr1vyy6nhfyks7wdu3dudslys37v252w2nwhv0fw2nfawemmnqs6l44z",
+                    )
+                    .unwrap(),
+                    Value::new(2_000_000, output_ma.clone()),
+                    None,
+                    None,
+                )))
+                .unwrap();
+            tx_builder
+                .add_mint(
+                    SingleMintBuilder::new_single_asset(name.clone(), 1234).native_script(
+                        min_script.clone(),
+                        NativeScriptWitnessInfo::assume_signature_count(),
+                    ),
+                )
+                .unwrap();
+
+            // the minted tokens fully cover the output's so only ADA is left to select
+            let required = tx_builder.required_value_to_select().unwrap();
+            assert_eq!(required.coin, 2_000_000);
+            assert!(required.multiasset.is_empty());
+            assert_eq!(required.to_select(), Value::from(2_000_000));
+            assert!(required.surplus().is_zero());
+
+            tx_builder.add_utxo(make_input(0u8, Value::from(1_500_000)));
+            tx_builder.add_utxo(make_input(1u8, Value::from(1_000_000)));
+            tx_builder.select_utxos(strategy).unwrap();
+            assert_eq!(tx_builder.inputs.len(), 2);
+            let required = tx_builder.required_value_to_select().unwrap();
+            assert!(required.is_covered());
+            assert_eq!(required.surplus(), Value::from(500_000));
+        }
+    }
+
     #[test]
     #[flaky_test::flaky_test]
     fn tx_builder_cip2_random_improve() {
@@ -4470,6 +5388,38 @@ mod tests {
         assert_json_metadatum(met.get(num2).unwrap());
     }
 
+    #[test]
+    fn set_auxiliary_data_keeps_shelley_format() {
+        let mut tx_builder = create_default_tx_builder();
+        let (_, _, addr_net_0) = create_account();
+        let input = SingleInputBuilder::new(
+            TransactionInput::new(genesis_id(), 0),
+            TransactionOutput::new(addr_net_0.clone(), Value::from(1_000_000), None, None),
+        )
+        .payment_key()
+        .unwrap();
+        tx_builder.add_input(input).unwrap();
+
+        // shelley-format metadata {42: "a"} with a non-canonical key encoding as another tool could produce
+        let aux_bytes = hex::decode("a119002a6161").unwrap();
+        let aux_data = AuxiliaryData::from_cbor_bytes(&aux_bytes).unwrap();
+        assert_eq!(aux_data.format(), AuxiliaryDataFormat::Shelley);
+        tx_builder.set_auxiliary_data(aux_data);
+
+        let signed_tx_builder = tx_builder
+            .build(ChangeSelectionAlgo::Default, &addr_net_0)
+            .unwrap();
+        assert!(signed_tx_builder.verify_auxiliary_data());
+        let tx = signed_tx_builder.build_unchecked();
+        let tx_aux_data = tx.auxiliary_data.unwrap();
+        assert_eq!(tx_aux_data.format(), AuxiliaryDataFormat::Shelley);
+        assert_eq!(tx_aux_data.to_cbor_bytes(), aux_bytes);
+        assert_eq!(
+            tx.body.auxiliary_data_hash.unwrap(),
+            AuxiliaryDataHash::from(blake2b256(&aux_bytes))
+        );
+    }
+
     #[test]
     fn add_metadatum_with_empty_auxiliary() {
         let mut tx_builder = create_default_tx_builder();
@@ -4757,6 +5707,73 @@ mod tests {
         assert_mint_asset(&mint, &policy_id);
     }
 
+    #[test]
+    fn min_fee_with_wide_assume_worst_multisig() {
+        // a 10-of-20 has 184756 minimal signer sets which used to be listed on every min_fee()
+        let mut tx_builder = create_default_tx_builder();
+        let script = NativeScript::new_script_n_of_k(
+            10,
+            (0..20)
+                .map(|i| NativeScript::new_script_pubkey(fake_key_hash(i)))
+                .collect(),
+        );
+        let result = create_mint_asset_builder()
+            .native_script(script, NativeScriptWitnessInfo::assume_signature_count());
+        tx_builder.add_mint(result).unwrap();
+
+        let start = std::time::Instant::now();
+        tx_builder.min_fee(false).unwrap();
+        let witness_set = tx_builder
+            .witness_builders
+            .build_fake(None, Some(100))
+            .unwrap();
+        assert!(start.elapsed() < std::time::Duration::from_secs(5));
+        // any of the 20 keys could sign
+        assert_eq!(witness_set.vkeywitnesses.map(|wits| wits.len()), Some(20));
+    }
+
+    #[test]
+    fn add_mint_zero_amount_rejected() {
+        let mut tx_builder = create_default_tx_builder();
+
+        let (mint_script, policy_id) = mint_script_and_policy(0);
+        let zero = SingleMintBuilder::new_single_asset(create_asset_name(), 0).native_script(
+            mint_script,
+            NativeScriptWitnessInfo::assume_signature_count(),
+        );
+        assert!(matches!(
+            tx_builder.add_mint(zero),
+            Err(TxBuilderError::ZeroMint(policy, _)) if policy == policy_id
+        ));
+        assert!(tx_builder.mint.is_none());
+    }
+
+    #[test]
+    fn add_mint_duplicate_asset_keeps_mint() {
+        let mut tx_builder = create_default_tx_builder();
+
+        let (mint_script, policy_id) = mint_script_and_policy(0);
+
+        let result = create_mint_asset_builder().native_script(
+            mint_script.clone(),
+            NativeScriptWitnessInfo::assume_signature_count(),
+        );
+        tx_builder.add_mint(result).unwrap();
+
+        let duplicate = SingleMintBuilder::new_single_asset(create_asset_name(), 1).native_script(
+            mint_script,
+            NativeScriptWitnessInfo::assume_signature_count(),
+        );
+        assert!(matches!(
+            tx_builder.add_mint(duplicate),
+            Err(TxBuilderError::DuplicateMint(..))
+        ));
+
+        let mint = tx_builder.mint.unwrap();
+        assert_eq!(mint.len(), 1);
+        assert_mint_asset(&mint, &policy_id);
+    }
+
     #[test]
     fn add_mint_asset_with_existing_mint() {
         let mut tx_builder = create_default_tx_builder();
@@ -4869,7 +5886,7 @@ mod tests {
         assert_eq!(mint.deref().get(&policy_id2).unwrap().len(), 1);
         assert_eq!(mint.deref().get(&policy_id3).unwrap().len(), 2);
 
-        let mint_scripts = tx_builder.witness_builders.build_fake().unwrap();
+        let mint_scripts = tx_builder.witness_builders.build_fake(None, None).unwrap();
 
         assert_eq!(mint_scripts.native_scripts.unwrap().len(), 3);
         assert_eq!(mint_scripts.vkeywitnesses.unwrap().len(), 6);
@@ -5785,10 +6802,12 @@ mod tests {
                 .with_value(880_000)
                 .build().unwrap();
 
-            tx_builder.add_reference_input(TransactionUnspentOutput::new(
-                TransactionInput::new(genesis_id(), 1),
-                output.output,
-            ));
+            tx_builder
+                .add_reference_input(TransactionUnspentOutput::new(
+                    TransactionInput::new(genesis_id(), 1),
+                    output.output,
+                ))
+                .unwrap();
         }
         tx_builder
             .add_output(
@@ -5890,10 +6909,12 @@ mod tests {
             .with_value(880_000)
             .build().unwrap();
 
-        tx_builder.add_reference_input(TransactionUnspentOutput::new(
-            TransactionInput::new(genesis_id(), 1),
-            output.output,
-        ));
+        tx_builder
+            .add_reference_input(TransactionUnspentOutput::new(
+                TransactionInput::new(genesis_id(), 2),
+                output.output,
+            ))
+            .unwrap();
         tx_builder.add_input(input).unwrap();
 
         tx_builder.add_input(input2).unwrap();
@@ -5927,4 +6948,1073 @@ mod tests {
         assert!(final_tx.witness_set.plutus_v2_scripts.is_none());
         assert!(final_tx.witness_set.plutus_v1_scripts.is_none());
     }
+
+    fn multisig_spend_tx_builder(witness_info: NativeScriptWitnessInfo) -> TransactionBuilder {
+        let mut tx_builder = create_default_tx_builder();
+        // atLeast 2 [sig 1, sig 2, sig 3]
+        let script = NativeScript::new_script_n_of_k(
+            2,
+            vec![
+                NativeScript::new_script_pubkey(fake_key_hash(1)),
+                NativeScript::new_script_pubkey(fake_key_hash(2)),
+                NativeScript::new_script_pubkey(fake_key_hash(3)),
+            ],
+        );
+        let script_address = EnterpriseAddress::new(
+            NetworkInfo::testnet().network_id(),
+            StakeCredential::new_script(script.hash()),
+        )
+        .to_address();
+        let input = SingleInputBuilder::new(
+            TransactionInput::new(genesis_id(), 0),
+            TransactionOutput::new(script_address, Value::from(10_000_000), None, None),
+        )
+        .native_script(script, witness_info)
+        .unwrap();
+        tx_builder.add_input(input).unwrap();
+        tx_builder.set_ttl(1000);
+        tx_builder
+    }
+
+    #[test]
+    fn build_tx_multisig_specific_signers() {
+        let (_, _, change_addr) = create_account();
+
+        let mut assume_worst =
+            multisig_spend_tx_builder(NativeScriptWitnessInfo::assume_signature_count());
+        let assume_worst_fee = assume_worst
+            .build(ChangeSelectionAlgo::Default, &change_addr)
+            .unwrap()
+            .body()
+            .fee;
+
+        let signers = vec![fake_key_hash(1), fake_key_hash(3)];
+        let mut tx_builder =
+            multisig_spend_tx_builder(NativeScriptWitnessInfo::specific_signers(signers.clone()));
+        let signed_tx_builder = tx_builder
+            .build(ChangeSelectionAlgo::Default, &change_addr)
+            .unwrap();
+        // only 2 of the 3 witnesses are paid for
+        assert!(signed_tx_builder.body().fee < assume_worst_fee);
+        assert_eq!(
+            signed_tx_builder.witness_set().required_wits.vkeys,
+            signers.into_iter().collect()
+        );
+        assert!(matches!(
+            signed_tx_builder.build_checked(),
+            Err(WitnessBuilderError::MissingWitnesses(remaining)) if remaining.vkeys.len() == 2
+        ));
+
+        let mut tx_builder =
+            multisig_spend_tx_builder(NativeScriptWitnessInfo::specific_signers(vec![
+                fake_key_hash(2),
+            ]));
+        assert!(matches!(
+            tx_builder.build(ChangeSelectionAlgo::Default, &change_addr),
+            Err(TxBuilderError::WitnessBuildFailed(
+                WitnessBuilderError::InsufficientNativeScriptSigners(_)
+            ))
+        ));
+    }
+
+    fn plutus_spend_tx_builder() -> (TransactionBuilder, Address) {
+        let mut tx_builder = create_default_tx_builder();
+        let (_, _, addr_net_0) = create_account();
+        // always succeeds
+        let script = PlutusScript::PlutusV1(PlutusV1Script::new(
+            hex::decode("4e4d01000033222220051200120011").unwrap(),
+        ));
+        let script_address = EnterpriseAddress::new(
+            NetworkInfo::testnet().network_id(),
+            StakeCredential::new_script(script.hash()),
+        )
+        .to_address();
+        let input = SingleInputBuilder::new(
+            TransactionInput::new(genesis_id(), 0),
+            TransactionOutput::new(script_address, Value::from(10_000_000), None, None),
+        )
+        .plutus_script(
+            PartialPlutusWitness::new(
+                PlutusScriptWitness::from(script),
+                PlutusData::new_bytes(vec![]),
+            ),
+            vec![].into(),
+            PlutusData::new_integer_from_u64(42),
+        )
+        .unwrap();
+        tx_builder.add_input(input).unwrap();
+        let collateral = SingleInputBuilder::new(
+            TransactionInput::new(genesis_id(), 1),
+            TransactionOutput::new(addr_net_0.clone(), Value::from(5_000_000), None, None),
+        )
+        .payment_key()
+        .unwrap();
+        tx_builder.add_collateral(collateral).unwrap();
+        tx_builder
+            .add_output(
+                TransactionOutputBuilder::new()
+                    .with_address(addr_net_0.clone())
+                    .next()
+                    .unwrap()
+                    .with_value(2_000_000)
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap();
+        (tx_builder, addr_net_0)
+    }
+
+    struct MockEvaluator {
+        ex_units: ExUnits,
+    }
+
+    impl TxEvaluator for MockEvaluator {
+        fn evaluate(
+            &self,
+            tx: &Transaction,
+            resolved_inputs: &[TransactionUnspentOutput],
+        ) -> Result<BTreeMap<RedeemerKey, ExUnits>, EvalError> {
+            // spent input + collateral
+            assert_eq!(resolved_inputs.len(), 2);
+            Ok(tx
+                .witness_set
+                .redeemers
+                .clone()
+                .unwrap()
+                .to_flat_format()
+                .into_iter()
+                .map(|r| (RedeemerKey::new(r.tag, r.index), self.ex_units.clone()))
+                .collect())
+        }
+    }
+
+    #[test]
+    fn build_with_evaluator() {
+        let ex_units = ExUnits::new(1_000_000, 500_000_000);
+        let (mut tx_builder, change_addr) = plutus_spend_tx_builder();
+        let mut manual_builder = tx_builder.clone();
+
+        let signed_tx = tx_builder
+            .build_with_evaluator(
+                &MockEvaluator {
+                    ex_units: ex_units.clone(),
+                },
+                ChangeSelectionAlgo::Default,
+                &change_addr,
+            )
+            .unwrap()
+            .build_unchecked();
+        let redeemers = signed_tx
+            .witness_set
+            .redeemers
+            .clone()
+            .unwrap()
+            .to_flat_format();
+        assert_eq!(redeemers.len(), 1);
+        assert_eq!(redeemers[0].tag, RedeemerTag::Spend);
+        assert_eq!(redeemers[0].ex_units, ex_units);
+
+        // must match doing the evaluation steps by hand
+        manual_builder.set_exunits(
+            RedeemerWitnessKey::new(RedeemerTag::Spend, 0),
+            ex_units.clone(),
+        );
+        let manual_tx = manual_builder
+            .build(ChangeSelectionAlgo::Default, &change_addr)
+            .unwrap()
+            .build_unchecked();
+        assert_eq!(signed_tx.body.fee, manual_tx.body.fee);
+        assert_eq!(
+            signed_tx.body.to_cbor_bytes(),
+            manual_tx.body.to_cbor_bytes()
+        );
+
+        // the reference evaluator gives the same result
+        let (mut tx_builder, change_addr) = plutus_spend_tx_builder();
+        let mut fixed = FixedExUnitsEvaluator::default();
+        fixed.set(RedeemerTag::Spend, 0, ex_units);
+        let fixed_tx = tx_builder
+            .build_with_evaluator(&fixed, ChangeSelectionAlgo::Default, &change_addr)
+            .unwrap()
+            .build_unchecked();
+        assert_eq!(
+            fixed_tx.body.to_cbor_bytes(),
+            manual_tx.body.to_cbor_bytes()
+        );
+    }
+
+    #[test]
+    fn script_purposes_match_built_redeemers() {
+        let (mut tx_builder, change_addr) = plutus_spend_tx_builder();
+        let script = PlutusScript::PlutusV1(PlutusV1Script::new(
+            hex::decode("4e4d01000033222220051200120011").unwrap(),
+        ));
+        let script_address = EnterpriseAddress::new(
+            NetworkInfo::testnet().network_id(),
+            StakeCredential::new_script(script.hash()),
+        )
+        .to_address();
+        // a key input sorting between the two script inputs
+        let key_utxo = TransactionUnspentOutput::new(
+            TransactionInput::new(TransactionHash::from([1; 32]), 0),
+            TransactionOutput::new(change_addr.clone(), Value::from(3_000_000), None, None),
+        );
+        let script_utxo = TransactionUnspentOutput::new(
+            TransactionInput::new(TransactionHash::from([2; 32]), 0),
+            TransactionOutput::new(script_address.clone(), Value::from(4_000_000), None, None),
+        );
+        tx_builder
+            .add_input(
+                SingleInputBuilder::new(script_utxo.input.clone(), script_utxo.output.clone())
+                    .plutus_script(
+                        PartialPlutusWitness::new(
+                            PlutusScriptWitness::from(script.clone()),
+                            PlutusData::new_bytes(vec![]),
+                        ),
+                        vec![].into(),
+                        PlutusData::new_integer_from_u64(42),
+                    )
+                    .unwrap(),
+            )
+            .unwrap();
+        tx_builder
+            .add_input(
+                SingleInputBuilder::new(key_utxo.input.clone(), key_utxo.output.clone())
+                    .payment_key()
+                    .unwrap(),
+            )
+            .unwrap();
+        let mut fixed = FixedExUnitsEvaluator::default();
+        fixed.set(RedeemerTag::Spend, 0, ExUnits::new(1_000_000, 500_000_000));
+        fixed.set(RedeemerTag::Spend, 2, ExUnits::new(1_000_000, 500_000_000));
+        let tx = tx_builder
+            .build_with_evaluator(&fixed, ChangeSelectionAlgo::Default, &change_addr)
+            .unwrap()
+            .build_unchecked();
+
+        let resolved_inputs = [
+            TransactionUnspentOutput::new(
+                TransactionInput::new(genesis_id(), 0),
+                TransactionOutput::new(script_address, Value::from(10_000_000), None, None),
+            ),
+            key_utxo,
+            script_utxo,
+        ];
+        let purposes = crate::builders::script_purposes(&tx.body, &resolved_inputs).unwrap();
+        let purpose_keys = purposes
+            .iter()
+            .map(|purpose| (purpose.redeemer_key.tag, purpose.redeemer_key.index))
+            .collect::<Vec<_>>();
+        let redeemer_keys = tx
+            .witness_set
+            .redeemers
+            .clone()
+            .unwrap()
+            .to_flat_format()
+            .iter()
+            .map(|redeemer| (redeemer.tag, redeemer.index))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            purpose_keys,
+            vec![(RedeemerTag::Spend, 0), (RedeemerTag::Spend, 2)]
+        );
+        assert_eq!(redeemer_keys, purpose_keys);
+        assert!(purposes
+            .iter()
+            .all(|purpose| purpose.script_hash == script.hash()));
+    }
+
+    #[test]
+    fn script_purposes_match_built_vote_and_proposal_redeemers() {
+        use crate::builders::proposal_builder::ProposalBuilder;
+        use crate::builders::vote_builder::VoteBuilder;
+        use crate::certs::Url;
+        use crate::governance::{
+            Anchor, GovAction, GovActionId, ProposalProcedure, Vote, Voter, VotingProcedure,
+        };
+        use crate::ProtocolParamUpdate;
+        use cml_crypto::AnchorDocHash;
+
+        let (mut tx_builder, change_addr) = plutus_spend_tx_builder();
+        let script = PlutusScript::PlutusV1(PlutusV1Script::new(
+            hex::decode("4e4d01000033222220051200120011").unwrap(),
+        ));
+        let script_witness = || {
+            PartialPlutusWitness::new(
+                PlutusScriptWitness::from(script.clone()),
+                PlutusData::new_bytes(vec![]),
+            )
+        };
+        let action = |index| GovActionId::new(TransactionHash::from([3; 32]), index);
+        let (_, (_, stake_cred), _) = create_account();
+        let reward_address = RewardAddress::new(NetworkInfo::testnet().network_id(), stake_cred);
+        let anchor = Anchor::new(
+            Url::new("https://example.com".to_owned()).unwrap(),
+            AnchorDocHash::from([5; 32]),
+        );
+
+        // the committee key voter sorts before the DRep script voter despite being added later
+        // and the script voter gets one redeemer for both of its votes
+        let votes = VoteBuilder::new()
+            .with_plutus_vote_inline_datum(
+                Voter::new_d_rep_script_hash(script.hash()),
+                action(0),
+                VotingProcedure::new(Vote::Yes, None),
+                script_witness(),
+                vec![].into(),
+            )
+            .unwrap()
+            .with_plutus_vote_inline_datum(
+                Voter::new_d_rep_script_hash(script.hash()),
+                action(1),
+                VotingProcedure::new(Vote::No, None),
+                script_witness(),
+                vec![].into(),
+            )
+            .unwrap()
+            .with_vote(
+                Voter::new_constitutional_committee_hot_key_hash(Ed25519KeyHash::from([0xff; 28])),
+                action(0),
+                VotingProcedure::new(Vote::Yes, None),
+            )
+            .unwrap()
+            .build();
+        tx_builder.add_vote(votes);
+        // the key proposal counts towards the index of the script one
+        let proposals = ProposalBuilder::new()
+            .with_proposal(ProposalProcedure::new(
+                1_000_000,
+                reward_address.clone(),
+                GovAction::new_info_action(),
+                anchor.clone(),
+            ))
+            .unwrap()
+            .with_plutus_proposal_inline_datum(
+                ProposalProcedure::new(
+                    1_000_000,
+                    reward_address,
+                    GovAction::new_parameter_change_action(
+                        None,
+                        ProtocolParamUpdate::new(),
+                        Some(script.hash()),
+                    ),
+                    anchor,
+                ),
+                script_witness(),
+                vec![].into(),
+            )
+            .unwrap()
+            .build();
+        tx_builder.add_proposal(proposals);
+
+        let mut fixed = FixedExUnitsEvaluator::default();
+        for (tag, index) in [
+            (RedeemerTag::Spend, 0),
+            (RedeemerTag::Voting, 1),
+            (RedeemerTag::Proposing, 1),
+        ] {
+            fixed.set(tag, index, ExUnits::new(1_000_000, 500_000_000));
+        }
+        let tx = tx_builder
+            .build_with_evaluator(&fixed, ChangeSelectionAlgo::Default, &change_addr)
+            .unwrap()
+            .build_unchecked();
+
+        let resolved_inputs = [TransactionUnspentOutput::new(
+            TransactionInput::new(genesis_id(), 0),
+            TransactionOutput::new(
+                EnterpriseAddress::new(
+                    NetworkInfo::testnet().network_id(),
+                    StakeCredential::new_script(script.hash()),
+                )
+                .to_address(),
+                Value::from(10_000_000),
+                None,
+                None,
+            ),
+        )];
+        let purpose_keys = crate::builders::script_purposes(&tx.body, &resolved_inputs)
+            .unwrap()
+            .iter()
+            .map(|purpose| (purpose.redeemer_key.tag, purpose.redeemer_key.index))
+            .collect::<Vec<_>>();
+        let redeemer_keys = tx
+            .witness_set
+            .redeemers
+            .clone()
+            .unwrap()
+            .to_flat_format()
+            .iter()
+            .map(|redeemer| (redeemer.tag, redeemer.index))
+            .collect::<BTreeSet<_>>();
+        assert_eq!(
+            purpose_keys,
+            vec![
+                (RedeemerTag::Spend, 0),
+                (RedeemerTag::Voting, 1),
+                (RedeemerTag::Proposing, 1)
+            ]
+        );
+        assert_eq!(redeemer_keys, purpose_keys.into_iter().collect());
+    }
+
+    #[test]
+    fn build_with_evaluator_missing_ex_units() {
+        let (mut tx_builder, change_addr) = plutus_spend_tx_builder();
+        let res = tx_builder.build_with_evaluator(
+            &FixedExUnitsEvaluator::default(),
+            ChangeSelectionAlgo::Default,
+            &change_addr,
+        );
+        assert!(matches!(
+            res,
+            Err(TxBuilderError::EvaluationFailed(EvalError::MissingExUnits(
+                RedeemerTag::Spend,
+                0
+            )))
+        ));
+    }
+
+    #[test]
+    fn reference_inputs_conflicts_and_getters() {
+        let mut tx_builder = create_default_tx_builder();
+        let (_, _, addr_net_0) = create_account();
+        let script = Script::new_native(NativeScript::new_script_invalid_before(1));
+        let datum = PlutusData::new_bytes(vec![1, 2, 3]);
+        let ref_utxo = TransactionUnspentOutput::new(
+            TransactionInput::new(genesis_id(), 0),
+            TransactionOutput::new(
+                addr_net_0.clone(),
+                Value::from(1_000_000),
+                Some(DatumOption::new_datum(datum.clone())),
+                Some(script.clone()),
+            ),
+        );
+        tx_builder.add_reference_input(ref_utxo.clone()).unwrap();
+        // adding the same reference input again is a no-op
+        tx_builder.add_reference_input(ref_utxo.clone()).unwrap();
+        let reference_inputs = tx_builder.get_reference_inputs();
+        assert_eq!(reference_inputs.len(), 1);
+        assert_eq!(reference_inputs[0].input, ref_utxo.input);
+        assert_eq!(tx_builder.reference_scripts(), vec![script]);
+        assert_eq!(tx_builder.reference_datums(), vec![datum]);
+
+        // a referenced input can't also be spent
+        let input = SingleInputBuilder::new(ref_utxo.input.clone(), ref_utxo.output.clone())
+            .payment_key()
+            .unwrap();
+        assert!(matches!(
+            tx_builder.add_input(input),
+            Err(TxBuilderError::SpentAndReferenced(_))
+        ));
+
+        // nor can a spent input be referenced
+        let spent = SingleInputBuilder::new(
+            TransactionInput::new(genesis_id(), 1),
+            TransactionOutput::new(addr_net_0, Value::from(1_000_000), None, None),
+        )
+        .payment_key()
+        .unwrap();
+        let spent_utxo =
+            TransactionUnspentOutput::new(spent.input.clone(), spent.utxo_info.clone());
+        tx_builder.add_input(spent).unwrap();
+        assert!(matches!(
+            tx_builder.add_reference_input(spent_utxo),
+            Err(TxBuilderError::SpentAndReferenced(_))
+        ));
+        assert_eq!(tx_builder.get_reference_inputs().len(), 1);
+    }
+
+    #[test]
+    fn spend_datum_from_reference_input() {
+        let (_, _, addr_net_0) = create_account();
+        // always succeeds
+        let script = PlutusScript::PlutusV1(PlutusV1Script::new(
+            hex::decode("4e4d01000033222220051200120011").unwrap(),
+        ));
+        let datum = PlutusData::new_integer_from_u64(42);
+        let spent_utxo = TransactionUnspentOutput::new(
+            TransactionInput::new(genesis_id(), 0),
+            TransactionOutput::new(
+                EnterpriseAddress::new(
+                    NetworkInfo::testnet().network_id(),
+                    StakeCredential::new_script(script.hash()),
+                )
+                .to_address(),
+                Value::from(10_000_000),
+                Some(DatumOption::new_hash(hash_plutus_data(&datum))),
+                None,
+            ),
+        );
+        let ref_utxo = TransactionUnspentOutput::new(
+            TransactionInput::new(genesis_id(), 1),
+            TransactionOutput::new(
+                addr_net_0.clone(),
+                Value::from(2_000_000),
+                Some(DatumOption::new_datum(datum.clone())),
+                None,
+            ),
+        );
+        let witness = PartialPlutusWitness::new(
+            PlutusScriptWitness::from(script),
+            PlutusData::new_bytes(vec![]),
+        );
+        let input = SingleInputBuilder::from(spent_utxo.clone())
+            .plutus_script_reference_datum(witness.clone(), vec![].into())
+            .unwrap();
+
+        // the datum can't be resolved without the reference input
+        let mut tx_builder = create_default_tx_builder();
+        assert!(matches!(
+            tx_builder.add_input(input.clone()),
+            Err(TxBuilderError::WitnessBuildFailed(
+                WitnessBuilderError::MissingWitnesses(_)
+            ))
+        ));
+
+        tx_builder.add_reference_input(ref_utxo.clone()).unwrap();
+        tx_builder.add_input(input).unwrap();
+        let referenced_datums = tx_builder.referenced_datums();
+        assert_eq!(referenced_datums.len(), 1);
+        assert_eq!(referenced_datums[0].input, ref_utxo.input);
+        let collateral = SingleInputBuilder::new(
+            TransactionInput::new(genesis_id(), 2),
+            TransactionOutput::new(addr_net_0.clone(), Value::from(5_000_000), None, None),
+        )
+        .payment_key()
+        .unwrap();
+        tx_builder.add_collateral(collateral).unwrap();
+        tx_builder.set_exunits(
+            RedeemerWitnessKey::new(RedeemerTag::Spend, 0),
+            ExUnits::new(1_000_000, 1_000_000),
+        );
+        let tx = tx_builder
+            .build(ChangeSelectionAlgo::Default, &addr_net_0)
+            .unwrap()
+            .build_unchecked();
+        assert!(tx.witness_set.plutus_datums.is_none());
+        assert!(tx.witness_set.redeemers.is_some());
+        assert!(crate::builders::utils::missing_datums(
+            &tx,
+            &[spent_utxo.clone(), ref_utxo.clone()]
+        )
+        .is_empty());
+
+        // a datum also provided explicitly isn't duplicated into the witness set
+        let mut tx_builder = create_default_tx_builder();
+        tx_builder.add_reference_input(ref_utxo).unwrap();
+        tx_builder
+            .add_input(
+                SingleInputBuilder::from(spent_utxo)
+                    .plutus_script(witness, vec![].into(), datum)
+                    .unwrap(),
+            )
+            .unwrap();
+        assert!(tx_builder
+            .witness_builders
+            .witness_set_builder
+            .get_plutus_datum()
+            .is_empty());
+    }
+
+    #[test]
+    fn build_for_evaluation_provisional_ex_units() {
+        let draft_ex_units = |tx_builder: &TransactionBuilder, change_addr: &Address| {
+            tx_builder
+                .build_for_evaluation(ChangeSelectionAlgo::Default, change_addr)
+                .unwrap()
+                .draft_tx()
+                .unwrap()
+                .witness_set
+                .redeemers
+                .unwrap()
+                .to_flat_format()
+                .into_iter()
+                .map(|redeemer| redeemer.ex_units)
+                .collect::<Vec<_>>()
+        };
+
+        let (mut tx_builder, change_addr) = plutus_spend_tx_builder();
+        assert_eq!(
+            draft_ex_units(&tx_builder, &change_addr),
+            vec![ExUnits::dummy()]
+        );
+
+        // EvenSplit/Max need max_tx_ex_units in the config
+        tx_builder.set_provisional_ex_units(ProvisionalExUnitsStrategy::EvenSplit);
+        assert!(matches!(
+            tx_builder.build_for_evaluation(ChangeSelectionAlgo::Default, &change_addr),
+            Err(TxBuilderError::MaxTxExUnitsNotSet(
+                ProvisionalExUnitsStrategy::EvenSplit
+            ))
+        ));
+        let max_tx_ex_units = ExUnits::new(14_000_000, 10_000_000_001);
+        tx_builder.config.max_tx_ex_units = Some(max_tx_ex_units.clone());
+
+        // add a second script input so the budget is split
+        let script = PlutusScript::PlutusV1(PlutusV1Script::new(
+            hex::decode("4e4d01000033222220051200120011").unwrap(),
+        ));
+        let script_address = EnterpriseAddress::new(
+            NetworkInfo::testnet().network_id(),
+            StakeCredential::new_script(script.hash()),
+        )
+        .to_address();
+        let input = SingleInputBuilder::new(
+            TransactionInput::new(genesis_id(), 2),
+            TransactionOutput::new(script_address, Value::from(10_000_000), None, None),
+        )
+        .plutus_script(
+            PartialPlutusWitness::new(
+                PlutusScriptWitness::from(script),
+                PlutusData::new_bytes(vec![]),
+            ),
+            vec![].into(),
+            PlutusData::new_integer_from_u64(43),
+        )
+        .unwrap();
+        tx_builder.add_input(input).unwrap();
+        assert_eq!(
+            draft_ex_units(&tx_builder, &change_addr),
+            max_tx_ex_units.split_evenly(2)
+        );
+
+        tx_builder.set_provisional_ex_units(ProvisionalExUnitsStrategy::Max);
+        assert_eq!(
+            draft_ex_units(&tx_builder, &change_addr),
+            vec![max_tx_ex_units.clone(); 2]
+        );
+
+        // explicitly set ExUnits are left alone
+        let fixed = ExUnits::new(1_000, 2_000);
+        let evaluated = ExUnits::new(1_000_000, 500_000_000);
+        tx_builder.set_provisional_ex_units(ProvisionalExUnitsStrategy::Fixed(fixed.clone()));
+        let mut manual_builder = tx_builder.clone();
+        manual_builder.set_exunits(
+            RedeemerWitnessKey::new(RedeemerTag::Spend, 0),
+            evaluated.clone(),
+        );
+        assert_eq!(
+            draft_ex_units(&manual_builder, &change_addr),
+            vec![evaluated.clone(), fixed.clone()]
+        );
+
+        // the draft patched with the evaluation results matches a build with an evaluator
+        let mut redeemer_builder = tx_builder
+            .build_for_evaluation(ChangeSelectionAlgo::Default, &change_addr)
+            .unwrap();
+        // provisional ExUnits aren't dummies so the draft gets a real script_data_hash
+        assert_ne!(
+            redeemer_builder.draft_body().script_data_hash,
+            Some(ScriptDataHash::from([0u8; ScriptDataHash::BYTE_COUNT]))
+        );
+        let mut evaluator = FixedExUnitsEvaluator::default();
+        evaluator.set(RedeemerTag::Spend, 0, evaluated.clone());
+        evaluator.set(RedeemerTag::Spend, 1, evaluated.clone());
+        let draft_tx = redeemer_builder.draft_tx().unwrap();
+        for (key, ex_units) in evaluator.evaluate(&draft_tx, &[]).unwrap() {
+            redeemer_builder.set_exunits(RedeemerWitnessKey::new(key.tag, key.index), ex_units);
+        }
+        let patched = redeemer_builder
+            .build()
+            .unwrap()
+            .to_flat_format()
+            .into_iter()
+            .map(|redeemer| redeemer.ex_units)
+            .collect::<Vec<_>>();
+        assert_eq!(patched, vec![evaluated.clone(); 2]);
+    }
+
+    #[test]
+    fn communication_datum_in_witness_set() {
+        use crate::crypto::hash::{hash_plutus_data, hash_script_data};
+
+        let mut tx_builder = create_realistic_tx_builder();
+        let (_, _, change_addr) = create_account();
+        tx_builder
+            .add_input(make_input(1u8, Value::from(20_000_000)))
+            .unwrap();
+        let datum = PlutusData::new_integer_from_u64(42);
+        // the same datum used by two outputs is only included once
+        for _ in 0..2 {
+            tx_builder
+                .add_output(
+                    TransactionOutputBuilder::new()
+                        .with_address(change_addr.clone())
+                        .with_communication_data(datum.clone())
+                        .next()
+                        .unwrap()
+                        .with_value(2_000_000)
+                        .build()
+                        .unwrap(),
+                )
+                .unwrap();
+        }
+        let tx = tx_builder
+            .build(ChangeSelectionAlgo::Default, &change_addr)
+            .unwrap()
+            .build_unchecked();
+        assert!(tx.body.outputs[..2]
+            .iter()
+            .all(|output| output.datum_hash() == Some(&hash_plutus_data(&datum))));
+        let datums = tx.witness_set.plutus_datums.unwrap();
+        assert_eq!(datums.as_ref(), &[datum][..]);
+        // no redeemers but the datums must still be covered by the script_data_hash
+        assert!(tx.witness_set.redeemers.is_none());
+        assert_eq!(
+            tx.body.script_data_hash,
+            Some(hash_script_data(
+                &Redeemers::new_arr_legacy_redeemer(vec![]),
+                &CostModels::default(),
+                Some(&datums),
+                None
+            ))
+        );
+    }
+
+    fn create_tx_builder_with_collateral_policy(policy: CollateralPolicy) -> TransactionBuilder {
+        TransactionBuilder::new(
+            TransactionBuilderConfigBuilder::default()
+                .fee_algo(create_linear_fee(44, 155381))
+                .pool_deposit(500000000)
+                .key_deposit(2000000)
+                .max_value_size(MAX_VALUE_SIZE)
+                .max_tx_size(MAX_TX_SIZE)
+                .coins_per_utxo_byte(COINS_PER_UTXO_BYTE)
+                .ex_unit_prices(ExUnitPrices::new(SubCoin::new(0, 0), SubCoin::new(0, 0)))
+                .collateral_percentage(150)
+                .max_collateral_inputs(3)
+                .collateral_policy(policy)
+                .build()
+                .unwrap(),
+        )
+    }
+
+    #[test]
+    fn collateral_policies() {
+        let (_, _, address) = create_account();
+        let token_policy = PolicyId::from([1; 28]);
+        let pure_collateral = SingleInputBuilder::new(
+            TransactionInput::new(genesis_id(), 1),
+            TransactionOutput::new(address.clone(), Value::from(2_000_000), None, None),
+        )
+        .payment_key()
+        .unwrap();
+        let token_collateral = SingleInputBuilder::new(
+            TransactionInput::new(genesis_id(), 2),
+            TransactionOutput::new(
+                address.clone(),
+                Value::new(10_000_000, create_multiasset_one_asset(&token_policy)),
+                None,
+                None,
+            ),
+        )
+        .payment_key()
+        .unwrap();
+        let candidates = [token_collateral.clone(), pure_collateral.clone()];
+
+        let build = |mut tx_builder: TransactionBuilder| {
+            tx_builder
+                .add_input(
+                    SingleInputBuilder::new(
+                        TransactionInput::new(genesis_id(), 0),
+                        TransactionOutput::new(
+                            address.clone(),
+                            Value::from(10_000_000),
+                            None,
+                            None,
+                        ),
+                    )
+                    .payment_key()
+                    .unwrap(),
+                )
+                .unwrap();
+            tx_builder
+                .add_output(
+                    TransactionOutputBuilder::new()
+                        .with_address(address.clone())
+                        .next()
+                        .unwrap()
+                        .with_value(2_000_000)
+                        .build()
+                        .unwrap(),
+                )
+                .unwrap();
+            tx_builder
+                .build(ChangeSelectionAlgo::Default, &address)
+                .unwrap()
+                .body()
+        };
+
+        // token-bearing collateral is rejected outright
+        let mut tx_builder =
+            create_tx_builder_with_collateral_policy(CollateralPolicy::PureAdaOnly);
+        assert!(matches!(
+            tx_builder.add_collateral(token_collateral.clone()),
+            Err(TxBuilderError::CollateralHasTokens(_))
+        ));
+        assert!(matches!(
+            tx_builder.clone().select_collateral(&candidates, 5_000_000),
+            Err(TxBuilderError::InsufficientCollateral(5_000_000, 2_000_000))
+        ));
+        tx_builder
+            .select_collateral(&candidates, 1_000_000)
+            .unwrap();
+        let body = build(tx_builder);
+        assert_eq!(
+            body.collateral_inputs.unwrap().to_vec(),
+            vec![pure_collateral.input.clone()]
+        );
+        assert!(body.collateral_return.is_none());
+
+        // pure ADA is preferred and the tokens + excess ADA are returned automatically
+        let mut tx_builder = create_tx_builder_with_collateral_policy(CollateralPolicy::AutoReturn);
+        tx_builder
+            .select_collateral(&candidates, 3_000_000)
+            .unwrap();
+        let body = build(tx_builder);
+        assert_eq!(
+            body.collateral_inputs.unwrap().to_vec(),
+            vec![
+                pure_collateral.input.clone(),
+                token_collateral.input.clone()
+            ]
+        );
+        let total = body.total_collateral.unwrap();
+        assert_eq!(total, (body.fee * 150).div_ceil(100));
+        let collateral_return = body.collateral_return.unwrap();
+        assert_eq!(collateral_return.address(), &address);
+        assert_eq!(collateral_return.amount().coin + total, 12_000_000);
+        assert_eq!(
+            collateral_return.amount().multiasset,
+            create_multiasset_one_asset(&token_policy)
+        );
+
+        // nothing implicit: tokens are accepted but no return is added
+        let mut tx_builder = create_tx_builder_with_collateral_policy(CollateralPolicy::Manual);
+        tx_builder
+            .select_collateral(&candidates, 3_000_000)
+            .unwrap();
+        let body = build(tx_builder);
+        assert_eq!(
+            body.collateral_inputs.unwrap().to_vec(),
+            vec![token_collateral.input.clone()]
+        );
+        assert!(body.collateral_return.is_none());
+        assert!(body.total_collateral.is_none());
+    }
+
+    #[test]
+    fn network_id_mismatched_withdrawal() {
+        let mut tx_builder = create_realistic_tx_builder();
+        let (_, (_, stake_cred), address) = create_account();
+        tx_builder
+            .add_input(
+                SingleInputBuilder::new(
+                    TransactionInput::new(genesis_id(), 0),
+                    TransactionOutput::new(address.clone(), Value::from(10_000_000), None, None),
+                )
+                .payment_key()
+                .unwrap(),
+            )
+            .unwrap();
+        let mainnet_account = RewardAddress::new(NetworkInfo::mainnet().network_id(), stake_cred);
+        tx_builder.add_withdrawal(
+            crate::builders::withdrawal_builder::SingleWithdrawalBuilder::new(
+                mainnet_account.clone(),
+                1_000_000,
+            )
+            .payment_key()
+            .unwrap(),
+        );
+        // nothing to check against without a network
+        tx_builder.validate_network_id().unwrap();
+
+        tx_builder.set_network_id(NetworkId::testnet());
+        match tx_builder.build(ChangeSelectionAlgo::Default, &address) {
+            Err(TxBuilderError::NetworkIdMismatch(network, mismatches)) => {
+                assert_eq!(network, 0);
+                assert_eq!(
+                    mismatches,
+                    vec![NetworkIdMismatch::Withdrawal(mainnet_account)]
+                );
+            }
+            other => panic!("expected network id mismatch, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    fn make_key_input(key_byte: u8, hash_byte: u8, index: u64) -> InputBuilderResult {
+        let address = EnterpriseAddress::new(
+            NetworkInfo::testnet().network_id(),
+            StakeCredential::new_pub_key(Ed25519KeyHash::from([key_byte; 28])),
+        )
+        .to_address();
+        SingleInputBuilder::new(
+            TransactionInput::new(TransactionHash::from([hash_byte; 32]), index),
+            TransactionOutput::new(address, Value::from(1_000_000), None, None),
+        )
+        .payment_key()
+        .unwrap()
+    }
+
+    #[test]
+    fn size_estimate_never_underestimates() {
+        // large enough to fit over 255 inputs
+        let mut tx_builder = TransactionBuilder::new(
+            TransactionBuilderConfigBuilder::default()
+                .fee_algo(create_linear_fee(44, 155381))
+                .pool_deposit(500000000)
+                .key_deposit(2000000)
+                .max_value_size(MAX_VALUE_SIZE)
+                .max_tx_size(65536)
+                .coins_per_utxo_byte(COINS_PER_UTXO_BYTE)
+                .ex_unit_prices(ExUnitPrices::new(SubCoin::new(0, 0), SubCoin::new(0, 0)))
+                .collateral_percentage(150)
+                .max_collateral_inputs(3)
+                .build()
+                .unwrap(),
+        );
+        let exact_size = |tx_builder: &TransactionBuilder| {
+            let mut tx_builder = tx_builder.clone();
+            tx_builder.set_fee(0);
+            tx_builder.full_size().unwrap()
+        };
+        let (_, _, address) = create_account();
+        // enough inputs, outputs and distinct vkeys for all of their length headers to grow
+        for i in 0..300u64 {
+            let input = make_key_input((i % 40) as u8, (i % 7) as u8, i);
+            if i > 0 {
+                let size_before = exact_size(&tx_builder);
+                let fee = tx_builder.fee_for_input(&input).unwrap();
+                let mut with_input = tx_builder.clone();
+                with_input.add_input(input.clone()).unwrap();
+                assert_eq!(fee, (exact_size(&with_input) - size_before) as u64 * 44);
+            }
+            tx_builder.add_input(input).unwrap();
+            if i > 0 {
+                // kept up to date without building the tx again
+                assert!(tx_builder.size_estimate.get().is_some());
+            }
+            if i % 10 == 0 {
+                tx_builder
+                    .add_output(
+                        TransactionOutputBuilder::new()
+                            .with_address(address.clone())
+                            .next()
+                            .unwrap()
+                            .with_value(2_000_000 + i)
+                            .build()
+                            .unwrap(),
+                    )
+                    .unwrap();
+            }
+            let estimate = tx_builder.current_size_estimate().unwrap();
+            let exact = exact_size(&tx_builder);
+            assert!(
+                estimate >= exact && estimate - exact <= SIZE_ESTIMATE_MAX_DELTA,
+                "estimate {} exact {} after {} inputs",
+                estimate,
+                exact,
+                i + 1
+            );
+        }
+
+        // anything else is computed exactly again
+        tx_builder.set_ttl(1_000_000);
+        assert!(tx_builder.size_estimate.get().is_none());
+        tx_builder.set_fee(1_000_000);
+        assert_eq!(
+            tx_builder.current_size_estimate().unwrap(),
+            tx_builder.full_size().unwrap()
+        );
+    }
+
+    #[test]
+    fn required_signers_deduplicated() {
+        let (_, _, address) = create_account();
+        let hash = match address.payment_cred() {
+            Some(Credential::PubKey { hash, .. }) => *hash,
+            _ => panic!(),
+        };
+        let input = make_key_input(1, 1, 0);
+        let mut once = create_realistic_tx_builder();
+        once.add_input(input.clone()).unwrap();
+        once.add_required_signer(hash);
+
+        let mut twice = create_realistic_tx_builder();
+        twice.add_input(input).unwrap();
+        twice.add_required_signer(hash);
+        twice.add_required_signer(hash);
+        twice.add_required_signers_from_address(&address).unwrap();
+        assert_eq!(twice.required_signers(), vec![hash]);
+        assert_eq!(twice.min_fee(false).unwrap(), once.min_fee(false).unwrap());
+
+        twice.set_fee(twice.min_fee(false).unwrap());
+        let body = twice.build_body().unwrap();
+        assert_eq!(body.required_signers.unwrap().len(), 1);
+
+        let script_address = EnterpriseAddress::new(
+            NetworkInfo::testnet().network_id(),
+            StakeCredential::new_script(ScriptHash::from([2; 28])),
+        )
+        .to_address();
+        assert!(matches!(
+            twice.add_required_signers_from_address(&script_address),
+            Err(TxBuilderError::RequiredSignerNotKeyHash(_))
+        ));
+        assert!(matches!(
+            twice.add_required_signers_from_address(&byron_address()),
+            Err(TxBuilderError::RequiredSignerNotKeyHash(_))
+        ));
+        assert_eq!(twice.required_signers().len(), 1);
+    }
+
+    #[test]
+    fn validity_window_relative() {
+        let mut tx_builder = create_default_tx_builder();
+        // start inclusive, ttl exclusive: a 1 slot window is only valid in the current slot
+        tx_builder.set_validity_window_relative(1000, 1).unwrap();
+        assert_eq!(tx_builder.validity_start_interval, Some(1000));
+        assert_eq!(tx_builder.ttl, Some(1001));
+        tx_builder.set_validity_window_relative(1000, 10).unwrap();
+        assert_eq!(tx_builder.validity_start_interval, Some(1000));
+        assert_eq!(tx_builder.ttl, Some(1010));
+        assert!(matches!(
+            tx_builder.set_validity_window_relative(2000, 0),
+            Err(TxBuilderError::EmptyValidityWindow)
+        ));
+        assert!(tx_builder
+            .set_validity_window_relative(u64::MAX, 1)
+            .is_err());
+        // failed calls leave the previous window as-is
+        assert_eq!(tx_builder.validity_start_interval, Some(1000));
+        assert_eq!(tx_builder.ttl, Some(1010));
+
+        tx_builder.config.max_validity_window = Some(10);
+        tx_builder.set_validity_window_relative(1000, 10).unwrap();
+        assert!(matches!(
+            tx_builder.set_validity_window_relative(1000, 11),
+            Err(TxBuilderError::ValidityWindowTooLong(11, 10))
+        ));
+
+        // only whole slots count
+        let slot_config = SlotConfig::new(0, 0, 20000).unwrap();
+        assert!(matches!(
+            tx_builder.set_validity_end_in(19, &slot_config, 1000),
+            Err(TxBuilderError::EmptyValidityWindow)
+        ));
+        tx_builder
+            .set_validity_end_in(59, &slot_config, 1000)
+            .unwrap();
+        assert_eq!(tx_builder.validity_start_interval, Some(1000));
+        assert_eq!(tx_builder.ttl, Some(1002));
+        tx_builder
+            .set_validity_end_in(10, &SlotConfig::preview(), 5000)
+            .unwrap();
+        assert_eq!(tx_builder.ttl, Some(5010));
+        assert!(matches!(
+            tx_builder.set_validity_end_in(11, &SlotConfig::preview(), 5000),
+            Err(TxBuilderError::ValidityWindowTooLong(11, 10))
+        ));
+    }
 }
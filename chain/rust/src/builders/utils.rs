@@ -1,6 +1,32 @@
-use crate::RequiredSigners;
+use std::convert::TryFrom;
 
-use super::witness_builder::RequiredWitnessSet;
+use std::collections::BTreeSet;
+
+use crate::{
+    address::Address,
+    assets::{AssetArithmeticError, AssetName, MultiAsset},
+    certs::StakeCredential,
+    crypto::hash::hash_plutus_data,
+    min_ada::min_ada_required,
+    transaction::{
+        DatumOption, NativeScript, Transaction, TransactionBody, TransactionInput,
+        TransactionOutput,
+    },
+    Coin, PolicyId, RequiredSigners, Script, Value,
+};
+use cml_crypto::{DatumHash, ScriptHash};
+
+use cml_core::ordered_hash_map::OrderedHashMap;
+
+use super::{
+    input_builder::{InputBuilderResult, SingleInputBuilder},
+    output_builder::{SingleOutputBuilderResult, TransactionOutputBuilder},
+    tx_builder::{
+        ChangeSelectionAlgo, TransactionBuilder, TransactionBuilderConfig,
+        TransactionUnspentOutput, TxBuilderError,
+    },
+    witness_builder::RequiredWitnessSet,
+};
 
 pub(crate) fn required_wits_from_required_signers(
     required_signers: &RequiredSigners,
@@ -12,3 +38,954 @@ pub(crate) fn required_wits_from_required_signers(
         .for_each(|required_signer| required_wits.add_vkey_key_hash(*required_signer));
     required_wits
 }
+
+#[derive(Debug, thiserror::Error)]
+pub enum CollateralError {
+    #[error("Transaction has redeemers but no collateral inputs")]
+    NoCollateralInputs,
+    #[error("Max collateral input count {max} exceeded. Found: {found}")]
+    TooManyCollateralInputs { max: u64, found: u64 },
+    #[error("Collateral input {0:?} was not found in the resolved collateral")]
+    MissingCollateralUtxo(TransactionInput),
+    #[error("Collateral input {0:?} is at a Byron address")]
+    ByronAddress(TransactionInput),
+    #[error("Collateral input {0:?} is locked by a script")]
+    ScriptLocked(TransactionInput),
+    #[error("Asset arithmetic: {0}")]
+    AssetArithmetic(#[from] AssetArithmeticError),
+    #[error("Collateral tokens not returned via collateral_return: {0:?}")]
+    UnreturnedTokens(MultiAsset),
+    #[error("total_collateral field is {declared} but the collateral inputs minus collateral_return is {actual}")]
+    TotalCollateralMismatch { declared: Coin, actual: Coin },
+    #[error("Insufficient collateral: {provided} provided but {required} required (short by {shortfall})")]
+    InsufficientCollateral {
+        required: Coin,
+        provided: Coin,
+        shortfall: Coin,
+    },
+}
+
+/// Summary of a transaction's collateral as computed by check_collateral()
+#[derive(Clone, Debug)]
+pub struct CollateralReport {
+    /// Sum of all resolved collateral inputs
+    pub input_value: Value,
+    /// ADA that would be taken if script validation fails (inputs minus collateral_return)
+    pub collateral: Coin,
+    /// Minimum collateral needed for the tx's fee i.e. ceil(fee * collateral_percentage / 100)
+    pub required_collateral: Coin,
+}
+
+impl CollateralReport {
+    /// ADA in excess of the required collateral
+    pub fn excess(&self) -> Coin {
+        self.collateral - self.required_collateral
+    }
+}
+
+/// Validates the collateral of {tx} against the ledger rules applied to transactions with scripts:
+/// * There must be at most {max_collateral_inputs} collateral inputs.
+/// * All collateral must be at key-locked Shelley addresses (no Byron or script addresses).
+/// * Any tokens in the collateral must be returned via collateral_return.
+/// * If total_collateral is set it must match the collateral inputs minus collateral_return.
+/// * The collateral must be at least ceil(fee * {collateral_percentage} / 100).
+///
+/// {resolved_collateral} must contain the UTXOs for all of the tx's collateral inputs.
+/// Transactions without redeemers don't need collateral and only have their
+/// collateral checked if they contain any.
+pub fn check_collateral(
+    tx: &Transaction,
+    resolved_collateral: &[TransactionUnspentOutput],
+    collateral_percentage: u64,
+    max_collateral_inputs: u64,
+) -> Result<CollateralReport, CollateralError> {
+    let has_redeemers = tx
+        .witness_set
+        .redeemers
+        .as_ref()
+        .is_some_and(|redeemers| !redeemers.is_empty());
+    let collateral_inputs = tx
+        .body
+        .collateral_inputs
+        .as_ref()
+        .map(|inputs| inputs.as_ref())
+        .unwrap_or_default();
+    if collateral_inputs.is_empty() && has_redeemers {
+        return Err(CollateralError::NoCollateralInputs);
+    }
+    if collateral_inputs.len() as u64 > max_collateral_inputs {
+        return Err(CollateralError::TooManyCollateralInputs {
+            max: max_collateral_inputs,
+            found: collateral_inputs.len() as u64,
+        });
+    }
+    let mut input_value = Value::zero();
+    for input in collateral_inputs {
+        let utxo = resolved_collateral
+            .iter()
+            .find(|utxo| utxo.input == *input)
+            .ok_or_else(|| CollateralError::MissingCollateralUtxo(input.clone()))?;
+        match utxo.output.address() {
+            Address::Byron(_) => return Err(CollateralError::ByronAddress(input.clone())),
+            address => {
+                if let Some(StakeCredential::Script { .. }) = address.payment_cred() {
+                    return Err(CollateralError::ScriptLocked(input.clone()));
+                }
+            }
+        }
+        input_value = input_value.checked_add(utxo.output.amount())?;
+    }
+    let balance = match &tx.body.collateral_return {
+        Some(collateral_return) => input_value.checked_sub(collateral_return.amount())?,
+        None => input_value.clone(),
+    };
+    if balance.has_multiassets() {
+        return Err(CollateralError::UnreturnedTokens(balance.multiasset));
+    }
+    if let Some(declared) = tx.body.total_collateral {
+        if declared != balance.coin {
+            return Err(CollateralError::TotalCollateralMismatch {
+                declared,
+                actual: balance.coin,
+            });
+        }
+    }
+    let required_collateral = if has_redeemers || !collateral_inputs.is_empty() {
+        let required = (tx.body.fee as u128 * collateral_percentage as u128).div_ceil(100);
+        u64::try_from(required).unwrap_or(u64::MAX)
+    } else {
+        0
+    };
+    if balance.coin < required_collateral {
+        return Err(CollateralError::InsufficientCollateral {
+            required: required_collateral,
+            provided: balance.coin,
+            shortfall: required_collateral - balance.coin,
+        });
+    }
+    Ok(CollateralReport {
+        input_value,
+        collateral: balance.coin,
+        required_collateral,
+    })
+}
+
+/// Groups {utxos} by the stake credential of their address, in order of first appearance.
+/// UTxOs without a stake credential (enterprise, pointer and Byron addresses) are grouped under None.
+pub fn group_utxos_by_stake_credential(
+    utxos: &[TransactionUnspentOutput],
+) -> OrderedHashMap<Option<StakeCredential>, Vec<TransactionUnspentOutput>> {
+    let mut groups = OrderedHashMap::<_, Vec<_>>::new();
+    for utxo in utxos {
+        groups
+            .entry(utxo.output.address().staking_cred().cloned())
+            .or_default()
+            .push(utxo.clone());
+    }
+    groups
+}
+
+/// Total value (ADA and tokens) of {utxos}
+pub fn summarize_utxos(utxos: &[TransactionUnspentOutput]) -> Result<Value, AssetArithmeticError> {
+    utxos.iter().try_fold(Value::zero(), |total, utxo| {
+        total.checked_add(utxo.output.amount())
+    })
+}
+
+/// An output holding less ADA than the ledger's minimum for its size (OutputTooSmallUTxO)
+#[derive(
+    Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+pub struct MinAdaViolation {
+    /// Index in the body's outputs or None for the collateral return
+    pub output_index: Option<u64>,
+    pub coin: Coin,
+    pub required: Coin,
+}
+
+/// Every output of {tx_body} (including the collateral return) holding less ADA than required
+/// for {coins_per_utxo_byte}, in output order with the collateral return last.
+pub fn check_min_ada(tx_body: &TransactionBody, coins_per_utxo_byte: Coin) -> Vec<MinAdaViolation> {
+    tx_body
+        .outputs
+        .iter()
+        .enumerate()
+        .map(|(i, output)| (Some(i as u64), output))
+        .chain(
+            tx_body
+                .collateral_return
+                .iter()
+                .map(|output| (None, output)),
+        )
+        .filter_map(|(output_index, output)| {
+            // only overflows for absurdly large outputs which couldn't be funded anyway
+            let required = min_ada_required(output, coins_per_utxo_byte).unwrap_or(Coin::MAX);
+            let coin = output.amount().coin;
+            (coin < required).then_some(MinAdaViolation {
+                output_index,
+                coin,
+                required,
+            })
+        })
+        .collect()
+}
+
+/// Result of max_send()
+#[derive(Clone, Debug)]
+pub struct MaxSendResult {
+    /// Largest sendable quantity of the requested asset, or lovelace if no asset was requested
+    pub amount: u64,
+    /// Output paying {amount} to the destination, including the ADA it needs to hold
+    pub output: TransactionOutput,
+    /// Fee of the transaction built to send {amount}
+    pub fee: Coin,
+    /// True if nothing is left for a change output i.e. the whole UTxO set goes to the destination
+    pub sweep: bool,
+}
+
+/// Largest amount of ADA (or of {asset} if given) that can be sent from {utxos} to {address}
+/// in a single transaction spending all of {utxos}, with the fee covered and any change
+/// (returned to the address of the first UTxO) holding enough ADA.
+///
+/// For ADA the destination gets everything but the fee and change, if any tokens need it.
+/// For a token the destination gets its entire quantity with the minimum ADA required, or all of
+/// the ADA as well if the rest would be too small for a change output.
+/// Only UTxOs at payment key addresses are supported.
+// TxBuilderError is large but it's what TransactionBuilder returns so it is passed through
+// unboxed like the rest of the builder API (same for the helpers below)
+#[allow(clippy::result_large_err)]
+pub fn max_send(
+    utxos: &[TransactionUnspentOutput],
+    address: &Address,
+    asset: Option<(PolicyId, AssetName)>,
+    config: &TransactionBuilderConfig,
+) -> Result<MaxSendResult, TxBuilderError> {
+    let change_address = utxos
+        .first()
+        .map(|utxo| utxo.output.address().clone())
+        .ok_or(TxBuilderError::MissingInputOrOutput)?;
+    let inputs = utxos
+        .iter()
+        .map(|utxo| SingleInputBuilder::from(utxo.clone()).payment_key())
+        .collect::<Result<Vec<_>, _>>()?;
+    let total = summarize_utxos(utxos)?;
+    let build = |amount: Value| {
+        max_send_build(&inputs, address, amount, config, &change_address).map(|body| {
+            let sweep = body.outputs.len() == 1;
+            MaxSendResult {
+                amount: 0,
+                output: body.outputs[0].clone(),
+                fee: body.fee,
+                sweep,
+            }
+        })
+    };
+    match asset {
+        Some((policy_id, asset_name)) => {
+            let quantity = total.multiasset.get(&policy_id, &asset_name).unwrap_or(0);
+            let mut sent = MultiAsset::new();
+            sent.set(policy_id, asset_name, quantity);
+            let with_min_ada = (TransactionOutputBuilder::new()
+                .with_address(address.clone())
+                .next()?
+                .with_asset_and_min_required_coin(sent.clone(), config.coins_per_utxo_byte())?
+                .build()?)
+            .output;
+            let mut result = build(with_min_ada.amount().clone())?;
+            if result.sweep {
+                // the builder burns leftover ADA too small for change so we send it instead
+                let min_coin = with_min_ada.amount().coin;
+                let coin = max_send_coin(min_coin, total.coin, |coin| {
+                    build(Value::new(coin, sent.clone())).is_ok()
+                });
+                result = build(Value::new(coin, sent))?;
+            }
+            result.amount = quantity;
+            Ok(result)
+        }
+        None => {
+            let min_coin = min_ada_required(
+                &TransactionOutput::new(address.clone(), Value::zero(), None, None),
+                config.coins_per_utxo_byte(),
+            )?;
+            // surface the builder's error if not even the minimum output can be funded
+            build(Value::from(min_coin))?;
+            // fixed point of the fee (and any change holding the tokens) to start the search near the answer
+            let change = Value::new(total.coin, total.multiasset.clone());
+            let change_reserve = if change.has_multiassets() {
+                let change_output =
+                    TransactionOutput::new(change_address.clone(), change, None, None);
+                min_ada_required(&change_output, config.coins_per_utxo_byte())?
+            } else {
+                0
+            };
+            let mut estimate = total.coin;
+            for _ in 0..4 {
+                let fee = max_send_min_fee(&inputs, address, estimate, config)?;
+                let next = total
+                    .coin
+                    .saturating_sub(fee)
+                    .saturating_sub(change_reserve)
+                    .max(min_coin);
+                if next == estimate {
+                    break;
+                }
+                estimate = next;
+            }
+            let coin = max_send_coin(min_coin, estimate, |coin| build(Value::from(coin)).is_ok());
+            let mut result = build(Value::from(coin))?;
+            result.amount = coin;
+            Ok(result)
+        }
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn max_send_build(
+    inputs: &[InputBuilderResult],
+    address: &Address,
+    amount: Value,
+    config: &TransactionBuilderConfig,
+    change_address: &Address,
+) -> Result<TransactionBody, TxBuilderError> {
+    let mut builder = TransactionBuilder::new(config.clone());
+    for input in inputs {
+        builder.add_input(input.clone())?;
+    }
+    builder.add_output(SingleOutputBuilderResult::new(TransactionOutput::new(
+        address.clone(),
+        amount,
+        None,
+        None,
+    )))?;
+    builder
+        .build(ChangeSelectionAlgo::Default, change_address)
+        .map(|signed| signed.body())
+}
+
+#[allow(clippy::result_large_err)]
+fn max_send_min_fee(
+    inputs: &[InputBuilderResult],
+    address: &Address,
+    coin: Coin,
+    config: &TransactionBuilderConfig,
+) -> Result<Coin, TxBuilderError> {
+    let mut builder = TransactionBuilder::new(config.clone());
+    for input in inputs {
+        builder.add_input(input.clone())?;
+    }
+    builder.add_output(SingleOutputBuilderResult::new(TransactionOutput::new(
+        address.clone(),
+        Value::from(coin),
+        None,
+        None,
+    )))?;
+    builder.min_fee(false)
+}
+
+/// Largest coin for which {sendable} holds, given that it holds for {lower}.
+/// Gallops from {estimate} then bisects so only a handful of builds are needed when the estimate is close.
+fn max_send_coin(lower: Coin, estimate: Coin, sendable: impl Fn(Coin) -> bool) -> Coin {
+    let (mut lo, mut hi) = if estimate > lower && sendable(estimate) {
+        let mut lo = estimate;
+        let mut step = 1u64;
+        loop {
+            let next = lo.saturating_add(step);
+            if next == lo || !sendable(next) {
+                break (lo, next);
+            }
+            lo = next;
+            step = step.saturating_mul(2);
+        }
+    } else {
+        let mut hi = estimate.max(lower + 1);
+        let mut step = 1u64;
+        loop {
+            let next = hi.saturating_sub(step).max(lower);
+            if next == lower || sendable(next) {
+                break (next, hi);
+            }
+            hi = next;
+            step = step.saturating_mul(2);
+        }
+    };
+    // invariant: sendable(lo) && !sendable(hi)
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        if sendable(mid) {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+fn datum_hash(output: &TransactionOutput) -> Option<DatumHash> {
+    match output.datum() {
+        Some(DatumOption::Hash { datum_hash, .. }) => Some(datum_hash),
+        _ => None,
+    }
+}
+
+fn witness_datum_hashes(tx: &Transaction) -> Vec<DatumHash> {
+    tx.witness_set
+        .plutus_datums
+        .as_ref()
+        .map(|datums| datums.iter().map(hash_plutus_data).collect())
+        .unwrap_or_default()
+}
+
+fn resolve<'a>(
+    resolved: &'a [TransactionUnspentOutput],
+    input: &TransactionInput,
+) -> Option<&'a TransactionOutput> {
+    resolved
+        .iter()
+        .find(|utxo| utxo.input == *input)
+        .map(|utxo| &utxo.output)
+}
+
+/// Datum hashes of the plutus script-locked outputs spent by {tx} whose datums are neither
+/// provided in the witness set, inline in the output nor inline in one of the reference inputs.
+/// This is the ledger's MissingDatum failure.
+///
+/// {resolved} must contain the UTXOs for all inputs and reference inputs.
+/// Inputs that aren't resolved are skipped, as are outputs locked by native scripts provided
+/// in the witness set or as reference scripts since those never need datums.
+pub fn missing_datums(tx: &Transaction, resolved: &[TransactionUnspentOutput]) -> Vec<DatumHash> {
+    let mut provided = witness_datum_hashes(tx);
+    let mut native_scripts: BTreeSet<ScriptHash> = tx
+        .witness_set
+        .native_scripts
+        .as_ref()
+        .map(|scripts| scripts.iter().map(NativeScript::hash).collect())
+        .unwrap_or_default();
+    for input in tx
+        .body
+        .reference_inputs
+        .iter()
+        .flat_map(|inputs| inputs.iter())
+    {
+        if let Some(Script::Native { script, .. }) =
+            resolve(resolved, input).and_then(TransactionOutput::script_ref)
+        {
+            native_scripts.insert(script.hash());
+        }
+        if let Some(DatumOption::Datum { datum, .. }) =
+            resolve(resolved, input).and_then(TransactionOutput::datum)
+        {
+            provided.push(hash_plutus_data(&datum));
+        }
+    }
+    let mut missing = Vec::new();
+    for input in tx.body.inputs.iter() {
+        if let Some(output) = resolve(resolved, input) {
+            let is_plutus_locked = match output.address().payment_cred() {
+                Some(StakeCredential::Script { hash, .. }) => !native_scripts.contains(hash),
+                _ => false,
+            };
+            if let Some(hash) = datum_hash(output).filter(|_| is_plutus_locked) {
+                if !provided.contains(&hash) && !missing.contains(&hash) {
+                    missing.push(hash);
+                }
+            }
+        }
+    }
+    missing
+}
+
+/// Datum hashes of the datums in {tx}'s witness set that the ledger won't accept.
+/// Datums are only allowed if their hash is in one of the spent outputs, the reference
+/// inputs or the tx's own outputs. This is the ledger's NotAllowedSupplementalDatums failure.
+///
+/// {resolved} must contain the UTXOs for all inputs and reference inputs.
+pub fn extraneous_datums(
+    tx: &Transaction,
+    resolved: &[TransactionUnspentOutput],
+) -> Vec<DatumHash> {
+    let allowed: BTreeSet<DatumHash> = tx
+        .body
+        .inputs
+        .iter()
+        .chain(
+            tx.body
+                .reference_inputs
+                .iter()
+                .flat_map(|inputs| inputs.iter()),
+        )
+        .filter_map(|input| resolve(resolved, input).and_then(datum_hash))
+        .chain(tx.body.outputs.iter().filter_map(datum_hash))
+        .collect();
+    witness_datum_hashes(tx)
+        .into_iter()
+        .filter(|hash| !allowed.contains(hash))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        address::{BaseAddress, EnterpriseAddress},
+        assets::AssetName,
+        byron::ByronAddress,
+        plutus::{ExUnits, LegacyRedeemer, PlutusData, RedeemerTag, Redeemers},
+        transaction::{TransactionBody, TransactionOutput, TransactionWitnessSet},
+    };
+    use crate::{
+        builders::tx_builder::TransactionBuilderConfigBuilder, fees::LinearFee,
+        plutus::ExUnitPrices, SubCoin,
+    };
+    use cml_crypto::{Ed25519KeyHash, ScriptHash, TransactionHash};
+    use quickcheck::TestResult;
+    use quickcheck_macros::quickcheck;
+
+    fn key_address() -> Address {
+        EnterpriseAddress::new(
+            1,
+            StakeCredential::new_pub_key(Ed25519KeyHash::from([1; 28])),
+        )
+        .to_address()
+    }
+
+    fn utxo(index: u64, address: Address, amount: Value) -> TransactionUnspentOutput {
+        TransactionUnspentOutput::new(
+            TransactionInput::new(TransactionHash::from([0; 32]), index),
+            TransactionOutput::new(address, amount, None, None),
+        )
+    }
+
+    fn script_tx(fee: Coin, collateral: &[TransactionUnspentOutput]) -> Transaction {
+        let mut body = TransactionBody::new(vec![].into(), vec![], fee);
+        body.collateral_inputs = Some(
+            collateral
+                .iter()
+                .map(|utxo| utxo.input.clone())
+                .collect::<Vec<_>>()
+                .into(),
+        );
+        let mut witness_set = TransactionWitnessSet::new();
+        witness_set.redeemers = Some(Redeemers::new_arr_legacy_redeemer(vec![
+            LegacyRedeemer::new(
+                RedeemerTag::Spend,
+                0,
+                PlutusData::new_integer(0u64.into()),
+                ExUnits::new(1000, 1000),
+            ),
+        ]));
+        Transaction::new(body, witness_set, true, None)
+    }
+
+    #[test]
+    fn collateral_sufficient() {
+        let collateral = [utxo(0, key_address(), Value::from(2_000_000))];
+        let tx = script_tx(1_000_001, &collateral);
+        let report = check_collateral(&tx, &collateral, 150, 3).unwrap();
+        assert_eq!(report.collateral, 2_000_000);
+        // rounds up
+        assert_eq!(report.required_collateral, 1_500_002);
+        assert_eq!(report.excess(), 499_998);
+    }
+
+    #[test]
+    fn collateral_insufficient() {
+        let collateral = [utxo(0, key_address(), Value::from(1_500_001))];
+        let tx = script_tx(1_000_001, &collateral);
+        assert!(matches!(
+            check_collateral(&tx, &collateral, 150, 3),
+            Err(CollateralError::InsufficientCollateral {
+                required: 1_500_002,
+                provided: 1_500_001,
+                shortfall: 1
+            })
+        ));
+        let no_collateral = script_tx(1_000_000, &[]);
+        assert!(matches!(
+            check_collateral(&no_collateral, &[], 150, 3),
+            Err(CollateralError::NoCollateralInputs)
+        ));
+    }
+
+    #[test]
+    fn collateral_bad_inputs() {
+        let collateral = (0..4)
+            .map(|i| utxo(i, key_address(), Value::from(1_000_000)))
+            .collect::<Vec<_>>();
+        let tx = script_tx(100_000, &collateral);
+        assert!(matches!(
+            check_collateral(&tx, &collateral, 150, 3),
+            Err(CollateralError::TooManyCollateralInputs { max: 3, found: 4 })
+        ));
+        assert!(matches!(
+            check_collateral(&tx, &collateral[1..], 150, 4),
+            Err(CollateralError::MissingCollateralUtxo(_))
+        ));
+
+        let byron = ByronAddress::from_base58(
+            "Ae2tdPwUPEZ5uzkzh1o2DHECiUi3iugvnnKHRisPgRRP3CTF4KCMvy54Xd3",
+        )
+        .unwrap()
+        .to_address();
+        let collateral = [utxo(0, byron, Value::from(1_000_000))];
+        let tx = script_tx(100_000, &collateral);
+        assert!(matches!(
+            check_collateral(&tx, &collateral, 150, 3),
+            Err(CollateralError::ByronAddress(_))
+        ));
+
+        let script =
+            EnterpriseAddress::new(1, StakeCredential::new_script(ScriptHash::from([2; 28])))
+                .to_address();
+        let collateral = [utxo(0, script, Value::from(1_000_000))];
+        let tx = script_tx(100_000, &collateral);
+        assert!(matches!(
+            check_collateral(&tx, &collateral, 150, 3),
+            Err(CollateralError::ScriptLocked(_))
+        ));
+    }
+
+    #[test]
+    fn collateral_tokens_and_total() {
+        let mut tokens = MultiAsset::new();
+        tokens.set(
+            ScriptHash::from([3; 28]),
+            AssetName::new(b"token".to_vec()).unwrap(),
+            10,
+        );
+        let collateral = [utxo(
+            0,
+            key_address(),
+            Value::new(5_000_000, tokens.clone()),
+        )];
+        let mut tx = script_tx(200_000, &collateral);
+        assert!(matches!(
+            check_collateral(&tx, &collateral, 150, 3),
+            Err(CollateralError::UnreturnedTokens(_))
+        ));
+
+        tx.body.collateral_return = Some(TransactionOutput::new(
+            key_address(),
+            Value::new(4_000_000, tokens),
+            None,
+            None,
+        ));
+        let report = check_collateral(&tx, &collateral, 150, 3).unwrap();
+        assert_eq!(report.collateral, 1_000_000);
+        assert_eq!(report.required_collateral, 300_000);
+
+        tx.body.total_collateral = Some(1_000_000);
+        assert!(check_collateral(&tx, &collateral, 150, 3).is_ok());
+        tx.body.total_collateral = Some(300_000);
+        assert!(matches!(
+            check_collateral(&tx, &collateral, 150, 3),
+            Err(CollateralError::TotalCollateralMismatch {
+                declared: 300_000,
+                actual: 1_000_000
+            })
+        ));
+    }
+
+    #[test]
+    fn utxo_grouping_and_summary() {
+        let stake = |byte| StakeCredential::new_pub_key(Ed25519KeyHash::from([byte; 28]));
+        let base = |byte| {
+            BaseAddress::new(
+                1,
+                StakeCredential::new_pub_key(Ed25519KeyHash::from([1; 28])),
+                stake(byte),
+            )
+            .to_address()
+        };
+        let byron = ByronAddress::from_base58(
+            "Ae2tdPwUPEZ5uzkzh1o2DHECiUi3iugvnnKHRisPgRRP3CTF4KCMvy54Xd3",
+        )
+        .unwrap()
+        .to_address();
+        let mut tokens = MultiAsset::new();
+        tokens.set(
+            ScriptHash::from([3; 28]),
+            AssetName::new(b"token".to_vec()).unwrap(),
+            5,
+        );
+        let utxos = vec![
+            utxo(0, base(10), Value::from(1_000_000)),
+            utxo(1, key_address(), Value::from(2_000_000)),
+            utxo(2, base(11), Value::new(3_000_000, tokens.clone())),
+            utxo(3, byron, Value::from(4_000_000)),
+            utxo(4, base(10), Value::new(5_000_000, tokens)),
+        ];
+
+        let groups = group_utxos_by_stake_credential(&utxos);
+        let group_inputs = groups
+            .iter()
+            .map(|(cred, utxos)| {
+                (
+                    cred.clone(),
+                    utxos
+                        .iter()
+                        .map(|utxo| utxo.input.index)
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            group_inputs,
+            vec![
+                (Some(stake(10)), vec![0, 4]),
+                (None, vec![1, 3]),
+                (Some(stake(11)), vec![2]),
+            ]
+        );
+
+        let total = summarize_utxos(&utxos).unwrap();
+        assert_eq!(total.coin, 15_000_000);
+        assert_eq!(
+            total.multiasset.get(
+                &ScriptHash::from([3; 28]),
+                &AssetName::new(b"token".to_vec()).unwrap()
+            ),
+            Some(10)
+        );
+        assert_eq!(summarize_utxos(&[]).unwrap(), Value::zero());
+        let overflow = [
+            utxo(0, key_address(), Value::from(u64::MAX)),
+            utxo(1, key_address(), Value::from(1)),
+        ];
+        assert!(summarize_utxos(&overflow).is_err());
+    }
+
+    #[test]
+    fn missing_and_extraneous_datums() {
+        let script_address =
+            EnterpriseAddress::new(1, StakeCredential::new_script(ScriptHash::from([9; 28])))
+                .to_address();
+        let needed = PlutusData::new_integer(1u64.into());
+        let missing = PlutusData::new_integer(2u64.into());
+        let inline = PlutusData::new_integer(3u64.into());
+        let extra = PlutusData::new_integer(4u64.into());
+        let script_utxo = |index, datum| {
+            TransactionUnspentOutput::new(
+                TransactionInput::new(TransactionHash::from([0; 32]), index),
+                TransactionOutput::new(script_address.clone(), Value::from(1), Some(datum), None),
+            )
+        };
+        let resolved = vec![
+            script_utxo(0, DatumOption::new_hash(hash_plutus_data(&needed))),
+            script_utxo(1, DatumOption::new_hash(hash_plutus_data(&missing))),
+            script_utxo(2, DatumOption::new_datum(inline)),
+            // datum hash at a key address doesn't need the datum
+            TransactionUnspentOutput::new(
+                TransactionInput::new(TransactionHash::from([0; 32]), 3),
+                TransactionOutput::new(
+                    key_address(),
+                    Value::from(1),
+                    Some(DatumOption::new_hash(hash_plutus_data(&extra))),
+                    None,
+                ),
+            ),
+        ];
+        let body = TransactionBody::new(
+            resolved
+                .iter()
+                .map(|utxo| utxo.input.clone())
+                .collect::<Vec<_>>()
+                .into(),
+            vec![],
+            0,
+        );
+        let mut witness_set = TransactionWitnessSet::new();
+        let unrelated = PlutusData::new_integer(5u64.into());
+        witness_set.plutus_datums = Some(vec![needed.clone(), extra, unrelated.clone()].into());
+        let tx = Transaction::new(body, witness_set, true, None);
+
+        assert_eq!(
+            missing_datums(&tx, &resolved),
+            vec![hash_plutus_data(&missing)]
+        );
+        assert_eq!(
+            extraneous_datums(&tx, &resolved),
+            vec![hash_plutus_data(&unrelated)]
+        );
+    }
+
+    #[test]
+    fn min_ada_boundary() {
+        let output = |coin| TransactionOutput::new(key_address(), Value::from(coin), None, None);
+        let required = min_ada_required(&output(1_000_000), 4310).unwrap();
+        let mut body = TransactionBody::new(
+            vec![].into(),
+            vec![output(required), output(required - 1)],
+            0,
+        );
+        body.collateral_return = Some(output(1));
+        assert_eq!(
+            check_min_ada(&body, 4310),
+            vec![
+                MinAdaViolation {
+                    output_index: Some(1),
+                    coin: required - 1,
+                    required,
+                },
+                MinAdaViolation {
+                    output_index: None,
+                    coin: 1,
+                    required: min_ada_required(&output(1), 4310).unwrap(),
+                },
+            ]
+        );
+        body.outputs.pop();
+        body.collateral_return = None;
+        assert!(check_min_ada(&body, 4310).is_empty());
+    }
+
+    fn max_send_config() -> TransactionBuilderConfig {
+        TransactionBuilderConfigBuilder::new()
+            .fee_algo(LinearFee::new(44, 155381, 0))
+            .pool_deposit(500000000)
+            .key_deposit(2000000)
+            .max_value_size(5000)
+            .max_tx_size(16384)
+            .coins_per_utxo_byte(4310)
+            .ex_unit_prices(ExUnitPrices::new(
+                SubCoin::new(577, 10000),
+                SubCoin::new(721, 10000000),
+            ))
+            .collateral_percentage(150)
+            .max_collateral_inputs(3)
+            .build()
+            .unwrap()
+    }
+
+    fn max_send_destination() -> Address {
+        EnterpriseAddress::new(
+            1,
+            StakeCredential::new_pub_key(Ed25519KeyHash::from([2; 28])),
+        )
+        .to_address()
+    }
+
+    fn max_send_asset() -> (PolicyId, AssetName) {
+        (
+            PolicyId::from([0; 28]),
+            AssetName::new(b"token".to_vec()).unwrap(),
+        )
+    }
+
+    fn max_send_utxos(coins: &[u32], tokens: &[u16]) -> Vec<TransactionUnspentOutput> {
+        coins
+            .iter()
+            .take(8)
+            .enumerate()
+            .map(|(i, coin)| {
+                let mut value = Value::from(1_000_000 + *coin as u64 * 8);
+                if let Some(quantity) = tokens.get(i).filter(|quantity| **quantity != 0) {
+                    let (policy_id, asset_name) = max_send_asset();
+                    // alternate between the sent asset and another one that must go to change
+                    let policy_id = if i % 2 == 0 {
+                        policy_id
+                    } else {
+                        PolicyId::from([1; 28])
+                    };
+                    value
+                        .multiasset
+                        .set(policy_id, asset_name, *quantity as u64);
+                }
+                utxo(i as u64, key_address(), value)
+            })
+            .collect()
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn send(utxos: &[TransactionUnspentOutput], amount: Value) -> Result<(), TxBuilderError> {
+        let mut builder = TransactionBuilder::new(max_send_config());
+        for utxo in utxos {
+            builder.add_input(SingleInputBuilder::from(utxo.clone()).payment_key()?)?;
+        }
+        builder.add_output(SingleOutputBuilderResult::new(TransactionOutput::new(
+            max_send_destination(),
+            amount,
+            None,
+            None,
+        )))?;
+        builder
+            .build(ChangeSelectionAlgo::Default, &key_address())
+            .map(|_| ())
+    }
+
+    #[quickcheck]
+    fn max_send_ada_is_tight(coins: Vec<u32>, tokens: Vec<u16>) -> TestResult {
+        if coins.is_empty() {
+            return TestResult::discard();
+        }
+        let utxos = max_send_utxos(&coins, &tokens);
+        let result = max_send(&utxos, &max_send_destination(), None, &max_send_config());
+        match result {
+            Ok(result) => TestResult::from_bool(
+                result.output.amount().coin == result.amount
+                    && send(&utxos, Value::from(result.amount)).is_ok()
+                    && send(&utxos, Value::from(result.amount + 1)).is_err()
+                    && result.sweep != summarize_utxos(&utxos).unwrap().has_multiassets(),
+            ),
+            // not even a minimum ADA output can be funded
+            Err(_) => TestResult::from_bool(send(&utxos, Value::from(1_000_000)).is_err()),
+        }
+    }
+
+    #[quickcheck]
+    fn max_send_token_is_tight(coins: Vec<u32>, tokens: Vec<u16>) -> TestResult {
+        let utxos = max_send_utxos(&coins, &tokens);
+        let (policy_id, asset_name) = max_send_asset();
+        let total = summarize_utxos(&utxos).unwrap();
+        if total.multiasset.get(&policy_id, &asset_name).is_none() {
+            return TestResult::discard();
+        }
+        let result = max_send(
+            &utxos,
+            &max_send_destination(),
+            Some((policy_id, asset_name.clone())),
+            &max_send_config(),
+        );
+        match result {
+            Ok(result) => {
+                let mut more = result.output.amount().clone();
+                more.multiasset
+                    .set(policy_id, asset_name.clone(), result.amount + 1);
+                TestResult::from_bool(
+                    result
+                        .output
+                        .amount()
+                        .multiasset
+                        .get(&policy_id, &asset_name)
+                        == Some(result.amount)
+                        && send(&utxos, result.output.amount().clone()).is_ok()
+                        && send(&utxos, more).is_err(),
+                )
+            }
+            Err(_) => TestResult::passed(),
+        }
+    }
+
+    #[test]
+    fn max_send_sweep() {
+        let utxos = max_send_utxos(&[1_000_000, 2_000_000], &[]);
+        let result = max_send(&utxos, &max_send_destination(), None, &max_send_config()).unwrap();
+        assert!(result.sweep);
+        assert_eq!(result.amount + result.fee, 2_000_000 + 3_000_000 * 8);
+
+        let utxos = max_send_utxos(&[1_000_000, 2_000_000], &[5]);
+        let result = max_send(
+            &utxos,
+            &max_send_destination(),
+            Some(max_send_asset()),
+            &max_send_config(),
+        )
+        .unwrap();
+        assert!(!result.sweep);
+        assert_eq!(result.amount, 5);
+        assert_eq!(
+            result.output.amount().coin,
+            min_ada_required(&result.output, 4310).unwrap()
+        );
+    }
+}
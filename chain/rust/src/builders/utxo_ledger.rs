@@ -0,0 +1,287 @@
+use std::collections::BTreeSet;
+
+use cml_core::ordered_hash_map::OrderedHashMap;
+use cml_crypto::TransactionHash;
+
+use crate::crypto::hash::hash_transaction;
+use crate::transaction::{TransactionBody, TransactionInput, TransactionOutput};
+
+use super::tx_builder::TransactionUnspentOutput;
+
+#[derive(Debug, thiserror::Error)]
+pub enum UtxoLedgerError {
+    #[error("Input {0:?} is not an available UTxO in the ledger")]
+    UnknownInput(TransactionInput),
+    #[error("Input {0:?} is reserved as collateral and can't be spent")]
+    CollateralReserved(TransactionInput),
+    #[error("Transaction {0} was already applied")]
+    AlreadyApplied(TransactionHash),
+}
+
+#[derive(Clone, Debug)]
+struct AppliedTx {
+    hash: TransactionHash,
+    spent: Vec<TransactionUnspentOutput>,
+    created: Vec<TransactionInput>,
+}
+
+/// Local view of the UTxOs available to a wallet/dApp that allows chaining transactions
+/// i.e. building a transaction spending the outputs of previous ones that haven't hit the chain yet.
+///
+/// Build each transaction with TransactionBuilder using available_utxos() then apply() its body.
+/// Applied transactions can be rolled back in reverse order with unapply() e.g. if one fails to submit.
+/// Inputs can be reserved as collateral so they are never handed out or spent by applied transactions.
+///
+/// Transactions are assumed to be phase-2 valid i.e. collateral is never consumed.
+#[derive(Clone, Debug, Default)]
+pub struct UtxoLedger {
+    utxos: OrderedHashMap<TransactionInput, TransactionOutput>,
+    reserved_collateral: BTreeSet<TransactionInput>,
+    applied: Vec<AppliedTx>,
+}
+
+impl UtxoLedger {
+    pub fn new(utxos: Vec<TransactionUnspentOutput>) -> Self {
+        let mut ledger = Self::default();
+        for utxo in utxos {
+            ledger.add_utxo(utxo);
+        }
+        ledger
+    }
+
+    /// Adds a UTxO e.g. one that was received on-chain in the meantime.
+    pub fn add_utxo(&mut self, utxo: TransactionUnspentOutput) {
+        self.utxos.insert(utxo.input, utxo.output);
+    }
+
+    pub fn get(&self, input: &TransactionInput) -> Option<&TransactionOutput> {
+        self.utxos.get(input)
+    }
+
+    /// All UTxOs that can be used as inputs i.e. excluding reserved collateral.
+    pub fn available_utxos(&self) -> Vec<TransactionUnspentOutput> {
+        self.utxos
+            .iter()
+            .filter(|(input, _)| !self.reserved_collateral.contains(*input))
+            .map(|(input, output)| TransactionUnspentOutput::new(input.clone(), output.clone()))
+            .collect()
+    }
+
+    /// Reserves {input} as collateral so it's excluded from available_utxos() and can't be spent by apply()
+    pub fn reserve_collateral(&mut self, input: TransactionInput) -> Result<(), UtxoLedgerError> {
+        if !self.utxos.contains_key(&input) {
+            return Err(UtxoLedgerError::UnknownInput(input));
+        }
+        self.reserved_collateral.insert(input);
+        Ok(())
+    }
+
+    /// Returns whether {input} was reserved as collateral.
+    pub fn release_collateral(&mut self, input: &TransactionInput) -> bool {
+        self.reserved_collateral.remove(input)
+    }
+
+    pub fn collateral_utxos(&self) -> Vec<TransactionUnspentOutput> {
+        self.reserved_collateral
+            .iter()
+            .filter_map(|input| {
+                self.utxos
+                    .get(input)
+                    .map(|output| TransactionUnspentOutput::new(input.clone(), output.clone()))
+            })
+            .collect()
+    }
+
+    /// Removes the inputs spent by {body} and adds its outputs so they can be spent by the next transaction.
+    /// Returns the hash of the applied transaction. Nothing is changed if this fails.
+    pub fn apply(&mut self, body: &TransactionBody) -> Result<TransactionHash, UtxoLedgerError> {
+        let hash = hash_transaction(body);
+        if self.applied.iter().any(|tx| tx.hash == hash) {
+            return Err(UtxoLedgerError::AlreadyApplied(hash));
+        }
+        for input in body.inputs.iter() {
+            if self.reserved_collateral.contains(input) {
+                return Err(UtxoLedgerError::CollateralReserved(input.clone()));
+            }
+            if !self.utxos.contains_key(input) {
+                return Err(UtxoLedgerError::UnknownInput(input.clone()));
+            }
+        }
+        let spent = body
+            .inputs
+            .iter()
+            .filter_map(|input| {
+                self.utxos
+                    .remove(input)
+                    .map(|output| TransactionUnspentOutput::new(input.clone(), output))
+            })
+            .collect();
+        let created = body
+            .outputs
+            .iter()
+            .enumerate()
+            .map(|(index, output)| {
+                let input = TransactionInput::new(hash, index as u64);
+                self.utxos.insert(input.clone(), output.clone());
+                input
+            })
+            .collect();
+        self.applied.push(AppliedTx {
+            hash,
+            spent,
+            created,
+        });
+        Ok(hash)
+    }
+
+    /// Rolls back the most recently applied transaction, restoring the UTxOs it spent.
+    /// Returns its hash or None if there are no applied transactions left.
+    pub fn unapply(&mut self) -> Option<TransactionHash> {
+        let applied = self.applied.pop()?;
+        for input in applied.created.iter() {
+            self.utxos.remove(input);
+        }
+        for utxo in applied.spent {
+            self.add_utxo(utxo);
+        }
+        Some(applied.hash)
+    }
+
+    /// Hashes of all applied (and not rolled back) transactions in the order they were applied.
+    pub fn pending_transactions(&self) -> Vec<TransactionHash> {
+        self.applied.iter().map(|tx| tx.hash).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::address::{Address, EnterpriseAddress};
+    use crate::builders::input_builder::SingleInputBuilder;
+    use crate::builders::output_builder::TransactionOutputBuilder;
+    use crate::builders::tx_builder::{
+        ChangeSelectionAlgo, TransactionBuilder, TransactionBuilderConfigBuilder,
+    };
+    use crate::certs::StakeCredential;
+    use crate::fees::LinearFee;
+    use crate::{ExUnitPrices, SubCoin, Value};
+    use cml_crypto::Ed25519KeyHash;
+
+    fn address(x: u8) -> Address {
+        EnterpriseAddress::new(
+            0,
+            StakeCredential::new_pub_key(Ed25519KeyHash::from([x; 28])),
+        )
+        .to_address()
+    }
+
+    fn tx_builder() -> TransactionBuilder {
+        TransactionBuilder::new(
+            TransactionBuilderConfigBuilder::default()
+                .fee_algo(LinearFee::new(44, 155381, 0))
+                .pool_deposit(500000000)
+                .key_deposit(2000000)
+                .max_value_size(5000)
+                .max_tx_size(16384)
+                .coins_per_utxo_byte(4310)
+                .ex_unit_prices(ExUnitPrices::new(SubCoin::new(0, 0), SubCoin::new(0, 0)))
+                .collateral_percentage(150)
+                .max_collateral_inputs(3)
+                .build()
+                .unwrap(),
+        )
+    }
+
+    // pays 2 ADA to {to} from all available UTxOs with change going back to address(0)
+    fn pay(ledger: &UtxoLedger, to: u8) -> TransactionBody {
+        let mut builder = tx_builder();
+        for utxo in ledger.available_utxos() {
+            builder
+                .add_input(SingleInputBuilder::from(utxo).payment_key().unwrap())
+                .unwrap();
+        }
+        builder
+            .add_output(
+                TransactionOutputBuilder::new()
+                    .with_address(address(to))
+                    .next()
+                    .unwrap()
+                    .with_value(2_000_000)
+                    .build()
+                    .unwrap(),
+            )
+            .unwrap();
+        builder
+            .build(ChangeSelectionAlgo::Default, &address(0))
+            .unwrap()
+            .body()
+    }
+
+    #[test]
+    fn chain_three_transactions() {
+        let genesis = TransactionInput::new(TransactionHash::from([1; 32]), 0);
+        let collateral = TransactionInput::new(TransactionHash::from([1; 32]), 1);
+        let mut ledger = UtxoLedger::new(vec![
+            TransactionUnspentOutput::new(
+                genesis.clone(),
+                TransactionOutput::new(address(0), Value::from(20_000_000), None, None),
+            ),
+            TransactionUnspentOutput::new(
+                collateral.clone(),
+                TransactionOutput::new(address(0), Value::from(5_000_000), None, None),
+            ),
+        ]);
+        ledger.reserve_collateral(collateral.clone()).unwrap();
+        assert_eq!(ledger.available_utxos().len(), 1);
+        assert_eq!(ledger.collateral_utxos()[0].input, collateral);
+
+        let body1 = pay(&ledger, 1);
+        let hash1 = ledger.apply(&body1).unwrap();
+        assert!(ledger.get(&genesis).is_none());
+        // second tx spends the change of the first
+        let body2 = pay(&ledger, 2);
+        assert!(body2
+            .inputs
+            .iter()
+            .all(|input| input.transaction_id == hash1));
+        let hash2 = ledger.apply(&body2).unwrap();
+        let body3 = pay(&ledger, 3);
+        assert!(body3
+            .inputs
+            .iter()
+            .all(|input| input.transaction_id == hash2));
+        let hash3 = ledger.apply(&body3).unwrap();
+        assert_eq!(ledger.pending_transactions(), vec![hash1, hash2, hash3]);
+        assert!(matches!(
+            ledger.apply(&body3),
+            Err(UtxoLedgerError::AlreadyApplied(_))
+        ));
+        // each tx spends everything available so only the last payment + change are left
+        assert_eq!(ledger.available_utxos().len(), 2);
+        assert!(ledger.get(&collateral).is_some());
+
+        // roll back the last one
+        assert_eq!(ledger.unapply(), Some(hash3));
+        assert_eq!(ledger.pending_transactions(), vec![hash1, hash2]);
+        assert!(ledger
+            .available_utxos()
+            .iter()
+            .all(|utxo| utxo.input.transaction_id != hash3));
+        assert!(body3.inputs.iter().all(|input| ledger.get(input).is_some()));
+        assert_eq!(ledger.unapply(), Some(hash2));
+        assert_eq!(ledger.unapply(), Some(hash1));
+        assert_eq!(ledger.unapply(), None);
+        assert!(ledger.get(&genesis).is_some());
+
+        // spending reserved collateral is not allowed
+        let mut spend_collateral = body1.clone();
+        spend_collateral.inputs = vec![collateral.clone()].into();
+        assert!(matches!(
+            ledger.apply(&spend_collateral),
+            Err(UtxoLedgerError::CollateralReserved(_))
+        ));
+        assert!(ledger.release_collateral(&collateral));
+        assert!(ledger.apply(&spend_collateral).is_ok());
+        assert!(ledger.get(&collateral).is_none());
+    }
+}
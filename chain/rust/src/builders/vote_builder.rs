@@ -1,4 +1,5 @@
 use crate::{
+    certs::utils::UrlError,
     crypto::hash::hash_plutus_data,
     governance::{GovActionId, Voter, VotingProcedure, VotingProcedures},
     plutus::PlutusData,
@@ -24,6 +25,8 @@ pub enum VoteBuilderError {
     VoteAlreayExists,
     #[error("Missing the following witnesses for the input: {0:?}")]
     MissingWitnesses(Box<RequiredWitnessSet>),
+    #[error("Invalid anchor URL: {0}")]
+    InvalidAnchorUrl(#[from] UrlError),
 }
 
 #[derive(Clone, Debug, Default)]
@@ -36,6 +39,7 @@ pub struct VoteBuilderResult {
 #[derive(Clone, Debug)]
 pub struct VoteBuilder {
     result: VoteBuilderResult,
+    strict_urls: bool,
 }
 
 impl Default for VoteBuilder {
@@ -48,9 +52,24 @@ impl VoteBuilder {
     pub fn new() -> Self {
         Self {
             result: VoteBuilderResult::default(),
+            strict_urls: true,
         }
     }
 
+    /// By default vote anchor URLs are checked with Url::validate_strict() when added.
+    /// This turns that check off e.g. to re-submit on-chain data that doesn't pass it.
+    pub fn skip_url_validation(mut self) -> Self {
+        self.strict_urls = false;
+        self
+    }
+
+    fn validate_urls(&self, procedure: &VotingProcedure) -> Result<(), VoteBuilderError> {
+        if let Some(anchor) = procedure.anchor.as_ref().filter(|_| self.strict_urls) {
+            anchor.anchor_url.validate_strict()?;
+        }
+        Ok(())
+    }
+
     /// Add a vote using a voter with a key hash
     /// Will throw an error if the voter is script-hash based
     pub fn with_vote(
@@ -59,6 +78,7 @@ impl VoteBuilder {
         gov_action_id: GovActionId,
         procedure: VotingProcedure,
     ) -> Result<Self, VoteBuilderError> {
+        self.validate_urls(&procedure)?;
         if let Some(key_hash) = voter.key_hash() {
             self.result.required_wits.add_vkey_key_hash(*key_hash);
         } else {
@@ -85,6 +105,7 @@ impl VoteBuilder {
         native_script: NativeScript,
         witness_info: NativeScriptWitnessInfo,
     ) -> Result<Self, VoteBuilderError> {
+        self.validate_urls(&procedure)?;
         if let Some(script_hash) = voter.script_hash() {
             if *script_hash != native_script.hash() {
                 let mut err_req_wits = RequiredWitnessSet::new();
@@ -163,6 +184,7 @@ impl VoteBuilder {
         required_signers: RequiredSigners,
         datum: Option<PlutusData>,
     ) -> Result<Self, VoteBuilderError> {
+        self.validate_urls(&procedure)?;
         let mut required_wits = required_wits_from_required_signers(&required_signers);
         if let Some(script_hash) = voter.script_hash() {
             required_wits.add_script_hash(*script_hash);
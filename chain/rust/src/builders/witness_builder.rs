@@ -12,9 +12,10 @@ use crate::{
         LegacyRedeemer, PlutusData, PlutusScript, PlutusV1Script, PlutusV2Script, PlutusV3Script,
         Redeemers,
     },
-    transaction::TransactionWitnessSet,
+    transaction::{DatumOption, TransactionWitnessSet},
     NativeScript, RequiredSigners, Script,
 };
+use cml_core::Slot;
 use cml_crypto::{
     DatumHash, Ed25519KeyHash, Ed25519Signature, PublicKey, RawBytesEncoding, ScriptHash,
 };
@@ -32,6 +33,12 @@ pub enum WitnessBuilderError {
     MissingExUnit(#[from] MissingExunitError),
     #[error("LegacyRedeemer build failed: {0}")]
     RedeemBuildFailed(#[from] RedeemerBuilderError),
+    #[error("Native script {0} can't be satisfied within the tx validity interval")]
+    UnsatisfiableNativeScript(ScriptHash),
+    #[error(
+        "Native script {0} can't be satisfied by the given signers within the tx validity interval"
+    )]
+    InsufficientNativeScriptSigners(ScriptHash),
 }
 
 #[derive(Debug, Clone)] //, Eq, Ord, PartialEq, PartialOrd)]
@@ -189,6 +196,15 @@ impl RequiredWitnessSet {
         })
     }
 
+    /// Removes the datums provided inline by any of {ref_inputs}
+    pub fn remove_ref_datums(&mut self, ref_inputs: &[TransactionUnspentOutput]) {
+        ref_inputs.iter().for_each(|utxo| {
+            if let Some(DatumOption::Datum { datum, .. }) = utxo.output.datum() {
+                self.plutus_data.remove(&hash_plutus_data(&datum));
+            }
+        })
+    }
+
     pub(crate) fn len(&self) -> usize {
         self.vkeys.len()
             + self.bootstraps.len()
@@ -218,7 +234,8 @@ impl RequiredWitnessSet {
                     NativeScriptWitnessInfo::Count(num) => {
                         self.add_fake_vkey_witnesses_by_num(*num)
                     }
-                    NativeScriptWitnessInfo::Vkeys(ref vkeys) => {
+                    NativeScriptWitnessInfo::Vkeys(ref vkeys)
+                    | NativeScriptWitnessInfo::SpecificSigners(ref vkeys) => {
                         vkeys
                             .iter()
                             .cloned()
@@ -241,6 +258,22 @@ impl RequiredWitnessSet {
         }
     }
 
+    /// Like AssumeWorst in add_input_aggregate_fake_witness_data() but only counts the signers
+    /// that can satisfy {script} given the tx validity interval (see NativeScript::signer_requirement_within)
+    pub(crate) fn add_native_script_fake_witnesses_within(
+        &mut self,
+        script: &NativeScript,
+        validity_start: Option<Slot>,
+        ttl: Option<Slot>,
+    ) {
+        let num = match script.signer_requirement_within(validity_start, ttl) {
+            Some(requirement) => requirement.possible_signers.len(),
+            // the tx would be invalid anyway but the interval might not be final yet
+            None => script.get_required_signers().len(),
+        };
+        self.add_fake_vkey_witnesses_by_num(num);
+    }
+
     pub fn new() -> Self {
         // have to expose new so it's visible in WASM
         Self::default()
@@ -425,8 +458,11 @@ impl TransactionWitnessSetBuilder {
         data: &InputAggregateWitnessData,
     ) {
         match data {
-            InputAggregateWitnessData::NativeScript(script, _info) => {
+            InputAggregateWitnessData::NativeScript(script, info) => {
                 self.add_script(script.clone().into());
+                if let NativeScriptWitnessInfo::SpecificSigners(signers) = info {
+                    self.required_wits.vkeys.extend(signers.iter().cloned());
+                }
             }
             InputAggregateWitnessData::PlutusScript(witness, _info, option) => {
                 match &witness.script {
@@ -514,6 +550,8 @@ impl TransactionWitnessSetBuilder {
         remaining_wits
     }
 
+    // WitnessBuilderError is large as MissingWitnesses reports the whole RequiredWitnessSet
+    #[allow(clippy::result_large_err)]
     pub fn try_build(&self) -> Result<TransactionWitnessSet, WitnessBuilderError> {
         let remaining_wits = self.remaining_wits();
 
@@ -523,6 +561,54 @@ impl TransactionWitnessSetBuilder {
 
         Ok(self.clone().build())
     }
+
+    /// remaining_wits() plus the vkeys still needed by the native scripts in this witness set
+    /// for a tx with the given validity interval. When a script can be satisfied by different
+    /// sets of signers the branches needing the fewest additional signatures are reported
+    /// (see NativeScript::missing_signers_within), counting vkeys that are already required
+    /// (e.g. NativeScriptWitnessInfo::SpecificSigners) as present.
+    #[allow(clippy::result_large_err)]
+    pub fn remaining_wits_within(
+        &self,
+        validity_start: Option<Slot>,
+        ttl: Option<Slot>,
+    ) -> Result<RequiredWitnessSet, WitnessBuilderError> {
+        let mut remaining_wits = self.remaining_wits();
+        let signers = self
+            .vkeys
+            .keys()
+            .map(|vkey| vkey.hash())
+            .collect::<BTreeSet<_>>();
+        for script in self.get_native_script() {
+            let present = signers
+                .iter()
+                .chain(remaining_wits.vkeys.iter())
+                .cloned()
+                .collect::<BTreeSet<_>>();
+            let missing = script
+                .missing_signers_within(validity_start, ttl, &present)
+                .ok_or_else(|| WitnessBuilderError::UnsatisfiableNativeScript(script.hash()))?;
+            remaining_wits.vkeys.extend(missing);
+        }
+        Ok(remaining_wits)
+    }
+
+    /// try_build() that also checks the native scripts can be satisfied by the added vkeys
+    /// for a tx with the given validity interval
+    #[allow(clippy::result_large_err)]
+    pub fn try_build_within(
+        &self,
+        validity_start: Option<Slot>,
+        ttl: Option<Slot>,
+    ) -> Result<TransactionWitnessSet, WitnessBuilderError> {
+        let remaining_wits = self.remaining_wits_within(validity_start, ttl)?;
+
+        if remaining_wits.len() > 0 {
+            return Err(WitnessBuilderError::MissingWitnesses(remaining_wits));
+        }
+
+        Ok(self.clone().build())
+    }
 }
 
 pub fn merge_fake_witness(
@@ -602,6 +688,7 @@ pub enum NativeScriptWitnessInfo {
     Count(usize),
     Vkeys(Vec<Ed25519KeyHash>),
     AssumeWorst,
+    SpecificSigners(Vec<Ed25519KeyHash>),
 }
 
 impl NativeScriptWitnessInfo {
@@ -619,6 +706,35 @@ impl NativeScriptWitnessInfo {
     pub fn assume_signature_count() -> Self {
         NativeScriptWitnessInfo::AssumeWorst
     }
+
+    /// Exactly these keys will sign e.g. the known co-signers of an N-of-K multisig.
+    /// Unlike vkeys() they become required witnesses and building the tx fails if they
+    /// can't satisfy the script within the tx validity interval (see check_native_script_signers())
+    pub fn specific_signers(signers: Vec<Ed25519KeyHash>) -> Self {
+        NativeScriptWitnessInfo::SpecificSigners(signers)
+    }
+}
+
+/// Checks that signatures by {signers} satisfy {script} for a tx with the given validity interval
+// same (large) WitnessBuilderError as TransactionWitnessSetBuilder::try_build_within()
+#[allow(clippy::result_large_err)]
+pub fn check_native_script_signers(
+    script: &NativeScript,
+    signers: &[Ed25519KeyHash],
+    validity_start: Option<Slot>,
+    ttl: Option<Slot>,
+) -> Result<(), WitnessBuilderError> {
+    let signers = signers.iter().cloned().collect::<BTreeSet<_>>();
+    let missing = script
+        .missing_signers_within(validity_start, ttl, &signers)
+        .ok_or_else(|| WitnessBuilderError::UnsatisfiableNativeScript(script.hash()))?;
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(WitnessBuilderError::InsufficientNativeScriptSigners(
+            script.hash(),
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -812,6 +928,128 @@ mod tests {
         assert!(builder.try_build().is_ok());
     }
 
+    #[test]
+    fn native_script_requirements_within() {
+        let key = |id: u8| fake_raw_key_public(id).hash();
+        let sig = |id: u8| NativeScript::new_script_pubkey(key(id));
+        // all [any [sig 0, all [sig 1, after 200]], atLeast 2 [sig 2, sig 3, before 100]]
+        let script = NativeScript::new_script_all(vec![
+            NativeScript::new_script_any(vec![
+                sig(0),
+                NativeScript::new_script_all(vec![
+                    sig(1),
+                    NativeScript::new_script_invalid_before(200),
+                ]),
+            ]),
+            NativeScript::new_script_n_of_k(
+                2,
+                vec![
+                    sig(2),
+                    sig(3),
+                    NativeScript::new_script_invalid_hereafter(100),
+                ],
+            ),
+        ]);
+
+        // sig 1 can't help before slot 200
+        let mut fake_wits = RequiredWitnessSet::new();
+        fake_wits.add_native_script_fake_witnesses_within(&script, None, Some(50));
+        assert_eq!(fake_wits.vkeys.len(), 3);
+        let mut fake_wits = RequiredWitnessSet::new();
+        fake_wits.add_native_script_fake_witnesses_within(&script, Some(250), Some(50));
+        assert_eq!(fake_wits.vkeys.len(), 4);
+
+        let mut builder = TransactionWitnessSetBuilder::new();
+        builder.add_script(script.into());
+        // either sig 2 or sig 3 is enough inside the window
+        let remaining = builder.remaining_wits_within(None, Some(50)).unwrap();
+        assert_eq!(remaining.vkeys.len(), 2);
+        assert!(remaining.vkeys.contains(&key(0)));
+
+        builder.add_vkey(Vkeywitness::new(
+            fake_raw_key_public(0),
+            fake_raw_key_sig(0),
+        ));
+        builder.add_vkey(Vkeywitness::new(
+            fake_raw_key_public(2),
+            fake_raw_key_sig(2),
+        ));
+        assert!(builder.try_build_within(None, Some(50)).is_ok());
+        // but both are needed once the ttl is past the time branch
+        match builder.try_build_within(None, Some(150)) {
+            Err(WitnessBuilderError::MissingWitnesses(remaining)) => {
+                assert_eq!(remaining.vkeys, std::iter::once(key(3)).collect())
+            }
+            res => panic!("expected missing witnesses, got {:?}", res),
+        }
+
+        builder.add_script(
+            NativeScript::new_script_all(vec![
+                sig(0),
+                NativeScript::new_script_invalid_before(200),
+            ])
+            .into(),
+        );
+        assert!(matches!(
+            builder.try_build_within(Some(150), Some(50)),
+            Err(WitnessBuilderError::UnsatisfiableNativeScript(_))
+        ));
+    }
+
+    #[test]
+    fn native_script_specific_signers() {
+        let key = |id: u8| fake_raw_key_public(id).hash();
+        // atLeast 2 [sig 0, sig 1, sig 2]
+        let script = NativeScript::new_script_n_of_k(
+            2,
+            vec![
+                NativeScript::new_script_pubkey(key(0)),
+                NativeScript::new_script_pubkey(key(1)),
+                NativeScript::new_script_pubkey(key(2)),
+            ],
+        );
+        let data = InputAggregateWitnessData::NativeScript(
+            script.clone(),
+            NativeScriptWitnessInfo::specific_signers(vec![key(0), key(2)]),
+        );
+
+        let mut fake_wits = RequiredWitnessSet::new();
+        fake_wits.add_input_aggregate_fake_witness_data(&data);
+        assert_eq!(fake_wits.vkeys, vec![key(0), key(2)].into_iter().collect());
+
+        let mut builder = TransactionWitnessSetBuilder::new();
+        builder.add_input_aggregate_real_witness_data(&data);
+        assert_eq!(
+            builder.remaining_wits().vkeys,
+            vec![key(0), key(2)].into_iter().collect()
+        );
+
+        assert!(check_native_script_signers(&script, &[key(0), key(2)], None, None).is_ok());
+        assert!(matches!(
+            check_native_script_signers(&script, &[key(1)], None, None),
+            Err(WitnessBuilderError::InsufficientNativeScriptSigners(_))
+        ));
+
+        // the second signer only counts before slot 100
+        let timed = NativeScript::new_script_n_of_k(
+            2,
+            vec![
+                NativeScript::new_script_pubkey(key(0)),
+                NativeScript::new_script_all(vec![
+                    NativeScript::new_script_pubkey(key(1)),
+                    NativeScript::new_script_invalid_hereafter(100),
+                ]),
+                NativeScript::new_script_pubkey(key(2)),
+            ],
+        );
+        let signers = [key(0), key(1)];
+        assert!(check_native_script_signers(&timed, &signers, None, Some(50)).is_ok());
+        assert!(matches!(
+            check_native_script_signers(&timed, &signers, None, Some(150)),
+            Err(WitnessBuilderError::InsufficientNativeScriptSigners(_))
+        ));
+    }
+
     #[test]
     fn tx_witness_set_roundtrip_test() {
         let data = "a102818458205e8379f58f0838234af67f73738f0fee0d8185232e200b8e42887f4f06544a9a5840f5cfea560d2f8645ed624b65bf08cf83346eb5168ee4df0f63ce2d0d5f677db88fef2d5d9f032f09223889b5e85504ab44dd0a0cde1f1fd8f57deefde8c2080658202d3b7d9b806f88f10f1193e94ef97e5c02370c1464f61a30a8f1ac1a46115b2d5829a201581e581c072931653330243cf126aea85d39e73c6bd04601fe77424efb9e371002451a4170cb17";
@@ -1,5 +1,6 @@
 use super::*;
 
+use cml_core::serialization::StripEncodings;
 use cml_core::{error::DeserializeError, serialization::Deserialize};
 
 const CRC_TABLE: [u32; 256] = [
@@ -277,6 +278,7 @@ const CRC_TABLE: [u32; 256] = [
     serde::Serialize,
     schemars::JsonSchema,
     Copy,
+    StripEncodings,
 )]
 pub struct Crc32(u32);
 
@@ -3,6 +3,7 @@ use noop_proc_macro::wasm_bindgen;
 #[cfg(feature = "used_from_wasm")]
 use wasm_bindgen::prelude::wasm_bindgen;
 
+use cml_core::serialization::StripEncodings;
 use std::io::{BufRead, Write};
 
 use cml_crypto::{chain_crypto::hash::Blake2b224, Bip32PublicKey, PublicKey};
@@ -17,8 +18,9 @@ use cbor_event::{self, de::Deserializer, se::Serializer};
 pub use self::crc32::Crc32;
 pub use cml_core::network::ProtocolMagic;
 pub use utils::{
-    make_daedalus_bootstrap_witness, make_icarus_bootstrap_witness, AddressId, ByronAddressError,
-    ByronScript, ParseExtendedAddrError, StakeholderId,
+    make_daedalus_bootstrap_witness, make_icarus_bootstrap_witness, make_redeem_signature,
+    verify_redeem_signature, AddressId, ByronAddressError, ByronScript, ParseExtendedAddrError,
+    StakeholderId,
 };
 
 mod base58;
@@ -42,6 +44,7 @@ mod utils;
     serde::Deserialize,
     serde::Serialize,
     schemars::JsonSchema,
+    StripEncodings,
 )]
 pub struct AddrAttributes {
     pub stake_distribution: Option<StakeDistribution>,
@@ -77,6 +80,7 @@ impl Default for AddrAttributes {
     serde::Deserialize,
     serde::Serialize,
     schemars::JsonSchema,
+    StripEncodings,
 )]
 #[wasm_bindgen]
 pub enum ByronAddrType {
@@ -96,6 +100,7 @@ pub enum ByronAddrType {
     serde::Deserialize,
     serde::Serialize,
     schemars::JsonSchema,
+    StripEncodings,
 )]
 pub struct AddressContent {
     pub address_id: AddressId,
@@ -117,7 +122,7 @@ impl AddressContent {
     }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, StripEncodings)]
 pub struct ByronAddress {
     pub content: AddressContent,
     pub crc: Crc32,
@@ -132,7 +137,9 @@ impl ByronAddress {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ByronTxOut {
     pub address: ByronAddress,
     pub amount: Coin,
@@ -155,6 +162,7 @@ impl ByronTxOut {
     serde::Deserialize,
     serde::Serialize,
     schemars::JsonSchema,
+    StripEncodings,
 )]
 pub struct HDAddressPayload(pub Vec<u8>);
 
@@ -180,7 +188,9 @@ impl From<HDAddressPayload> for Vec<u8> {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub enum SpendingData {
     SpendingDataPubKey(Bip32PublicKey),
     SpendingDataScript(ByronScript),
@@ -212,6 +222,7 @@ impl SpendingData {
     serde::Deserialize,
     serde::Serialize,
     schemars::JsonSchema,
+    StripEncodings,
 )]
 pub enum StakeDistribution {
     SingleKey(StakeholderId),
@@ -11,9 +11,10 @@ use cml_core::{
     serialization::{Deserialize, ToBytes},
 };
 use cml_crypto::{
-    chain_crypto::{self, Sha3_256},
+    blake2b256,
+    chain_crypto::{self, byron_tags::SigningTag, Sha3_256},
     impl_hash_type, Bip32PrivateKey, Bip32PublicKey, CryptoError, Ed25519Signature,
-    LegacyDaedalusPrivateKey, PublicKey, RawBytesEncoding, TransactionHash,
+    LegacyDaedalusPrivateKey, PrivateKey, PublicKey, RawBytesEncoding, TransactionHash,
 };
 use std::{convert::TryFrom, fmt};
 
@@ -216,6 +217,18 @@ impl ByronAddress {
         self.into()
     }
 
+    /// AVVM (Ada Voucher Vending Machine) redeem address for {pubkey}
+    /// These were only created in the Byron genesis and can only be spent by a redemption tx.
+    pub fn new_redeem(pubkey: &PublicKey, protocol_magic: ProtocolMagic) -> Self {
+        AddressContent::new_redeem(pubkey.clone(), Some(protocol_magic)).into()
+    }
+
+    /// Transaction hash of the UTxO created for this address by the Byron genesis
+    /// i.e. the one a redemption tx spends (always at index 0)
+    pub fn genesis_tx_hash(&self) -> TransactionHash {
+        blake2b256(&self.to_bytes()).into()
+    }
+
     pub fn from_address(addr: &Address) -> Option<Self> {
         match addr {
             Address::Byron(byron) => Some(byron.clone()),
@@ -292,6 +305,36 @@ pub fn make_icarus_bootstrap_witness(
     BootstrapWitness::new(vkey, signature, chain_code, addr.content.addr_attributes).unwrap()
 }
 
+// tag || cbor(protocol_magic) || cbor(tx_hash) as signed by Byron redeem witnesses
+fn redeem_data_to_sign(tx_hash: &TransactionHash, protocol_magic: ProtocolMagic) -> Vec<u8> {
+    let mut buf = vec![SigningTag::RedeemTx as u8];
+    cbor_event::se::Serializer::new(&mut buf)
+        .serialize(&protocol_magic)
+        .unwrap()
+        .write_bytes(tx_hash.to_raw_bytes())
+        .unwrap();
+    buf
+}
+
+/// Signs the Byron tx {tx_hash} with the redeem (AVVM) key {key} for a redemption tx witness
+pub fn make_redeem_signature(
+    tx_hash: &TransactionHash,
+    key: &PrivateKey,
+    protocol_magic: ProtocolMagic,
+) -> Ed25519Signature {
+    key.sign(&redeem_data_to_sign(tx_hash, protocol_magic))
+}
+
+/// Verifies a Byron redeem witness signature over the redemption tx {tx_hash}
+pub fn verify_redeem_signature(
+    tx_hash: &TransactionHash,
+    pubkey: &PublicKey,
+    signature: &Ed25519Signature,
+    protocol_magic: ProtocolMagic,
+) -> bool {
+    pubkey.verify(&redeem_data_to_sign(tx_hash, protocol_magic), signature)
+}
+
 impl serde::Serialize for ByronAddress {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -563,4 +606,46 @@ mod tests {
             "Ae2tdPwUPEZ3MHKkpT5Bpj549vrRH7nBqYjNXnCV8G2Bc2YxNcGHEa8ykDp"
         );
     }
+
+    #[test]
+    fn redeem_signature() {
+        use super::{make_redeem_signature, verify_redeem_signature, ByronAddrType};
+        use cml_crypto::{PrivateKey, RawBytesEncoding, TransactionHash};
+
+        let key = PrivateKey::from_normal_bytes(&[7; 32]).unwrap();
+        let mainnet = NetworkInfo::mainnet().protocol_magic();
+        let addr = ByronAddress::new_redeem(&key.to_public(), mainnet);
+        assert_eq!(addr.content.addr_type, ByronAddrType::Redeem);
+        // mainnet magic is omitted from the address
+        assert_eq!(addr.content.addr_attributes.protocol_magic, None);
+        assert_eq!(
+            ByronAddress::from_base58(&addr.to_base58())
+                .unwrap()
+                .to_bytes(),
+            addr.to_bytes()
+        );
+
+        let tx_hash = TransactionHash::from([1; 32]);
+        let sig = make_redeem_signature(&tx_hash, &key, mainnet);
+        assert!(verify_redeem_signature(
+            &tx_hash,
+            &key.to_public(),
+            &sig,
+            mainnet
+        ));
+        assert!(!verify_redeem_signature(
+            &TransactionHash::from([2; 32]),
+            &key.to_public(),
+            &sig,
+            mainnet
+        ));
+        assert!(!verify_redeem_signature(
+            &tx_hash,
+            &key.to_public(),
+            &sig,
+            NetworkInfo::testnet().protocol_magic()
+        ));
+        // the redeem signature is domain separated from a plain tx hash signature
+        assert!(!key.to_public().verify(tx_hash.to_raw_bytes(), &sig));
+    }
 }
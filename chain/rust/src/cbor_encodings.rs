@@ -73,6 +73,9 @@ pub struct ProtocolParamUpdateEncoding {
 pub struct RationalEncoding {
     pub len_encoding: LenEncoding,
     pub tag_encoding: Option<cbor_event::Sz>,
+    /// Read without the tag 30 the CDDL requires (as in some historical protocol updates).
+    /// Kept when re-serializing non-canonically, canonical serialization always writes the tag.
+    pub untagged: bool,
     pub numerator_encoding: Option<cbor_event::Sz>,
     pub denominator_encoding: Option<cbor_event::Sz>,
 }
@@ -81,6 +84,9 @@ pub struct RationalEncoding {
 pub struct UnitIntervalEncoding {
     pub len_encoding: LenEncoding,
     pub tag_encoding: Option<cbor_event::Sz>,
+    /// Read without the tag 30 the CDDL requires (as in some historical protocol updates).
+    /// Kept when re-serializing non-canonically, canonical serialization always writes the tag.
+    pub untagged: bool,
     pub start_encoding: Option<cbor_event::Sz>,
     pub end_encoding: Option<cbor_event::Sz>,
 }
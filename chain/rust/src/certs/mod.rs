@@ -21,12 +21,14 @@ use cbor_encodings::{
 };
 use cml_core::error::*;
 
-use cml_core::serialization::{LenEncoding, StringEncoding};
+use cml_core::serialization::{LenEncoding, StringEncoding, StripEncodings};
 
 use std::convert::TryFrom;
 
 #[allow(clippy::large_enum_variant)]
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct AuthCommitteeHotCert {
     pub committee_cold_credential: CommitteeColdCredential,
     pub committee_hot_credential: CommitteeHotCredential,
@@ -48,7 +50,9 @@ impl AuthCommitteeHotCert {
 }
 
 #[allow(clippy::large_enum_variant)]
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub enum Certificate {
     /// Will be deprecated in the next era. Use RegCert instead which takes an explicit deposit amount, as that can change.
     StakeRegistration(StakeRegistration),
@@ -202,67 +206,37 @@ pub type CommitteeColdCredential = Credential;
 pub type CommitteeHotCredential = Credential;
 
 #[derive(
-    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, derivative::Derivative,
-)]
-#[derivative(
-    Eq,
-    PartialEq,
-    Ord = "feature_allow_slow_enum",
-    PartialOrd = "feature_allow_slow_enum",
-    Hash
+    Clone,
+    Debug,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    derivative::Derivative,
+    StripEncodings,
 )]
+#[derivative(Eq, PartialEq, Hash)]
 pub enum Credential {
     PubKey {
         hash: Ed25519KeyHash,
-        #[derivative(
-            PartialEq = "ignore",
-            Ord = "ignore",
-            PartialOrd = "ignore",
-            Hash = "ignore"
-        )]
+        #[derivative(PartialEq = "ignore", Hash = "ignore")]
         #[serde(skip)]
         len_encoding: LenEncoding,
-        #[derivative(
-            PartialEq = "ignore",
-            Ord = "ignore",
-            PartialOrd = "ignore",
-            Hash = "ignore"
-        )]
+        #[derivative(PartialEq = "ignore", Hash = "ignore")]
         #[serde(skip)]
         tag_encoding: Option<cbor_event::Sz>,
-        #[derivative(
-            PartialEq = "ignore",
-            Ord = "ignore",
-            PartialOrd = "ignore",
-            Hash = "ignore"
-        )]
+        #[derivative(PartialEq = "ignore", Hash = "ignore")]
         #[serde(skip)]
         hash_encoding: StringEncoding,
     },
     Script {
         hash: ScriptHash,
-        #[derivative(
-            PartialEq = "ignore",
-            Ord = "ignore",
-            PartialOrd = "ignore",
-            Hash = "ignore"
-        )]
+        #[derivative(PartialEq = "ignore", Hash = "ignore")]
         #[serde(skip)]
         len_encoding: LenEncoding,
-        #[derivative(
-            PartialEq = "ignore",
-            Ord = "ignore",
-            PartialOrd = "ignore",
-            Hash = "ignore"
-        )]
+        #[derivative(PartialEq = "ignore", Hash = "ignore")]
         #[serde(skip)]
         tag_encoding: Option<cbor_event::Sz>,
-        #[derivative(
-            PartialEq = "ignore",
-            Ord = "ignore",
-            PartialOrd = "ignore",
-            Hash = "ignore"
-        )]
+        #[derivative(PartialEq = "ignore", Hash = "ignore")]
         #[serde(skip)]
         hash_encoding: StringEncoding,
     },
@@ -288,9 +262,16 @@ impl Credential {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, derivative::Derivative, StripEncodings)]
+#[derivative(Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct DNSName {
     pub inner: String,
+    #[derivative(
+        PartialEq = "ignore",
+        Ord = "ignore",
+        PartialOrd = "ignore",
+        Hash = "ignore"
+    )]
     pub encodings: Option<DNSNameEncoding>,
 }
 
@@ -360,36 +341,113 @@ impl schemars::JsonSchema for DNSName {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+/// Ordered by variant (in declaration order) then hash. This is only meant for use as a map
+/// key (e.g. stake distributions) and isn't the ledger's ordering. Encodings are ignored.
+#[derive(
+    Clone,
+    Debug,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    derivative::Derivative,
+    StripEncodings,
+)]
+#[derivative(
+    Eq,
+    PartialEq,
+    Ord = "feature_allow_slow_enum",
+    PartialOrd = "feature_allow_slow_enum",
+    Hash
+)]
 pub enum DRep {
     Key {
         pool: Ed25519KeyHash,
         #[serde(skip)]
+        #[derivative(
+            PartialEq = "ignore",
+            Ord = "ignore",
+            PartialOrd = "ignore",
+            Hash = "ignore"
+        )]
         len_encoding: LenEncoding,
         #[serde(skip)]
+        #[derivative(
+            PartialEq = "ignore",
+            Ord = "ignore",
+            PartialOrd = "ignore",
+            Hash = "ignore"
+        )]
         index_0_encoding: Option<cbor_event::Sz>,
         #[serde(skip)]
+        #[derivative(
+            PartialEq = "ignore",
+            Ord = "ignore",
+            PartialOrd = "ignore",
+            Hash = "ignore"
+        )]
         pool_encoding: StringEncoding,
     },
     Script {
         script_hash: ScriptHash,
         #[serde(skip)]
+        #[derivative(
+            PartialEq = "ignore",
+            Ord = "ignore",
+            PartialOrd = "ignore",
+            Hash = "ignore"
+        )]
         len_encoding: LenEncoding,
         #[serde(skip)]
+        #[derivative(
+            PartialEq = "ignore",
+            Ord = "ignore",
+            PartialOrd = "ignore",
+            Hash = "ignore"
+        )]
         index_0_encoding: Option<cbor_event::Sz>,
         #[serde(skip)]
+        #[derivative(
+            PartialEq = "ignore",
+            Ord = "ignore",
+            PartialOrd = "ignore",
+            Hash = "ignore"
+        )]
         script_hash_encoding: StringEncoding,
     },
     AlwaysAbstain {
         #[serde(skip)]
+        #[derivative(
+            PartialEq = "ignore",
+            Ord = "ignore",
+            PartialOrd = "ignore",
+            Hash = "ignore"
+        )]
         always_abstain_encoding: Option<cbor_event::Sz>,
         #[serde(skip)]
+        #[derivative(
+            PartialEq = "ignore",
+            Ord = "ignore",
+            PartialOrd = "ignore",
+            Hash = "ignore"
+        )]
         len_encoding: LenEncoding,
     },
     AlwaysNoConfidence {
         #[serde(skip)]
+        #[derivative(
+            PartialEq = "ignore",
+            Ord = "ignore",
+            PartialOrd = "ignore",
+            Hash = "ignore"
+        )]
         always_no_confidence_encoding: Option<cbor_event::Sz>,
         #[serde(skip)]
+        #[derivative(
+            PartialEq = "ignore",
+            Ord = "ignore",
+            PartialOrd = "ignore",
+            Hash = "ignore"
+        )]
         len_encoding: LenEncoding,
     },
 }
@@ -430,9 +488,16 @@ impl DRep {
 
 pub type DrepCredential = Credential;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, derivative::Derivative, StripEncodings)]
+#[derivative(Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Ipv4 {
     pub inner: Vec<u8>,
+    #[derivative(
+        PartialEq = "ignore",
+        Ord = "ignore",
+        PartialOrd = "ignore",
+        Hash = "ignore"
+    )]
     pub encodings: Option<Ipv4Encoding>,
 }
 
@@ -473,9 +538,16 @@ impl From<Ipv4> for Vec<u8> {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, derivative::Derivative, StripEncodings)]
+#[derivative(Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Ipv6 {
     pub inner: Vec<u8>,
+    #[derivative(
+        PartialEq = "ignore",
+        Ord = "ignore",
+        PartialOrd = "ignore",
+        Hash = "ignore"
+    )]
     pub encodings: Option<Ipv6Encoding>,
 }
 
@@ -516,11 +588,26 @@ impl From<Ipv6> for Vec<u8> {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone,
+    Debug,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    derivative::Derivative,
+    StripEncodings,
+)]
+#[derivative(Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct MultiHostName {
     /// A SRV DNS record
     pub dns_name: DNSName,
     #[serde(skip)]
+    #[derivative(
+        PartialEq = "ignore",
+        Ord = "ignore",
+        PartialOrd = "ignore",
+        Hash = "ignore"
+    )]
     pub encodings: Option<MultiHostNameEncoding>,
 }
 
@@ -534,11 +621,26 @@ impl MultiHostName {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone,
+    Debug,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    derivative::Derivative,
+    StripEncodings,
+)]
+#[derivative(Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct PoolMetadata {
     pub url: Url,
     pub pool_metadata_hash: PoolMetadataHash,
     #[serde(skip)]
+    #[derivative(
+        PartialEq = "ignore",
+        Ord = "ignore",
+        PartialOrd = "ignore",
+        Hash = "ignore"
+    )]
     pub encodings: Option<PoolMetadataEncoding>,
 }
 
@@ -552,7 +654,9 @@ impl PoolMetadata {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct PoolParams {
     pub operator: Ed25519KeyHash,
     pub vrf_keyhash: VRFKeyHash,
@@ -595,7 +699,9 @@ impl PoolParams {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct PoolRegistration {
     pub pool_params: PoolParams,
     #[serde(skip)]
@@ -611,7 +717,9 @@ impl PoolRegistration {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct PoolRetirement {
     pub pool: Ed25519KeyHash,
     pub epoch: Epoch,
@@ -629,7 +737,9 @@ impl PoolRetirement {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct RegCert {
     pub stake_credential: StakeCredential,
     pub deposit: Coin,
@@ -647,7 +757,9 @@ impl RegCert {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct RegDrepCert {
     pub drep_credential: DrepCredential,
     pub deposit: Coin,
@@ -667,7 +779,22 @@ impl RegDrepCert {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone,
+    Debug,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    derivative::Derivative,
+    StripEncodings,
+)]
+#[derivative(
+    Eq,
+    PartialEq,
+    Ord = "feature_allow_slow_enum",
+    PartialOrd = "feature_allow_slow_enum",
+    Hash
+)]
 pub enum Relay {
     SingleHostAddr(SingleHostAddr),
     SingleHostName(SingleHostName),
@@ -692,7 +819,9 @@ impl Relay {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ResignCommitteeColdCert {
     pub committee_cold_credential: CommitteeColdCredential,
     pub anchor: Option<Anchor>,
@@ -710,12 +839,27 @@ impl ResignCommitteeColdCert {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone,
+    Debug,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    derivative::Derivative,
+    StripEncodings,
+)]
+#[derivative(Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct SingleHostAddr {
     pub port: Option<Port>,
     pub ipv4: Option<Ipv4>,
     pub ipv6: Option<Ipv6>,
     #[serde(skip)]
+    #[derivative(
+        PartialEq = "ignore",
+        Ord = "ignore",
+        PartialOrd = "ignore",
+        Hash = "ignore"
+    )]
     pub encodings: Option<SingleHostAddrEncoding>,
 }
 
@@ -730,12 +874,27 @@ impl SingleHostAddr {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone,
+    Debug,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    derivative::Derivative,
+    StripEncodings,
+)]
+#[derivative(Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct SingleHostName {
     pub port: Option<Port>,
     /// An A or AAAA DNS record
     pub dns_name: DNSName,
     #[serde(skip)]
+    #[derivative(
+        PartialEq = "ignore",
+        Ord = "ignore",
+        PartialOrd = "ignore",
+        Hash = "ignore"
+    )]
     pub encodings: Option<SingleHostNameEncoding>,
 }
 
@@ -752,7 +911,9 @@ impl SingleHostName {
 
 pub type StakeCredential = Credential;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct StakeDelegation {
     pub stake_credential: StakeCredential,
     pub pool: Ed25519KeyHash,
@@ -770,7 +931,9 @@ impl StakeDelegation {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct StakeDeregistration {
     pub stake_credential: StakeCredential,
     #[serde(skip)]
@@ -786,7 +949,9 @@ impl StakeDeregistration {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct StakeRegDelegCert {
     pub stake_credential: StakeCredential,
     pub pool: Ed25519KeyHash,
@@ -806,7 +971,9 @@ impl StakeRegDelegCert {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct StakeRegistration {
     pub stake_credential: StakeCredential,
     #[serde(skip)]
@@ -822,7 +989,9 @@ impl StakeRegistration {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct StakeVoteDelegCert {
     pub stake_credential: StakeCredential,
     pub pool: Ed25519KeyHash,
@@ -842,7 +1011,9 @@ impl StakeVoteDelegCert {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct StakeVoteRegDelegCert {
     pub stake_credential: StakeCredential,
     pub pool: Ed25519KeyHash,
@@ -881,7 +1052,9 @@ impl From<Url> for String {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct UnregCert {
     pub stake_credential: StakeCredential,
     pub deposit: Coin,
@@ -899,7 +1072,9 @@ impl UnregCert {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct UnregDrepCert {
     pub drep_credential: DrepCredential,
     pub deposit: Coin,
@@ -917,7 +1092,9 @@ impl UnregDrepCert {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct UpdateDrepCert {
     pub drep_credential: DrepCredential,
     pub anchor: Option<Anchor>,
@@ -935,9 +1112,16 @@ impl UpdateDrepCert {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, derivative::Derivative, StripEncodings)]
+#[derivative(Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Url {
     pub inner: String,
+    #[derivative(
+        PartialEq = "ignore",
+        Ord = "ignore",
+        PartialOrd = "ignore",
+        Hash = "ignore"
+    )]
     pub encodings: Option<UrlEncoding>,
 }
 
@@ -1007,7 +1191,9 @@ impl schemars::JsonSchema for Url {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct VoteDelegCert {
     pub stake_credential: StakeCredential,
     pub d_rep: DRep,
@@ -1025,7 +1211,9 @@ impl VoteDelegCert {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct VoteRegDelegCert {
     pub stake_credential: StakeCredential,
     pub d_rep: DRep,
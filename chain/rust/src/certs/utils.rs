@@ -1,8 +1,17 @@
-use std::{borrow::Cow, str::FromStr};
+use std::{
+    borrow::Cow,
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet},
+    str::FromStr,
+};
 
-use super::{Ipv4, Ipv6, StakeCredential};
-use cml_core::DeserializeError;
-use cml_crypto::RawBytesEncoding;
+use super::{
+    Certificate, DNSName, Ipv4, Ipv6, PoolParams, PoolRegistration, PoolRetirement, Relay,
+    StakeCredential, Url,
+};
+use crate::{NetworkId, Port};
+use cml_core::{serialization::Serialize, DeserializeError, Epoch};
+use cml_crypto::{Ed25519KeyHash, RawBytesEncoding};
 
 impl StakeCredential {
     // we don't implement RawBytesEncoding as from_raw_bytes() would be unable to distinguish
@@ -14,6 +23,27 @@ impl StakeCredential {
     }
 }
 
+/// Same order as the ledger: script credentials come before key hash credentials,
+/// then by hash. This matters for e.g. the redeemer indices of withdrawals which follow
+/// the order of their reward accounts (network then credential). Encodings are ignored.
+impl Ord for StakeCredential {
+    fn cmp(&self, other: &Self) -> Ordering {
+        fn key(cred: &StakeCredential) -> (u8, &[u8]) {
+            match cred {
+                StakeCredential::Script { hash, .. } => (0, hash.to_raw_bytes()),
+                StakeCredential::PubKey { hash, .. } => (1, hash.to_raw_bytes()),
+            }
+        }
+        key(self).cmp(&key(other))
+    }
+}
+
+impl PartialOrd for StakeCredential {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum IPStringParsingError {
     #[error("Invalid IPv4 Address String, expected period-separated bytes e.g. 0.0.0.0")]
@@ -240,9 +270,688 @@ impl schemars::JsonSchema for Ipv6 {
     }
 }
 
+impl PoolRegistration {
+    /// Whether the pool params differ in any way that matters on-chain.
+    /// Encoding details, the order of the pool owners and unreduced margins (e.g. 1/2 vs 2/4) are ignored.
+    pub fn differs_materially_from(&self, other: &PoolRegistration) -> bool {
+        fn owners(params: &PoolParams) -> BTreeSet<&Ed25519KeyHash> {
+            params.pool_owners.iter().collect()
+        }
+        fn relays(params: &PoolParams) -> Vec<Vec<u8>> {
+            params
+                .relays
+                .iter()
+                .map(Serialize::to_canonical_cbor_bytes)
+                .collect()
+        }
+        let lhs = &self.pool_params;
+        let rhs = &other.pool_params;
+        let same_margin = lhs.margin.start as u128 * rhs.margin.end as u128
+            == rhs.margin.start as u128 * lhs.margin.end as u128;
+        let same_metadata = match (&lhs.pool_metadata, &rhs.pool_metadata) {
+            (Some(lhs), Some(rhs)) => {
+                lhs.url.get() == rhs.url.get() && lhs.pool_metadata_hash == rhs.pool_metadata_hash
+            }
+            (None, None) => true,
+            _ => false,
+        };
+        !(lhs.operator == rhs.operator
+            && lhs.vrf_keyhash == rhs.vrf_keyhash
+            && lhs.pledge == rhs.pledge
+            && lhs.cost == rhs.cost
+            && same_margin
+            && lhs.reward_account == rhs.reward_account
+            && owners(lhs) == owners(rhs)
+            && relays(lhs) == relays(rhs)
+            && same_metadata)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RelayParsingError {
+    #[error("Relay has no host")]
+    EmptyHost,
+    #[error("Invalid relay port: {0}")]
+    InvalidPort(String),
+    #[error("Invalid relay IPv6 address: {0}")]
+    Ipv6(IPStringParsingError),
+    #[error("Multi host name (SRV) relays can't have a port")]
+    PortOnMultiHostName,
+    #[error("Invalid relay DNS name: {0}")]
+    DnsName(DeserializeError),
+}
+
+impl FromStr for Relay {
+    type Err = RelayParsingError;
+
+    /// Parses relays as entered in pool tooling:
+    /// * "203.0.113.7:3001" or "203.0.113.7" - IPv4 single host address
+    /// * "[2001:db8::1]:3001" or "2001:db8::1" - IPv6 single host address
+    /// * "relay.example.com:3001" or "relay.example.com" - single host name (A/AAAA record)
+    /// * "_srv.relays.example.com" - multi host name (SRV record, as these start with an underscore)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        fn parse_port(port: &str) -> Result<Port, RelayParsingError> {
+            port.parse()
+                .map_err(|_e| RelayParsingError::InvalidPort(port.to_owned()))
+        }
+        let s = s.trim();
+        if let Some(bracketed) = s.strip_prefix('[') {
+            let (ipv6, rest) = bracketed.split_once(']').ok_or(RelayParsingError::Ipv6(
+                IPStringParsingError::IPv6StringFormat,
+            ))?;
+            let port = match rest.strip_prefix(':') {
+                Some(port) => Some(parse_port(port)?),
+                None if rest.is_empty() => None,
+                None => return Err(RelayParsingError::InvalidPort(rest.to_owned())),
+            };
+            let ipv6 = Ipv6::from_str(ipv6).map_err(RelayParsingError::Ipv6)?;
+            return Ok(Relay::new_single_host_addr(port, None, Some(ipv6)));
+        }
+        if s.matches(':').count() > 1 {
+            // unbracketed IPv6 can't have a port as it would be ambiguous
+            let ipv6 = Ipv6::from_str(s).map_err(RelayParsingError::Ipv6)?;
+            return Ok(Relay::new_single_host_addr(None, None, Some(ipv6)));
+        }
+        let (host, port) = match s.split_once(':') {
+            Some((host, port)) => (host, Some(parse_port(port)?)),
+            None => (s, None),
+        };
+        if host.is_empty() {
+            return Err(RelayParsingError::EmptyHost);
+        }
+        if let Ok(ipv4) = Ipv4::from_str(host) {
+            return Ok(Relay::new_single_host_addr(port, Some(ipv4), None));
+        }
+        let dns_name = DNSName::new(host.to_owned()).map_err(RelayParsingError::DnsName)?;
+        if host.starts_with('_') {
+            if port.is_some() {
+                return Err(RelayParsingError::PortOnMultiHostName);
+            }
+            Ok(Relay::new_multi_host_name(dns_name))
+        } else {
+            Ok(Relay::new_single_host_name(port, dns_name))
+        }
+    }
+}
+
+impl std::fmt::Display for Relay {
+    /// Inverse of from_str(). A single host address with both an IPv4 and an IPv6 address
+    /// can't be represented as one string so only its IPv4 address is shown.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (host, port) = match self {
+            Relay::SingleHostAddr(relay) => match (&relay.ipv4, &relay.ipv6) {
+                (Some(ipv4), _) => (ipv4.to_string(), relay.port),
+                (None, Some(ipv6)) => match relay.port {
+                    Some(_) => (format!("[{ipv6}]"), relay.port),
+                    None => (ipv6.to_string(), None),
+                },
+                (None, None) => (String::new(), relay.port),
+            },
+            Relay::SingleHostName(relay) => (relay.dns_name.get().clone(), relay.port),
+            Relay::MultiHostName(relay) => (relay.dns_name.get().clone(), None),
+        };
+        match port {
+            Some(port) => write!(f, "{host}:{port}"),
+            None => write!(f, "{host}"),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PoolParamsValidationError {
+    #[error("reward_account has network id {found} but the target network is {expected}")]
+    RewardAccountNetworkMismatch { found: u8, expected: u64 },
+    #[error("relays[{0}].dns_name is invalid: {1}")]
+    InvalidDnsName(usize, DNSNameError),
+    #[error("relays[{0}].dns_name is empty")]
+    EmptyDnsName(usize),
+    #[error("pool_metadata.url is invalid: {0}")]
+    InvalidMetadataUrl(UrlError),
+}
+
+impl PoolParams {
+    /// Checks that the reward account is for {network_id}.
+    /// The ledger only rejects a mismatch at submission time (WrongNetworkInTxBody / WrongNetwork).
+    pub fn validate_network(&self, network_id: NetworkId) -> Result<(), PoolParamsValidationError> {
+        if u64::from(self.reward_account.network) != network_id.network {
+            return Err(PoolParamsValidationError::RewardAccountNetworkMismatch {
+                found: self.reward_account.network,
+                expected: network_id.network,
+            });
+        }
+        Ok(())
+    }
+
+    /// Checks that no single/multi host name relay has an empty DNS name.
+    /// These are allowed by the CDDL but can never be resolved.
+    pub fn validate_relays(&self) -> Result<(), PoolParamsValidationError> {
+        for (i, relay) in self.relays.iter().enumerate() {
+            let dns_name = match relay {
+                Relay::SingleHostAddr(_) => None,
+                Relay::SingleHostName(relay) => Some(&relay.dns_name),
+                Relay::MultiHostName(relay) => Some(&relay.dns_name),
+            };
+            if dns_name.is_some_and(|dns_name| dns_name.get().is_empty()) {
+                return Err(PoolParamsValidationError::EmptyDnsName(i));
+            }
+        }
+        Ok(())
+    }
+
+    /// Strict checks of the relay DNS names and the metadata URL.
+    /// See DNSName::validate_strict() and Url::validate_strict()
+    pub fn validate_urls(&self) -> Result<(), PoolParamsValidationError> {
+        for (i, relay) in self.relays.iter().enumerate() {
+            let dns_name = match relay {
+                Relay::SingleHostAddr(_) => continue,
+                Relay::SingleHostName(relay) => &relay.dns_name,
+                Relay::MultiHostName(relay) => &relay.dns_name,
+            };
+            dns_name
+                .validate_strict()
+                .map_err(|e| PoolParamsValidationError::InvalidDnsName(i, e))?;
+        }
+        if let Some(pool_metadata) = &self.pool_metadata {
+            pool_metadata
+                .url
+                .validate_strict()
+                .map_err(PoolParamsValidationError::InvalidMetadataUrl)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DNSNameError {
+    #[error(transparent)]
+    Length(#[from] DeserializeError),
+    #[error("DNS name is empty")]
+    Empty,
+    #[error("DNS name label \"{0}\" must be 1 to 63 characters long")]
+    LabelLength(String),
+    #[error("DNS name label \"{0}\" contains invalid character {1:?}")]
+    InvalidCharacter(String, char),
+    #[error("DNS name label \"{0}\" can't start or end with a hyphen")]
+    Hyphen(String),
+    #[error("DNS name label \"{0}\" starts with an underscore but follows a hostname label")]
+    Underscore(String),
+}
+
+impl DNSName {
+    /// Max length in bytes of a single label (RFC 1035)
+    pub const MAX_LABEL_LEN: usize = 63;
+
+    /// Like new() but also checks the hostname grammar. See validate_strict()
+    pub fn new_strict(dns_name: String) -> Result<Self, DNSNameError> {
+        let dns_name = Self::new(dns_name)?;
+        dns_name.validate_strict()?;
+        Ok(dns_name)
+    }
+
+    /// Checks that this is a RFC 1123 hostname: dot-separated labels of 1-63 letters, digits
+    /// or hyphens that don't start or end with a hyphen, with an optional trailing dot.
+    /// Leading labels may start with an underscore (e.g. _cardano._tcp.example.com) for
+    /// the SRV records of multi host name relays.
+    /// The CDDL (and so new()) only limits the length so on-chain relays may not pass this.
+    pub fn validate_strict(&self) -> Result<(), DNSNameError> {
+        let name = self.get();
+        let name = name.strip_suffix('.').unwrap_or(name);
+        if name.is_empty() {
+            return Err(DNSNameError::Empty);
+        }
+        let mut in_hostname = false;
+        for label in name.split('.') {
+            if label.is_empty() || label.len() > Self::MAX_LABEL_LEN {
+                return Err(DNSNameError::LabelLength(label.to_owned()));
+            }
+            let body = match label.strip_prefix('_') {
+                Some(_) if in_hostname => return Err(DNSNameError::Underscore(label.to_owned())),
+                Some(body) => body,
+                None => {
+                    in_hostname = true;
+                    label
+                }
+            };
+            if let Some(c) = body
+                .chars()
+                .find(|c| !c.is_ascii_alphanumeric() && *c != '-')
+            {
+                return Err(DNSNameError::InvalidCharacter(label.to_owned(), c));
+            }
+            if body.is_empty() {
+                return Err(DNSNameError::LabelLength(label.to_owned()));
+            }
+            if body.starts_with('-') || body.ends_with('-') {
+                return Err(DNSNameError::Hyphen(label.to_owned()));
+            }
+        }
+        if !in_hostname {
+            return Err(DNSNameError::Empty);
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum UrlError {
+    #[error(transparent)]
+    Length(#[from] DeserializeError),
+    #[error("URL contains whitespace or control character {0:?}")]
+    InvalidCharacter(char),
+    #[error("URL has no scheme, expected e.g. https://")]
+    MissingScheme,
+    #[error("URL scheme \"{0}\" is not supported, expected http, https or ipfs")]
+    UnsupportedScheme(String),
+    #[error("URL has nothing after the scheme")]
+    MissingLocation,
+}
+
+impl Url {
+    /// Schemes accepted by validate_strict()
+    pub const STRICT_SCHEMES: [&'static str; 3] = ["http", "https", "ipfs"];
+
+    /// Like new() but also checks the URL is usable off-chain. See validate_strict()
+    pub fn new_strict(url: String) -> Result<Self, UrlError> {
+        let url = Self::new(url)?;
+        url.validate_strict()?;
+        Ok(url)
+    }
+
+    /// Checks that this URL has an http, https or ipfs scheme followed by a non-empty location
+    /// and contains no whitespace or control characters.
+    /// The CDDL (and so new()) only limits the length so on-chain URLs may not pass this.
+    pub fn validate_strict(&self) -> Result<(), UrlError> {
+        let url = self.get();
+        if let Some(c) = url.chars().find(|c| c.is_whitespace() || c.is_control()) {
+            return Err(UrlError::InvalidCharacter(c));
+        }
+        let (scheme, location) = url.split_once("://").ok_or(UrlError::MissingScheme)?;
+        if !scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+            || !scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+        {
+            return Err(UrlError::MissingScheme);
+        }
+        if !Self::STRICT_SCHEMES
+            .iter()
+            .any(|strict| scheme.eq_ignore_ascii_case(strict))
+        {
+            return Err(UrlError::UnsupportedScheme(scheme.to_owned()));
+        }
+        if location
+            .split(['/', '?', '#'])
+            .next()
+            .unwrap_or("")
+            .is_empty()
+        {
+            return Err(UrlError::MissingLocation);
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PoolLifecycleError {
+    #[error("Pool {} is not registered", .0.to_hex())]
+    NotRegistered(Ed25519KeyHash),
+    #[error("Retirement epoch {epoch} must be after the current epoch {current_epoch}")]
+    RetirementEpochNotInFuture { epoch: Epoch, current_epoch: Epoch },
+}
+
+#[derive(Clone, Debug)]
+struct PoolState {
+    registration: PoolRegistration,
+    future_registration: Option<PoolRegistration>,
+    retiring_epoch: Option<Epoch>,
+}
+
+/// Tracks the registration state of stake pools from certificates fed in chain order.
+/// This follows the ledger's POOL/POOLREAP rules:
+/// * Registering a new pool takes effect immediately.
+/// * Re-registering an existing pool stages the new params for the next epoch boundary
+///   and cancels any pending retirement.
+/// * Retiring requires the pool to be registered and an epoch after the current one.
+///   Later retirements override earlier ones.
+/// * Pools are removed at the epoch boundary of their retirement epoch.
+///
+/// The maximum retirement epoch (eMax protocol param) and deposits are not checked.
+#[derive(Clone, Debug, Default)]
+pub struct PoolLifecycle {
+    pools: BTreeMap<Ed25519KeyHash, PoolState>,
+}
+
+impl PoolLifecycle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Applies {cert} if it's a pool registration or retirement. Other certificates are ignored.
+    pub fn apply_cert(
+        &mut self,
+        cert: &Certificate,
+        current_epoch: Epoch,
+    ) -> Result<(), PoolLifecycleError> {
+        match cert {
+            Certificate::PoolRegistration(registration) => {
+                self.register(registration);
+                Ok(())
+            }
+            Certificate::PoolRetirement(retirement) => self.retire(retirement, current_epoch),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns true if this was a new registration, or false if it updated an existing pool.
+    pub fn register(&mut self, registration: &PoolRegistration) -> bool {
+        match self.pools.get_mut(&registration.pool_params.operator) {
+            Some(state) => {
+                state.future_registration = Some(registration.clone());
+                state.retiring_epoch = None;
+                false
+            }
+            None => {
+                self.pools.insert(
+                    registration.pool_params.operator,
+                    PoolState {
+                        registration: registration.clone(),
+                        future_registration: None,
+                        retiring_epoch: None,
+                    },
+                );
+                true
+            }
+        }
+    }
+
+    pub fn retire(
+        &mut self,
+        retirement: &PoolRetirement,
+        current_epoch: Epoch,
+    ) -> Result<(), PoolLifecycleError> {
+        let state = self
+            .pools
+            .get_mut(&retirement.pool)
+            .ok_or(PoolLifecycleError::NotRegistered(retirement.pool))?;
+        if retirement.epoch <= current_epoch {
+            return Err(PoolLifecycleError::RetirementEpochNotInFuture {
+                epoch: retirement.epoch,
+                current_epoch,
+            });
+        }
+        state.retiring_epoch = Some(retirement.epoch);
+        Ok(())
+    }
+
+    /// Transitions into {new_epoch}: staged re-registrations take effect
+    /// and pools retiring in (or before) {new_epoch} are removed.
+    /// Returns the ids of the removed pools.
+    pub fn process_epoch_boundary(&mut self, new_epoch: Epoch) -> Vec<Ed25519KeyHash> {
+        let mut retired = Vec::new();
+        self.pools.retain(|pool, state| {
+            if let Some(future_registration) = state.future_registration.take() {
+                state.registration = future_registration;
+            }
+            let retiring = state.retiring_epoch.is_some_and(|epoch| epoch <= new_epoch);
+            if retiring {
+                retired.push(*pool);
+            }
+            !retiring
+        });
+        retired
+    }
+
+    pub fn is_registered(&self, pool: &Ed25519KeyHash) -> bool {
+        self.pools.contains_key(pool)
+    }
+
+    /// Epoch at which {pool} will be retired, if a retirement is pending
+    pub fn retiring_epoch(&self, pool: &Ed25519KeyHash) -> Option<Epoch> {
+        self.pools.get(pool).and_then(|state| state.retiring_epoch)
+    }
+
+    /// The registration currently in effect for {pool}
+    pub fn registration(&self, pool: &Ed25519KeyHash) -> Option<&PoolRegistration> {
+        self.pools.get(pool).map(|state| &state.registration)
+    }
+
+    /// A re-registration of {pool} that will take effect at the next epoch boundary
+    pub fn future_registration(&self, pool: &Ed25519KeyHash) -> Option<&PoolRegistration> {
+        self.pools
+            .get(pool)
+            .and_then(|state| state.future_registration.as_ref())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        address::RewardAddress,
+        certs::{DNSName, DRep, PoolMetadata, Relay, SingleHostName, Url},
+        crypto::{PoolMetadataHash, VRFKeyHash},
+        UnitInterval,
+    };
+    use cml_core::serialization::Deserialize;
+
+    proptest::proptest! {
+        #[test]
+        fn certificate_roundtrip(cert in crate::testing::arb_certificate()) {
+            crate::testing::roundtrip_cbor(&cert)?;
+            crate::testing::roundtrip_json(&cert)?;
+        }
+    }
+
+    fn pool_registration(operator: u8, pledge: u64, owners: &[u8]) -> PoolRegistration {
+        let reward_account = RewardAddress::new(
+            0,
+            StakeCredential::new_pub_key(Ed25519KeyHash::from([operator; 28])),
+        );
+        PoolRegistration::new(PoolParams::new(
+            Ed25519KeyHash::from([operator; 28]),
+            VRFKeyHash::from([operator; 32]),
+            pledge,
+            340_000_000,
+            UnitInterval::new(1, 50),
+            reward_account,
+            owners
+                .iter()
+                .map(|owner| Ed25519KeyHash::from([*owner; 28]))
+                .collect::<Vec<_>>()
+                .into(),
+            vec![Relay::new_single_host_name(
+                None,
+                DNSName::new("relay.example.com".to_owned()).unwrap(),
+            )],
+            Some(PoolMetadata::new(
+                Url::new("https://example.com/pool.json".to_owned()).unwrap(),
+                PoolMetadataHash::from([7; 32]),
+            )),
+        ))
+    }
+
+    #[test]
+    fn pool_params_validation() {
+        // reward account built for testnet (network id 0)
+        let params = pool_registration(1, 1000, &[2]).pool_params;
+        assert!(params.validate_network(NetworkId::testnet()).is_ok());
+        let err = params.validate_network(NetworkId::mainnet()).unwrap_err();
+        assert!(matches!(
+            err,
+            PoolParamsValidationError::RewardAccountNetworkMismatch {
+                found: 0,
+                expected: 1
+            }
+        ));
+        assert!(err.to_string().contains("reward_account"));
+
+        assert!(params.validate_relays().is_ok());
+        let mut empty_dns = params.clone();
+        empty_dns.relays.push(Relay::new_multi_host_name(
+            DNSName::new(String::new()).unwrap(),
+        ));
+        let err = empty_dns.validate_relays().unwrap_err();
+        assert!(matches!(err, PoolParamsValidationError::EmptyDnsName(1)));
+        assert_eq!(err.to_string(), "relays[1].dns_name is empty");
+
+        // testnet reward account in a mainnet-configured cert builder
+        use crate::builders::certificate_builder::{CertBuilderError, SingleCertificateBuilder};
+        let cert = Certificate::PoolRegistration(pool_registration(1, 1000, &[2]));
+        assert!(matches!(
+            SingleCertificateBuilder::new_for_network(cert.clone(), NetworkId::mainnet()),
+            Err(CertBuilderError::InvalidPoolParams(
+                PoolParamsValidationError::RewardAccountNetworkMismatch { .. }
+            ))
+        ));
+        assert!(SingleCertificateBuilder::new_for_network(cert, NetworkId::testnet()).is_ok());
+    }
+
+    #[test]
+    fn dns_name_strict() {
+        for name in [
+            "relay.example.com",
+            "relay-1.example.com.",
+            "localhost",
+            "_cardano._tcp.example.com",
+        ] {
+            assert!(DNSName::new_strict(name.to_owned()).is_ok(), "{}", name);
+        }
+        assert!(matches!(
+            DNSName::new_strict("relay .example.com".to_owned()),
+            Err(DNSNameError::InvalidCharacter(label, ' ')) if label == "relay "
+        ));
+        assert!(matches!(
+            DNSName::new_strict("-relay.example.com".to_owned()),
+            Err(DNSNameError::Hyphen(_))
+        ));
+        assert!(matches!(
+            DNSName::new_strict("relay..example.com".to_owned()),
+            Err(DNSNameError::LabelLength(label)) if label.is_empty()
+        ));
+        assert!(matches!(
+            DNSName::new_strict(format!("{}.com", "a".repeat(64))),
+            Err(DNSNameError::LabelLength(_))
+        ));
+        assert!(matches!(
+            DNSName::new_strict("relay._tcp.example.com".to_owned()),
+            Err(DNSNameError::Underscore(label)) if label == "_tcp"
+        ));
+        assert!(matches!(
+            DNSName::new_strict(String::new()),
+            Err(DNSNameError::Empty)
+        ));
+        assert!(matches!(
+            DNSName::new_strict("a".repeat(129)),
+            Err(DNSNameError::Length(_))
+        ));
+
+        // the lenient constructor still accepts it for on-chain data
+        let spaced = DNSName::new("relay .example.com".to_owned()).unwrap();
+        let mut registration = pool_registration(1, 1000, &[2]);
+        registration
+            .pool_params
+            .relays
+            .push(Relay::new_single_host_name(None, spaced));
+        assert!(matches!(
+            registration.pool_params.validate_urls(),
+            Err(PoolParamsValidationError::InvalidDnsName(1, _))
+        ));
+
+        // the certificate builder is strict by default
+        use crate::builders::certificate_builder::{CertBuilderError, SingleCertificateBuilder};
+        let cert = Certificate::PoolRegistration(registration);
+        assert!(matches!(
+            SingleCertificateBuilder::new(cert.clone()).payment_key(),
+            Err(CertBuilderError::InvalidPoolParams(
+                PoolParamsValidationError::InvalidDnsName(1, DNSNameError::InvalidCharacter(..))
+            ))
+        ));
+        assert!(SingleCertificateBuilder::new(cert)
+            .skip_url_validation()
+            .payment_key()
+            .is_ok());
+    }
+
+    #[test]
+    fn pool_registration_differs_materially() {
+        let registration = pool_registration(1, 1000, &[2, 3]);
+        let mut same = pool_registration(1, 1000, &[3, 2]);
+        same.pool_params.margin = UnitInterval::new(2, 100);
+        assert!(!registration.differs_materially_from(&same));
+        let same_from_cbor =
+            PoolRegistration::from_cbor_bytes(&registration.to_cbor_bytes()).unwrap();
+        assert!(!registration.differs_materially_from(&same_from_cbor));
+        assert!(registration.differs_materially_from(&pool_registration(1, 2000, &[2, 3])));
+        assert!(registration.differs_materially_from(&pool_registration(1, 1000, &[2])));
+        let mut no_metadata = registration.clone();
+        no_metadata.pool_params.pool_metadata = None;
+        assert!(registration.differs_materially_from(&no_metadata));
+    }
+
+    #[test]
+    fn pool_lifecycle_reregistration_cancels_retirement() {
+        let registration = pool_registration(1, 1000, &[2]);
+        let updated = pool_registration(1, 5000, &[2]);
+        let pool = registration.pool_params.operator;
+        let mut lifecycle = PoolLifecycle::new();
+        assert!(matches!(
+            lifecycle.apply_cert(
+                &Certificate::PoolRetirement(PoolRetirement::new(pool, 12)),
+                10
+            ),
+            Err(PoolLifecycleError::NotRegistered(_))
+        ));
+        // register -> retire -> re-register all within epoch 10
+        lifecycle
+            .apply_cert(&Certificate::PoolRegistration(registration.clone()), 10)
+            .unwrap();
+        assert!(lifecycle.is_registered(&pool));
+        assert!(matches!(
+            lifecycle.apply_cert(
+                &Certificate::PoolRetirement(PoolRetirement::new(pool, 10)),
+                10
+            ),
+            Err(PoolLifecycleError::RetirementEpochNotInFuture {
+                epoch: 10,
+                current_epoch: 10
+            })
+        ));
+        lifecycle
+            .apply_cert(
+                &Certificate::PoolRetirement(PoolRetirement::new(pool, 11)),
+                10,
+            )
+            .unwrap();
+        assert_eq!(lifecycle.retiring_epoch(&pool), Some(11));
+        lifecycle
+            .apply_cert(&Certificate::PoolRegistration(updated.clone()), 10)
+            .unwrap();
+        assert_eq!(lifecycle.retiring_epoch(&pool), None);
+        // the update is only staged until the next epoch
+        assert!(!lifecycle
+            .registration(&pool)
+            .unwrap()
+            .differs_materially_from(&registration));
+        assert!(lifecycle.future_registration(&pool).is_some());
+        assert!(lifecycle.process_epoch_boundary(11).is_empty());
+        assert!(lifecycle.is_registered(&pool));
+        assert!(!lifecycle
+            .registration(&pool)
+            .unwrap()
+            .differs_materially_from(&updated));
+        assert!(lifecycle.future_registration(&pool).is_none());
+        // a retirement that isn't cancelled is reaped at its epoch boundary
+        lifecycle
+            .apply_cert(
+                &Certificate::PoolRetirement(PoolRetirement::new(pool, 13)),
+                11,
+            )
+            .unwrap();
+        assert!(lifecycle.process_epoch_boundary(12).is_empty());
+        assert_eq!(lifecycle.process_epoch_boundary(13), vec![pool]);
+        assert!(!lifecycle.is_registered(&pool));
+        assert_eq!(lifecycle.retiring_epoch(&pool), None);
+    }
 
     #[test]
     fn ipv4_json() {
@@ -298,4 +1007,112 @@ mod tests {
             "\"0:a:0:b:0:c:0:d\"",
         );
     }
+
+    #[test]
+    fn relay_strings() {
+        fn round_trip(s: &str) -> Relay {
+            let relay = Relay::from_str(s).unwrap();
+            assert_eq!(relay.to_string(), s);
+            relay
+        }
+        match round_trip("203.0.113.7:3001") {
+            Relay::SingleHostAddr(relay) => {
+                assert_eq!(relay.port, Some(3001));
+                assert_eq!(relay.ipv4.unwrap().to_string(), "203.0.113.7");
+                assert!(relay.ipv6.is_none());
+            }
+            _ => panic!("expected single host addr"),
+        }
+        match round_trip("[2001:db8::1]:3001") {
+            Relay::SingleHostAddr(relay) => {
+                assert_eq!(relay.port, Some(3001));
+                assert!(relay.ipv4.is_none());
+                assert_eq!(relay.ipv6.unwrap().to_string(), "2001:db8::1");
+            }
+            _ => panic!("expected single host addr"),
+        }
+        round_trip("2001:db8::1");
+        match round_trip("relay.example.com:3001") {
+            Relay::SingleHostName(relay) => {
+                assert_eq!(relay.port, Some(3001));
+                assert_eq!(relay.dns_name.get(), "relay.example.com");
+            }
+            _ => panic!("expected single host name"),
+        }
+        assert!(matches!(
+            round_trip("relay.example.com"),
+            Relay::SingleHostName(SingleHostName { port: None, .. })
+        ));
+        assert!(matches!(
+            round_trip("_srv.relays.example.com"),
+            Relay::MultiHostName(_)
+        ));
+
+        assert!(matches!(
+            Relay::from_str("_srv.relays.example.com:3001"),
+            Err(RelayParsingError::PortOnMultiHostName)
+        ));
+        assert!(matches!(
+            Relay::from_str("relay.example.com:70000"),
+            Err(RelayParsingError::InvalidPort(_))
+        ));
+        assert!(matches!(
+            Relay::from_str("[2001:db8::1]3001"),
+            Err(RelayParsingError::InvalidPort(_))
+        ));
+        assert!(matches!(
+            Relay::from_str(":3001"),
+            Err(RelayParsingError::EmptyHost)
+        ));
+        let too_long = format!("{}.example.com:3001", "a".repeat(128));
+        assert!(matches!(
+            Relay::from_str(&too_long),
+            Err(RelayParsingError::DnsName(_))
+        ));
+    }
+
+    #[test]
+    fn credential_ledger_order() {
+        let key = StakeCredential::new_pub_key(Ed25519KeyHash::from([0x00; 28]));
+        let script = StakeCredential::new_script(cml_crypto::ScriptHash::from([0xff; 28]));
+        assert!(script < key);
+        // [0 (2 byte uint), h'00..']
+        let non_canonical = StakeCredential::from_cbor_bytes(
+            &hex::decode(format!("82190000581c{}", "00".repeat(28))).unwrap(),
+        )
+        .unwrap();
+        assert_ne!(non_canonical.to_cbor_bytes(), key.to_cbor_bytes());
+        assert_eq!(non_canonical, key);
+        assert_eq!(non_canonical.cmp(&key), Ordering::Equal);
+        // withdrawal redeemers are indexed in this order
+        let accounts: BTreeSet<RewardAddress> = vec![key, script.clone()]
+            .into_iter()
+            .map(|cred| RewardAddress::new(0, cred))
+            .collect();
+        assert_eq!(accounts.first().unwrap().payment, script);
+    }
+
+    #[test]
+    fn drep_relay_eq_ignore_encodings() {
+        use std::collections::HashSet;
+        let hash = "00".repeat(28);
+        // [0, h'00..'] and [0 (1 byte uint), h'00..' (indefinite bytes)]
+        let drep =
+            DRep::from_cbor_bytes(&hex::decode(format!("8200581c{}", hash)).unwrap()).unwrap();
+        let non_canonical =
+            DRep::from_cbor_bytes(&hex::decode(format!("8218005f581c{}ff", hash)).unwrap())
+                .unwrap();
+        assert_ne!(drep.to_cbor_bytes(), non_canonical.to_cbor_bytes());
+        assert_eq!(drep, non_canonical);
+        assert_eq!(
+            vec![drep, non_canonical]
+                .into_iter()
+                .collect::<HashSet<_>>()
+                .len(),
+            1
+        );
+        let relay = Relay::from_str("relay.example.com:3001").unwrap();
+        let reparsed = Relay::from_cbor_bytes(&relay.to_cbor_bytes()).unwrap();
+        assert_eq!(relay, reparsed);
+    }
 }
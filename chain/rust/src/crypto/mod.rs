@@ -19,10 +19,12 @@ use cbor_encodings::{
     BootstrapWitnessEncoding, KESSignatureEncoding, VRFCertEncoding, VkeywitnessEncoding,
 };
 use cml_core::error::*;
-use cml_core::serialization::{LenEncoding, StringEncoding};
+use cml_core::serialization::{LenEncoding, StringEncoding, StripEncodings};
 use std::convert::TryFrom;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct BootstrapWitness {
     pub public_key: Vkey,
     pub signature: Ed25519Signature,
@@ -57,7 +59,7 @@ impl BootstrapWitness {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, StripEncodings)]
 pub struct KESSignature {
     pub inner: Vec<u8>,
     pub encodings: Option<KESSignatureEncoding>,
@@ -141,7 +143,9 @@ impl schemars::JsonSchema for KESSignature {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub enum Nonce {
     Identity {
         #[serde(skip)]
@@ -178,7 +182,9 @@ impl Nonce {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct VRFCert {
     pub output: Vec<u8>,
     pub proof: Vec<u8>,
@@ -204,7 +210,9 @@ impl VRFCert {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct Vkeywitness {
     pub vkey: Vkey,
     pub ed25519_signature: Ed25519Signature,
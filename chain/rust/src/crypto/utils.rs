@@ -1,11 +1,12 @@
 use std::convert::{TryFrom, TryInto};
 
-use super::{BootstrapWitness, Vkeywitness};
+use super::{BootstrapWitness, Nonce, VRFCert, Vkeywitness};
 use crate::byron::AddressContent;
 
 use cml_crypto::{
+    blake2b256,
     chain_crypto::{self, derive::combine_pk_and_chaincode},
-    CryptoError, PrivateKey, RawBytesEncoding, TransactionHash,
+    CryptoError, NonceHash, PrivateKey, RawBytesEncoding, TransactionHash,
 };
 
 impl BootstrapWitness {
@@ -50,3 +51,36 @@ pub fn make_vkey_witness(tx_body_hash: &TransactionHash, sk: &PrivateKey) -> Vke
     let sig = sk.sign(tx_body_hash.to_raw_bytes());
     Vkeywitness::new(sk.to_public(), sig)
 }
+
+impl VRFCert {
+    /// Nonce contribution of a TPraos (Shelley through Alonzo) header's `nonce_vrf` certificate.
+    /// This is simply blake2b256(output).
+    pub fn tpraos_nonce(&self) -> NonceHash {
+        NonceHash::from(blake2b256(&self.output))
+    }
+
+    /// Nonce contribution of a Praos (Babbage onwards) header's `vrf_result` certificate.
+    /// Praos uses a single VRF for both leader election and the nonce so the output is
+    /// first domain-separated with the "N" prefix: blake2b256(blake2b256("N" || output))
+    pub fn praos_nonce(&self) -> NonceHash {
+        let mut prefixed = Vec::with_capacity(self.output.len() + 1);
+        prefixed.push(b'N');
+        prefixed.extend_from_slice(&self.output);
+        NonceHash::from(blake2b256(&blake2b256(&prefixed)))
+    }
+}
+
+/// Folds a single block's nonce contribution (see VRFCert::tpraos_nonce() / VRFCert::praos_nonce())
+/// into the evolving nonce as the ledger does: blake2b256(current || header_nonce).
+/// The identity (neutral) nonce is treated as the identity element.
+pub fn evolve_nonce(current: &Nonce, header_nonce: &NonceHash) -> Nonce {
+    match current {
+        Nonce::Identity { .. } => Nonce::new_hash(*header_nonce),
+        Nonce::Hash { hash, .. } => {
+            let mut bytes = Vec::with_capacity(2 * NonceHash::BYTE_COUNT);
+            bytes.extend_from_slice(hash.to_raw_bytes());
+            bytes.extend_from_slice(header_nonce.to_raw_bytes());
+            Nonce::new_hash(NonceHash::from(blake2b256(&bytes)))
+        }
+    }
+}
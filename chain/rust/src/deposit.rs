@@ -1,8 +1,8 @@
 use cml_core::ArithmeticError;
 
 use crate::{
-    certs::Certificate, governance::ProposalProcedure, transaction::TransactionBody, Coin, Value,
-    Withdrawals,
+    certs::Certificate, governance::ProposalProcedure, transaction::TransactionBody,
+    utils::withdrawals_total, Coin, Value, Withdrawals,
 };
 
 pub fn internal_get_implicit_input(
@@ -13,10 +13,7 @@ pub fn internal_get_implicit_input(
 ) -> Result<Value, ArithmeticError> {
     let withdrawal_sum = match withdrawals {
         None => 0,
-        Some(w) => w
-            .values()
-            .try_fold(0u64, |acc, withdrawal_amt| acc.checked_add(*withdrawal_amt))
-            .ok_or(ArithmeticError::IntegerOverflow)?,
+        Some(w) => withdrawals_total(w)?,
     };
     let certificate_refund = match certs {
         None => 0,
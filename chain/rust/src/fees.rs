@@ -1,5 +1,5 @@
 use crate::plutus::utils::compute_total_ex_units;
-use crate::plutus::ExUnitPrices;
+use crate::plutus::{ExUnitPrices, ExUnits};
 use crate::transaction::Transaction;
 use crate::Coin;
 use cml_core::{serialization::Serialize, ArithmeticError};
@@ -42,24 +42,34 @@ pub fn min_script_fee(
 ) -> Result<Coin, ArithmeticError> {
     if let Some(redeemers) = &tx.witness_set.redeemers {
         let total_ex_units = compute_total_ex_units(&redeemers.clone().to_flat_format())?;
-        let script_fee = ((BigRational::new(total_ex_units.mem.into(), 1u64.into())
-            * BigRational::new(
-                ex_unit_prices.mem_price.numerator.into(),
-                ex_unit_prices.mem_price.denominator.into(),
-            ))
-            + (BigRational::new(total_ex_units.steps.into(), 1u64.into())
-                * BigRational::new(
-                    ex_unit_prices.step_price.numerator.into(),
-                    ex_unit_prices.step_price.denominator.into(),
-                )))
-        .ceil()
-        .to_integer();
-        u64::try_from(script_fee).map_err(|_| ArithmeticError::IntegerOverflow)
+        ex_units_fee(&total_ex_units, ex_unit_prices)
     } else {
         Ok(0)
     }
 }
 
+/**
+ * Fee for {ex_units} at {ex_unit_prices} (rounded up)
+ */
+pub fn ex_units_fee(
+    ex_units: &ExUnits,
+    ex_unit_prices: &ExUnitPrices,
+) -> Result<Coin, ArithmeticError> {
+    let fee = ((BigRational::new(ex_units.mem.into(), 1u64.into())
+        * BigRational::new(
+            ex_unit_prices.mem_price.numerator.into(),
+            ex_unit_prices.mem_price.denominator.into(),
+        ))
+        + (BigRational::new(ex_units.steps.into(), 1u64.into())
+            * BigRational::new(
+                ex_unit_prices.step_price.numerator.into(),
+                ex_unit_prices.step_price.denominator.into(),
+            )))
+    .ceil()
+    .to_integer();
+    u64::try_from(fee).map_err(|_| ArithmeticError::IntegerOverflow)
+}
+
 /**
  * Calculates the cost of all ref scripts
  * * `total_ref_script_size` - Total size (original, not hashes) of all ref scripts. Duplicate scripts are counted as many times as they occur
@@ -1,4 +1,5 @@
 use crate::{
+    assets::ZeroValueError,
     byron::ProtocolMagic,
     plutus::{CostModels, Language},
 };
@@ -6,6 +7,8 @@ use cml_core::network::{
     BYRON_MAINNET_NETWORK_MAGIC, BYRON_TESTNET_NETWORK_MAGIC, PREPROD_NETWORK_MAGIC,
     PREVIEW_NETWORK_MAGIC, SANCHO_TESTNET_NETWORK_MAGIC,
 };
+use cml_core::Slot;
+use std::convert::TryFrom;
 
 #[derive(
     Debug, Clone, Eq, Hash, Ord, PartialEq, PartialOrd, serde::Serialize, serde::Deserialize,
@@ -67,6 +70,105 @@ impl NetworkInfo {
     }
 }
 
+/// Mapping between slots and POSIX time (in milliseconds) for the (post-Byron) eras which
+/// have a fixed slot length: zero_slot started at zero_time and every slot lasts slot_length ms.
+#[derive(
+    Debug, Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd, serde::Serialize, serde::Deserialize,
+)]
+#[serde(try_from = "SlotConfigFields")]
+pub struct SlotConfig {
+    zero_time: u64,
+    zero_slot: Slot,
+    slot_length: u64,
+}
+
+impl SlotConfig {
+    /// Fails if {slot_length} is 0 as slots couldn't be computed from a time then
+    pub fn new(zero_time: u64, zero_slot: Slot, slot_length: u64) -> Result<Self, ZeroValueError> {
+        if slot_length == 0 {
+            return Err(ZeroValueError("slot_length"));
+        }
+        Ok(Self {
+            zero_time,
+            zero_slot,
+            slot_length,
+        })
+    }
+
+    /// POSIX time (ms) at which zero_slot started
+    pub fn zero_time(&self) -> u64 {
+        self.zero_time
+    }
+
+    pub fn zero_slot(&self) -> Slot {
+        self.zero_slot
+    }
+
+    /// Length of a slot in milliseconds
+    pub fn slot_length(&self) -> u64 {
+        self.slot_length
+    }
+
+    /// Starting at the first Shelley slot
+    pub fn mainnet() -> Self {
+        Self {
+            zero_time: 1596059091000,
+            zero_slot: 4492800,
+            slot_length: 1000,
+        }
+    }
+
+    pub fn preview() -> Self {
+        Self {
+            zero_time: 1666656000000,
+            zero_slot: 0,
+            slot_length: 1000,
+        }
+    }
+
+    /// Starting at the first Shelley slot
+    pub fn preprod() -> Self {
+        Self {
+            zero_time: 1655769600000,
+            zero_slot: 86400,
+            slot_length: 1000,
+        }
+    }
+
+    /// POSIX time (ms) at which {slot} starts. None if it's before zero_slot or overflows.
+    pub fn slot_to_posix_time(&self, slot: Slot) -> Option<u64> {
+        slot.checked_sub(self.zero_slot)?
+            .checked_mul(self.slot_length)?
+            .checked_add(self.zero_time)
+    }
+
+    /// Slot containing {posix_time} (ms). None if it's before zero_time or overflows.
+    pub fn posix_time_to_slot(&self, posix_time: u64) -> Option<Slot> {
+        (posix_time.checked_sub(self.zero_time)? / self.slot_length).checked_add(self.zero_slot)
+    }
+
+    /// How many whole slots fit in {seconds}
+    pub fn slots_in(&self, seconds: u64) -> u64 {
+        seconds.saturating_mul(1000) / self.slot_length
+    }
+}
+
+// same fields as SlotConfig so that deserialized ones also go through SlotConfig::new()
+#[derive(serde::Deserialize)]
+struct SlotConfigFields {
+    zero_time: u64,
+    zero_slot: Slot,
+    slot_length: u64,
+}
+
+impl TryFrom<SlotConfigFields> for SlotConfig {
+    type Error = ZeroValueError;
+
+    fn try_from(fields: SlotConfigFields) -> Result<Self, Self::Error> {
+        Self::new(fields.zero_time, fields.zero_slot, fields.slot_length)
+    }
+}
+
 // TODO: https://github.com/dcSpark/cardano-multiplatform-lib/issues/92
 pub fn plutus_alonzo_cost_models() -> CostModels {
     let ops = vec![
@@ -87,3 +189,40 @@ pub fn plutus_alonzo_cost_models() -> CostModels {
     res.inner.insert(Language::PlutusV1 as u64, ops);
     res
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slot_config_conversions() {
+        let mainnet = SlotConfig::mainnet();
+        assert_eq!(mainnet.slot_to_posix_time(4492800), Some(1596059091000));
+        assert_eq!(mainnet.slot_to_posix_time(4492801), Some(1596059092000));
+        // byron slots have a different length
+        assert_eq!(mainnet.slot_to_posix_time(4492799), None);
+        assert_eq!(mainnet.posix_time_to_slot(1596059091999), Some(4492800));
+        assert_eq!(mainnet.posix_time_to_slot(1596059092000), Some(4492801));
+        assert_eq!(mainnet.posix_time_to_slot(1596059090999), None);
+        let preprod = SlotConfig::preprod();
+        assert_eq!(
+            preprod.posix_time_to_slot(preprod.slot_to_posix_time(1234567).unwrap()),
+            Some(1234567)
+        );
+        assert_eq!(SlotConfig::preview().slots_in(3600), 3600);
+        assert_eq!(SlotConfig::new(0, 0, 20000).unwrap().slots_in(3600), 180);
+    }
+
+    #[test]
+    fn slot_config_zero_slot_length() {
+        assert!(SlotConfig::new(0, 0, 0).is_err());
+        let json = serde_json::to_string(&SlotConfig::preview()).unwrap();
+        assert_eq!(
+            serde_json::from_str::<SlotConfig>(&json).unwrap(),
+            SlotConfig::preview()
+        );
+        let zero_length = json.replace("\"slot_length\":1000", "\"slot_length\":0");
+        assert_ne!(zero_length, json);
+        assert!(serde_json::from_str::<SlotConfig>(&zero_length).is_err());
+    }
+}
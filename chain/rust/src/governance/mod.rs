@@ -2,6 +2,7 @@
 // https://github.com/dcSpark/cddl-codegen
 
 pub mod cbor_encodings;
+pub mod ratification;
 pub mod serialization;
 pub mod utils;
 
@@ -24,14 +25,29 @@ use cbor_encodings::{
 };
 
 use cml_core::ordered_hash_map::OrderedHashMap;
-use cml_core::serialization::{LenEncoding, StringEncoding};
+use cml_core::serialization::{LenEncoding, StringEncoding, StripEncodings};
 use std::collections::BTreeMap;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone,
+    Debug,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    derivative::Derivative,
+    StripEncodings,
+)]
+#[derivative(Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Anchor {
     pub anchor_url: Url,
     pub anchor_doc_hash: AnchorDocHash,
     #[serde(skip)]
+    #[derivative(
+        PartialEq = "ignore",
+        Ord = "ignore",
+        PartialOrd = "ignore",
+        Hash = "ignore"
+    )]
     pub encodings: Option<AnchorEncoding>,
 }
 
@@ -45,7 +61,9 @@ impl Anchor {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct Constitution {
     pub anchor: Anchor,
     pub script_hash: Option<ScriptHash>,
@@ -64,7 +82,9 @@ impl Constitution {
 }
 
 #[allow(clippy::large_enum_variant)]
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub enum GovAction {
     ParameterChangeAction(ParameterChangeAction),
     HardForkInitiationAction(HardForkInitiationAction),
@@ -136,8 +156,16 @@ impl GovAction {
     }
 }
 
+/// Ordered by transaction_id then gov_action_index like the ledger's GovActionId.
+/// Encodings are ignored by Eq/Ord/Hash.
 #[derive(
-    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, derivative::Derivative,
+    Clone,
+    Debug,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    derivative::Derivative,
+    StripEncodings,
 )]
 #[derivative(Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct GovActionId {
@@ -163,7 +191,9 @@ impl GovActionId {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct HardForkInitiationAction {
     pub action_id: Option<GovActionId>,
     pub version: ProtocolVersion,
@@ -181,7 +211,9 @@ impl HardForkInitiationAction {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct NewConstitution {
     pub action_id: Option<GovActionId>,
     pub constitution: Constitution,
@@ -199,7 +231,9 @@ impl NewConstitution {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct NoConfidence {
     pub action_id: Option<GovActionId>,
     #[serde(skip)]
@@ -215,7 +249,9 @@ impl NoConfidence {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ParameterChangeAction {
     pub action_id: Option<GovActionId>,
     pub update: ProtocolParamUpdate,
@@ -239,7 +275,9 @@ impl ParameterChangeAction {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ProposalProcedure {
     pub deposit: Coin,
     pub reward_account: RewardAccount,
@@ -266,7 +304,9 @@ impl ProposalProcedure {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct TreasuryWithdrawalsAction {
     pub withdrawal: OrderedHashMap<RewardAccount, Coin>,
     pub policy_hash: Option<ScriptHash>,
@@ -287,7 +327,9 @@ impl TreasuryWithdrawalsAction {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct UpdateCommittee {
     pub action_id: Option<GovActionId>,
     pub cold_credentials: SetCommitteeColdCredential,
@@ -325,6 +367,7 @@ impl UpdateCommittee {
     serde::Deserialize,
     serde::Serialize,
     schemars::JsonSchema,
+    StripEncodings,
 )]
 #[wasm_bindgen]
 pub enum Vote {
@@ -334,7 +377,13 @@ pub enum Vote {
 }
 
 #[derive(
-    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, derivative::Derivative,
+    Clone,
+    Debug,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    derivative::Derivative,
+    StripEncodings,
 )]
 #[derivative(
     Eq,
@@ -528,7 +577,9 @@ impl Voter {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct VotingProcedure {
     pub vote: Vote,
     pub anchor: Option<Anchor>,
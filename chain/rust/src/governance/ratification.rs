@@ -0,0 +1,903 @@
+//! Stake-weighted vote tallies of governance actions.
+//!
+//! The stake distributions and committee state are not derivable from blocks alone so they
+//! are sourced externally (e.g. from db-sync or a node's ledger state query) and combined
+//! here with the collected votes to see whether actions would currently be ratified.
+
+use std::hash::Hash;
+use std::io::{BufRead, Seek, Write};
+
+use cbor_event::{de::Deserializer, se::Serializer};
+use cml_core::{
+    error::{DeserializeError, DeserializeFailure},
+    ordered_hash_map::OrderedHashMap,
+    serialization::{canonical_key_cmp, Deserialize, LenEncoding, Serialize, StripEncodings},
+    Epoch,
+};
+use cml_crypto::{Ed25519KeyHash, RawBytesEncoding};
+
+use super::{GovAction, GovActionId, Vote, Voter, VotingProcedures};
+use crate::assets::Coin;
+use crate::certs::{Credential, DRep};
+use crate::governance::utils::{CommitteeAuthorization, CommitteeState};
+use crate::{DRepVotingThresholds, PoolVotingThresholds, ProtocolParamUpdate, UnitInterval};
+
+#[derive(serde::Deserialize, serde::Serialize)]
+struct DRepStakeEntry {
+    drep: DRep,
+    stake: Coin,
+}
+
+/// Active voting stake delegated to each DRep, including the AlwaysAbstain and
+/// AlwaysNoConfidence options. Inactive DReps must be left out as they don't count.
+///
+/// Serializes (serde) as a list of {drep, stake} entries and to CBOR as a map of DRep to coin.
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, StripEncodings)]
+#[serde(from = "Vec<DRepStakeEntry>", into = "Vec<DRepStakeEntry>")]
+pub struct DRepStakeDistribution {
+    pub stake: OrderedHashMap<DRep, Coin>,
+    encoding: LenEncoding,
+}
+
+#[derive(serde::Deserialize, serde::Serialize)]
+struct SpoStakeEntry {
+    pool: Ed25519KeyHash,
+    stake: Coin,
+}
+
+/// Active stake of each stake pool (by pool operator key hash).
+///
+/// Serializes (serde) as a list of {pool, stake} entries and to CBOR as a map of pool to coin.
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, StripEncodings)]
+#[serde(from = "Vec<SpoStakeEntry>", into = "Vec<SpoStakeEntry>")]
+pub struct SpoStakeDistribution {
+    pub stake: OrderedHashMap<Ed25519KeyHash, Coin>,
+    encoding: LenEncoding,
+}
+
+impl DRepStakeDistribution {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl SpoStakeDistribution {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl From<OrderedHashMap<DRep, Coin>> for DRepStakeDistribution {
+    fn from(stake: OrderedHashMap<DRep, Coin>) -> Self {
+        Self {
+            stake,
+            encoding: LenEncoding::default(),
+        }
+    }
+}
+
+impl From<OrderedHashMap<Ed25519KeyHash, Coin>> for SpoStakeDistribution {
+    fn from(stake: OrderedHashMap<Ed25519KeyHash, Coin>) -> Self {
+        Self {
+            stake,
+            encoding: LenEncoding::default(),
+        }
+    }
+}
+
+impl From<Vec<DRepStakeEntry>> for DRepStakeDistribution {
+    fn from(entries: Vec<DRepStakeEntry>) -> Self {
+        entries
+            .into_iter()
+            .map(|entry| (entry.drep, entry.stake))
+            .collect::<OrderedHashMap<_, _>>()
+            .into()
+    }
+}
+
+impl From<DRepStakeDistribution> for Vec<DRepStakeEntry> {
+    fn from(distribution: DRepStakeDistribution) -> Self {
+        distribution
+            .stake
+            .take()
+            .into_iter()
+            .map(|(drep, stake)| DRepStakeEntry { drep, stake })
+            .collect()
+    }
+}
+
+impl From<Vec<SpoStakeEntry>> for SpoStakeDistribution {
+    fn from(entries: Vec<SpoStakeEntry>) -> Self {
+        entries
+            .into_iter()
+            .map(|entry| (entry.pool, entry.stake))
+            .collect::<OrderedHashMap<_, _>>()
+            .into()
+    }
+}
+
+impl From<SpoStakeDistribution> for Vec<SpoStakeEntry> {
+    fn from(distribution: SpoStakeDistribution) -> Self {
+        distribution
+            .stake
+            .take()
+            .into_iter()
+            .map(|(pool, stake)| SpoStakeEntry { pool, stake })
+            .collect()
+    }
+}
+
+fn serialize_stake_map<'se, K: Hash + Eq + Ord, W: Write>(
+    stake: &OrderedHashMap<K, Coin>,
+    encoding: &LenEncoding,
+    serializer: &'se mut Serializer<W>,
+    force_canonical: bool,
+    serialize_key: impl Fn(&K, &mut Serializer<Vec<u8>>) -> cbor_event::Result<()>,
+) -> cbor_event::Result<&'se mut Serializer<W>> {
+    serializer.write_map_sz(encoding.to_len_sz(stake.len() as u64, force_canonical))?;
+    let mut key_order = stake
+        .iter()
+        .map(|(k, v)| {
+            let mut buf = cbor_event::se::Serializer::new_vec();
+            serialize_key(k, &mut buf)?;
+            Ok((buf.finalize(), v))
+        })
+        .collect::<Result<Vec<(Vec<u8>, &Coin)>, cbor_event::Error>>()?;
+    if force_canonical {
+        key_order.sort_by(|(lhs_bytes, _), (rhs_bytes, _)| canonical_key_cmp(lhs_bytes, rhs_bytes));
+    }
+    for (key_bytes, value) in key_order {
+        serializer.write_raw_bytes(&key_bytes)?;
+        serializer.write_unsigned_integer(*value)?;
+    }
+    encoding.end(serializer, force_canonical)
+}
+
+fn deserialize_stake_map<K: Hash + Eq + Ord, R: BufRead + Seek>(
+    raw: &mut Deserializer<R>,
+    deserialize_key: impl Fn(&mut Deserializer<R>) -> Result<K, DeserializeError>,
+) -> Result<(OrderedHashMap<K, Coin>, LenEncoding), DeserializeError> {
+    let mut stake = OrderedHashMap::new();
+    let map_len = raw.map_sz()?;
+    let encoding = map_len.into();
+    while match map_len {
+        cbor_event::LenSz::Len(n, _) => (stake.len() as u64) < n,
+        cbor_event::LenSz::Indefinite => true,
+    } {
+        if raw.cbor_type()? == cbor_event::Type::Special {
+            assert_eq!(raw.special()?, cbor_event::Special::Break);
+            break;
+        }
+        let key = deserialize_key(raw)?;
+        let coin = raw.unsigned_integer()?;
+        stake.insert(key, coin);
+    }
+    Ok((stake, encoding))
+}
+
+impl Serialize for DRepStakeDistribution {
+    fn serialize<'se, W: Write>(
+        &self,
+        serializer: &'se mut Serializer<W>,
+        force_canonical: bool,
+    ) -> cbor_event::Result<&'se mut Serializer<W>> {
+        serialize_stake_map(
+            &self.stake,
+            &self.encoding,
+            serializer,
+            force_canonical,
+            |drep, buf| drep.serialize(buf, force_canonical).map(|_| ()),
+        )
+    }
+}
+
+impl Deserialize for DRepStakeDistribution {
+    fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
+        deserialize_stake_map(raw, DRep::deserialize)
+            .map(|(stake, encoding)| Self { stake, encoding })
+            .map_err(|e| e.annotate("DRepStakeDistribution"))
+    }
+}
+
+impl Serialize for SpoStakeDistribution {
+    fn serialize<'se, W: Write>(
+        &self,
+        serializer: &'se mut Serializer<W>,
+        force_canonical: bool,
+    ) -> cbor_event::Result<&'se mut Serializer<W>> {
+        serialize_stake_map(
+            &self.stake,
+            &self.encoding,
+            serializer,
+            force_canonical,
+            |pool, buf| buf.write_bytes(pool.to_raw_bytes()).map(|_| ()),
+        )
+    }
+}
+
+impl Deserialize for SpoStakeDistribution {
+    fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
+        deserialize_stake_map(raw, |raw| {
+            Ed25519KeyHash::from_raw_bytes(&raw.bytes()?)
+                .map_err(|e| DeserializeFailure::InvalidStructure(Box::new(e)).into())
+        })
+        .map(|(stake, encoding)| Self { stake, encoding })
+        .map_err(|e| e.annotate("SpoStakeDistribution"))
+    }
+}
+
+/// Protocol parameters and ledger state needed for ratification besides the votes themselves
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct RatificationParams {
+    /// Fraction of active committee members that must vote yes
+    pub committee_quorum: UnitInterval,
+    /// Actions requiring committee approval fail when fewer members are active
+    pub min_committee_size: u64,
+    pub d_rep_voting_thresholds: DRepVotingThresholds,
+    pub pool_voting_thresholds: PoolVotingThresholds,
+    /// Whether the last enacted action was a NoConfidence i.e. there is no committee
+    pub no_confidence: bool,
+    /// Epoch the votes are tallied in, to leave out expired committee members
+    pub current_epoch: Epoch,
+}
+
+/// Votes of one voting body on a governance action. These are lovelace for DReps
+/// and SPOs but member counts for the constitutional committee.
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct VotingBodyTally {
+    pub yes: u64,
+    /// Includes the stake (or members) that didn't vote
+    pub no: u64,
+    pub abstain: u64,
+    /// Minimum yes / (yes + no) ratio. None if this body doesn't vote on the action
+    /// (accepted is then true) or if the action can't be ratified at all (accepted is then false).
+    pub threshold: Option<UnitInterval>,
+    pub accepted: bool,
+}
+
+impl VotingBodyTally {
+    fn not_voting() -> Self {
+        Self {
+            accepted: true,
+            ..Self::default()
+        }
+    }
+
+    fn add(&mut self, vote: Vote, weight: u64) {
+        match vote {
+            Vote::Yes => self.yes += weight,
+            Vote::No => self.no += weight,
+            Vote::Abstain => self.abstain += weight,
+        }
+    }
+
+    /// Applies the threshold. As in the ledger the ratio is 0 when nobody voted yes or no.
+    fn decide(mut self, threshold: Option<UnitInterval>) -> Self {
+        self.accepted = match &threshold {
+            Some(threshold) => {
+                (self.yes as u128) * (threshold.end as u128)
+                    >= (threshold.start as u128) * (self.yes as u128 + self.no as u128)
+            }
+            None => false,
+        };
+        self.threshold = threshold;
+        self
+    }
+}
+
+/// Stake-weighted tally of a governance action, see tally_gov_action()
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct GovActionTally {
+    pub action_id: GovActionId,
+    pub committee: VotingBodyTally,
+    pub dreps: VotingBodyTally,
+    pub pools: VotingBodyTally,
+    /// Whether all voting bodies accept the action
+    pub accepted: bool,
+}
+
+fn max_threshold<'a>(
+    thresholds: impl IntoIterator<Item = &'a UnitInterval>,
+) -> Option<UnitInterval> {
+    thresholds
+        .into_iter()
+        .max_by(|lhs, rhs| {
+            ((lhs.start as u128) * (rhs.end as u128))
+                .cmp(&((rhs.start as u128) * (lhs.end as u128)))
+        })
+        .cloned()
+}
+
+/// DRep threshold of a parameter update: the highest one of the parameter groups it touches
+fn param_update_d_rep_threshold(
+    update: &ProtocolParamUpdate,
+    thresholds: &DRepVotingThresholds,
+) -> Option<UnitInterval> {
+    let network = update.max_block_body_size.is_some()
+        || update.max_transaction_size.is_some()
+        || update.max_block_header_size.is_some()
+        || update.max_value_size.is_some()
+        || update.max_tx_ex_units.is_some()
+        || update.max_block_ex_units.is_some()
+        || update.max_collateral_inputs.is_some();
+    let economic = update.minfee_a.is_some()
+        || update.minfee_b.is_some()
+        || update.key_deposit.is_some()
+        || update.pool_deposit.is_some()
+        || update.expansion_rate.is_some()
+        || update.treasury_growth_rate.is_some()
+        || update.min_pool_cost.is_some()
+        || update.ada_per_utxo_byte.is_some()
+        || update.execution_costs.is_some()
+        || update.min_fee_ref_script_cost_per_byte.is_some();
+    let technical = update.maximum_epoch.is_some()
+        || update.n_opt.is_some()
+        || update.pool_pledge_influence.is_some()
+        || update.cost_models_for_script_languages.is_some()
+        || update.collateral_percentage.is_some();
+    let governance = update.pool_voting_thresholds.is_some()
+        || update.d_rep_voting_thresholds.is_some()
+        || update.min_committee_size.is_some()
+        || update.committee_term_limit.is_some()
+        || update.governance_action_validity_period.is_some()
+        || update.governance_action_deposit.is_some()
+        || update.d_rep_deposit.is_some()
+        || update.d_rep_inactivity_period.is_some();
+    let groups = vec![
+        (network, &thresholds.pp_network_group),
+        (economic, &thresholds.pp_economic_group),
+        (technical, &thresholds.pp_technical_group),
+        (governance, &thresholds.pp_governance_group),
+    ];
+    max_threshold(
+        groups
+            .into_iter()
+            .filter(|(touched, _)| *touched)
+            .map(|(_, threshold)| threshold),
+    )
+}
+
+/// Whether SPOs vote on a parameter update i.e. whether it touches a security relevant parameter
+fn param_update_is_security_relevant(update: &ProtocolParamUpdate) -> bool {
+    update.max_block_body_size.is_some()
+        || update.max_transaction_size.is_some()
+        || update.max_block_header_size.is_some()
+        || update.max_value_size.is_some()
+        || update.max_block_ex_units.is_some()
+        || update.minfee_a.is_some()
+        || update.minfee_b.is_some()
+        || update.ada_per_utxo_byte.is_some()
+        || update.governance_action_deposit.is_some()
+        || update.min_fee_ref_script_cost_per_byte.is_some()
+}
+
+fn vote_of(votes: &VotingProcedures, voter: &Voter, action_id: &GovActionId) -> Option<Vote> {
+    votes
+        .get(voter)
+        .and_then(|procedures| procedures.get(action_id))
+        .map(|procedure| procedure.vote)
+}
+
+fn committee_tally(
+    action_id: &GovActionId,
+    votes: &VotingProcedures,
+    committee: &CommitteeState,
+    params: &RatificationParams,
+) -> (VotingBodyTally, u64) {
+    let mut tally = VotingBodyTally::default();
+    let mut active = 0;
+    for (_cold, member) in committee.members() {
+        let expired = member
+            .expires_after
+            .map(|epoch| epoch < params.current_epoch)
+            .unwrap_or(false);
+        let hot = match &member.authorization {
+            Some(CommitteeAuthorization::HotCredential(hot)) if !expired => hot,
+            _ => {
+                tally.add(Vote::Abstain, 1);
+                continue;
+            }
+        };
+        active += 1;
+        let voter = match hot {
+            Credential::PubKey { hash, .. } => {
+                Voter::new_constitutional_committee_hot_key_hash(*hash)
+            }
+            Credential::Script { hash, .. } => {
+                Voter::new_constitutional_committee_hot_script_hash(*hash)
+            }
+        };
+        tally.add(vote_of(votes, &voter, action_id).unwrap_or(Vote::No), 1);
+    }
+    (tally, active)
+}
+
+/// Tallies the votes on a governance action weighted by the given stake distributions and
+/// checks them against the thresholds for that type of action, following the Conway ledger's
+/// ratification rules:
+/// * DReps and SPOs that didn't vote count as voting no. The ledger instead lets SPOs that
+///   didn't vote follow their reward account's AlwaysAbstain/AlwaysNoConfidence delegation
+///   for actions other than hard forks, which isn't reflected here.
+/// * Stake delegated to AlwaysAbstain doesn't count and stake delegated to AlwaysNoConfidence
+///   votes yes on NoConfidence actions and no on all others.
+/// * Committee members that expired, resigned or have no hot credential count as abstaining.
+///   Members the committee state has no term for are assumed to not have expired.
+///
+/// Enactment order (prev_gov_action_id), guardrail scripts, treasury balance and the
+/// bootstrap phase are not checked. Info actions are never accepted.
+pub fn tally_gov_action(
+    action_id: &GovActionId,
+    action: &GovAction,
+    votes: &VotingProcedures,
+    dreps: &DRepStakeDistribution,
+    pools: &SpoStakeDistribution,
+    committee: &CommitteeState,
+    params: &RatificationParams,
+) -> GovActionTally {
+    let d_rep_thresholds = &params.d_rep_voting_thresholds;
+    let pool_thresholds = &params.pool_voting_thresholds;
+    let (committee_votes, d_rep_threshold, pool_threshold) = match action {
+        GovAction::ParameterChangeAction(action) => (
+            true,
+            param_update_d_rep_threshold(&action.update, d_rep_thresholds),
+            if param_update_is_security_relevant(&action.update) {
+                Some(
+                    pool_thresholds
+                        .security_relevant_parameter_voting_threshold
+                        .clone(),
+                )
+            } else {
+                None
+            },
+        ),
+        GovAction::HardForkInitiationAction(_) => (
+            true,
+            Some(d_rep_thresholds.hard_fork_initiation.clone()),
+            Some(pool_thresholds.hard_fork_initiation.clone()),
+        ),
+        GovAction::TreasuryWithdrawalsAction(_) => (
+            true,
+            Some(d_rep_thresholds.treasury_withdrawal.clone()),
+            None,
+        ),
+        GovAction::NoConfidence(_) => (
+            false,
+            Some(d_rep_thresholds.motion_no_confidence.clone()),
+            Some(pool_thresholds.motion_no_confidence.clone()),
+        ),
+        GovAction::UpdateCommittee(_) if params.no_confidence => (
+            false,
+            Some(d_rep_thresholds.committee_no_confidence.clone()),
+            Some(pool_thresholds.committee_no_confidence.clone()),
+        ),
+        GovAction::UpdateCommittee(_) => (
+            false,
+            Some(d_rep_thresholds.committee_normal.clone()),
+            Some(pool_thresholds.committee_normal.clone()),
+        ),
+        GovAction::NewConstitution(_) => (
+            true,
+            Some(d_rep_thresholds.update_constitution.clone()),
+            None,
+        ),
+        GovAction::InfoAction { .. } => {
+            let rejected = VotingBodyTally::default();
+            return GovActionTally {
+                action_id: action_id.clone(),
+                committee: rejected.clone(),
+                dreps: rejected.clone(),
+                pools: rejected,
+                accepted: false,
+            };
+        }
+    };
+
+    let committee_tally = if committee_votes {
+        let (tally, active) = committee_tally(action_id, votes, committee, params);
+        if params.no_confidence || active < params.min_committee_size {
+            tally.decide(None)
+        } else {
+            tally.decide(Some(params.committee_quorum.clone()))
+        }
+    } else {
+        VotingBodyTally::not_voting()
+    };
+
+    let d_rep_tally = match d_rep_threshold {
+        Some(threshold) => {
+            let mut tally = VotingBodyTally::default();
+            for (drep, stake) in dreps.stake.iter() {
+                let vote = match drep {
+                    DRep::Key { pool, .. } => {
+                        vote_of(votes, &Voter::new_d_rep_key_hash(*pool), action_id)
+                            .unwrap_or(Vote::No)
+                    }
+                    DRep::Script { script_hash, .. } => vote_of(
+                        votes,
+                        &Voter::new_d_rep_script_hash(*script_hash),
+                        action_id,
+                    )
+                    .unwrap_or(Vote::No),
+                    DRep::AlwaysAbstain { .. } => Vote::Abstain,
+                    DRep::AlwaysNoConfidence { .. } => match action {
+                        GovAction::NoConfidence(_) => Vote::Yes,
+                        _ => Vote::No,
+                    },
+                };
+                tally.add(vote, *stake);
+            }
+            tally.decide(Some(threshold))
+        }
+        None => VotingBodyTally::not_voting(),
+    };
+
+    let pool_tally = match pool_threshold {
+        Some(threshold) => {
+            let mut tally = VotingBodyTally::default();
+            for (pool, stake) in pools.stake.iter() {
+                let vote = vote_of(votes, &Voter::new_staking_pool_key_hash(*pool), action_id)
+                    .unwrap_or(Vote::No);
+                tally.add(vote, *stake);
+            }
+            tally.decide(Some(threshold))
+        }
+        None => VotingBodyTally::not_voting(),
+    };
+
+    let accepted = committee_tally.accepted && d_rep_tally.accepted && pool_tally.accepted;
+    GovActionTally {
+        action_id: action_id.clone(),
+        committee: committee_tally,
+        dreps: d_rep_tally,
+        pools: pool_tally,
+        accepted,
+    }
+}
+
+/// Runs tally_gov_action() for each of the given (e.g. all active) governance actions
+pub fn tally_report<'a>(
+    actions: impl IntoIterator<Item = (&'a GovActionId, &'a GovAction)>,
+    votes: &VotingProcedures,
+    dreps: &DRepStakeDistribution,
+    pools: &SpoStakeDistribution,
+    committee: &CommitteeState,
+    params: &RatificationParams,
+) -> Vec<GovActionTally> {
+    actions
+        .into_iter()
+        .map(|(action_id, action)| {
+            tally_gov_action(action_id, action, votes, dreps, pools, committee, params)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::certs::{AuthCommitteeHotCert, Certificate, ResignCommitteeColdCert};
+    use crate::governance::{UpdateCommittee, VotingProcedure};
+    use crate::Rational;
+    use cml_crypto::{ScriptHash, TransactionHash};
+
+    fn percent(value: u64) -> UnitInterval {
+        UnitInterval::new(value, 100)
+    }
+
+    fn params() -> RatificationParams {
+        RatificationParams {
+            committee_quorum: UnitInterval::new(2, 3),
+            min_committee_size: 2,
+            d_rep_voting_thresholds: DRepVotingThresholds::new(
+                percent(67),
+                percent(67),
+                percent(60),
+                percent(75),
+                percent(60),
+                percent(67),
+                percent(67),
+                percent(50),
+                percent(75),
+                percent(67),
+            ),
+            pool_voting_thresholds: PoolVotingThresholds::new(
+                percent(51),
+                percent(51),
+                percent(51),
+                percent(51),
+                percent(51),
+            ),
+            no_confidence: false,
+            current_epoch: 10,
+        }
+    }
+
+    fn key(byte: u8) -> Ed25519KeyHash {
+        Ed25519KeyHash::from([byte; 28])
+    }
+
+    fn action_id(index: u64) -> GovActionId {
+        GovActionId::new(TransactionHash::from([9; 32]), index)
+    }
+
+    fn vote(votes: &mut VotingProcedures, voter: Voter, action_id: &GovActionId, vote: Vote) {
+        votes
+            .entry(voter)
+            .or_default()
+            .insert(action_id.clone(), VotingProcedure::new(vote, None));
+    }
+
+    /// Members 1 and 2 are active, 3 expired in epoch 5 and 4 resigned
+    fn committee() -> CommitteeState {
+        let mut committee = CommitteeState::new();
+        let mut members = OrderedHashMap::new();
+        for (member, expires_after) in [(1, 100), (2, 100), (3, 5), (4, 100)] {
+            members.insert(Credential::new_pub_key(key(member)), expires_after);
+        }
+        committee.enact_update_committee(&UpdateCommittee::new(
+            None,
+            vec![].into(),
+            members,
+            UnitInterval::new(2, 3),
+        ));
+        for member in 1..=4 {
+            committee.apply_cert(&Certificate::AuthCommitteeHotCert(
+                AuthCommitteeHotCert::new(
+                    Credential::new_pub_key(key(member)),
+                    Credential::new_pub_key(key(member + 10)),
+                ),
+            ));
+        }
+        committee.apply_cert(&Certificate::ResignCommitteeColdCert(
+            ResignCommitteeColdCert::new(Credential::new_pub_key(key(4)), None),
+        ));
+        committee
+    }
+
+    fn dreps() -> DRepStakeDistribution {
+        let mut dreps = DRepStakeDistribution::new();
+        dreps.stake.insert(DRep::new_key(key(21)), 70);
+        dreps.stake.insert(DRep::new_key(key(22)), 20);
+        dreps
+            .stake
+            .insert(DRep::new_script(ScriptHash::from([23; 28])), 10);
+        dreps.stake.insert(DRep::new_always_abstain(), 500);
+        dreps.stake.insert(DRep::new_always_no_confidence(), 5);
+        dreps
+    }
+
+    fn pools() -> SpoStakeDistribution {
+        let mut pools = SpoStakeDistribution::new();
+        pools.stake.insert(key(31), 100);
+        pools.stake.insert(key(32), 50);
+        pools.stake.insert(key(33), 50);
+        pools
+    }
+
+    #[test]
+    fn tally_accept_and_reject() {
+        let treasury_id = action_id(0);
+        let treasury = GovAction::new_treasury_withdrawals_action(OrderedHashMap::new(), None);
+        let no_confidence_id = action_id(1);
+        let no_confidence = GovAction::new_no_confidence(None);
+
+        let mut votes = VotingProcedures::new();
+        for member in [11, 12] {
+            vote(
+                &mut votes,
+                Voter::new_constitutional_committee_hot_key_hash(key(member)),
+                &treasury_id,
+                Vote::Yes,
+            );
+        }
+        // expired and resigned members' votes don't count
+        for member in [13, 14] {
+            vote(
+                &mut votes,
+                Voter::new_constitutional_committee_hot_key_hash(key(member)),
+                &treasury_id,
+                Vote::No,
+            );
+        }
+        vote(
+            &mut votes,
+            Voter::new_d_rep_key_hash(key(21)),
+            &treasury_id,
+            Vote::Yes,
+        );
+        vote(
+            &mut votes,
+            Voter::new_d_rep_key_hash(key(22)),
+            &treasury_id,
+            Vote::No,
+        );
+        vote(
+            &mut votes,
+            Voter::new_d_rep_script_hash(ScriptHash::from([23; 28])),
+            &treasury_id,
+            Vote::Yes,
+        );
+        vote(
+            &mut votes,
+            Voter::new_d_rep_key_hash(key(21)),
+            &no_confidence_id,
+            Vote::No,
+        );
+        vote(
+            &mut votes,
+            Voter::new_d_rep_key_hash(key(22)),
+            &no_confidence_id,
+            Vote::Yes,
+        );
+        vote(
+            &mut votes,
+            Voter::new_staking_pool_key_hash(key(31)),
+            &no_confidence_id,
+            Vote::Yes,
+        );
+        vote(
+            &mut votes,
+            Voter::new_staking_pool_key_hash(key(32)),
+            &no_confidence_id,
+            Vote::Abstain,
+        );
+
+        let report = tally_report(
+            vec![
+                (&treasury_id, &treasury),
+                (&no_confidence_id, &no_confidence),
+            ],
+            &votes,
+            &dreps(),
+            &pools(),
+            &committee(),
+            &params(),
+        );
+
+        // 80 yes vs 20 + 5 (AlwaysNoConfidence) no is above 67%
+        let treasury_tally = &report[0];
+        assert_eq!(treasury_tally.action_id, treasury_id);
+        assert_eq!(
+            (
+                treasury_tally.committee.yes,
+                treasury_tally.committee.no,
+                treasury_tally.committee.abstain
+            ),
+            (2, 0, 2)
+        );
+        assert!(treasury_tally.committee.accepted);
+        assert_eq!(
+            (treasury_tally.dreps.yes, treasury_tally.dreps.no),
+            (80, 25)
+        );
+        assert_eq!(treasury_tally.dreps.abstain, 500);
+        assert!(treasury_tally.dreps.accepted);
+        assert!(treasury_tally.pools.threshold.is_none());
+        assert!(treasury_tally.pools.accepted);
+        assert!(treasury_tally.accepted);
+
+        // AlwaysNoConfidence now votes yes but the DReps that didn't vote count as no:
+        // 25 yes vs 80 no is below 67%
+        let no_confidence_tally = &report[1];
+        assert!(no_confidence_tally.committee.threshold.is_none());
+        assert!(no_confidence_tally.committee.accepted);
+        assert_eq!(
+            (no_confidence_tally.dreps.yes, no_confidence_tally.dreps.no),
+            (25, 80)
+        );
+        assert!(!no_confidence_tally.dreps.accepted);
+        assert_eq!(
+            (
+                no_confidence_tally.pools.yes,
+                no_confidence_tally.pools.no,
+                no_confidence_tally.pools.abstain
+            ),
+            (100, 50, 50)
+        );
+        assert!(no_confidence_tally.pools.accepted);
+        assert!(!no_confidence_tally.accepted);
+    }
+
+    #[test]
+    fn tally_thresholds_per_action() {
+        let votes = VotingProcedures::new();
+        let id = action_id(0);
+
+        // economic (67%) and technical (50%) groups without security relevant parameters
+        let mut update = ProtocolParamUpdate::new();
+        update.key_deposit = Some(2_000_000);
+        update.pool_pledge_influence = Some(Rational::new(3, 10));
+        let action = GovAction::new_parameter_change_action(None, update.clone(), None);
+        let tally = tally_gov_action(
+            &id,
+            &action,
+            &votes,
+            &dreps(),
+            &pools(),
+            &committee(),
+            &params(),
+        );
+        assert_eq!(tally.dreps.threshold.unwrap().start, 67);
+        assert!(tally.pools.threshold.is_none());
+
+        // governance group (75%) and a security relevant parameter
+        update.governance_action_deposit = Some(100_000_000_000);
+        let action = GovAction::new_parameter_change_action(None, update, None);
+        let tally = tally_gov_action(
+            &id,
+            &action,
+            &votes,
+            &dreps(),
+            &pools(),
+            &committee(),
+            &params(),
+        );
+        assert_eq!(tally.dreps.threshold.unwrap().start, 75);
+        assert_eq!(tally.pools.threshold.unwrap().start, 51);
+
+        // without a committee actions needing its approval can't pass
+        let mut no_confidence = params();
+        no_confidence.no_confidence = true;
+        let action = GovAction::new_hard_fork_initiation_action(
+            None,
+            crate::block::ProtocolVersion::new(10, 0),
+        );
+        let tally = tally_gov_action(
+            &id,
+            &action,
+            &votes,
+            &dreps(),
+            &pools(),
+            &committee(),
+            &no_confidence,
+        );
+        assert!(tally.committee.threshold.is_none());
+        assert!(!tally.committee.accepted);
+
+        // too few active members (only 2 of the 4 are)
+        let mut min_size = params();
+        min_size.min_committee_size = 3;
+        let tally = tally_gov_action(
+            &id,
+            &action,
+            &votes,
+            &dreps(),
+            &pools(),
+            &committee(),
+            &min_size,
+        );
+        assert!(!tally.committee.accepted);
+
+        let tally = tally_gov_action(
+            &id,
+            &GovAction::new_info_action(),
+            &votes,
+            &dreps(),
+            &pools(),
+            &committee(),
+            &params(),
+        );
+        assert!(!tally.committee.accepted && !tally.dreps.accepted && !tally.pools.accepted);
+        assert!(!tally.accepted);
+    }
+
+    #[test]
+    fn stake_distribution_round_trip() {
+        let dreps = dreps();
+        let bytes = dreps.to_cbor_bytes();
+        let decoded = DRepStakeDistribution::from_cbor_bytes(&bytes).unwrap();
+        assert_eq!(decoded.stake, dreps.stake);
+        assert_eq!(decoded.to_cbor_bytes(), bytes);
+        let json = serde_json::to_string(&dreps).unwrap();
+        let decoded: DRepStakeDistribution = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.stake, dreps.stake);
+
+        let pools = pools();
+        let bytes = pools.to_cbor_bytes();
+        let decoded = SpoStakeDistribution::from_cbor_bytes(&bytes).unwrap();
+        assert_eq!(decoded.stake, pools.stake);
+        let json = serde_json::to_string(&pools).unwrap();
+        assert!(json.starts_with("[{\"pool\":"));
+        let decoded: SpoStakeDistribution = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.stake, pools.stake);
+    }
+}
@@ -1111,10 +1111,7 @@ impl SerializeEmbeddedGroup for TreasuryWithdrawalsAction {
             .collect::<Result<Vec<(Vec<u8>, &_, &_)>, cbor_event::Error>>()?;
         if force_canonical {
             key_order.sort_by(|(lhs_bytes, _, _), (rhs_bytes, _, _)| {
-                match lhs_bytes.len().cmp(&rhs_bytes.len()) {
-                    std::cmp::Ordering::Equal => lhs_bytes.cmp(rhs_bytes),
-                    diff_ord => diff_ord,
-                }
+                canonical_key_cmp(lhs_bytes, rhs_bytes)
             });
         }
         for (key_bytes, key, value) in key_order {
@@ -1325,10 +1322,7 @@ impl SerializeEmbeddedGroup for UpdateCommittee {
             .collect::<Result<Vec<(Vec<u8>, &_, &_)>, cbor_event::Error>>()?;
         if force_canonical {
             key_order.sort_by(|(lhs_bytes, _, _), (rhs_bytes, _, _)| {
-                match lhs_bytes.len().cmp(&rhs_bytes.len()) {
-                    std::cmp::Ordering::Equal => lhs_bytes.cmp(rhs_bytes),
-                    diff_ord => diff_ord,
-                }
+                canonical_key_cmp(lhs_bytes, rhs_bytes)
             });
         }
         for (key_bytes, key, value) in key_order {
@@ -1,6 +1,115 @@
-use cml_crypto::{Ed25519KeyHash, ScriptHash};
+use std::collections::BTreeMap;
 
-use super::{GovAction, Voter};
+use cml_core::Epoch;
+use cml_crypto::{AnchorDocHash, Ed25519KeyHash, ScriptHash};
+
+use super::{
+    Anchor, Constitution, GovAction, NewConstitution, ProposalProcedure, UpdateCommittee, Voter,
+};
+use crate::certs::{
+    utils::UrlError, Certificate, CommitteeColdCredential, CommitteeHotCredential, Credential, Url,
+};
+use crate::transaction::TransactionBody;
+
+#[derive(Debug, thiserror::Error)]
+#[error(
+    "Anchor URL is {0} bytes long but URLs can be at most {} bytes",
+    Anchor::MAX_URL_LEN
+)]
+pub struct AnchorUrlTooLong(pub usize);
+
+impl Anchor {
+    /// Max length in bytes of the URL of an anchor
+    pub const MAX_URL_LEN: usize = 128;
+
+    /// Creates an anchor directly from a URL string, checking the URL length limit.
+    pub fn from_url(
+        anchor_url: String,
+        anchor_doc_hash: AnchorDocHash,
+    ) -> Result<Self, AnchorUrlTooLong> {
+        if anchor_url.len() > Self::MAX_URL_LEN {
+            return Err(AnchorUrlTooLong(anchor_url.len()));
+        }
+        let anchor_url = Url::new(anchor_url).expect("length checked above");
+        Ok(Self::new(anchor_url, anchor_doc_hash))
+    }
+
+    /// Like from_url() but also checks the URL with Url::validate_strict()
+    pub fn from_url_strict(
+        anchor_url: String,
+        anchor_doc_hash: AnchorDocHash,
+    ) -> Result<Self, UrlError> {
+        Ok(Self::new(Url::new_strict(anchor_url)?, anchor_doc_hash))
+    }
+
+    /// Hashes an anchor's off-chain document for use as its anchor_doc_hash.
+    /// As per CIP-100 this is the blake2b-256 of the raw bytes of the document exactly as served
+    /// (no JSON(-LD) canonicalization is applied) which is also what `cardano-cli hash anchor-data` does.
+    pub fn hash_document(document: &[u8]) -> AnchorDocHash {
+        AnchorDocHash::from(cml_crypto::blake2b256(document))
+    }
+
+    /// Whether {document} (as raw bytes exactly as served) matches this anchor's anchor_doc_hash
+    pub fn matches_document(&self, document: &[u8]) -> bool {
+        Self::hash_document(document) == self.anchor_doc_hash
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AnchorDocumentError {
+    #[error("Anchor document is not valid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Anchor document is missing required fields: {0:?}")]
+    MissingFields(Vec<&'static str>),
+    #[error("Unsupported hashAlgorithm \"{0}\", only blake2b-256 is supported")]
+    UnsupportedHashAlgorithm(String),
+}
+
+/// Lenient structural check of a CIP-100/CIP-108 governance metadata document.
+/// Only checks that authors, body.title and body.abstract are present
+/// and that hashAlgorithm (if present) is blake2b-256. Unknown fields are allowed.
+pub fn validate_anchor_document(document: &[u8]) -> Result<(), AnchorDocumentError> {
+    let json: serde_json::Value = serde_json::from_slice(document)?;
+    if let Some(hash_algorithm) = json.get("hashAlgorithm") {
+        if hash_algorithm.as_str() != Some("blake2b-256") {
+            return Err(AnchorDocumentError::UnsupportedHashAlgorithm(
+                hash_algorithm
+                    .as_str()
+                    .map(str::to_owned)
+                    .unwrap_or_else(|| hash_algorithm.to_string()),
+            ));
+        }
+    }
+    let body = json.get("body");
+    let missing = [
+        ("authors", json.get("authors")),
+        ("body.title", body.and_then(|body| body.get("title"))),
+        ("body.abstract", body.and_then(|body| body.get("abstract"))),
+    ]
+    .iter()
+    .filter(|(_, value)| value.is_none_or(serde_json::Value::is_null))
+    .map(|(field, _)| *field)
+    .collect::<Vec<_>>();
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(AnchorDocumentError::MissingFields(missing))
+    }
+}
+
+impl Constitution {
+    /// The guardrail script that ParameterChange and TreasuryWithdrawals actions must be approved by
+    pub fn guardrail_script_hash(&self) -> Option<&ScriptHash> {
+        self.script_hash.as_ref()
+    }
+}
+
+impl NewConstitution {
+    /// Guardrail script of the proposed constitution
+    pub fn guardrail_script_hash(&self) -> Option<&ScriptHash> {
+        self.constitution.guardrail_script_hash()
+    }
+}
 
 impl GovAction {
     pub fn script_hash(&self) -> Option<&ScriptHash> {
@@ -19,6 +128,18 @@ impl GovAction {
     }
 }
 
+impl ProposalProcedure {
+    /// Strict check (see Url::validate_strict()) of the proposal's anchor URL
+    /// and of the proposed constitution's anchor URL for NewConstitution actions.
+    pub fn validate_urls(&self) -> Result<(), UrlError> {
+        self.anchor.anchor_url.validate_strict()?;
+        if let GovAction::NewConstitution(action) = &self.gov_action {
+            action.constitution.anchor.anchor_url.validate_strict()?;
+        }
+        Ok(())
+    }
+}
+
 impl Voter {
     pub fn key_hash(&self) -> Option<&Ed25519KeyHash> {
         match self {
@@ -46,3 +167,446 @@ impl Voter {
         }
     }
 }
+
+/// Latest committee certificate of a constitutional committee member
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub enum CommitteeAuthorization {
+    /// Authorized this hot credential to vote on its behalf
+    HotCredential(CommitteeHotCredential),
+    /// Resigned with this (optional) explanation
+    Resigned(Option<Anchor>),
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct CommitteeMemberState {
+    /// Last epoch the member is part of the committee. Only known for members
+    /// added by an UpdateCommittee enactment passed to this tracker.
+    pub expires_after: Option<Epoch>,
+    pub authorization: Option<CommitteeAuthorization>,
+}
+
+#[derive(serde::Deserialize, serde::Serialize)]
+struct CommitteeMemberEntry {
+    cold_credential: CommitteeColdCredential,
+    #[serde(flatten)]
+    state: CommitteeMemberState,
+}
+
+/// Keeps track of which hot credential each constitutional committee member (cold credential)
+/// has authorized and which members have resigned, e.g. for indexers to attribute committee votes.
+///
+/// Certificates are applied in chain order with apply_cert() / apply_tx_body(). Which governance
+/// actions get enacted isn't known from the certificates so UpdateCommittee enactments must be
+/// passed in via enact_update_committee(). The state serializes (serde) as a list of members.
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(from = "Vec<CommitteeMemberEntry>", into = "Vec<CommitteeMemberEntry>")]
+pub struct CommitteeState {
+    members: BTreeMap<CommitteeColdCredential, CommitteeMemberState>,
+}
+
+impl CommitteeState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Applies AuthCommitteeHotCert and ResignCommitteeColdCert certificates, ignoring all others.
+    /// An authorization replaces any previous one, including a resignation.
+    pub fn apply_cert(&mut self, cert: &Certificate) {
+        match cert {
+            Certificate::AuthCommitteeHotCert(cert) => {
+                self.members
+                    .entry(cert.committee_cold_credential.clone())
+                    .or_default()
+                    .authorization = Some(CommitteeAuthorization::HotCredential(
+                    cert.committee_hot_credential.clone(),
+                ));
+            }
+            Certificate::ResignCommitteeColdCert(cert) => {
+                self.members
+                    .entry(cert.committee_cold_credential.clone())
+                    .or_default()
+                    .authorization = Some(CommitteeAuthorization::Resigned(cert.anchor.clone()));
+            }
+            _ => (),
+        }
+    }
+
+    /// Applies the certificates of a transaction. Only pass in phase-2 valid transactions.
+    pub fn apply_tx_body(&mut self, body: &TransactionBody) {
+        if let Some(certs) = &body.certs {
+            certs.iter().for_each(|cert| self.apply_cert(cert));
+        }
+    }
+
+    /// Applies an enacted UpdateCommittee action: removed members are forgotten (along
+    /// with their authorization) and added members get their term's expiry epoch.
+    pub fn enact_update_committee(&mut self, action: &UpdateCommittee) {
+        for cold in action.cold_credentials.iter() {
+            self.members.remove(cold);
+        }
+        for (cold, epoch) in action.credentials.iter() {
+            self.members.entry(cold.clone()).or_default().expires_after = Some(*epoch);
+        }
+    }
+
+    /// Members this tracker knows of (either by certificates or enactments)
+    pub fn members(
+        &self,
+    ) -> impl Iterator<Item = (&CommitteeColdCredential, &CommitteeMemberState)> {
+        self.members.iter()
+    }
+
+    pub fn member(&self, cold: &CommitteeColdCredential) -> Option<&CommitteeMemberState> {
+        self.members.get(cold)
+    }
+
+    /// The currently authorized hot credential of this member, if it has one and hasn't resigned since
+    pub fn hot_credential_for(
+        &self,
+        cold: &CommitteeColdCredential,
+    ) -> Option<&CommitteeHotCredential> {
+        match self.members.get(cold)?.authorization.as_ref()? {
+            CommitteeAuthorization::HotCredential(hot) => Some(hot),
+            CommitteeAuthorization::Resigned(_) => None,
+        }
+    }
+
+    /// Whether the member's latest committee certificate is a resignation
+    pub fn is_resigned(&self, cold: &CommitteeColdCredential) -> bool {
+        matches!(
+            self.members
+                .get(cold)
+                .and_then(|member| member.authorization.as_ref()),
+            Some(CommitteeAuthorization::Resigned(_))
+        )
+    }
+
+    /// Members currently authorizing this hot credential. The ledger doesn't prevent
+    /// several members from authorizing the same hot credential.
+    pub fn cold_credentials_for(
+        &self,
+        hot: &CommitteeHotCredential,
+    ) -> Vec<&CommitteeColdCredential> {
+        self.members
+            .iter()
+            .filter(|(_, member)| {
+                matches!(
+                    &member.authorization,
+                    Some(CommitteeAuthorization::HotCredential(authorized)) if authorized == hot
+                )
+            })
+            .map(|(cold, _)| cold)
+            .collect()
+    }
+
+    /// The members a vote by this voter counts for. Empty for non-committee voters.
+    pub fn voter_cold_credentials(&self, voter: &Voter) -> Vec<&CommitteeColdCredential> {
+        let hot = match voter {
+            Voter::ConstitutionalCommitteeHotKeyHash {
+                ed25519_key_hash, ..
+            } => Credential::new_pub_key(*ed25519_key_hash),
+            Voter::ConstitutionalCommitteeHotScriptHash { script_hash, .. } => {
+                Credential::new_script(*script_hash)
+            }
+            _ => return Vec::new(),
+        };
+        self.cold_credentials_for(&hot)
+    }
+}
+
+impl From<Vec<CommitteeMemberEntry>> for CommitteeState {
+    fn from(entries: Vec<CommitteeMemberEntry>) -> Self {
+        Self {
+            members: entries
+                .into_iter()
+                .map(|entry| (entry.cold_credential, entry.state))
+                .collect(),
+        }
+    }
+}
+
+impl From<CommitteeState> for Vec<CommitteeMemberEntry> {
+    fn from(state: CommitteeState) -> Self {
+        state
+            .members
+            .into_iter()
+            .map(|(cold_credential, state)| CommitteeMemberEntry {
+                cold_credential,
+                state,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        address::RewardAddress,
+        certs::StakeCredential,
+        governance::{GovActionId, ProposalProcedure},
+    };
+    use cml_core::serialization::{Deserialize, Serialize};
+    use cml_crypto::TransactionHash;
+
+    #[test]
+    fn new_constitution_proposal_round_trip() {
+        let anchor = Anchor::from_url(
+            "https://example.com/constitution.txt".to_owned(),
+            AnchorDocHash::from([1; 32]),
+        )
+        .unwrap();
+        let guardrail = ScriptHash::from([2; 28]);
+        let action = GovAction::new_new_constitution(
+            Some(GovActionId::new(TransactionHash::from([3; 32]), 0)),
+            Constitution::new(anchor.clone(), Some(guardrail)),
+        );
+        match &action {
+            GovAction::NewConstitution(new_constitution) => {
+                assert_eq!(new_constitution.guardrail_script_hash(), Some(&guardrail))
+            }
+            _ => panic!(),
+        }
+        let proposal = ProposalProcedure::new(
+            100_000_000_000,
+            RewardAddress::new(
+                0,
+                StakeCredential::new_pub_key(Ed25519KeyHash::from([4; 28])),
+            ),
+            action,
+            anchor,
+        );
+        let bytes = proposal.to_cbor_bytes();
+        let decoded = ProposalProcedure::from_cbor_bytes(&bytes).unwrap();
+        assert_eq!(decoded.to_cbor_bytes(), bytes);
+        match &decoded.gov_action {
+            GovAction::NewConstitution(new_constitution) => {
+                assert_eq!(new_constitution.guardrail_script_hash(), Some(&guardrail));
+                assert_eq!(
+                    new_constitution.constitution.anchor.anchor_url.get(),
+                    "https://example.com/constitution.txt"
+                );
+            }
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn anchor_url_too_long() {
+        let url = format!("https://example.com/{}", "a".repeat(109));
+        assert_eq!(url.len(), 129);
+        let err = Anchor::from_url(url, AnchorDocHash::from([0; 32])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Anchor URL is 129 bytes long but URLs can be at most 128 bytes"
+        );
+        let url = format!("https://example.com/{}", "a".repeat(108));
+        assert!(Anchor::from_url(url, AnchorDocHash::from([0; 32])).is_ok());
+    }
+
+    #[test]
+    fn anchor_url_strict() {
+        use crate::builders::proposal_builder::{ProposalBuilder, ProposalBuilderError};
+        let hash = AnchorDocHash::from([0; 32]);
+        for url in [
+            "https://example.com/proposal.jsonld",
+            "http://example.com",
+            "ipfs://bafkreib4uyr5hz5xhwplt3ujd6xghjytgifwbg3tsjkyklnqdbppqn5y2q",
+        ] {
+            assert!(
+                Anchor::from_url_strict(url.to_owned(), hash).is_ok(),
+                "{}",
+                url
+            );
+        }
+        // no scheme
+        let no_scheme = Anchor::from_url("example.com/proposal.jsonld".to_owned(), hash).unwrap();
+        assert!(matches!(
+            no_scheme.anchor_url.validate_strict(),
+            Err(UrlError::MissingScheme)
+        ));
+        assert!(matches!(
+            Anchor::from_url_strict("ftp://example.com/a".to_owned(), hash),
+            Err(UrlError::UnsupportedScheme(scheme)) if scheme == "ftp"
+        ));
+        assert!(matches!(
+            Anchor::from_url_strict("https://example.com/a b".to_owned(), hash),
+            Err(UrlError::InvalidCharacter(' '))
+        ));
+        assert!(matches!(
+            Anchor::from_url_strict("https:///a".to_owned(), hash),
+            Err(UrlError::MissingLocation)
+        ));
+
+        // the proposal builder is strict by default
+        let proposal = ProposalProcedure::new(
+            100_000_000_000,
+            RewardAddress::new(
+                0,
+                StakeCredential::new_pub_key(Ed25519KeyHash::from([4; 28])),
+            ),
+            GovAction::new_info_action(),
+            no_scheme,
+        );
+        assert!(matches!(
+            ProposalBuilder::new().with_proposal(proposal.clone()),
+            Err(ProposalBuilderError::InvalidAnchorUrl(
+                UrlError::MissingScheme
+            ))
+        ));
+        let built = ProposalBuilder::new()
+            .skip_url_validation()
+            .with_proposal(proposal)
+            .unwrap()
+            .build();
+        assert_eq!(built.proposals.len(), 1);
+    }
+
+    const CIP108_DOCUMENT: &str = r#"{
+  "@context": {
+    "CIP100": "https://github.com/cardano-foundation/CIPs/blob/master/CIP-0100/README.md#",
+    "CIP108": "https://github.com/cardano-foundation/CIPs/blob/master/CIP-0108/README.md#",
+    "hashAlgorithm": "CIP100:hashAlgorithm",
+    "body": {
+      "@id": "CIP108:body",
+      "@context": {
+        "title": "CIP108:title",
+        "abstract": "CIP108:abstract",
+        "motivation": "CIP108:motivation",
+        "rationale": "CIP108:rationale"
+      }
+    },
+    "authors": {
+      "@id": "CIP100:authors",
+      "@container": "@set"
+    }
+  },
+  "hashAlgorithm": "blake2b-256",
+  "body": {
+    "title": "Fund the documentation working group",
+    "abstract": "Withdraw 100,000 ada from the treasury to fund documentation.",
+    "motivation": "The documentation is out of date.",
+    "rationale": "Up to date documentation helps everyone."
+  },
+  "authors": []
+}"#;
+
+    #[test]
+    fn anchor_document_hash() {
+        // reference BLAKE2b-256 digests of "" and "abc"
+        assert_eq!(
+            Anchor::hash_document(b"").to_hex(),
+            "0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8"
+        );
+        assert_eq!(
+            Anchor::hash_document(b"abc").to_hex(),
+            "bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319"
+        );
+        // from `b2sum -l 256` (what `cardano-cli hash anchor-data` computes) over the document
+        let anchor = Anchor::from_url(
+            "https://example.com/proposal.jsonld".to_owned(),
+            AnchorDocHash::from_hex(
+                "cf9b3ca2445ed0109b280849cab8299f547d51fce5de074b255e77cd8b8e1b1b",
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert!(anchor.matches_document(CIP108_DOCUMENT.as_bytes()));
+        // whitespace changes the hash as it's over the raw bytes
+        let reformatted = serde_json::to_string(
+            &serde_json::from_str::<serde_json::Value>(CIP108_DOCUMENT).unwrap(),
+        )
+        .unwrap();
+        assert!(!anchor.matches_document(reformatted.as_bytes()));
+        assert!(validate_anchor_document(CIP108_DOCUMENT.as_bytes()).is_ok());
+    }
+
+    #[test]
+    fn anchor_document_validation() {
+        assert!(matches!(
+            validate_anchor_document(b"{\"body\": {\"title\": \"t\"}}"),
+            Err(AnchorDocumentError::MissingFields(fields)) if fields == vec!["authors", "body.abstract"]
+        ));
+        assert!(matches!(
+            validate_anchor_document(b"{\"hashAlgorithm\": \"sha256\"}"),
+            Err(AnchorDocumentError::UnsupportedHashAlgorithm(algorithm)) if algorithm == "sha256"
+        ));
+        assert!(matches!(
+            validate_anchor_document(b"not json"),
+            Err(AnchorDocumentError::Json(_))
+        ));
+    }
+
+    #[test]
+    fn committee_auth_resign_reauth() {
+        use crate::certs::{AuthCommitteeHotCert, ResignCommitteeColdCert};
+        use crate::UnitInterval;
+        use cml_core::ordered_hash_map::OrderedHashMap;
+
+        let cold = Credential::new_pub_key(Ed25519KeyHash::from([1; 28]));
+        let other_cold = Credential::new_script(ScriptHash::from([2; 28]));
+        let hot = Credential::new_pub_key(Ed25519KeyHash::from([3; 28]));
+        let new_hot = Credential::new_script(ScriptHash::from([4; 28]));
+        let hot_voter =
+            Voter::new_constitutional_committee_hot_key_hash(Ed25519KeyHash::from([3; 28]));
+        let auth = |cold: &Credential, hot: &Credential| {
+            Certificate::AuthCommitteeHotCert(AuthCommitteeHotCert::new(cold.clone(), hot.clone()))
+        };
+
+        let mut state = CommitteeState::new();
+        state.apply_cert(&auth(&cold, &hot));
+        assert_eq!(state.hot_credential_for(&cold), Some(&hot));
+        assert!(!state.is_resigned(&cold));
+        assert_eq!(state.voter_cold_credentials(&hot_voter), vec![&cold]);
+
+        // two members can share a hot credential (script credentials are ordered first)
+        state.apply_cert(&auth(&other_cold, &hot));
+        assert_eq!(
+            state.voter_cold_credentials(&hot_voter),
+            vec![&other_cold, &cold]
+        );
+
+        state.apply_cert(&Certificate::ResignCommitteeColdCert(
+            ResignCommitteeColdCert::new(cold.clone(), None),
+        ));
+        assert!(state.is_resigned(&cold));
+        assert_eq!(state.hot_credential_for(&cold), None);
+        assert_eq!(state.voter_cold_credentials(&hot_voter), vec![&other_cold]);
+
+        // re-authorizing undoes the resignation
+        state.apply_cert(&auth(&cold, &new_hot));
+        assert!(!state.is_resigned(&cold));
+        assert_eq!(state.hot_credential_for(&cold), Some(&new_hot));
+        assert_eq!(
+            state.voter_cold_credentials(&Voter::new_constitutional_committee_hot_script_hash(
+                ScriptHash::from([4; 28])
+            )),
+            vec![&cold]
+        );
+        assert!(state
+            .voter_cold_credentials(&Voter::new_d_rep_key_hash(Ed25519KeyHash::from([3; 28])))
+            .is_empty());
+
+        let json = serde_json::to_string(&state).unwrap();
+        let decoded: CommitteeState = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.hot_credential_for(&cold), Some(&new_hot));
+        assert_eq!(decoded.hot_credential_for(&other_cold), Some(&hot));
+
+        // removing a member forgets its authorization, adding one records its term
+        let mut added = OrderedHashMap::new();
+        added.insert(cold.clone(), 500);
+        state.enact_update_committee(&UpdateCommittee::new(
+            None,
+            vec![other_cold.clone()].into(),
+            added,
+            UnitInterval::new(2, 3),
+        ));
+        assert!(state.member(&other_cold).is_none());
+        assert_eq!(
+            state.voter_cold_credentials(&hot_voter),
+            Vec::<&Credential>::new()
+        );
+        assert_eq!(state.member(&cold).unwrap().expires_after, Some(500));
+        assert_eq!(state.hot_credential_for(&cold), Some(&new_hot));
+    }
+}
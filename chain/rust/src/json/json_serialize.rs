@@ -544,9 +544,13 @@ impl From<Value> for serde_json::Value {
         match value {
             Value::Bool(b) => serde_json::Value::Bool(b),
             Value::Null => serde_json::Value::Null,
-            Value::Number(n) => {
-                serde_json::Value::Number(serde_json::Number::from_str(&n.to_string()).unwrap())
-            }
+            // serde_json numbers can only hold 64-bit integers exactly so anything
+            // bigger is kept as its decimal string instead of being rounded to a float
+            Value::Number(n) => match (n.as_u64(), n.as_i64()) {
+                (Some(u), _) => serde_json::Value::from(u),
+                (None, Some(i)) => serde_json::Value::from(i),
+                (None, None) => serde_json::Value::String(n.to_string()),
+            },
             Value::String(s) => serde_json::Value::String(s),
             Value::Array(arr) => {
                 serde_json::Value::Array(arr.into_iter().map(|e| e.into()).collect())
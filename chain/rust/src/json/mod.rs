@@ -1,3 +1,4 @@
 pub(crate) mod json_serialize;
 pub mod metadatums;
+pub mod native_scripts;
 pub mod plutus_datums;
@@ -0,0 +1,228 @@
+use crate::transaction::NativeScript;
+use cml_crypto::Ed25519KeyHash;
+use serde_json::{json, Map, Value as JSONValue};
+
+#[derive(Debug, thiserror::Error)]
+pub enum NativeScriptJsonError {
+    #[error("JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("native script JSON must be an object, found: {0}")]
+    NonObject(JSONValue),
+    #[error("missing field \"{0}\"")]
+    MissingField(&'static str),
+    #[error("field \"{0}\" has the wrong type: {1}")]
+    WrongType(&'static str, JSONValue),
+    #[error("unknown native script type \"{0}\"")]
+    UnknownType(String),
+    #[error("Invalid key hash: {0}")]
+    InvalidKeyHash(#[from] cml_crypto::DeserializeError),
+}
+
+impl NativeScript {
+    /// Parses the JSON format used by cardano-cli for simple scripts e.g.
+    /// {"type": "atLeast", "required": 2, "scripts": [{"type": "sig", "keyHash": "..."}, ...]}
+    /// Supported types are "sig", "all", "any", "atLeast", "after" and "before".
+    pub fn from_cli_json(json: &str) -> Result<Self, NativeScriptJsonError> {
+        let value = serde_json::from_str(json)?;
+        Self::from_cli_json_value(&value)
+    }
+
+    /// Converts to the JSON format used by cardano-cli for simple scripts.
+    pub fn to_cli_json(&self) -> Result<String, NativeScriptJsonError> {
+        serde_json::to_string_pretty(&self.to_cli_json_value()).map_err(Into::into)
+    }
+
+    pub fn from_cli_json_value(value: &JSONValue) -> Result<Self, NativeScriptJsonError> {
+        fn field<'a>(
+            obj: &'a Map<String, JSONValue>,
+            name: &'static str,
+        ) -> Result<&'a JSONValue, NativeScriptJsonError> {
+            obj.get(name)
+                .ok_or(NativeScriptJsonError::MissingField(name))
+        }
+        fn u64_field(
+            obj: &Map<String, JSONValue>,
+            name: &'static str,
+        ) -> Result<u64, NativeScriptJsonError> {
+            let value = field(obj, name)?;
+            value
+                .as_u64()
+                .ok_or_else(|| NativeScriptJsonError::WrongType(name, value.clone()))
+        }
+        fn scripts_field(
+            obj: &Map<String, JSONValue>,
+        ) -> Result<Vec<NativeScript>, NativeScriptJsonError> {
+            let value = field(obj, "scripts")?;
+            value
+                .as_array()
+                .ok_or_else(|| NativeScriptJsonError::WrongType("scripts", value.clone()))?
+                .iter()
+                .map(NativeScript::from_cli_json_value)
+                .collect()
+        }
+
+        let obj = value
+            .as_object()
+            .ok_or_else(|| NativeScriptJsonError::NonObject(value.clone()))?;
+        let script_type = field(obj, "type")?;
+        match script_type
+            .as_str()
+            .ok_or_else(|| NativeScriptJsonError::WrongType("type", script_type.clone()))?
+        {
+            "sig" => {
+                let key_hash = field(obj, "keyHash")?;
+                let key_hash = key_hash
+                    .as_str()
+                    .ok_or_else(|| NativeScriptJsonError::WrongType("keyHash", key_hash.clone()))?;
+                Ok(Self::new_script_pubkey(Ed25519KeyHash::from_hex(key_hash)?))
+            }
+            "all" => Ok(Self::new_script_all(scripts_field(obj)?)),
+            "any" => Ok(Self::new_script_any(scripts_field(obj)?)),
+            "atLeast" => Ok(Self::new_script_n_of_k(
+                u64_field(obj, "required")?,
+                scripts_field(obj)?,
+            )),
+            // cardano-cli's "after" means the tx is only valid from that slot onwards
+            "after" => Ok(Self::new_script_invalid_before(u64_field(obj, "slot")?)),
+            // cardano-cli's "before" means the tx is only valid until that slot
+            "before" => Ok(Self::new_script_invalid_hereafter(u64_field(obj, "slot")?)),
+            unknown => Err(NativeScriptJsonError::UnknownType(unknown.to_owned())),
+        }
+    }
+
+    pub fn to_cli_json_value(&self) -> JSONValue {
+        fn scripts_json(scripts: &[NativeScript]) -> Vec<JSONValue> {
+            scripts
+                .iter()
+                .map(NativeScript::to_cli_json_value)
+                .collect()
+        }
+        match self {
+            Self::ScriptPubkey(script) => json!({
+                "type": "sig",
+                "keyHash": script.ed25519_key_hash.to_hex(),
+            }),
+            Self::ScriptAll(script) => json!({
+                "type": "all",
+                "scripts": scripts_json(&script.native_scripts),
+            }),
+            Self::ScriptAny(script) => json!({
+                "type": "any",
+                "scripts": scripts_json(&script.native_scripts),
+            }),
+            Self::ScriptNOfK(script) => json!({
+                "type": "atLeast",
+                "required": script.n,
+                "scripts": scripts_json(&script.native_scripts),
+            }),
+            Self::ScriptInvalidBefore(script) => json!({
+                "type": "after",
+                "slot": script.before,
+            }),
+            Self::ScriptInvalidHereafter(script) => json!({
+                "type": "before",
+                "slot": script.after,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cml_core::serialization::Serialize;
+
+    const MULTISIG_POLICY: &str = r#"{
+        "type": "all",
+        "scripts": [
+            {
+                "type": "atLeast",
+                "required": 2,
+                "scripts": [
+                    {"type": "sig", "keyHash": "e09d36c79dec9bd1b3d9e152247701cd0bb860b5ebfd1de8abb6735a"},
+                    {"type": "sig", "keyHash": "a687dcc24e00dd3caafbeb5e68f97ca8ef269cb6fe971345eb951756"},
+                    {"type": "sig", "keyHash": "0bd1d702b2e6188fe0857a6dc7ffb0675229bab58c86638ffa87ed6d"}
+                ]
+            },
+            {"type": "after", "slot": 1000},
+            {"type": "before", "slot": 3000000}
+        ]
+    }"#;
+
+    #[test]
+    fn cli_json_multisig_round_trip() {
+        let script = NativeScript::from_cli_json(MULTISIG_POLICY).unwrap();
+        match &script {
+            NativeScript::ScriptAll(all) => {
+                assert_eq!(all.native_scripts.len(), 3);
+                match &all.native_scripts[0] {
+                    NativeScript::ScriptNOfK(n_of_k) => {
+                        assert_eq!(n_of_k.n, 2);
+                        assert_eq!(n_of_k.native_scripts.len(), 3);
+                    }
+                    _ => panic!(),
+                }
+                match &all.native_scripts[1] {
+                    NativeScript::ScriptInvalidBefore(after) => assert_eq!(after.before, 1000),
+                    _ => panic!(),
+                }
+                match &all.native_scripts[2] {
+                    NativeScript::ScriptInvalidHereafter(before) => {
+                        assert_eq!(before.after, 3000000)
+                    }
+                    _ => panic!(),
+                }
+            }
+            _ => panic!(),
+        }
+        assert_eq!(hex::encode(script.to_cbor_bytes()), "820183830302838200581ce09d36c79dec9bd1b3d9e152247701cd0bb860b5ebfd1de8abb6735a8200581ca687dcc24e00dd3caafbeb5e68f97ca8ef269cb6fe971345eb9517568200581c0bd1d702b2e6188fe0857a6dc7ffb0675229bab58c86638ffa87ed6d82041903e882051a002dc6c0");
+        // policy id as computed by `cardano-cli transaction policyid`
+        assert_eq!(
+            script.hash().to_hex(),
+            "9bb97ef261adf6c57d42584e01b8cc18f106d0026059ac8a50f540b4"
+        );
+        let sigs = [
+            "e09d36c79dec9bd1b3d9e152247701cd0bb860b5ebfd1de8abb6735a",
+            "a687dcc24e00dd3caafbeb5e68f97ca8ef269cb6fe971345eb951756",
+            "0bd1d702b2e6188fe0857a6dc7ffb0675229bab58c86638ffa87ed6d",
+        ]
+        .iter()
+        .map(|h| NativeScript::new_script_pubkey(Ed25519KeyHash::from_hex(h).unwrap()))
+        .collect::<Vec<_>>();
+        assert_eq!(
+            NativeScript::all_of(&[
+                NativeScript::at_least(2, &sigs),
+                NativeScript::new_script_invalid_before(1000),
+                NativeScript::new_script_invalid_hereafter(3000000),
+            ]),
+            script
+        );
+        let json = script.to_cli_json().unwrap();
+        let from_json = NativeScript::from_cli_json(&json).unwrap();
+        assert_eq!(from_json, script);
+        assert_eq!(
+            serde_json::from_str::<JSONValue>(&json).unwrap(),
+            serde_json::from_str::<JSONValue>(MULTISIG_POLICY).unwrap()
+        );
+    }
+
+    #[test]
+    fn cli_json_errors() {
+        assert!(matches!(
+            NativeScript::from_cli_json(r#"{"type": "sig"}"#),
+            Err(NativeScriptJsonError::MissingField("keyHash"))
+        ));
+        assert!(matches!(
+            NativeScript::from_cli_json(r#"{"type": "atLeast", "required": "2", "scripts": []}"#),
+            Err(NativeScriptJsonError::WrongType("required", _))
+        ));
+        assert!(matches!(
+            NativeScript::from_cli_json(r#"{"type": "none"}"#),
+            Err(NativeScriptJsonError::UnknownType(_))
+        ));
+        assert!(matches!(
+            NativeScript::from_cli_json("[]"),
+            Err(NativeScriptJsonError::NonObject(_))
+        ));
+    }
+}
@@ -3,6 +3,10 @@ use crate::{
     plutus::{ConstrPlutusData, PlutusData, PlutusMap},
     utils::BigInteger,
 };
+use cml_core::{
+    error::DeserializeError,
+    serialization::{Deserialize, Serialize},
+};
 use std::collections::BTreeMap;
 use std::str::FromStr;
 
@@ -45,6 +49,8 @@ pub enum CardanoNodePlutusDatumSchema {
     ///    e.g. { "constructor": 2, "fields": [{"int": 2}, {"list": [{"bytes": "CAFEF00D"}]}]}
     /// 2. For all other cases there must be only one field named "int", "bytes", "list" or "map"
     ///    BigInteger's value is a JSON number e.g. {"int": 100}
+    ///    (a decimal string e.g. {"int": "100"} is also accepted, which is how the serde JSON impls
+    ///    represent integers that don't fit in 64 bits)
     ///    Bytes' value is a hex string representing the bytes WITHOUT any prefix e.g. {"bytes": "CAFEF00D"}
     ///    Lists' value is a JSON list of its elements encoded via the same schema e.g. {"list": [{"bytes": "CAFEF00D"}]}
     ///    Maps' value is a JSON list of objects, one for each key-value pair in the map, with keys "k" and "v"
@@ -91,6 +97,12 @@ pub enum PlutusJsonError {
     DetailedKeyInBasicSchema(PlutusData),
     #[error("detailed schemas must either have only one of the following keys: \"int\", \"bytes\", \"list\" or \"map\", or both of these 2 keys: \"constructor\" + \"fields\"")]
     InvalidTaggedConstructor,
+    #[error("Not a CIP-68 datum: {0}")]
+    NotCip68(&'static str),
+    #[error("Invalid CIP-68 JSON: {0}")]
+    InvalidCip68Json(String),
+    #[error("Raw CBOR: {0}")]
+    RawCbor(#[from] DeserializeError),
 }
 
 pub fn encode_json_str_to_plutus_datum(
@@ -171,6 +183,12 @@ pub fn encode_json_value_to_plutus_datum(
                     match k.as_str() {
                         "int" => match v {
                             JSONValue::Number(x) => Ok(PlutusData::new_integer(x)),
+                            // integers too big for serde_json numbers are decimal strings there
+                            JSONValue::String(s) => BigInteger::from_str(&s)
+                                .map(PlutusData::new_integer)
+                                .map_err(|_| {
+                                    PlutusJsonError::DetailedKeyMismatch(k, JSONValue::String(s))
+                                }),
                             _ => Err(PlutusJsonError::DetailedKeyMismatch(k, v)),
                         },
                         "bytes" => match v {
@@ -337,9 +355,225 @@ pub fn decode_plutus_datum_to_json_value(
     }
 }
 
+/// JSON object key marking a value with no flat CIP-68 JSON form.
+/// Its value is the hex of the original CBOR so it round-trips byte-for-byte.
+pub const CIP68_RAW_KEY: &str = "@raw";
+
+/// Renders a CIP-68 datum (`#6.121([metadata, version, extra])`) as flat JSON e.g.
+/// `{"extra":{"@raw":"d87980"},"metadata":{"name":"Token #1"},"version":1}`
+///
+/// Metadata conversions:
+/// * bytes are strings if they are printable utf8 not starting with "0x", otherwise "0x"-prefixed hex
+/// * integers within the 64-bit range are numbers
+/// * lists are arrays
+/// * maps whose keys all render to distinct strings are objects
+/// * anything else (constructors, other maps, big integers) is written as {"@raw": "<cbor hex>"}
+///
+/// `extra` and any fields past it (under "unknown_fields") are always written raw.
+/// Object keys are sorted and no whitespace is emitted so the output can be compared or hashed directly.
+pub fn decode_cip68_datum_to_json_str(datum: &PlutusData) -> Result<String, PlutusJsonError> {
+    decode_cip68_datum_to_json_value(datum)
+        .and_then(|v| serde_json::to_string(&v).map_err(Into::into))
+}
+
+pub fn decode_cip68_datum_to_json_value(
+    datum: &PlutusData,
+) -> Result<serde_json::Value, PlutusJsonError> {
+    let fields = match datum {
+        PlutusData::ConstrPlutusData(constr) if constr.alternative == 0 => &constr.fields,
+        _ => return Err(PlutusJsonError::NotCip68("expected constructor 0")),
+    };
+    let metadata = match fields.first() {
+        Some(PlutusData::Map(map)) => cip68_map_to_json(map)
+            .ok_or(PlutusJsonError::NotCip68("metadata keys must be bytes"))?,
+        _ => return Err(PlutusJsonError::NotCip68("metadata must be a map")),
+    };
+    let version = fields
+        .get(1)
+        .and_then(cip68_integer_to_json)
+        .ok_or(PlutusJsonError::NotCip68("version must be an integer"))?;
+    let mut obj = serde_json::Map::new();
+    obj.insert(String::from("metadata"), metadata);
+    obj.insert(String::from("version"), version);
+    if let Some(extra) = fields.get(2) {
+        obj.insert(String::from("extra"), cip68_raw_to_json(extra));
+    }
+    if fields.len() > 3 {
+        obj.insert(
+            String::from("unknown_fields"),
+            serde_json::Value::Array(fields[3..].iter().map(cip68_raw_to_json).collect()),
+        );
+    }
+    Ok(serde_json::Value::Object(obj))
+}
+
+/// Inverse of `decode_cip68_datum_to_json_str`. Strings starting with "0x" are read as hex bytes
+/// and objects of the form {"@raw": "<cbor hex>"} are decoded as-is.
+/// Metadata maps are re-created with their keys in sorted order.
+pub fn encode_cip68_json_str_to_plutus_datum(json: &str) -> Result<PlutusData, PlutusJsonError> {
+    let value: serde_json::Value = serde_json::from_str(json)?;
+    encode_cip68_json_value_to_plutus_datum(value)
+}
+
+pub fn encode_cip68_json_value_to_plutus_datum(
+    value: serde_json::Value,
+) -> Result<PlutusData, PlutusJsonError> {
+    let mut obj = match value {
+        serde_json::Value::Object(obj) => obj,
+        _ => {
+            return Err(PlutusJsonError::InvalidCip68Json(String::from(
+                "top level must be an object",
+            )))
+        }
+    };
+    let mut fields = Vec::new();
+    match obj.remove("metadata") {
+        Some(metadata @ serde_json::Value::Object(_)) => {
+            fields.push(cip68_json_to_plutus_datum(metadata)?)
+        }
+        _ => {
+            return Err(PlutusJsonError::InvalidCip68Json(String::from(
+                "\"metadata\" must be an object",
+            )))
+        }
+    }
+    match obj.remove("version") {
+        Some(version @ serde_json::Value::Number(_)) => {
+            fields.push(cip68_json_to_plutus_datum(version)?)
+        }
+        _ => {
+            return Err(PlutusJsonError::InvalidCip68Json(String::from(
+                "\"version\" must be a number",
+            )))
+        }
+    }
+    if let Some(extra) = obj.remove("extra") {
+        fields.push(cip68_json_to_plutus_datum(extra)?);
+    }
+    match obj.remove("unknown_fields") {
+        Some(serde_json::Value::Array(unknown)) if fields.len() == 3 => {
+            for field in unknown {
+                fields.push(cip68_json_to_plutus_datum(field)?);
+            }
+        }
+        Some(_) => {
+            return Err(PlutusJsonError::InvalidCip68Json(String::from(
+                "\"unknown_fields\" must be an array and requires \"extra\"",
+            )))
+        }
+        None => (),
+    }
+    if let Some(key) = obj.keys().next() {
+        return Err(PlutusJsonError::InvalidCip68Json(format!(
+            "unexpected key \"{key}\""
+        )));
+    }
+    Ok(PlutusData::new_constr_plutus_data(ConstrPlutusData::new(
+        0, fields,
+    )))
+}
+
+fn cip68_bytes_to_string(bytes: &[u8]) -> String {
+    std::str::from_utf8(bytes)
+        .ok()
+        .filter(|utf8| !utf8.starts_with("0x") && utf8.chars().all(|c| !c.is_control()))
+        .map(String::from)
+        .unwrap_or_else(|| format!("0x{}", hex::encode(bytes)))
+}
+
+fn cip68_integer_to_json(datum: &PlutusData) -> Option<serde_json::Value> {
+    datum
+        .as_u64()
+        .map(serde_json::Value::from)
+        .or_else(|| datum.as_i64().map(serde_json::Value::from))
+}
+
+fn cip68_raw_to_json(datum: &PlutusData) -> serde_json::Value {
+    let mut obj = serde_json::Map::new();
+    obj.insert(
+        String::from(CIP68_RAW_KEY),
+        serde_json::Value::String(hex::encode(datum.to_cbor_bytes())),
+    );
+    serde_json::Value::Object(obj)
+}
+
+fn cip68_map_to_json(map: &PlutusMap) -> Option<serde_json::Value> {
+    let mut obj = serde_json::Map::new();
+    for (key, value) in map.entries.iter() {
+        let key = match key {
+            PlutusData::Bytes { bytes, .. } => cip68_bytes_to_string(bytes),
+            _ => return None,
+        };
+        if obj.insert(key, cip68_value_to_json(value)).is_some() {
+            // duplicate keys can't be represented in a JSON object
+            return None;
+        }
+    }
+    if obj.len() == 1 && obj.contains_key(CIP68_RAW_KEY) {
+        // would be mistaken for the raw escape hatch
+        return None;
+    }
+    Some(serde_json::Value::Object(obj))
+}
+
+fn cip68_value_to_json(datum: &PlutusData) -> serde_json::Value {
+    match datum {
+        PlutusData::Bytes { bytes, .. } => serde_json::Value::String(cip68_bytes_to_string(bytes)),
+        PlutusData::Integer(_) => {
+            cip68_integer_to_json(datum).unwrap_or_else(|| cip68_raw_to_json(datum))
+        }
+        PlutusData::List { list, .. } => {
+            serde_json::Value::Array(list.iter().map(cip68_value_to_json).collect())
+        }
+        PlutusData::Map(map) => cip68_map_to_json(map).unwrap_or_else(|| cip68_raw_to_json(datum)),
+        PlutusData::ConstrPlutusData(_) => cip68_raw_to_json(datum),
+    }
+}
+
+fn cip68_string_to_bytes(s: &str) -> Result<Vec<u8>, PlutusJsonError> {
+    match s.strip_prefix("0x") {
+        Some(stripped) => hex::decode(stripped).map_err(Into::into),
+        None => Ok(s.as_bytes().to_vec()),
+    }
+}
+
+fn cip68_json_to_plutus_datum(value: serde_json::Value) -> Result<PlutusData, PlutusJsonError> {
+    match value {
+        serde_json::Value::Null => Err(PlutusJsonError::NullFound),
+        serde_json::Value::Bool(_) => Err(PlutusJsonError::BoolFound),
+        serde_json::Value::Number(number) => number
+            .as_u64()
+            .map(PlutusData::new_integer_from_u64)
+            .or_else(|| number.as_i64().map(PlutusData::new_integer_from_i64))
+            .ok_or_else(|| {
+                PlutusJsonError::InvalidCip68Json(format!("{number} is not an integer"))
+            }),
+        serde_json::Value::String(s) => cip68_string_to_bytes(&s).map(PlutusData::new_bytes),
+        serde_json::Value::Array(arr) => arr
+            .into_iter()
+            .map(cip68_json_to_plutus_datum)
+            .collect::<Result<Vec<_>, _>>()
+            .map(PlutusData::new_list),
+        serde_json::Value::Object(obj) => {
+            if let (1, Some(serde_json::Value::String(raw))) = (obj.len(), obj.get(CIP68_RAW_KEY)) {
+                return PlutusData::from_cbor_bytes(&hex::decode(raw)?).map_err(Into::into);
+            }
+            let mut map = PlutusMap::new();
+            for (key, value) in obj {
+                map.set(
+                    PlutusData::new_bytes(cip68_string_to_bytes(&key)?),
+                    cip68_json_to_plutus_datum(value)?,
+                );
+            }
+            Ok(PlutusData::new_map(map))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::plutus::PlutusData;
+    use crate::plutus::{ConstrPlutusData, PlutusData, PlutusMap};
+    use cml_core::serialization::{Deserialize, Serialize};
 
     #[test]
     fn plutus_datum_json() {
@@ -348,4 +582,104 @@ mod tests {
         let datum: PlutusData = serde_json::from_str(json).unwrap();
         assert_eq!(json, serde_json::to_string(&datum).unwrap());
     }
+
+    #[test]
+    fn cip68_reference_datum_json() {
+        // reference NFT datum in the shape produced by common minting tools:
+        // indefinite constructor fields, unsorted metadata keys and a unit extra
+        let cbor = "d8799fa4446e616d654b546f6b656e20233134383745696d6167655835697066733a2f2f516d527a6963705265757477436b4d36616f74754b6a4572464355443231334470775071364279757a4d4a617561496d656469615479706549696d6167652f706e674566696c65739fa3496d656469615479706549696d6167652f706e67446e616d654b546f6b656e202331343837437372635835697066733a2f2f516d527a6963705265757477436b4d36616f74754b6a4572464355443231334470775071364279757a4d4a617561ff01d87980ff";
+        let datum = PlutusData::from_cbor_bytes(&hex::decode(cbor).unwrap()).unwrap();
+        let json = datum.to_cip68_json().unwrap();
+        assert_eq!(
+            json,
+            "{\"extra\":{\"@raw\":\"d87980\"},\"metadata\":{\"files\":[{\"mediaType\":\"image/png\",\"name\":\"Token #1487\",\"src\":\"ipfs://QmRzicpReutwCkM6aotuKjErFCUD213DpwPq6ByuzMJaua\"}],\"image\":\"ipfs://QmRzicpReutwCkM6aotuKjErFCUD213DpwPq6ByuzMJaua\",\"mediaType\":\"image/png\",\"name\":\"Token #1487\"},\"version\":1}"
+        );
+        let authored = PlutusData::from_cip68_json(&json).unwrap();
+        // key order is canonicalized so only the JSON form is stable, not the bytes
+        assert_eq!(authored.to_cip68_json().unwrap(), json);
+        assert_eq!(
+            authored.to_cardano_node_format().to_cip68_json().unwrap(),
+            json
+        );
+    }
+
+    #[test]
+    fn cip68_raw_escapes() {
+        let mut int_keyed = PlutusMap::new();
+        int_keyed.set(
+            PlutusData::new_integer_from_u64(1),
+            PlutusData::new_bytes(vec![]),
+        );
+        let mut collision = PlutusMap::new();
+        collision.set(
+            PlutusData::new_bytes(b"@raw".to_vec()),
+            PlutusData::new_bytes(b"00".to_vec()),
+        );
+        let mut metadata = PlutusMap::new();
+        metadata.set(
+            PlutusData::new_bytes(vec![0xff, 0x00]),
+            PlutusData::new_bytes(b"0xnot hex".to_vec()),
+        );
+        metadata.set(
+            PlutusData::new_bytes(b"int_keyed".to_vec()),
+            PlutusData::new_map(int_keyed),
+        );
+        metadata.set(
+            PlutusData::new_bytes(b"collision".to_vec()),
+            PlutusData::new_map(collision),
+        );
+        metadata.set(
+            PlutusData::new_bytes(b"constr".to_vec()),
+            PlutusData::new_constr_plutus_data(ConstrPlutusData::new(1, vec![])),
+        );
+        metadata.set(
+            PlutusData::new_bytes(b"big".to_vec()),
+            PlutusData::new_integer_from_str("-18446744073709551616").unwrap(),
+        );
+        let datum = PlutusData::new_constr_plutus_data(ConstrPlutusData::new(
+            0,
+            vec![
+                PlutusData::new_map(metadata),
+                PlutusData::new_integer_from_u64(2),
+                PlutusData::new_bytes(vec![0xca, 0xfe]),
+                PlutusData::new_list(vec![PlutusData::new_integer_from_i64(-1)]),
+            ],
+        ));
+        let json = datum.to_cip68_json().unwrap();
+        assert_eq!(
+            json,
+            "{\"extra\":{\"@raw\":\"42cafe\"},\"metadata\":{\"0xff00\":\"0x30786e6f7420686578\",\"big\":{\"@raw\":\"3bffffffffffffffff\"},\"collision\":{\"@raw\":\"a14440726177423030\"},\"constr\":{\"@raw\":\"d87a80\"},\"int_keyed\":{\"@raw\":\"a10140\"}},\"unknown_fields\":[{\"@raw\":\"8120\"}],\"version\":2}"
+        );
+        let authored = PlutusData::from_cip68_json(&json).unwrap();
+        assert_eq!(authored.to_cip68_json().unwrap(), json);
+        match &authored {
+            PlutusData::ConstrPlutusData(constr) => {
+                assert_eq!(constr.fields.len(), 4);
+                assert_eq!(
+                    constr.fields[2].to_cbor_bytes(),
+                    hex::decode("42cafe").unwrap()
+                );
+                assert_eq!(
+                    constr.fields[3].to_cbor_bytes(),
+                    hex::decode("8120").unwrap()
+                );
+            }
+            _ => panic!("expected constructor"),
+        }
+    }
+
+    #[test]
+    fn cip68_not_a_datum() {
+        assert!(PlutusData::new_integer_from_u64(0).to_cip68_json().is_err());
+        let wrong_alt = PlutusData::new_constr_plutus_data(ConstrPlutusData::new(
+            1,
+            vec![
+                PlutusData::new_map(PlutusMap::new()),
+                PlutusData::new_integer_from_u64(1),
+            ],
+        ));
+        assert!(wrong_alt.to_cip68_json().is_err());
+        assert!(PlutusData::from_cip68_json("{\"metadata\":{},\"version\":1,\"foo\":1}").is_err());
+        assert!(PlutusData::from_cip68_json("{\"metadata\":{},\"version\":1.5}").is_err());
+    }
 }
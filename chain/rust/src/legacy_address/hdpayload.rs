@@ -8,6 +8,7 @@
 //! symmetric key used to encrypt, can then decrypt the address
 //! payload and find the derivation path associated with it.
 //!
+use cml_core::serialization::StripEncodings;
 use cryptoxide::chacha20poly1305::ChaCha20Poly1305;
 use cryptoxide::hmac::Hmac;
 use cryptoxide::pbkdf2::pbkdf2;
@@ -83,7 +84,7 @@ pub const MAX_PAYLOAD_SIZE: usize = 48;
 pub type Result<T> = ::std::result::Result<T, Error>;
 
 /// A derivation path of HD wallet derivation indices which uses a CBOR encoding
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, StripEncodings)]
 #[cfg_attr(feature = "generic-serialization", derive(Serialize, Deserialize))]
 pub struct Path(Vec<u32>);
 impl Deref for Path {
@@ -215,7 +216,7 @@ impl Drop for HDKey {
 ///
 /// It's however possible to store anything in this attributes, including
 /// non encrypted information.
-#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, StripEncodings)]
 #[cfg_attr(feature = "generic-serialization", derive(Serialize, Deserialize))]
 pub struct HDAddressPayload(Vec<u8>);
 impl AsRef<[u8]> for HDAddressPayload {
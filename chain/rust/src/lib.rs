@@ -10,6 +10,7 @@
 // from within their proc macros itself. Issue: https://github.com/mcarton/rust-derivative/issues/115
 #![allow(clippy::non_canonical_partial_ord_impl)]
 
+pub mod account;
 pub mod address;
 pub mod assets;
 pub mod auxdata;
@@ -26,6 +27,8 @@ pub mod json;
 pub mod min_ada;
 pub mod plutus;
 pub mod serialization;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 pub mod transaction;
 pub mod utils;
 
@@ -41,7 +44,7 @@ pub use utils::{NetworkId, NonemptySet, Set};
 pub use cml_core::{
     error::{DeserializeError, DeserializeFailure},
     ordered_hash_map::OrderedHashMap,
-    serialization::{Deserialize, LenEncoding, Serialize, StringEncoding},
+    serialization::{Deserialize, LenEncoding, Serialize, StringEncoding, StripEncodings},
     CertificateIndex, Epoch, Int, Slot, TransactionIndex,
 };
 
@@ -63,7 +66,9 @@ use plutus::{
 };
 use transaction::{NativeScript, TransactionInput};
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct DRepVotingThresholds {
     pub motion_no_confidence: UnitInterval,
     pub committee_normal: UnitInterval,
@@ -137,7 +142,9 @@ pub type PolicyIdList = Vec<PolicyId>;
 
 pub type RequiredSigners = NonemptySetRawBytes<Ed25519KeyHash>;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct PoolVotingThresholds {
     pub motion_no_confidence: UnitInterval,
     pub committee_normal: UnitInterval,
@@ -169,7 +176,9 @@ impl PoolVotingThresholds {
 
 pub type Port = u16;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ProtocolParamUpdate {
     pub minfee_a: Option<Coin>,
     pub minfee_b: Option<Coin>,
@@ -249,7 +258,9 @@ impl Default for ProtocolParamUpdate {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct Rational {
     pub numerator: u64,
     pub denominator: u64,
@@ -270,7 +281,13 @@ impl Rational {
 pub type RewardAccountList = Vec<RewardAccount>;
 
 #[derive(
-    Clone, Debug, derivative::Derivative, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+    Clone,
+    Debug,
+    derivative::Derivative,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    StripEncodings,
 )]
 #[derivative(Hash, PartialEq)]
 pub enum Script {
@@ -353,7 +370,9 @@ pub type SetTransactionInput = Set<TransactionInput>;
 
 pub type SubCoin = Rational;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct UnitInterval {
     pub start: u64,
     pub end: u64,
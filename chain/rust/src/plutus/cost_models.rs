@@ -0,0 +1,1133 @@
+//! Human-readable names for the Plutus cost model parameters.
+//!
+//! On chain a cost model is a bare list of integers whose meaning depends on
+//! its position. Explorers and APIs (e.g. Blockfrost, Ogmios) instead expose them
+//! as JSON objects keyed by parameter name, and since those objects are often
+//! alphabetized the original order can't be recovered without knowing the ledger's
+//! order. The tables here list each language's parameters in that ledger order.
+
+use super::{CostModels, Language};
+use cml_core::ordered_hash_map::OrderedHashMap;
+use std::convert::TryFrom;
+
+#[derive(Debug, thiserror::Error)]
+pub enum CostModelNamesError {
+    #[error("Unknown Plutus language id: {0}")]
+    UnknownLanguage(u64),
+    #[error("Unknown Plutus language key: {0}")]
+    UnknownLanguageKey(String),
+    #[error("{language:?} cost model has {found} parameters but at most {max} are known")]
+    TooManyParams {
+        language: Language,
+        found: usize,
+        max: usize,
+    },
+    #[error("Unknown {language:?} cost model parameter: {name}")]
+    UnknownParam { language: Language, name: String },
+    #[error("{language:?} cost model parameter {name} is given more than once (e.g. under both its current and legacy name)")]
+    DuplicateParam {
+        language: Language,
+        name: &'static str,
+    },
+    #[error("Missing {language:?} cost model parameter: {name}")]
+    MissingParam {
+        language: Language,
+        name: &'static str,
+    },
+    #[error("{language:?} cost model parameter {name} is not a 64-bit integer")]
+    InvalidValue { language: Language, name: String },
+    #[error("Invalid named cost models JSON: {0}")]
+    InvalidJson(&'static str),
+}
+
+/// The cost model parameter names for `language` in ledger order, as of the given
+/// major protocol version. New builtins only ever append parameters so the result
+/// for an older protocol version is always a prefix of a newer one.
+/// Returns an empty slice if the language isn't enabled at that protocol version.
+pub fn cost_model_param_names(
+    language: Language,
+    protocol_version: u64,
+) -> &'static [&'static str] {
+    let len = match (language, protocol_version) {
+        (Language::PlutusV1, 5..) => PLUTUS_V1_NAMES.len(),
+        (Language::PlutusV2, 7..=9) => 175,
+        (Language::PlutusV2, 10..) => PLUTUS_V2_NAMES.len(),
+        (Language::PlutusV3, 9) => 251,
+        (Language::PlutusV3, 10..) => PLUTUS_V3_NAMES.len(),
+        _ => 0,
+    };
+    &all_param_names(language)[..len]
+}
+
+/// Every known parameter name for `language` (i.e. as of the latest protocol version)
+fn all_param_names(language: Language) -> &'static [&'static str] {
+    match language {
+        Language::PlutusV1 => &PLUTUS_V1_NAMES,
+        Language::PlutusV2 => &PLUTUS_V2_NAMES,
+        Language::PlutusV3 => &PLUTUS_V3_NAMES,
+    }
+}
+
+/// Maps names used by older node versions to their current form
+fn canonical_param_name(language: Language, name: &str) -> std::borrow::Cow<'_, str> {
+    if language == Language::PlutusV1 {
+        if let Some(rest) = name.strip_prefix("blake2b-") {
+            return format!("blake2b_256-{}", rest).into();
+        }
+        if let Some(rest) = name.strip_prefix("verifySignature-") {
+            return format!("verifyEd25519Signature-{}", rest).into();
+        }
+    }
+    name.into()
+}
+
+fn language_from_key(key: &str) -> Result<Language, CostModelNamesError> {
+    match key {
+        "PlutusV1" | "plutus:v1" => Ok(Language::PlutusV1),
+        "PlutusV2" | "plutus:v2" => Ok(Language::PlutusV2),
+        "PlutusV3" | "plutus:v3" => Ok(Language::PlutusV3),
+        _ => Err(CostModelNamesError::UnknownLanguageKey(key.to_owned())),
+    }
+}
+
+impl CostModels {
+    /// Converts to a JSON object keyed by language (e.g. "PlutusV1") where each cost
+    /// model is an object from parameter name to value.
+    pub fn to_named_json(&self) -> Result<serde_json::Value, CostModelNamesError> {
+        let mut languages = serde_json::Map::new();
+        for (id, costs) in self.inner.iter() {
+            let language =
+                Language::try_from(*id).map_err(|_| CostModelNamesError::UnknownLanguage(*id))?;
+            let names = all_param_names(language);
+            if costs.len() > names.len() {
+                return Err(CostModelNamesError::TooManyParams {
+                    language,
+                    found: costs.len(),
+                    max: names.len(),
+                });
+            }
+            let params = names
+                .iter()
+                .zip(costs.iter())
+                .map(|(name, cost)| (name.to_string(), serde_json::Value::from(*cost)))
+                .collect();
+            languages.insert(format!("{:?}", language), serde_json::Value::Object(params));
+        }
+        Ok(serde_json::Value::Object(languages))
+    }
+
+    /// Parses cost models in the format produced by `to_named_json()`.
+    /// The parameter objects can be in any order (e.g. alphabetized) as the values are
+    /// placed by their ledger position. Languages can be keyed by "PlutusV1" or "plutus:v1"
+    /// and a cost model may also be given directly as an already-ordered array.
+    pub fn from_named_json(json: &serde_json::Value) -> Result<Self, CostModelNamesError> {
+        let languages = json.as_object().ok_or(CostModelNamesError::InvalidJson(
+            "expected an object of languages",
+        ))?;
+        let mut inner = OrderedHashMap::new();
+        for (key, params) in languages.iter() {
+            let language = language_from_key(key)?;
+            let costs = match params {
+                serde_json::Value::Array(values) => values
+                    .iter()
+                    .enumerate()
+                    .map(|(i, value)| {
+                        value
+                            .as_i64()
+                            .ok_or_else(|| CostModelNamesError::InvalidValue {
+                                language,
+                                name: i.to_string(),
+                            })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?,
+                serde_json::Value::Object(named) => named_costs(language, named)?,
+                _ => {
+                    return Err(CostModelNamesError::InvalidJson(
+                        "expected a cost model object or array",
+                    ))
+                }
+            };
+            inner.insert(language.into(), costs);
+        }
+        Ok(Self::new(inner))
+    }
+}
+
+fn named_costs(
+    language: Language,
+    named: &serde_json::Map<String, serde_json::Value>,
+) -> Result<Vec<i64>, CostModelNamesError> {
+    let names = all_param_names(language);
+    let mut costs = vec![None; names.len()];
+    for (name, value) in named.iter() {
+        let canonical = canonical_param_name(language, name);
+        let index = names
+            .iter()
+            .position(|known| *known == canonical)
+            .ok_or_else(|| CostModelNamesError::UnknownParam {
+                language,
+                name: name.clone(),
+            })?;
+        if costs[index].is_some() {
+            return Err(CostModelNamesError::DuplicateParam {
+                language,
+                name: names[index],
+            });
+        }
+        costs[index] = Some(
+            value
+                .as_i64()
+                .ok_or_else(|| CostModelNamesError::InvalidValue {
+                    language,
+                    name: name.clone(),
+                })?,
+        );
+    }
+    // parameters only get appended so a valid cost model is a prefix of the full list
+    let len = named.len();
+    costs
+        .into_iter()
+        .take(len)
+        .zip(names.iter())
+        .map(|(cost, name)| cost.ok_or(CostModelNamesError::MissingParam { language, name }))
+        .collect()
+}
+
+static PLUTUS_V1_NAMES: [&str; 166] = [
+    "addInteger-cpu-arguments-intercept",
+    "addInteger-cpu-arguments-slope",
+    "addInteger-memory-arguments-intercept",
+    "addInteger-memory-arguments-slope",
+    "appendByteString-cpu-arguments-intercept",
+    "appendByteString-cpu-arguments-slope",
+    "appendByteString-memory-arguments-intercept",
+    "appendByteString-memory-arguments-slope",
+    "appendString-cpu-arguments-intercept",
+    "appendString-cpu-arguments-slope",
+    "appendString-memory-arguments-intercept",
+    "appendString-memory-arguments-slope",
+    "bData-cpu-arguments",
+    "bData-memory-arguments",
+    "blake2b_256-cpu-arguments-intercept",
+    "blake2b_256-cpu-arguments-slope",
+    "blake2b_256-memory-arguments",
+    "cekApplyCost-exBudgetCPU",
+    "cekApplyCost-exBudgetMemory",
+    "cekBuiltinCost-exBudgetCPU",
+    "cekBuiltinCost-exBudgetMemory",
+    "cekConstCost-exBudgetCPU",
+    "cekConstCost-exBudgetMemory",
+    "cekDelayCost-exBudgetCPU",
+    "cekDelayCost-exBudgetMemory",
+    "cekForceCost-exBudgetCPU",
+    "cekForceCost-exBudgetMemory",
+    "cekLamCost-exBudgetCPU",
+    "cekLamCost-exBudgetMemory",
+    "cekStartupCost-exBudgetCPU",
+    "cekStartupCost-exBudgetMemory",
+    "cekVarCost-exBudgetCPU",
+    "cekVarCost-exBudgetMemory",
+    "chooseData-cpu-arguments",
+    "chooseData-memory-arguments",
+    "chooseList-cpu-arguments",
+    "chooseList-memory-arguments",
+    "chooseUnit-cpu-arguments",
+    "chooseUnit-memory-arguments",
+    "consByteString-cpu-arguments-intercept",
+    "consByteString-cpu-arguments-slope",
+    "consByteString-memory-arguments-intercept",
+    "consByteString-memory-arguments-slope",
+    "constrData-cpu-arguments",
+    "constrData-memory-arguments",
+    "decodeUtf8-cpu-arguments-intercept",
+    "decodeUtf8-cpu-arguments-slope",
+    "decodeUtf8-memory-arguments-intercept",
+    "decodeUtf8-memory-arguments-slope",
+    "divideInteger-cpu-arguments-constant",
+    "divideInteger-cpu-arguments-model-arguments-intercept",
+    "divideInteger-cpu-arguments-model-arguments-slope",
+    "divideInteger-memory-arguments-intercept",
+    "divideInteger-memory-arguments-minimum",
+    "divideInteger-memory-arguments-slope",
+    "encodeUtf8-cpu-arguments-intercept",
+    "encodeUtf8-cpu-arguments-slope",
+    "encodeUtf8-memory-arguments-intercept",
+    "encodeUtf8-memory-arguments-slope",
+    "equalsByteString-cpu-arguments-constant",
+    "equalsByteString-cpu-arguments-intercept",
+    "equalsByteString-cpu-arguments-slope",
+    "equalsByteString-memory-arguments",
+    "equalsData-cpu-arguments-intercept",
+    "equalsData-cpu-arguments-slope",
+    "equalsData-memory-arguments",
+    "equalsInteger-cpu-arguments-intercept",
+    "equalsInteger-cpu-arguments-slope",
+    "equalsInteger-memory-arguments",
+    "equalsString-cpu-arguments-constant",
+    "equalsString-cpu-arguments-intercept",
+    "equalsString-cpu-arguments-slope",
+    "equalsString-memory-arguments",
+    "fstPair-cpu-arguments",
+    "fstPair-memory-arguments",
+    "headList-cpu-arguments",
+    "headList-memory-arguments",
+    "iData-cpu-arguments",
+    "iData-memory-arguments",
+    "ifThenElse-cpu-arguments",
+    "ifThenElse-memory-arguments",
+    "indexByteString-cpu-arguments",
+    "indexByteString-memory-arguments",
+    "lengthOfByteString-cpu-arguments",
+    "lengthOfByteString-memory-arguments",
+    "lessThanByteString-cpu-arguments-intercept",
+    "lessThanByteString-cpu-arguments-slope",
+    "lessThanByteString-memory-arguments",
+    "lessThanEqualsByteString-cpu-arguments-intercept",
+    "lessThanEqualsByteString-cpu-arguments-slope",
+    "lessThanEqualsByteString-memory-arguments",
+    "lessThanEqualsInteger-cpu-arguments-intercept",
+    "lessThanEqualsInteger-cpu-arguments-slope",
+    "lessThanEqualsInteger-memory-arguments",
+    "lessThanInteger-cpu-arguments-intercept",
+    "lessThanInteger-cpu-arguments-slope",
+    "lessThanInteger-memory-arguments",
+    "listData-cpu-arguments",
+    "listData-memory-arguments",
+    "mapData-cpu-arguments",
+    "mapData-memory-arguments",
+    "mkCons-cpu-arguments",
+    "mkCons-memory-arguments",
+    "mkNilData-cpu-arguments",
+    "mkNilData-memory-arguments",
+    "mkNilPairData-cpu-arguments",
+    "mkNilPairData-memory-arguments",
+    "mkPairData-cpu-arguments",
+    "mkPairData-memory-arguments",
+    "modInteger-cpu-arguments-constant",
+    "modInteger-cpu-arguments-model-arguments-intercept",
+    "modInteger-cpu-arguments-model-arguments-slope",
+    "modInteger-memory-arguments-intercept",
+    "modInteger-memory-arguments-minimum",
+    "modInteger-memory-arguments-slope",
+    "multiplyInteger-cpu-arguments-intercept",
+    "multiplyInteger-cpu-arguments-slope",
+    "multiplyInteger-memory-arguments-intercept",
+    "multiplyInteger-memory-arguments-slope",
+    "nullList-cpu-arguments",
+    "nullList-memory-arguments",
+    "quotientInteger-cpu-arguments-constant",
+    "quotientInteger-cpu-arguments-model-arguments-intercept",
+    "quotientInteger-cpu-arguments-model-arguments-slope",
+    "quotientInteger-memory-arguments-intercept",
+    "quotientInteger-memory-arguments-minimum",
+    "quotientInteger-memory-arguments-slope",
+    "remainderInteger-cpu-arguments-constant",
+    "remainderInteger-cpu-arguments-model-arguments-intercept",
+    "remainderInteger-cpu-arguments-model-arguments-slope",
+    "remainderInteger-memory-arguments-intercept",
+    "remainderInteger-memory-arguments-minimum",
+    "remainderInteger-memory-arguments-slope",
+    "sha2_256-cpu-arguments-intercept",
+    "sha2_256-cpu-arguments-slope",
+    "sha2_256-memory-arguments",
+    "sha3_256-cpu-arguments-intercept",
+    "sha3_256-cpu-arguments-slope",
+    "sha3_256-memory-arguments",
+    "sliceByteString-cpu-arguments-intercept",
+    "sliceByteString-cpu-arguments-slope",
+    "sliceByteString-memory-arguments-intercept",
+    "sliceByteString-memory-arguments-slope",
+    "sndPair-cpu-arguments",
+    "sndPair-memory-arguments",
+    "subtractInteger-cpu-arguments-intercept",
+    "subtractInteger-cpu-arguments-slope",
+    "subtractInteger-memory-arguments-intercept",
+    "subtractInteger-memory-arguments-slope",
+    "tailList-cpu-arguments",
+    "tailList-memory-arguments",
+    "trace-cpu-arguments",
+    "trace-memory-arguments",
+    "unBData-cpu-arguments",
+    "unBData-memory-arguments",
+    "unConstrData-cpu-arguments",
+    "unConstrData-memory-arguments",
+    "unIData-cpu-arguments",
+    "unIData-memory-arguments",
+    "unListData-cpu-arguments",
+    "unListData-memory-arguments",
+    "unMapData-cpu-arguments",
+    "unMapData-memory-arguments",
+    "verifyEd25519Signature-cpu-arguments-intercept",
+    "verifyEd25519Signature-cpu-arguments-slope",
+    "verifyEd25519Signature-memory-arguments",
+];
+
+static PLUTUS_V2_NAMES: [&str; 185] = [
+    "addInteger-cpu-arguments-intercept",
+    "addInteger-cpu-arguments-slope",
+    "addInteger-memory-arguments-intercept",
+    "addInteger-memory-arguments-slope",
+    "appendByteString-cpu-arguments-intercept",
+    "appendByteString-cpu-arguments-slope",
+    "appendByteString-memory-arguments-intercept",
+    "appendByteString-memory-arguments-slope",
+    "appendString-cpu-arguments-intercept",
+    "appendString-cpu-arguments-slope",
+    "appendString-memory-arguments-intercept",
+    "appendString-memory-arguments-slope",
+    "bData-cpu-arguments",
+    "bData-memory-arguments",
+    "blake2b_256-cpu-arguments-intercept",
+    "blake2b_256-cpu-arguments-slope",
+    "blake2b_256-memory-arguments",
+    "cekApplyCost-exBudgetCPU",
+    "cekApplyCost-exBudgetMemory",
+    "cekBuiltinCost-exBudgetCPU",
+    "cekBuiltinCost-exBudgetMemory",
+    "cekConstCost-exBudgetCPU",
+    "cekConstCost-exBudgetMemory",
+    "cekDelayCost-exBudgetCPU",
+    "cekDelayCost-exBudgetMemory",
+    "cekForceCost-exBudgetCPU",
+    "cekForceCost-exBudgetMemory",
+    "cekLamCost-exBudgetCPU",
+    "cekLamCost-exBudgetMemory",
+    "cekStartupCost-exBudgetCPU",
+    "cekStartupCost-exBudgetMemory",
+    "cekVarCost-exBudgetCPU",
+    "cekVarCost-exBudgetMemory",
+    "chooseData-cpu-arguments",
+    "chooseData-memory-arguments",
+    "chooseList-cpu-arguments",
+    "chooseList-memory-arguments",
+    "chooseUnit-cpu-arguments",
+    "chooseUnit-memory-arguments",
+    "consByteString-cpu-arguments-intercept",
+    "consByteString-cpu-arguments-slope",
+    "consByteString-memory-arguments-intercept",
+    "consByteString-memory-arguments-slope",
+    "constrData-cpu-arguments",
+    "constrData-memory-arguments",
+    "decodeUtf8-cpu-arguments-intercept",
+    "decodeUtf8-cpu-arguments-slope",
+    "decodeUtf8-memory-arguments-intercept",
+    "decodeUtf8-memory-arguments-slope",
+    "divideInteger-cpu-arguments-constant",
+    "divideInteger-cpu-arguments-model-arguments-intercept",
+    "divideInteger-cpu-arguments-model-arguments-slope",
+    "divideInteger-memory-arguments-intercept",
+    "divideInteger-memory-arguments-minimum",
+    "divideInteger-memory-arguments-slope",
+    "encodeUtf8-cpu-arguments-intercept",
+    "encodeUtf8-cpu-arguments-slope",
+    "encodeUtf8-memory-arguments-intercept",
+    "encodeUtf8-memory-arguments-slope",
+    "equalsByteString-cpu-arguments-constant",
+    "equalsByteString-cpu-arguments-intercept",
+    "equalsByteString-cpu-arguments-slope",
+    "equalsByteString-memory-arguments",
+    "equalsData-cpu-arguments-intercept",
+    "equalsData-cpu-arguments-slope",
+    "equalsData-memory-arguments",
+    "equalsInteger-cpu-arguments-intercept",
+    "equalsInteger-cpu-arguments-slope",
+    "equalsInteger-memory-arguments",
+    "equalsString-cpu-arguments-constant",
+    "equalsString-cpu-arguments-intercept",
+    "equalsString-cpu-arguments-slope",
+    "equalsString-memory-arguments",
+    "fstPair-cpu-arguments",
+    "fstPair-memory-arguments",
+    "headList-cpu-arguments",
+    "headList-memory-arguments",
+    "iData-cpu-arguments",
+    "iData-memory-arguments",
+    "ifThenElse-cpu-arguments",
+    "ifThenElse-memory-arguments",
+    "indexByteString-cpu-arguments",
+    "indexByteString-memory-arguments",
+    "lengthOfByteString-cpu-arguments",
+    "lengthOfByteString-memory-arguments",
+    "lessThanByteString-cpu-arguments-intercept",
+    "lessThanByteString-cpu-arguments-slope",
+    "lessThanByteString-memory-arguments",
+    "lessThanEqualsByteString-cpu-arguments-intercept",
+    "lessThanEqualsByteString-cpu-arguments-slope",
+    "lessThanEqualsByteString-memory-arguments",
+    "lessThanEqualsInteger-cpu-arguments-intercept",
+    "lessThanEqualsInteger-cpu-arguments-slope",
+    "lessThanEqualsInteger-memory-arguments",
+    "lessThanInteger-cpu-arguments-intercept",
+    "lessThanInteger-cpu-arguments-slope",
+    "lessThanInteger-memory-arguments",
+    "listData-cpu-arguments",
+    "listData-memory-arguments",
+    "mapData-cpu-arguments",
+    "mapData-memory-arguments",
+    "mkCons-cpu-arguments",
+    "mkCons-memory-arguments",
+    "mkNilData-cpu-arguments",
+    "mkNilData-memory-arguments",
+    "mkNilPairData-cpu-arguments",
+    "mkNilPairData-memory-arguments",
+    "mkPairData-cpu-arguments",
+    "mkPairData-memory-arguments",
+    "modInteger-cpu-arguments-constant",
+    "modInteger-cpu-arguments-model-arguments-intercept",
+    "modInteger-cpu-arguments-model-arguments-slope",
+    "modInteger-memory-arguments-intercept",
+    "modInteger-memory-arguments-minimum",
+    "modInteger-memory-arguments-slope",
+    "multiplyInteger-cpu-arguments-intercept",
+    "multiplyInteger-cpu-arguments-slope",
+    "multiplyInteger-memory-arguments-intercept",
+    "multiplyInteger-memory-arguments-slope",
+    "nullList-cpu-arguments",
+    "nullList-memory-arguments",
+    "quotientInteger-cpu-arguments-constant",
+    "quotientInteger-cpu-arguments-model-arguments-intercept",
+    "quotientInteger-cpu-arguments-model-arguments-slope",
+    "quotientInteger-memory-arguments-intercept",
+    "quotientInteger-memory-arguments-minimum",
+    "quotientInteger-memory-arguments-slope",
+    "remainderInteger-cpu-arguments-constant",
+    "remainderInteger-cpu-arguments-model-arguments-intercept",
+    "remainderInteger-cpu-arguments-model-arguments-slope",
+    "remainderInteger-memory-arguments-intercept",
+    "remainderInteger-memory-arguments-minimum",
+    "remainderInteger-memory-arguments-slope",
+    "serialiseData-cpu-arguments-intercept",
+    "serialiseData-cpu-arguments-slope",
+    "serialiseData-memory-arguments-intercept",
+    "serialiseData-memory-arguments-slope",
+    "sha2_256-cpu-arguments-intercept",
+    "sha2_256-cpu-arguments-slope",
+    "sha2_256-memory-arguments",
+    "sha3_256-cpu-arguments-intercept",
+    "sha3_256-cpu-arguments-slope",
+    "sha3_256-memory-arguments",
+    "sliceByteString-cpu-arguments-intercept",
+    "sliceByteString-cpu-arguments-slope",
+    "sliceByteString-memory-arguments-intercept",
+    "sliceByteString-memory-arguments-slope",
+    "sndPair-cpu-arguments",
+    "sndPair-memory-arguments",
+    "subtractInteger-cpu-arguments-intercept",
+    "subtractInteger-cpu-arguments-slope",
+    "subtractInteger-memory-arguments-intercept",
+    "subtractInteger-memory-arguments-slope",
+    "tailList-cpu-arguments",
+    "tailList-memory-arguments",
+    "trace-cpu-arguments",
+    "trace-memory-arguments",
+    "unBData-cpu-arguments",
+    "unBData-memory-arguments",
+    "unConstrData-cpu-arguments",
+    "unConstrData-memory-arguments",
+    "unIData-cpu-arguments",
+    "unIData-memory-arguments",
+    "unListData-cpu-arguments",
+    "unListData-memory-arguments",
+    "unMapData-cpu-arguments",
+    "unMapData-memory-arguments",
+    "verifyEcdsaSecp256k1Signature-cpu-arguments",
+    "verifyEcdsaSecp256k1Signature-memory-arguments",
+    "verifyEd25519Signature-cpu-arguments-intercept",
+    "verifyEd25519Signature-cpu-arguments-slope",
+    "verifyEd25519Signature-memory-arguments",
+    "verifySchnorrSecp256k1Signature-cpu-arguments-intercept",
+    "verifySchnorrSecp256k1Signature-cpu-arguments-slope",
+    "verifySchnorrSecp256k1Signature-memory-arguments",
+    "integerToByteString-cpu-arguments-c0",
+    "integerToByteString-cpu-arguments-c1",
+    "integerToByteString-cpu-arguments-c2",
+    "integerToByteString-memory-arguments-intercept",
+    "integerToByteString-memory-arguments-slope",
+    "byteStringToInteger-cpu-arguments-c0",
+    "byteStringToInteger-cpu-arguments-c1",
+    "byteStringToInteger-cpu-arguments-c2",
+    "byteStringToInteger-memory-arguments-intercept",
+    "byteStringToInteger-memory-arguments-slope",
+];
+
+static PLUTUS_V3_NAMES: [&str; 297] = [
+    "addInteger-cpu-arguments-intercept",
+    "addInteger-cpu-arguments-slope",
+    "addInteger-memory-arguments-intercept",
+    "addInteger-memory-arguments-slope",
+    "appendByteString-cpu-arguments-intercept",
+    "appendByteString-cpu-arguments-slope",
+    "appendByteString-memory-arguments-intercept",
+    "appendByteString-memory-arguments-slope",
+    "appendString-cpu-arguments-intercept",
+    "appendString-cpu-arguments-slope",
+    "appendString-memory-arguments-intercept",
+    "appendString-memory-arguments-slope",
+    "bData-cpu-arguments",
+    "bData-memory-arguments",
+    "blake2b_256-cpu-arguments-intercept",
+    "blake2b_256-cpu-arguments-slope",
+    "blake2b_256-memory-arguments",
+    "cekApplyCost-exBudgetCPU",
+    "cekApplyCost-exBudgetMemory",
+    "cekBuiltinCost-exBudgetCPU",
+    "cekBuiltinCost-exBudgetMemory",
+    "cekConstCost-exBudgetCPU",
+    "cekConstCost-exBudgetMemory",
+    "cekDelayCost-exBudgetCPU",
+    "cekDelayCost-exBudgetMemory",
+    "cekForceCost-exBudgetCPU",
+    "cekForceCost-exBudgetMemory",
+    "cekLamCost-exBudgetCPU",
+    "cekLamCost-exBudgetMemory",
+    "cekStartupCost-exBudgetCPU",
+    "cekStartupCost-exBudgetMemory",
+    "cekVarCost-exBudgetCPU",
+    "cekVarCost-exBudgetMemory",
+    "chooseData-cpu-arguments",
+    "chooseData-memory-arguments",
+    "chooseList-cpu-arguments",
+    "chooseList-memory-arguments",
+    "chooseUnit-cpu-arguments",
+    "chooseUnit-memory-arguments",
+    "consByteString-cpu-arguments-intercept",
+    "consByteString-cpu-arguments-slope",
+    "consByteString-memory-arguments-intercept",
+    "consByteString-memory-arguments-slope",
+    "constrData-cpu-arguments",
+    "constrData-memory-arguments",
+    "decodeUtf8-cpu-arguments-intercept",
+    "decodeUtf8-cpu-arguments-slope",
+    "decodeUtf8-memory-arguments-intercept",
+    "decodeUtf8-memory-arguments-slope",
+    "divideInteger-cpu-arguments-constant",
+    "divideInteger-cpu-arguments-model-arguments-c00",
+    "divideInteger-cpu-arguments-model-arguments-c01",
+    "divideInteger-cpu-arguments-model-arguments-c02",
+    "divideInteger-cpu-arguments-model-arguments-c10",
+    "divideInteger-cpu-arguments-model-arguments-c11",
+    "divideInteger-cpu-arguments-model-arguments-c20",
+    "divideInteger-cpu-arguments-model-arguments-minimum",
+    "divideInteger-memory-arguments-intercept",
+    "divideInteger-memory-arguments-minimum",
+    "divideInteger-memory-arguments-slope",
+    "encodeUtf8-cpu-arguments-intercept",
+    "encodeUtf8-cpu-arguments-slope",
+    "encodeUtf8-memory-arguments-intercept",
+    "encodeUtf8-memory-arguments-slope",
+    "equalsByteString-cpu-arguments-constant",
+    "equalsByteString-cpu-arguments-intercept",
+    "equalsByteString-cpu-arguments-slope",
+    "equalsByteString-memory-arguments",
+    "equalsData-cpu-arguments-intercept",
+    "equalsData-cpu-arguments-slope",
+    "equalsData-memory-arguments",
+    "equalsInteger-cpu-arguments-intercept",
+    "equalsInteger-cpu-arguments-slope",
+    "equalsInteger-memory-arguments",
+    "equalsString-cpu-arguments-constant",
+    "equalsString-cpu-arguments-intercept",
+    "equalsString-cpu-arguments-slope",
+    "equalsString-memory-arguments",
+    "fstPair-cpu-arguments",
+    "fstPair-memory-arguments",
+    "headList-cpu-arguments",
+    "headList-memory-arguments",
+    "iData-cpu-arguments",
+    "iData-memory-arguments",
+    "ifThenElse-cpu-arguments",
+    "ifThenElse-memory-arguments",
+    "indexByteString-cpu-arguments",
+    "indexByteString-memory-arguments",
+    "lengthOfByteString-cpu-arguments",
+    "lengthOfByteString-memory-arguments",
+    "lessThanByteString-cpu-arguments-intercept",
+    "lessThanByteString-cpu-arguments-slope",
+    "lessThanByteString-memory-arguments",
+    "lessThanEqualsByteString-cpu-arguments-intercept",
+    "lessThanEqualsByteString-cpu-arguments-slope",
+    "lessThanEqualsByteString-memory-arguments",
+    "lessThanEqualsInteger-cpu-arguments-intercept",
+    "lessThanEqualsInteger-cpu-arguments-slope",
+    "lessThanEqualsInteger-memory-arguments",
+    "lessThanInteger-cpu-arguments-intercept",
+    "lessThanInteger-cpu-arguments-slope",
+    "lessThanInteger-memory-arguments",
+    "listData-cpu-arguments",
+    "listData-memory-arguments",
+    "mapData-cpu-arguments",
+    "mapData-memory-arguments",
+    "mkCons-cpu-arguments",
+    "mkCons-memory-arguments",
+    "mkNilData-cpu-arguments",
+    "mkNilData-memory-arguments",
+    "mkNilPairData-cpu-arguments",
+    "mkNilPairData-memory-arguments",
+    "mkPairData-cpu-arguments",
+    "mkPairData-memory-arguments",
+    "modInteger-cpu-arguments-constant",
+    "modInteger-cpu-arguments-model-arguments-c00",
+    "modInteger-cpu-arguments-model-arguments-c01",
+    "modInteger-cpu-arguments-model-arguments-c02",
+    "modInteger-cpu-arguments-model-arguments-c10",
+    "modInteger-cpu-arguments-model-arguments-c11",
+    "modInteger-cpu-arguments-model-arguments-c20",
+    "modInteger-cpu-arguments-model-arguments-minimum",
+    "modInteger-memory-arguments-intercept",
+    "modInteger-memory-arguments-slope",
+    "multiplyInteger-cpu-arguments-intercept",
+    "multiplyInteger-cpu-arguments-slope",
+    "multiplyInteger-memory-arguments-intercept",
+    "multiplyInteger-memory-arguments-slope",
+    "nullList-cpu-arguments",
+    "nullList-memory-arguments",
+    "quotientInteger-cpu-arguments-constant",
+    "quotientInteger-cpu-arguments-model-arguments-c00",
+    "quotientInteger-cpu-arguments-model-arguments-c01",
+    "quotientInteger-cpu-arguments-model-arguments-c02",
+    "quotientInteger-cpu-arguments-model-arguments-c10",
+    "quotientInteger-cpu-arguments-model-arguments-c11",
+    "quotientInteger-cpu-arguments-model-arguments-c20",
+    "quotientInteger-cpu-arguments-model-arguments-minimum",
+    "quotientInteger-memory-arguments-intercept",
+    "quotientInteger-memory-arguments-minimum",
+    "quotientInteger-memory-arguments-slope",
+    "remainderInteger-cpu-arguments-constant",
+    "remainderInteger-cpu-arguments-model-arguments-c00",
+    "remainderInteger-cpu-arguments-model-arguments-c01",
+    "remainderInteger-cpu-arguments-model-arguments-c02",
+    "remainderInteger-cpu-arguments-model-arguments-c10",
+    "remainderInteger-cpu-arguments-model-arguments-c11",
+    "remainderInteger-cpu-arguments-model-arguments-c20",
+    "remainderInteger-cpu-arguments-model-arguments-minimum",
+    "remainderInteger-memory-arguments-intercept",
+    "remainderInteger-memory-arguments-slope",
+    "serialiseData-cpu-arguments-intercept",
+    "serialiseData-cpu-arguments-slope",
+    "serialiseData-memory-arguments-intercept",
+    "serialiseData-memory-arguments-slope",
+    "sha2_256-cpu-arguments-intercept",
+    "sha2_256-cpu-arguments-slope",
+    "sha2_256-memory-arguments",
+    "sha3_256-cpu-arguments-intercept",
+    "sha3_256-cpu-arguments-slope",
+    "sha3_256-memory-arguments",
+    "sliceByteString-cpu-arguments-intercept",
+    "sliceByteString-cpu-arguments-slope",
+    "sliceByteString-memory-arguments-intercept",
+    "sliceByteString-memory-arguments-slope",
+    "sndPair-cpu-arguments",
+    "sndPair-memory-arguments",
+    "subtractInteger-cpu-arguments-intercept",
+    "subtractInteger-cpu-arguments-slope",
+    "subtractInteger-memory-arguments-intercept",
+    "subtractInteger-memory-arguments-slope",
+    "tailList-cpu-arguments",
+    "tailList-memory-arguments",
+    "trace-cpu-arguments",
+    "trace-memory-arguments",
+    "unBData-cpu-arguments",
+    "unBData-memory-arguments",
+    "unConstrData-cpu-arguments",
+    "unConstrData-memory-arguments",
+    "unIData-cpu-arguments",
+    "unIData-memory-arguments",
+    "unListData-cpu-arguments",
+    "unListData-memory-arguments",
+    "unMapData-cpu-arguments",
+    "unMapData-memory-arguments",
+    "verifyEcdsaSecp256k1Signature-cpu-arguments",
+    "verifyEcdsaSecp256k1Signature-memory-arguments",
+    "verifyEd25519Signature-cpu-arguments-intercept",
+    "verifyEd25519Signature-cpu-arguments-slope",
+    "verifyEd25519Signature-memory-arguments",
+    "verifySchnorrSecp256k1Signature-cpu-arguments-intercept",
+    "verifySchnorrSecp256k1Signature-cpu-arguments-slope",
+    "verifySchnorrSecp256k1Signature-memory-arguments",
+    "cekConstrCost-exBudgetCPU",
+    "cekConstrCost-exBudgetMemory",
+    "cekCaseCost-exBudgetCPU",
+    "cekCaseCost-exBudgetMemory",
+    "bls12_381_G1_add-cpu-arguments",
+    "bls12_381_G1_add-memory-arguments",
+    "bls12_381_G1_compress-cpu-arguments",
+    "bls12_381_G1_compress-memory-arguments",
+    "bls12_381_G1_equal-cpu-arguments",
+    "bls12_381_G1_equal-memory-arguments",
+    "bls12_381_G1_hashToGroup-cpu-arguments-intercept",
+    "bls12_381_G1_hashToGroup-cpu-arguments-slope",
+    "bls12_381_G1_hashToGroup-memory-arguments",
+    "bls12_381_G1_neg-cpu-arguments",
+    "bls12_381_G1_neg-memory-arguments",
+    "bls12_381_G1_scalarMul-cpu-arguments-intercept",
+    "bls12_381_G1_scalarMul-cpu-arguments-slope",
+    "bls12_381_G1_scalarMul-memory-arguments",
+    "bls12_381_G1_uncompress-cpu-arguments",
+    "bls12_381_G1_uncompress-memory-arguments",
+    "bls12_381_G2_add-cpu-arguments",
+    "bls12_381_G2_add-memory-arguments",
+    "bls12_381_G2_compress-cpu-arguments",
+    "bls12_381_G2_compress-memory-arguments",
+    "bls12_381_G2_equal-cpu-arguments",
+    "bls12_381_G2_equal-memory-arguments",
+    "bls12_381_G2_hashToGroup-cpu-arguments-intercept",
+    "bls12_381_G2_hashToGroup-cpu-arguments-slope",
+    "bls12_381_G2_hashToGroup-memory-arguments",
+    "bls12_381_G2_neg-cpu-arguments",
+    "bls12_381_G2_neg-memory-arguments",
+    "bls12_381_G2_scalarMul-cpu-arguments-intercept",
+    "bls12_381_G2_scalarMul-cpu-arguments-slope",
+    "bls12_381_G2_scalarMul-memory-arguments",
+    "bls12_381_G2_uncompress-cpu-arguments",
+    "bls12_381_G2_uncompress-memory-arguments",
+    "bls12_381_finalVerify-cpu-arguments",
+    "bls12_381_finalVerify-memory-arguments",
+    "bls12_381_millerLoop-cpu-arguments",
+    "bls12_381_millerLoop-memory-arguments",
+    "bls12_381_mulMlResult-cpu-arguments",
+    "bls12_381_mulMlResult-memory-arguments",
+    "keccak_256-cpu-arguments-intercept",
+    "keccak_256-cpu-arguments-slope",
+    "keccak_256-memory-arguments",
+    "blake2b_224-cpu-arguments-intercept",
+    "blake2b_224-cpu-arguments-slope",
+    "blake2b_224-memory-arguments",
+    "integerToByteString-cpu-arguments-c0",
+    "integerToByteString-cpu-arguments-c1",
+    "integerToByteString-cpu-arguments-c2",
+    "integerToByteString-memory-arguments-intercept",
+    "integerToByteString-memory-arguments-slope",
+    "byteStringToInteger-cpu-arguments-c0",
+    "byteStringToInteger-cpu-arguments-c1",
+    "byteStringToInteger-cpu-arguments-c2",
+    "byteStringToInteger-memory-arguments-intercept",
+    "byteStringToInteger-memory-arguments-slope",
+    "andByteString-cpu-arguments-intercept",
+    "andByteString-cpu-arguments-slope1",
+    "andByteString-cpu-arguments-slope2",
+    "andByteString-memory-arguments-intercept",
+    "andByteString-memory-arguments-slope",
+    "orByteString-cpu-arguments-intercept",
+    "orByteString-cpu-arguments-slope1",
+    "orByteString-cpu-arguments-slope2",
+    "orByteString-memory-arguments-intercept",
+    "orByteString-memory-arguments-slope",
+    "xorByteString-cpu-arguments-intercept",
+    "xorByteString-cpu-arguments-slope1",
+    "xorByteString-cpu-arguments-slope2",
+    "xorByteString-memory-arguments-intercept",
+    "xorByteString-memory-arguments-slope",
+    "complementByteString-cpu-arguments-intercept",
+    "complementByteString-cpu-arguments-slope",
+    "complementByteString-memory-arguments-intercept",
+    "complementByteString-memory-arguments-slope",
+    "readBit-cpu-arguments",
+    "readBit-memory-arguments",
+    "writeBits-cpu-arguments-intercept",
+    "writeBits-cpu-arguments-slope",
+    "writeBits-memory-arguments-intercept",
+    "writeBits-memory-arguments-slope",
+    "replicateByte-cpu-arguments-intercept",
+    "replicateByte-cpu-arguments-slope",
+    "replicateByte-memory-arguments-intercept",
+    "replicateByte-memory-arguments-slope",
+    "shiftByteString-cpu-arguments-intercept",
+    "shiftByteString-cpu-arguments-slope",
+    "shiftByteString-memory-arguments-intercept",
+    "shiftByteString-memory-arguments-slope",
+    "rotateByteString-cpu-arguments-intercept",
+    "rotateByteString-cpu-arguments-slope",
+    "rotateByteString-memory-arguments-intercept",
+    "rotateByteString-memory-arguments-slope",
+    "countSetBits-cpu-arguments-intercept",
+    "countSetBits-cpu-arguments-slope",
+    "countSetBits-memory-arguments",
+    "findFirstSetBit-cpu-arguments-intercept",
+    "findFirstSetBit-cpu-arguments-slope",
+    "findFirstSetBit-memory-arguments",
+    "ripemd_160-cpu-arguments-intercept",
+    "ripemd_160-cpu-arguments-slope",
+    "ripemd_160-memory-arguments",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn param_name_counts() {
+        let counts = [
+            (Language::PlutusV1, 4, 0),
+            (Language::PlutusV1, 5, 166),
+            (Language::PlutusV1, 10, 166),
+            (Language::PlutusV2, 6, 0),
+            (Language::PlutusV2, 7, 175),
+            (Language::PlutusV2, 9, 175),
+            (Language::PlutusV2, 10, 185),
+            (Language::PlutusV3, 8, 0),
+            (Language::PlutusV3, 9, 251),
+            (Language::PlutusV3, 10, 297),
+        ];
+        for (language, protocol_version, count) in counts {
+            assert_eq!(
+                cost_model_param_names(language, protocol_version).len(),
+                count,
+                "{:?} at protocol version {}",
+                language,
+                protocol_version
+            );
+        }
+        for language in [Language::PlutusV1, Language::PlutusV2, Language::PlutusV3] {
+            let names = all_param_names(language);
+            for (i, name) in names.iter().enumerate() {
+                assert!(!names[..i].contains(name), "duplicate {}", name);
+            }
+        }
+        assert_eq!(
+            &PLUTUS_V2_NAMES[..175],
+            cost_model_param_names(Language::PlutusV2, 9)
+        );
+        assert_eq!(
+            PLUTUS_V2_NAMES[167],
+            "verifyEcdsaSecp256k1Signature-cpu-arguments"
+        );
+        assert_eq!(
+            PLUTUS_V3_NAMES[251],
+            "andByteString-cpu-arguments-intercept"
+        );
+    }
+
+    // The tables were typed by hand so this checks the shape of every name: each builtin's
+    // parameters are contiguous with its cpu ones before its memory ones, and the machine
+    // step costs come in CPU/Memory pairs.
+    #[test]
+    fn param_name_structure() {
+        for language in [Language::PlutusV1, Language::PlutusV2, Language::PlutusV3] {
+            let names = all_param_names(language);
+            let mut finished = Vec::<&str>::new();
+            let mut current: Option<(&str, &str)> = None;
+            for name in names.iter() {
+                let (group, kind) = if let Some(step) = name.strip_prefix("cek") {
+                    let (step, budget) = step.split_once("Cost-exBudget").unwrap_or_else(|| {
+                        panic!("{:?} machine cost {} is malformed", language, name)
+                    });
+                    let kind = match budget {
+                        "CPU" => "cpu",
+                        "Memory" => "memory",
+                        _ => panic!("{:?} machine cost {} is malformed", language, name),
+                    };
+                    (step, kind)
+                } else {
+                    let (builtin, rest) = name.split_once('-').unwrap_or_else(|| {
+                        panic!("{:?} parameter {} has no cost kind", language, name)
+                    });
+                    let kind = if rest.starts_with("cpu-arguments") {
+                        "cpu"
+                    } else if rest.starts_with("memory-arguments") {
+                        "memory"
+                    } else {
+                        panic!(
+                            "{:?} parameter {} is neither cpu nor memory",
+                            language, name
+                        )
+                    };
+                    (builtin, kind)
+                };
+                match current {
+                    Some((prev_group, prev_kind)) if prev_group == group => {
+                        assert!(
+                            prev_kind == kind || (prev_kind == "cpu" && kind == "memory"),
+                            "{:?} {} comes after the memory parameters",
+                            language,
+                            name
+                        );
+                    }
+                    _ => {
+                        if let Some((prev_group, prev_kind)) = current {
+                            assert!(
+                                prev_kind == "memory",
+                                "{:?} {} has no memory cost",
+                                language,
+                                prev_group
+                            );
+                            finished.push(prev_group);
+                        }
+                        assert!(
+                            !finished.contains(&group),
+                            "{:?} parameters of {} are split up",
+                            language,
+                            group
+                        );
+                        assert!(kind == "cpu", "{:?} {} has no cpu cost", language, group);
+                    }
+                }
+                current = Some((group, kind));
+            }
+            assert_eq!(
+                current.map(|(_, kind)| kind),
+                Some("memory"),
+                "{:?}",
+                language
+            );
+        }
+    }
+
+    // plutus_alonzo_cost_models() is the PlutusV1 cost model of the mainnet Alonzo genesis in
+    // ledger order so the table must name its values as the genesis file does
+    #[test]
+    fn param_names_match_alonzo_genesis() {
+        use crate::genesis::network_info::plutus_alonzo_cost_models;
+
+        let cost_models = plutus_alonzo_cost_models();
+        let costs = cost_models.inner.get(&0).unwrap();
+        assert_eq!(costs.len(), PLUTUS_V1_NAMES.len());
+        let named = PLUTUS_V1_NAMES
+            .iter()
+            .copied()
+            .zip(costs.iter().copied())
+            .collect::<std::collections::BTreeMap<_, _>>();
+        for (name, value) in [
+            ("addInteger-cpu-arguments-intercept", 197209),
+            ("appendByteString-cpu-arguments-intercept", 396231),
+            ("appendByteString-cpu-arguments-slope", 621),
+            ("blake2b_256-cpu-arguments-intercept", 2477736),
+            ("blake2b_256-cpu-arguments-slope", 29175),
+            ("blake2b_256-memory-arguments", 4),
+            ("cekStartupCost-exBudgetCPU", 100),
+            ("cekVarCost-exBudgetCPU", 29773),
+            ("divideInteger-cpu-arguments-constant", 148000),
+            (
+                "divideInteger-cpu-arguments-model-arguments-intercept",
+                425507,
+            ),
+            ("divideInteger-cpu-arguments-model-arguments-slope", 118),
+            ("equalsByteString-cpu-arguments-intercept", 112536),
+            ("equalsByteString-cpu-arguments-slope", 247),
+            ("equalsString-cpu-arguments-constant", 1000),
+            ("equalsInteger-cpu-arguments-intercept", 136542),
+            ("equalsInteger-cpu-arguments-slope", 1326),
+            ("lessThanEqualsInteger-cpu-arguments-intercept", 145276),
+            ("lessThanEqualsInteger-cpu-arguments-slope", 1366),
+            ("lessThanInteger-cpu-arguments-intercept", 179690),
+            ("lessThanInteger-cpu-arguments-slope", 497),
+            ("multiplyInteger-cpu-arguments-intercept", 61516),
+            ("multiplyInteger-cpu-arguments-slope", 11218),
+            ("sha3_256-cpu-arguments-slope", 82363),
+            ("subtractInteger-cpu-arguments-intercept", 197209),
+            ("verifyEd25519Signature-cpu-arguments-intercept", 3345831),
+        ] {
+            assert_eq!(named.get(name), Some(&value), "{}", name);
+        }
+        // the genesis (with its pre-Vasil names) was a map so the ledger's order is alphabetical
+        let legacy_names = PLUTUS_V1_NAMES
+            .iter()
+            .map(|name| {
+                name.replace("blake2b_256-", "blake2b-")
+                    .replace("verifyEd25519Signature-", "verifySignature-")
+            })
+            .collect::<Vec<_>>();
+        for pair in legacy_names.windows(2) {
+            assert!(pair[0] < pair[1], "{} is before {}", pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn named_json_ledger_positions() {
+        let mut cost_models = CostModels::default();
+        for language in [Language::PlutusV1, Language::PlutusV2, Language::PlutusV3] {
+            let costs = (0..all_param_names(language).len() as i64)
+                .map(|i| i * 10 + u64::from(language) as i64)
+                .collect();
+            cost_models.inner.insert(language.into(), costs);
+        }
+        // serde_json objects iterate in alphabetical order, just like the explorers' JSON, so
+        // decoding only gets the ledger order back by looking up every name's position
+        let json = cost_models.to_named_json().unwrap();
+        for (language, params) in json.as_object().unwrap() {
+            let language = language_from_key(language).unwrap();
+            let json_order = params.as_object().unwrap().keys().collect::<Vec<_>>();
+            // V1 happens to be in alphabetical order already
+            if language == Language::PlutusV1 {
+                continue;
+            }
+            assert_ne!(
+                json_order,
+                all_param_names(language).iter().collect::<Vec<_>>(),
+                "{:?}",
+                language
+            );
+        }
+        let decoded = CostModels::from_named_json(&json).unwrap();
+        for (language, costs) in cost_models.inner.iter() {
+            assert_eq!(decoded.inner.get(language), Some(costs));
+        }
+    }
+
+    #[test]
+    fn named_json_legacy_and_errors() {
+        let json = serde_json::json!({
+            "plutus:v1": {
+                "addInteger-cpu-arguments-intercept": 197209,
+                "addInteger-cpu-arguments-slope": 0,
+            },
+            "PlutusV2": [1, 2, 3],
+        });
+        let cost_models = CostModels::from_named_json(&json).unwrap();
+        assert_eq!(cost_models.inner.get(&0), Some(&vec![197209, 0]));
+        assert_eq!(cost_models.inner.get(&1), Some(&vec![1, 2, 3]));
+
+        // pre-Vasil names for PlutusV1
+        let mut legacy = serde_json::Map::new();
+        for (i, name) in PLUTUS_V1_NAMES.iter().enumerate() {
+            let name = name
+                .replace("blake2b_256-", "blake2b-")
+                .replace("verifyEd25519Signature-", "verifySignature-");
+            legacy.insert(name, serde_json::Value::from(i));
+        }
+        let legacy = serde_json::json!({ "PlutusV1": legacy });
+        let cost_models = CostModels::from_named_json(&legacy).unwrap();
+        assert_eq!(
+            cost_models.inner.get(&0),
+            Some(&(0..PLUTUS_V1_NAMES.len() as i64).collect::<Vec<_>>())
+        );
+
+        // the same parameter under both names
+        let duplicate = serde_json::json!({
+            "PlutusV1": {
+                "blake2b-cpu-arguments-intercept": 0,
+                "blake2b_256-cpu-arguments-intercept": 1,
+            },
+        });
+        assert!(matches!(
+            CostModels::from_named_json(&duplicate),
+            Err(CostModelNamesError::DuplicateParam {
+                language: Language::PlutusV1,
+                name: "blake2b_256-cpu-arguments-intercept",
+            })
+        ));
+
+        let gap = serde_json::json!({
+            "PlutusV1": { "addInteger-cpu-arguments-slope": 0 },
+        });
+        assert!(matches!(
+            CostModels::from_named_json(&gap),
+            Err(CostModelNamesError::MissingParam {
+                name: "addInteger-cpu-arguments-intercept",
+                ..
+            })
+        ));
+        let unknown = serde_json::json!({
+            "PlutusV1": { "ripemd_160-cpu-arguments-intercept": 0 },
+        });
+        assert!(matches!(
+            CostModels::from_named_json(&unknown),
+            Err(CostModelNamesError::UnknownParam { .. })
+        ));
+        let mut too_long = CostModels::default();
+        too_long.inner.insert(0, vec![0; PLUTUS_V1_NAMES.len() + 1]);
+        assert!(matches!(
+            too_long.to_named_json(),
+            Err(CostModelNamesError::TooManyParams { .. })
+        ));
+    }
+}
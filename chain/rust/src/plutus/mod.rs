@@ -2,6 +2,7 @@
 // https://github.com/dcSpark/cddl-codegen
 
 pub mod cbor_encodings;
+pub mod cost_models;
 pub mod serialization;
 pub mod utils;
 
@@ -22,12 +23,13 @@ use cbor_encodings::{
 };
 
 use cml_core::ordered_hash_map::OrderedHashMap;
-use cml_core::serialization::{LenEncoding, Serialize, StringEncoding};
+use cml_core::serialization::{LenEncoding, Serialize, StringEncoding, StripEncodings};
 use cml_crypto::{blake2b256, DatumHash};
 
-pub use utils::{ConstrPlutusData, PlutusMap, PlutusScript};
+pub use cost_models::{cost_model_param_names, CostModelNamesError};
+pub use utils::{ConstrPlutusData, PlutusDataVisitor, PlutusMap, PlutusScript};
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, StripEncodings)]
 pub struct CostModels {
     pub inner: OrderedHashMap<u64, Vec<i64>>,
     pub encodings: Option<CostModelsEncoding>,
@@ -88,7 +90,9 @@ impl schemars::JsonSchema for CostModels {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ExUnitPrices {
     pub mem_price: SubCoin,
     pub step_price: SubCoin,
@@ -107,7 +111,13 @@ impl ExUnitPrices {
 }
 
 #[derive(
-    Clone, Debug, derivative::Derivative, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+    Clone,
+    Debug,
+    derivative::Derivative,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    StripEncodings,
 )]
 #[derivative(PartialEq, Hash, Eq)]
 pub struct ExUnits {
@@ -147,7 +157,9 @@ pub enum Language {
     PlutusV3,
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct LegacyRedeemer {
     pub tag: RedeemerTag,
     pub index: u64,
@@ -169,7 +181,7 @@ impl LegacyRedeemer {
     }
 }
 
-#[derive(Clone, Debug, derivative::Derivative)]
+#[derive(Clone, Debug, derivative::Derivative, StripEncodings)]
 #[derivative(
     Eq,
     PartialEq,
@@ -235,7 +247,7 @@ impl PlutusData {
     }
 }
 
-#[derive(Clone, Debug, derivative::Derivative)]
+#[derive(Clone, Debug, derivative::Derivative, StripEncodings)]
 #[derivative(Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct PlutusV1Script {
     pub inner: Vec<u8>,
@@ -304,7 +316,7 @@ impl schemars::JsonSchema for PlutusV1Script {
     }
 }
 
-#[derive(Clone, Debug, derivative::Derivative)]
+#[derive(Clone, Debug, derivative::Derivative, StripEncodings)]
 #[derivative(Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct PlutusV2Script {
     pub inner: Vec<u8>,
@@ -373,7 +385,7 @@ impl schemars::JsonSchema for PlutusV2Script {
     }
 }
 
-#[derive(Clone, Debug, derivative::Derivative)]
+#[derive(Clone, Debug, derivative::Derivative, StripEncodings)]
 #[derivative(Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct PlutusV3Script {
     pub inner: Vec<u8>,
@@ -443,7 +455,13 @@ impl schemars::JsonSchema for PlutusV3Script {
 }
 
 #[derive(
-    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, derivative::Derivative,
+    Clone,
+    Debug,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    derivative::Derivative,
+    StripEncodings,
 )]
 #[derivative(Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct RedeemerKey {
@@ -481,6 +499,7 @@ impl RedeemerKey {
     serde::Deserialize,
     serde::Serialize,
     schemars::JsonSchema,
+    StripEncodings,
 )]
 #[wasm_bindgen]
 pub enum RedeemerTag {
@@ -492,7 +511,9 @@ pub enum RedeemerTag {
     Proposing,
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct RedeemerVal {
     pub data: PlutusData,
     pub ex_units: ExUnits,
@@ -510,7 +531,9 @@ impl RedeemerVal {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub enum Redeemers {
     ArrLegacyRedeemer {
         arr_legacy_redeemer: Vec<LegacyRedeemer>,
@@ -43,10 +43,7 @@ impl Serialize for CostModels {
             .collect::<Result<Vec<(Vec<u8>, &_, &_)>, cbor_event::Error>>()?;
         if force_canonical {
             key_order.sort_by(|(lhs_bytes, _, _), (rhs_bytes, _, _)| {
-                match lhs_bytes.len().cmp(&rhs_bytes.len()) {
-                    std::cmp::Ordering::Equal => lhs_bytes.cmp(rhs_bytes),
-                    diff_ord => diff_ord,
-                }
+                canonical_key_cmp(lhs_bytes, rhs_bytes)
             });
         }
         for (key_bytes, key, value) in key_order {
@@ -1083,10 +1080,7 @@ impl Serialize for Redeemers {
                     .collect::<Result<Vec<(Vec<u8>, &_, &_)>, cbor_event::Error>>()?;
                 if force_canonical {
                     key_order.sort_by(|(lhs_bytes, _, _), (rhs_bytes, _, _)| {
-                        match lhs_bytes.len().cmp(&rhs_bytes.len()) {
-                            std::cmp::Ordering::Equal => lhs_bytes.cmp(rhs_bytes),
-                            diff_ord => diff_ord,
-                        }
+                        canonical_key_cmp(lhs_bytes, rhs_bytes)
                     });
                 }
                 for (key_bytes, _key, value) in key_order {
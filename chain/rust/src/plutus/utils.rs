@@ -2,8 +2,9 @@ use super::{CostModels, Language, LegacyRedeemer, RedeemerKey, RedeemerVal, Rede
 use super::{ExUnits, PlutusData, PlutusV1Script, PlutusV2Script, PlutusV3Script};
 use crate::crypto::hash::{hash_script, ScriptHashNamespace};
 use crate::json::plutus_datums::{
-    decode_plutus_datum_to_json_value, encode_json_value_to_plutus_datum,
-    CardanoNodePlutusDatumSchema,
+    decode_cip68_datum_to_json_str, decode_plutus_datum_to_json_value,
+    encode_cip68_json_str_to_plutus_datum, encode_json_value_to_plutus_datum,
+    CardanoNodePlutusDatumSchema, PlutusJsonError,
 };
 use crate::utils::BigInteger;
 use cbor_event::de::Deserializer;
@@ -66,6 +67,37 @@ impl schemars::JsonSchema for PlutusData {
 }
 
 impl PlutusData {
+    pub fn new_integer_from_i64(integer: i64) -> Self {
+        Self::Integer(BigInteger::from(integer))
+    }
+
+    pub fn new_integer_from_u64(integer: u64) -> Self {
+        Self::Integer(BigInteger::from(integer))
+    }
+
+    /// Creates an integer datum from its decimal string representation (e.g. "-12345")
+    /// Useful for values outside of the 64-bit range as these are encoded as CBOR bignums
+    pub fn new_integer_from_str(integer: &str) -> Result<Self, num_bigint::ParseBigIntError> {
+        use std::str::FromStr;
+        BigInteger::from_str(integer).map(Self::Integer)
+    }
+
+    /// Returns None if this is not an integer datum or the value does not fit in an i64
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Integer(bigint) => bigint.as_i64(),
+            _ => None,
+        }
+    }
+
+    /// Returns None if this is not an integer datum or the value does not fit in a u64
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            Self::Integer(bigint) => bigint.as_u64(),
+            _ => None,
+        }
+    }
+
     /**
      *  Convert to a Datum that will serialize equivalent to cardano-node's format
      *
@@ -137,10 +169,195 @@ impl PlutusData {
             }),
         }
     }
+
+    /// Flat JSON for a CIP-68 `(metadata, version, extra)` datum.
+    /// See json::plutus_datums::decode_cip68_datum_to_json_str() for the conventions used.
+    pub fn to_cip68_json(&self) -> Result<String, PlutusJsonError> {
+        decode_cip68_datum_to_json_str(self)
+    }
+
+    /// Creates a CIP-68 datum from the JSON format produced by to_cip68_json()
+    pub fn from_cip68_json(json: &str) -> Result<Self, PlutusJsonError> {
+        encode_cip68_json_str_to_plutus_datum(json)
+    }
+}
+
+/// Callbacks for PlutusData::walk(). Every callback defaults to doing nothing so only the
+/// ones of interest need to be implemented.
+/// Nodes are visited depth-first in encoding order, with map keys visited before their values.
+pub trait PlutusDataVisitor<'a> {
+    fn enter_constr(&mut self, _constr: &'a ConstrPlutusData) {}
+
+    fn exit_constr(&mut self, _constr: &'a ConstrPlutusData) {}
+
+    fn enter_map(&mut self, _map: &'a PlutusMap) {}
+
+    fn exit_map(&mut self, _map: &'a PlutusMap) {}
+
+    fn enter_list(&mut self, _list: &'a [PlutusData]) {}
+
+    fn exit_list(&mut self, _list: &'a [PlutusData]) {}
+
+    fn visit_integer(&mut self, _integer: &'a BigInteger) {}
+
+    fn visit_bytes(&mut self, _bytes: &'a [u8]) {}
+}
+
+impl PlutusData {
+    /// Visits every node of this datum without cloning any of it. See PlutusDataVisitor.
+    /// This doesn't recurse so it works on arbitrarily deep datums.
+    pub fn walk<'a>(&'a self, visitor: &mut impl PlutusDataVisitor<'a>) {
+        enum Step<'a> {
+            Enter(&'a PlutusData),
+            Exit(&'a PlutusData),
+        }
+        let mut stack = vec![Step::Enter(self)];
+        while let Some(step) = stack.pop() {
+            match step {
+                Step::Enter(datum) => match datum {
+                    Self::ConstrPlutusData(constr) => {
+                        visitor.enter_constr(constr);
+                        stack.push(Step::Exit(datum));
+                        stack.extend(constr.fields.iter().rev().map(Step::Enter));
+                    }
+                    Self::Map(map) => {
+                        visitor.enter_map(map);
+                        stack.push(Step::Exit(datum));
+                        for (key, value) in map.entries.iter().rev() {
+                            stack.push(Step::Enter(value));
+                            stack.push(Step::Enter(key));
+                        }
+                    }
+                    Self::List { list, .. } => {
+                        visitor.enter_list(list);
+                        stack.push(Step::Exit(datum));
+                        stack.extend(list.iter().rev().map(Step::Enter));
+                    }
+                    Self::Integer(integer) => visitor.visit_integer(integer),
+                    Self::Bytes { bytes, .. } => visitor.visit_bytes(bytes),
+                },
+                Step::Exit(datum) => match datum {
+                    Self::ConstrPlutusData(constr) => visitor.exit_constr(constr),
+                    Self::Map(map) => visitor.exit_map(map),
+                    Self::List { list, .. } => visitor.exit_list(list),
+                    Self::Integer(_) | Self::Bytes { .. } => (),
+                },
+            }
+        }
+    }
+
+    /// Number of nodes on the longest path from this datum to a leaf (an integer, bytes or
+    /// an empty constr/map/list) including both ends i.e. a leaf on its own has a depth of 1.
+    pub fn max_depth(&self) -> usize {
+        #[derive(Default)]
+        struct DepthVisitor {
+            depth: usize,
+            max_depth: usize,
+        }
+        impl DepthVisitor {
+            fn enter(&mut self) {
+                self.depth += 1;
+                self.max_depth = self.max_depth.max(self.depth);
+            }
+        }
+        impl PlutusDataVisitor<'_> for DepthVisitor {
+            fn enter_constr(&mut self, _constr: &ConstrPlutusData) {
+                self.enter();
+            }
+
+            fn exit_constr(&mut self, _constr: &ConstrPlutusData) {
+                self.depth -= 1;
+            }
+
+            fn enter_map(&mut self, _map: &PlutusMap) {
+                self.enter();
+            }
+
+            fn exit_map(&mut self, _map: &PlutusMap) {
+                self.depth -= 1;
+            }
+
+            fn enter_list(&mut self, _list: &[PlutusData]) {
+                self.enter();
+            }
+
+            fn exit_list(&mut self, _list: &[PlutusData]) {
+                self.depth -= 1;
+            }
+
+            fn visit_integer(&mut self, _integer: &BigInteger) {
+                self.max_depth = self.max_depth.max(self.depth + 1);
+            }
+
+            fn visit_bytes(&mut self, _bytes: &[u8]) {
+                self.max_depth = self.max_depth.max(self.depth + 1);
+            }
+        }
+        let mut visitor = DepthVisitor::default();
+        self.walk(&mut visitor);
+        visitor.max_depth
+    }
+
+    /// Number of datums contained in this one, including itself. Map keys and values both count.
+    pub fn total_nodes(&self) -> usize {
+        struct CountVisitor(usize);
+        impl PlutusDataVisitor<'_> for CountVisitor {
+            fn enter_constr(&mut self, _constr: &ConstrPlutusData) {
+                self.0 += 1;
+            }
+
+            fn enter_map(&mut self, _map: &PlutusMap) {
+                self.0 += 1;
+            }
+
+            fn enter_list(&mut self, _list: &[PlutusData]) {
+                self.0 += 1;
+            }
+
+            fn visit_integer(&mut self, _integer: &BigInteger) {
+                self.0 += 1;
+            }
+
+            fn visit_bytes(&mut self, _bytes: &[u8]) {
+                self.0 += 1;
+            }
+        }
+        let mut visitor = CountVisitor(0);
+        self.walk(&mut visitor);
+        visitor.0
+    }
+
+    /// All bytes leaves anywhere in this datum (in walk() order) for which {predicate} holds
+    /// e.g. to pull every 28-byte hash out of a datum with a known layout.
+    pub fn find_bytes<P: FnMut(&[u8]) -> bool>(&self, predicate: P) -> Vec<&[u8]> {
+        struct FindVisitor<'a, P> {
+            predicate: P,
+            found: Vec<&'a [u8]>,
+        }
+        impl<'a, P: FnMut(&[u8]) -> bool> PlutusDataVisitor<'a> for FindVisitor<'a, P> {
+            fn visit_bytes(&mut self, bytes: &'a [u8]) {
+                if (self.predicate)(bytes) {
+                    self.found.push(bytes);
+                }
+            }
+        }
+        let mut visitor = FindVisitor {
+            predicate,
+            found: Vec::new(),
+        };
+        self.walk(&mut visitor);
+        visitor.found
+    }
 }
 
 #[derive(
-    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, derivative::Derivative,
+    Clone,
+    Debug,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    derivative::Derivative,
+    StripEncodings,
 )]
 #[derivative(Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct ConstrPlutusData {
@@ -573,6 +790,56 @@ impl RawBytesEncoding for PlutusV3Script {
     }
 }
 
+/// The forms plutus script bytes are commonly passed around in
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PlutusScriptForm {
+    /// The flat-encoded program without any CBOR wrapping
+    Flat,
+    /// The flat program wrapped in a CBOR bytestring e.g. compiledCode in Aiken's plutus.json
+    /// This is the raw bytes form in CML (to_raw_bytes() / from_raw_bytes()) and what the script hash is computed over.
+    Raw,
+    /// The raw form wrapped in another CBOR bytestring i.e. the CBOR encoding of the script
+    /// e.g. cborHex in cardano-cli's text envelopes. This is to_cbor_bytes() / from_cbor_bytes() in CML.
+    Cbor,
+}
+
+impl PlutusScriptForm {
+    /// Detects which form {bytes} are in by counting the CBOR bytestring wrappers around the flat program.
+    /// Flat programs start with their version (e.g. 1.0.0) so they are never a single CBOR bytestring.
+    pub fn detect(bytes: &[u8]) -> Self {
+        match unwrap_cbor_bytes(bytes) {
+            Some(inner) => match unwrap_cbor_bytes(&inner) {
+                Some(_) => Self::Cbor,
+                None => Self::Raw,
+            },
+            None => Self::Flat,
+        }
+    }
+}
+
+/// Converts plutus script bytes in any PlutusScriptForm to the raw form used by from_raw_bytes()
+pub fn normalize_plutus_script_bytes(bytes: &[u8]) -> Vec<u8> {
+    match PlutusScriptForm::detect(bytes) {
+        PlutusScriptForm::Flat => {
+            let mut serializer = Serializer::new_vec();
+            serializer
+                .write_bytes(bytes)
+                .expect("writing to a Vec can't fail");
+            serializer.finalize()
+        }
+        PlutusScriptForm::Raw => bytes.to_vec(),
+        PlutusScriptForm::Cbor => unwrap_cbor_bytes(bytes).expect("checked by detect()"),
+    }
+}
+
+/// The contents of {bytes} if they are exactly one CBOR bytestring
+fn unwrap_cbor_bytes(bytes: &[u8]) -> Option<Vec<u8>> {
+    let mut raw = Deserializer::from(std::io::Cursor::new(bytes));
+    let inner = raw.bytes().ok()?;
+    let consumed = raw.as_mut_ref().stream_position().ok()?;
+    (consumed == bytes.len() as u64).then_some(inner)
+}
+
 impl ExUnits {
     pub fn checked_add(&self, other: &ExUnits) -> Result<ExUnits, ArithmeticError> {
         let mem = self
@@ -590,6 +857,19 @@ impl ExUnits {
     pub fn dummy() -> ExUnits {
         ExUnits::new(u64::MAX, u64::MAX)
     }
+
+    /// Splits this budget into {n} parts as evenly as possible. The parts always sum to exactly
+    /// this budget, with any remainder going to the first parts. Returns an empty Vec for n = 0.
+    pub fn split_evenly(&self, n: usize) -> Vec<ExUnits> {
+        if n == 0 {
+            return Vec::new();
+        }
+        let n_u64 = n as u64;
+        let split = |total: u64, i: u64| total / n_u64 + u64::from(i < total % n_u64);
+        (0..n_u64)
+            .map(|i| ExUnits::new(split(self.mem, i), split(self.steps, i)))
+            .collect()
+    }
 }
 
 pub fn compute_total_ex_units(redeemers: &[LegacyRedeemer]) -> Result<ExUnits, ArithmeticError> {
@@ -628,7 +908,7 @@ impl From<Language> for u64 {
     }
 }
 
-#[derive(Clone, Debug, Default, derivative::Derivative)]
+#[derive(Clone, Debug, Default, derivative::Derivative, StripEncodings)]
 #[derivative(Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct PlutusMap {
     // possibly duplicates (very rare - only found on testnet)
@@ -707,10 +987,7 @@ impl Serialize for PlutusMap {
             .collect::<Result<Vec<(Vec<u8>, &_, &_)>, cbor_event::Error>>()?;
         if force_canonical {
             key_order.sort_by(|(lhs_bytes, _, _), (rhs_bytes, _, _)| {
-                match lhs_bytes.len().cmp(&rhs_bytes.len()) {
-                    std::cmp::Ordering::Equal => lhs_bytes.cmp(rhs_bytes),
-                    diff_ord => diff_ord,
-                }
+                canonical_key_cmp(lhs_bytes, rhs_bytes)
             });
         }
         for (key_bytes, _key, value) in key_order {
@@ -785,6 +1062,19 @@ impl Redeemers {
         }
     }
 
+    pub fn len(&self) -> usize {
+        match self {
+            Self::ArrLegacyRedeemer {
+                arr_legacy_redeemer,
+                ..
+            } => arr_legacy_redeemer.len(),
+            Self::MapRedeemerKeyToRedeemerVal {
+                map_redeemer_key_to_redeemer_val,
+                ..
+            } => map_redeemer_key_to_redeemer_val.len(),
+        }
+    }
+
     pub fn is_empty(&self) -> bool {
         match self {
             Self::ArrLegacyRedeemer {
@@ -818,7 +1108,140 @@ impl Redeemers {
 
 #[cfg(test)]
 mod tests {
-    use crate::plutus::{CostModels, Language};
+    use super::{normalize_plutus_script_bytes, PlutusDataVisitor, PlutusScriptForm};
+    use crate::plutus::{
+        ConstrPlutusData, CostModels, ExUnits, Language, PlutusData, PlutusMap, PlutusV1Script,
+    };
+    use crate::utils::BigInteger;
+    use cml_core::serialization::{Deserialize, DeserializeLimits, RawBytesEncoding, Serialize};
+    use cml_core::{DeserializeFailure, LimitKind};
+
+    proptest::proptest! {
+        #[test]
+        fn plutus_data_roundtrip(datum in crate::testing::arb_plutus_data(4)) {
+            crate::testing::roundtrip_cbor(&datum)?;
+        }
+
+        #[test]
+        fn plutus_data_json_roundtrip(datum in crate::testing::arb_plutus_data(4)) {
+            crate::testing::roundtrip_json(&datum)?;
+        }
+    }
+
+    #[test]
+    fn plutus_data_serde_json_big_ints() {
+        use std::str::FromStr;
+        let big = BigInteger::from_str("-1267650600228229401496703205376").unwrap();
+        let datum = PlutusData::new_list(vec![
+            PlutusData::new_integer(BigInteger::from(u64::MAX)),
+            PlutusData::new_integer(big),
+        ]);
+        // 64-bit integers stay JSON numbers while bigger ones keep all of their digits as strings
+        let json = serde_json::to_string(&datum).unwrap();
+        assert_eq!(
+            json,
+            r#"{"list":[{"int":18446744073709551615},{"int":"-1267650600228229401496703205376"}]}"#
+        );
+        let decoded: PlutusData = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.to_cbor_bytes(), datum.to_cbor_bytes());
+    }
+
+    #[test]
+    fn plutus_script_forms() {
+        // the always succeeds script from the plutus examples
+        let cbor = hex::decode("4e4d01000033222220051200120011").unwrap();
+        let raw = hex::decode("4d01000033222220051200120011").unwrap();
+        let flat = hex::decode("01000033222220051200120011").unwrap();
+        assert_eq!(PlutusScriptForm::detect(&cbor), PlutusScriptForm::Cbor);
+        assert_eq!(PlutusScriptForm::detect(&raw), PlutusScriptForm::Raw);
+        assert_eq!(PlutusScriptForm::detect(&flat), PlutusScriptForm::Flat);
+        let from_cbor = PlutusV1Script::from_cbor_bytes(&cbor).unwrap();
+        let from_raw = PlutusV1Script::from_raw_bytes(&raw).unwrap();
+        assert_eq!(from_cbor.hash(), from_raw.hash());
+        assert_eq!(
+            from_raw.hash().to_hex(),
+            "67f33146617a5e61936081db3b2117cbf59bd2123748f58ac9678656"
+        );
+        // using the wrong form gives a different script entirely
+        assert_ne!(
+            PlutusV1Script::from_raw_bytes(&cbor).unwrap().hash(),
+            from_raw.hash()
+        );
+        for bytes in [&cbor, &raw, &flat].iter() {
+            let normalized =
+                PlutusV1Script::from_raw_bytes(&normalize_plutus_script_bytes(bytes)).unwrap();
+            assert_eq!(normalized.hash(), from_raw.hash());
+            assert_eq!(normalized.to_cbor_bytes(), cbor);
+        }
+    }
+
+    #[test]
+    fn plutus_data_integer_constructors() {
+        let max_i64 = PlutusData::new_integer_from_i64(i64::MAX);
+        assert_eq!(max_i64.as_i64(), Some(i64::MAX));
+        assert_eq!(max_i64.as_u64(), Some(i64::MAX as u64));
+        let min_i64 = PlutusData::new_integer_from_i64(i64::MIN);
+        assert_eq!(hex::encode(min_i64.to_cbor_bytes()), "3b7fffffffffffffff");
+        assert_eq!(min_i64.as_i64(), Some(i64::MIN));
+        assert_eq!(min_i64.as_u64(), None);
+        let max_u64 = PlutusData::new_integer_from_u64(u64::MAX);
+        assert_eq!(hex::encode(max_u64.to_cbor_bytes()), "1bffffffffffffffff");
+        assert_eq!(max_u64.as_i64(), None);
+        assert_eq!(max_u64.as_u64(), Some(u64::MAX));
+        // 2^64 and -2^64 - 1 need tagged bignums
+        let above_u64 = PlutusData::new_integer_from_str("18446744073709551616").unwrap();
+        assert_eq!(
+            hex::encode(above_u64.to_cbor_bytes()),
+            "c249010000000000000000"
+        );
+        assert_eq!(above_u64.as_u64(), None);
+        let below_nint = PlutusData::new_integer_from_str("-18446744073709551617").unwrap();
+        assert_eq!(
+            hex::encode(below_nint.to_cbor_bytes()),
+            "c349010000000000000000"
+        );
+        assert_eq!(below_nint.as_i64(), None);
+        let decoded = PlutusData::from_cbor_bytes(&below_nint.to_cbor_bytes()).unwrap();
+        assert_eq!(decoded, below_nint);
+        assert!(PlutusData::new_integer_from_str("12a").is_err());
+        assert!(PlutusData::new_integer_from_str("").is_err());
+        // non-integer datums
+        assert_eq!(PlutusData::new_bytes(vec![0x01]).as_i64(), None);
+        assert_eq!(PlutusData::new_list(vec![]).as_u64(), None);
+    }
+
+    #[test]
+    fn plutus_data_with_limits() {
+        let limits = DeserializeLimits::default();
+        // a megabyte of nested indefinite lists would overflow the stack of from_cbor_bytes()
+        let bomb = vec![0x9f; 1_000_000];
+        let err = PlutusData::from_cbor_bytes_with_limits(&bomb, &limits).unwrap_err();
+        assert!(matches!(
+            err.failure(),
+            DeserializeFailure::LimitExceeded {
+                limit: LimitKind::Depth,
+                ..
+            }
+        ));
+        // realistic nesting is fine
+        let mut datum = PlutusData::new_integer_from_u64(42);
+        for _ in 0..20 {
+            datum = PlutusData::new_constr_plutus_data(ConstrPlutusData::new(
+                0,
+                vec![datum, PlutusData::new_bytes(vec![0; 32])],
+            ));
+        }
+        let bytes = datum.to_cbor_bytes();
+        assert_eq!(
+            PlutusData::from_cbor_bytes_with_limits(&bytes, &limits).unwrap(),
+            datum
+        );
+        let shallow = DeserializeLimits {
+            max_depth: 10,
+            ..limits
+        };
+        assert!(PlutusData::from_cbor_bytes_with_limits(&bytes, &shallow).is_err());
+    }
 
     #[test]
     pub fn test_cost_model() {
@@ -842,4 +1265,110 @@ mod tests {
             "a141005901d59f1a000302590001011a00060bc719026d00011a000249f01903e800011a000249f018201a0025cea81971f70419744d186419744d186419744d186419744d186419744d186419744d18641864186419744d18641a000249f018201a000249f018201a000249f018201a000249f01903e800011a000249f018201a000249f01903e800081a000242201a00067e2318760001011a000249f01903e800081a000249f01a0001b79818f7011a000249f0192710011a0002155e19052e011903e81a000249f01903e8011a000249f018201a000249f018201a000249f0182001011a000249f0011a000249f0041a000194af18f8011a000194af18f8011a0002377c190556011a0002bdea1901f1011a000249f018201a000249f018201a000249f018201a000249f018201a000249f018201a000249f018201a000242201a00067e23187600010119f04c192bd200011a000249f018201a000242201a00067e2318760001011a000242201a00067e2318760001011a0025cea81971f704001a000141bb041a000249f019138800011a000249f018201a000302590001011a000249f018201a000249f018201a000249f018201a000249f018201a000249f018201a000249f018201a000249f018201a00330da70101ff"
         );
     }
+
+    #[test]
+    fn ex_units_split_evenly() {
+        let budget = ExUnits::new(14_000_000, 10_000_000_001);
+        let parts = budget.split_evenly(3);
+        assert_eq!(
+            parts,
+            vec![
+                ExUnits::new(4_666_667, 3_333_333_334),
+                ExUnits::new(4_666_667, 3_333_333_334),
+                ExUnits::new(4_666_666, 3_333_333_333),
+            ]
+        );
+        assert_eq!(
+            super::compute_total_ex_units(
+                &parts
+                    .into_iter()
+                    .map(|ex_units| crate::plutus::LegacyRedeemer::new(
+                        crate::plutus::RedeemerTag::Spend,
+                        0,
+                        PlutusData::new_integer(0u64.into()),
+                        ex_units
+                    ))
+                    .collect::<Vec<_>>()
+            )
+            .unwrap(),
+            budget
+        );
+        assert_eq!(budget.split_evenly(1), vec![budget.clone()]);
+        assert!(budget.split_evenly(0).is_empty());
+    }
+
+    #[test]
+    fn plutus_data_walk() {
+        // 121([{h'00': [1, h'0102'], 2: h''}, []])
+        let mut map = PlutusMap::new();
+        map.set(
+            PlutusData::new_bytes(vec![0x00]),
+            PlutusData::new_list(vec![
+                PlutusData::new_integer_from_u64(1),
+                PlutusData::new_bytes(vec![0x01, 0x02]),
+            ]),
+        );
+        map.set(
+            PlutusData::new_integer_from_u64(2),
+            PlutusData::new_bytes(vec![]),
+        );
+        let datum = PlutusData::new_constr_plutus_data(ConstrPlutusData::new(
+            0,
+            vec![PlutusData::new_map(map), PlutusData::new_list(vec![])],
+        ));
+
+        struct Trace(String);
+        impl PlutusDataVisitor<'_> for Trace {
+            fn enter_constr(&mut self, constr: &ConstrPlutusData) {
+                self.0 += &format!("c{}(", constr.alternative);
+            }
+
+            fn exit_constr(&mut self, _constr: &ConstrPlutusData) {
+                self.0 += ")";
+            }
+
+            fn enter_map(&mut self, _map: &PlutusMap) {
+                self.0 += "{";
+            }
+
+            fn exit_map(&mut self, _map: &PlutusMap) {
+                self.0 += "}";
+            }
+
+            fn enter_list(&mut self, _list: &[PlutusData]) {
+                self.0 += "[";
+            }
+
+            fn exit_list(&mut self, _list: &[PlutusData]) {
+                self.0 += "]";
+            }
+
+            fn visit_integer(&mut self, integer: &BigInteger) {
+                self.0 += &format!("{integer} ");
+            }
+
+            fn visit_bytes(&mut self, bytes: &[u8]) {
+                self.0 += &format!("h'{}' ", hex::encode(bytes));
+            }
+        }
+        let mut trace = Trace(String::new());
+        datum.walk(&mut trace);
+        assert_eq!(trace.0, "c0({h'00' [1 h'0102' ]2 h'' }[])");
+        assert_eq!(datum.total_nodes(), 9);
+        assert_eq!(datum.max_depth(), 4);
+        assert_eq!(
+            datum.find_bytes(|bytes| bytes.len() == 2),
+            vec![&[0x01, 0x02][..]]
+        );
+        assert_eq!(datum.find_bytes(|_| true).len(), 3);
+
+        let mut deep = PlutusData::new_integer_from_u64(0);
+        for _ in 0..1000 {
+            deep = PlutusData::new_list(vec![deep]);
+        }
+        assert_eq!(deep.max_depth(), 1001);
+        assert_eq!(deep.total_nodes(), 1001);
+        assert_eq!(PlutusData::new_bytes(vec![]).max_depth(), 1);
+        assert_eq!(PlutusData::new_list(vec![]).max_depth(), 1);
+    }
 }
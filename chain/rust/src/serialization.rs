@@ -1669,17 +1669,25 @@ impl Serialize for Rational {
         serializer: &'se mut Serializer<W>,
         force_canonical: bool,
     ) -> cbor_event::Result<&'se mut Serializer<W>> {
-        serializer.write_tag_sz(
-            30u64,
-            fit_sz(
+        let untagged = !force_canonical
+            && self
+                .encodings
+                .as_ref()
+                .map(|encs| encs.untagged)
+                .unwrap_or(false);
+        if !untagged {
+            serializer.write_tag_sz(
                 30u64,
-                self.encodings
-                    .as_ref()
-                    .map(|encs| encs.tag_encoding)
-                    .unwrap_or_default(),
-                force_canonical,
-            ),
-        )?;
+                fit_sz(
+                    30u64,
+                    self.encodings
+                        .as_ref()
+                        .map(|encs| encs.tag_encoding)
+                        .unwrap_or_default(),
+                    force_canonical,
+                ),
+            )?;
+        }
         serializer.write_array_sz(
             self.encodings
                 .as_ref()
@@ -1719,16 +1727,22 @@ impl Serialize for Rational {
 
 impl Deserialize for Rational {
     fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
-        let (tag, tag_encoding) = raw.tag_sz()?;
-        if tag != 30 {
-            return Err(DeserializeError::new(
-                "Rational",
-                DeserializeFailure::TagMismatch {
-                    found: tag,
-                    expected: 30,
-                },
-            ));
-        }
+        // the tag is required by the CDDL but some historical protocol updates omit it
+        let tag_encoding = if raw.cbor_type()? == cbor_event::Type::Tag {
+            let (tag, tag_encoding) = raw.tag_sz()?;
+            if tag != 30 {
+                return Err(DeserializeError::new(
+                    "Rational",
+                    DeserializeFailure::TagMismatch {
+                        found: tag,
+                        expected: 30,
+                    },
+                ));
+            }
+            Some(tag_encoding)
+        } else {
+            None
+        };
         let len = raw.array_sz()?;
         let len_encoding: LenEncoding = len.into();
         let mut read_len = CBORReadLen::new(len);
@@ -1757,7 +1771,8 @@ impl Deserialize for Rational {
                 denominator,
                 encodings: Some(RationalEncoding {
                     len_encoding,
-                    tag_encoding: Some(tag_encoding),
+                    tag_encoding,
+                    untagged: tag_encoding.is_none(),
                     numerator_encoding,
                     denominator_encoding,
                 }),
@@ -2016,17 +2031,25 @@ impl Serialize for UnitInterval {
         serializer: &'se mut Serializer<W>,
         force_canonical: bool,
     ) -> cbor_event::Result<&'se mut Serializer<W>> {
-        serializer.write_tag_sz(
-            30u64,
-            fit_sz(
+        let untagged = !force_canonical
+            && self
+                .encodings
+                .as_ref()
+                .map(|encs| encs.untagged)
+                .unwrap_or(false);
+        if !untagged {
+            serializer.write_tag_sz(
                 30u64,
-                self.encodings
-                    .as_ref()
-                    .map(|encs| encs.tag_encoding)
-                    .unwrap_or_default(),
-                force_canonical,
-            ),
-        )?;
+                fit_sz(
+                    30u64,
+                    self.encodings
+                        .as_ref()
+                        .map(|encs| encs.tag_encoding)
+                        .unwrap_or_default(),
+                    force_canonical,
+                ),
+            )?;
+        }
         serializer.write_array_sz(
             self.encodings
                 .as_ref()
@@ -2066,16 +2089,22 @@ impl Serialize for UnitInterval {
 
 impl Deserialize for UnitInterval {
     fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
-        let (tag, tag_encoding) = raw.tag_sz()?;
-        if tag != 30 {
-            return Err(DeserializeError::new(
-                "UnitInterval",
-                DeserializeFailure::TagMismatch {
-                    found: tag,
-                    expected: 30,
-                },
-            ));
-        }
+        // the tag is required by the CDDL but some historical protocol updates omit it
+        let tag_encoding = if raw.cbor_type()? == cbor_event::Type::Tag {
+            let (tag, tag_encoding) = raw.tag_sz()?;
+            if tag != 30 {
+                return Err(DeserializeError::new(
+                    "UnitInterval",
+                    DeserializeFailure::TagMismatch {
+                        found: tag,
+                        expected: 30,
+                    },
+                ));
+            }
+            Some(tag_encoding)
+        } else {
+            None
+        };
         let len = raw.array_sz()?;
         let len_encoding: LenEncoding = len.into();
         let mut read_len = CBORReadLen::new(len);
@@ -2104,7 +2133,8 @@ impl Deserialize for UnitInterval {
                 end,
                 encodings: Some(UnitIntervalEncoding {
                     len_encoding,
-                    tag_encoding: Some(tag_encoding),
+                    tag_encoding,
+                    untagged: tag_encoding.is_none(),
                     start_encoding,
                     end_encoding,
                 }),
@@ -0,0 +1,178 @@
+//! Property-test (proptest) strategies for chain types, on top of the generic strategies and
+//! round-trip properties of cml_core::testing (re-exported here).
+//! Enabled by the `testing` feature (off by default).
+
+use proptest::prelude::*;
+
+pub use cml_core::testing::*;
+
+use crate::address::{Address, BaseAddress, EnterpriseAddress, RewardAddress};
+use crate::assets::{AssetName, Coin, MultiAsset, Value};
+use crate::certs::{Certificate, Credential, DRep};
+use crate::crypto::hash::hash_plutus_data;
+use crate::plutus::{ConstrPlutusData, PlutusData, PlutusMap};
+use crate::transaction::{DatumOption, TransactionInput, TransactionOutput};
+use crate::utils::BigInteger;
+use crate::PolicyId;
+use cml_crypto::{Ed25519KeyHash, ScriptHash, TransactionHash};
+
+pub fn arb_ed25519_key_hash() -> impl Strategy<Value = Ed25519KeyHash> {
+    any::<[u8; 28]>().prop_map(Ed25519KeyHash::from)
+}
+
+pub fn arb_script_hash() -> impl Strategy<Value = ScriptHash> {
+    any::<[u8; 28]>().prop_map(ScriptHash::from)
+}
+
+pub fn arb_credential() -> impl Strategy<Value = Credential> {
+    prop_oneof![
+        arb_ed25519_key_hash().prop_map(Credential::new_pub_key),
+        arb_script_hash().prop_map(Credential::new_script),
+    ]
+}
+
+/// Base, enterprise and reward addresses on any network id
+pub fn arb_address() -> impl Strategy<Value = Address> {
+    let network = 0u8..=15;
+    prop_oneof![
+        (network.clone(), arb_credential(), arb_credential()).prop_map(
+            |(network, payment, stake)| BaseAddress::new(network, payment, stake).to_address()
+        ),
+        (network.clone(), arb_credential())
+            .prop_map(|(network, payment)| EnterpriseAddress::new(network, payment).to_address()),
+        (network, arb_credential())
+            .prop_map(|(network, stake)| RewardAddress::new(network, stake).to_address()),
+    ]
+}
+
+pub fn arb_asset_name() -> impl Strategy<Value = AssetName> {
+    arb_bytes(32).prop_map(|name| AssetName::new(name).expect("at most 32 bytes"))
+}
+
+/// Up to 4 policies with up to 4 (non-zero) assets each
+pub fn arb_multiasset() -> impl Strategy<Value = MultiAsset> {
+    prop::collection::vec(
+        (
+            any::<[u8; 28]>().prop_map(PolicyId::from),
+            prop::collection::vec((arb_asset_name(), 1..=Coin::MAX), 1..=4),
+        ),
+        0..=4,
+    )
+    .prop_map(|policies| {
+        let mut multiasset = MultiAsset::new();
+        for (policy_id, assets) in policies {
+            for (asset_name, amount) in assets {
+                multiasset.set(policy_id, asset_name, amount);
+            }
+        }
+        multiasset
+    })
+}
+
+pub fn arb_value() -> impl Strategy<Value = Value> {
+    (arb_u64(), arb_multiasset()).prop_map(|(coin, multiasset)| Value::new(coin, multiasset))
+}
+
+/// Integers both within and outside (big numbers) of the 64-bit CBOR range
+pub fn arb_big_integer() -> impl Strategy<Value = BigInteger> {
+    prop_oneof![
+        any::<i64>().prop_map(BigInteger::from),
+        any::<i128>().prop_map(BigInteger::from),
+    ]
+}
+
+/// Bytes of up to 100 bytes (i.e. including ones needing chunks) with random encodings
+fn arb_bounded_bytes() -> impl Strategy<Value = PlutusData> {
+    arb_bytes(100).prop_flat_map(|bytes| {
+        arb_string_encoding(bytes.len()).prop_map(move |bytes_encoding| PlutusData::Bytes {
+            bytes: bytes.clone(),
+            bytes_encoding,
+        })
+    })
+}
+
+/// Plutus datums nested up to {depth} levels with random list/bytes encodings
+pub fn arb_plutus_data(depth: u32) -> impl Strategy<Value = PlutusData> {
+    arb_plutus_data_with(depth, arb_big_integer())
+}
+
+fn arb_plutus_data_with(
+    depth: u32,
+    integers: impl Strategy<Value = BigInteger> + 'static,
+) -> impl Strategy<Value = PlutusData> {
+    let leaf = prop_oneof![
+        integers.prop_map(PlutusData::new_integer),
+        arb_bounded_bytes(),
+    ];
+    leaf.prop_recursive(depth, 64, 4, |inner| {
+        prop_oneof![
+            (
+                prop::collection::vec(inner.clone(), 0..=4),
+                arb_len_encoding()
+            )
+                .prop_map(|(list, list_encoding)| PlutusData::List {
+                    list,
+                    list_encoding,
+                }),
+            prop::collection::vec((inner.clone(), inner.clone()), 0..=4).prop_map(|entries| {
+                let mut map = PlutusMap::new();
+                for (key, value) in entries {
+                    map.set(key, value);
+                }
+                PlutusData::new_map(map)
+            }),
+            // compact (121-127 and 1280-1400) as well as general form (102) alternatives
+            (
+                prop_oneof![0u64..=6, 7u64..=127, 128u64..=1000],
+                prop::collection::vec(inner, 0..=4)
+            )
+                .prop_map(|(alternative, fields)| PlutusData::new_constr_plutus_data(
+                    ConstrPlutusData::new(alternative, fields)
+                )),
+        ]
+    })
+}
+
+pub fn arb_transaction_input() -> impl Strategy<Value = TransactionInput> {
+    (any::<[u8; 32]>(), 0u64..=u16::MAX as u64)
+        .prop_map(|(hash, index)| TransactionInput::new(TransactionHash::from(hash), index))
+}
+
+/// Outputs without a datum, with a datum hash or with an inline datum (no script refs)
+pub fn arb_transaction_output() -> impl Strategy<Value = TransactionOutput> {
+    let datum = prop_oneof![
+        Just(None),
+        arb_plutus_data(2).prop_map(|datum| Some(DatumOption::new_hash(hash_plutus_data(&datum)))),
+        arb_plutus_data(2).prop_map(|datum| Some(DatumOption::new_datum(datum))),
+    ];
+    (arb_address(), arb_value(), datum)
+        .prop_map(|(address, amount, datum)| TransactionOutput::new(address, amount, datum, None))
+}
+
+pub fn arb_d_rep() -> impl Strategy<Value = DRep> {
+    prop_oneof![
+        arb_ed25519_key_hash().prop_map(DRep::new_key),
+        arb_script_hash().prop_map(DRep::new_script),
+        Just(DRep::new_always_abstain()),
+        Just(DRep::new_always_no_confidence()),
+    ]
+}
+
+/// Stake (de)registration and delegation certificates, both pre-conway and conway ones
+pub fn arb_certificate() -> impl Strategy<Value = Certificate> {
+    prop_oneof![
+        arb_credential().prop_map(Certificate::new_stake_registration),
+        arb_credential().prop_map(Certificate::new_stake_deregistration),
+        (arb_credential(), arb_ed25519_key_hash())
+            .prop_map(|(cred, pool)| Certificate::new_stake_delegation(cred, pool)),
+        (arb_credential(), arb_u64())
+            .prop_map(|(cred, deposit)| Certificate::new_reg_cert(cred, deposit)),
+        (arb_credential(), arb_u64())
+            .prop_map(|(cred, deposit)| Certificate::new_unreg_cert(cred, deposit)),
+        (arb_credential(), arb_d_rep())
+            .prop_map(|(cred, d_rep)| Certificate::new_vote_deleg_cert(cred, d_rep)),
+        (arb_credential(), arb_ed25519_key_hash(), arb_d_rep()).prop_map(|(cred, pool, d_rep)| {
+            Certificate::new_stake_vote_deleg_cert(cred, pool, d_rep)
+        }),
+    ]
+}
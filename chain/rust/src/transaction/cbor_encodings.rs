@@ -108,6 +108,7 @@ pub struct TransactionBodyEncoding {
     pub current_treasury_value_key_encoding: Option<cbor_event::Sz>,
     pub donation_encoding: Option<cbor_event::Sz>,
     pub donation_key_encoding: Option<cbor_event::Sz>,
+    pub original_bytes: Option<Vec<u8>>,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -27,11 +27,17 @@ use cbor_encodings::{
     TransactionWitnessSetEncoding,
 };
 use cml_core::ordered_hash_map::OrderedHashMap;
-use cml_core::serialization::{LenEncoding, StringEncoding};
+use cml_core::serialization::{LenEncoding, StringEncoding, StripEncodings};
 use std::collections::BTreeMap;
 
 #[derive(
-    Clone, Debug, derivative::Derivative, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+    Clone,
+    Debug,
+    derivative::Derivative,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    StripEncodings,
 )]
 #[derivative(Hash, Eq, PartialEq)]
 pub struct AlonzoFormatTxOut {
@@ -55,7 +61,13 @@ impl AlonzoFormatTxOut {
 }
 
 #[derive(
-    Clone, Debug, derivative::Derivative, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+    Clone,
+    Debug,
+    derivative::Derivative,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    StripEncodings,
 )]
 #[derivative(Hash, Eq, PartialEq)]
 pub struct ConwayFormatTxOut {
@@ -81,7 +93,13 @@ impl ConwayFormatTxOut {
 }
 
 #[derive(
-    Clone, Debug, derivative::Derivative, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+    Clone,
+    Debug,
+    derivative::Derivative,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    StripEncodings,
 )]
 #[derivative(Eq, PartialEq, Hash)]
 pub enum DatumOption {
@@ -136,7 +154,15 @@ impl DatumOption {
 }
 
 #[derive(
-    Clone, Debug, Hash, PartialEq, Eq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+    Clone,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    StripEncodings,
 )]
 pub enum NativeScript {
     ScriptPubkey(ScriptPubkey),
@@ -178,7 +204,13 @@ impl NativeScript {
 }
 
 #[derive(
-    Clone, Debug, derivative::Derivative, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+    Clone,
+    Debug,
+    derivative::Derivative,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    StripEncodings,
 )]
 #[derivative(Hash, PartialEq, Eq)]
 pub struct ScriptAll {
@@ -198,7 +230,13 @@ impl ScriptAll {
 }
 
 #[derive(
-    Clone, Debug, derivative::Derivative, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+    Clone,
+    Debug,
+    derivative::Derivative,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    StripEncodings,
 )]
 #[derivative(Hash, PartialEq, Eq)]
 pub struct ScriptAny {
@@ -218,7 +256,13 @@ impl ScriptAny {
 }
 
 #[derive(
-    Clone, Debug, derivative::Derivative, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+    Clone,
+    Debug,
+    derivative::Derivative,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    StripEncodings,
 )]
 #[derivative(Hash, PartialEq, Eq)]
 pub struct ScriptInvalidBefore {
@@ -238,7 +282,13 @@ impl ScriptInvalidBefore {
 }
 
 #[derive(
-    Clone, Debug, derivative::Derivative, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+    Clone,
+    Debug,
+    derivative::Derivative,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    StripEncodings,
 )]
 #[derivative(Hash, PartialEq, Eq)]
 pub struct ScriptInvalidHereafter {
@@ -258,7 +308,13 @@ impl ScriptInvalidHereafter {
 }
 
 #[derive(
-    Clone, Debug, derivative::Derivative, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+    Clone,
+    Debug,
+    derivative::Derivative,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    StripEncodings,
 )]
 #[derivative(Hash, PartialEq, Eq)]
 pub struct ScriptNOfK {
@@ -280,7 +336,13 @@ impl ScriptNOfK {
 }
 
 #[derive(
-    Clone, Debug, derivative::Derivative, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+    Clone,
+    Debug,
+    derivative::Derivative,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    StripEncodings,
 )]
 #[derivative(Hash, PartialEq, Eq)]
 pub struct ScriptPubkey {
@@ -301,7 +363,9 @@ impl ScriptPubkey {
 
 pub type ScriptRef = Script;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct Transaction {
     pub body: TransactionBody,
     pub witness_set: TransactionWitnessSet,
@@ -328,7 +392,9 @@ impl Transaction {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct TransactionBody {
     pub inputs: SetTransactionInput,
     pub outputs: Vec<TransactionOutput>,
@@ -382,8 +448,16 @@ impl TransactionBody {
     }
 }
 
+/// Ordered by transaction_id then index like the ledger's TxIn, which is the order
+/// the redeemer indices of spent inputs refer to. Encodings are ignored by Eq/Ord/Hash.
 #[derive(
-    Clone, Debug, derivative::Derivative, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+    Clone,
+    Debug,
+    derivative::Derivative,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    StripEncodings,
 )]
 #[derivative(Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub struct TransactionInput {
@@ -410,7 +484,15 @@ impl TransactionInput {
 }
 
 #[derive(
-    Clone, Debug, Hash, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+    Clone,
+    Debug,
+    Hash,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    StripEncodings,
 )]
 pub enum TransactionOutput {
     AlonzoFormatTxOut(AlonzoFormatTxOut),
@@ -427,7 +509,9 @@ impl TransactionOutput {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct TransactionWitnessSet {
     pub vkeywitnesses: Option<NonemptySetVkeywitness>,
     pub native_scripts: Option<NonemptySetNativeScript>,
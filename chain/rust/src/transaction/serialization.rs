@@ -1832,15 +1832,9 @@ impl Serialize for TransactionBody {
                             })
                             .collect::<Result<Vec<(Vec<u8>, &_, &_)>, cbor_event::Error>>()?;
                         if force_canonical {
-                            key_order.sort_by(
-                                |(lhs_bytes, _, _), (rhs_bytes, _, _)| match lhs_bytes
-                                    .len()
-                                    .cmp(&rhs_bytes.len())
-                                {
-                                    std::cmp::Ordering::Equal => lhs_bytes.cmp(rhs_bytes),
-                                    diff_ord => diff_ord,
-                                },
-                            );
+                            key_order.sort_by(|(lhs_bytes, _, _), (rhs_bytes, _, _)| {
+                                canonical_key_cmp(lhs_bytes, rhs_bytes)
+                            });
                         }
                         for (key_bytes, key, value) in key_order {
                             serializer.write_raw_bytes(&key_bytes)?;
@@ -1913,6 +1907,20 @@ impl Serialize for TransactionBody {
                 }
                 8 => {
                     if let Some(field) = &self.mint {
+                        // { + policy_id => { + asset_name => nonZeroInt64 } } in the CDDL
+                        if let Some((policy_id, _)) =
+                            field.iter().find(|(_, assets)| assets.is_empty())
+                        {
+                            return Err(cbor_event::Error::CustomError(format!(
+                                "TransactionBody.mint: {} has no assets",
+                                policy_id.to_raw_hex()
+                            )));
+                        }
+                        if field.is_empty() {
+                            return Err(cbor_event::Error::CustomError(
+                                "TransactionBody.mint: no policies".to_owned(),
+                            ));
+                        }
                         serializer.write_unsigned_integer_sz(
                             9u64,
                             fit_sz(
@@ -1952,15 +1960,9 @@ impl Serialize for TransactionBody {
                             })
                             .collect::<Result<Vec<(Vec<u8>, &_, &_)>, cbor_event::Error>>()?;
                         if force_canonical {
-                            key_order.sort_by(
-                                |(lhs_bytes, _, _), (rhs_bytes, _, _)| match lhs_bytes
-                                    .len()
-                                    .cmp(&rhs_bytes.len())
-                                {
-                                    std::cmp::Ordering::Equal => lhs_bytes.cmp(rhs_bytes),
-                                    diff_ord => diff_ord,
-                                },
-                            );
+                            key_order.sort_by(|(lhs_bytes, _, _), (rhs_bytes, _, _)| {
+                                canonical_key_cmp(lhs_bytes, rhs_bytes)
+                            });
                         }
                         for (key_bytes, key, value) in key_order {
                             serializer.write_raw_bytes(&key_bytes)?;
@@ -1983,10 +1985,7 @@ impl Serialize for TransactionBody {
                                 .collect::<Result<Vec<(Vec<u8>, &_, &_)>, cbor_event::Error>>()?;
                             if force_canonical {
                                 key_order.sort_by(|(lhs_bytes, _, _), (rhs_bytes, _, _)| {
-                                    match lhs_bytes.len().cmp(&rhs_bytes.len()) {
-                                        std::cmp::Ordering::Equal => lhs_bytes.cmp(rhs_bytes),
-                                        diff_ord => diff_ord,
-                                    }
+                                    canonical_key_cmp(lhs_bytes, rhs_bytes)
                                 });
                             }
                             for (key_bytes, key, value) in key_order {
@@ -1995,6 +1994,13 @@ impl Serialize for TransactionBody {
                                     .get(key)
                                     .cloned()
                                     .unwrap_or_default();
+                                if *value == 0 {
+                                    // nonZeroInt64 in the CDDL
+                                    return Err(cbor_event::Error::CustomError(format!(
+                                        "TransactionBody.mint: {} has a 0 amount",
+                                        key.to_raw_hex()
+                                    )));
+                                }
                                 if *value >= 0 {
                                     serializer.write_unsigned_integer_sz(
                                         *value as u64,
@@ -2182,15 +2188,9 @@ impl Serialize for TransactionBody {
                             })
                             .collect::<Result<Vec<(Vec<u8>, &_, &_)>, cbor_event::Error>>()?;
                         if force_canonical {
-                            key_order.sort_by(
-                                |(lhs_bytes, _, _), (rhs_bytes, _, _)| match lhs_bytes
-                                    .len()
-                                    .cmp(&rhs_bytes.len())
-                                {
-                                    std::cmp::Ordering::Equal => lhs_bytes.cmp(rhs_bytes),
-                                    diff_ord => diff_ord,
-                                },
-                            );
+                            key_order.sort_by(|(lhs_bytes, _, _), (rhs_bytes, _, _)| {
+                                canonical_key_cmp(lhs_bytes, rhs_bytes)
+                            });
                         }
                         for (key_bytes, key, value) in key_order {
                             serializer.write_raw_bytes(&key_bytes)?;
@@ -2214,10 +2214,7 @@ impl Serialize for TransactionBody {
                                 .collect::<Result<Vec<(Vec<u8>, &_, &_)>, cbor_event::Error>>()?;
                             if force_canonical {
                                 key_order.sort_by(|(lhs_bytes, _, _), (rhs_bytes, _, _)| {
-                                    match lhs_bytes.len().cmp(&rhs_bytes.len()) {
-                                        std::cmp::Ordering::Equal => lhs_bytes.cmp(rhs_bytes),
-                                        diff_ord => diff_ord,
-                                    }
+                                    canonical_key_cmp(lhs_bytes, rhs_bytes)
                                 });
                             }
                             for (key_bytes, _key, value) in key_order {
@@ -2289,9 +2286,9 @@ impl Serialize for TransactionBody {
                             ),
                         )?;
                         serializer.write_unsigned_integer_sz(
-                            *field,
+                            field.get(),
                             fit_sz(
-                                *field,
+                                field.get(),
                                 self.encodings
                                     .as_ref()
                                     .map(|encs| encs.donation_encoding)
@@ -2539,6 +2536,9 @@ impl Deserialize for TransactionBody {
                                                 (x as i64, Some(enc))
                                             },
                                         };
+                                        if mint_value_value == 0 {
+                                            return Err(DeserializeFailure::RangeCheck { found: 0, min: Some(1), max: None }.into());
+                                        }
                                         if mint_value_table.insert(mint_value_key.clone(), mint_value_value).is_some() {
                                             return Err(DeserializeFailure::DuplicateKey(Key::Str(String::from("some complicated/unsupported type"))).into());
                                         }
@@ -2721,7 +2721,9 @@ impl Deserialize for TransactionBody {
                             }
                             let (tmp_donation, tmp_donation_encoding) = (|| -> Result<_, DeserializeError> {
                                 read_len.read_elems(1)?;
-                                raw.unsigned_integer_sz().map_err(Into::<DeserializeError>::into).map(|(x, enc)| (x, Some(enc)))
+                                let (x, enc) = raw.unsigned_integer_sz()?;
+                                let x = PositiveCoin::try_new(x).map_err(|_| DeserializeFailure::RangeCheck { found: 0, min: Some(1), max: None })?;
+                                Ok((x, Some(enc)))
                             })().map_err(|e| e.annotate("donation"))?;
                             donation = Some(tmp_donation);
                             donation_encoding = tmp_donation_encoding;
@@ -2816,6 +2818,7 @@ impl Deserialize for TransactionBody {
                     current_treasury_value_encoding,
                     donation_key_encoding,
                     donation_encoding,
+                    original_bytes: None,
                 }),
             })
         })().map_err(|e| e.annotate("TransactionBody"))
@@ -1,21 +1,137 @@
 use std::collections::BTreeSet;
+use std::convert::TryFrom;
 
 use crate::{
     address::Address,
-    plutus::Language,
+    assets::{AssetName, MultiAsset},
+    auxdata::AuxiliaryData,
+    certs::Certificate,
+    crypto::hash::hash_auxiliary_data,
+    fees::LinearFee,
+    plutus::{ExUnitPrices, ExUnits, Language, RedeemerTag, Redeemers},
     transaction::{DatumOption, ScriptRef, TransactionOutput},
-    Value,
+    Coin, NonemptySetPlutusData, PolicyId, Script, Value,
 };
-use cml_crypto::{DatumHash, Ed25519KeyHash, TransactionHash};
+use cml_core::{
+    ordered_hash_map::OrderedHashMap,
+    serialization::{OriginalBytes, Serialize},
+    ArithmeticError, Slot,
+};
+use cml_crypto::{DatumHash, Ed25519KeyHash, ScriptHash, TransactionHash};
+
+use serde_json::Value as JSONValue;
 
 use super::{
-    AlonzoFormatTxOut, ConwayFormatTxOut, NativeScript, TransactionBody, TransactionWitnessSet,
+    cbor_encodings::TransactionBodyEncoding, AlonzoFormatTxOut, ConwayFormatTxOut, NativeScript,
+    Transaction, TransactionBody, TransactionWitnessSet,
 };
 
 impl TransactionBody {
+    /// Uses the original bytes if they were kept (see OriginalBytes)
     pub fn hash(&self) -> TransactionHash {
         crate::crypto::hash::hash_transaction(self)
     }
+
+    /// Sets auxiliary_data_hash to the hash of {auxiliary_data} as it is serialized
+    /// (keeping its original bytes/encoding details) e.g. after another tool attached metadata.
+    /// Note: this changes the tx hash so any existing signatures will be invalidated.
+    pub fn set_auxiliary_data_hash_from(&mut self, auxiliary_data: &AuxiliaryData) {
+        let auxiliary_data_hash = hash_auxiliary_data(auxiliary_data);
+        if self.auxiliary_data_hash != Some(auxiliary_data_hash) {
+            self.auxiliary_data_hash = Some(auxiliary_data_hash);
+            // the kept original bytes would no longer match the body
+            self.set_original_bytes(None);
+        }
+    }
+
+    /// Whether auxiliary_data_hash is set and matches {auxiliary_data} as it is serialized
+    pub fn verify_auxiliary_data(&self, auxiliary_data: &AuxiliaryData) -> bool {
+        self.auxiliary_data_hash == Some(hash_auxiliary_data(auxiliary_data))
+    }
+
+    /// Returns this body with {fee} as the fee.
+    /// Only the fee's own encoding details are dropped so the rest of the body re-serializes
+    /// exactly as it was read (field order, map/int encodings, etc) e.g. for re-signing:
+    /// ```
+    /// use cml_chain::transaction::TransactionBody;
+    /// use cml_core::serialization::{Deserialize, Serialize};
+    ///
+    /// // { 2: 1000 (non-canonical 4 byte uint), 0: [], 1: [] }
+    /// let bytes = hex::decode("a3021a000003e800800180").unwrap();
+    /// let body = TransactionBody::from_cbor_bytes(&bytes).unwrap();
+    /// assert_eq!(body.to_cbor_bytes(), bytes);
+    ///
+    /// let edited = body.clone().with_fee(2000).with_ttl(500);
+    /// // only the fee bytes changed and the ttl was added after the existing fields
+    /// assert_eq!(hex::encode(edited.to_cbor_bytes()), "a4021907d000800180031901f4");
+    /// assert_ne!(edited.hash(), body.hash());
+    /// ```
+    /// Note: this changes the tx hash so any existing signatures will be invalidated.
+    pub fn with_fee(mut self, fee: Coin) -> Self {
+        self.fee = fee;
+        if let Some(encs) = self.edited_encodings(2, true) {
+            encs.fee_encoding = None;
+        }
+        self
+    }
+
+    /// Returns this body with {ttl} as the ttl (field 3). See with_fee()
+    pub fn with_ttl(mut self, ttl: Slot) -> Self {
+        let was_present = self.ttl.replace(ttl).is_some();
+        if let Some(encs) = self.edited_encodings(3, was_present) {
+            encs.ttl_encoding = None;
+        }
+        self
+    }
+
+    /// Returns this body with {validity_start} as the validity_interval_start (field 8). See with_fee()
+    pub fn with_validity_start(mut self, validity_start: Slot) -> Self {
+        let was_present = self
+            .validity_interval_start
+            .replace(validity_start)
+            .is_some();
+        if let Some(encs) = self.edited_encodings(7, was_present) {
+            encs.validity_interval_start_encoding = None;
+        }
+        self
+    }
+
+    /// Drops the kept original bytes after the field at {field_index} was edited and, if the
+    /// field is new, adds it to the original field order before the first later field.
+    /// Otherwise serialization would fall back to canonical order for all fields.
+    /// Note: {field_index} is the generated field index (as in orig_deser_order) which is not
+    /// the map key past key 5 e.g. validity_interval_start is key 8 but index 7.
+    fn edited_encodings(
+        &mut self,
+        field_index: usize,
+        was_present: bool,
+    ) -> Option<&mut TransactionBodyEncoding> {
+        let encs = self.encodings.as_mut()?;
+        encs.original_bytes = None;
+        if !was_present && !encs.orig_deser_order.is_empty() {
+            let pos = encs
+                .orig_deser_order
+                .iter()
+                .position(|field| *field > field_index)
+                .unwrap_or(encs.orig_deser_order.len());
+            encs.orig_deser_order.insert(pos, field_index);
+        }
+        Some(encs)
+    }
+}
+
+impl OriginalBytes for TransactionBody {
+    fn original_bytes(&self) -> Option<&[u8]> {
+        self.encodings
+            .as_ref()
+            .and_then(|encs| encs.original_bytes.as_deref())
+    }
+
+    fn set_original_bytes(&mut self, original_bytes: Option<Vec<u8>>) {
+        self.encodings
+            .get_or_insert_with(Default::default)
+            .original_bytes = original_bytes;
+    }
 }
 
 impl TransactionOutput {
@@ -105,6 +221,42 @@ impl From<ConwayFormatTxOut> for TransactionOutput {
     }
 }
 
+/// Why a TransactionOutput can't be represented in the (pre-Babbage) Alonzo output format
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum AlonzoFormatTxOutError {
+    #[error("Inline datums are not supported in Alonzo format outputs")]
+    InlineDatum,
+    #[error("Script references are not supported in Alonzo format outputs")]
+    ScriptReference,
+}
+
+/// Down-converts e.g. builder outputs for pre-Babbage eras. Datum hashes are kept but
+/// encoding details of Conway format outputs are not (they're serialized as arrays instead).
+impl TryFrom<TransactionOutput> for AlonzoFormatTxOut {
+    type Error = AlonzoFormatTxOutError;
+
+    fn try_from(tx_out: TransactionOutput) -> Result<Self, Self::Error> {
+        match tx_out {
+            TransactionOutput::AlonzoFormatTxOut(alonzo) => Ok(alonzo),
+            TransactionOutput::ConwayFormatTxOut(conway) => {
+                let datum_hash = match conway.datum_option {
+                    Some(DatumOption::Hash { datum_hash, .. }) => Some(datum_hash),
+                    Some(DatumOption::Datum { .. }) => {
+                        return Err(AlonzoFormatTxOutError::InlineDatum)
+                    }
+                    None => None,
+                };
+                if conway.script_reference.is_some() {
+                    return Err(AlonzoFormatTxOutError::ScriptReference);
+                }
+                let mut alonzo = AlonzoFormatTxOut::new(conway.address, conway.amount);
+                alonzo.datum_hash = datum_hash;
+                Ok(alonzo)
+            }
+        }
+    }
+}
+
 pub type RequiredSignersSet = BTreeSet<Ed25519KeyHash>;
 
 impl From<&NativeScript> for RequiredSignersSet {
@@ -132,6 +284,21 @@ impl From<&NativeScript> for RequiredSignersSet {
 }
 
 impl NativeScript {
+    /// ScriptAll from a slice of scripts (cardano-cli's "all")
+    pub fn all_of(native_scripts: &[NativeScript]) -> Self {
+        Self::new_script_all(native_scripts.to_vec())
+    }
+
+    /// ScriptAny from a slice of scripts (cardano-cli's "any")
+    pub fn any_of(native_scripts: &[NativeScript]) -> Self {
+        Self::new_script_any(native_scripts.to_vec())
+    }
+
+    /// ScriptNOfK from a slice of scripts (cardano-cli's "atLeast")
+    pub fn at_least(n: u64, native_scripts: &[NativeScript]) -> Self {
+        Self::new_script_n_of_k(n, native_scripts.to_vec())
+    }
+
     /// Returns an array of unique Ed25519KeyHashes
     /// contained within this script recursively on any depth level.
     /// The order of the keys in the result is not determined in any way.
@@ -201,6 +368,26 @@ impl TransactionWitnessSet {
         }
     }
 
+    /// Removes all vkey witnesses whose key hash isn't in {keys} e.g. to drop foreign or stale
+    /// signatures before forwarding a tx to the next signer.
+    /// The encodings of all remaining witnesses (and the witness set itself) are preserved.
+    pub fn retain_vkeys(&mut self, keys: &BTreeSet<Ed25519KeyHash>) {
+        if let Some(vkeys) = &mut self.vkeywitnesses {
+            vkeys.retain(|witness| keys.contains(&witness.vkey.hash()));
+            if vkeys.is_empty() {
+                self.vkeywitnesses = None;
+                self.remove_orig_deser_field(0);
+            }
+        }
+    }
+
+    // keeps the original order of the remaining fields after one is set to None
+    fn remove_orig_deser_field(&mut self, field_index: usize) {
+        if let Some(encs) = &mut self.encodings {
+            encs.orig_deser_order.retain(|index| *index != field_index);
+        }
+    }
+
     pub fn languages(&self) -> Vec<Language> {
         let mut used_langs = vec![];
         if self.plutus_v1_scripts.is_some() {
@@ -214,4 +401,1636 @@ impl TransactionWitnessSet {
         }
         used_langs
     }
+
+    /// The parts of the witness set covered by the script data (integrity) hash:
+    /// the redeemers (an empty map if there are none) and the plutus datums if there are any.
+    /// Both keep their original encodings so hashing them reproduces the on-chain script_data_hash.
+    pub fn script_integrity_view(&self) -> (Redeemers, Option<NonemptySetPlutusData>) {
+        (
+            self.redeemers.clone().unwrap_or_else(|| {
+                Redeemers::new_map_redeemer_key_to_redeemer_val(OrderedHashMap::default())
+            }),
+            self.plutus_datums.clone(),
+        )
+    }
+
+    /// All native and plutus scripts in the witness set in that order
+    pub fn all_scripts(&self) -> Vec<Script> {
+        let mut scripts = vec![];
+        if let Some(native_scripts) = &self.native_scripts {
+            scripts.extend(native_scripts.iter().cloned().map(Script::new_native));
+        }
+        if let Some(plutus_v1_scripts) = &self.plutus_v1_scripts {
+            scripts.extend(plutus_v1_scripts.iter().cloned().map(Script::new_plutus_v1));
+        }
+        if let Some(plutus_v2_scripts) = &self.plutus_v2_scripts {
+            scripts.extend(plutus_v2_scripts.iter().cloned().map(Script::new_plutus_v2));
+        }
+        if let Some(plutus_v3_scripts) = &self.plutus_v3_scripts {
+            scripts.extend(plutus_v3_scripts.iter().cloned().map(Script::new_plutus_v3));
+        }
+        scripts
+    }
+}
+
+/// A single difference found by Transaction::diff()
+#[derive(
+    Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+pub struct TransactionFieldDiff {
+    /// Location of the difference in the tx's JSON e.g. "body.fee" or "witness_set.vkeywitnesses[1]"
+    pub path: String,
+    /// Summary of the value in the left tx, or None if it isn't present there
+    pub left: Option<String>,
+    /// Summary of the value in the right tx, or None if it isn't present there
+    pub right: Option<String>,
+}
+
+#[derive(
+    Clone, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+pub struct TransactionDiff {
+    pub differences: Vec<TransactionFieldDiff>,
+}
+
+impl TransactionDiff {
+    pub fn is_empty(&self) -> bool {
+        self.differences.is_empty()
+    }
+}
+
+impl Transaction {
+    /// The unsigned form of this tx: all vkey and bootstrap witnesses are removed while the body,
+    /// is_valid, auxiliary data and the remaining witnesses (scripts, datums, redeemers) are kept
+    /// as-is, including their encodings, so the tx hash doesn't change.
+    pub fn without_witnesses(&self) -> Self {
+        let mut tx = self.clone();
+        if tx.witness_set.vkeywitnesses.take().is_some() {
+            tx.witness_set.remove_orig_deser_field(0);
+        }
+        if tx.witness_set.bootstrap_witnesses.take().is_some() {
+            tx.witness_set.remove_orig_deser_field(2);
+        }
+        tx
+    }
+
+    /// Replaces the witness set without touching any other part of the tx (or its encodings),
+    /// returning the previous witness set.
+    pub fn replace_witness_set(
+        &mut self,
+        witness_set: TransactionWitnessSet,
+    ) -> TransactionWitnessSet {
+        std::mem::replace(&mut self.witness_set, witness_set)
+    }
+
+    /// Returns a copy of this tx with {body} as the body, keeping the encodings of everything else.
+    /// Note: changing the body changes the tx hash so all existing vkey/bootstrap witnesses become
+    /// invalid. With {strip_witnesses} they are removed (see without_witnesses()) to be re-signed.
+    pub fn with_body(&self, body: TransactionBody, strip_witnesses: bool) -> Self {
+        let mut tx = if strip_witnesses {
+            self.without_witnesses()
+        } else {
+            self.clone()
+        };
+        tx.body = body;
+        tx
+    }
+
+    /// Returns a copy of this tx with {witness_set} as the witness set. See replace_witness_set()
+    pub fn with_witness_set(&self, witness_set: TransactionWitnessSet) -> Self {
+        let mut tx = self.clone();
+        tx.witness_set = witness_set;
+        tx
+    }
+
+    /// Returns a copy of this tx with {auxiliary_data} as the auxiliary data.
+    /// The body's auxiliary_data_hash is not updated, use TransactionBody::set_auxiliary_data_hash_from()
+    /// and with_body() for that (which also invalidates existing signatures).
+    pub fn with_auxiliary_data(&self, auxiliary_data: Option<AuxiliaryData>) -> Self {
+        let mut tx = self.clone();
+        tx.auxiliary_data = auxiliary_data;
+        tx
+    }
+}
+
+impl Transaction {
+    /// Structural diff against {other} e.g. to find what another tool changed in a tx being co-signed.
+    /// Encoding details (CBOR formats, original bytes, etc) are ignored.
+    /// Lists of the same length are compared element-wise, otherwise (e.g. a witness was added)
+    /// only the elements not present in the other list are reported.
+    pub fn diff(&self, other: &Transaction) -> TransactionDiff {
+        // the JSON representation already ignores encoding details
+        let lhs = serde_json::to_value(self).expect("Transaction JSON serialization failed");
+        let rhs = serde_json::to_value(other).expect("Transaction JSON serialization failed");
+        let mut diff = TransactionDiff::default();
+        diff_json("", &lhs, &rhs, &mut diff.differences);
+        diff
+    }
+}
+
+fn diff_json(
+    path: &str,
+    lhs: &JSONValue,
+    rhs: &JSONValue,
+    differences: &mut Vec<TransactionFieldDiff>,
+) {
+    if lhs == rhs {
+        return;
+    }
+    match (lhs, rhs) {
+        (JSONValue::Object(lhs), JSONValue::Object(rhs)) => {
+            let keys = lhs.keys().chain(rhs.keys()).collect::<BTreeSet<_>>();
+            for key in keys {
+                let field_path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                diff_json(
+                    &field_path,
+                    lhs.get(key).unwrap_or(&JSONValue::Null),
+                    rhs.get(key).unwrap_or(&JSONValue::Null),
+                    differences,
+                );
+            }
+        }
+        (JSONValue::Array(lhs), JSONValue::Array(rhs)) if lhs.len() == rhs.len() => {
+            for (i, (lhs, rhs)) in lhs.iter().zip(rhs.iter()).enumerate() {
+                diff_json(&format!("{}[{}]", path, i), lhs, rhs, differences);
+            }
+        }
+        (JSONValue::Array(lhs), JSONValue::Array(rhs)) => {
+            for (i, elem) in lhs.iter().enumerate() {
+                if !rhs.contains(elem) {
+                    differences.push(TransactionFieldDiff {
+                        path: format!("{}[{}]", path, i),
+                        left: summarize_json(elem),
+                        right: None,
+                    });
+                }
+            }
+            for (i, elem) in rhs.iter().enumerate() {
+                if !lhs.contains(elem) {
+                    differences.push(TransactionFieldDiff {
+                        path: format!("{}[{}]", path, i),
+                        left: None,
+                        right: summarize_json(elem),
+                    });
+                }
+            }
+        }
+        _ => differences.push(TransactionFieldDiff {
+            path: path.to_owned(),
+            left: summarize_json(lhs),
+            right: summarize_json(rhs),
+        }),
+    }
+}
+
+fn summarize_json(value: &JSONValue) -> Option<String> {
+    const MAX_SUMMARY_CHARS: usize = 128;
+    let summary = match value {
+        JSONValue::Null => return None,
+        JSONValue::String(s) => s.clone(),
+        other => other.to_string(),
+    };
+    if summary.chars().count() > MAX_SUMMARY_CHARS {
+        Some(format!(
+            "{}...",
+            summary.chars().take(MAX_SUMMARY_CHARS).collect::<String>()
+        ))
+    } else {
+        Some(summary)
+    }
+}
+
+/// Number and total CBOR size of a kind of witness set entry
+#[derive(
+    Clone, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+pub struct WitnessEntryStats {
+    pub count: u64,
+    pub size: u64,
+}
+
+impl WitnessEntryStats {
+    fn new<'a, T: Serialize + 'a>(entries: Option<impl IntoIterator<Item = &'a T>>) -> Self {
+        entries
+            .into_iter()
+            .flatten()
+            .fold(Self::default(), |stats, entry| Self {
+                count: stats.count + 1,
+                size: stats.size + entry.to_cbor_bytes().len() as u64,
+            })
+    }
+}
+
+/// Counts and sizes of a transaction's contents as returned by Transaction::stats()
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+pub struct TxStats {
+    /// Serialized size of the whole transaction in bytes
+    pub size: u64,
+    pub input_count: u64,
+    pub reference_input_count: u64,
+    pub collateral_input_count: u64,
+    pub output_count: u64,
+    pub certificate_count: u64,
+    pub vkey_witness_count: u64,
+    pub bootstrap_witness_count: u64,
+    pub native_scripts: WitnessEntryStats,
+    pub plutus_v1_scripts: WitnessEntryStats,
+    pub plutus_v2_scripts: WitnessEntryStats,
+    pub plutus_v3_scripts: WitnessEntryStats,
+    /// Datums in the witness set. Inline datums in outputs are not included.
+    pub datums: WitnessEntryStats,
+    pub redeemer_count: u64,
+    /// Sum of the ExUnits of all redeemers
+    pub total_ex_units: ExUnits,
+    /// Minimum fee for the tx. Only present when using Transaction::stats_with_min_fee()
+    pub min_fee: Option<Coin>,
+}
+
+impl Transaction {
+    /// Counts and sizes of the tx's contents e.g. for dashboards or checking how close a tx is to the limits
+    pub fn stats(&self) -> TxStats {
+        fn count<T>(entries: Option<impl AsRef<[T]>>) -> u64 {
+            entries.map_or(0, |entries| entries.as_ref().len() as u64)
+        }
+        let witness_set = &self.witness_set;
+        let redeemers = witness_set
+            .redeemers
+            .clone()
+            .map(|redeemers| redeemers.to_flat_format())
+            .unwrap_or_default();
+        let total_ex_units = redeemers.iter().fold(ExUnits::new(0, 0), |sum, redeemer| {
+            ExUnits::new(
+                sum.mem.saturating_add(redeemer.ex_units.mem),
+                sum.steps.saturating_add(redeemer.ex_units.steps),
+            )
+        });
+        TxStats {
+            size: self.to_cbor_bytes().len() as u64,
+            input_count: self.body.inputs.len() as u64,
+            reference_input_count: count(self.body.reference_inputs.as_ref()),
+            collateral_input_count: count(self.body.collateral_inputs.as_ref()),
+            output_count: self.body.outputs.len() as u64,
+            certificate_count: count(self.body.certs.as_ref()),
+            vkey_witness_count: count(witness_set.vkeywitnesses.as_ref()),
+            bootstrap_witness_count: count(witness_set.bootstrap_witnesses.as_ref()),
+            native_scripts: WitnessEntryStats::new(witness_set.native_scripts.as_ref()),
+            plutus_v1_scripts: WitnessEntryStats::new(witness_set.plutus_v1_scripts.as_ref()),
+            plutus_v2_scripts: WitnessEntryStats::new(witness_set.plutus_v2_scripts.as_ref()),
+            plutus_v3_scripts: WitnessEntryStats::new(witness_set.plutus_v3_scripts.as_ref()),
+            datums: WitnessEntryStats::new(witness_set.plutus_datums.as_ref()),
+            redeemer_count: redeemers.len() as u64,
+            total_ex_units,
+            min_fee: None,
+        }
+    }
+
+    /// Same as stats() but also computes the minimum fee for the tx as-is using the given protocol params.
+    /// * `total_ref_script_size` - Total size of all scripts in the reference inputs (see fees::min_ref_script_fee)
+    pub fn stats_with_min_fee(
+        &self,
+        linear_fee: &LinearFee,
+        ex_unit_prices: &ExUnitPrices,
+        total_ref_script_size: u64,
+    ) -> Result<TxStats, ArithmeticError> {
+        let mut stats = self.stats();
+        stats.min_fee = Some(crate::fees::min_fee(
+            self,
+            linear_fee,
+            ex_unit_prices,
+            total_ref_script_size,
+        )?);
+        Ok(stats)
+    }
+}
+
+/// Which optional parts of a transaction are present as returned by TransactionBody::feature_flags().
+/// Meant for wallets to show "this transaction does X" warnings when signing.
+#[derive(
+    Clone, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+pub struct TxFeatures {
+    pub has_mint: bool,
+    pub has_certs: bool,
+    pub has_withdrawals: bool,
+    pub has_votes: bool,
+    pub has_proposals: bool,
+    /// Whether plutus scripts are run. From the body alone this is based on script_data_hash
+    /// which is required whenever there are redeemers or datums.
+    pub has_plutus: bool,
+    pub has_ref_inputs: bool,
+    pub has_collateral: bool,
+    /// Native scripts are only visible in the witness set so this is only set by Transaction::feature_flags()
+    pub has_native_scripts: bool,
+    pub has_metadata_hash: bool,
+    pub has_required_signers: bool,
+    pub has_validity_start: bool,
+    pub has_ttl: bool,
+    pub has_treasury_donation: bool,
+    pub has_current_treasury_value: bool,
+    /// Number of minted/burned assets (over all policies)
+    pub mint_count: u64,
+    pub cert_count: u64,
+    pub withdrawal_count: u64,
+    /// Number of votes over all voters
+    pub vote_count: u64,
+    pub proposal_count: u64,
+}
+
+impl TransactionBody {
+    /// Which optional fields of the body are present. See TxFeatures.
+    pub fn feature_flags(&self) -> TxFeatures {
+        fn count<T>(entries: Option<impl AsRef<[T]>>) -> u64 {
+            entries.map_or(0, |entries| entries.as_ref().len() as u64)
+        }
+        let mint_count = self.mint.as_ref().map_or(0, |mint| {
+            mint.iter().map(|(_, assets)| assets.len() as u64).sum()
+        });
+        let withdrawal_count = self
+            .withdrawals
+            .as_ref()
+            .map_or(0, |withdrawals| withdrawals.len() as u64);
+        let vote_count = self.voting_procedures.as_ref().map_or(0, |voters| {
+            voters.values().map(|votes| votes.len() as u64).sum()
+        });
+        TxFeatures {
+            has_mint: mint_count > 0,
+            has_certs: self.certs.is_some(),
+            has_withdrawals: withdrawal_count > 0,
+            has_votes: vote_count > 0,
+            has_proposals: self.proposal_procedures.is_some(),
+            has_plutus: self.script_data_hash.is_some(),
+            has_ref_inputs: self.reference_inputs.is_some(),
+            has_collateral: self.collateral_inputs.is_some(),
+            has_native_scripts: false,
+            has_metadata_hash: self.auxiliary_data_hash.is_some(),
+            has_required_signers: self.required_signers.is_some(),
+            has_validity_start: self.validity_interval_start.is_some(),
+            has_ttl: self.ttl.is_some(),
+            has_treasury_donation: self.donation.is_some(),
+            has_current_treasury_value: self.current_treasury_value.is_some(),
+            mint_count,
+            cert_count: count(self.certs.as_ref()),
+            withdrawal_count,
+            vote_count,
+            proposal_count: count(self.proposal_procedures.as_ref()),
+        }
+    }
+}
+
+impl Transaction {
+    /// Same as TransactionBody::feature_flags() but also takes the witness set into account
+    /// for has_plutus and has_native_scripts.
+    pub fn feature_flags(&self) -> TxFeatures {
+        let witness_set = &self.witness_set;
+        let mut features = self.body.feature_flags();
+        features.has_plutus |= witness_set.redeemers.is_some()
+            || witness_set.plutus_v1_scripts.is_some()
+            || witness_set.plutus_v2_scripts.is_some()
+            || witness_set.plutus_v3_scripts.is_some();
+        features.has_native_scripts = witness_set.native_scripts.is_some();
+        features
+    }
+}
+
+/// Transaction features which are not visible to every plutus language's script context.
+/// Scripts of an older language will fail phase-2 validation when the tx uses these.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+)]
+pub enum TxContextFeature {
+    ReferenceInputs,
+    InlineDatums,
+    ReferenceScripts,
+    ConwayCertificates,
+    VotingProcedures,
+    ProposalProcedures,
+    CurrentTreasuryValue,
+    TreasuryDonation,
+    VotingRedeemers,
+    ProposingRedeemers,
+}
+
+impl TxContextFeature {
+    /// The first plutus language whose script context can represent this feature
+    pub fn min_language(&self) -> Language {
+        match self {
+            Self::ReferenceInputs | Self::InlineDatums | Self::ReferenceScripts => {
+                Language::PlutusV2
+            }
+            Self::ConwayCertificates
+            | Self::VotingProcedures
+            | Self::ProposalProcedures
+            | Self::CurrentTreasuryValue
+            | Self::TreasuryDonation
+            | Self::VotingRedeemers
+            | Self::ProposingRedeemers => Language::PlutusV3,
+        }
+    }
+}
+
+/// A plutus script in the witness set whose language can't observe some of the tx's features
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+pub struct PlutusLanguageWarning {
+    pub script_hash: ScriptHash,
+    pub language: Language,
+    pub unsupported_features: Vec<TxContextFeature>,
+}
+
+impl Transaction {
+    /// Features used by this tx that are only visible to newer plutus languages
+    pub fn plutus_context_features(&self) -> BTreeSet<TxContextFeature> {
+        let body = &self.body;
+        let mut features = BTreeSet::new();
+        if body.reference_inputs.is_some() {
+            features.insert(TxContextFeature::ReferenceInputs);
+        }
+        for output in body.outputs.iter() {
+            if let Some(DatumOption::Datum { .. }) = output.datum() {
+                features.insert(TxContextFeature::InlineDatums);
+            }
+            if output.script_ref().is_some() {
+                features.insert(TxContextFeature::ReferenceScripts);
+            }
+        }
+        if body.certs.iter().flatten().any(|cert| {
+            !matches!(
+                cert,
+                Certificate::StakeRegistration(_)
+                    | Certificate::StakeDeregistration(_)
+                    | Certificate::StakeDelegation(_)
+                    | Certificate::PoolRegistration(_)
+                    | Certificate::PoolRetirement(_)
+            )
+        }) {
+            features.insert(TxContextFeature::ConwayCertificates);
+        }
+        if body.voting_procedures.is_some() {
+            features.insert(TxContextFeature::VotingProcedures);
+        }
+        if body.proposal_procedures.is_some() {
+            features.insert(TxContextFeature::ProposalProcedures);
+        }
+        if body.current_treasury_value.is_some() {
+            features.insert(TxContextFeature::CurrentTreasuryValue);
+        }
+        if body.donation.is_some() {
+            features.insert(TxContextFeature::TreasuryDonation);
+        }
+        if let Some(redeemers) = &self.witness_set.redeemers {
+            for redeemer in redeemers.clone().to_flat_format() {
+                match redeemer.tag {
+                    RedeemerTag::Voting => {
+                        features.insert(TxContextFeature::VotingRedeemers);
+                    }
+                    RedeemerTag::Proposing => {
+                        features.insert(TxContextFeature::ProposingRedeemers);
+                    }
+                    _ => (),
+                }
+            }
+        }
+        features
+    }
+
+    /// Minimum plutus language that can observe all features this tx uses.
+    /// None if the tx uses nothing beyond what PlutusV1 can see.
+    /// This is static analysis of the tx only: reference scripts in resolved inputs are not known here.
+    pub fn required_plutus_language_floor(&self) -> Option<Language> {
+        self.plutus_context_features()
+            .iter()
+            .map(TxContextFeature::min_language)
+            .max()
+    }
+
+    /// Warns about each plutus script in the witness set whose language can't observe
+    /// all features of this tx, which would make the script fail phase-2 validation.
+    pub fn plutus_language_warnings(&self) -> Vec<PlutusLanguageWarning> {
+        let features = self.plutus_context_features();
+        let witness_set = &self.witness_set;
+        let scripts = witness_set
+            .plutus_v1_scripts
+            .iter()
+            .flatten()
+            .map(|script| (script.hash(), Language::PlutusV1))
+            .chain(
+                witness_set
+                    .plutus_v2_scripts
+                    .iter()
+                    .flatten()
+                    .map(|script| (script.hash(), Language::PlutusV2)),
+            );
+        scripts
+            .filter_map(|(script_hash, language)| {
+                let unsupported_features = features
+                    .iter()
+                    .filter(|feature| feature.min_language() > language)
+                    .copied()
+                    .collect::<Vec<_>>();
+                if unsupported_features.is_empty() {
+                    None
+                } else {
+                    Some(PlutusLanguageWarning {
+                        script_hash,
+                        language,
+                        unsupported_features,
+                    })
+                }
+            })
+            .collect()
+    }
+}
+
+impl Transaction {
+    /// Request body for cardano-submit-api (POST /api/submit/tx with Content-Type: application/cbor).
+    /// These are the tx's CBOR bytes with its original encoding preserved (not re-encoded
+    /// canonically) as re-encoding could change the body hash and invalidate the signatures.
+    pub fn to_submit_api_bytes(&self) -> Vec<u8> {
+        self.to_cbor_bytes()
+    }
+
+    /// Request body for Ogmios' (v6) submitTransaction JSON-RPC method with the tx as hex CBOR
+    /// (original encoding preserved, see to_submit_api_bytes())
+    pub fn to_ogmios_submit_json(&self) -> String {
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "method": "submitTransaction",
+            "params": {
+                "transaction": {
+                    "cbor": hex::encode(self.to_submit_api_bytes()),
+                },
+            },
+        })
+        .to_string()
+    }
+}
+
+/// Reason a node rejected a submitted transaction.
+/// Only the most common rejections are typed, all others are kept as Other.
+#[derive(Clone, Debug, PartialEq, thiserror::Error)]
+pub enum TxSubmitError {
+    #[error("Transaction is for the {query_era} era but the ledger is in the {ledger_era} era")]
+    EraMismatch {
+        query_era: String,
+        ledger_era: String,
+    },
+    #[error("Missing vkey witnesses for: {0:?}")]
+    MissingVkeyWitnesses(Vec<Ed25519KeyHash>),
+    #[error("Value not conserved: consumed {consumed:?} but produced {produced:?}")]
+    ValueNotConserved { consumed: Value, produced: Value },
+    #[error("Submission failed ({code}): {message}")]
+    Other {
+        code: i64,
+        message: String,
+        data: Option<JSONValue>,
+    },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum OgmiosResponseError {
+    #[error("Ogmios response is not valid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Ogmios response has no error code")]
+    NoError,
+}
+
+impl TxSubmitError {
+    pub const OGMIOS_ERA_MISMATCH: i64 = 3005;
+    pub const OGMIOS_MISSING_SIGNATORIES: i64 = 3101;
+    pub const OGMIOS_VALUE_NOT_CONSERVED: i64 = 3123;
+
+    /// Parses an Ogmios (v6) submitTransaction error response.
+    /// Known error codes whose data isn't in the expected format are returned as Other.
+    pub fn from_ogmios_response(response: &str) -> Result<Self, OgmiosResponseError> {
+        let response: JSONValue = serde_json::from_str(response)?;
+        let error = response.get("error").ok_or(OgmiosResponseError::NoError)?;
+        let code = error
+            .get("code")
+            .and_then(JSONValue::as_i64)
+            .ok_or(OgmiosResponseError::NoError)?;
+        let message = error
+            .get("message")
+            .and_then(JSONValue::as_str)
+            .unwrap_or_default()
+            .to_owned();
+        let data = error.get("data");
+        let typed = data.and_then(|data| match code {
+            Self::OGMIOS_ERA_MISMATCH => Some(Self::EraMismatch {
+                query_era: data.get("queryEra")?.as_str()?.to_owned(),
+                ledger_era: data.get("ledgerEra")?.as_str()?.to_owned(),
+            }),
+            Self::OGMIOS_MISSING_SIGNATORIES => data
+                .get("missingSignatories")?
+                .as_array()?
+                .iter()
+                .map(|key_hash| Ed25519KeyHash::from_hex(key_hash.as_str()?).ok())
+                .collect::<Option<Vec<_>>>()
+                .map(Self::MissingVkeyWitnesses),
+            Self::OGMIOS_VALUE_NOT_CONSERVED => Some(Self::ValueNotConserved {
+                consumed: ogmios_value(data.get("consumedValue")?)?,
+                produced: ogmios_value(data.get("producedValue")?)?,
+            }),
+            _ => None,
+        });
+        Ok(typed.unwrap_or_else(|| Self::Other {
+            code,
+            message,
+            data: data.cloned(),
+        }))
+    }
+
+    /// The Ogmios error code of this rejection
+    pub fn ogmios_code(&self) -> i64 {
+        match self {
+            Self::EraMismatch { .. } => Self::OGMIOS_ERA_MISMATCH,
+            Self::MissingVkeyWitnesses(_) => Self::OGMIOS_MISSING_SIGNATORIES,
+            Self::ValueNotConserved { .. } => Self::OGMIOS_VALUE_NOT_CONSERVED,
+            Self::Other { code, .. } => *code,
+        }
+    }
+}
+
+// Ogmios values are { "ada": { "lovelace": n }, "<policy id hex>": { "<asset name hex>": n } }
+fn ogmios_value(value: &JSONValue) -> Option<Value> {
+    let mut coin = 0;
+    let mut multiasset = MultiAsset::new();
+    for (policy, assets) in value.as_object()? {
+        if policy == "ada" {
+            coin = assets.get("lovelace")?.as_u64()?;
+            continue;
+        }
+        let policy_id = PolicyId::from_hex(policy).ok()?;
+        for (asset_name, amount) in assets.as_object()? {
+            let asset_name = AssetName::new(hex::decode(asset_name).ok()?).ok()?;
+            multiasset.set(policy_id, asset_name, amount.as_u64()?);
+        }
+    }
+    Some(Value::new(coin, multiasset))
+}
+
+#[cfg(test)]
+mod tests {
+    use cml_core::{
+        error::{DeserializeFailure, LimitKind},
+        serialization::{CborHex, Deserialize, DeserializeLimits, Serialize, StripEncodings},
+        CborHexError,
+    };
+
+    use super::*;
+    use crate::crypto::{hash::hash_plutus_data, Vkeywitness};
+    use crate::plutus::{PlutusData, PlutusV2Script};
+    use crate::transaction::TransactionInput;
+    use crate::utils::BigInteger;
+    use cml_crypto::{AuxiliaryDataHash, PrivateKey, RawBytesEncoding};
+
+    proptest::proptest! {
+        #[test]
+        fn transaction_input_output_roundtrip(
+            input in crate::testing::arb_transaction_input(),
+            output in crate::testing::arb_transaction_output(),
+        ) {
+            crate::testing::roundtrip_cbor(&input)?;
+            crate::testing::roundtrip_json(&input)?;
+            crate::testing::roundtrip_cbor(&output)?;
+        }
+    }
+
+    #[test]
+    fn transaction_input_eq_hash_ignore_encodings() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+        fn hash_of(input: &TransactionInput) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            input.hash(&mut hasher);
+            hasher.finish()
+        }
+        let tx_id = "5820".to_owned() + &"ab".repeat(32);
+        // [tx_id, 1]
+        let canonical =
+            TransactionInput::from_cbor_bytes(&hex::decode(format!("82{}01", tx_id)).unwrap())
+                .unwrap();
+        // [_ tx_id, 1 (4 byte uint)]
+        let non_canonical = TransactionInput::from_cbor_bytes(
+            &hex::decode(format!("9f{}1a00000001ff", tx_id)).unwrap(),
+        )
+        .unwrap();
+        assert_ne!(canonical.to_cbor_bytes(), non_canonical.to_cbor_bytes());
+        assert_eq!(canonical, non_canonical);
+        assert_eq!(canonical.cmp(&non_canonical), std::cmp::Ordering::Equal);
+        assert_eq!(hash_of(&canonical), hash_of(&non_canonical));
+        // ordered by tx id then index
+        let next = TransactionInput::new(canonical.transaction_id, 2);
+        let other_tx = TransactionInput::new(TransactionHash::from([0xac; 32]), 0);
+        assert!(non_canonical < next && next < other_tx);
+    }
+
+    // { 0: [], 1: [], 2: 0, 5: { <reward_account>: 1, <reward_account>: 2 } }
+    fn tx_body_with_withdrawals(first_key: u8, second_key: u8) -> Vec<u8> {
+        let mut bytes = hex::decode("a400800180020005a2").unwrap();
+        for (key_byte, amount) in [(first_key, 0x01), (second_key, 0x02)] {
+            bytes.extend_from_slice(&[0x58, 0x1d, 0xe1]);
+            bytes.extend_from_slice(&[key_byte; 28]);
+            bytes.push(amount);
+        }
+        bytes
+    }
+
+    #[test]
+    fn withdrawals_duplicate_key_rejected() {
+        let err = TransactionBody::from_cbor_bytes(&tx_body_with_withdrawals(0xaa, 0xaa))
+            .expect_err("duplicate reward account must not deserialize");
+        assert!(matches!(err.failure(), DeserializeFailure::DuplicateKey(_)));
+        assert!(err
+            .location()
+            .is_some_and(|location| location.contains("withdrawals")));
+    }
+
+    #[test]
+    fn withdrawals_distinct_keys_round_trip() {
+        let bytes = tx_body_with_withdrawals(0xaa, 0xbb);
+        let body = TransactionBody::from_cbor_bytes(&bytes).unwrap();
+        assert_eq!(body.withdrawals.as_ref().map(|w| w.len()), Some(2));
+        assert_eq!(body.to_cbor_bytes(), bytes);
+    }
+
+    #[test]
+    fn withdrawals_canonical_json() {
+        use cml_core::serialization::ToCanonicalJson;
+        let body_ab =
+            TransactionBody::from_cbor_bytes(&tx_body_with_withdrawals(0xaa, 0xbb)).unwrap();
+        let body_ba =
+            TransactionBody::from_cbor_bytes(&tx_body_with_withdrawals(0xbb, 0xaa)).unwrap();
+        assert_ne!(body_ab.to_cbor_bytes(), body_ba.to_cbor_bytes());
+        // amounts differ per key so re-key both to the same values to get logically-equal maps
+        let mut withdrawals_ab = body_ab.withdrawals.clone().unwrap();
+        let mut withdrawals_ba = body_ba.withdrawals.clone().unwrap();
+        for (_, amount) in withdrawals_ab.iter_mut().chain(withdrawals_ba.iter_mut()) {
+            *amount = 1_000_000_000_000_000_000;
+        }
+        let json = withdrawals_ab.to_json_canonical().unwrap();
+        assert_eq!(json, withdrawals_ba.to_json_canonical().unwrap());
+        assert!(json.contains("1000000000000000000"));
+        assert!(!json.contains(char::is_whitespace));
+        // keys are sorted as strings (not shorter first) and whole floats written as integers
+        assert_eq!(
+            cml_core::serialization::canonicalize_json(&serde_json::json!({
+                "c": { "bb": 1, "a": null },
+                "b": 2.0e3,
+                "aa": [1.5, 1e20],
+            })),
+            r#"{"aa":[1.5,100000000000000000000],"b":2000,"c":{"a":null,"bb":1}}"#
+        );
+    }
+
+    #[test]
+    fn tx_body_cbor_hex() {
+        let hex_str = hex::encode(tx_body_with_withdrawals(0xaa, 0xbb));
+        let body = TransactionBody::from_cbor_hex(&hex_str).unwrap();
+        assert_eq!(body.to_cbor_hex(), hex_str);
+        assert!(matches!(
+            TransactionBody::from_cbor_hex("a40"),
+            Err(CborHexError::Hex(hex::FromHexError::OddLength))
+        ));
+        assert!(matches!(
+            TransactionBody::from_cbor_hex("a4"),
+            Err(CborHexError::Cbor(_))
+        ));
+    }
+
+    #[test]
+    fn tx_body_original_bytes_hash() {
+        // { 0: [[#32 0xab, 0]], 1: [], 2: 10, 5: { <reward_account>: 1 } }
+        // using indefinite lengths everywhere and a 4-byte fee
+        let mut bytes = hex::decode("bf009f825820").unwrap();
+        bytes.extend_from_slice(&[0xab; 32]);
+        bytes.extend_from_slice(&hex::decode("00ff019fff021a0000000a05bf581de1").unwrap());
+        bytes.extend_from_slice(&[0xaa; 28]);
+        bytes.extend_from_slice(&hex::decode("01ffff").unwrap());
+
+        let re_encoded = TransactionBody::from_cbor_bytes(&bytes).unwrap();
+        assert_eq!(re_encoded.original_bytes(), None);
+        let mut original = TransactionBody::from_cbor_bytes_keep_original(&bytes).unwrap();
+        assert_eq!(original.original_bytes(), Some(bytes.as_slice()));
+        let expected = TransactionHash::from(cml_crypto::blake2b256(&bytes));
+        assert_eq!(re_encoded.hash(), expected);
+        assert_eq!(original.hash(), expected);
+
+        // original bytes are considered an encoding detail
+        assert_eq!(original.stripped().original_bytes(), None);
+        original.fee = 11;
+        original.set_original_bytes(None);
+        assert_ne!(original.hash(), expected);
+    }
+
+    #[test]
+    fn tx_body_field_edits_keep_encodings() {
+        // { 0: [], 1: [], 2: 0, 8: 10 (2-byte uint) } as an indefinite map
+        let bytes = hex::decode("bf0080018002000819000aff").unwrap();
+        let body = TransactionBody::from_cbor_bytes_keep_original(&bytes).unwrap();
+
+        // a new ttl goes before the validity start, the validity start is re-encoded in place
+        let edited = body.clone().with_ttl(7).with_validity_start(20);
+        assert_eq!(edited.original_bytes(), None);
+        assert_eq!(
+            hex::encode(edited.to_cbor_bytes()),
+            "bf00800180020003070814ff"
+        );
+        assert_eq!(edited.ttl, Some(7));
+        assert_eq!(edited.validity_interval_start, Some(20));
+
+        // a replaced ttl keeps its position and key encoding
+        let edited = edited.with_ttl(1000);
+        assert_eq!(
+            hex::encode(edited.to_cbor_bytes()),
+            "bf008001800200031903e80814ff"
+        );
+
+        // unchanged fields are untouched by a fee edit
+        let edited = body.with_fee(0);
+        assert_eq!(edited.original_bytes(), None);
+        assert_eq!(edited.to_cbor_bytes(), bytes);
+
+        // bodies without encoding details serialize canonically as before
+        let built = TransactionBody::new(vec![].into(), vec![], 0).with_ttl(5);
+        assert_eq!(hex::encode(built.to_cbor_bytes()), "a40080018002000305");
+    }
+
+    #[test]
+    fn tx_body_field_edits_add_missing_fields() {
+        // { 0: [], 1: [], 2: 0 }
+        let bytes = hex::decode("a3008001800200").unwrap();
+        let body = TransactionBody::from_cbor_bytes(&bytes).unwrap();
+        let edited = body.with_validity_start(20).with_ttl(7).with_fee(5);
+        let edited_bytes = edited.to_cbor_bytes();
+        assert_eq!(hex::encode(&edited_bytes), "a500800180020503070814");
+        let decoded = TransactionBody::from_cbor_bytes(&edited_bytes).unwrap();
+        assert_eq!(decoded.fee, 5);
+        assert_eq!(decoded.ttl, Some(7));
+        assert_eq!(decoded.validity_interval_start, Some(20));
+        assert_eq!(decoded.to_cbor_bytes(), edited_bytes);
+
+        // { 1: [], 0: [], 9: { policy: { h'': 1 } }, 2: 0 } in non-canonical order.
+        // validity_interval_start (key 8) is a lower field index than mint (key 9)
+        // so both new fields must be placed before the mint and not after it.
+        let mint_hex = format!("09a1581c{}a14001", "00".repeat(28));
+        let bytes = hex::decode(format!("a401800080{mint_hex}0200")).unwrap();
+        let body = TransactionBody::from_cbor_bytes(&bytes).unwrap();
+        assert_eq!(body.to_cbor_bytes(), bytes);
+        let edited = body.with_validity_start(20).with_ttl(7);
+        let edited_bytes = edited.to_cbor_bytes();
+        assert_eq!(
+            hex::encode(&edited_bytes),
+            format!("a60180008003070814{mint_hex}0200")
+        );
+        let decoded = TransactionBody::from_cbor_bytes(&edited_bytes).unwrap();
+        assert_eq!(decoded.ttl, Some(7));
+        assert_eq!(decoded.validity_interval_start, Some(20));
+        assert!(decoded.mint.is_some());
+        assert_eq!(decoded.to_cbor_bytes(), edited_bytes);
+    }
+
+    #[test]
+    fn strip_encodings_structural_equality() {
+        let input_hash = [0xab; 32];
+        // { 0: [[hash, 0]], 1: [], 2: 10 }
+        let mut canonical_bytes = hex::decode("a30081825820").unwrap();
+        canonical_bytes.extend_from_slice(&input_hash);
+        canonical_bytes.extend_from_slice(&hex::decode("000180020a").unwrap());
+        // the same body as an indefinite map with an indefinite input list, a 1-byte input
+        // index and a 4-byte fee
+        let mut non_canonical_bytes = hex::decode("bf009f825820").unwrap();
+        non_canonical_bytes.extend_from_slice(&input_hash);
+        non_canonical_bytes.extend_from_slice(&hex::decode("1800ff0180021a0000000aff").unwrap());
+        let canonical = TransactionBody::from_cbor_bytes(&canonical_bytes).unwrap();
+        let non_canonical = TransactionBody::from_cbor_bytes(&non_canonical_bytes).unwrap();
+        assert_eq!(canonical.to_cbor_bytes(), canonical_bytes);
+        assert_eq!(non_canonical.to_cbor_bytes(), non_canonical_bytes);
+
+        assert_eq!(non_canonical.stripped().to_cbor_bytes(), canonical_bytes);
+        assert_eq!(
+            canonical.stripped().to_cbor_bytes(),
+            non_canonical.stripped().to_cbor_bytes()
+        );
+        let built = TransactionBody::new(
+            vec![TransactionInput::new(TransactionHash::from(input_hash), 0)].into(),
+            vec![],
+            10,
+        );
+        assert_eq!(
+            non_canonical.stripped().to_cbor_bytes(),
+            built.to_cbor_bytes()
+        );
+        let mut stripped = non_canonical.clone();
+        stripped.strip_encodings();
+        assert_eq!(stripped.to_cbor_bytes(), canonical_bytes);
+        // the original is left untouched by stripped()
+        assert_eq!(non_canonical.to_cbor_bytes(), non_canonical_bytes);
+    }
+
+    #[test]
+    fn transaction_diff_fee_and_witness() {
+        let body =
+            TransactionBody::from_cbor_bytes(&hex::decode("a300800180020a").unwrap()).unwrap();
+        let witness = |key: u8| {
+            let sk = PrivateKey::from_normal_bytes(&[key; 32]).unwrap();
+            Vkeywitness::new(sk.to_public(), sk.sign(body.hash().to_raw_bytes()))
+        };
+        let mut witness_set = TransactionWitnessSet::new();
+        witness_set.vkeywitnesses = Some(vec![witness(1)].into());
+        let tx = Transaction::new(body.clone(), witness_set, true, None);
+        assert!(tx.diff(&tx).is_empty());
+        // encoding-only differences are ignored
+        let non_canonical_body =
+            TransactionBody::from_cbor_bytes(&hex::decode("bf00800180021a0000000aff").unwrap())
+                .unwrap();
+        let mut re_encoded = tx.clone();
+        re_encoded.body = non_canonical_body;
+        assert!(tx.diff(&re_encoded).is_empty());
+
+        let mut other = tx.clone();
+        other.body.fee = 11;
+        other
+            .witness_set
+            .vkeywitnesses
+            .as_mut()
+            .unwrap()
+            .push(witness(2));
+        let diff = tx.diff(&other);
+        assert_eq!(diff.differences.len(), 2);
+        assert_eq!(
+            diff.differences[0],
+            TransactionFieldDiff {
+                path: "body.fee".to_owned(),
+                left: Some("10".to_owned()),
+                right: Some("11".to_owned()),
+            }
+        );
+        assert_eq!(diff.differences[1].path, "witness_set.vkeywitnesses[1]");
+        assert_eq!(diff.differences[1].left, None);
+        assert!(diff.differences[1].right.is_some());
+        let json = serde_json::to_string(&diff).unwrap();
+        assert_eq!(
+            serde_json::from_str::<TransactionDiff>(&json).unwrap(),
+            diff
+        );
+    }
+
+    #[test]
+    fn conway_tx_stats() {
+        use crate::{
+            certs::{Certificate, DRep, StakeCredential},
+            fees::LinearFee,
+            plutus::{
+                ExUnitPrices, PlutusData, PlutusV1Script, PlutusV2Script, PlutusV3Script,
+                RedeemerKey, RedeemerTag, RedeemerVal, Redeemers,
+            },
+            transaction::{TransactionInput, TransactionOutput},
+            SubCoin,
+        };
+        use cml_core::ordered_hash_map::OrderedHashMap;
+
+        let input = |index| TransactionInput::new(TransactionHash::from([1; 32]), index);
+        let address =
+            Address::from_bech32("addr1vx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzers66hrl8")
+                .unwrap();
+        let stake_cred = StakeCredential::new_pub_key(Ed25519KeyHash::from([2; 28]));
+        let mut body = TransactionBody::new(
+            vec![input(0), input(1)].into(),
+            vec![
+                TransactionOutput::new(address.clone(), Value::from(2_000_000), None, None),
+                TransactionOutput::new(
+                    address,
+                    Value::from(3_000_000),
+                    Some(DatumOption::new_datum(PlutusData::new_bytes(vec![0; 8]))),
+                    None,
+                ),
+            ],
+            500_000,
+        );
+        body.reference_inputs = Some(vec![input(2)].into());
+        body.collateral_inputs = Some(vec![input(3)].into());
+        body.certs = Some(
+            vec![
+                Certificate::new_reg_cert(stake_cred.clone(), 2_000_000),
+                Certificate::new_vote_deleg_cert(stake_cred, DRep::new_always_abstain()),
+            ]
+            .into(),
+        );
+
+        let mut witness_set = TransactionWitnessSet::new();
+        witness_set.vkeywitnesses = Some(
+            [1u8, 2]
+                .iter()
+                .map(|key| {
+                    let sk = PrivateKey::from_normal_bytes(&[*key; 32]).unwrap();
+                    Vkeywitness::new(sk.to_public(), sk.sign(body.hash().to_raw_bytes()))
+                })
+                .collect::<Vec<_>>()
+                .into(),
+        );
+        witness_set.native_scripts = Some(
+            vec![NativeScript::new_script_pubkey(Ed25519KeyHash::from(
+                [3; 28],
+            ))]
+            .into(),
+        );
+        witness_set.plutus_v1_scripts = Some(vec![PlutusV1Script::new(vec![1; 10])].into());
+        witness_set.plutus_v2_scripts = Some(vec![PlutusV2Script::new(vec![2; 100])].into());
+        witness_set.plutus_v3_scripts = Some(
+            vec![
+                PlutusV3Script::new(vec![3; 30]),
+                PlutusV3Script::new(vec![4; 40]),
+            ]
+            .into(),
+        );
+        witness_set.plutus_datums = Some(
+            vec![
+                PlutusData::new_integer(42u64.into()),
+                PlutusData::new_bytes(vec![5; 32]),
+            ]
+            .into(),
+        );
+        let mut redeemers = OrderedHashMap::new();
+        redeemers.insert(
+            RedeemerKey::new(RedeemerTag::Spend, 0),
+            RedeemerVal::new(
+                PlutusData::new_integer(0u64.into()),
+                ExUnits::new(1000, 2000),
+            ),
+        );
+        redeemers.insert(
+            RedeemerKey::new(RedeemerTag::Cert, 1),
+            RedeemerVal::new(PlutusData::new_integer(1u64.into()), ExUnits::new(300, 400)),
+        );
+        witness_set.redeemers = Some(Redeemers::new_map_redeemer_key_to_redeemer_val(redeemers));
+
+        let bytes = Transaction::new(body, witness_set, true, None).to_cbor_bytes();
+        let tx = Transaction::from_cbor_bytes(&bytes).unwrap();
+        let stats = tx.stats();
+        assert_eq!(stats.size, bytes.len() as u64);
+        assert_eq!(stats.input_count, 2);
+        assert_eq!(stats.reference_input_count, 1);
+        assert_eq!(stats.collateral_input_count, 1);
+        assert_eq!(stats.output_count, 2);
+        assert_eq!(stats.certificate_count, 2);
+        assert_eq!(stats.vkey_witness_count, 2);
+        assert_eq!(stats.bootstrap_witness_count, 0);
+        // [0, h'..'] = 1 + 1 + 2 + 28
+        assert_eq!(
+            stats.native_scripts,
+            WitnessEntryStats { count: 1, size: 32 }
+        );
+        // byte string headers add 1 byte below 24 bytes and 2 bytes below 256
+        assert_eq!(
+            stats.plutus_v1_scripts,
+            WitnessEntryStats { count: 1, size: 11 }
+        );
+        assert_eq!(
+            stats.plutus_v2_scripts,
+            WitnessEntryStats {
+                count: 1,
+                size: 102
+            }
+        );
+        assert_eq!(
+            stats.plutus_v3_scripts,
+            WitnessEntryStats { count: 2, size: 74 }
+        );
+        assert_eq!(stats.datums, WitnessEntryStats { count: 2, size: 36 });
+        assert_eq!(stats.redeemer_count, 2);
+        assert_eq!(stats.total_ex_units, ExUnits::new(1300, 2400));
+        assert_eq!(stats.min_fee, None);
+
+        let linear_fee = LinearFee::new(44, 155381, 15);
+        let ex_unit_prices =
+            ExUnitPrices::new(SubCoin::new(577, 10000), SubCoin::new(721, 10000000));
+        let stats = tx
+            .stats_with_min_fee(&linear_fee, &ex_unit_prices, 0)
+            .unwrap();
+        assert_eq!(
+            stats.min_fee,
+            Some(crate::fees::min_fee(&tx, &linear_fee, &ex_unit_prices, 0).unwrap())
+        );
+        // 1300 * 0.0577 + 2400 * 0.0000721 = 75.18 -> 76 on top of the size fee
+        assert_eq!(stats.min_fee, Some(155381 + 44 * stats.size + 76));
+        assert!(serde_json::to_value(&stats).is_ok());
+    }
+
+    #[test]
+    fn auxiliary_data_hash_shelley_and_conway() {
+        // { 1: "a" } encoded as an indefinite map
+        let shelley_bytes = hex::decode("bf016161ff").unwrap();
+        // 259({ 0: { 1: "a" } })
+        let conway_bytes = hex::decode("d90103a100a1016161").unwrap();
+        for bytes in [shelley_bytes, conway_bytes].iter() {
+            let auxiliary_data = AuxiliaryData::from_cbor_bytes(bytes).unwrap();
+            let mut body = TransactionBody::from_cbor_bytes_keep_original(
+                &hex::decode("a300800180020a").unwrap(),
+            )
+            .unwrap();
+            assert!(!body.verify_auxiliary_data(&auxiliary_data));
+            body.set_auxiliary_data_hash_from(&auxiliary_data);
+            assert_eq!(
+                body.auxiliary_data_hash,
+                Some(AuxiliaryDataHash::from(cml_crypto::blake2b256(bytes)))
+            );
+            assert!(body.verify_auxiliary_data(&auxiliary_data));
+            // the body changed so its original bytes can't be used for the tx hash anymore
+            assert_eq!(body.original_bytes(), None);
+            let mut different = auxiliary_data.clone();
+            different.metadata_mut().set(
+                2,
+                crate::auxdata::TransactionMetadatum::new_int(1u64.into()),
+            );
+            assert!(!body.verify_auxiliary_data(&different));
+        }
+    }
+
+    #[test]
+    fn plutus_language_floor_and_warnings() {
+        use crate::{
+            certs::{Certificate, DRep, StakeCredential},
+            plutus::{PlutusV1Script, PlutusV2Script},
+            transaction::TransactionInput,
+        };
+
+        let input = |index| TransactionInput::new(TransactionHash::from([1; 32]), index);
+        let address =
+            Address::from_bech32("addr1vx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzers66hrl8")
+                .unwrap();
+        let body = TransactionBody::new(
+            vec![input(0)].into(),
+            vec![TransactionOutput::new(
+                address,
+                Value::from(2_000_000),
+                None,
+                None,
+            )],
+            500_000,
+        );
+        let v1_script = PlutusV1Script::new(vec![1; 10]);
+        let mut witness_set = TransactionWitnessSet::new();
+        witness_set.plutus_v1_scripts = Some(vec![v1_script.clone()].into());
+        let mut tx = Transaction::new(body, witness_set, true, None);
+        assert!(tx.plutus_context_features().is_empty());
+        assert_eq!(tx.required_plutus_language_floor(), None);
+        assert!(tx.plutus_language_warnings().is_empty());
+
+        // V1 scripts can't see reference inputs
+        tx.body.reference_inputs = Some(vec![input(1)].into());
+        assert_eq!(
+            tx.required_plutus_language_floor(),
+            Some(Language::PlutusV2)
+        );
+        let warnings = tx.plutus_language_warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].script_hash, v1_script.hash());
+        assert_eq!(warnings[0].language, Language::PlutusV1);
+        assert_eq!(
+            warnings[0].unsupported_features,
+            vec![TxContextFeature::ReferenceInputs]
+        );
+
+        // and V2 scripts can't see conway certs
+        tx.body.certs = Some(
+            vec![Certificate::new_vote_deleg_cert(
+                StakeCredential::new_pub_key(Ed25519KeyHash::from([2; 28])),
+                DRep::new_always_abstain(),
+            )]
+            .into(),
+        );
+        tx.witness_set.plutus_v2_scripts = Some(vec![PlutusV2Script::new(vec![2; 10])].into());
+        assert_eq!(
+            tx.required_plutus_language_floor(),
+            Some(Language::PlutusV3)
+        );
+        let warnings = tx.plutus_language_warnings();
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(
+            warnings[0].unsupported_features,
+            vec![
+                TxContextFeature::ReferenceInputs,
+                TxContextFeature::ConwayCertificates
+            ]
+        );
+        assert_eq!(warnings[1].language, Language::PlutusV2);
+        assert_eq!(
+            warnings[1].unsupported_features,
+            vec![TxContextFeature::ConwayCertificates]
+        );
+    }
+
+    #[test]
+    fn feature_flags() {
+        use crate::address::RewardAddress;
+        use crate::assets::{AssetName, Mint};
+        use crate::certs::{Certificate, StakeCredential, Url};
+        use crate::governance::{
+            Anchor, GovAction, GovActionId, ProposalProcedure, Vote, Voter, VotingProcedure,
+        };
+        use crate::{OrderedHashMap, PolicyId};
+        use cml_crypto::AnchorDocHash;
+
+        let mut body = TransactionBody::new(vec![].into(), vec![], 0);
+        assert_eq!(body.feature_flags(), TxFeatures::default());
+
+        let cred = StakeCredential::new_pub_key(Ed25519KeyHash::from([1; 28]));
+        let mut mint = Mint::new();
+        let policy = PolicyId::from([2; 28]);
+        mint.set(policy, AssetName::new(b"a".to_vec()).unwrap(), 1);
+        mint.set(policy, AssetName::new(b"b".to_vec()).unwrap(), -1);
+        body.mint = Some(mint);
+        body.certs = Some(vec![Certificate::new_stake_registration(cred.clone())].into());
+        let mut votes = OrderedHashMap::new();
+        for index in 0..2 {
+            votes.insert(
+                GovActionId::new(TransactionHash::from([3; 32]), index),
+                VotingProcedure::new(Vote::Yes, None),
+            );
+        }
+        let mut voting_procedures = OrderedHashMap::new();
+        voting_procedures.insert(
+            Voter::new_d_rep_key_hash(Ed25519KeyHash::from([4; 28])),
+            votes,
+        );
+        body.voting_procedures = Some(voting_procedures);
+        body.proposal_procedures = Some(
+            vec![ProposalProcedure::new(
+                100_000_000_000,
+                RewardAddress::new(0, cred),
+                GovAction::new_info_action(),
+                Anchor::new(
+                    Url::new("https://example.com".to_owned()).unwrap(),
+                    AnchorDocHash::from([5; 32]),
+                ),
+            )]
+            .into(),
+        );
+        body.ttl = Some(1000);
+        body.donation = Some(crate::assets::PositiveCoin::try_new(1_000_000).unwrap());
+
+        let features = body.feature_flags();
+        assert_eq!(
+            features,
+            TxFeatures {
+                has_mint: true,
+                has_certs: true,
+                has_votes: true,
+                has_proposals: true,
+                has_ttl: true,
+                has_treasury_donation: true,
+                mint_count: 2,
+                cert_count: 1,
+                vote_count: 2,
+                proposal_count: 1,
+                ..Default::default()
+            }
+        );
+
+        // native scripts and plutus-only witnesses are only visible via the whole tx
+        let mut witness_set = TransactionWitnessSet::new();
+        witness_set.native_scripts = Some(
+            vec![NativeScript::new_script_pubkey(Ed25519KeyHash::from(
+                [6; 28],
+            ))]
+            .into(),
+        );
+        let tx = Transaction::new(body, witness_set, true, None);
+        assert!(!features.has_native_scripts);
+        let tx_features = tx.feature_flags();
+        assert!(tx_features.has_native_scripts);
+        assert!(!tx_features.has_plutus);
+    }
+
+    #[test]
+    fn zero_mint_and_donation_rejected() {
+        use crate::assets::{AssetName, Mint, PositiveCoin};
+        use crate::PolicyId;
+
+        let serialize = |body: &TransactionBody| {
+            let mut serializer = cbor_event::se::Serializer::new_vec();
+            body.serialize(&mut serializer, false)?;
+            Ok::<_, cbor_event::Error>(serializer.finalize())
+        };
+        let mut body = TransactionBody::new(vec![].into(), vec![], 0);
+        let mut mint = Mint::new();
+        mint.set(PolicyId::from([1; 28]), AssetName::new(vec![]).unwrap(), 0);
+        body.mint = Some(mint);
+        assert!(serialize(&body).is_err());
+
+        // a 0 amount next to non-zero ones still fails
+        let mut mint = Mint::new();
+        mint.set(PolicyId::from([2; 28]), AssetName::new(vec![]).unwrap(), 0);
+        mint.set(
+            PolicyId::from([2; 28]),
+            AssetName::new(vec![1]).unwrap(),
+            -1,
+        );
+        body.mint = Some(mint);
+        assert!(serialize(&body).is_err());
+
+        // an empty mint or policy would be written as key 9 with an empty map
+        body.mint = Some(Mint::new());
+        assert!(serialize(&body).is_err());
+        let mut mint = Mint::new();
+        mint.insert(PolicyId::from([3; 28]), Default::default());
+        body.mint = Some(mint);
+        assert!(serialize(&body).is_err());
+
+        // { 0: [], 1: [], 2: 0, 9: { policy2: { h'01': -1 } } }
+        let mut mint = Mint::new();
+        mint.set(
+            PolicyId::from([2; 28]),
+            AssetName::new(vec![1]).unwrap(),
+            -1,
+        );
+        body.mint = Some(mint);
+        assert_eq!(
+            hex::encode(serialize(&body).unwrap()),
+            format!("a400800180020009a1581c{}a1410120", "02".repeat(28))
+        );
+
+        // { 0: [], 1: [], 2: 0, 9: { policy: { h'': 0 } } }
+        let zero_mint = format!("a400800180020009a1581c{}a14000", "01".repeat(28));
+        let err = TransactionBody::from_cbor_bytes(&hex::decode(zero_mint).unwrap()).unwrap_err();
+        assert!(matches!(
+            err.failure(),
+            DeserializeFailure::RangeCheck { found: 0, .. }
+        ));
+        // { 0: [], 1: [], 2: 0, 22: 0 }
+        let zero_donation = hex::decode("a40080018002001600").unwrap();
+        assert!(TransactionBody::from_cbor_bytes(&zero_donation).is_err());
+
+        body.mint = None;
+        body.donation = Some(PositiveCoin::try_new(1).unwrap());
+        let bytes = body.to_cbor_bytes();
+        assert_eq!(hex::encode(&bytes), "a40080018002001601");
+        assert_eq!(
+            TransactionBody::from_cbor_bytes(&bytes).unwrap().donation,
+            body.donation
+        );
+    }
+
+    #[test]
+    fn strip_and_restore_witnesses() {
+        use crate::transaction::NativeScript;
+        // { 0: [], 1: [], 2: 10 }
+        let body_bytes = hex::decode("a300800180020a").unwrap();
+        let body = TransactionBody::from_cbor_bytes(&body_bytes).unwrap();
+        let keys = [1u8, 2]
+            .iter()
+            .map(|key| PrivateKey::from_normal_bytes(&[*key; 32]).unwrap())
+            .collect::<Vec<_>>();
+        let witness_bytes = keys
+            .iter()
+            .map(|sk| {
+                Vkeywitness::new(sk.to_public(), sk.sign(body.hash().to_raw_bytes()))
+                    .to_cbor_bytes()
+            })
+            .collect::<Vec<_>>();
+        let script_bytes =
+            NativeScript::new_script_pubkey(keys[0].to_public().hash()).to_cbor_bytes();
+        // witness set with non-default encodings: native scripts before vkeys, tagged vkey set
+        // { 1: [script], 0: 258([witness1, witness2]) }
+        let mut witness_set_bytes = vec![0xa2, 0x01, 0x81];
+        witness_set_bytes.extend_from_slice(&script_bytes);
+        witness_set_bytes.extend_from_slice(&[0x00, 0xd9, 0x01, 0x02, 0x82]);
+        for bytes in witness_bytes.iter() {
+            witness_set_bytes.extend_from_slice(bytes);
+        }
+        let tx_bytes = |witness_set: &[u8]| {
+            let mut bytes = vec![0x84];
+            bytes.extend_from_slice(&body_bytes);
+            bytes.extend_from_slice(witness_set);
+            bytes.extend_from_slice(&[0xf5, 0xf6]);
+            bytes
+        };
+        let tx = Transaction::from_cbor_bytes(&tx_bytes(&witness_set_bytes)).unwrap();
+        assert_eq!(tx.to_cbor_bytes(), tx_bytes(&witness_set_bytes));
+
+        let mut retained = tx.witness_set.clone();
+        retained.retain_vkeys(&vec![keys[1].to_public().hash()].into_iter().collect());
+        let mut retained_bytes = vec![0xa2, 0x01, 0x81];
+        retained_bytes.extend_from_slice(&script_bytes);
+        retained_bytes.extend_from_slice(&[0x00, 0xd9, 0x01, 0x02, 0x81]);
+        retained_bytes.extend_from_slice(&witness_bytes[1]);
+        assert_eq!(retained.to_cbor_bytes(), retained_bytes);
+
+        let mut unsigned = tx.without_witnesses();
+        assert!(unsigned.witness_set.vkeywitnesses.is_none());
+        assert!(unsigned.witness_set.native_scripts.is_some());
+        assert_eq!(unsigned.body.hash(), tx.body.hash());
+        let mut unsigned_witness_set_bytes = vec![0xa1, 0x01, 0x81];
+        unsigned_witness_set_bytes.extend_from_slice(&script_bytes);
+        assert_eq!(
+            unsigned.to_cbor_bytes(),
+            tx_bytes(&unsigned_witness_set_bytes)
+        );
+        let mut none_retained = tx.witness_set.clone();
+        none_retained.retain_vkeys(&BTreeSet::new());
+        assert_eq!(
+            none_retained.to_cbor_bytes(),
+            unsigned.witness_set.to_cbor_bytes()
+        );
+
+        let previous = unsigned.replace_witness_set(tx.witness_set.clone());
+        assert_eq!(previous.to_cbor_bytes(), unsigned_witness_set_bytes);
+        assert_eq!(unsigned.to_cbor_bytes(), tx_bytes(&witness_set_bytes));
+    }
+
+    #[test]
+    fn transaction_with_parts() {
+        // [{ 0: [], 1: [], 2: 10 (non-minimally encoded) }, { 0: [witness] }, true, null]
+        let body_bytes = hex::decode("a300800180021a0000000a").unwrap();
+        let body = TransactionBody::from_cbor_bytes(&body_bytes).unwrap();
+        let sk = PrivateKey::from_normal_bytes(&[1; 32]).unwrap();
+        let witness = Vkeywitness::new(sk.to_public(), sk.sign(body.hash().to_raw_bytes()));
+        let mut tx_bytes = vec![0x84];
+        tx_bytes.extend_from_slice(&body_bytes);
+        tx_bytes.extend_from_slice(&[0xa1, 0x00, 0x81]);
+        tx_bytes.extend_from_slice(&witness.to_cbor_bytes());
+        tx_bytes.extend_from_slice(&[0xf5, 0xf6]);
+        let tx = Transaction::from_cbor_bytes(&tx_bytes).unwrap();
+
+        let edited = tx.with_body(tx.body.clone().with_fee(20), false);
+        assert_eq!(edited.body.fee, 20);
+        assert_eq!(
+            edited.witness_set.to_cbor_bytes(),
+            tx.witness_set.to_cbor_bytes()
+        );
+        let stripped = tx.with_body(tx.body.clone().with_fee(20), true);
+        assert!(stripped.witness_set.vkeywitnesses.is_none());
+        assert_eq!(stripped.body.to_cbor_bytes(), edited.body.to_cbor_bytes());
+
+        // re-sign the edited body
+        let resigned_witness =
+            Vkeywitness::new(sk.to_public(), sk.sign(stripped.body.hash().to_raw_bytes()));
+        let mut witness_set = stripped.witness_set.clone();
+        witness_set.vkeywitnesses = Some(vec![resigned_witness].into());
+        let resigned = stripped.with_witness_set(witness_set);
+        assert_eq!(
+            resigned.witness_set.vkeywitnesses.as_ref().unwrap().len(),
+            1
+        );
+        assert_ne!(
+            resigned.witness_set.to_cbor_bytes(),
+            tx.witness_set.to_cbor_bytes()
+        );
+
+        // swapping the same parts back in reproduces the original bytes
+        let restored = resigned
+            .with_body(tx.body.clone(), false)
+            .with_witness_set(tx.witness_set.clone());
+        assert_eq!(restored.to_cbor_bytes(), tx_bytes);
+
+        let auxiliary_data = AuxiliaryData::new_shelley(crate::auxdata::Metadata::new());
+        let with_aux = tx.with_auxiliary_data(Some(auxiliary_data.clone()));
+        assert_eq!(
+            with_aux.auxiliary_data.as_ref().unwrap().to_cbor_bytes(),
+            auxiliary_data.to_cbor_bytes()
+        );
+        assert_eq!(with_aux.body.to_cbor_bytes(), body_bytes);
+        assert_eq!(with_aux.with_auxiliary_data(None).to_cbor_bytes(), tx_bytes);
+    }
+
+    #[test]
+    fn alonzo_format_tx_out_conversion() {
+        let address =
+            Address::from_bech32("addr1vx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzers66hrl8")
+                .unwrap();
+        let datum = PlutusData::new_integer(BigInteger::from(42));
+        let datum_hash = hash_plutus_data(&datum);
+        let to_alonzo = |datum_option, script_reference| {
+            AlonzoFormatTxOut::try_from(TransactionOutput::new(
+                address.clone(),
+                Value::from(1_000_000),
+                datum_option,
+                script_reference,
+            ))
+        };
+        let alonzo = to_alonzo(Some(DatumOption::new_hash(datum_hash)), None).unwrap();
+        assert_eq!(alonzo.datum_hash, Some(datum_hash));
+        assert_eq!(alonzo.amount, Value::from(1_000_000));
+        assert_eq!(to_alonzo(None, None).unwrap().datum_hash, None);
+        assert_eq!(
+            to_alonzo(Some(DatumOption::new_datum(datum.clone())), None),
+            Err(AlonzoFormatTxOutError::InlineDatum)
+        );
+        let script_ref = Script::new_plutus_v2(PlutusV2Script::new(vec![0x4d, 0x01]));
+        assert_eq!(
+            to_alonzo(None, Some(script_ref.clone())),
+            Err(AlonzoFormatTxOutError::ScriptReference)
+        );
+        // already in the Alonzo format (with its encoding details) is a no-op
+        let bytes = alonzo.to_cbor_bytes();
+        let output = TransactionOutput::from_cbor_bytes(&bytes).unwrap();
+        assert_eq!(
+            AlonzoFormatTxOut::try_from(output).unwrap().to_cbor_bytes(),
+            bytes
+        );
+    }
+
+    #[test]
+    fn submit_envelopes() {
+        // [{ 0: [], 1: [], 2: 10 (non-minimally encoded) }, {}, true, null]
+        let tx_bytes = hex::decode("84a300800180021a0000000aa0f5f6").unwrap();
+        let tx = Transaction::from_cbor_bytes(&tx_bytes).unwrap();
+        assert_eq!(tx.to_submit_api_bytes(), tx_bytes);
+        let request: JSONValue = serde_json::from_str(&tx.to_ogmios_submit_json()).unwrap();
+        assert_eq!(
+            request,
+            serde_json::json!({
+                "jsonrpc": "2.0",
+                "method": "submitTransaction",
+                "params": { "transaction": { "cbor": "84a300800180021a0000000aa0f5f6" } },
+            })
+        );
+    }
+
+    #[test]
+    fn transaction_with_limits() {
+        // [{ 0: [], 1: [], 2: 10 (non-minimally encoded) }, {}, true, null]
+        let tx_bytes = hex::decode("84a300800180021a0000000aa0f5f6").unwrap();
+        let tx = Transaction::from_cbor_bytes_with_limits(&tx_bytes, &DeserializeLimits::default())
+            .unwrap();
+        assert_eq!(tx.to_cbor_bytes(), tx_bytes);
+        for (limits, expected) in [
+            (DeserializeLimits::new(1, 4, 1024), LimitKind::Depth),
+            (DeserializeLimits::new(8, 2, 1024), LimitKind::CollectionLen),
+            (DeserializeLimits::new(8, 4, 14), LimitKind::Size),
+        ] {
+            let err = Transaction::from_cbor_bytes_with_limits(&tx_bytes, &limits).unwrap_err();
+            match err.failure() {
+                DeserializeFailure::LimitExceeded { limit, .. } => assert_eq!(*limit, expected),
+                _ => panic!("unexpected failure: {}", err),
+            }
+        }
+    }
+
+    #[test]
+    fn ogmios_submit_errors() {
+        let era_mismatch = r#"{"jsonrpc":"2.0","method":"submitTransaction","error":{"code":3005,"message":"Failed to submit the transaction in the current era. This may happen when trying to submit a transaction near an era boundary (i.e. at the moment of a hard-fork).","data":{"queryEra":"babbage","ledgerEra":"conway"}},"id":null}"#;
+        assert_eq!(
+            TxSubmitError::from_ogmios_response(era_mismatch).unwrap(),
+            TxSubmitError::EraMismatch {
+                query_era: "babbage".to_owned(),
+                ledger_era: "conway".to_owned(),
+            }
+        );
+
+        let missing_signatories = r#"{"jsonrpc":"2.0","method":"submitTransaction","error":{"code":3101,"message":"Some signatures are missing. A transaction must be signed by all required signatories.","data":{"missingSignatories":["3542acb3a64d80c29302260d62c3b87a742ad14abf855ebc6733081e"]}},"id":null}"#;
+        assert_eq!(
+            TxSubmitError::from_ogmios_response(missing_signatories).unwrap(),
+            TxSubmitError::MissingVkeyWitnesses(vec![Ed25519KeyHash::from_hex(
+                "3542acb3a64d80c29302260d62c3b87a742ad14abf855ebc6733081e"
+            )
+            .unwrap()])
+        );
+
+        let value_not_conserved = r#"{"jsonrpc":"2.0","method":"submitTransaction","error":{"code":3123,"message":"The transaction is unbalanced: the consumed value doesn't match the produced value.","data":{"consumedValue":{"ada":{"lovelace":10000000}},"producedValue":{"ada":{"lovelace":9800000},"b0d07d45fe9514f80213f4020e5a61241458be626841cde717cb38a7":{"":1,"4d494c4b":2}}}},"id":null}"#;
+        let policy_id =
+            PolicyId::from_hex("b0d07d45fe9514f80213f4020e5a61241458be626841cde717cb38a7").unwrap();
+        let mut produced_assets = MultiAsset::new();
+        produced_assets.set(policy_id, AssetName::new(vec![]).unwrap(), 1);
+        produced_assets.set(policy_id, AssetName::new(b"MILK".to_vec()).unwrap(), 2);
+        assert_eq!(
+            TxSubmitError::from_ogmios_response(value_not_conserved).unwrap(),
+            TxSubmitError::ValueNotConserved {
+                consumed: Value::from(10000000),
+                produced: Value::new(9800000, produced_assets),
+            }
+        );
+
+        let fee_too_small = r#"{"jsonrpc":"2.0","method":"submitTransaction","error":{"code":3122,"message":"The transaction fee is too small.","data":{"minimumRequiredFee":{"ada":{"lovelace":170000}},"providedFee":{"ada":{"lovelace":150000}}}},"id":null}"#;
+        match TxSubmitError::from_ogmios_response(fee_too_small).unwrap() {
+            TxSubmitError::Other {
+                code,
+                message,
+                data,
+            } => {
+                assert_eq!(code, 3122);
+                assert_eq!(message, "The transaction fee is too small.");
+                assert!(data.is_some());
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+
+        let success = r#"{"jsonrpc":"2.0","method":"submitTransaction","result":{"transaction":{"id":"ee155ace9c40292074cb6aff8c9ccdd273c81648ff1149ef36bcea6ebb8a3e25"}},"id":null}"#;
+        assert!(matches!(
+            TxSubmitError::from_ogmios_response(success),
+            Err(OgmiosResponseError::NoError)
+        ));
+    }
 }
@@ -1,21 +1,25 @@
+pub mod vectors;
+
 use cbor_event::{de::Deserializer, se::Serializer, Sz};
 use cml_core::{
     error::{DeserializeError, DeserializeFailure},
-    serialization::{fit_sz, sz_max, Deserialize, LenEncoding, Serialize},
-    Int, Slot,
+    serialization::{fit_sz, sz_max, Deserialize, LenEncoding, Serialize, StripEncodings},
+    ArithmeticError, Int, Slot,
 };
 use cml_crypto::{Ed25519KeyHash, RawBytesEncoding, ScriptHash};
 use derivative::Derivative;
 use std::iter::IntoIterator;
 use std::{
+    collections::BTreeSet,
     convert::TryFrom,
     io::{BufRead, Seek, Write},
 };
 
 use crate::{
+    address::{Address, AddressError, RewardAccount},
     crypto::hash::{hash_script, ScriptHashNamespace},
     plutus::{Language, PlutusScript, PlutusV1Script, PlutusV2Script, PlutusV3Script},
-    NativeScript, Script, SubCoin,
+    Coin, NativeScript, Script, SubCoin, UnitInterval, Withdrawals,
 };
 
 impl Script {
@@ -104,8 +108,294 @@ impl NativeScript {
 
         verify_helper(self, lower_bound, upper_bound, key_hashes)
     }
+
+    /// Which signers can matter for this script for a tx with the given validity interval
+    /// (same bounds as verify()) without listing the ways to satisfy it, so this stays linear
+    /// in the script size e.g. for fee estimation of wide N-of-K multisigs.
+    /// Returns None if no set of signatures can satisfy the script within the interval.
+    pub fn signer_requirement_within(
+        &self,
+        lower_bound: Option<Slot>,
+        upper_bound: Option<Slot>,
+    ) -> Option<NativeScriptSignerRequirement> {
+        fn helper(
+            script: &NativeScript,
+            lower_bound: Option<Slot>,
+            upper_bound: Option<Slot>,
+        ) -> Option<NativeScriptSignerRequirement> {
+            // the satisfiable sub-scripts' requirements, sorted by how many signers they need
+            let satisfiable = |sub_scripts: &Vec<NativeScript>| {
+                let mut reqs = sub_scripts
+                    .iter()
+                    .filter_map(|sub_script| helper(sub_script, lower_bound, upper_bound))
+                    .collect::<Vec<_>>();
+                reqs.sort_by_key(|req| req.min_signers);
+                reqs
+            };
+            // {min_signers} from the first {n} requirements, {possible_signers} from all of them
+            let combine = |reqs: Vec<NativeScriptSignerRequirement>, n: usize| {
+                let min_signers = reqs.iter().take(n).map(|req| req.min_signers).sum();
+                let possible_signers = reqs
+                    .into_iter()
+                    .flat_map(|req| req.possible_signers)
+                    .collect::<BTreeSet<_>>();
+                NativeScriptSignerRequirement {
+                    min_signers: std::cmp::min(min_signers, possible_signers.len()),
+                    possible_signers,
+                }
+            };
+            let time_lock = |satisfied: bool| {
+                if satisfied {
+                    Some(NativeScriptSignerRequirement::default())
+                } else {
+                    None
+                }
+            };
+            match script {
+                NativeScript::ScriptPubkey(pub_key) => Some(NativeScriptSignerRequirement {
+                    possible_signers: std::iter::once(pub_key.ed25519_key_hash).collect(),
+                    min_signers: 1,
+                }),
+                NativeScript::ScriptAll(script_all) => {
+                    let reqs = satisfiable(&script_all.native_scripts);
+                    if reqs.len() < script_all.native_scripts.len() {
+                        return None;
+                    }
+                    let n = reqs.len();
+                    Some(combine(reqs, n))
+                }
+                NativeScript::ScriptAny(script_any) => {
+                    let reqs = satisfiable(&script_any.native_scripts);
+                    if reqs.is_empty() {
+                        return None;
+                    }
+                    Some(combine(reqs, 1))
+                }
+                NativeScript::ScriptNOfK(script_atleast) => {
+                    let reqs = satisfiable(&script_atleast.native_scripts);
+                    let n = script_atleast.n as usize;
+                    if reqs.len() < n {
+                        return None;
+                    }
+                    Some(combine(reqs, n))
+                }
+                NativeScript::ScriptInvalidBefore(timelock_start) => time_lock(
+                    matches!(lower_bound, Some(tx_slot) if tx_slot >= timelock_start.before),
+                ),
+                NativeScript::ScriptInvalidHereafter(timelock_expiry) => time_lock(
+                    matches!(upper_bound, Some(tx_slot) if tx_slot < timelock_expiry.after),
+                ),
+            }
+        }
+
+        helper(self, lower_bound, upper_bound)
+    }
+
+    /// Which signers need to be added to {signers} to satisfy this script for a tx with the
+    /// given validity interval (same bounds as verify()). Each branch picks its cheapest
+    /// sub-scripts on its own so the result is always sufficient but, when keys are shared
+    /// between branches, not necessarily the smallest possible. It is empty iff verify() holds.
+    /// Returns None if no set of signatures can satisfy the script within the interval.
+    pub fn missing_signers_within(
+        &self,
+        lower_bound: Option<Slot>,
+        upper_bound: Option<Slot>,
+        signers: &BTreeSet<Ed25519KeyHash>,
+    ) -> Option<BTreeSet<Ed25519KeyHash>> {
+        fn helper(
+            script: &NativeScript,
+            lower_bound: Option<Slot>,
+            upper_bound: Option<Slot>,
+            signers: &BTreeSet<Ed25519KeyHash>,
+        ) -> Option<BTreeSet<Ed25519KeyHash>> {
+            // union of the {n} satisfiable sub-scripts missing the fewest signers
+            let cheapest = |sub_scripts: &Vec<NativeScript>, n: usize| {
+                let mut missing = sub_scripts
+                    .iter()
+                    .filter_map(|sub_script| helper(sub_script, lower_bound, upper_bound, signers))
+                    .collect::<Vec<_>>();
+                if missing.len() < n {
+                    return None;
+                }
+                missing.sort_by_key(BTreeSet::len);
+                Some(missing.into_iter().take(n).flatten().collect())
+            };
+            let time_lock = |satisfied: bool| {
+                if satisfied {
+                    Some(BTreeSet::new())
+                } else {
+                    None
+                }
+            };
+            match script {
+                NativeScript::ScriptPubkey(pub_key) => Some(
+                    std::iter::once(pub_key.ed25519_key_hash)
+                        .filter(|signer| !signers.contains(signer))
+                        .collect(),
+                ),
+                NativeScript::ScriptAll(script_all) => {
+                    cheapest(&script_all.native_scripts, script_all.native_scripts.len())
+                }
+                NativeScript::ScriptAny(script_any) => cheapest(&script_any.native_scripts, 1),
+                NativeScript::ScriptNOfK(script_atleast) => {
+                    cheapest(&script_atleast.native_scripts, script_atleast.n as usize)
+                }
+                NativeScript::ScriptInvalidBefore(timelock_start) => time_lock(
+                    matches!(lower_bound, Some(tx_slot) if tx_slot >= timelock_start.before),
+                ),
+                NativeScript::ScriptInvalidHereafter(timelock_expiry) => time_lock(
+                    matches!(upper_bound, Some(tx_slot) if tx_slot < timelock_expiry.after),
+                ),
+            }
+        }
+
+        helper(self, lower_bound, upper_bound, signers)
+    }
+
+    /// Which signatures can satisfy this script for a tx with the given validity interval
+    /// (same bounds as verify()) e.g. atLeast 2 of [sig A, sig B, before X] inside the window
+    /// only needs either A or B.
+    /// Returns every minimal set of signers (no set contains another) smallest first,
+    /// or None if no set of signatures can satisfy the script within the interval.
+    /// The number of sets grows combinatorially (an 8-of-16 has 12870) so this fails with
+    /// TooManySignerSets once more than {max_sets} candidate sets would have to be considered.
+    /// Use signer_requirement_within() or missing_signers_within() when the sets aren't needed.
+    pub fn min_signers_within(
+        &self,
+        lower_bound: Option<Slot>,
+        upper_bound: Option<Slot>,
+        max_sets: usize,
+    ) -> Result<Option<Vec<BTreeSet<Ed25519KeyHash>>>, TooManySignerSets> {
+        type SignerSets = Vec<BTreeSet<Ed25519KeyHash>>;
+
+        // removes duplicates and any set that is a superset of another one
+        fn minimize(mut sets: SignerSets) -> SignerSets {
+            sets.sort_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+            sets.dedup();
+            let mut minimal: SignerSets = Vec::new();
+            for set in sets {
+                // after dedup only a strictly smaller set can be a subset
+                if !minimal
+                    .iter()
+                    .take_while(|smaller| smaller.len() < set.len())
+                    .any(|smaller| smaller.is_subset(&set))
+                {
+                    minimal.push(set);
+                }
+            }
+            minimal
+        }
+
+        // every way to satisfy both a and b
+        fn product(
+            a: &SignerSets,
+            b: &SignerSets,
+            max_sets: usize,
+        ) -> Result<SignerSets, TooManySignerSets> {
+            if a.len().saturating_mul(b.len()) > max_sets {
+                return Err(TooManySignerSets(max_sets));
+            }
+            Ok(minimize(
+                a.iter()
+                    .flat_map(|x| b.iter().map(move |y| x.union(y).cloned().collect()))
+                    .collect(),
+            ))
+        }
+
+        // an empty result means the script can't be satisfied
+        fn helper(
+            script: &NativeScript,
+            lower_bound: Option<Slot>,
+            upper_bound: Option<Slot>,
+            max_sets: usize,
+        ) -> Result<SignerSets, TooManySignerSets> {
+            let time_lock = |satisfied: bool| {
+                if satisfied {
+                    vec![BTreeSet::new()]
+                } else {
+                    vec![]
+                }
+            };
+            let sets = match script {
+                NativeScript::ScriptPubkey(pub_key) => {
+                    vec![std::iter::once(pub_key.ed25519_key_hash).collect()]
+                }
+                NativeScript::ScriptAll(script_all) => {
+                    let mut acc = vec![BTreeSet::new()];
+                    for sub_script in script_all.native_scripts.iter() {
+                        let sub_sets = helper(sub_script, lower_bound, upper_bound, max_sets)?;
+                        acc = product(&acc, &sub_sets, max_sets)?;
+                    }
+                    acc
+                }
+                NativeScript::ScriptAny(script_any) => {
+                    let mut sets = Vec::new();
+                    for sub_script in script_any.native_scripts.iter() {
+                        sets.append(&mut helper(sub_script, lower_bound, upper_bound, max_sets)?);
+                        if sets.len() > max_sets {
+                            return Err(TooManySignerSets(max_sets));
+                        }
+                    }
+                    minimize(sets)
+                }
+                NativeScript::ScriptNOfK(script_atleast) => {
+                    let n = script_atleast.n as usize;
+                    if n > script_atleast.native_scripts.len() {
+                        return Ok(vec![]);
+                    }
+                    // by_count[j] = ways to satisfy exactly j of the sub-scripts seen so far
+                    let mut by_count: Vec<SignerSets> = vec![vec![]; n + 1];
+                    by_count[0] = vec![BTreeSet::new()];
+                    for sub_script in script_atleast.native_scripts.iter() {
+                        let sub_sets = helper(sub_script, lower_bound, upper_bound, max_sets)?;
+                        if sub_sets.is_empty() {
+                            continue;
+                        }
+                        for j in (1..=n).rev() {
+                            let mut extended = product(&by_count[j - 1], &sub_sets, max_sets)?;
+                            extended.append(&mut by_count[j]);
+                            if extended.len() > max_sets {
+                                return Err(TooManySignerSets(max_sets));
+                            }
+                            by_count[j] = minimize(extended);
+                        }
+                    }
+                    by_count.pop().unwrap_or_default()
+                }
+                NativeScript::ScriptInvalidBefore(timelock_start) => time_lock(
+                    matches!(lower_bound, Some(tx_slot) if tx_slot >= timelock_start.before),
+                ),
+                NativeScript::ScriptInvalidHereafter(timelock_expiry) => time_lock(
+                    matches!(upper_bound, Some(tx_slot) if tx_slot < timelock_expiry.after),
+                ),
+            };
+            Ok(sets)
+        }
+
+        let sets = helper(self, lower_bound, upper_bound, max_sets)?;
+        if sets.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(sets))
+        }
+    }
+}
+
+/// Which signers can satisfy a native script within a tx validity interval.
+/// See NativeScript::signer_requirement_within()
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NativeScriptSignerRequirement {
+    /// Every key in a sub-script that can still be satisfied within the interval.
+    /// This is the most vkey witnesses that can be relevant e.g. for fee estimation.
+    pub possible_signers: BTreeSet<Ed25519KeyHash>,
+    /// Fewest signatures needed, counting a key once per branch it appears in
+    pub min_signers: usize,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("Native script has more than {0} sets of signers to consider")]
+pub struct TooManySignerSets(pub usize);
+
 impl From<NativeScript> for Script {
     fn from(script: NativeScript) -> Self {
         Self::new_native(script)
@@ -284,7 +574,7 @@ enum BigIntEncoding {
     Bytes(StringEncoding),
 }
 
-#[derive(Clone, Debug, Derivative)]
+#[derive(Clone, Debug, Derivative, StripEncodings)]
 #[derivative(Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct BigInteger {
     pub(crate) num: num_bigint::BigInt,
@@ -395,6 +685,22 @@ impl BigInteger {
         }
     }
 
+    /// Converts to an i64
+    /// Returns None if the number was too big (or small) for an i64
+    pub fn as_i64(&self) -> Option<i64> {
+        i64::try_from(&self.num).ok()
+    }
+
+    pub fn from_i128(x: i128) -> Self {
+        Self::from(x)
+    }
+
+    /// Converts to an i128
+    /// Returns None if the number was too big (or small) for an i128
+    pub fn as_i128(&self) -> Option<i128> {
+        i128::try_from(&self.num).ok()
+    }
+
     /// Converts to an Int
     /// Returns None when the number is too big for an Int (outside +/- 64-bit unsigned)
     /// Retains encoding info if the original was encoded as an Int
@@ -509,14 +815,20 @@ impl Serialize for BigInteger {
                     serializer.write_unsigned_integer(*u64_digits.first().unwrap())
                 }
                 // nint
-                num_bigint::Sign::Minus => serializer
-                    .write_negative_integer(-(*u64_digits.first().unwrap() as i128) as i64),
+                num_bigint::Sign::Minus => {
+                    let value = *u64_digits.first().unwrap();
+                    // CBOR nint stores -n - 1 so anything below i64::MIN still fits
+                    serializer.write_negative_integer_sz(-(value as i128), Sz::canonical(value - 1))
+                }
             },
             _ => {
                 // Small edge case: nint's minimum is -18446744073709551616 but in this bigint lib
                 // that takes 2 u64 bytes so we put that as a special case here:
                 if sign == num_bigint::Sign::Minus && u64_digits == vec![0, 1] {
-                    serializer.write_negative_integer(-18446744073709551616i128 as i64)
+                    serializer.write_negative_integer_sz(
+                        -18446744073709551616i128,
+                        Sz::canonical(u64::MAX),
+                    )
                 } else {
                     write_self_as_bytes(serializer, &StringEncoding::Canonical)
                 }
@@ -596,7 +908,9 @@ where
     }
 }
 
-#[derive(Clone, Copy, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Copy, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct NetworkId {
     pub network: u64,
     #[serde(skip)]
@@ -672,10 +986,46 @@ impl SubCoin {
     }
 }
 
+impl UnitInterval {
+    /// Approximate value as a float e.g. for display. Not exact so don't use it for ledger rules.
+    /// A zero denominator gives NaN or infinity.
+    pub fn to_f64(&self) -> f64 {
+        self.start as f64 / self.end as f64
+    }
+}
+
+/// Sum of all withdrawal amounts. Errors on overflow.
+pub fn withdrawals_total(withdrawals: &Withdrawals) -> Result<Coin, ArithmeticError> {
+    withdrawals
+        .values()
+        .try_fold(0u64, |acc, coin| acc.checked_add(*coin))
+        .ok_or(ArithmeticError::IntegerOverflow)
+}
+
+/// Inserts a withdrawal from a bech32 reward address (e.g. stake1...).
+/// Returns the previous amount for that reward account if there was one.
+pub fn withdrawals_insert_bech32(
+    withdrawals: &mut Withdrawals,
+    reward_address: &str,
+    coin: Coin,
+) -> Result<Option<Coin>, AddressError> {
+    let reward_account = RewardAccount::try_from(Address::from_bech32(reward_address)?)?;
+    Ok(withdrawals.insert(reward_account, coin))
+}
+
+/// Looks up the withdrawal amount of a bech32 reward address (e.g. stake1...).
+pub fn withdrawals_get_bech32(
+    withdrawals: &Withdrawals,
+    reward_address: &str,
+) -> Result<Option<Coin>, AddressError> {
+    let reward_account = RewardAccount::try_from(Address::from_bech32(reward_address)?)?;
+    Ok(withdrawals.get(&reward_account).copied())
+}
+
 // Represents the cddl: #6.258([+ T]) / [* T]
 // it DOES NOT and CAN NOT have any encoding detials per element!
 // so you can NOT use it on any primitives so must be serializable directly
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, StripEncodings)]
 pub struct NonemptySet<T> {
     elems: Vec<T>,
     len_encoding: LenEncoding,
@@ -843,7 +1193,7 @@ impl<T: Deserialize> Deserialize for NonemptySet<T> {
 pub type Set<T> = NonemptySet<T>;
 
 // Represents the cddl: #6.258([+ T]) / [* T] where T uses RawBytesEncoding
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, StripEncodings)]
 pub struct NonemptySetRawBytes<T> {
     elems: Vec<T>,
     len_encoding: LenEncoding,
@@ -1027,6 +1377,43 @@ mod tests {
     use super::*;
     use std::str::FromStr;
 
+    #[test]
+    fn bigint_i64_i128_boundaries() {
+        // (value, canonical CBOR, fits in i64, fits in u64)
+        let cases: [(i128, &str, bool, bool); 10] = [
+            (0, "00", true, true),
+            (-1, "20", true, false),
+            (i64::MAX as i128, "1b7fffffffffffffff", true, true),
+            (1 << 63, "1b8000000000000000", false, true),
+            (i64::MIN as i128, "3b7fffffffffffffff", true, false),
+            (-(1 << 63) - 1, "3b8000000000000000", false, false),
+            (u64::MAX as i128, "1bffffffffffffffff", false, true),
+            (1 << 64, "c249010000000000000000", false, false),
+            (-(1 << 64), "3bffffffffffffffff", false, false),
+            (-(1 << 64) - 1, "c349010000000000000000", false, false),
+        ];
+        for (value, cbor_hex, fits_i64, fits_u64) in cases {
+            let x = BigInteger::from_i128(value);
+            assert_eq!(hex::encode(x.to_cbor_bytes()), cbor_hex, "{}", value);
+            let decoded = BigInteger::from_cbor_bytes(&hex::decode(cbor_hex).unwrap()).unwrap();
+            assert_eq!(decoded, x);
+            assert_eq!(decoded.as_i128(), Some(value));
+            assert_eq!(
+                decoded.as_i64(),
+                i64::try_from(value).ok().filter(|_| fits_i64)
+            );
+            assert_eq!(decoded.as_i64().is_some(), fits_i64);
+            assert_eq!(decoded.as_u64().is_some(), fits_u64);
+            assert_eq!(decoded.to_string(), value.to_string());
+        }
+        assert_eq!(BigInteger::from_i128(i128::MIN).as_i128(), Some(i128::MIN));
+        assert_eq!(BigInteger::from_i128(i128::MAX).as_i128(), Some(i128::MAX));
+        let above_i128 = BigInteger::from_str("170141183460469231731687303715884105728").unwrap();
+        assert_eq!(above_i128.as_i128(), None);
+        let below_i128 = BigInteger::from_str("-170141183460469231731687303715884105729").unwrap();
+        assert_eq!(below_i128.as_i128(), None);
+    }
+
     #[test]
     fn bigint_uint_u64_min() {
         let bytes = [0x00];
@@ -1141,4 +1528,174 @@ mod tests {
         assert_eq!(x.as_int(), None);
         assert_eq!(x.to_string(), "-18446744073709551617");
     }
+
+    #[test]
+    fn withdrawals_bech32_total() {
+        let mut withdrawals = Withdrawals::new();
+        let addrs = [
+            "stake1uyehkck0lajq8gr28t9uxnuvgcqrc6070x3k9r8048z8y5gh6ffgw",
+            "stake1u9ylzsgxaa6xctf4juup682ar3juj85n8tx3hthnljg47zctvm3rc",
+            "stake178phkx6acpnf78fuvxn0mkew3l0fd058hzquvz7w36x4gtcccycj5",
+        ];
+        for (i, addr) in addrs.iter().enumerate() {
+            assert_eq!(
+                withdrawals_insert_bech32(&mut withdrawals, addr, 1_000_000 * (i as u64 + 1))
+                    .unwrap(),
+                None
+            );
+        }
+        assert_eq!(withdrawals_total(&withdrawals).unwrap(), 6_000_000);
+        assert_eq!(
+            withdrawals_get_bech32(&withdrawals, addrs[1]).unwrap(),
+            Some(2_000_000)
+        );
+        // not a reward address
+        assert!(withdrawals_get_bech32(
+            &withdrawals,
+            "addr1vx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzers66hrl8"
+        )
+        .is_err());
+        withdrawals_insert_bech32(&mut withdrawals, addrs[0], u64::MAX).unwrap();
+        assert!(withdrawals_total(&withdrawals).is_err());
+    }
+
+    #[test]
+    fn native_script_min_signers_within() {
+        let key = |x: u8| Ed25519KeyHash::from([x; 28]);
+        let sig = |x: u8| NativeScript::new_script_pubkey(key(x));
+        let set = |xs: &[u8]| xs.iter().map(|x| key(*x)).collect::<BTreeSet<_>>();
+        // atLeast 2 of [sig 1, sig 2, before 100]
+        let inner = NativeScript::new_script_n_of_k(
+            2,
+            vec![
+                sig(1),
+                sig(2),
+                NativeScript::new_script_invalid_hereafter(100),
+            ],
+        );
+        assert_eq!(
+            inner.min_signers_within(None, Some(50), 100).unwrap(),
+            Some(vec![set(&[1]), set(&[2])])
+        );
+        // outside the window (or no ttl) the time branch can't help
+        assert_eq!(
+            inner.min_signers_within(None, Some(100), 100).unwrap(),
+            Some(vec![set(&[1, 2])])
+        );
+        assert_eq!(
+            inner.min_signers_within(None, None, 100).unwrap(),
+            Some(vec![set(&[1, 2])])
+        );
+
+        // atLeast 2 of [inner, all [sig 3, after 200], sig 1]
+        let outer = NativeScript::new_script_n_of_k(
+            2,
+            vec![
+                inner.clone(),
+                NativeScript::new_script_all(vec![
+                    sig(3),
+                    NativeScript::new_script_invalid_before(200),
+                ]),
+                sig(1),
+            ],
+        );
+        assert_eq!(
+            outer.min_signers_within(Some(250), Some(50), 100).unwrap(),
+            Some(vec![set(&[1]), set(&[2, 3])])
+        );
+        assert_eq!(
+            outer.min_signers_within(Some(150), Some(50), 100).unwrap(),
+            Some(vec![set(&[1])])
+        );
+        assert_eq!(
+            outer.min_signers_within(Some(150), Some(150), 100).unwrap(),
+            Some(vec![set(&[1, 2])])
+        );
+        for (lower, upper) in [(Some(250), Some(50)), (Some(150), Some(150))].iter() {
+            for signers in outer
+                .min_signers_within(*lower, *upper, 100)
+                .unwrap()
+                .unwrap()
+            {
+                let signers = signers.into_iter().collect::<Vec<_>>();
+                assert!(outer.verify(*lower, *upper, &signers));
+            }
+        }
+
+        // the linear helpers agree with the listed sets
+        let requirement = outer
+            .signer_requirement_within(Some(250), Some(50))
+            .unwrap();
+        assert_eq!(requirement.possible_signers, set(&[1, 2, 3]));
+        // sig 1 is counted for both the inner script and the sig 1 branch
+        assert_eq!(requirement.min_signers, 2);
+        let requirement = outer
+            .signer_requirement_within(Some(150), Some(150))
+            .unwrap();
+        assert_eq!(requirement.possible_signers, set(&[1, 2]));
+        assert_eq!(requirement.min_signers, 2);
+        assert_eq!(
+            outer.missing_signers_within(Some(150), Some(150), &set(&[2])),
+            Some(set(&[1]))
+        );
+        assert_eq!(
+            outer.missing_signers_within(Some(250), Some(50), &set(&[3])),
+            Some(set(&[1]))
+        );
+        assert_eq!(
+            outer.missing_signers_within(Some(250), Some(50), &set(&[1])),
+            Some(set(&[]))
+        );
+
+        let unsatisfiable = NativeScript::new_script_all(vec![
+            sig(1),
+            NativeScript::new_script_invalid_before(200),
+        ]);
+        assert_eq!(
+            unsatisfiable
+                .min_signers_within(Some(150), None, 100)
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            NativeScript::new_script_n_of_k(3, vec![sig(1), sig(2)])
+                .min_signers_within(None, None, 100)
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            unsatisfiable.signer_requirement_within(Some(150), None),
+            None
+        );
+        assert_eq!(
+            unsatisfiable.missing_signers_within(Some(150), None, &set(&[1])),
+            None
+        );
+    }
+
+    #[test]
+    fn native_script_wide_n_of_k_signers() {
+        let key = |x: u8| Ed25519KeyHash::from([x; 28]);
+        // 10-of-20 has 184756 minimal signer sets
+        let script = NativeScript::new_script_n_of_k(
+            10,
+            (0..20)
+                .map(|x| NativeScript::new_script_pubkey(key(x)))
+                .collect(),
+        );
+        let start = std::time::Instant::now();
+        let requirement = script.signer_requirement_within(None, None).unwrap();
+        assert_eq!(requirement.possible_signers.len(), 20);
+        assert_eq!(requirement.min_signers, 10);
+        let present = (0..7).map(key).collect::<BTreeSet<_>>();
+        assert_eq!(
+            script.missing_signers_within(None, None, &present),
+            Some((7..10).map(key).collect())
+        );
+        assert_eq!(
+            script.min_signers_within(None, None, 1000),
+            Err(TooManySignerSets(1000))
+        );
+        assert!(start.elapsed() < std::time::Duration::from_secs(1));
+    }
 }
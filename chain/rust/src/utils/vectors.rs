@@ -0,0 +1,401 @@
+//! Reference values that test suites commonly cross-check against other tools:
+//! datum hashes, script hashes/addresses, native script policy ids, CIP-14 asset
+//! fingerprints and CIP-1852 stake addresses.
+//!
+//! generate_report() computes a batch of these from a JSON description so that
+//! external test suites (or a small CLI wrapper) can snapshot the output.
+
+use cml_core::{serialization::Deserialize, DeserializeError};
+use cml_crypto::{Bip32PrivateKey, DatumHash, ScriptHash};
+
+use crate::{
+    account::{Account, AccountError},
+    address::{EnterpriseAddress, RewardAddress},
+    assets::{utils::asset_fingerprint, AssetName},
+    certs::StakeCredential,
+    crypto::hash::hash_plutus_data,
+    plutus::{
+        utils::normalize_plutus_script_bytes, PlutusData, PlutusV1Script, PlutusV2Script,
+        PlutusV3Script,
+    },
+    NativeScript, PolicyId, Script,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum VectorsError {
+    #[error("JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("{field}: invalid hex: {source}")]
+    Hex {
+        field: String,
+        source: hex::FromHexError,
+    },
+    #[error("{field}: {source}")]
+    Deserialize {
+        field: String,
+        source: DeserializeError,
+    },
+    #[error("{field}: {source}")]
+    Account { field: String, source: AccountError },
+}
+
+/// Language of a script given to generate_report()
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScriptLanguage {
+    Native,
+    PlutusV1,
+    PlutusV2,
+    PlutusV3,
+}
+
+/// blake2b256 of the datum's CBOR as it was encoded (i.e. respecting any preserved encoding details)
+pub fn datum_hash(datum: &PlutusData) -> DatumHash {
+    hash_plutus_data(datum)
+}
+
+/// Parses a script of the given language. Native scripts are CBOR while plutus scripts
+/// can be in any of the forms accepted by normalize_plutus_script_bytes().
+pub fn script_from_bytes(
+    language: ScriptLanguage,
+    bytes: &[u8],
+) -> Result<Script, DeserializeError> {
+    Ok(match language {
+        ScriptLanguage::Native => Script::new_native(NativeScript::from_cbor_bytes(bytes)?),
+        ScriptLanguage::PlutusV1 => {
+            Script::new_plutus_v1(PlutusV1Script::new(normalize_plutus_script_bytes(bytes)))
+        }
+        ScriptLanguage::PlutusV2 => {
+            Script::new_plutus_v2(PlutusV2Script::new(normalize_plutus_script_bytes(bytes)))
+        }
+        ScriptLanguage::PlutusV3 => {
+            Script::new_plutus_v3(PlutusV3Script::new(normalize_plutus_script_bytes(bytes)))
+        }
+    })
+}
+
+/// Enterprise (no staking part) address with the script as its payment credential
+pub fn script_address(script: &Script, network: u8) -> EnterpriseAddress {
+    EnterpriseAddress::new(network, StakeCredential::new_script(script.hash()))
+}
+
+/// Policy id of tokens minted under the native script (i.e. its script hash)
+pub fn policy_id(script: &NativeScript) -> PolicyId {
+    script.hash()
+}
+
+/// Reward address of the stake key m/1852'/1815'/account'/2/index of the wallet
+/// with the given BIP39 entropy (and optional BIP39 password, empty for none)
+pub fn stake_address(
+    entropy: &[u8],
+    password: &[u8],
+    account: u32,
+    index: u32,
+    network: u8,
+) -> Result<RewardAddress, AccountError> {
+    let root_key = Bip32PrivateKey::from_bip39_entropy(entropy, password);
+    Account::from_root_key(&root_key, account).reward_address(network, index)
+}
+
+#[derive(Clone, Debug, serde::Deserialize)]
+struct ReportInput {
+    network_id: u8,
+    #[serde(default)]
+    datums: Vec<String>,
+    #[serde(default)]
+    scripts: Vec<ScriptInput>,
+    #[serde(default)]
+    assets: Vec<AssetInput>,
+    #[serde(default)]
+    stake_keys: Vec<StakeKeyInput>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize)]
+struct ScriptInput {
+    language: ScriptLanguage,
+    bytes: String,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+struct AssetInput {
+    policy_id: String,
+    asset_name: String,
+}
+
+#[derive(Clone, Debug, serde::Deserialize)]
+struct StakeKeyInput {
+    entropy: String,
+    #[serde(default)]
+    password: String,
+    #[serde(default)]
+    account: u32,
+    #[serde(default)]
+    index: u32,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+struct ReportOutput {
+    network_id: u8,
+    datums: Vec<DatumOutput>,
+    scripts: Vec<ScriptOutput>,
+    assets: Vec<AssetOutput>,
+    stake_keys: Vec<StakeKeyOutput>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+struct DatumOutput {
+    cbor: String,
+    hash: String,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+struct ScriptOutput {
+    language: ScriptLanguage,
+    hash: String,
+    address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    policy_id: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+struct AssetOutput {
+    #[serde(flatten)]
+    asset: AssetInput,
+    fingerprint: String,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+struct StakeKeyOutput {
+    account: u32,
+    index: u32,
+    key_hash: String,
+    address: String,
+}
+
+fn decode_hex(field: String, hex_str: &str) -> Result<(String, Vec<u8>), VectorsError> {
+    match hex::decode(hex_str) {
+        Ok(bytes) => Ok((field, bytes)),
+        Err(source) => Err(VectorsError::Hex { field, source }),
+    }
+}
+
+fn to_bech32(address: crate::address::Address) -> String {
+    // only fails with an invalid prefix
+    address.to_bech32(None).expect("default address prefix")
+}
+
+/// Computes the reference values for every entry of a JSON description, e.g.
+/// {
+///   "network_id": 1,
+///   "datums": ["d87980"],
+///   "scripts": [{"language": "plutus_v1", "bytes": "4e4d01000033222220051200120011"}],
+///   "assets": [{"policy_id": "7eae28af...", "asset_name": "504154415445"}],
+///   "stake_keys": [{"entropy": "df9ed25e...", "password": "", "account": 0, "index": 0}]
+/// }
+/// All lists are optional. Script languages are "native", "plutus_v1", "plutus_v2" or "plutus_v3".
+/// The output is pretty-printed JSON with the same lists in the same order, where each
+/// entry holds the computed hashes/addresses (bech32)/fingerprints for the matching input.
+pub fn generate_report(json_input: &str) -> Result<String, VectorsError> {
+    let input: ReportInput = serde_json::from_str(json_input)?;
+    let network = input.network_id;
+    let datums = input
+        .datums
+        .iter()
+        .enumerate()
+        .map(|(i, cbor)| {
+            let (field, bytes) = decode_hex(format!("datums[{i}]"), cbor)?;
+            let datum = PlutusData::from_cbor_bytes(&bytes)
+                .map_err(|source| VectorsError::Deserialize { field, source })?;
+            Ok(DatumOutput {
+                cbor: cbor.clone(),
+                hash: datum_hash(&datum).to_hex(),
+            })
+        })
+        .collect::<Result<Vec<_>, VectorsError>>()?;
+    let scripts = input
+        .scripts
+        .iter()
+        .enumerate()
+        .map(|(i, script)| {
+            let (field, bytes) = decode_hex(format!("scripts[{i}].bytes"), &script.bytes)?;
+            let parsed = script_from_bytes(script.language, &bytes)
+                .map_err(|source| VectorsError::Deserialize { field, source })?;
+            let hash = parsed.hash();
+            Ok(ScriptOutput {
+                language: script.language,
+                hash: hash.to_hex(),
+                address: to_bech32(script_address(&parsed, network).to_address()),
+                policy_id: match &parsed {
+                    Script::Native { script, .. } => Some(policy_id(script).to_hex()),
+                    _ => None,
+                },
+            })
+        })
+        .collect::<Result<Vec<_>, VectorsError>>()?;
+    let assets = input
+        .assets
+        .iter()
+        .enumerate()
+        .map(|(i, asset)| {
+            let policy_id = ScriptHash::from_hex(&asset.policy_id).map_err(|source| {
+                VectorsError::Deserialize {
+                    field: format!("assets[{i}].policy_id"),
+                    source,
+                }
+            })?;
+            let (field, name_bytes) =
+                decode_hex(format!("assets[{i}].asset_name"), &asset.asset_name)?;
+            let asset_name = AssetName::new(name_bytes)
+                .map_err(|source| VectorsError::Deserialize { field, source })?;
+            Ok(AssetOutput {
+                asset: asset.clone(),
+                fingerprint: asset_fingerprint(&policy_id, &asset_name),
+            })
+        })
+        .collect::<Result<Vec<_>, VectorsError>>()?;
+    let stake_keys = input
+        .stake_keys
+        .iter()
+        .enumerate()
+        .map(|(i, key)| {
+            let (field, entropy) = decode_hex(format!("stake_keys[{i}].entropy"), &key.entropy)?;
+            let address = stake_address(
+                &entropy,
+                key.password.as_bytes(),
+                key.account,
+                key.index,
+                network,
+            )
+            .map_err(|source| VectorsError::Account { field, source })?;
+            Ok(StakeKeyOutput {
+                account: key.account,
+                index: key.index,
+                key_hash: match &address.payment {
+                    StakeCredential::PubKey { hash, .. } => hash.to_hex(),
+                    StakeCredential::Script { hash, .. } => hash.to_hex(),
+                },
+                address: to_bech32(address.to_address()),
+            })
+        })
+        .collect::<Result<Vec<_>, VectorsError>>()?;
+    let output = ReportOutput {
+        network_id: network,
+        datums,
+        scripts,
+        assets,
+        stake_keys,
+    };
+    serde_json::to_string_pretty(&output).map_err(Into::into)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::genesis::network_info::NetworkInfo;
+
+    const ALWAYS_SUCCEEDS_V1: &str = "4e4d01000033222220051200120011";
+    const UNIT_DATUM: &str = "d87980";
+    // test walk nut penalty hip pave soap entry language right filter choice
+    const ENTROPY_12: &str = "df9ed25ed146bf43336a5d7cf7395994";
+
+    #[test]
+    fn known_vectors() {
+        let datum = PlutusData::from_cbor_bytes(&hex::decode(UNIT_DATUM).unwrap()).unwrap();
+        assert_eq!(
+            datum_hash(&datum).to_hex(),
+            "923918e403bf43c34b4ef6b48eb2ee04babed17320d8d1b9ff9ad086e86f44ec"
+        );
+        let script = script_from_bytes(
+            ScriptLanguage::PlutusV1,
+            &hex::decode(ALWAYS_SUCCEEDS_V1).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            script.hash().to_hex(),
+            "67f33146617a5e61936081db3b2117cbf59bd2123748f58ac9678656"
+        );
+        assert_eq!(
+            script_address(&script, NetworkInfo::testnet().network_id())
+                .to_address()
+                .to_bech32(None)
+                .unwrap(),
+            "addr_test1wpnlxv2xv9a9ucvnvzqakwepzl9ltx7jzgm53av2e9ncv4sysemm8"
+        );
+        let address = stake_address(
+            &hex::decode(ENTROPY_12).unwrap(),
+            &[],
+            0,
+            0,
+            NetworkInfo::mainnet().network_id(),
+        )
+        .unwrap();
+        assert_eq!(
+            address.to_address().to_bech32(None).unwrap(),
+            "stake1uyevw2xnsc0pvn9t9r9c7qryfqfeerchgrlm3ea2nefr9hqxdekzz"
+        );
+    }
+
+    #[test]
+    fn report() {
+        let input = serde_json::json!({
+            "network_id": 1,
+            "datums": [UNIT_DATUM],
+            "scripts": [
+                {"language": "plutus_v1", "bytes": ALWAYS_SUCCEEDS_V1},
+                // ScriptAll []
+                {"language": "native", "bytes": "820180"},
+            ],
+            "assets": [{
+                "policy_id": "7eae28af2208be856f7a119668ae52a49b73725e326dc16579dcc373",
+                "asset_name": "504154415445",
+            }],
+            "stake_keys": [{"entropy": ENTROPY_12}],
+        });
+        let output: serde_json::Value =
+            serde_json::from_str(&generate_report(&input.to_string()).unwrap()).unwrap();
+        assert_eq!(
+            output["datums"][0]["hash"],
+            "923918e403bf43c34b4ef6b48eb2ee04babed17320d8d1b9ff9ad086e86f44ec"
+        );
+        assert_eq!(
+            output["scripts"][0]["hash"],
+            "67f33146617a5e61936081db3b2117cbf59bd2123748f58ac9678656"
+        );
+        assert!(output["scripts"][0].get("policy_id").is_none());
+        assert_eq!(
+            output["scripts"][1]["policy_id"],
+            output["scripts"][1]["hash"]
+        );
+        assert_eq!(
+            output["assets"][0]["fingerprint"],
+            "asset13n25uv0yaf5kus35fm2k86cqy60z58d9xmde92"
+        );
+        assert_eq!(
+            output["stake_keys"][0]["address"],
+            "stake1uyevw2xnsc0pvn9t9r9c7qryfqfeerchgrlm3ea2nefr9hqxdekzz"
+        );
+    }
+
+    #[test]
+    fn report_errors_name_the_field() {
+        let err = generate_report(r#"{"network_id": 0, "datums": ["d87980", "zz"]}"#).unwrap_err();
+        assert!(
+            matches!(&err, VectorsError::Hex { field, .. } if field == "datums[1]"),
+            "{}",
+            err
+        );
+        let err = generate_report(
+            r#"{"network_id": 0, "assets": [{"policy_id": "00", "asset_name": ""}]}"#,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(&err, VectorsError::Deserialize { field, .. } if field == "assets[0].policy_id"),
+            "{}",
+            err
+        );
+        assert!(matches!(
+            generate_report(r#"{"datums": []}"#),
+            Err(VectorsError::Json(_))
+        ));
+    }
+}
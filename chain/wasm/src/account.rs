@@ -0,0 +1,136 @@
+use wasm_bindgen::prelude::{wasm_bindgen, JsError};
+
+pub use cml_chain::account::KeyRole;
+
+use cml_core_wasm::impl_wasm_conversions;
+use cml_crypto_wasm::{Bip32PrivateKey, Bip32PublicKey, Ed25519KeyHash, TransactionHash};
+
+use crate::{
+    address::{Address, AddressList, BaseAddress, EnterpriseAddress, RewardAddress},
+    crypto::Vkeywitness,
+    transaction::TransactionBody,
+};
+
+/// A CIP-1852 wallet account i.e. the m/1852'/1815'/account' node.
+/// Created from a private key it can also sign, created from a public key it can only derive
+/// addresses and recognize them.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct Account(cml_chain::account::Account);
+
+impl_wasm_conversions!(cml_chain::account::Account, Account);
+
+#[wasm_bindgen]
+impl Account {
+    /// {key} must be the account node (m/1852'/1815'/account') not the root key
+    pub fn from_private_key(key: &Bip32PrivateKey) -> Result<Account, JsError> {
+        // Bip32PrivateKey isn't Clone so we copy it via its bytes
+        use cml_crypto::RawBytesEncoding;
+        cml_crypto::Bip32PrivateKey::from_raw_bytes(key.as_ref().to_raw_bytes())
+            .map(|key| cml_chain::account::Account::from_private_key(key).into())
+            .map_err(Into::into)
+    }
+
+    /// {key} must be the account node (m/1852'/1815'/account') e.g. an exported account xpub
+    pub fn from_public_key(key: &Bip32PublicKey) -> Self {
+        cml_chain::account::Account::from_public_key(key.as_ref().clone()).into()
+    }
+
+    /// Derives the account node m/1852'/1815'/{account_index}' from a root key
+    pub fn from_root_key(root_key: &Bip32PrivateKey, account_index: u32) -> Self {
+        cml_chain::account::Account::from_root_key(root_key.as_ref(), account_index).into()
+    }
+
+    /// How many indices per role are searched to find the key behind an address (default 20)
+    pub fn set_lookahead(&mut self, lookahead: u32) {
+        self.0.set_lookahead(lookahead);
+    }
+
+    pub fn lookahead(&self) -> u32 {
+        self.0.lookahead()
+    }
+
+    pub fn public_key(&self) -> Bip32PublicKey {
+        self.0.public_key().clone().into()
+    }
+
+    /// m/1852'/1815'/account'/{role}/{index}
+    pub fn derive_public(&self, role: KeyRole, index: u32) -> Result<Bip32PublicKey, JsError> {
+        self.0
+            .derive_public(role, index)
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
+    /// m/1852'/1815'/account'/{role}/{index}
+    /// Throws if the account was created from a public key.
+    pub fn derive_private(&self, role: KeyRole, index: u32) -> Result<Bip32PrivateKey, JsError> {
+        self.0
+            .derive_private(role, index)
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
+    pub fn key_hash(&self, role: KeyRole, index: u32) -> Result<Ed25519KeyHash, JsError> {
+        self.0
+            .key_hash(role, index)
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
+    pub fn base_address(
+        &self,
+        network: u8,
+        payment_role: KeyRole,
+        payment_index: u32,
+        stake_index: u32,
+    ) -> Result<BaseAddress, JsError> {
+        self.0
+            .base_address(network, payment_role, payment_index, stake_index)
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
+    pub fn enterprise_address(
+        &self,
+        network: u8,
+        payment_role: KeyRole,
+        payment_index: u32,
+    ) -> Result<EnterpriseAddress, JsError> {
+        self.0
+            .enterprise_address(network, payment_role, payment_index)
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
+    pub fn reward_address(&self, network: u8, stake_index: u32) -> Result<RewardAddress, JsError> {
+        self.0
+            .reward_address(network, stake_index)
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
+    /// Whether the address's payment key (the stake key for reward addresses) is one of this
+    /// account's keys within the lookahead
+    pub fn owns_address(&self, address: &Address) -> bool {
+        self.0.owns_address(address.as_ref())
+    }
+
+    /// The addresses in {body}'s outputs, collateral return and withdrawals belonging to this account
+    pub fn owned_addresses(&self, body: &TransactionBody) -> AddressList {
+        self.0.owned_addresses(body.as_ref()).into()
+    }
+
+    /// Signs {tx_hash} with the payment key of {address} (the stake key for reward addresses).
+    /// Throws if the key isn't within the lookahead or the account was created from a public key.
+    pub fn sign_for_address(
+        &self,
+        tx_hash: &TransactionHash,
+        address: &Address,
+    ) -> Result<Vkeywitness, JsError> {
+        self.0
+            .sign_for_address(tx_hash.as_ref(), address.as_ref())
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+}
@@ -3,7 +3,7 @@ use super::*;
 pub use cml_chain::address::{AddressHeaderKind, AddressKind};
 
 use cml_core::CertificateIndex;
-use cml_core_wasm::{impl_wasm_conversions, impl_wasm_json_api};
+use cml_core_wasm::{impl_wasm_conversions, impl_wasm_json_api, impl_wasm_list};
 
 use crate::certs::StakeCredential;
 
@@ -15,6 +15,8 @@ impl_wasm_conversions!(cml_chain::address::Address, Address);
 
 impl_wasm_json_api!(Address);
 
+impl_wasm_list!(cml_chain::address::Address, Address, AddressList);
+
 #[wasm_bindgen]
 impl Address {
     /// header has 4 bits addr type discrim then 4 bits network discrim.
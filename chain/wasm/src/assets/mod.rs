@@ -4,7 +4,13 @@ pub mod utils;
 
 pub use utils::{Mint, MultiAsset, Value};
 
-pub use cml_chain::assets::{Coin, NonZeroInt64, PositiveCoin};
+pub use cml_chain::assets::Coin;
+
+/// nonZeroInt64 in the CDDL. Functions taking one throw when it's 0.
+pub type NonZeroInt64 = i64;
+
+/// positive_coin in the CDDL. Functions taking one throw when it's 0.
+pub type PositiveCoin = Coin;
 
 use cml_core_wasm::{impl_wasm_cbor_json_api, impl_wasm_conversions};
 
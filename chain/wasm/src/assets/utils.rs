@@ -46,10 +46,127 @@ impl AssetName {
             .map(str::to_owned)
             .map_err(Into::into)
     }
+
+    /**
+     * Prefixes name with the CIP-67 label. name can be at most 28 bytes.
+     */
+    pub fn new_with_label(label: u16, name: &[u8]) -> Result<AssetName, JsError> {
+        cml_chain::assets::AssetName::new_with_label(label, name)
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
+    /**
+     * CIP-68 reference NFT (label 100)
+     */
+    pub fn new_cip68_reference(name: &[u8]) -> Result<AssetName, JsError> {
+        cml_chain::assets::AssetName::new_cip68_reference(name)
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
+    /**
+     * CIP-68 user NFT (label 222)
+     */
+    pub fn new_cip68_user_nft(name: &[u8]) -> Result<AssetName, JsError> {
+        cml_chain::assets::AssetName::new_cip68_user_nft(name)
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
+    /**
+     * CIP-68 user FT (label 333)
+     */
+    pub fn new_cip68_user_ft(name: &[u8]) -> Result<AssetName, JsError> {
+        cml_chain::assets::AssetName::new_cip68_user_ft(name)
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
+    /**
+     * CIP-68 user RFT (label 444)
+     */
+    pub fn new_cip68_user_rft(name: &[u8]) -> Result<AssetName, JsError> {
+        cml_chain::assets::AssetName::new_cip68_user_rft(name)
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+}
+
+/**
+ * A CIP-67 label decoded from the start of an asset name along with the rest of the name
+ */
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct AssetNameLabel {
+    label: u16,
+    content: Vec<u8>,
+}
+
+#[wasm_bindgen]
+impl AssetNameLabel {
+    /**
+     * Encodes label as the 4 byte CIP-67 prefix (with checksum)
+     */
+    pub fn encode(label: u16) -> Vec<u8> {
+        cml_chain::assets::AssetNameLabel::encode(label).to_vec()
+    }
+
+    /**
+     * Splits asset_name into its CIP-67 label and the remaining bytes.
+     * Returns undefined if it does not start with a well-formed label.
+     */
+    pub fn decode(asset_name: &AssetName) -> Option<AssetNameLabel> {
+        cml_chain::assets::AssetNameLabel::decode(asset_name.as_ref())
+            .map(|(label, content)| Self { label, content })
+    }
+
+    pub fn label(&self) -> u16 {
+        self.label
+    }
+
+    pub fn content(&self) -> Vec<u8> {
+        self.content.clone()
+    }
 }
 
 impl_raw_bytes_api!(cml_chain::assets::AssetName, AssetName);
 
+/**
+ * CIP-14 asset fingerprint (e.g. asset1rjklcrnsdzqp65wjgrg55sy9723kw09mlgvlc3)
+ */
+#[wasm_bindgen]
+pub fn asset_fingerprint(policy_id: &PolicyId, asset_name: &AssetName) -> String {
+    cml_chain::assets::utils::asset_fingerprint(policy_id.as_ref(), asset_name.as_ref())
+}
+
+/**
+ * Decodes a CIP-14 asset fingerprint into its 20-byte blake2b-160 hash.
+ * This can't recover the policy id / asset name but can be used for lookups by fingerprint.
+ */
+#[wasm_bindgen]
+pub fn from_fingerprint(fingerprint: &str) -> Result<Vec<u8>, JsError> {
+    cml_chain::assets::utils::from_fingerprint(fingerprint)
+        .map(|hash| hash.to_vec())
+        .map_err(Into::into)
+}
+
+/**
+ * Formats lovelace as ADA with up to 6 decimals and no trailing zeros e.g. 1500000 -> "1.5"
+ */
+#[wasm_bindgen]
+pub fn coin_to_ada_string(coin: Coin) -> String {
+    cml_chain::assets::utils::coin_to_ada_string(coin)
+}
+
+/**
+ * Parses an ADA amount with up to 6 decimals (e.g. "1.5") into lovelace
+ */
+#[wasm_bindgen]
+pub fn coin_from_ada_string(ada: &str) -> Result<Coin, JsError> {
+    cml_chain::assets::utils::coin_from_ada_string(ada).map_err(Into::into)
+}
+
 #[derive(Clone, Debug)]
 #[wasm_bindgen]
 pub struct MultiAsset(cml_chain::assets::MultiAsset);
@@ -61,7 +178,25 @@ impl MultiAsset {
     }
 
     pub fn policy_count(&self) -> usize {
-        self.0.len()
+        self.0.policy_count()
+    }
+
+    /// Number of assets across all policies
+    pub fn asset_count(&self) -> usize {
+        self.0.asset_count()
+    }
+
+    /// Get the value of policy_id:asset_name, or 0 if it doesn't exist.
+    pub fn get_asset(&self, policy_id: &PolicyId, asset: &AssetName) -> Coin {
+        self.0.get_asset(policy_id.as_ref(), asset.as_ref())
+    }
+
+    /// Set the value of policy_id:asset_name to value, removing the asset (and the policy
+    /// if it has no other assets) when value is 0.
+    /// Returns the previous value, or 0 if it didn't exist
+    pub fn set_asset(&mut self, policy_id: &PolicyId, asset: &AssetName, value: Coin) -> Coin {
+        self.0
+            .set_asset(policy_id.clone().into(), asset.clone().into(), value)
     }
 
     pub fn insert_assets(
@@ -198,6 +333,21 @@ impl Mint {
     pub fn as_negative_multiasset(&self) -> MultiAsset {
         self.0.as_negative_multiasset().into()
     }
+
+    /// Human-readable summary for logs, see format_compact()
+    pub fn to_display_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// e.g. "2 assets (d5e6bf05… HOSKY: +100, 1a2b3c4d… 000de140…: -1)" with at most max_assets assets listed and shortened policy ids / asset names
+    pub fn format_compact(&self, max_assets: usize) -> String {
+        self.0.format_compact(max_assets)
+    }
+
+    /// Same as format_compact() but listing all assets with their full policy ids / asset names
+    pub fn format_full(&self) -> String {
+        self.0.format_full()
+    }
 }
 
 impl_wasm_conversions!(cml_chain::assets::Mint, Mint);
@@ -222,6 +372,10 @@ impl Value {
         self.0.coin
     }
 
+    pub fn set_coin(&mut self, coin: Coin) {
+        self.0.coin = coin;
+    }
+
     pub fn multi_asset(&self) -> MultiAsset {
         self.0.multiasset.clone().into()
     }
@@ -259,6 +413,34 @@ impl Value {
     pub fn clamped_sub(&self, rhs: &Value) -> Value {
         self.0.clamped_sub(rhs.as_ref()).into()
     }
+
+    /// Whether this value covers {other} i.e. it has at least as much ADA and as much of every
+    /// asset in {other} (missing assets count as 0).
+    pub fn geq(&self, other: &Value) -> bool {
+        self.0.geq(other.as_ref())
+    }
+
+    /// Compares the amounts of ADA and every asset (missing assets count as 0).
+    /// Returns -1 (less), 0 (equal), 1 (greater) or undefined if they are incomparable
+    /// i.e. some amounts are greater and some are less.
+    pub fn compare(&self, rhs: &Value) -> Option<i8> {
+        self.0.compare(rhs.as_ref()).map(|ordering| ordering as i8)
+    }
+
+    /// Human-readable summary for logs, see format_compact()
+    pub fn to_display_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// e.g. "1.5 ADA + 2 assets (d5e6bf05… HOSKY: 100, 1a2b3c4d… 000de140…: 1)" with at most max_assets assets listed and shortened policy ids / asset names
+    pub fn format_compact(&self, max_assets: usize) -> String {
+        self.0.format_compact(max_assets)
+    }
+
+    /// Same as format_compact() but listing all assets with their full policy ids / asset names
+    pub fn format_full(&self) -> String {
+        self.0.format_full()
+    }
 }
 
 impl_wasm_conversions!(cml_chain::assets::Value, Value);
@@ -186,11 +186,11 @@ impl AsMut<cml_chain::auxdata::Metadata> for Metadata {
 
 #[wasm_bindgen]
 pub enum TransactionMetadatumKind {
-    Map,
-    List,
-    Int,
-    Bytes,
-    Text,
+    Map = 0,
+    List = 1,
+    Int = 2,
+    Bytes = 3,
+    Text = 4,
 }
 
 #[wasm_bindgen]
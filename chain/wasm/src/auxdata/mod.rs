@@ -72,9 +72,9 @@ impl AuxiliaryData {
 
 #[wasm_bindgen]
 pub enum AuxiliaryDataKind {
-    Shelley,
-    ShelleyMA,
-    Conway,
+    Shelley = 0,
+    ShelleyMA = 1,
+    Conway = 2,
 }
 
 #[derive(Clone, Debug)]
@@ -1,7 +1,7 @@
 use crate::{NativeScriptList, PlutusV1ScriptList, PlutusV2ScriptList};
 use wasm_bindgen::prelude::wasm_bindgen;
 
-use super::{AuxiliaryData, Metadata};
+use super::{AuxiliaryData, AuxiliaryDataKind, Metadata};
 
 #[wasm_bindgen]
 impl AuxiliaryData {
@@ -53,3 +53,23 @@ impl AuxiliaryData {
         self.0.add(other.clone().into())
     }
 }
+
+impl From<AuxiliaryDataKind> for cml_chain::auxdata::utils::AuxiliaryDataFormat {
+    fn from(kind: AuxiliaryDataKind) -> Self {
+        match kind {
+            AuxiliaryDataKind::Shelley => Self::Shelley,
+            AuxiliaryDataKind::ShelleyMA => Self::ShelleyMA,
+            AuxiliaryDataKind::Conway => Self::Conway,
+        }
+    }
+}
+
+impl From<cml_chain::auxdata::utils::AuxiliaryDataFormat> for AuxiliaryDataKind {
+    fn from(format: cml_chain::auxdata::utils::AuxiliaryDataFormat) -> Self {
+        match format {
+            cml_chain::auxdata::utils::AuxiliaryDataFormat::Shelley => Self::Shelley,
+            cml_chain::auxdata::utils::AuxiliaryDataFormat::ShelleyMA => Self::ShelleyMA,
+            cml_chain::auxdata::utils::AuxiliaryDataFormat::Conway => Self::Conway,
+        }
+    }
+}
@@ -1,6 +1,8 @@
 // This file was code-generated using an experimental CDDL to rust tool:
 // https://github.com/dcSpark/cddl-codegen
 
+pub mod utils;
+
 use super::{
     MapTransactionIndexToAuxiliaryData, TransactionBodyList, TransactionIndex,
     TransactionWitnessSetList,
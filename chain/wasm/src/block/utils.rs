@@ -0,0 +1,69 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use cml_core_wasm::{impl_wasm_conversions, impl_wasm_json_api, impl_wasm_list};
+use cml_crypto_wasm::NonceHash;
+
+use super::{Block, HeaderBody};
+use crate::TransactionIndex;
+
+#[wasm_bindgen]
+impl Block {
+    /**
+     * auxiliary_data_set entries that are well-formed CBOR but failed to deserialize.
+     * These are re-serialized from their original bytes.
+     */
+    pub fn unparsed_auxiliary_data(&self) -> UnparsedAuxiliaryDataList {
+        self.0.unparsed_auxiliary_data.clone().into()
+    }
+}
+
+#[wasm_bindgen]
+impl HeaderBody {
+    /**
+     * This block's contribution to the evolving epoch nonce (Praos / Babbage onwards)
+     */
+    pub fn vrf_output_to_nonce(&self) -> NonceHash {
+        self.0.vrf_output_to_nonce().into()
+    }
+}
+
+/**
+ * An auxiliary_data_set entry of a block that failed to deserialize e.g. metadata with non-UTF8 text.
+ */
+#[derive(Clone, Debug)]
+#[wasm_bindgen]
+pub struct UnparsedAuxiliaryData(cml_chain::block::UnparsedAuxiliaryData);
+
+impl_wasm_json_api!(UnparsedAuxiliaryData);
+
+impl_wasm_conversions!(
+    cml_chain::block::UnparsedAuxiliaryData,
+    UnparsedAuxiliaryData
+);
+
+#[wasm_bindgen]
+impl UnparsedAuxiliaryData {
+    pub fn transaction_index(&self) -> TransactionIndex {
+        self.0.transaction_index
+    }
+
+    /**
+     * The CBOR bytes of the auxiliary data
+     */
+    pub fn bytes(&self) -> Vec<u8> {
+        self.0.bytes.clone()
+    }
+
+    /**
+     * Why deserializing it as AuxiliaryData failed
+     */
+    pub fn error(&self) -> String {
+        self.0.error.clone()
+    }
+}
+
+impl_wasm_list!(
+    cml_chain::block::UnparsedAuxiliaryData,
+    UnparsedAuxiliaryData,
+    UnparsedAuxiliaryDataList
+);
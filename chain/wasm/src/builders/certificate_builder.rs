@@ -36,6 +36,27 @@ impl SingleCertificateBuilder {
             .into()
     }
 
+    /// Like new() but also validates {cert} against the network it will be submitted to.
+    /// For pool registrations this checks the reward account network and that relays don't
+    /// have empty DNS names.
+    pub fn new_for_network(
+        cert: &Certificate,
+        network_id: &NetworkId,
+    ) -> Result<SingleCertificateBuilder, JsError> {
+        cml_chain::builders::certificate_builder::SingleCertificateBuilder::new_for_network(
+            cert.clone().into(),
+            network_id.clone().into(),
+        )
+        .map(Into::into)
+        .map_err(Into::into)
+    }
+
+    /// By default payment_key(), native_script() and plutus_script() strictly check relay DNS names,
+    /// pool metadata URLs and anchor URLs. This turns those checks off.
+    pub fn skip_url_validation(&self) -> SingleCertificateBuilder {
+        self.0.clone().skip_url_validation().into()
+    }
+
     /// note: particularly useful for StakeRegistration which doesn't require witnessing
     pub fn skip_witness(&self) -> CertificateBuilderResult {
         self.0.clone().skip_witness().into()
@@ -0,0 +1,78 @@
+use std::collections::BTreeMap;
+
+use cml_chain::builders::evaluator::EvalError;
+use cml_core_wasm::{impl_wasm_conversions, impl_wasm_list, impl_wasm_map_btree};
+use wasm_bindgen::prelude::{wasm_bindgen, JsValue};
+
+use super::tx_builder::TransactionUnspentOutput;
+use crate::{
+    plutus::{ExUnits, RedeemerKey, RedeemerTag},
+    transaction::Transaction,
+    RedeemerKeyList,
+};
+
+impl_wasm_map_btree!(
+    cml_chain::plutus::RedeemerKey,
+    cml_chain::plutus::ExUnits,
+    RedeemerKey,
+    ExUnits,
+    RedeemerKeyList,
+    MapRedeemerKeyToExUnits
+);
+
+impl_wasm_list!(
+    cml_chain::builders::tx_builder::TransactionUnspentOutput,
+    TransactionUnspentOutput,
+    TransactionUnspentOutputList
+);
+
+#[wasm_bindgen]
+extern "C" {
+    /// Any JS object with the method:
+    /// evaluate(tx: Transaction, resolved_inputs: TransactionUnspentOutputList): MapRedeemerKeyToExUnits
+    /// which must return the ExUnits for every redeemer in the draft {tx}.
+    /// Throwing from evaluate() will fail the build with that error.
+    pub type TxEvaluator;
+
+    #[wasm_bindgen(method, catch)]
+    fn evaluate(
+        this: &TxEvaluator,
+        tx: Transaction,
+        resolved_inputs: TransactionUnspentOutputList,
+    ) -> Result<MapRedeemerKeyToExUnits, JsValue>;
+}
+
+impl cml_chain::builders::evaluator::TxEvaluator for TxEvaluator {
+    fn evaluate(
+        &self,
+        tx: &cml_chain::transaction::Transaction,
+        resolved_inputs: &[cml_chain::builders::tx_builder::TransactionUnspentOutput],
+    ) -> Result<BTreeMap<cml_chain::plutus::RedeemerKey, cml_chain::plutus::ExUnits>, EvalError>
+    {
+        TxEvaluator::evaluate(self, tx.clone().into(), resolved_inputs.to_vec().into())
+            .map(Into::into)
+            .map_err(|e| EvalError::ScriptFailure(format!("{:?}", e)))
+    }
+}
+
+/// Evaluator that does no script execution and simply applies the ExUnits given to it.
+/// Useful when the budgets are already known (e.g. from a previous evaluation) or for tests.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct FixedExUnitsEvaluator(cml_chain::builders::evaluator::FixedExUnitsEvaluator);
+
+impl_wasm_conversions!(
+    cml_chain::builders::evaluator::FixedExUnitsEvaluator,
+    FixedExUnitsEvaluator
+);
+
+#[wasm_bindgen]
+impl FixedExUnitsEvaluator {
+    pub fn new(ex_units: &MapRedeemerKeyToExUnits) -> Self {
+        cml_chain::builders::evaluator::FixedExUnitsEvaluator::new(ex_units.clone().into()).into()
+    }
+
+    pub fn set(&mut self, tag: RedeemerTag, index: u64, ex_units: &ExUnits) {
+        self.0.set(tag, index, ex_units.clone().into())
+    }
+}
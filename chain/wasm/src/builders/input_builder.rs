@@ -7,7 +7,7 @@ use crate::{
     transaction::{TransactionInput, TransactionOutput},
     NativeScript, RequiredSigners,
 };
-use cml_core_wasm::impl_wasm_conversions;
+use cml_core_wasm::{impl_wasm_conversions, impl_wasm_list};
 use wasm_bindgen::prelude::{wasm_bindgen, JsError};
 
 #[wasm_bindgen]
@@ -19,6 +19,12 @@ impl_wasm_conversions!(
     InputBuilderResult
 );
 
+impl_wasm_list!(
+    cml_chain::builders::input_builder::InputBuilderResult,
+    InputBuilderResult,
+    InputBuilderResultList
+);
+
 #[wasm_bindgen]
 #[derive(Clone)]
 pub struct SingleInputBuilder(cml_chain::builders::input_builder::SingleInputBuilder);
@@ -96,4 +102,21 @@ impl SingleInputBuilder {
             .map(Into::into)
             .map_err(Into::into)
     }
+
+    /// For script inputs locked by a datum hash whose datum is the inline datum of a reference input.
+    /// The reference input must be added to the TransactionBuilder before this input.
+    pub fn plutus_script_reference_datum(
+        &self,
+        partial_witness: &PartialPlutusWitness,
+        required_signers: &RequiredSigners,
+    ) -> Result<InputBuilderResult, JsError> {
+        self.0
+            .clone()
+            .plutus_script_reference_datum(
+                partial_witness.clone().into(),
+                required_signers.clone().into(),
+            )
+            .map(Into::into)
+            .map_err(Into::into)
+    }
 }
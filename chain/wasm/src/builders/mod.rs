@@ -1,10 +1,13 @@
 pub mod certificate_builder;
+pub mod evaluator;
 pub mod input_builder;
 pub mod mint_builder;
 pub mod output_builder;
 pub mod proposal_builder;
 pub mod redeemer_builder;
 pub mod tx_builder;
+pub mod utils;
+pub mod utxo_ledger;
 pub mod vote_builder;
 pub mod withdrawal_builder;
 pub mod witness_builder;
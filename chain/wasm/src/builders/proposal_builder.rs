@@ -32,6 +32,11 @@ impl ProposalBuilder {
         Self(cml_chain::builders::proposal_builder::ProposalBuilder::new())
     }
 
+    /// By default proposal anchor URLs are strictly checked when added. This turns that check off.
+    pub fn skip_url_validation(&self) -> ProposalBuilder {
+        self.0.clone().skip_url_validation().into()
+    }
+
     pub fn with_proposal(&self, proposal: ProposalProcedure) -> Result<ProposalBuilder, JsError> {
         self.0
             .clone()
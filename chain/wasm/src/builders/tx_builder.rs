@@ -1,4 +1,6 @@
-use cml_chain::builders::tx_builder::{ChangeSelectionAlgo, CoinSelectionStrategyCIP2};
+use cml_chain::builders::tx_builder::{
+    ChangeSelectionAlgo, CoinSelectionStrategyCIP2, CollateralPolicy,
+};
 use cml_core_wasm::{impl_wasm_cbor_event_serialize_api, impl_wasm_conversions};
 use cml_crypto_wasm::Ed25519KeyHash;
 use wasm_bindgen::prelude::{wasm_bindgen, JsError};
@@ -8,17 +10,24 @@ use crate::{
     assets::Mint,
     auxdata::AuxiliaryData,
     builders::{
-        certificate_builder::CertificateBuilderResult, input_builder::InputBuilderResult,
-        mint_builder::MintBuilderResult, output_builder::SingleOutputBuilderResult,
-        proposal_builder::ProposalBuilderResult, redeemer_builder::RedeemerWitnessKey,
-        vote_builder::VoteBuilderResult, withdrawal_builder::WithdrawalBuilderResult,
+        certificate_builder::CertificateBuilderResult,
+        evaluator::{FixedExUnitsEvaluator, TransactionUnspentOutputList, TxEvaluator},
+        input_builder::{InputBuilderResult, InputBuilderResultList},
+        mint_builder::MintBuilderResult,
+        output_builder::SingleOutputBuilderResult,
+        proposal_builder::ProposalBuilderResult,
+        redeemer_builder::RedeemerWitnessKey,
+        vote_builder::VoteBuilderResult,
+        withdrawal_builder::WithdrawalBuilderResult,
         witness_builder::TransactionWitnessSetBuilder,
     },
     crypto::{BootstrapWitness, Vkeywitness},
     fees::LinearFee,
+    genesis::network_info::SlotConfig,
     plutus::{CostModels, ExUnitPrices, ExUnits, Redeemers},
     transaction::{Transaction, TransactionBody, TransactionInput, TransactionOutput},
-    Coin, NetworkId, Slot, Value, Withdrawals,
+    utils::ScriptList,
+    Coin, Ed25519KeyHashList, NetworkId, PlutusDataList, Slot, Value, Withdrawals,
 };
 
 #[wasm_bindgen]
@@ -51,6 +60,66 @@ impl TransactionUnspentOutput {
     }
 }
 
+/// How build_for_evaluation() fills in the ExUnits of redeemers which don't have any set yet.
+/// Without one of these the draft tx uses dummy (maximum-size) ExUnits.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct ProvisionalExUnitsStrategy(cml_chain::builders::tx_builder::ProvisionalExUnitsStrategy);
+
+impl_wasm_conversions!(
+    cml_chain::builders::tx_builder::ProvisionalExUnitsStrategy,
+    ProvisionalExUnitsStrategy
+);
+
+#[wasm_bindgen]
+impl ProvisionalExUnitsStrategy {
+    /// Split the config's max_tx_ex_units evenly across the redeemers missing ExUnits
+    pub fn new_even_split() -> Self {
+        cml_chain::builders::tx_builder::ProvisionalExUnitsStrategy::EvenSplit.into()
+    }
+
+    /// Give every redeemer missing ExUnits the config's full max_tx_ex_units
+    pub fn new_max() -> Self {
+        cml_chain::builders::tx_builder::ProvisionalExUnitsStrategy::Max.into()
+    }
+
+    /// Give every redeemer missing ExUnits these ExUnits
+    pub fn new_fixed(ex_units: &ExUnits) -> Self {
+        cml_chain::builders::tx_builder::ProvisionalExUnitsStrategy::Fixed(ex_units.clone().into())
+            .into()
+    }
+
+    pub fn kind(&self) -> ProvisionalExUnitsStrategyKind {
+        match &self.0 {
+            cml_chain::builders::tx_builder::ProvisionalExUnitsStrategy::EvenSplit => {
+                ProvisionalExUnitsStrategyKind::EvenSplit
+            }
+            cml_chain::builders::tx_builder::ProvisionalExUnitsStrategy::Max => {
+                ProvisionalExUnitsStrategyKind::Max
+            }
+            cml_chain::builders::tx_builder::ProvisionalExUnitsStrategy::Fixed(_) => {
+                ProvisionalExUnitsStrategyKind::Fixed
+            }
+        }
+    }
+
+    pub fn as_fixed(&self) -> Option<ExUnits> {
+        match &self.0 {
+            cml_chain::builders::tx_builder::ProvisionalExUnitsStrategy::Fixed(ex_units) => {
+                Some(ex_units.clone().into())
+            }
+            _ => None,
+        }
+    }
+}
+
+#[wasm_bindgen]
+pub enum ProvisionalExUnitsStrategyKind {
+    EvenSplit = 0,
+    Max = 1,
+    Fixed = 2,
+}
+
 #[wasm_bindgen]
 #[derive(Clone, Debug)]
 pub struct TransactionBuilderConfig(cml_chain::builders::tx_builder::TransactionBuilderConfig);
@@ -109,6 +178,10 @@ impl TransactionBuilderConfigBuilder {
         self.0.clone().prefer_pure_change(prefer_pure_change).into()
     }
 
+    pub fn collateral_policy(&self, collateral_policy: CollateralPolicy) -> Self {
+        self.0.clone().collateral_policy(collateral_policy).into()
+    }
+
     pub fn ex_unit_prices(&self, ex_unit_prices: &ExUnitPrices) -> Self {
         self.0
             .clone()
@@ -137,11 +210,54 @@ impl TransactionBuilderConfigBuilder {
             .into()
     }
 
+    /// Optional. Only needed for the EvenSplit/Max provisional ExUnits strategies.
+    pub fn max_tx_ex_units(&self, max_tx_ex_units: &ExUnits) -> Self {
+        self.0
+            .clone()
+            .max_tx_ex_units(max_tx_ex_units.clone().into())
+            .into()
+    }
+
+    /// Optional. Max length in slots of windows set by set_validity_window_relative() and
+    /// set_validity_end_in(). Unlimited by default.
+    pub fn max_validity_window(&self, max_validity_window: u64) -> Self {
+        self.0
+            .clone()
+            .max_validity_window(max_validity_window)
+            .into()
+    }
+
     pub fn build(&self) -> Result<TransactionBuilderConfig, JsError> {
         self.0.clone().build().map(Into::into).map_err(Into::into)
     }
 }
 
+/// Signed per-asset difference between what a transaction needs and what it already has.
+/// Positive amounts still need to be covered by inputs and negative ones are already in excess.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct ValueDelta(cml_chain::builders::tx_builder::ValueDelta);
+
+impl_wasm_conversions!(cml_chain::builders::tx_builder::ValueDelta, ValueDelta);
+
+#[wasm_bindgen]
+impl ValueDelta {
+    /// The positive part i.e. what still needs to be selected
+    pub fn to_select(&self) -> Value {
+        self.0.to_select().into()
+    }
+
+    /// The negated negative part i.e. what is in excess of what is needed
+    pub fn surplus(&self) -> Value {
+        self.0.surplus().into()
+    }
+
+    /// Whether nothing (more) needs to be selected
+    pub fn is_covered(&self) -> bool {
+        self.0.is_covered()
+    }
+}
+
 #[wasm_bindgen]
 #[derive(Clone, Debug)]
 pub struct TransactionBuilder(cml_chain::builders::tx_builder::TransactionBuilder);
@@ -164,6 +280,18 @@ impl TransactionBuilder {
         self.0.select_utxos(strategy).map_err(Into::into)
     }
 
+    /// Same as select_utxos() but selects inputs covering {target} (plus the fees of the added
+    /// inputs) instead of what the builder computes is still needed.
+    pub fn select_utxos_for_target(
+        &mut self,
+        strategy: CoinSelectionStrategyCIP2,
+        target: &Value,
+    ) -> Result<(), JsError> {
+        self.0
+            .select_utxos_for_target(strategy, target.as_ref())
+            .map_err(Into::into)
+    }
+
     pub fn add_input(&mut self, result: &InputBuilderResult) -> Result<(), JsError> {
         self.0.add_input(result.clone().into()).map_err(Into::into)
     }
@@ -178,8 +306,32 @@ impl TransactionBuilder {
     }
 
     /// Add a reference input. Must be called BEFORE adding anything (inputs, certs, etc) that refer to this reference input.
-    pub fn add_reference_input(&mut self, utxo: &TransactionUnspentOutput) {
-        self.0.add_reference_input(utxo.clone().into())
+    /// Errors if the input is already being spent by this transaction. Adding the same reference input twice is a no-op.
+    pub fn add_reference_input(&mut self, utxo: &TransactionUnspentOutput) -> Result<(), JsError> {
+        self.0
+            .add_reference_input(utxo.clone().into())
+            .map_err(Into::into)
+    }
+
+    /// All reference inputs added so far along with the outputs they resolve to
+    pub fn get_reference_inputs(&self) -> TransactionUnspentOutputList {
+        self.0.get_reference_inputs().into()
+    }
+
+    /// Scripts made available to the transaction by its reference inputs
+    pub fn reference_scripts(&self) -> ScriptList {
+        self.0.reference_scripts().into()
+    }
+
+    /// Inline datums made available to the transaction by its reference inputs
+    pub fn reference_datums(&self) -> PlutusDataList {
+        self.0.reference_datums().into()
+    }
+
+    /// Reference inputs whose inline datum is the datum of an input spent by this transaction.
+    /// These datums are left out of the witness set.
+    pub fn referenced_datums(&self) -> TransactionUnspentOutputList {
+        self.0.referenced_datums().into()
     }
 
     /// Add explicit output via a TransactionOutput object
@@ -209,6 +361,33 @@ impl TransactionBuilder {
         self.0.set_validity_start_interval(validity_start_interval)
     }
 
+    /// Sets validity_start_interval to {current_slot} and ttl to {current_slot} + {length_slots}.
+    /// As per the ledger the start is inclusive and the ttl exclusive so the tx is valid in
+    /// exactly {length_slots} slots. Throws if the window is empty or longer than the config's
+    /// max_validity_window.
+    pub fn set_validity_window_relative(
+        &mut self,
+        current_slot: Slot,
+        length_slots: u64,
+    ) -> Result<(), JsError> {
+        self.0
+            .set_validity_window_relative(current_slot, length_slots)
+            .map_err(Into::into)
+    }
+
+    /// Same as set_validity_window_relative() with the window being the whole slots that fit in
+    /// {seconds} as per {slot_config} e.g. SlotConfig.mainnet()
+    pub fn set_validity_end_in(
+        &mut self,
+        seconds: u64,
+        slot_config: &SlotConfig,
+        current_slot: Slot,
+    ) -> Result<(), JsError> {
+        self.0
+            .set_validity_end_in(seconds, slot_config.as_ref(), current_slot)
+            .map_err(Into::into)
+    }
+
     pub fn add_cert(&mut self, result: &CertificateBuilderResult) {
         self.0.add_cert(result.clone().into())
     }
@@ -260,10 +439,35 @@ impl TransactionBuilder {
             .map_err(Into::into)
     }
 
+    /// Adds collateral from {candidates} (largest ADA amount first) until it covers {required}
+    /// respecting the config's collateral policy.
+    pub fn select_collateral(
+        &mut self,
+        candidates: &InputBuilderResultList,
+        required: Coin,
+    ) -> Result<(), JsError> {
+        self.0
+            .select_collateral(candidates.as_ref(), required)
+            .map_err(Into::into)
+    }
+
     pub fn add_required_signer(&mut self, hash: &Ed25519KeyHash) {
         self.0.add_required_signer(hash.clone().into())
     }
 
+    /// Requires a signature from the payment key of this address.
+    /// Throws for script and Byron addresses.
+    pub fn add_required_signers_from_address(&mut self, address: &Address) -> Result<(), JsError> {
+        self.0
+            .add_required_signers_from_address(address.as_ref())
+            .map_err(Into::into)
+    }
+
+    /// The key hashes the tx body will list as required signers, each only once
+    pub fn required_signers(&self) -> Ed25519KeyHashList {
+        self.0.required_signers().into()
+    }
+
     pub fn set_network_id(&mut self, network_id: &NetworkId) {
         self.0.set_network_id(network_id.clone().into())
     }
@@ -272,6 +476,12 @@ impl TransactionBuilder {
         self.0.network_id().map(Into::into)
     }
 
+    /// Checks that the outputs, withdrawals and collateral return all use the network set
+    /// with set_network_id() (if any). This is also checked when building.
+    pub fn validate_network_id(&self) -> Result<(), JsError> {
+        self.0.validate_network_id().map_err(Into::into)
+    }
+
     /// does not include refunds or withdrawals
     pub fn get_explicit_input(&self) -> Result<Value, JsError> {
         self.0
@@ -288,6 +498,15 @@ impl TransactionBuilder {
             .map_err(Into::into)
     }
 
+    /// What the inputs selected from the added utxos still need to cover: outputs + fee (without
+    /// any further inputs) + deposits + burn - mint - withdrawals - refunds - already added inputs.
+    pub fn required_value_to_select(&self) -> Result<ValueDelta, JsError> {
+        self.0
+            .required_value_to_select()
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
     /// Return explicit input plus implicit input plus mint
     pub fn get_total_input(&self) -> Result<Value, JsError> {
         self.0.get_total_input().map(Into::into).map_err(Into::into)
@@ -325,6 +544,12 @@ impl TransactionBuilder {
         self.0.full_size().map_err(Into::into)
     }
 
+    /// Size the transaction would have if built now, kept up to date as key-witnessed inputs and
+    /// outputs are added instead of serializing the whole tx. Never smaller than the built tx.
+    pub fn current_size_estimate(&self) -> Result<usize, JsError> {
+        self.0.current_size_estimate().map_err(Into::into)
+    }
+
     pub fn output_sizes(&self) -> Vec<usize> {
         self.0.output_sizes()
     }
@@ -358,12 +583,48 @@ impl TransactionBuilder {
             .map_err(Into::into)
     }
 
+    // TODO: switch from ChangeSelectionAlgo to ChangeSelectionBuilder
+    /// Builds the transaction using {evaluator} to compute the ExUnits of all redeemers.
+    /// This builds a draft tx for evaluation, sets the resulting ExUnits then does the final
+    /// build (including fee/change calculation) to move to the step where real witnesses can be added.
+    /// NOTE: is_valid set to true
+    pub fn build_with_evaluator(
+        &mut self,
+        evaluator: &TxEvaluator,
+        algo: ChangeSelectionAlgo,
+        change_address: &Address,
+    ) -> Result<SignedTxBuilder, JsError> {
+        self.0
+            .build_with_evaluator(evaluator, algo, change_address.as_ref())
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
+    /// Same as build_with_evaluator() but using the provided ExUnits instead of evaluating scripts
+    pub fn build_with_fixed_ex_units(
+        &mut self,
+        evaluator: &FixedExUnitsEvaluator,
+        algo: ChangeSelectionAlgo,
+        change_address: &Address,
+    ) -> Result<SignedTxBuilder, JsError> {
+        self.0
+            .build_with_evaluator(evaluator.as_ref(), algo, change_address.as_ref())
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
     /// used to override the exunit values initially provided when adding inputs
     pub fn set_exunits(&mut self, redeemer: &RedeemerWitnessKey, ex_units: &ExUnits) {
         self.0
             .set_exunits((*redeemer).into(), ex_units.clone().into())
     }
 
+    /// Sets how build_for_evaluation() fills in redeemers that don't have ExUnits yet.
+    /// Useful for evaluators that reject the default dummy ExUnits.
+    pub fn set_provisional_ex_units(&mut self, strategy: &ProvisionalExUnitsStrategy) {
+        self.0.set_provisional_ex_units(strategy.clone().into())
+    }
+
     /// warning: sum of all parts of a transaction must equal 0. You cannot just set the fee to the min value and forget about it
     /// warning: min_fee may be slightly larger than the actual minimum fee (ex: a few lovelaces)
     /// this is done to simplify the library code, but can be fixed later
@@ -525,4 +786,9 @@ impl SignedTxBuilder {
     pub fn auxiliary_data(&self) -> Option<AuxiliaryData> {
         self.0.auxiliary_data().map(|aux| aux.into())
     }
+
+    /// Whether the body's auxiliary_data_hash matches the auxiliary data (or neither are present)
+    pub fn verify_auxiliary_data(&self) -> bool {
+        self.0.verify_auxiliary_data()
+    }
 }
@@ -0,0 +1,257 @@
+use cml_core::ordered_hash_map::OrderedHashMap;
+use cml_core_wasm::{impl_wasm_conversions, impl_wasm_json_api, impl_wasm_list};
+use cml_crypto_wasm::DatumHash;
+use wasm_bindgen::prelude::{wasm_bindgen, JsError};
+
+use super::{evaluator::TransactionUnspentOutputList, tx_builder::TransactionBuilderConfig};
+use crate::{
+    address::Address,
+    assets::AssetName,
+    certs::StakeCredential,
+    transaction::{Transaction, TransactionBody, TransactionOutput},
+    Coin, PolicyId, StakeCredentialList, Value,
+};
+
+impl_wasm_list!(cml_crypto::DatumHash, DatumHash, DatumHashList);
+
+/// Summary of a transaction's collateral as computed by check_collateral()
+#[derive(Clone, Debug)]
+#[wasm_bindgen]
+pub struct CollateralReport(cml_chain::builders::utils::CollateralReport);
+
+impl_wasm_conversions!(
+    cml_chain::builders::utils::CollateralReport,
+    CollateralReport
+);
+
+#[wasm_bindgen]
+impl CollateralReport {
+    /// Sum of all resolved collateral inputs
+    pub fn input_value(&self) -> Value {
+        self.0.input_value.clone().into()
+    }
+
+    /// ADA that would be taken if script validation fails (inputs minus collateral_return)
+    pub fn collateral(&self) -> Coin {
+        self.0.collateral
+    }
+
+    /// Minimum collateral needed for the tx's fee i.e. ceil(fee * collateral_percentage / 100)
+    pub fn required_collateral(&self) -> Coin {
+        self.0.required_collateral
+    }
+
+    /// ADA in excess of the required collateral
+    pub fn excess(&self) -> Coin {
+        self.0.excess()
+    }
+}
+
+/// Validates the collateral of {tx} against the ledger rules applied to transactions with scripts:
+/// at most {max_collateral_inputs} inputs, only key-locked Shelley addresses, all tokens returned
+/// via collateral_return, a consistent total_collateral and at least ceil(fee * {collateral_percentage} / 100) ADA.
+/// {resolved_collateral} must contain the UTXOs for all of the tx's collateral inputs.
+/// Throws with the exact shortfall if the collateral is insufficient.
+#[wasm_bindgen]
+pub fn check_collateral(
+    tx: &Transaction,
+    resolved_collateral: &TransactionUnspentOutputList,
+    collateral_percentage: u64,
+    max_collateral_inputs: u64,
+) -> Result<CollateralReport, JsError> {
+    cml_chain::builders::utils::check_collateral(
+        tx.as_ref(),
+        resolved_collateral.as_ref(),
+        collateral_percentage,
+        max_collateral_inputs,
+    )
+    .map(Into::into)
+    .map_err(Into::into)
+}
+
+/// UTxOs grouped by the stake credential of their address as returned by group_utxos_by_stake_credential()
+#[derive(Clone, Debug)]
+#[wasm_bindgen]
+pub struct StakeCredentialUtxoGroups(
+    OrderedHashMap<
+        Option<cml_chain::certs::StakeCredential>,
+        Vec<cml_chain::builders::tx_builder::TransactionUnspentOutput>,
+    >,
+);
+
+impl_wasm_conversions!(
+    OrderedHashMap<
+        Option<cml_chain::certs::StakeCredential>,
+        Vec<cml_chain::builders::tx_builder::TransactionUnspentOutput>,
+    >,
+    StakeCredentialUtxoGroups
+);
+
+#[wasm_bindgen]
+impl StakeCredentialUtxoGroups {
+    /// Number of groups, including the one for UTxOs without a stake credential
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Stake credentials of all groups in order of first appearance
+    pub fn credentials(&self) -> StakeCredentialList {
+        self.0.keys().flatten().cloned().collect::<Vec<_>>().into()
+    }
+
+    pub fn get(&self, credential: &StakeCredential) -> Option<TransactionUnspentOutputList> {
+        self.0
+            .get(&Some(credential.as_ref().clone()))
+            .map(|utxos| utxos.clone().into())
+    }
+
+    /// UTxOs without a stake credential (enterprise, pointer and Byron addresses)
+    pub fn unstaked(&self) -> Option<TransactionUnspentOutputList> {
+        self.0.get(&None).map(|utxos| utxos.clone().into())
+    }
+}
+
+/// Groups utxos by the stake credential of their address, in order of first appearance.
+/// UTxOs without a stake credential (enterprise, pointer and Byron addresses) are grouped separately.
+#[wasm_bindgen]
+pub fn group_utxos_by_stake_credential(
+    utxos: &TransactionUnspentOutputList,
+) -> StakeCredentialUtxoGroups {
+    cml_chain::builders::utils::group_utxos_by_stake_credential(utxos.as_ref()).into()
+}
+
+/// Total value (ADA and tokens) of utxos
+#[wasm_bindgen]
+pub fn summarize_utxos(utxos: &TransactionUnspentOutputList) -> Result<Value, JsError> {
+    cml_chain::builders::utils::summarize_utxos(utxos.as_ref())
+        .map(Into::into)
+        .map_err(Into::into)
+}
+
+/// Datum hashes of the plutus script-locked outputs spent by {tx} whose datums are neither
+/// provided in the witness set nor inline in the output.
+/// {resolved} must contain the UTXOs for all inputs and reference inputs.
+#[wasm_bindgen]
+pub fn missing_datums(tx: &Transaction, resolved: &TransactionUnspentOutputList) -> DatumHashList {
+    cml_chain::builders::utils::missing_datums(tx.as_ref(), resolved.as_ref()).into()
+}
+
+/// Datum hashes of the datums in {tx}'s witness set that don't belong to any spent output,
+/// reference input or output of {tx} and would thus be rejected by the ledger.
+/// {resolved} must contain the UTXOs for all inputs and reference inputs.
+#[wasm_bindgen]
+pub fn extraneous_datums(
+    tx: &Transaction,
+    resolved: &TransactionUnspentOutputList,
+) -> DatumHashList {
+    cml_chain::builders::utils::extraneous_datums(tx.as_ref(), resolved.as_ref()).into()
+}
+
+/// An output holding less ADA than the ledger's minimum for its size as reported by check_min_ada()
+#[derive(Clone, Debug)]
+#[wasm_bindgen]
+pub struct MinAdaViolation(cml_chain::builders::utils::MinAdaViolation);
+
+impl_wasm_conversions!(cml_chain::builders::utils::MinAdaViolation, MinAdaViolation);
+
+impl_wasm_json_api!(MinAdaViolation);
+
+#[wasm_bindgen]
+impl MinAdaViolation {
+    /// Index in the body's outputs or undefined for the collateral return
+    pub fn output_index(&self) -> Option<u64> {
+        self.0.output_index
+    }
+
+    pub fn coin(&self) -> Coin {
+        self.0.coin
+    }
+
+    pub fn required(&self) -> Coin {
+        self.0.required
+    }
+}
+
+impl_wasm_list!(
+    cml_chain::builders::utils::MinAdaViolation,
+    MinAdaViolation,
+    MinAdaViolationList
+);
+
+impl_wasm_json_api!(MinAdaViolationList);
+
+/// Every output of {tx_body} (including the collateral return) holding less ADA than required
+/// for {coins_per_utxo_byte}, in output order with the collateral return last.
+#[wasm_bindgen]
+pub fn check_min_ada(tx_body: &TransactionBody, coins_per_utxo_byte: Coin) -> MinAdaViolationList {
+    cml_chain::builders::utils::check_min_ada(tx_body.as_ref(), coins_per_utxo_byte).into()
+}
+
+/// Result of max_send() and max_send_asset()
+#[derive(Clone, Debug)]
+#[wasm_bindgen]
+pub struct MaxSendResult(cml_chain::builders::utils::MaxSendResult);
+
+impl_wasm_conversions!(cml_chain::builders::utils::MaxSendResult, MaxSendResult);
+
+#[wasm_bindgen]
+impl MaxSendResult {
+    /// Largest sendable quantity of the requested asset, or lovelace for max_send()
+    pub fn amount(&self) -> u64 {
+        self.0.amount
+    }
+
+    /// Output paying amount() to the destination, including the ADA it needs to hold
+    pub fn output(&self) -> TransactionOutput {
+        self.0.output.clone().into()
+    }
+
+    /// Fee of the transaction built to send amount()
+    pub fn fee(&self) -> Coin {
+        self.0.fee
+    }
+
+    /// True if nothing is left for a change output i.e. the whole UTxO set goes to the destination
+    pub fn sweep(&self) -> bool {
+        self.0.sweep
+    }
+}
+
+/// Largest amount of ADA that can be sent from {utxos} to {address} in a single transaction
+/// spending all of {utxos}, with the fee covered and any change holding enough ADA for its tokens.
+/// Change is returned to the address of the first UTxO. Only payment key addresses are supported.
+#[wasm_bindgen]
+pub fn max_send(
+    utxos: &TransactionUnspentOutputList,
+    address: &Address,
+    config: &TransactionBuilderConfig,
+) -> Result<MaxSendResult, JsError> {
+    cml_chain::builders::utils::max_send(utxos.as_ref(), address.as_ref(), None, config.as_ref())
+        .map(Into::into)
+        .map_err(Into::into)
+}
+
+/// Like max_send() but for the entire quantity of the asset {policy_id}.{asset_name}.
+/// The destination output holds the minimum ADA required, or all remaining ADA if it would be
+/// too small for a change output.
+#[wasm_bindgen]
+pub fn max_send_asset(
+    utxos: &TransactionUnspentOutputList,
+    address: &Address,
+    policy_id: &PolicyId,
+    asset_name: &AssetName,
+    config: &TransactionBuilderConfig,
+) -> Result<MaxSendResult, JsError> {
+    cml_chain::builders::utils::max_send(
+        utxos.as_ref(),
+        address.as_ref(),
+        Some((*policy_id.as_ref(), asset_name.as_ref().clone())),
+        config.as_ref(),
+    )
+    .map(Into::into)
+    .map_err(Into::into)
+}
@@ -0,0 +1,82 @@
+use crate::transaction::{TransactionBody, TransactionInput, TransactionOutput};
+use cml_core_wasm::{impl_wasm_conversions, impl_wasm_list};
+use cml_crypto_wasm::TransactionHash;
+use wasm_bindgen::prelude::{wasm_bindgen, JsError};
+
+use super::evaluator::TransactionUnspentOutputList;
+use super::tx_builder::TransactionUnspentOutput;
+
+impl_wasm_list!(
+    cml_crypto::TransactionHash,
+    TransactionHash,
+    TransactionHashList
+);
+
+/// Local view of the UTxOs available to a wallet/dApp that allows chaining transactions
+/// i.e. building a transaction spending the outputs of previous ones that haven't hit the chain yet.
+///
+/// Build each transaction with TransactionBuilder using available_utxos() then apply() its body.
+/// Applied transactions can be rolled back in reverse order with unapply() e.g. if one fails to submit.
+/// Inputs can be reserved as collateral so they are never handed out or spent by applied transactions.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct UtxoLedger(cml_chain::builders::utxo_ledger::UtxoLedger);
+
+impl_wasm_conversions!(cml_chain::builders::utxo_ledger::UtxoLedger, UtxoLedger);
+
+#[wasm_bindgen]
+impl UtxoLedger {
+    pub fn new(utxos: &TransactionUnspentOutputList) -> Self {
+        cml_chain::builders::utxo_ledger::UtxoLedger::new(utxos.clone().into()).into()
+    }
+
+    /// Adds a UTxO e.g. one that was received on-chain in the meantime.
+    pub fn add_utxo(&mut self, utxo: &TransactionUnspentOutput) {
+        self.0.add_utxo(utxo.clone().into())
+    }
+
+    pub fn get(&self, input: &TransactionInput) -> Option<TransactionOutput> {
+        self.0.get(input.as_ref()).cloned().map(Into::into)
+    }
+
+    /// All UTxOs that can be used as inputs i.e. excluding reserved collateral.
+    pub fn available_utxos(&self) -> TransactionUnspentOutputList {
+        self.0.available_utxos().into()
+    }
+
+    /// Reserves {input} as collateral so it's excluded from available_utxos() and can't be spent by apply()
+    pub fn reserve_collateral(&mut self, input: &TransactionInput) -> Result<(), JsError> {
+        self.0
+            .reserve_collateral(input.clone().into())
+            .map_err(Into::into)
+    }
+
+    /// Returns whether {input} was reserved as collateral.
+    pub fn release_collateral(&mut self, input: &TransactionInput) -> bool {
+        self.0.release_collateral(input.as_ref())
+    }
+
+    pub fn collateral_utxos(&self) -> TransactionUnspentOutputList {
+        self.0.collateral_utxos().into()
+    }
+
+    /// Removes the inputs spent by {body} and adds its outputs so they can be spent by the next transaction.
+    /// Returns the hash of the applied transaction. Nothing is changed if this fails.
+    pub fn apply(&mut self, body: &TransactionBody) -> Result<TransactionHash, JsError> {
+        self.0
+            .apply(body.as_ref())
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
+    /// Rolls back the most recently applied transaction, restoring the UTxOs it spent.
+    /// Returns its hash or undefined if there are no applied transactions left.
+    pub fn unapply(&mut self) -> Option<TransactionHash> {
+        self.0.unapply().map(Into::into)
+    }
+
+    /// Hashes of all applied (and not rolled back) transactions in the order they were applied.
+    pub fn pending_transactions(&self) -> TransactionHashList {
+        self.0.pending_transactions().into()
+    }
+}
@@ -32,6 +32,11 @@ impl VoteBuilder {
         Self(cml_chain::builders::vote_builder::VoteBuilder::new())
     }
 
+    /// By default vote anchor URLs are strictly checked when added. This turns that check off.
+    pub fn skip_url_validation(&self) -> VoteBuilder {
+        self.0.clone().skip_url_validation().into()
+    }
+
     pub fn with_vote(
         &self,
         voter: &Voter,
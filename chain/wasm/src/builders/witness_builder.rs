@@ -5,13 +5,31 @@ use crate::{
     plutus::{utils::PlutusScript, LegacyRedeemer, PlutusData},
     transaction::TransactionWitnessSet,
     Ed25519KeyHashList, LegacyRedeemerList, NativeScriptList, PlutusDataList, PlutusV1ScriptList,
-    PlutusV2ScriptList, Script,
+    PlutusV2ScriptList, PlutusV3ScriptList, Script,
 };
-use cml_core_wasm::impl_wasm_conversions;
+use cml_core::Slot;
+use cml_core_wasm::{impl_wasm_conversions, impl_wasm_list};
 use cml_crypto_wasm::{DatumHash, Ed25519KeyHash, ScriptHash};
 use wasm_bindgen::prelude::{wasm_bindgen, JsError};
 
-use super::redeemer_builder::RedeemerWitnessKey;
+use super::{
+    evaluator::TransactionUnspentOutputList, redeemer_builder::RedeemerWitnessKey,
+    utils::DatumHashList,
+};
+
+impl_wasm_list!(cml_crypto::ScriptHash, ScriptHash, ScriptHashList);
+
+impl_wasm_list!(
+    cml_chain::byron::ByronAddress,
+    ByronAddress,
+    ByronAddressList
+);
+
+impl_wasm_list!(
+    cml_chain::builders::redeemer_builder::RedeemerWitnessKey,
+    RedeemerWitnessKey,
+    RedeemerWitnessKeyList
+);
 
 #[wasm_bindgen]
 #[derive(Debug, Clone)]
@@ -33,18 +51,38 @@ impl PlutusScriptWitness {
         cml_chain::builders::witness_builder::PlutusScriptWitness::from(*hash.as_ref()).into()
     }
 
-    // pub fn script(&self) -> Option<PlutusScript> {
-    //     match self {
-    //         Self::Ref(_) => None,
-    //         Self::Script(script) => Some(script.clone()),
-    //     }
-    // }
+    pub fn kind(&self) -> PlutusScriptWitnessKind {
+        match &self.0 {
+            cml_chain::builders::witness_builder::PlutusScriptWitness::Ref(_) => {
+                PlutusScriptWitnessKind::Ref
+            }
+            cml_chain::builders::witness_builder::PlutusScriptWitness::Script(_) => {
+                PlutusScriptWitnessKind::Script
+            }
+        }
+    }
+
+    /// The script if it's provided directly i.e. not by a reference input
+    pub fn as_script(&self) -> Option<PlutusScript> {
+        match &self.0 {
+            cml_chain::builders::witness_builder::PlutusScriptWitness::Script(script) => {
+                Some(script.clone().into())
+            }
+            _ => None,
+        }
+    }
 
     pub fn hash(&self) -> ScriptHash {
         self.0.hash().into()
     }
 }
 
+#[wasm_bindgen]
+pub enum PlutusScriptWitnessKind {
+    Ref = 0,
+    Script = 1,
+}
+
 /// A partial Plutus witness
 /// It contains all the information needed to witness the Plutus script execution
 /// except for the redeemer tag and index
@@ -90,11 +128,28 @@ impl_wasm_conversions!(
 
 #[wasm_bindgen]
 impl InputAggregateWitnessData {
+    pub fn kind(&self) -> InputAggregateWitnessDataKind {
+        match &self.0 {
+            cml_chain::builders::witness_builder::InputAggregateWitnessData::NativeScript(..) => {
+                InputAggregateWitnessDataKind::NativeScript
+            }
+            cml_chain::builders::witness_builder::InputAggregateWitnessData::PlutusScript(..) => {
+                InputAggregateWitnessDataKind::PlutusScript
+            }
+        }
+    }
+
     pub fn plutus_data(&self) -> Option<PlutusData> {
         self.0.redeemer_plutus_data().map(|d| d.clone().into())
     }
 }
 
+#[wasm_bindgen]
+pub enum InputAggregateWitnessDataKind {
+    NativeScript = 0,
+    PlutusScript = 1,
+}
+
 #[wasm_bindgen]
 #[derive(Clone, Debug, Default)]
 pub struct RequiredWitnessSet(cml_chain::builders::witness_builder::RequiredWitnessSet);
@@ -138,6 +193,46 @@ impl RequiredWitnessSet {
         cml_chain::builders::witness_builder::RequiredWitnessSet::new().into()
     }
 
+    /// Key hashes of the vkey witnesses required
+    pub fn vkeys(&self) -> Ed25519KeyHashList {
+        self.0.vkeys.iter().cloned().collect::<Vec<_>>().into()
+    }
+
+    /// Byron addresses whose bootstrap witnesses are required
+    pub fn bootstraps(&self) -> ByronAddressList {
+        self.0.bootstraps.iter().cloned().collect::<Vec<_>>().into()
+    }
+
+    /// Hashes of the (native or plutus) scripts required
+    pub fn scripts(&self) -> ScriptHashList {
+        self.0.scripts.iter().cloned().collect::<Vec<_>>().into()
+    }
+
+    /// Hashes of the datums required
+    pub fn plutus_data(&self) -> DatumHashList {
+        self.0
+            .plutus_data
+            .iter()
+            .cloned()
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    /// Redeemers required, by the tag and index of what they are for
+    pub fn redeemers(&self) -> RedeemerWitnessKeyList {
+        self.0.redeemers.iter().cloned().collect::<Vec<_>>().into()
+    }
+
+    /// Scripts provided by {ref_inputs} via reference scripts don't need to be witnessed
+    pub fn remove_ref_scripts(&mut self, ref_inputs: &TransactionUnspentOutputList) {
+        self.0.remove_ref_scripts(ref_inputs.as_ref());
+    }
+
+    /// Datums provided inline by {ref_inputs} don't need to be in the witness set
+    pub fn remove_ref_datums(&mut self, ref_inputs: &TransactionUnspentOutputList) {
+        self.0.remove_ref_datums(ref_inputs.as_ref());
+    }
+
     // comes from witsVKeyNeeded in the Ledger spec
     // this is here instead of withdrawal_builder.rs due to wasm restrictions on &mut params
     pub fn withdrawal_required_wits(&mut self, address: &RewardAddress) {
@@ -186,8 +281,12 @@ impl TransactionWitnessSetBuilder {
         self.0.get_plutus_v2_script().into()
     }
 
-    pub fn add_plutus_datum(&mut self, plutus_datum: PlutusData) {
-        self.0.add_plutus_datum(plutus_datum.into());
+    pub fn get_plutus_v3_script(&self) -> PlutusV3ScriptList {
+        self.0.get_plutus_v3_script().into()
+    }
+
+    pub fn add_plutus_datum(&mut self, plutus_datum: &PlutusData) {
+        self.0.add_plutus_datum(plutus_datum.clone().into());
     }
 
     pub fn get_plutus_datum(&self) -> PlutusDataList {
@@ -226,6 +325,30 @@ impl TransactionWitnessSetBuilder {
         self.0.try_build().map(Into::into).map_err(Into::into)
     }
 
+    /// remaining_wits() plus the vkeys still needed by the native scripts
+    /// for a tx with the given validity interval
+    pub fn remaining_wits_within(
+        &self,
+        validity_start: Option<Slot>,
+        ttl: Option<Slot>,
+    ) -> Result<RequiredWitnessSet, JsError> {
+        self.0
+            .remaining_wits_within(validity_start, ttl)
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
+    pub fn try_build_within(
+        &self,
+        validity_start: Option<Slot>,
+        ttl: Option<Slot>,
+    ) -> Result<TransactionWitnessSet, JsError> {
+        self.0
+            .try_build_within(validity_start, ttl)
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
     pub fn merge_fake_witness(&mut self, required_wits: &RequiredWitnessSet) {
         cml_chain::builders::witness_builder::merge_fake_witness(
             &mut self.0,
@@ -261,4 +384,43 @@ impl NativeScriptWitnessInfo {
         cml_chain::builders::witness_builder::NativeScriptWitnessInfo::assume_signature_count()
             .into()
     }
+
+    /// Exactly these keys will sign e.g. the known co-signers of an N-of-K multisig.
+    /// Unlike vkeys() they become required witnesses and building the tx throws if they
+    /// can't satisfy the script within the tx validity interval
+    pub fn specific_signers(signers: &Ed25519KeyHashList) -> Self {
+        cml_chain::builders::witness_builder::NativeScriptWitnessInfo::specific_signers(
+            signers.clone().into(),
+        )
+        .into()
+    }
+
+    pub fn kind(&self) -> NativeScriptWitnessInfoKind {
+        match &self.0 {
+            cml_chain::builders::witness_builder::NativeScriptWitnessInfo::Count(_) => {
+                NativeScriptWitnessInfoKind::Count
+            }
+            cml_chain::builders::witness_builder::NativeScriptWitnessInfo::Vkeys(_) => {
+                NativeScriptWitnessInfoKind::Vkeys
+            }
+            cml_chain::builders::witness_builder::NativeScriptWitnessInfo::AssumeWorst => {
+                NativeScriptWitnessInfoKind::AssumeWorst
+            }
+            cml_chain::builders::witness_builder::NativeScriptWitnessInfo::SpecificSigners(_) => {
+                NativeScriptWitnessInfoKind::SpecificSigners
+            }
+        }
+    }
+}
+
+#[wasm_bindgen]
+pub enum NativeScriptWitnessInfoKind {
+    /// See num_signatures()
+    Count = 0,
+    /// See vkeys()
+    Vkeys = 1,
+    /// See assume_signature_count()
+    AssumeWorst = 2,
+    /// See specific_signers()
+    SpecificSigners = 3,
 }
@@ -204,9 +204,9 @@ impl SpendingData {
 
 #[wasm_bindgen]
 pub enum SpendingDataKind {
-    SpendingDataPubKey,
-    SpendingDataScript,
-    SpendingDataRedeem,
+    SpendingDataPubKey = 0,
+    SpendingDataScript = 1,
+    SpendingDataRedeem = 2,
 }
 
 #[derive(Clone, Debug)]
@@ -250,8 +250,8 @@ impl StakeDistribution {
 
 #[wasm_bindgen]
 pub enum StakeDistributionKind {
-    SingleKey,
-    BootstrapEra,
+    SingleKey = 0,
+    BootstrapEra = 1,
 }
 
 #[derive(Clone, Debug)]
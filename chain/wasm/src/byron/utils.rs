@@ -1,6 +1,6 @@
 use cml_crypto_wasm::{
-    impl_hash_type_ext, Bip32PrivateKey, Bip32PublicKey, LegacyDaedalusPrivateKey, PublicKey,
-    TransactionHash,
+    impl_hash_type_ext, Bip32PrivateKey, Bip32PublicKey, Ed25519Signature,
+    LegacyDaedalusPrivateKey, PrivateKey, PublicKey, TransactionHash,
 };
 use wasm_bindgen::{prelude::wasm_bindgen, JsError};
 
@@ -154,6 +154,22 @@ impl ByronAddress {
     pub fn from_address_content(address_content: &AddressContent) -> Self {
         cml_chain::byron::ByronAddress::from(address_content.as_ref().clone()).into()
     }
+
+    /**
+     * AVVM (Ada Voucher Vending Machine) redeem address for {pubkey}
+     * These were only created in the Byron genesis and can only be spent by a redemption tx.
+     */
+    pub fn new_redeem(pubkey: &PublicKey, protocol_magic: &ProtocolMagic) -> Self {
+        cml_chain::byron::ByronAddress::new_redeem(pubkey.as_ref(), (*protocol_magic).into()).into()
+    }
+
+    /**
+     * Transaction hash of the UTxO created for this address by the Byron genesis
+     * i.e. the one a redemption tx spends (always at index 0)
+     */
+    pub fn genesis_tx_hash(&self) -> TransactionHash {
+        self.0.genesis_tx_hash().into()
+    }
 }
 
 #[wasm_bindgen]
@@ -203,6 +219,41 @@ pub fn make_daedalus_bootstrap_witness(
     .into()
 }
 
+/**
+ * Signs the Byron tx {tx_hash} with the redeem (AVVM) key {key} for a redemption tx witness
+ */
+#[wasm_bindgen]
+pub fn make_redeem_signature(
+    tx_hash: &TransactionHash,
+    key: &PrivateKey,
+    protocol_magic: &ProtocolMagic,
+) -> Ed25519Signature {
+    cml_chain::byron::make_redeem_signature(
+        tx_hash.as_ref(),
+        key.as_ref(),
+        (*protocol_magic).into(),
+    )
+    .into()
+}
+
+/**
+ * Verifies a Byron redeem witness signature over the redemption tx {tx_hash}
+ */
+#[wasm_bindgen]
+pub fn verify_redeem_signature(
+    tx_hash: &TransactionHash,
+    pubkey: &PublicKey,
+    signature: &Ed25519Signature,
+    protocol_magic: &ProtocolMagic,
+) -> bool {
+    cml_chain::byron::verify_redeem_signature(
+        tx_hash.as_ref(),
+        pubkey.as_ref(),
+        signature.as_ref(),
+        (*protocol_magic).into(),
+    )
+}
+
 #[wasm_bindgen]
 pub fn make_icarus_bootstrap_witness(
     tx_body_hash: TransactionHash,
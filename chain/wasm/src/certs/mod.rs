@@ -9,6 +9,8 @@ use cml_core_wasm::{impl_wasm_cbor_json_api, impl_wasm_conversions};
 use cml_crypto_wasm::{Ed25519KeyHash, PoolMetadataHash, ScriptHash, VRFKeyHash};
 use wasm_bindgen::prelude::wasm_bindgen;
 
+pub mod utils;
+
 #[derive(Clone, Debug)]
 #[wasm_bindgen]
 pub struct AuthCommitteeHotCert(cml_chain::certs::AuthCommitteeHotCert);
@@ -399,23 +401,23 @@ impl Certificate {
 
 #[wasm_bindgen]
 pub enum CertificateKind {
-    StakeRegistration,
-    StakeDeregistration,
-    StakeDelegation,
-    PoolRegistration,
-    PoolRetirement,
-    RegCert,
-    UnregCert,
-    VoteDelegCert,
-    StakeVoteDelegCert,
-    StakeRegDelegCert,
-    VoteRegDelegCert,
-    StakeVoteRegDelegCert,
-    AuthCommitteeHotCert,
-    ResignCommitteeColdCert,
-    RegDrepCert,
-    UnregDrepCert,
-    UpdateDrepCert,
+    StakeRegistration = 0,
+    StakeDeregistration = 1,
+    StakeDelegation = 2,
+    PoolRegistration = 3,
+    PoolRetirement = 4,
+    RegCert = 5,
+    UnregCert = 6,
+    VoteDelegCert = 7,
+    StakeVoteDelegCert = 8,
+    StakeRegDelegCert = 9,
+    VoteRegDelegCert = 10,
+    StakeVoteRegDelegCert = 11,
+    AuthCommitteeHotCert = 12,
+    ResignCommitteeColdCert = 13,
+    RegDrepCert = 14,
+    UnregDrepCert = 15,
+    UpdateDrepCert = 16,
 }
 
 pub type CommitteeColdCredential = Credential;
@@ -468,8 +470,8 @@ impl Credential {
 
 #[wasm_bindgen]
 pub enum CredentialKind {
-    PubKey,
-    Script,
+    PubKey = 0,
+    Script = 1,
 }
 
 #[derive(Clone, Debug)]
@@ -541,10 +543,10 @@ impl DRep {
 
 #[wasm_bindgen]
 pub enum DRepKind {
-    Key,
-    Script,
-    AlwaysAbstain,
-    AlwaysNoConfidence,
+    Key = 0,
+    Script = 1,
+    AlwaysAbstain = 2,
+    AlwaysNoConfidence = 3,
 }
 
 pub type DrepCredential = Credential;
@@ -875,9 +877,9 @@ impl Relay {
 
 #[wasm_bindgen]
 pub enum RelayKind {
-    SingleHostAddr,
-    SingleHostName,
-    MultiHostName,
+    SingleHostAddr = 0,
+    SingleHostName = 1,
+    MultiHostName = 2,
 }
 
 #[derive(Clone, Debug)]
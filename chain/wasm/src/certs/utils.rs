@@ -1,5 +1,14 @@
-use wasm_bindgen::prelude::wasm_bindgen;
-use super::{Ipv4, Ipv6, StakeCredential};
+use std::str::FromStr;
+
+use cml_core_wasm::impl_wasm_conversions;
+use cml_crypto_wasm::Ed25519KeyHash;
+use wasm_bindgen::prelude::{wasm_bindgen, JsError};
+
+use super::{
+    Certificate, DNSName, Ipv4, Ipv6, MultiHostName, PoolMetadata, PoolParams, PoolRegistration,
+    PoolRetirement, Relay, SingleHostName, StakeCredential, Url,
+};
+use crate::{Ed25519KeyHashList, Epoch, NetworkId};
 
 #[wasm_bindgen]
 impl StakeCredential {
@@ -17,7 +26,9 @@ impl Ipv4 {
 
     #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Result<Ipv4, JsError> {
-        cml_chain::certs::Ipv4::from_str(s).map(Into::into).map_err(Into::into)
+        cml_chain::certs::Ipv4::from_str(s)
+            .map(Into::into)
+            .map_err(Into::into)
     }
 }
 
@@ -29,6 +40,178 @@ impl Ipv6 {
 
     #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Result<Ipv6, JsError> {
-        cml_chain::certs::Ipv6::from_str(s).map(Into::into).map_err(Into::into)
+        cml_chain::certs::Ipv6::from_str(s)
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+}
+
+#[wasm_bindgen]
+impl Relay {
+    /// Renders the relay as accepted by from_str() e.g. "relay.example.com:3001"
+    /// A single host address with both IPv4 and IPv6 addresses only shows the IPv4 one.
+    pub fn to_str(&self) -> String {
+        self.0.to_string()
     }
-}
\ No newline at end of file
+
+    /// Parses "203.0.113.7:3001", "[2001:db8::1]:3001", "relay.example.com:3001" (single host)
+    /// or "_srv.relays.example.com" (multi host SRV record). Ports are optional for single hosts.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Result<Relay, JsError> {
+        cml_chain::certs::Relay::from_str(s)
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+}
+
+#[wasm_bindgen]
+impl Url {
+    /// Creates a Url, which can be at most 128 bytes long (e.g. for an Anchor)
+    pub fn new(url: String) -> Result<Url, JsError> {
+        cml_chain::certs::Url::new(url)
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
+    /// Like new() but also requires an http, https or ipfs scheme and no whitespace
+    pub fn new_strict(url: String) -> Result<Url, JsError> {
+        cml_chain::certs::Url::new_strict(url)
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
+    /// Same as get()
+    pub fn to_str(&self) -> String {
+        self.0.get().clone()
+    }
+}
+
+#[wasm_bindgen]
+impl DNSName {
+    /// Creates a DNSName which must be a valid (RFC 1123) hostname.
+    /// Leading labels may start with an underscore for SRV records e.g. _cardano._tcp.example.com
+    pub fn new_strict(dns_name: String) -> Result<DNSName, JsError> {
+        cml_chain::certs::DNSName::new_strict(dns_name)
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
+    /// Same as get()
+    pub fn to_str(&self) -> String {
+        self.0.get().clone()
+    }
+}
+
+#[wasm_bindgen]
+impl PoolMetadata {
+    /// The url as a plain string i.e. url().get()
+    pub fn url_str(&self) -> String {
+        self.0.url.get().clone()
+    }
+}
+
+#[wasm_bindgen]
+impl SingleHostName {
+    /// The DNS name as a plain string i.e. dns_name().get()
+    pub fn dns_name_str(&self) -> String {
+        self.0.dns_name.get().clone()
+    }
+}
+
+#[wasm_bindgen]
+impl MultiHostName {
+    /// The DNS name as a plain string i.e. dns_name().get()
+    pub fn dns_name_str(&self) -> String {
+        self.0.dns_name.get().clone()
+    }
+}
+
+#[wasm_bindgen]
+impl PoolParams {
+    /// Checks that the reward account is for {network_id}.
+    /// The ledger only rejects a mismatch at submission time.
+    pub fn validate_network(&self, network_id: &NetworkId) -> Result<(), JsError> {
+        self.0
+            .validate_network(network_id.clone().into())
+            .map_err(Into::into)
+    }
+
+    /// Checks that no single/multi host name relay has an empty DNS name.
+    pub fn validate_relays(&self) -> Result<(), JsError> {
+        self.0.validate_relays().map_err(Into::into)
+    }
+}
+
+#[wasm_bindgen]
+impl PoolRegistration {
+    /// Whether the pool params differ in any way that matters on-chain.
+    /// Encoding details, the order of the pool owners and unreduced margins (e.g. 1/2 vs 2/4) are ignored.
+    pub fn differs_materially_from(&self, other: &PoolRegistration) -> bool {
+        self.0.differs_materially_from(other.as_ref())
+    }
+}
+
+/// Tracks the registration state of stake pools from certificates fed in chain order.
+/// Re-registering a pool cancels any pending retirement and its new params take effect
+/// at the next epoch boundary. Retiring pools are removed at their retirement epoch's boundary.
+#[derive(Clone, Debug, Default)]
+#[wasm_bindgen]
+pub struct PoolLifecycle(cml_chain::certs::utils::PoolLifecycle);
+
+impl_wasm_conversions!(cml_chain::certs::utils::PoolLifecycle, PoolLifecycle);
+
+#[wasm_bindgen]
+impl PoolLifecycle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Applies {cert} if it's a pool registration or retirement. Other certificates are ignored.
+    pub fn apply_cert(&mut self, cert: &Certificate, current_epoch: Epoch) -> Result<(), JsError> {
+        self.0
+            .apply_cert(cert.as_ref(), current_epoch)
+            .map_err(Into::into)
+    }
+
+    /// Returns true if this was a new registration, or false if it updated an existing pool.
+    pub fn register(&mut self, registration: &PoolRegistration) -> bool {
+        self.0.register(registration.as_ref())
+    }
+
+    pub fn retire(
+        &mut self,
+        retirement: &PoolRetirement,
+        current_epoch: Epoch,
+    ) -> Result<(), JsError> {
+        self.0
+            .retire(retirement.as_ref(), current_epoch)
+            .map_err(Into::into)
+    }
+
+    /// Transitions into {new_epoch} and returns the ids of the pools that were retired.
+    pub fn process_epoch_boundary(&mut self, new_epoch: Epoch) -> Ed25519KeyHashList {
+        self.0.process_epoch_boundary(new_epoch).into()
+    }
+
+    pub fn is_registered(&self, pool: &Ed25519KeyHash) -> bool {
+        self.0.is_registered(pool.as_ref())
+    }
+
+    /// Epoch at which {pool} will be retired, if a retirement is pending
+    pub fn retiring_epoch(&self, pool: &Ed25519KeyHash) -> Option<Epoch> {
+        self.0.retiring_epoch(pool.as_ref())
+    }
+
+    /// The registration currently in effect for {pool}
+    pub fn registration(&self, pool: &Ed25519KeyHash) -> Option<PoolRegistration> {
+        self.0.registration(pool.as_ref()).cloned().map(Into::into)
+    }
+
+    /// A re-registration of {pool} that will take effect at the next epoch boundary
+    pub fn future_registration(&self, pool: &Ed25519KeyHash) -> Option<PoolRegistration> {
+        self.0
+            .future_registration(pool.as_ref())
+            .cloned()
+            .map(Into::into)
+    }
+}
@@ -111,8 +111,8 @@ impl Nonce {
 
 #[wasm_bindgen]
 pub enum NonceKind {
-    Identity,
-    Hash,
+    Identity = 0,
+    Hash = 1,
 }
 
 #[derive(Clone, Debug)]
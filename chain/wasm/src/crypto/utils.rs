@@ -1,8 +1,12 @@
 use wasm_bindgen::prelude::{wasm_bindgen, JsError};
 
-use crate::{byron::AddressContent, crypto::BootstrapWitness, Vkeywitness};
+use crate::{
+    byron::AddressContent,
+    crypto::{BootstrapWitness, Nonce, VRFCert},
+    Vkeywitness,
+};
 
-use cml_crypto_wasm::{PrivateKey, TransactionHash};
+use cml_crypto_wasm::{NonceHash, PrivateKey, TransactionHash};
 
 #[wasm_bindgen]
 impl BootstrapWitness {
@@ -15,3 +19,28 @@ impl BootstrapWitness {
 pub fn make_vkey_witness(tx_body_hash: &TransactionHash, sk: &PrivateKey) -> Vkeywitness {
     cml_chain::crypto::utils::make_vkey_witness(tx_body_hash.as_ref(), sk.as_ref()).into()
 }
+
+#[wasm_bindgen]
+impl VRFCert {
+    /**
+     * Nonce contribution of a TPraos (Shelley through Alonzo) header's nonce_vrf certificate.
+     */
+    pub fn tpraos_nonce(&self) -> NonceHash {
+        self.0.tpraos_nonce().into()
+    }
+
+    /**
+     * Nonce contribution of a Praos (Babbage onwards) header's vrf_result certificate.
+     */
+    pub fn praos_nonce(&self) -> NonceHash {
+        self.0.praos_nonce().into()
+    }
+}
+
+/**
+ * Folds a single block's nonce contribution into the evolving nonce as the ledger does.
+ */
+#[wasm_bindgen]
+pub fn evolve_nonce(current: &Nonce, header_nonce: &NonceHash) -> Nonce {
+    cml_chain::crypto::utils::evolve_nonce(current.as_ref(), header_nonce.as_ref()).into()
+}
@@ -1,6 +1,7 @@
 use crate::byron::utils::ProtocolMagic;
+use cml_core::Slot;
 use cml_core_wasm::impl_wasm_conversions;
-use wasm_bindgen::prelude::wasm_bindgen;
+use wasm_bindgen::prelude::{wasm_bindgen, JsError};
 
 #[wasm_bindgen]
 pub struct NetworkInfo(cml_chain::genesis::network_info::NetworkInfo);
@@ -43,3 +44,62 @@ impl NetworkInfo {
         cml_chain::genesis::network_info::NetworkInfo::sancho_testnet().into()
     }
 }
+
+/// Mapping between slots and POSIX time (in milliseconds) for the (post-Byron) eras which
+/// have a fixed slot length: zero_slot started at zero_time and every slot lasts slot_length ms.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug)]
+pub struct SlotConfig(cml_chain::genesis::network_info::SlotConfig);
+
+impl_wasm_conversions!(cml_chain::genesis::network_info::SlotConfig, SlotConfig);
+
+#[wasm_bindgen]
+impl SlotConfig {
+    /// Fails if slot_length is 0
+    pub fn new(zero_time: u64, zero_slot: Slot, slot_length: u64) -> Result<SlotConfig, JsError> {
+        cml_chain::genesis::network_info::SlotConfig::new(zero_time, zero_slot, slot_length)
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
+    /// POSIX time (ms) at which zero_slot started
+    pub fn zero_time(&self) -> u64 {
+        self.0.zero_time()
+    }
+
+    pub fn zero_slot(&self) -> Slot {
+        self.0.zero_slot()
+    }
+
+    /// Length of a slot in milliseconds
+    pub fn slot_length(&self) -> u64 {
+        self.0.slot_length()
+    }
+
+    pub fn mainnet() -> Self {
+        cml_chain::genesis::network_info::SlotConfig::mainnet().into()
+    }
+
+    pub fn preview() -> Self {
+        cml_chain::genesis::network_info::SlotConfig::preview().into()
+    }
+
+    pub fn preprod() -> Self {
+        cml_chain::genesis::network_info::SlotConfig::preprod().into()
+    }
+
+    /// POSIX time (ms) at which {slot} starts. Undefined if it's before zero_slot.
+    pub fn slot_to_posix_time(&self, slot: Slot) -> Option<u64> {
+        self.0.slot_to_posix_time(slot)
+    }
+
+    /// Slot containing {posix_time} (ms). Undefined if it's before zero_time.
+    pub fn posix_time_to_slot(&self, posix_time: u64) -> Option<Slot> {
+        self.0.posix_time_to_slot(posix_time)
+    }
+
+    /// How many whole slots fit in {seconds}
+    pub fn slots_in(&self, seconds: u64) -> u64 {
+        self.0.slots_in(seconds)
+    }
+}
@@ -15,6 +15,7 @@ use cml_core::ordered_hash_map::OrderedHashMap;
 use cml_core_wasm::{impl_wasm_cbor_json_api, impl_wasm_conversions};
 use wasm_bindgen::prelude::wasm_bindgen;
 
+pub mod ratification;
 pub mod utils;
 
 #[derive(Clone, Debug)]
@@ -252,13 +253,13 @@ impl GovActionId {
 
 #[wasm_bindgen]
 pub enum GovActionKind {
-    ParameterChangeAction,
-    HardForkInitiationAction,
-    TreasuryWithdrawalsAction,
-    NoConfidence,
-    UpdateCommittee,
-    NewConstitution,
-    InfoAction,
+    ParameterChangeAction = 0,
+    HardForkInitiationAction = 1,
+    TreasuryWithdrawalsAction = 2,
+    NoConfidence = 3,
+    UpdateCommittee = 4,
+    NewConstitution = 5,
+    InfoAction = 6,
 }
 
 #[derive(Clone, Debug)]
@@ -596,11 +597,11 @@ impl Voter {
 
 #[wasm_bindgen]
 pub enum VoterKind {
-    ConstitutionalCommitteeHotKeyHash,
-    ConstitutionalCommitteeHotScriptHash,
-    DRepKeyHash,
-    DRepScriptHash,
-    StakingPoolKeyHash,
+    ConstitutionalCommitteeHotKeyHash = 0,
+    ConstitutionalCommitteeHotScriptHash = 1,
+    DRepKeyHash = 2,
+    DRepScriptHash = 3,
+    StakingPoolKeyHash = 4,
 }
 
 #[derive(Clone, Debug)]
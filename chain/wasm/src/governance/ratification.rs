@@ -0,0 +1,208 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use cml_core::Epoch;
+use cml_core_wasm::{impl_wasm_cbor_json_api, impl_wasm_conversions, impl_wasm_json_api};
+use cml_crypto_wasm::Ed25519KeyHash;
+
+use super::utils::CommitteeState;
+use super::{GovAction, GovActionId, VotingProcedures};
+use crate::assets::Coin;
+use crate::certs::DRep;
+use crate::{DRepVotingThresholds, PoolVotingThresholds, UnitInterval};
+
+/// Active voting stake delegated to each DRep (including AlwaysAbstain/AlwaysNoConfidence)
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct DRepStakeDistribution(cml_chain::governance::ratification::DRepStakeDistribution);
+
+impl_wasm_cbor_json_api!(DRepStakeDistribution);
+
+impl_wasm_conversions!(
+    cml_chain::governance::ratification::DRepStakeDistribution,
+    DRepStakeDistribution
+);
+
+#[wasm_bindgen]
+impl DRepStakeDistribution {
+    pub fn new() -> Self {
+        Self(cml_chain::governance::ratification::DRepStakeDistribution::new())
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.stake.len()
+    }
+
+    pub fn insert(&mut self, drep: &DRep, stake: Coin) -> Option<Coin> {
+        self.0.stake.insert(drep.clone().into(), stake)
+    }
+
+    pub fn get(&self, drep: &DRep) -> Option<Coin> {
+        self.0.stake.get(drep.as_ref()).copied()
+    }
+}
+
+/// Active stake of each stake pool (by pool operator key hash)
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct SpoStakeDistribution(cml_chain::governance::ratification::SpoStakeDistribution);
+
+impl_wasm_cbor_json_api!(SpoStakeDistribution);
+
+impl_wasm_conversions!(
+    cml_chain::governance::ratification::SpoStakeDistribution,
+    SpoStakeDistribution
+);
+
+#[wasm_bindgen]
+impl SpoStakeDistribution {
+    pub fn new() -> Self {
+        Self(cml_chain::governance::ratification::SpoStakeDistribution::new())
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.stake.len()
+    }
+
+    pub fn insert(&mut self, pool: &Ed25519KeyHash, stake: Coin) -> Option<Coin> {
+        self.0.stake.insert(pool.clone().into(), stake)
+    }
+
+    pub fn get(&self, pool: &Ed25519KeyHash) -> Option<Coin> {
+        self.0.stake.get(pool.as_ref()).copied()
+    }
+}
+
+/// Protocol parameters and ledger state needed for ratification besides the votes themselves
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct RatificationParams(cml_chain::governance::ratification::RatificationParams);
+
+impl_wasm_json_api!(RatificationParams);
+
+impl_wasm_conversions!(
+    cml_chain::governance::ratification::RatificationParams,
+    RatificationParams
+);
+
+#[wasm_bindgen]
+impl RatificationParams {
+    /// {no_confidence}: whether the last enacted action was a NoConfidence i.e. there is no committee
+    /// {current_epoch}: epoch the votes are tallied in, to leave out expired committee members
+    pub fn new(
+        committee_quorum: &UnitInterval,
+        min_committee_size: u64,
+        d_rep_voting_thresholds: &DRepVotingThresholds,
+        pool_voting_thresholds: &PoolVotingThresholds,
+        no_confidence: bool,
+        current_epoch: Epoch,
+    ) -> Self {
+        Self(cml_chain::governance::ratification::RatificationParams {
+            committee_quorum: committee_quorum.clone().into(),
+            min_committee_size,
+            d_rep_voting_thresholds: d_rep_voting_thresholds.clone().into(),
+            pool_voting_thresholds: pool_voting_thresholds.clone().into(),
+            no_confidence,
+            current_epoch,
+        })
+    }
+}
+
+/// Votes of one voting body on a governance action. Lovelace for DReps and SPOs,
+/// member counts for the constitutional committee.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct VotingBodyTally(cml_chain::governance::ratification::VotingBodyTally);
+
+impl_wasm_json_api!(VotingBodyTally);
+
+impl_wasm_conversions!(
+    cml_chain::governance::ratification::VotingBodyTally,
+    VotingBodyTally
+);
+
+#[wasm_bindgen]
+impl VotingBodyTally {
+    pub fn yes(&self) -> u64 {
+        self.0.yes
+    }
+
+    /// Includes the stake (or members) that didn't vote
+    pub fn no(&self) -> u64 {
+        self.0.no
+    }
+
+    pub fn abstain(&self) -> u64 {
+        self.0.abstain
+    }
+
+    /// None if this body doesn't vote on the action or if the action can't be ratified at all
+    pub fn threshold(&self) -> Option<UnitInterval> {
+        self.0.threshold.clone().map(Into::into)
+    }
+
+    pub fn accepted(&self) -> bool {
+        self.0.accepted
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct GovActionTally(cml_chain::governance::ratification::GovActionTally);
+
+impl_wasm_json_api!(GovActionTally);
+
+impl_wasm_conversions!(
+    cml_chain::governance::ratification::GovActionTally,
+    GovActionTally
+);
+
+#[wasm_bindgen]
+impl GovActionTally {
+    pub fn action_id(&self) -> GovActionId {
+        self.0.action_id.clone().into()
+    }
+
+    pub fn committee(&self) -> VotingBodyTally {
+        self.0.committee.clone().into()
+    }
+
+    pub fn dreps(&self) -> VotingBodyTally {
+        self.0.dreps.clone().into()
+    }
+
+    pub fn pools(&self) -> VotingBodyTally {
+        self.0.pools.clone().into()
+    }
+
+    /// Whether all voting bodies accept the action
+    pub fn accepted(&self) -> bool {
+        self.0.accepted
+    }
+}
+
+/**
+ * Tallies the votes on a governance action weighted by the given stake distributions and checks
+ * them against the thresholds for its type of action. DReps and SPOs that didn't vote count as no.
+ * Enactment order, guardrail scripts and the bootstrap phase are not checked.
+ */
+#[wasm_bindgen]
+pub fn tally_gov_action(
+    action_id: &GovActionId,
+    action: &GovAction,
+    votes: &VotingProcedures,
+    dreps: &DRepStakeDistribution,
+    pools: &SpoStakeDistribution,
+    committee: &CommitteeState,
+    params: &RatificationParams,
+) -> GovActionTally {
+    cml_chain::governance::ratification::tally_gov_action(
+        action_id.as_ref(),
+        action.as_ref(),
+        votes.as_ref(),
+        dreps.as_ref(),
+        pools.as_ref(),
+        committee.as_ref(),
+        params.as_ref(),
+    )
+    .into()
+}
@@ -1,8 +1,80 @@
-use wasm_bindgen::prelude::wasm_bindgen;
+use wasm_bindgen::prelude::{wasm_bindgen, JsError};
 
-use cml_crypto_wasm::{Ed25519KeyHash, ScriptHash};
+use cml_core::serialization::{Deserialize, Serialize};
+use cml_crypto_wasm::{AnchorDocHash, Ed25519KeyHash, ScriptHash};
 
-use super::{GovAction, Voter};
+use crate::certs::{Certificate, CommitteeColdCredential, CommitteeHotCredential, Credential};
+use crate::transaction::TransactionBody;
+use crate::{CommitteeColdCredentialList, ProposalProcedureList};
+use cml_core::Epoch;
+use cml_core_wasm::{impl_wasm_conversions, impl_wasm_json_api};
+
+use super::{Anchor, Constitution, GovAction, NewConstitution, UpdateCommittee, Voter};
+
+#[wasm_bindgen]
+impl Anchor {
+    /// Creates an anchor directly from a URL string.
+    /// Throws if the URL is longer than the 128 byte limit.
+    pub fn from_url(
+        anchor_url: String,
+        anchor_doc_hash: &AnchorDocHash,
+    ) -> Result<Anchor, JsError> {
+        cml_chain::governance::Anchor::from_url(anchor_url, anchor_doc_hash.clone().into())
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
+    /// Like from_url() but also throws if the URL doesn't have an http, https or ipfs scheme
+    /// or contains whitespace.
+    pub fn from_url_strict(
+        anchor_url: String,
+        anchor_doc_hash: &AnchorDocHash,
+    ) -> Result<Anchor, JsError> {
+        cml_chain::governance::Anchor::from_url_strict(anchor_url, anchor_doc_hash.clone().into())
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
+    /// Hashes an anchor's off-chain document for use as its anchor_doc_hash.
+    /// As per CIP-100 this is the blake2b-256 of the raw bytes of the document exactly as served
+    /// (no JSON(-LD) canonicalization is applied) which is also what `cardano-cli hash anchor-data` does.
+    pub fn hash_document(document: &[u8]) -> AnchorDocHash {
+        cml_chain::governance::Anchor::hash_document(document).into()
+    }
+
+    /// Whether {document} (as raw bytes exactly as served) matches this anchor's anchor_doc_hash
+    pub fn matches_document(&self, document: &[u8]) -> bool {
+        self.0.matches_document(document)
+    }
+
+    /// The anchor url as a plain string i.e. anchor_url().get()
+    pub fn anchor_url_str(&self) -> String {
+        self.0.anchor_url.get().clone()
+    }
+}
+
+/// Lenient structural check of a CIP-100/CIP-108 governance metadata document.
+/// Throws if authors, body.title or body.abstract are missing or if hashAlgorithm isn't blake2b-256.
+#[wasm_bindgen]
+pub fn validate_anchor_document(document: &[u8]) -> Result<(), JsError> {
+    cml_chain::governance::utils::validate_anchor_document(document).map_err(Into::into)
+}
+
+#[wasm_bindgen]
+impl Constitution {
+    /// The guardrail script that ParameterChange and TreasuryWithdrawals actions must be approved by
+    pub fn guardrail_script_hash(&self) -> Option<ScriptHash> {
+        self.0.guardrail_script_hash().map(|hash| (*hash).into())
+    }
+}
+
+#[wasm_bindgen]
+impl NewConstitution {
+    /// Guardrail script of the proposed constitution
+    pub fn guardrail_script_hash(&self) -> Option<ScriptHash> {
+        self.0.guardrail_script_hash().map(|hash| (*hash).into())
+    }
+}
 
 #[wasm_bindgen]
 impl GovAction {
@@ -21,3 +93,92 @@ impl Voter {
         self.0.script_hash().map(|hash| (*hash).into())
     }
 }
+
+#[wasm_bindgen]
+impl ProposalProcedureList {
+    /// Serializes as the nonempty set used for a tx body's proposal_procedures
+    pub fn to_cbor_bytes(&self) -> Vec<u8> {
+        cml_chain::NonemptySetProposalProcedure::from(self.0.clone()).to_cbor_bytes()
+    }
+
+    pub fn to_cbor_hex(&self) -> String {
+        hex::encode(self.to_cbor_bytes())
+    }
+
+    pub fn from_cbor_bytes(cbor_bytes: &[u8]) -> Result<ProposalProcedureList, JsError> {
+        cml_chain::NonemptySetProposalProcedure::from_cbor_bytes(cbor_bytes)
+            .map(|set| Self(set.into()))
+            .map_err(Into::into)
+    }
+}
+
+/// Tracks which hot credential each constitutional committee member (cold credential) has
+/// authorized and which members resigned, e.g. to attribute committee votes to members.
+/// UpdateCommittee enactments aren't known from certificates and must be passed in.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct CommitteeState(cml_chain::governance::utils::CommitteeState);
+
+impl_wasm_conversions!(cml_chain::governance::utils::CommitteeState, CommitteeState);
+
+impl_wasm_json_api!(CommitteeState);
+
+#[wasm_bindgen]
+impl CommitteeState {
+    pub fn new() -> Self {
+        cml_chain::governance::utils::CommitteeState::new().into()
+    }
+
+    /// Applies AuthCommitteeHotCert and ResignCommitteeColdCert certificates, ignoring all others
+    pub fn apply_cert(&mut self, cert: &Certificate) {
+        self.0.apply_cert(cert.as_ref());
+    }
+
+    /// Applies the certificates of a (phase-2 valid) transaction
+    pub fn apply_tx_body(&mut self, body: &TransactionBody) {
+        self.0.apply_tx_body(body.as_ref());
+    }
+
+    pub fn enact_update_committee(&mut self, action: &UpdateCommittee) {
+        self.0.enact_update_committee(action.as_ref());
+    }
+
+    pub fn hot_credential_for(&self, cold: &CommitteeColdCredential) -> Option<Credential> {
+        self.0
+            .hot_credential_for(cold.as_ref())
+            .map(|hot| hot.clone().into())
+    }
+
+    pub fn is_resigned(&self, cold: &CommitteeColdCredential) -> bool {
+        self.0.is_resigned(cold.as_ref())
+    }
+
+    /// Last epoch of the member's term, if added by an enactment passed to this tracker
+    pub fn expires_after(&self, cold: &CommitteeColdCredential) -> Option<Epoch> {
+        self.0
+            .member(cold.as_ref())
+            .and_then(|member| member.expires_after)
+    }
+
+    pub fn cold_credentials_for(
+        &self,
+        hot: &CommitteeHotCredential,
+    ) -> CommitteeColdCredentialList {
+        self.0
+            .cold_credentials_for(hot.as_ref())
+            .into_iter()
+            .cloned()
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    /// The members a vote by this voter counts for. Empty for non-committee voters.
+    pub fn voter_cold_credentials(&self, voter: &Voter) -> CommitteeColdCredentialList {
+        self.0
+            .voter_cold_credentials(voter.as_ref())
+            .into_iter()
+            .cloned()
+            .collect::<Vec<_>>()
+            .into()
+    }
+}
@@ -10,6 +10,7 @@ use cml_core_wasm::{impl_wasm_cbor_json_api, impl_wasm_conversions, impl_wasm_li
 
 pub use cml_core_wasm::Int;
 
+pub mod account;
 pub mod address;
 pub mod assets;
 pub mod auxdata;
@@ -33,8 +34,8 @@ use assets::AssetName;
 pub use assets::Value;
 use auxdata::{AuxiliaryData, TransactionMetadatum};
 
+use assets::NonZeroInt64;
 use certs::{Certificate, Relay, StakeCredential};
-use cml_chain::assets::NonZeroInt64;
 pub use cml_chain::{assets::Coin, Epoch};
 use cml_core::ordered_hash_map::OrderedHashMap;
 use cml_crypto_wasm::{Ed25519KeyHash, ScriptHash};
@@ -77,7 +78,6 @@ pub type NonemptySetVkeywitness = VkeywitnessList;
 
 pub type RequiredSigners = Ed25519KeyHashList;
 
-//extern crate serde_wasm_bindgen;
 // Code below here was code-generated using an experimental CDDL to rust tool:
 // https://github.com/dcSpark/cddl-codegen
 
@@ -202,11 +202,9 @@ impl_wasm_list!(
 
 #[derive(Clone, Debug)]
 #[wasm_bindgen]
-pub struct MapAssetNameToNonZeroInt64(
-    OrderedHashMap<cml_chain::assets::AssetName, cml_chain::assets::NonZeroInt64>,
-);
+pub struct MapAssetNameToNonZeroInt64(OrderedHashMap<cml_chain::assets::AssetName, i64>);
 
-impl_wasm_conversions!(OrderedHashMap<cml_chain::assets::AssetName, cml_chain::assets::NonZeroInt64>, MapAssetNameToNonZeroInt64);
+impl_wasm_conversions!(OrderedHashMap<cml_chain::assets::AssetName, i64>, MapAssetNameToNonZeroInt64);
 
 #[wasm_bindgen]
 impl MapAssetNameToNonZeroInt64 {
@@ -218,8 +216,14 @@ impl MapAssetNameToNonZeroInt64 {
         self.0.len()
     }
 
-    pub fn insert(&mut self, key: &AssetName, value: NonZeroInt64) -> Option<NonZeroInt64> {
-        self.0.insert(key.clone().into(), value)
+    /// Throws if {value} is 0
+    pub fn insert(
+        &mut self,
+        key: &AssetName,
+        value: NonZeroInt64,
+    ) -> Result<Option<NonZeroInt64>, JsError> {
+        let value = cml_chain::assets::NonZeroInt64::try_new(value)?;
+        Ok(self.0.insert(key.clone().into(), value.get()))
     }
 
     pub fn get(&self, key: &AssetName) -> Option<NonZeroInt64> {
@@ -1011,10 +1015,10 @@ impl Script {
 
 #[wasm_bindgen]
 pub enum ScriptKind {
-    Native,
-    PlutusV1,
-    PlutusV2,
-    PlutusV3,
+    Native = 0,
+    PlutusV1 = 1,
+    PlutusV2 = 2,
+    PlutusV3 = 3,
 }
 
 pub type Slot = u64;
@@ -209,11 +209,11 @@ impl PlutusData {
 
 #[wasm_bindgen]
 pub enum PlutusDataKind {
-    ConstrPlutusData,
-    Map,
-    List,
-    Integer,
-    Bytes,
+    ConstrPlutusData = 0,
+    Map = 1,
+    List = 2,
+    Integer = 3,
+    Bytes = 4,
 }
 
 #[derive(Clone, Debug)]
@@ -349,6 +349,6 @@ impl Redeemers {
 
 #[wasm_bindgen]
 pub enum RedeemersKind {
-    ArrLegacyRedeemer,
-    MapRedeemerKeyToRedeemerVal,
+    ArrLegacyRedeemer = 0,
+    MapRedeemerKeyToRedeemerVal = 1,
 }
@@ -1,10 +1,12 @@
 use crate::{
-    plutus::{PlutusData, Redeemers},
+    plutus::{CostModels, PlutusData, Redeemers},
     LegacyRedeemerList, PlutusDataList,
 };
 use cml_chain::plutus::Language;
+use cml_core::serialization::Deserialize;
 use cml_core_wasm::{
     impl_raw_bytes_api, impl_wasm_cbor_api, impl_wasm_cbor_json_api, impl_wasm_conversions,
+    impl_wasm_list, DeserializeLimits,
 };
 use cml_crypto_wasm::ScriptHash;
 use wasm_bindgen::prelude::{wasm_bindgen, JsError};
@@ -39,6 +41,56 @@ impl ConstrPlutusData {
 
 #[wasm_bindgen]
 impl PlutusData {
+    /**
+     * Same as from_cbor_bytes() but rejects CBOR exceeding {limits} before deserializing it.
+     * Use this for untrusted input e.g. datums from arbitrary transactions.
+     */
+    pub fn from_cbor_bytes_with_limits(
+        cbor_bytes: &[u8],
+        limits: &DeserializeLimits,
+    ) -> Result<PlutusData, JsError> {
+        cml_chain::plutus::PlutusData::from_cbor_bytes_with_limits(cbor_bytes, limits.as_ref())
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
+    pub fn new_integer_from_i64(integer: i64) -> Self {
+        cml_chain::plutus::PlutusData::new_integer_from_i64(integer).into()
+    }
+
+    pub fn new_integer_from_u64(integer: u64) -> Self {
+        cml_chain::plutus::PlutusData::new_integer_from_u64(integer).into()
+    }
+
+    /// Creates an integer datum from its decimal string representation (e.g. "-12345")
+    /// Useful for values outside of the 64-bit range as these are encoded as CBOR bignums
+    pub fn new_integer_from_str(integer: &str) -> Result<PlutusData, JsError> {
+        cml_chain::plutus::PlutusData::new_integer_from_str(integer)
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
+    /// Returns None if this is not an integer datum or the value does not fit in an i64
+    pub fn as_i64(&self) -> Option<i64> {
+        self.0.as_i64()
+    }
+
+    /// Returns None if this is not an integer datum or the value does not fit in a u64
+    pub fn as_u64(&self) -> Option<u64> {
+        self.0.as_u64()
+    }
+
+    /// Number of nodes on the longest path from this datum to a leaf, including both ends
+    /// i.e. an integer or bytes datum on its own has a depth of 1
+    pub fn max_depth(&self) -> usize {
+        self.0.max_depth()
+    }
+
+    /// Number of datums contained in this one, including itself. Map keys and values both count.
+    pub fn total_nodes(&self) -> usize {
+        self.0.total_nodes()
+    }
+
     /**
      *  Convert to a Datum that will serialize equivalent to cardano-node's format
      *
@@ -57,6 +109,20 @@ impl PlutusData {
     pub fn to_cardano_node_format(&self) -> Self {
         self.0.to_cardano_node_format().into()
     }
+
+    /// Flat JSON for a CIP-68 (metadata, version, extra) datum with sorted keys.
+    /// Bytes are utf8 strings where printable and "0x"-prefixed hex otherwise.
+    /// Values without a flat form, as well as extra and any unknown fields, are written as {"@raw": "<cbor hex>"}
+    pub fn to_cip68_json(&self) -> Result<String, JsError> {
+        self.0.to_cip68_json().map_err(Into::into)
+    }
+
+    /// Creates a CIP-68 datum from the JSON format produced by to_cip68_json()
+    pub fn from_cip68_json(json: &str) -> Result<PlutusData, JsError> {
+        cml_chain::plutus::PlutusData::from_cip68_json(json)
+            .map(Into::into)
+            .map_err(Into::into)
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -167,6 +233,20 @@ impl PlutusV1Script {
     pub fn hash(&self) -> ScriptHash {
         self.0.hash().into()
     }
+
+    /**
+     * Parses script hex in whichever form it was given:
+     * * the flat-encoded program without any CBOR wrapping
+     * * the raw form used by from_hex() / to_hex() i.e. the flat program in a CBOR bytestring (e.g. compiledCode in Aiken's plutus.json)
+     * * the CBOR form used by from_cbor_hex() / to_cbor_hex() i.e. the raw form in another bytestring (e.g. cborHex in cardano-cli's text envelopes)
+     */
+    pub fn normalize(hex: &str) -> Result<PlutusV1Script, JsError> {
+        let bytes = hex::decode(hex)?;
+        Ok(cml_chain::plutus::PlutusV1Script::new(
+            cml_chain::plutus::utils::normalize_plutus_script_bytes(&bytes),
+        )
+        .into())
+    }
 }
 
 #[wasm_bindgen]
@@ -174,6 +254,20 @@ impl PlutusV2Script {
     pub fn hash(&self) -> ScriptHash {
         self.0.hash().into()
     }
+
+    /**
+     * Parses script hex in whichever form it was given:
+     * * the flat-encoded program without any CBOR wrapping
+     * * the raw form used by from_hex() / to_hex() i.e. the flat program in a CBOR bytestring (e.g. compiledCode in Aiken's plutus.json)
+     * * the CBOR form used by from_cbor_hex() / to_cbor_hex() i.e. the raw form in another bytestring (e.g. cborHex in cardano-cli's text envelopes)
+     */
+    pub fn normalize(hex: &str) -> Result<PlutusV2Script, JsError> {
+        let bytes = hex::decode(hex)?;
+        Ok(cml_chain::plutus::PlutusV2Script::new(
+            cml_chain::plutus::utils::normalize_plutus_script_bytes(&bytes),
+        )
+        .into())
+    }
 }
 
 #[wasm_bindgen]
@@ -181,6 +275,20 @@ impl PlutusV3Script {
     pub fn hash(&self) -> ScriptHash {
         self.0.hash().into()
     }
+
+    /**
+     * Parses script hex in whichever form it was given:
+     * * the flat-encoded program without any CBOR wrapping
+     * * the raw form used by from_hex() / to_hex() i.e. the flat program in a CBOR bytestring (e.g. compiledCode in Aiken's plutus.json)
+     * * the CBOR form used by from_cbor_hex() / to_cbor_hex() i.e. the raw form in another bytestring (e.g. cborHex in cardano-cli's text envelopes)
+     */
+    pub fn normalize(hex: &str) -> Result<PlutusV3Script, JsError> {
+        let bytes = hex::decode(hex)?;
+        Ok(cml_chain::plutus::PlutusV3Script::new(
+            cml_chain::plutus::utils::normalize_plutus_script_bytes(&bytes),
+        )
+        .into())
+    }
 }
 
 impl_raw_bytes_api!(cml_chain::plutus::PlutusV1Script, PlutusV1Script);
@@ -204,11 +312,45 @@ impl ExUnits {
             .map(Into::into)
             .map_err(Into::into)
     }
+
+    /// Splits this budget into n parts as evenly as possible. The parts always sum to exactly this budget.
+    pub fn split_evenly(&self, n: usize) -> ExUnitsList {
+        self.0.split_evenly(n).into()
+    }
 }
 
+impl_wasm_list!(cml_chain::plutus::ExUnits, ExUnits, ExUnitsList);
+
 #[wasm_bindgen]
 pub fn compute_total_ex_units(redeemers: &Redeemers) -> Result<ExUnits, JsError> {
     cml_chain::plutus::utils::compute_total_ex_units(redeemers.to_flat_format().as_ref())
         .map(Into::into)
         .map_err(Into::into)
 }
+
+/// The cost model parameter names for the language in ledger order, as of the given major protocol version
+#[wasm_bindgen]
+pub fn cost_model_param_names(language: Language, protocol_version: u64) -> Vec<String> {
+    cml_chain::plutus::cost_model_param_names(language, protocol_version)
+        .iter()
+        .map(|name| name.to_string())
+        .collect()
+}
+
+#[wasm_bindgen]
+impl CostModels {
+    /// JSON object keyed by language (e.g. "PlutusV1") where each cost model maps parameter names to values
+    pub fn to_named_json(&self) -> Result<String, JsError> {
+        let json = self.0.to_named_json()?;
+        serde_json::to_string(&json).map_err(Into::into)
+    }
+
+    /// Parses the to_named_json() format. Parameters may be in any order (e.g. alphabetized)
+    /// and are placed by their ledger position.
+    pub fn from_named_json(json: &str) -> Result<CostModels, JsError> {
+        let json: serde_json::Value = serde_json::from_str(json)?;
+        cml_chain::plutus::CostModels::from_named_json(&json)
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+}
@@ -18,7 +18,7 @@ use cml_crypto_wasm::{
     AuxiliaryDataHash, DatumHash, Ed25519KeyHash, ScriptDataHash, TransactionHash,
 };
 
-use wasm_bindgen::prelude::wasm_bindgen;
+use wasm_bindgen::prelude::{wasm_bindgen, JsError};
 
 pub mod utils;
 
@@ -149,8 +149,8 @@ impl DatumOption {
 
 #[wasm_bindgen]
 pub enum DatumOptionKind {
-    Hash,
-    Datum,
+    Hash = 0,
+    Datum = 1,
 }
 
 #[derive(Clone, Debug)]
@@ -270,12 +270,12 @@ impl NativeScript {
 
 #[wasm_bindgen]
 pub enum NativeScriptKind {
-    ScriptPubkey,
-    ScriptAll,
-    ScriptAny,
-    ScriptNOfK,
-    ScriptInvalidBefore,
-    ScriptInvalidHereafter,
+    ScriptPubkey = 0,
+    ScriptAll = 1,
+    ScriptAny = 2,
+    ScriptNOfK = 3,
+    ScriptInvalidBefore = 4,
+    ScriptInvalidHereafter = 5,
 }
 
 #[derive(Clone, Debug)]
@@ -622,12 +622,14 @@ impl TransactionBody {
         self.0.current_treasury_value
     }
 
-    pub fn set_donation(&mut self, donation: PositiveCoin) {
-        self.0.donation = Some(donation)
+    /// Throws if {donation} is 0
+    pub fn set_donation(&mut self, donation: PositiveCoin) -> Result<(), JsError> {
+        self.0.donation = Some(cml_chain::assets::PositiveCoin::try_new(donation)?);
+        Ok(())
     }
 
     pub fn donation(&self) -> Option<PositiveCoin> {
-        self.0.donation
+        self.0.donation.map(Into::into)
     }
 
     pub fn new(inputs: &SetTransactionInput, outputs: &TransactionOutputList, fee: Coin) -> Self {
@@ -723,8 +725,8 @@ impl TransactionOutput {
 
 #[wasm_bindgen]
 pub enum TransactionOutputKind {
-    AlonzoFormatTxOut,
-    ConwayFormatTxOut,
+    AlonzoFormatTxOut = 0,
+    ConwayFormatTxOut = 1,
 }
 
 #[derive(Clone, Debug)]
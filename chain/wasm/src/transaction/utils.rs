@@ -1,14 +1,63 @@
+use std::convert::TryFrom;
+
 use crate::{
     address::Address,
+    auxdata::AuxiliaryData,
+    fees::LinearFee,
+    plutus::{ExUnitPrices, Redeemers},
     transaction::{DatumOption, ScriptRef, TransactionOutput},
-    utils::LanguageList,
-    Ed25519KeyHashList, NativeScript, Value,
+    utils::{LanguageList, ScriptList},
+    Coin, Ed25519KeyHashList, NativeScript, PlutusDataList, Value,
 };
-use cml_core::Slot;
+use cml_chain::plutus::Language;
+use cml_chain::transaction::utils::TxSubmitError as RustTxSubmitError;
+use cml_core::{serialization::Deserialize, Slot};
+use cml_core_wasm::{impl_wasm_conversions, impl_wasm_json_api, DeserializeLimits};
 use cml_crypto_wasm::{DatumHash, ScriptHash};
-use wasm_bindgen::prelude::wasm_bindgen;
+use wasm_bindgen::prelude::{wasm_bindgen, JsError, JsValue};
+
+use super::{AlonzoFormatTxOut, Transaction, TransactionBody, TransactionWitnessSet};
+
+#[wasm_bindgen]
+impl TransactionBody {
+    /// Sets auxiliary_data_hash to the hash of {auxiliary_data} as it is serialized
+    /// (keeping its original bytes/encoding details) e.g. after another tool attached metadata.
+    /// Note: this changes the tx hash so any existing signatures will be invalidated.
+    pub fn set_auxiliary_data_hash_from(&mut self, auxiliary_data: &AuxiliaryData) {
+        self.0.set_auxiliary_data_hash_from(auxiliary_data.as_ref())
+    }
+
+    /// Whether auxiliary_data_hash is set and matches {auxiliary_data} as it is serialized
+    pub fn verify_auxiliary_data(&self, auxiliary_data: &AuxiliaryData) -> bool {
+        self.0.verify_auxiliary_data(auxiliary_data.as_ref())
+    }
 
-use super::TransactionWitnessSet;
+    /// Returns a copy with {fee} as the fee.
+    /// Only the fee's own encoding details are dropped so the rest of the body re-serializes
+    /// exactly as it was read e.g. for re-signing after a fee bump.
+    /// Note: this changes the tx hash so any existing signatures will be invalidated.
+    pub fn with_fee(&self, fee: Coin) -> TransactionBody {
+        self.0.clone().with_fee(fee).into()
+    }
+
+    /// Returns a copy with {ttl} as the ttl. See with_fee()
+    pub fn with_ttl(&self, ttl: Slot) -> TransactionBody {
+        self.0.clone().with_ttl(ttl).into()
+    }
+
+    /// Returns a copy with {validity_start} as the validity_interval_start. See with_fee()
+    pub fn with_validity_start(&self, validity_start: Slot) -> TransactionBody {
+        self.0.clone().with_validity_start(validity_start).into()
+    }
+
+    /// Which optional fields of the body are present (has_mint, has_certs, has_votes, ...) along with
+    /// counts for some of them e.g. to drive "this transaction does X" warnings when signing.
+    /// has_native_scripts is always false as those are only in the witness set (see Transaction.feature_flags()).
+    pub fn feature_flags(&self) -> Result<JsValue, JsError> {
+        cml_core_wasm::js_value::to_js_value(&self.0.feature_flags())
+            .map_err(|e| JsError::new(&format!("TransactionBody::feature_flags: {}", e)))
+    }
+}
 
 #[wasm_bindgen]
 impl TransactionOutput {
@@ -55,6 +104,19 @@ impl TransactionOutput {
     }
 }
 
+#[wasm_bindgen]
+impl AlonzoFormatTxOut {
+    /// Down-converts e.g. builder outputs for pre-Babbage eras. Datum hashes are kept.
+    /// Throws if the output has an inline datum or a script reference.
+    pub fn from_transaction_output(
+        output: &TransactionOutput,
+    ) -> Result<AlonzoFormatTxOut, JsError> {
+        cml_chain::transaction::AlonzoFormatTxOut::try_from(output.as_ref().clone())
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+}
+
 // TODO: anything here? pub type RequiredSignersSet = BTreeSet<Ed25519KeyHash>;
 
 #[wasm_bindgen]
@@ -78,6 +140,37 @@ impl NativeScript {
     ) -> bool {
         self.0.verify(lower_bound, upper_bound, key_hashes.as_ref())
     }
+
+    /// Every minimal set of signers (as arrays of hex key hashes) that can satisfy this script
+    /// for a tx with the given validity interval, or null if it can't be satisfied.
+    /// Throws if more than {max_sets} candidate sets would have to be considered.
+    pub fn min_signers_within(
+        &self,
+        lower_bound: Option<Slot>,
+        upper_bound: Option<Slot>,
+        max_sets: usize,
+    ) -> Result<JsValue, JsError> {
+        let signer_sets = self
+            .0
+            .min_signers_within(lower_bound, upper_bound, max_sets)
+            .map_err(|e| JsError::new(&format!("NativeScript::min_signers_within: {}", e)))?;
+        cml_core_wasm::js_value::to_js_value(&signer_sets)
+            .map_err(|e| JsError::new(&format!("NativeScript::min_signers_within: {}", e)))
+    }
+
+    /// Parses the JSON format used by cardano-cli for simple scripts e.g.
+    /// {"type": "atLeast", "required": 2, "scripts": [{"type": "sig", "keyHash": "..."}, ...]}
+    /// Supported types are "sig", "all", "any", "atLeast", "after" and "before".
+    pub fn from_cli_json(json: &str) -> Result<NativeScript, JsError> {
+        cml_chain::transaction::NativeScript::from_cli_json(json)
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
+    /// Converts to the JSON format used by cardano-cli for simple scripts.
+    pub fn to_cli_json(&self) -> Result<String, JsError> {
+        self.0.to_cli_json().map_err(Into::into)
+    }
 }
 
 #[wasm_bindgen]
@@ -86,7 +179,302 @@ impl TransactionWitnessSet {
         self.0.add_all_witnesses(other.clone().into());
     }
 
+    /// Removes all vkey witnesses whose key hash isn't in {keys} e.g. to drop foreign or stale
+    /// signatures before forwarding a tx to the next signer. Other encodings are preserved.
+    pub fn retain_vkeys(&mut self, keys: &Ed25519KeyHashList) {
+        let keys: &Vec<cml_crypto::Ed25519KeyHash> = keys.as_ref();
+        self.0.retain_vkeys(&keys.iter().cloned().collect());
+    }
+
     pub fn languages(&self) -> LanguageList {
         self.0.languages().into()
     }
+
+    /// The parts of the witness set covered by the script data (integrity) hash
+    /// with their original encodings. See ScriptIntegrityView.
+    pub fn script_integrity_view(&self) -> ScriptIntegrityView {
+        let (redeemers, plutus_datums) = self.0.script_integrity_view();
+        ScriptIntegrityView {
+            redeemers,
+            plutus_datums,
+        }
+    }
+
+    /// All native and plutus scripts in the witness set in that order
+    pub fn all_scripts(&self) -> ScriptList {
+        self.0.all_scripts().into()
+    }
+}
+
+/// What feeds the script data hash of a witness set:
+/// the redeemers (an empty map if there are none) and the plutus datums if there are any.
+#[derive(Clone, Debug)]
+#[wasm_bindgen]
+pub struct ScriptIntegrityView {
+    redeemers: cml_chain::plutus::Redeemers,
+    plutus_datums: Option<cml_chain::NonemptySetPlutusData>,
+}
+
+#[wasm_bindgen]
+impl ScriptIntegrityView {
+    pub fn redeemers(&self) -> Redeemers {
+        self.redeemers.clone().into()
+    }
+
+    pub fn plutus_datums(&self) -> Option<PlutusDataList> {
+        self.plutus_datums.clone().map(Into::into)
+    }
+}
+
+/// Structural differences between two transactions as found by Transaction.diff()
+/// Each difference has the path of the field in the tx's JSON and a summary of both sides.
+#[derive(Clone, Debug)]
+#[wasm_bindgen]
+pub struct TransactionDiff(cml_chain::transaction::utils::TransactionDiff);
+
+impl_wasm_json_api!(TransactionDiff);
+
+impl_wasm_conversions!(
+    cml_chain::transaction::utils::TransactionDiff,
+    TransactionDiff
+);
+
+#[wasm_bindgen]
+impl TransactionDiff {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.differences.len()
+    }
+}
+
+#[wasm_bindgen]
+impl Transaction {
+    /**
+     * Same as from_cbor_bytes() but rejects CBOR exceeding {limits} before deserializing it.
+     * Use this for untrusted input e.g. user-submitted transactions.
+     */
+    pub fn from_cbor_bytes_with_limits(
+        cbor_bytes: &[u8],
+        limits: &DeserializeLimits,
+    ) -> Result<Transaction, JsError> {
+        cml_chain::transaction::Transaction::from_cbor_bytes_with_limits(
+            cbor_bytes,
+            limits.as_ref(),
+        )
+        .map(Into::into)
+        .map_err(Into::into)
+    }
+
+    /**
+     * Counts and sizes of the tx's contents as a JS object e.g. for dashboards or checking how close a tx is to the limits.
+     * Includes input/output/cert counts, witness counts, script and datum counts/sizes,
+     * total redeemer ExUnits and the serialized size.
+     */
+    pub fn stats(&self) -> Result<JsValue, JsError> {
+        cml_core_wasm::js_value::to_js_value(&self.0.stats())
+            .map_err(|e| JsError::new(&format!("Transaction::stats: {}", e)))
+    }
+
+    /// Same as TransactionBody.feature_flags() but also takes the witness set into account
+    /// for has_plutus and has_native_scripts.
+    pub fn feature_flags(&self) -> Result<JsValue, JsError> {
+        cml_core_wasm::js_value::to_js_value(&self.0.feature_flags())
+            .map_err(|e| JsError::new(&format!("Transaction::feature_flags: {}", e)))
+    }
+
+    /**
+     * Same as stats() but also includes the minimum fee (min_fee) for the tx as-is.
+     * * `total_ref_script_size` - Total size of all scripts in the reference inputs
+     */
+    pub fn stats_with_min_fee(
+        &self,
+        linear_fee: &LinearFee,
+        ex_unit_prices: &ExUnitPrices,
+        total_ref_script_size: u64,
+    ) -> Result<JsValue, JsError> {
+        let stats = self.0.stats_with_min_fee(
+            linear_fee.as_ref(),
+            ex_unit_prices.as_ref(),
+            total_ref_script_size,
+        )?;
+        cml_core_wasm::js_value::to_js_value(&stats)
+            .map_err(|e| JsError::new(&format!("Transaction::stats_with_min_fee: {}", e)))
+    }
+
+    /**
+     * Minimum plutus language whose script context can observe all features this tx uses
+     * (e.g. reference inputs need PlutusV2, conway certs/voting need PlutusV3).
+     * Undefined if PlutusV1 can see everything.
+     */
+    pub fn required_plutus_language_floor(&self) -> Option<Language> {
+        self.0.required_plutus_language_floor()
+    }
+
+    /**
+     * Array of {script_hash, language, unsupported_features} for each plutus script in the
+     * witness set that can't observe all features of this tx and would fail phase-2 validation.
+     */
+    pub fn plutus_language_warnings(&self) -> Result<JsValue, JsError> {
+        cml_core_wasm::js_value::to_js_value(&self.0.plutus_language_warnings())
+            .map_err(|e| JsError::new(&format!("Transaction::plutus_language_warnings: {}", e)))
+    }
+
+    /// Structural diff against {other} e.g. to find what another tool changed in a tx being co-signed.
+    /// Encoding details (CBOR formats, original bytes, etc) are ignored.
+    pub fn diff(&self, other: &Transaction) -> TransactionDiff {
+        self.0.diff(other.as_ref()).into()
+    }
+
+    /// The unsigned form of this tx: all vkey and bootstrap witnesses are removed while the body,
+    /// is_valid, auxiliary data and the remaining witnesses are kept with their encodings.
+    pub fn without_witnesses(&self) -> Transaction {
+        self.0.without_witnesses().into()
+    }
+
+    /// Replaces the witness set without touching any other part of the tx (or its encodings),
+    /// returning the previous witness set.
+    pub fn replace_witness_set(
+        &mut self,
+        witness_set: &TransactionWitnessSet,
+    ) -> TransactionWitnessSet {
+        self.0
+            .replace_witness_set(witness_set.clone().into())
+            .into()
+    }
+
+    /**
+     * Returns a copy of this tx with {body} as the body, keeping the encodings of everything else
+     * e.g. after TransactionBody.with_fee() since body() only returns a copy.
+     * Note: changing the body changes the tx hash so all existing vkey/bootstrap witnesses become invalid.
+     * With {strip_witnesses} they are removed (see without_witnesses()) so the tx can be re-signed.
+     */
+    pub fn with_body(&self, body: &TransactionBody, strip_witnesses: bool) -> Transaction {
+        self.0
+            .with_body(body.clone().into(), strip_witnesses)
+            .into()
+    }
+
+    /// Returns a copy of this tx with {witness_set} as the witness set. See replace_witness_set()
+    pub fn with_witness_set(&self, witness_set: &TransactionWitnessSet) -> Transaction {
+        self.0.with_witness_set(witness_set.clone().into()).into()
+    }
+
+    /**
+     * Returns a copy of this tx with {auxiliary_data} as the auxiliary data.
+     * The body's auxiliary_data_hash is not updated, use TransactionBody.set_auxiliary_data_hash_from()
+     * and with_body() for that (which also invalidates existing signatures).
+     */
+    pub fn with_auxiliary_data(&self, auxiliary_data: Option<AuxiliaryData>) -> Transaction {
+        self.0
+            .with_auxiliary_data(auxiliary_data.map(Into::into))
+            .into()
+    }
+
+    /// Request body for cardano-submit-api (Content-Type: application/cbor).
+    /// These are the tx's CBOR bytes with its original encoding preserved so signatures stay valid.
+    pub fn to_submit_api_bytes(&self) -> Vec<u8> {
+        self.0.to_submit_api_bytes()
+    }
+
+    /// Request body for Ogmios' (v6) submitTransaction JSON-RPC method
+    pub fn to_ogmios_submit_json(&self) -> String {
+        self.0.to_ogmios_submit_json()
+    }
+}
+
+/// Reason a node rejected a submitted transaction.
+/// Only the most common rejections are typed, all others are of kind Other.
+#[derive(Clone, Debug)]
+#[wasm_bindgen]
+pub struct TxSubmitError(RustTxSubmitError);
+
+impl_wasm_conversions!(RustTxSubmitError, TxSubmitError);
+
+#[wasm_bindgen]
+pub enum TxSubmitErrorKind {
+    EraMismatch = 0,
+    MissingVkeyWitnesses = 1,
+    ValueNotConserved = 2,
+    Other = 3,
+}
+
+#[wasm_bindgen]
+impl TxSubmitError {
+    /// Parses an Ogmios (v6) submitTransaction error response.
+    /// Throws if it isn't JSON or has no error code (e.g. the submission succeeded).
+    pub fn from_ogmios_response(response: &str) -> Result<TxSubmitError, JsError> {
+        RustTxSubmitError::from_ogmios_response(response)
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
+    pub fn kind(&self) -> TxSubmitErrorKind {
+        match &self.0 {
+            RustTxSubmitError::EraMismatch { .. } => TxSubmitErrorKind::EraMismatch,
+            RustTxSubmitError::MissingVkeyWitnesses(_) => TxSubmitErrorKind::MissingVkeyWitnesses,
+            RustTxSubmitError::ValueNotConserved { .. } => TxSubmitErrorKind::ValueNotConserved,
+            RustTxSubmitError::Other { .. } => TxSubmitErrorKind::Other,
+        }
+    }
+
+    pub fn ogmios_code(&self) -> i64 {
+        self.0.ogmios_code()
+    }
+
+    /// Human readable description (the Ogmios message for kind Other)
+    pub fn message(&self) -> String {
+        match &self.0 {
+            RustTxSubmitError::Other { message, .. } => message.clone(),
+            error => error.to_string(),
+        }
+    }
+
+    pub fn query_era(&self) -> Option<String> {
+        match &self.0 {
+            RustTxSubmitError::EraMismatch { query_era, .. } => Some(query_era.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn ledger_era(&self) -> Option<String> {
+        match &self.0 {
+            RustTxSubmitError::EraMismatch { ledger_era, .. } => Some(ledger_era.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn missing_vkey_witnesses(&self) -> Option<Ed25519KeyHashList> {
+        match &self.0 {
+            RustTxSubmitError::MissingVkeyWitnesses(key_hashes) => Some(key_hashes.clone().into()),
+            _ => None,
+        }
+    }
+
+    pub fn consumed(&self) -> Option<Value> {
+        match &self.0 {
+            RustTxSubmitError::ValueNotConserved { consumed, .. } => Some(consumed.clone().into()),
+            _ => None,
+        }
+    }
+
+    pub fn produced(&self) -> Option<Value> {
+        match &self.0 {
+            RustTxSubmitError::ValueNotConserved { produced, .. } => Some(produced.clone().into()),
+            _ => None,
+        }
+    }
+
+    /// The error's data as a JS object for kind Other (if present)
+    pub fn data(&self) -> Result<JsValue, JsError> {
+        match &self.0 {
+            RustTxSubmitError::Other {
+                data: Some(data), ..
+            } => cml_core_wasm::js_value::to_js_value(data)
+                .map_err(|e| JsError::new(&format!("TxSubmitError::data: {}", e))),
+            _ => Ok(JsValue::UNDEFINED),
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use crate::SubCoin;
+use crate::{Coin, MapRewardAccountToCoin, RewardAccountList, SubCoin, UnitInterval};
 
 use super::{Int, Script, ScriptHash};
 use cml_chain::plutus::Language;
@@ -8,6 +8,8 @@ use cml_core_wasm::{impl_wasm_cbor_json_api, impl_wasm_conversions, impl_wasm_li
 
 impl_wasm_list!(Language, Language, LanguageList, true, true);
 
+impl_wasm_list!(cml_chain::Script, Script, ScriptList);
+
 #[wasm_bindgen]
 #[derive(Clone, Debug)]
 pub struct BigInteger(cml_chain::utils::BigInteger);
@@ -40,6 +42,12 @@ impl BigInteger {
         self.0.as_u64()
     }
 
+    /// Converts to an i64
+    /// Returns None if the number was too big (or small) for an i64
+    pub fn as_i64(&self) -> Option<i64> {
+        self.0.as_i64()
+    }
+
     /// Converts to an Int
     /// Returns None when the number is too big for an Int (outside +/- 64-bit unsigned)
     /// Retains encoding info if the original was encoded as an Int
@@ -99,6 +107,78 @@ impl SubCoin {
     }
 }
 
+#[wasm_bindgen]
+impl UnitInterval {
+    /// Approximate value as a float e.g. for display. Not exact so don't use it for ledger rules.
+    pub fn to_f64(&self) -> f64 {
+        self.0.to_f64()
+    }
+}
+
+#[wasm_bindgen]
+impl MapRewardAccountToCoin {
+    /// Sum of all withdrawal amounts. Errors on overflow.
+    pub fn total(&self) -> Result<Coin, JsError> {
+        cml_chain::utils::withdrawals_total(self.as_ref()).map_err(Into::into)
+    }
+
+    /// Inserts a withdrawal from a bech32 reward address (e.g. stake1...).
+    /// Returns the previous amount for that reward account if there was one.
+    pub fn insert_bech32(&mut self, addr: &str, coin: Coin) -> Result<Option<Coin>, JsError> {
+        cml_chain::utils::withdrawals_insert_bech32(&mut self.0, addr, coin).map_err(Into::into)
+    }
+
+    /// Looks up the withdrawal amount of a bech32 reward address (e.g. stake1...).
+    pub fn get_bech32(&self, addr: &str) -> Result<Option<Coin>, JsError> {
+        cml_chain::utils::withdrawals_get_bech32(self.as_ref(), addr).map_err(Into::into)
+    }
+
+    pub fn entries(&self) -> RewardAccountCoinEntries {
+        let (reward_accounts, coins) = self.0.iter().map(|(k, v)| (k.clone(), *v)).unzip();
+        RewardAccountCoinEntries {
+            reward_accounts,
+            coins,
+        }
+    }
+}
+
+/// All entries of a MapRewardAccountToCoin as parallel arrays.
+/// The i-th coin corresponds to the i-th reward account.
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct RewardAccountCoinEntries {
+    reward_accounts: Vec<cml_chain::address::RewardAccount>,
+    coins: Vec<Coin>,
+}
+
+#[wasm_bindgen]
+impl RewardAccountCoinEntries {
+    pub fn reward_accounts(&self) -> RewardAccountList {
+        self.reward_accounts.clone().into()
+    }
+
+    /// Reward accounts encoded as bech32 using the default prefix for their network.
+    pub fn bech32_addresses(&self) -> Result<Vec<String>, JsError> {
+        self.reward_accounts
+            .iter()
+            .map(|reward_account| reward_account.clone().to_address().to_bech32(None))
+            .collect::<Result<_, _>>()
+            .map_err(Into::into)
+    }
+
+    pub fn coins(&self) -> Vec<Coin> {
+        self.coins.clone()
+    }
+
+    pub fn len(&self) -> usize {
+        self.coins.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.coins.is_empty()
+    }
+}
+
 // we provide direct From/Into conversions between NonemptySet<T> and TList
 // to allow the auto-generated code to work directly without changes
 macro_rules! impl_wasm_conversions_into {
@@ -165,3 +245,13 @@ impl_wasm_conversions_into!(
     cml_chain::SetCommitteeColdCredential,
     crate::CommitteeColdCredentialList
 );
+
+/**
+ * Computes reference values (datum hashes, script hashes/addresses, policy ids,
+ * asset fingerprints and stake addresses) for a JSON description of inputs.
+ * See cml_chain::utils::vectors::generate_report for the input/output format.
+ */
+#[wasm_bindgen]
+pub fn generate_test_vectors_report(json_input: &str) -> Result<String, JsError> {
+    cml_chain::utils::vectors::generate_report(json_input).map_err(Into::into)
+}
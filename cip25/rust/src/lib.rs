@@ -13,6 +13,7 @@ use cbor_event::se::Serializer;
 use cbor_event::Special as CBORSpecial;
 use cbor_event::Type as CBORType;
 pub use cml_core::error::*;
+use cml_core::serialization::StripEncodings;
 use std::convert::{From, TryFrom};
 use std::io::{BufRead, Write};
 
@@ -31,7 +32,9 @@ use std::io::{BufRead, Write};
 ///     },
 /// }
 /// ```
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub enum CIP25ChunkableString {
     Single(CIP25String64),
     Chunked(Vec<CIP25String64>),
@@ -51,7 +54,9 @@ impl CIP25ChunkableString {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct CIP25FilesDetails {
     pub name: CIP25String64,
     pub media_type: CIP25String64,
@@ -72,7 +77,9 @@ impl CIP25FilesDetails {
 /// It can be parsed by passing in the CBOR bytes of the entire transaction metadata
 /// or by passing in an existing Metadata struct.
 /// Parsing from CBOR bytes should be marginally faster.
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct CIP25Metadata {
     /// The core details of the CIP25 spec
     pub key_721: CIP25LabelMetadata,
@@ -84,7 +91,9 @@ impl CIP25Metadata {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct CIP25MetadataDetails {
     pub name: CIP25String64,
     pub image: CIP25ChunkableString,
@@ -105,7 +114,9 @@ impl CIP25MetadataDetails {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct CIP25String64(pub String);
 
 impl CIP25String64 {
@@ -215,6 +215,7 @@ pub enum CIP25Error {
     serde::Deserialize,
     serde::Serialize,
     schemars::JsonSchema,
+    StripEncodings,
 )]
 pub enum CIP25Version {
     /// Initial version of CIP25 with only string (utf8) asset names allowed.
@@ -223,7 +224,9 @@ pub enum CIP25Version {
     V2,
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct CIP25LabelMetadata {
     nfts: BTreeMap<PolicyId, BTreeMap<AssetName, CIP25MetadataDetails>>,
     version: CIP25Version,
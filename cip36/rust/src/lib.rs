@@ -6,7 +6,7 @@
 pub use cml_core::{
     error::{DeserializeError, DeserializeFailure},
     ordered_hash_map::OrderedHashMap,
-    serialization::{Deserialize, LenEncoding, Serialize, StringEncoding},
+    serialization::{Deserialize, LenEncoding, Serialize, StringEncoding, StripEncodings},
 };
 
 pub use cml_chain::{address::Address, auxdata::Metadata, NetworkId};
@@ -47,7 +47,9 @@ pub type CIP36Weight = u32;
 /// Weighted delegation input.
 /// This is the proportion of weight to assign to this public key relative to the weights
 /// of all other Delegations where this is used.
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct CIP36Delegation {
     pub voting_pub_key: CIP36VotingPubKey,
     pub weight: CIP36Weight,
@@ -65,7 +67,9 @@ impl CIP36Delegation {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub enum CIP36DelegationDistribution {
     Weighted {
         delegations: Vec<CIP36Delegation>,
@@ -106,7 +110,9 @@ pub struct CIP36DeregistrationCbor {
     pub deregistration_witness: CIP36DeregistrationWitness,
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct CIP36DeregistrationWitness {
     pub stake_witness: CIP36StakeWitness,
     #[serde(skip)]
@@ -122,7 +128,9 @@ impl CIP36DeregistrationWitness {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct CIP36KeyDeregistration {
     pub stake_credential: CIP36StakeCredential,
     pub nonce: CIP36Nonce,
@@ -131,7 +139,9 @@ pub struct CIP36KeyDeregistration {
     pub encodings: Option<CIP36KeyDeregistrationEncoding>,
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct CIP36KeyRegistration {
     pub delegation: CIP36DelegationDistribution,
     pub stake_credential: CIP36StakeCredential,
@@ -150,7 +160,9 @@ pub struct CIP36RegistrationCbor {
     pub registration_witness: CIP36RegistrationWitness,
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct CIP36RegistrationWitness {
     pub stake_witness: CIP36StakeWitness,
     #[serde(skip)]
@@ -0,0 +1,92 @@
+//! Derive macros for the traits in `cml_core::serialization`.
+//! These are re-exported from there so this crate should never have to be used directly.
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Fields};
+
+/// Derives `cml_core::serialization::StripEncodings`.
+///
+/// Encoding detail fields (`encodings`, `encoding` or anything ending in `_encoding(s)`) are
+/// reset to their `Default` and `strip_encodings()` is called on every other field.
+/// Generic type parameters must implement `StripEncodings` themselves.
+#[proc_macro_derive(StripEncodings)]
+pub fn derive_strip_encodings(input: TokenStream) -> TokenStream {
+    let mut input = parse_macro_input!(input as DeriveInput);
+    for param in input.generics.type_params_mut() {
+        param
+            .bounds
+            .push(parse_quote!(cml_core::serialization::StripEncodings));
+    }
+    let arms = match &input.data {
+        Data::Struct(data) => vec![strip_fields(quote!(Self), &data.fields)],
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .map(|variant| {
+                let ident = &variant.ident;
+                strip_fields(quote!(Self::#ident), &variant.fields)
+            })
+            .collect(),
+        Data::Union(_) => {
+            return syn::Error::new_spanned(
+                &input.ident,
+                "StripEncodings can't be derived for unions",
+            )
+            .to_compile_error()
+            .into()
+        }
+    };
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    quote! {
+        impl #impl_generics cml_core::serialization::StripEncodings for #ident #ty_generics #where_clause {
+            fn strip_encodings(&mut self) {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+    }
+    .into()
+}
+
+fn is_encoding_field(name: &str) -> bool {
+    name == "encoding"
+        || name == "encodings"
+        || name.ends_with("_encoding")
+        || name.ends_with("_encodings")
+}
+
+/// Match arm for {path} (a struct or enum variant) with {fields} that strips all of them
+fn strip_fields(path: TokenStream2, fields: &Fields) -> TokenStream2 {
+    let bindings = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| match &field.ident {
+            Some(ident) => ident.clone(),
+            None => format_ident!("field_{}", i),
+        })
+        .collect::<Vec<_>>();
+    let stmts = fields.iter().zip(bindings.iter()).map(|(field, binding)| {
+        if field
+            .ident
+            .as_ref()
+            .is_some_and(|ident| is_encoding_field(&ident.to_string()))
+        {
+            quote!(*#binding = Default::default();)
+        } else {
+            quote!(cml_core::serialization::StripEncodings::strip_encodings(#binding);)
+        }
+    });
+    let pattern = match fields {
+        Fields::Named(_) => quote!(#path { #(#bindings),* }),
+        Fields::Unnamed(_) => quote!(#path ( #(#bindings),* )),
+        Fields::Unit => quote!(#path),
+    };
+    quote! {
+        #pattern => {
+            #(#stmts)*
+        }
+    }
+}
@@ -13,12 +13,33 @@ impl std::fmt::Display for Key {
     }
 }
 
+/// Which of the DeserializeLimits was exceeded
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LimitKind {
+    Depth,
+    CollectionLen,
+    Size,
+}
+
+impl std::fmt::Display for LimitKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LimitKind::Depth => write!(f, "nesting depth"),
+            LimitKind::CollectionLen => write!(f, "collection length"),
+            LimitKind::Size => write!(f, "size in bytes"),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum DeserializeFailure {
     BadAddressType(u8),
     BreakInDefiniteLen,
     CBOR(cbor_event::Error),
     DefiniteLenMismatch(u64, Option<u64>),
+    /// A map had the same key more than once. Map fields backed by OrderedHashMap (e.g.
+    /// withdrawals, mint, voting procedures) can't hold both entries so always fail with this.
+    /// Only metadata (Metadata / MetadatumMap) keeps duplicates as they exist on mainnet.
     DuplicateKey(Key),
     EndingBreakMissing,
     ExpectedNull,
@@ -28,6 +49,11 @@ pub enum DeserializeFailure {
     },
     /// Invalid internal structure imposed on top of the CBOR format
     InvalidStructure(Box<dyn std::error::Error>),
+    /// The CBOR exceeded one of the DeserializeLimits it was deserialized with
+    LimitExceeded {
+        limit: LimitKind,
+        max: u64,
+    },
     MandatoryFieldMissing(Key),
     NoVariantMatched,
     NoVariantMatchedWithCauses(Vec<DeserializeError>),
@@ -65,6 +91,16 @@ impl DeserializeError {
         }
     }
 
+    /// Where in the structure the failure happened (dot-separated path), if known
+    pub fn location(&self) -> Option<&str> {
+        self.location.as_deref()
+    }
+
+    /// The underlying reason the deserialization failed
+    pub fn failure(&self) -> &DeserializeFailure {
+        &self.failure
+    }
+
     pub fn annotate<T: Into<String>>(self, location: T) -> Self {
         match self.location {
             Some(loc) => Self::new(format!("{}.{}", location.into(), loc), self.failure),
@@ -106,6 +142,9 @@ impl DeserializeError {
             DeserializeFailure::InvalidStructure(e) => {
                 write!(f, "Invalid internal structure: {e}")
             }
+            DeserializeFailure::LimitExceeded { limit, max } => {
+                write!(f, "Exceeded the limit of {max} for {limit}")
+            }
             DeserializeFailure::MandatoryFieldMissing(key) => {
                 write!(f, "Mandatory field {key} not found")
             }
@@ -167,6 +206,15 @@ impl From<cbor_event::Error> for DeserializeError {
     }
 }
 
+/// Error from CborHex::from_cbor_hex() telling apart invalid hex from invalid CBOR
+#[derive(Debug, thiserror::Error)]
+pub enum CborHexError {
+    #[error("Invalid hex: {0}")]
+    Hex(#[from] hex::FromHexError),
+    #[error("Invalid CBOR: {0}")]
+    Cbor(#[from] DeserializeError),
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum ArithmeticError {
     #[error("Integer overflow")]
@@ -16,10 +16,14 @@ pub mod error;
 pub mod network;
 pub mod ordered_hash_map;
 pub mod serialization;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 
-use crate::serialization::{fit_sz, Deserialize, Serialize};
+use crate::serialization::{fit_sz, Deserialize, Serialize, StripEncodings};
 
 extern crate derivative;
+// lets our own derive macros (which refer to cml_core::...) be used within this crate
+extern crate self as cml_core;
 use derivative::Derivative;
 
 use cbor_event::{de::Deserializer, se::Serializer};
@@ -33,7 +37,7 @@ pub type TransactionIndex = u16;
 
 pub type CertificateIndex = u64;
 
-#[derive(Clone, Debug, Derivative)]
+#[derive(Clone, Debug, Derivative, StripEncodings)]
 #[derivative(
     Eq,
     PartialEq,
@@ -291,4 +295,74 @@ mod tests {
         let y = Int::from(-1i64);
         assert_eq!(x.to_canonical_cbor_bytes(), y.to_canonical_cbor_bytes());
     }
+
+    proptest::proptest! {
+        #[test]
+        fn int_roundtrip(x in crate::testing::arb_int()) {
+            crate::testing::roundtrip_cbor(&x)?;
+            crate::testing::roundtrip_json(&x)?;
+        }
+    }
+
+    #[test]
+    fn read_cbor_item_bytes_nested() {
+        // [_ {1: h'00', 2: "\xff"}, 24(-1), [_ (_ "a", "b"), 1.5]] followed by 0x01
+        let item = hex::decode("9fa20141000261ffd818209f7f61616162fff93e00ffff").unwrap();
+        let mut raw = cbor_event::de::Deserializer::from(std::io::Cursor::new(
+            [item.as_slice(), &[0x01]].concat(),
+        ));
+        assert_eq!(serialization::read_cbor_item_bytes(&mut raw).unwrap(), item);
+        assert_eq!(raw.unsigned_integer().unwrap(), 1);
+        // truncated
+        let mut raw = cbor_event::de::Deserializer::from(std::io::Cursor::new(&item[..5]));
+        assert!(serialization::read_cbor_item_bytes(&mut raw).is_err());
+    }
+
+    #[test]
+    fn cbor_limits() {
+        use serialization::{check_cbor_limits, DeserializeLimits};
+        fn exceeded(data: &[u8], limits: &DeserializeLimits) -> Option<LimitKind> {
+            match check_cbor_limits(data, limits) {
+                Ok(()) => None,
+                Err(e) => match e.failure() {
+                    DeserializeFailure::LimitExceeded { limit, .. } => Some(*limit),
+                    _ => panic!("unexpected failure: {}", e),
+                },
+            }
+        }
+        let limits = DeserializeLimits::new(3, 2, 16);
+        // [[24(1)]] / [[[]]] / [[[[]]]]
+        assert_eq!(exceeded(&hex::decode("8181d81801").unwrap(), &limits), None);
+        assert_eq!(exceeded(&hex::decode("818180").unwrap(), &limits), None);
+        assert_eq!(
+            exceeded(&hex::decode("81818180").unwrap(), &limits),
+            Some(LimitKind::Depth)
+        );
+        // {1: 2, 3: 4} / [_ 1, 2] / [1, 2, 3] / {_ 1: 2, 3: 4, 5: 6} / (_ h'00', h'00', h'00')
+        assert_eq!(exceeded(&hex::decode("a201020304").unwrap(), &limits), None);
+        assert_eq!(exceeded(&hex::decode("9f0102ff").unwrap(), &limits), None);
+        assert_eq!(
+            exceeded(&hex::decode("83010203").unwrap(), &limits),
+            Some(LimitKind::CollectionLen)
+        );
+        assert_eq!(
+            exceeded(&hex::decode("bf010203040506ff").unwrap(), &limits),
+            Some(LimitKind::CollectionLen)
+        );
+        assert_eq!(
+            exceeded(&hex::decode("5f410041004100ff").unwrap(), &limits),
+            Some(LimitKind::CollectionLen)
+        );
+        // an array claiming 2^64 - 1 elements is rejected without reading any of them
+        assert_eq!(
+            exceeded(
+                &hex::decode("9bffffffffffffffff").unwrap(),
+                &DeserializeLimits::default()
+            ),
+            Some(LimitKind::CollectionLen)
+        );
+        assert_eq!(exceeded(&[0x40 + 16; 17], &limits), Some(LimitKind::Size));
+        // malformed CBOR within the limits is still an error
+        assert!(check_cbor_limits(&hex::decode("8201").unwrap(), &limits).is_err());
+    }
 }
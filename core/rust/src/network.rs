@@ -1,4 +1,7 @@
-use crate::{error::DeserializeError, serialization::Deserialize};
+use crate::{
+    error::DeserializeError,
+    serialization::{Deserialize, StripEncodings},
+};
 use cbor_event::de::Deserializer;
 use cbor_event::se::Serializer;
 use schemars::JsonSchema;
@@ -28,6 +31,7 @@ impl std::fmt::Display for ProtocolMagic {
     serde::Serialize,
     serde::Deserialize,
     JsonSchema,
+    StripEncodings,
 )]
 pub struct ProtocolMagic(u32);
 
@@ -48,6 +48,35 @@ where
     pub fn take(self) -> linked_hash_map::LinkedHashMap<K, V> {
         self.0
     }
+
+    /// Gets the entry for {key} for in-place access without hashing twice.
+    /// Modifying an existing entry keeps its position while inserting a new one appends it.
+    pub fn entry(&mut self, key: K) -> linked_hash_map::Entry<'_, K, V> {
+        self.0.entry(key)
+    }
+
+    /// Keeps only the entries for which {f} returns true, preserving their order.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        let entries = std::mem::take(&mut self.0);
+        self.0 = entries
+            .into_iter()
+            .filter_map(|(k, mut v)| if f(&k, &mut v) { Some((k, v)) } else { None })
+            .collect();
+    }
+}
+
+/// Behaves like insert() for each pair i.e. new keys are appended and
+/// keys already present have their value replaced and are moved to the end.
+impl<K, V> Extend<(K, V)> for OrderedHashMap<K, V>
+where
+    K: Hash + Eq + Ord,
+{
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        self.0.extend(iter)
+    }
 }
 
 #[allow(clippy::derived_hash_with_manual_eq)]
@@ -115,3 +144,41 @@ where
         Self(linked_hash_map::LinkedHashMap::from_iter(iter))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(map: &OrderedHashMap<u32, u32>) -> Vec<u32> {
+        map.keys().copied().collect()
+    }
+
+    #[test]
+    fn entry_keeps_insertion_order() {
+        let mut map: OrderedHashMap<u32, u32> = vec![(3, 0), (1, 0)].into_iter().collect();
+        *map.entry(3).or_insert(0) += 5;
+        *map.entry(2).or_insert_with(|| 7) += 1;
+        assert_eq!(keys(&map), vec![3, 1, 2]);
+        assert_eq!(map.get(&3), Some(&5));
+        assert_eq!(map.get(&2), Some(&8));
+    }
+
+    #[test]
+    fn extend_and_retain() {
+        let mut map: OrderedHashMap<u32, u32> = vec![(5, 1), (4, 2)].into_iter().collect();
+        map.extend(vec![(1, 3), (5, 4), (2, 5)]);
+        assert_eq!(keys(&map), vec![4, 1, 5, 2]);
+        assert_eq!(map.get(&5), Some(&4));
+        map.retain(|k, v| {
+            *v += 10;
+            *k != 1
+        });
+        assert_eq!(keys(&map), vec![4, 5, 2]);
+        assert_eq!(map.values().copied().collect::<Vec<_>>(), vec![12, 14, 15]);
+        // serde is unaffected: JSON is still key-sorted
+        assert_eq!(
+            serde_json::to_string(&map).unwrap(),
+            r#"{"2":15,"4":12,"5":14}"#
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::error::{DeserializeError, DeserializeFailure};
+use crate::error::{CborHexError, DeserializeError, DeserializeFailure, LimitKind};
 use cbor_event::{de::Deserializer, se::Serializer, Sz};
 use std::io::{BufRead, Seek, Write};
 
@@ -85,6 +85,18 @@ pub fn sz_max(sz: cbor_event::Sz) -> u64 {
     }
 }
 
+/// Number of bytes taken by a CBOR header (initial byte and argument) of the given size
+#[inline]
+pub fn sz_header_len(sz: cbor_event::Sz) -> usize {
+    match sz {
+        Sz::Inline => 1,
+        Sz::One => 2,
+        Sz::Two => 3,
+        Sz::Four => 5,
+        Sz::Eight => 9,
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum LenEncoding {
     Canonical,
@@ -226,6 +238,83 @@ pub trait Serialize {
         self.serialize(&mut buf, true).unwrap();
         buf.finalize()
     }
+
+    /// Like to_canonical_cbor_bytes() but with a choice of map key ordering.
+    /// to_canonical_cbor_bytes() always uses CanonicalKeyOrder::Bytewise.
+    fn to_canonical_cbor_bytes_with_key_order(&self, key_order: CanonicalKeyOrder) -> Vec<u8> {
+        with_canonical_key_order(key_order, || self.to_canonical_cbor_bytes())
+    }
+
+    /// Length of to_cbor_bytes() without keeping the encoded bytes around
+    fn cbor_len(&self) -> usize {
+        let mut counter = Serializer::new(ByteCounter::default());
+        self.serialize(&mut counter, false).unwrap();
+        counter.finalize().0
+    }
+}
+
+/// How map keys are ordered when serializing with force_canonical
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CanonicalKeyOrder {
+    /// RFC 8949 §4.2.1 core deterministic encoding: bytewise lexicographic order of the
+    /// encoded keys. This is the default.
+    #[default]
+    Bytewise,
+    /// RFC 7049 §3.9 canonical CBOR: shorter encoded keys first, then bytewise.
+    /// Differs from Bytewise only when keys of different major types are mixed (e.g. int and
+    /// text metadata keys). Use this for compatibility with hardware wallet firmware
+    /// implementing CIP-21 which references this ordering.
+    LengthFirst,
+}
+
+impl CanonicalKeyOrder {
+    /// Compares two encoded map keys
+    pub fn cmp_keys(&self, lhs: &[u8], rhs: &[u8]) -> std::cmp::Ordering {
+        match self {
+            Self::Bytewise => lhs.cmp(rhs),
+            Self::LengthFirst => lhs.len().cmp(&rhs.len()).then_with(|| lhs.cmp(rhs)),
+        }
+    }
+}
+
+thread_local! {
+    static CANONICAL_KEY_ORDER: std::cell::Cell<CanonicalKeyOrder> =
+        std::cell::Cell::new(CanonicalKeyOrder::default());
+}
+
+/// Runs {f} with {key_order} used for all canonical map serialization on this thread
+/// e.g. to build a whole tx's canonical bytes with the legacy ordering.
+pub fn with_canonical_key_order<R>(key_order: CanonicalKeyOrder, f: impl FnOnce() -> R) -> R {
+    // restores the previous ordering even if {f} panics
+    struct Restore(CanonicalKeyOrder);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CANONICAL_KEY_ORDER.with(|order| order.set(self.0));
+        }
+    }
+    let _restore = Restore(CANONICAL_KEY_ORDER.with(|order| order.replace(key_order)));
+    f()
+}
+
+/// Compares two encoded map keys with the current canonical key ordering.
+/// Serialize impls sort by this when force_canonical is set. See with_canonical_key_order()
+pub fn canonical_key_cmp(lhs: &[u8], rhs: &[u8]) -> std::cmp::Ordering {
+    CANONICAL_KEY_ORDER.with(|order| order.get().cmp_keys(lhs, rhs))
+}
+
+/// Writer that only counts how many bytes were written to it
+#[derive(Debug, Default)]
+struct ByteCounter(usize);
+
+impl Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0 += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
 }
 
 pub trait SerializeEmbeddedGroup {
@@ -250,6 +339,566 @@ pub trait Deserialize {
         let mut raw = Deserializer::from(std::io::Cursor::new(data));
         Self::deserialize(&mut raw)
     }
+
+    /// from_cbor_bytes() for untrusted input: the CBOR is first checked against {limits}
+    /// without recursing so e.g. deeply nested or huge payloads are rejected before any
+    /// allocation or recursion happens.
+    fn from_cbor_bytes_with_limits(
+        data: &[u8],
+        limits: &DeserializeLimits,
+    ) -> Result<Self, DeserializeError>
+    where
+        Self: Sized,
+    {
+        check_cbor_limits(data, limits)?;
+        Self::from_cbor_bytes(data)
+    }
+}
+
+/// Guardrails for deserializing untrusted CBOR e.g. user-submitted transactions.
+/// See Deserialize::from_cbor_bytes_with_limits().
+/// The defaults are far above anything found in real chain data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DeserializeLimits {
+    /// Maximum nesting of arrays, maps, tags and indefinite length strings
+    pub max_depth: usize,
+    /// Maximum number of elements (or entries for maps) in any array or map
+    pub max_collection_len: u64,
+    /// Maximum size of the whole CBOR in bytes
+    pub max_size: usize,
+}
+
+impl DeserializeLimits {
+    pub fn new(max_depth: usize, max_collection_len: u64, max_size: usize) -> Self {
+        Self {
+            max_depth,
+            max_collection_len,
+            max_size,
+        }
+    }
+
+    fn unlimited() -> Self {
+        Self::new(usize::MAX, u64::MAX, usize::MAX)
+    }
+}
+
+impl Default for DeserializeLimits {
+    fn default() -> Self {
+        Self::new(128, 1 << 20, 16 * 1024 * 1024)
+    }
+}
+
+/// Checks that the first CBOR item in {data} is well-formed and within {limits}
+pub fn check_cbor_limits(data: &[u8], limits: &DeserializeLimits) -> Result<(), DeserializeError> {
+    if data.len() > limits.max_size {
+        return Err(DeserializeFailure::LimitExceeded {
+            limit: LimitKind::Size,
+            max: limits.max_size as u64,
+        }
+        .into());
+    }
+    let mut raw = Deserializer::from(std::io::Cursor::new(data));
+    skip_cbor_item(&mut raw, limits)
+}
+
+/// Hex versions of to_cbor_bytes() / from_cbor_bytes() e.g. for cborHex in cardano-cli's
+/// text envelopes or CBOR returned by chain indexers.
+pub trait CborHex: Serialize + Deserialize + Sized {
+    /// Hex-encoded to_cbor_bytes()
+    fn to_cbor_hex(&self) -> String {
+        hex::encode(self.to_cbor_bytes())
+    }
+
+    /// from_cbor_bytes() on the hex-decoded {hex_str}
+    fn from_cbor_hex(hex_str: &str) -> Result<Self, CborHexError> {
+        let bytes = hex::decode(hex_str)?;
+        Self::from_cbor_bytes(&bytes).map_err(Into::into)
+    }
+}
+
+impl<T: Serialize + Deserialize> CborHex for T {}
+
+/// Drops all remembered CBOR encoding details (definite/indefinite lengths,
+/// integer widths, map key order, etc) from a structure and all of its fields.
+///
+/// Types that implement `PartialEq`/`Hash` (e.g. `TransactionInput`, `ExUnits`,
+/// `Value`, the address types and the native scripts) already ignore encoding
+/// details in those comparisons. Most other generated types (e.g. `TransactionBody`,
+/// `PoolParams`) do not implement equality at all and the same data deserialized
+/// from two different CBOR encodings will serialize (and hash) differently.
+/// Stripping both sides first lets them be compared by their `to_cbor_bytes()`
+/// regardless of where they came from.
+///
+/// After stripping, `to_cbor_bytes()` will produce canonical CBOR, so this must
+/// never be used on anything whose original bytes/hash must be kept.
+///
+/// Use `#[derive(StripEncodings)]` to implement this for a type.
+pub trait StripEncodings {
+    /// Removes all encoding details in-place
+    fn strip_encodings(&mut self);
+
+    /// Copy of this structure without any encoding details
+    fn stripped(&self) -> Self
+    where
+        Self: Clone,
+    {
+        let mut stripped = self.clone();
+        stripped.strip_encodings();
+        stripped
+    }
+}
+
+pub use cml_core_derive::StripEncodings;
+
+macro_rules! impl_strip_encodings_noop {
+    ($($ty:ty),*) => {
+        $(
+            impl StripEncodings for $ty {
+                fn strip_encodings(&mut self) {}
+            }
+        )*
+    };
+}
+
+impl_strip_encodings_noop!(bool, char, u8, u16, u32, u64, u128, usize);
+impl_strip_encodings_noop!(i8, i16, i32, i64, i128, isize, String);
+impl_strip_encodings_noop!(num_bigint::BigInt, num_bigint::BigUint);
+
+impl<T: StripEncodings> StripEncodings for Option<T> {
+    fn strip_encodings(&mut self) {
+        if let Some(inner) = self {
+            inner.strip_encodings();
+        }
+    }
+}
+
+impl<T: StripEncodings> StripEncodings for Box<T> {
+    fn strip_encodings(&mut self) {
+        self.as_mut().strip_encodings();
+    }
+}
+
+impl<T: StripEncodings> StripEncodings for Vec<T> {
+    fn strip_encodings(&mut self) {
+        self.iter_mut().for_each(StripEncodings::strip_encodings);
+    }
+}
+
+impl<T: StripEncodings, const N: usize> StripEncodings for [T; N] {
+    fn strip_encodings(&mut self) {
+        self.iter_mut().for_each(StripEncodings::strip_encodings);
+    }
+}
+
+impl<A: StripEncodings, B: StripEncodings> StripEncodings for (A, B) {
+    fn strip_encodings(&mut self) {
+        self.0.strip_encodings();
+        self.1.strip_encodings();
+    }
+}
+
+impl<A: StripEncodings, B: StripEncodings, C: StripEncodings> StripEncodings for (A, B, C) {
+    fn strip_encodings(&mut self) {
+        self.0.strip_encodings();
+        self.1.strip_encodings();
+        self.2.strip_encodings();
+    }
+}
+
+// keys can have encoding details too so maps/sets are rebuilt from their stripped entries.
+// Those details never take part in key comparisons so this can't merge any entries.
+impl<K, V> StripEncodings for std::collections::BTreeMap<K, V>
+where
+    K: StripEncodings + Ord,
+    V: StripEncodings,
+{
+    fn strip_encodings(&mut self) {
+        *self = std::mem::take(self)
+            .into_iter()
+            .map(|mut entry| {
+                entry.strip_encodings();
+                entry
+            })
+            .collect();
+    }
+}
+
+impl<T> StripEncodings for std::collections::BTreeSet<T>
+where
+    T: StripEncodings + Ord,
+{
+    fn strip_encodings(&mut self) {
+        *self = std::mem::take(self)
+            .into_iter()
+            .map(|mut elem| {
+                elem.strip_encodings();
+                elem
+            })
+            .collect();
+    }
+}
+
+impl<K, V> StripEncodings for std::collections::HashMap<K, V>
+where
+    K: StripEncodings + Eq + std::hash::Hash,
+    V: StripEncodings,
+{
+    fn strip_encodings(&mut self) {
+        *self = std::mem::take(self)
+            .into_iter()
+            .map(|mut entry| {
+                entry.strip_encodings();
+                entry
+            })
+            .collect();
+    }
+}
+
+impl<K, V> StripEncodings for crate::ordered_hash_map::OrderedHashMap<K, V>
+where
+    K: StripEncodings + Eq + Ord + std::hash::Hash,
+    V: StripEncodings,
+{
+    fn strip_encodings(&mut self) {
+        *self = std::mem::take(self)
+            .take()
+            .into_iter()
+            .map(|mut entry| {
+                entry.strip_encodings();
+                entry
+            })
+            .collect();
+    }
+}
+
+/// Deterministic JSON representation, useful for diffing or comparing two logically-equal
+/// structures that were built in a different order.
+///
+/// The output is compact (no whitespace at all) and:
+/// * object keys are sorted by their UTF-16 code units as in RFC 8785 (JCS). This is the same
+///   as bytewise (`jq -S`) order unless keys differ in characters outside the basic
+///   multilingual plane.
+/// * integral numbers are always rendered as plain integers (never as floats or in scientific
+///   notation) whatever their size e.g. 2.0e3 as 2000 and 1e20 as 100000000000000000000.
+///   Other numbers are kept as serde_json writes them.
+/// * array order is kept as-is since it is meaningful.
+pub trait ToCanonicalJson {
+    fn to_json_canonical(&self) -> Result<String, serde_json::Error>;
+}
+
+impl<T: serde::Serialize> ToCanonicalJson for T {
+    fn to_json_canonical(&self) -> Result<String, serde_json::Error> {
+        Ok(canonicalize_json(&serde_json::to_value(self)?))
+    }
+}
+
+/// Renders {value} as canonical JSON. See ToCanonicalJson.
+pub fn canonicalize_json(value: &serde_json::Value) -> String {
+    let mut json = String::new();
+    write_canonical_json(value, &mut json);
+    json
+}
+
+// serde_json::Map keeps its own (plain string) key order so the ordering can't be represented
+// in a serde_json::Value and must be applied while writing instead
+fn write_canonical_json(value: &serde_json::Value, json: &mut String) {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut entries = map.iter().collect::<Vec<_>>();
+            entries.sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+            json.push('{');
+            for (i, (key, value)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    json.push(',');
+                }
+                // Display of a JSON string value takes care of escaping
+                json.push_str(&serde_json::Value::from(key.as_str()).to_string());
+                json.push(':');
+                write_canonical_json(value, json);
+            }
+            json.push('}');
+        }
+        serde_json::Value::Array(elems) => {
+            json.push('[');
+            for (i, elem) in elems.iter().enumerate() {
+                if i > 0 {
+                    json.push(',');
+                }
+                write_canonical_json(elem, json);
+            }
+            json.push(']');
+        }
+        serde_json::Value::Number(number) => {
+            let text = number.to_string();
+            match number.as_f64() {
+                // integers are already written out in full (as-is to not lose any precision)
+                // so this is only floats like 2.0 or 1e20 which are exactly integral
+                Some(f) if text.contains(['.', 'e', 'E']) && f.is_finite() && f.fract() == 0.0 => {
+                    if f == 0.0 {
+                        // no -0
+                        json.push('0');
+                    } else {
+                        json.push_str(&expand_exponent(&text));
+                    }
+                }
+                _ => json.push_str(&text),
+            }
+        }
+        other => json.push_str(&other.to_string()),
+    }
+}
+
+// writes out the shortest float representation {text} of an integral number (e.g. 1e20 or
+// 2000.0) without a fraction or exponent. This is used instead of the exact binary value so
+// e.g. 1e300 becomes a 1 followed by 300 zeros and not 1000000000000000052504760255...
+fn expand_exponent(text: &str) -> String {
+    let (mantissa, exp) = match text.find(['e', 'E']) {
+        Some(i) => (&text[..i], text[i + 1..].parse::<i64>().unwrap_or(0)),
+        None => (text, 0),
+    };
+    let (sign, mantissa) = match mantissa.strip_prefix('-') {
+        Some(unsigned) => ("-", unsigned),
+        None => ("", mantissa),
+    };
+    let (int_part, frac_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let digits = format!("{}{}", int_part, frac_part);
+    let point = int_part.len() as i64 + exp;
+    let int_digits = if point >= digits.len() as i64 {
+        format!(
+            "{}{}",
+            digits,
+            "0".repeat((point - digits.len() as i64) as usize)
+        )
+    } else {
+        digits[..point.max(0) as usize].to_owned()
+    };
+    match int_digits.trim_start_matches('0') {
+        "" => "0".to_owned(),
+        int_digits => format!("{}{}", sign, int_digits),
+    }
+}
+
+/// Types that can remember the exact bytes they were deserialized from.
+///
+/// Some hashes (e.g. the transaction body hash or the auxiliary data hash) must be
+/// computed over the exact original bytes. Re-serializing with preserved encodings
+/// should give the same bytes, but the original bytes make this independent of any
+/// bugs or unsupported encoding details in that process. When present, the hashing
+/// functions for these types use the original bytes instead of `to_cbor_bytes()`.
+///
+/// This is opt-in (see `deserialize_keep_original` / `from_cbor_bytes_keep_original`, or
+/// `DeserializeKeepOriginal` for types containing these e.g. blocks) as the bytes are NOT
+/// updated if the structure is modified afterwards. `Deserialize` alone never keeps them.
+/// Use `set_original_bytes(None)` (or `StripEncodings`) after modifying one.
+pub trait OriginalBytes: Deserialize + Sized {
+    /// The exact bytes this was deserialized from, if they were kept
+    fn original_bytes(&self) -> Option<&[u8]>;
+
+    fn set_original_bytes(&mut self, original_bytes: Option<Vec<u8>>);
+
+    /// deserialize_keep_original() if {keep_original} is set, otherwise deserialize()
+    fn deserialize_maybe_keep_original<R: BufRead + Seek>(
+        raw: &mut Deserializer<R>,
+        keep_original: bool,
+    ) -> Result<Self, DeserializeError> {
+        if keep_original {
+            Self::deserialize_keep_original(raw)
+        } else {
+            Self::deserialize(raw)
+        }
+    }
+
+    /// Deserializes while also keeping the exact bytes that were read
+    fn deserialize_keep_original<R: BufRead + Seek>(
+        raw: &mut Deserializer<R>,
+    ) -> Result<Self, DeserializeError> {
+        let start = raw
+            .as_mut_ref()
+            .stream_position()
+            .map_err(cbor_event::Error::IoError)?;
+        let mut value = Self::deserialize(raw)?;
+        let end = raw
+            .as_mut_ref()
+            .stream_position()
+            .map_err(cbor_event::Error::IoError)?;
+        let mut original_bytes = vec![0; (end - start) as usize];
+        raw.as_mut_ref()
+            .seek(std::io::SeekFrom::Start(start))
+            .and_then(|_| raw.as_mut_ref().read_exact(&mut original_bytes))
+            .map_err(cbor_event::Error::IoError)?;
+        value.set_original_bytes(Some(original_bytes));
+        Ok(value)
+    }
+
+    fn from_cbor_bytes_keep_original(data: &[u8]) -> Result<Self, DeserializeError> {
+        let mut raw = Deserializer::from(std::io::Cursor::new(data));
+        Self::deserialize_keep_original(&mut raw)
+    }
+}
+
+/// Types containing `OriginalBytes` types (e.g. blocks and their transaction bodies) that can
+/// have all of them keep their original bytes. `Deserialize` is the same as passing false.
+pub trait DeserializeKeepOriginal: Deserialize + Sized {
+    /// Deserializes, having all contained `OriginalBytes` types keep their original bytes
+    /// if {keep_original} is set
+    fn deserialize_opt_keep_original<R: BufRead + Seek>(
+        raw: &mut Deserializer<R>,
+        keep_original: bool,
+    ) -> Result<Self, DeserializeError>;
+
+    fn from_cbor_bytes_keep_original(data: &[u8]) -> Result<Self, DeserializeError> {
+        let mut raw = Deserializer::from(std::io::Cursor::new(data));
+        Self::deserialize_opt_keep_original(&mut raw, true)
+    }
+}
+
+/// Reads the next CBOR item (including all nested items) as raw bytes without interpreting it.
+/// Unlike deserializing into cbor_event::Value this accepts anything well-formed on the wire
+/// e.g. text that isn't valid UTF-8, and arbitrarily deep nesting as it doesn't recurse.
+pub fn read_cbor_item_bytes<R: BufRead + Seek>(
+    raw: &mut Deserializer<R>,
+) -> Result<Vec<u8>, DeserializeError> {
+    let start = raw
+        .as_mut_ref()
+        .stream_position()
+        .map_err(cbor_event::Error::IoError)?;
+    skip_cbor_item(raw, &DeserializeLimits::unlimited())?;
+    let end = raw
+        .as_mut_ref()
+        .stream_position()
+        .map_err(cbor_event::Error::IoError)?;
+    let mut bytes = vec![0; (end - start) as usize];
+    raw.as_mut_ref()
+        .seek(std::io::SeekFrom::Start(start))
+        .and_then(|_| raw.as_mut_ref().read_exact(&mut bytes))
+        .map_err(cbor_event::Error::IoError)?;
+    Ok(bytes)
+}
+
+/// Skips the next CBOR item (including all nested items) iteratively, failing if it exceeds
+/// the depth or collection length of {limits}.
+fn skip_cbor_item<R: BufRead>(
+    raw: &mut Deserializer<R>,
+    limits: &DeserializeLimits,
+) -> Result<(), DeserializeError> {
+    fn skip_n<R: BufRead>(raw: &mut Deserializer<R>, n: u64) -> Result<(), DeserializeError> {
+        let skipped = std::io::copy(
+            &mut std::io::Read::take(raw.as_mut_ref(), n),
+            &mut std::io::sink(),
+        )
+        .map_err(cbor_event::Error::IoError)?;
+        if skipped != n {
+            return Err(cbor_event::Error::NotEnough(skipped as usize, n as usize).into());
+        }
+        Ok(())
+    }
+    let collection_len_exceeded = || -> DeserializeError {
+        DeserializeFailure::LimitExceeded {
+            limit: LimitKind::CollectionLen,
+            max: limits.max_collection_len,
+        }
+        .into()
+    };
+    // items left to read in each enclosing array/map/tag. None = indefinite (until break)
+    // along with how many items were read so far and how many items make up one entry
+    struct Frame {
+        remaining: Option<u64>,
+        read: u64,
+        per_entry: u64,
+    }
+    let mut frames = vec![Frame {
+        remaining: Some(1),
+        read: 0,
+        per_entry: 1,
+    }];
+    while let Some(top) = frames.last_mut() {
+        match &mut top.remaining {
+            Some(0) => {
+                frames.pop();
+                continue;
+            }
+            Some(n) => *n -= 1,
+            None => {
+                if raw.cbor_type()? == cbor_event::Type::Special && raw.special_break()? {
+                    frames.pop();
+                    continue;
+                }
+                top.read += 1;
+                if top.read > limits.max_collection_len.saturating_mul(top.per_entry) {
+                    return Err(collection_len_exceeded());
+                }
+            }
+        }
+        let len_sz = raw.cbor_len_sz()?;
+        let header_len = 1 + len_sz.bytes_following() as u64;
+        let nested = match raw.cbor_type()? {
+            cbor_event::Type::Special if matches!(len_sz, cbor_event::LenSz::Indefinite) => {
+                // a break outside of any indefinite length item
+                return Err(DeserializeFailure::BreakInDefiniteLen.into());
+            }
+            cbor_type @ (cbor_event::Type::UnsignedInteger
+            | cbor_event::Type::NegativeInteger
+            | cbor_event::Type::Tag)
+                if matches!(len_sz, cbor_event::LenSz::Indefinite) =>
+            {
+                return Err(cbor_event::Error::IndefiniteLenNotSupported(cbor_type).into());
+            }
+            cbor_event::Type::UnsignedInteger
+            | cbor_event::Type::NegativeInteger
+            | cbor_event::Type::Special => {
+                skip_n(raw, header_len)?;
+                None
+            }
+            cbor_event::Type::Bytes | cbor_event::Type::Text => {
+                skip_n(raw, header_len)?;
+                match len_sz {
+                    cbor_event::LenSz::Len(len, _) => {
+                        skip_n(raw, len)?;
+                        None
+                    }
+                    // chunks are definite strings so they're handled as regular items
+                    cbor_event::LenSz::Indefinite => Some((None, 1)),
+                }
+            }
+            cbor_event::Type::Array | cbor_event::Type::Map => {
+                let per_entry = if raw.cbor_type()? == cbor_event::Type::Map {
+                    2
+                } else {
+                    1
+                };
+                skip_n(raw, header_len)?;
+                match len_sz {
+                    cbor_event::LenSz::Len(len, _) if len > limits.max_collection_len => {
+                        return Err(collection_len_exceeded());
+                    }
+                    cbor_event::LenSz::Len(len, _) => {
+                        Some((Some(len.saturating_mul(per_entry)), per_entry))
+                    }
+                    cbor_event::LenSz::Indefinite => Some((None, per_entry)),
+                }
+            }
+            cbor_event::Type::Tag => {
+                skip_n(raw, header_len)?;
+                Some((Some(1), 1))
+            }
+        };
+        if let Some((remaining, per_entry)) = nested {
+            // the outermost frame is only there to read the item itself
+            if frames.len() > limits.max_depth {
+                return Err(DeserializeFailure::LimitExceeded {
+                    limit: LimitKind::Depth,
+                    max: limits.max_depth as u64,
+                }
+                .into());
+            }
+            frames.push(Frame {
+                remaining,
+                read: 0,
+                per_entry,
+            });
+        }
+    }
+    Ok(())
 }
 
 // TODO: remove ToBytes / FromBytes after we regenerate the WASM wrappers.
@@ -309,3 +958,55 @@ pub trait RawBytesEncoding {
         Self::from_raw_bytes(bytes.as_ref())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonical_json_key_order() {
+        // bytewise, not shorter keys first: "aa" sorts before "b"
+        assert_eq!(
+            canonicalize_json(&serde_json::json!({
+                "c": { "bb": 1, "a": null },
+                "b": 2,
+                "aa": [1.5],
+            })),
+            r#"{"aa":[1.5],"b":2,"c":{"a":null,"bb":1}}"#
+        );
+        // RFC 8785 §3.2.3: UTF-16 code units so U+1F600 (surrogates 0xD83D 0xDE00) comes
+        // before U+FF61 even though its UTF-8 encoding (0xF0...) sorts after (0xEF...)
+        assert_eq!(
+            canonicalize_json(&serde_json::json!({
+                "\u{ff61}": 1,
+                "\u{1f600}": 2,
+                "\u{80}": 3,
+                "1": 4,
+            })),
+            "{\"1\":4,\"\u{80}\":3,\"\u{1f600}\":2,\"\u{ff61}\":1}"
+        );
+    }
+
+    #[test]
+    fn canonical_json_numbers() {
+        assert_eq!(
+            canonicalize_json(&serde_json::json!([
+                2.0e3,
+                1e20,
+                -1e20,
+                1e300,
+                -0.0,
+                u64::MAX,
+                i64::MIN,
+                1.5,
+                -0.25,
+            ])),
+            format!(
+                "[2000,100000000000000000000,-100000000000000000000,1{},0,{},{},1.5,-0.25]",
+                "0".repeat(300),
+                u64::MAX,
+                i64::MIN
+            )
+        );
+    }
+}
@@ -0,0 +1,135 @@
+//! Property-test (proptest) strategies and round-trip properties for checking serialization
+//! stability on randomized inputs. Enabled by the `testing` feature (off by default).
+//!
+//! The properties are meant to be used inside `proptest!` blocks e.g.
+//! `proptest! { #[test] fn int(x in arb_int()) { roundtrip_cbor(&x)?; } }`
+
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+
+use crate::serialization::{Deserialize, LenEncoding, Serialize, StringEncoding};
+use crate::Int;
+
+/// Any CBOR integer/length width. Widths too small for a value are ignored by the serializer
+/// so this can be used as the (non-canonical) encoding detail of any value.
+pub fn arb_sz() -> impl Strategy<Value = cbor_event::Sz> {
+    prop_oneof![
+        Just(cbor_event::Sz::Inline),
+        Just(cbor_event::Sz::One),
+        Just(cbor_event::Sz::Two),
+        Just(cbor_event::Sz::Four),
+        Just(cbor_event::Sz::Eight),
+    ]
+}
+
+/// Optional encoding detail, with None (canonical) generated as often as all widths combined
+pub fn arb_sz_encoding() -> impl Strategy<Value = Option<cbor_event::Sz>> {
+    prop_oneof![Just(None), arb_sz().prop_map(Some)]
+}
+
+/// Integers over the whole CBOR range, biased towards the boundaries of each width
+pub fn arb_u64() -> impl Strategy<Value = u64> {
+    prop_oneof![
+        0u64..=24,
+        prop::sample::select(vec![
+            u8::MAX as u64,
+            u8::MAX as u64 + 1,
+            u16::MAX as u64,
+            u16::MAX as u64 + 1,
+            u32::MAX as u64,
+            u32::MAX as u64 + 1,
+            u64::MAX,
+        ]),
+        any::<u64>(),
+    ]
+}
+
+/// Ints with random (possibly non-minimal) encodings
+pub fn arb_int() -> impl Strategy<Value = Int> {
+    (any::<bool>(), arb_u64(), arb_sz_encoding()).prop_map(|(negative, value, encoding)| {
+        if negative {
+            Int::Nint { value, encoding }
+        } else {
+            Int::Uint { value, encoding }
+        }
+    })
+}
+
+/// Canonical, definite (any width) or indefinite length encodings for arrays/maps
+pub fn arb_len_encoding() -> impl Strategy<Value = LenEncoding> {
+    prop_oneof![
+        Just(LenEncoding::Canonical),
+        arb_sz().prop_map(LenEncoding::Definite),
+        Just(LenEncoding::Indefinite),
+    ]
+}
+
+/// Canonical, definite (any width) or chunked (indefinite) encodings for a string of {len} bytes.
+/// Chunks are at most 64 bytes as required by e.g. plutus bounded bytes.
+pub fn arb_string_encoding(len: usize) -> impl Strategy<Value = StringEncoding> {
+    let chunked =
+        prop::collection::vec((1u64..=64, arb_sz()), 0..=len.min(8)).prop_map(move |chunks| {
+            let mut remaining = len as u64;
+            let mut encoding = Vec::new();
+            for (chunk_len, sz) in chunks {
+                if remaining == 0 {
+                    break;
+                }
+                let chunk_len = chunk_len.min(remaining);
+                encoding.push((chunk_len, sz));
+                remaining -= chunk_len;
+            }
+            // any remainder goes in minimally-sized chunks of the maximum length
+            while remaining > 0 {
+                let chunk_len = remaining.min(64);
+                encoding.push((chunk_len, cbor_event::Sz::canonical(chunk_len)));
+                remaining -= chunk_len;
+            }
+            StringEncoding::Indefinite(encoding)
+        });
+    prop_oneof![
+        Just(StringEncoding::Canonical),
+        arb_sz().prop_map(StringEncoding::Definite),
+        chunked,
+    ]
+}
+
+/// Byte strings of up to {max_len} bytes
+pub fn arb_bytes(max_len: usize) -> impl Strategy<Value = Vec<u8>> {
+    prop::collection::vec(any::<u8>(), 0..=max_len)
+}
+
+/// Serializing {value}, deserializing it and serializing it again must give the same bytes,
+/// both as-is (encoding details preserved) and when forcing canonical encoding.
+pub fn roundtrip_cbor<T: Serialize + Deserialize>(value: &T) -> Result<(), TestCaseError> {
+    let bytes = value.to_cbor_bytes();
+    let decoded = T::from_cbor_bytes(&bytes)
+        .map_err(|e| TestCaseError::fail(format!("{} for {}", e, hex::encode(&bytes))))?;
+    prop_assert_eq!(hex::encode(decoded.to_cbor_bytes()), hex::encode(&bytes));
+    let canonical = value.to_canonical_cbor_bytes();
+    let decoded = T::from_cbor_bytes(&canonical)
+        .map_err(|e| TestCaseError::fail(format!("{} for {}", e, hex::encode(&canonical))))?;
+    prop_assert_eq!(
+        hex::encode(decoded.to_canonical_cbor_bytes()),
+        hex::encode(&canonical)
+    );
+    // canonical encoding must not depend on the original encoding details
+    prop_assert_eq!(
+        hex::encode(decoded.to_cbor_bytes()),
+        hex::encode(&canonical)
+    );
+    Ok(())
+}
+
+/// Converting {value} to JSON and back must give the same JSON
+pub fn roundtrip_json<T>(value: &T) -> Result<(), TestCaseError>
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+{
+    let json = serde_json::to_value(value).map_err(|e| TestCaseError::fail(e.to_string()))?;
+    let decoded: T = serde_json::from_value(json.clone())
+        .map_err(|e| TestCaseError::fail(format!("{} for {}", e, json)))?;
+    let rejson = serde_json::to_value(&decoded).map_err(|e| TestCaseError::fail(e.to_string()))?;
+    prop_assert_eq!(rejson, json);
+    Ok(())
+}
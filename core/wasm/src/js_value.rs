@@ -0,0 +1,60 @@
+use serde::ser::{Serialize, Serializer};
+use wasm_bindgen::JsValue;
+
+// Number.MAX_SAFE_INTEGER
+const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
+
+/// Converts to a structured JS object with the same shape as JSON.parse(to_json()).
+/// This goes via serde_json::Value so map keys are stringified exactly as in the JSON API.
+/// Integers outside of JS's safe integer range become BigInts instead of losing precision.
+/// Integers that don't fit in 64 bits (only possible in PlutusData) and BigInteger itself are
+/// decimal strings instead, exactly as in to_json().
+pub fn to_js_value<T: Serialize>(value: &T) -> Result<JsValue, serde_wasm_bindgen::Error> {
+    let json = serde_json::to_value(value).map_err(serde_wasm_bindgen::Error::new)?;
+    JsCompatible(&json).serialize(
+        &serde_wasm_bindgen::Serializer::json_compatible()
+            .serialize_large_number_types_as_bigints(true),
+    )
+}
+
+/// Inverse of to_js_value(). Accepts numbers or BigInts for integer fields.
+pub fn from_js_value<T: serde::de::DeserializeOwned>(
+    js_value: JsValue,
+) -> Result<T, serde_wasm_bindgen::Error> {
+    let json: serde_json::Value = serde_wasm_bindgen::from_value(js_value)?;
+    serde_json::from_value(json).map_err(serde_wasm_bindgen::Error::new)
+}
+
+struct JsCompatible<'a>(&'a serde_json::Value);
+
+impl Serialize for JsCompatible<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            serde_json::Value::Number(number) => {
+                // the serializer turns all u64/i64 into BigInts so only use those when needed
+                if let Some(u) = number.as_u64() {
+                    if u <= MAX_SAFE_INTEGER {
+                        serializer.serialize_f64(u as f64)
+                    } else {
+                        serializer.serialize_u64(u)
+                    }
+                } else if let Some(i) = number.as_i64() {
+                    if i >= -(MAX_SAFE_INTEGER as i64) {
+                        serializer.serialize_f64(i as f64)
+                    } else {
+                        serializer.serialize_i64(i)
+                    }
+                } else {
+                    number.serialize(serializer)
+                }
+            }
+            serde_json::Value::Array(elems) => {
+                serializer.collect_seq(elems.iter().map(JsCompatible))
+            }
+            serde_json::Value::Object(map) => {
+                serializer.collect_map(map.iter().map(|(k, v)| (k, JsCompatible(v))))
+            }
+            other => other.serialize(serializer),
+        }
+    }
+}
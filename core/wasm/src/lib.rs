@@ -3,7 +3,9 @@ use wasm_bindgen::prelude::{wasm_bindgen, JsValue};
 use cml_core::serialization::{Deserialize, Serialize};
 
 // re-export to make macros easier to use
-pub use cml_core::serialization::RawBytesEncoding;
+pub use cml_core::serialization::{RawBytesEncoding, ToCanonicalJson};
+
+pub mod js_value;
 
 #[macro_use]
 pub mod wasm_wrappers;
@@ -79,3 +81,65 @@ impl AsRef<cml_core::Int> for Int {
         &self.0
     }
 }
+
+/**
+ * Guardrails for deserializing untrusted CBOR e.g. user-submitted transactions.
+ * See from_cbor_bytes_with_limits() on Transaction, PlutusData and MultiEraBlock.
+ */
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DeserializeLimits(cml_core::serialization::DeserializeLimits);
+
+impl_wasm_conversions!(
+    cml_core::serialization::DeserializeLimits,
+    DeserializeLimits
+);
+
+#[wasm_bindgen]
+impl DeserializeLimits {
+    /**
+     * The default limits which are far above anything found in real chain data
+     */
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /**
+     * Maximum nesting of arrays, maps, tags and indefinite length strings
+     */
+    pub fn with_max_depth(&self, max_depth: usize) -> Self {
+        Self(cml_core::serialization::DeserializeLimits {
+            max_depth,
+            ..self.0
+        })
+    }
+
+    /**
+     * Maximum number of elements (or entries for maps) in any array or map
+     */
+    pub fn with_max_collection_len(&self, max_collection_len: u64) -> Self {
+        Self(cml_core::serialization::DeserializeLimits {
+            max_collection_len,
+            ..self.0
+        })
+    }
+
+    /**
+     * Maximum size of the whole CBOR in bytes
+     */
+    pub fn with_max_size(&self, max_size: usize) -> Self {
+        Self(cml_core::serialization::DeserializeLimits { max_size, ..self.0 })
+    }
+
+    pub fn max_depth(&self) -> usize {
+        self.0.max_depth
+    }
+
+    pub fn max_collection_len(&self) -> u64 {
+        self.0.max_collection_len
+    }
+
+    pub fn max_size(&self) -> usize {
+        self.0.max_size
+    }
+}
@@ -544,8 +544,26 @@ macro_rules! impl_wasm_json_api {
                 })
             }
 
+            /**
+             * Deterministic compact JSON: object keys are sorted by UTF-16 code units (RFC 8785) and
+             * integral numbers are never floats or in scientific notation.
+             * Two logically-equal structures always produce byte-identical output.
+             */
+            pub fn to_json_canonical(&self) -> Result<String, wasm_bindgen::JsError> {
+                $crate::ToCanonicalJson::to_json_canonical(&self.0).map_err(|e| {
+                    wasm_bindgen::JsError::new(&format!(
+                        concat!(stringify!($wasm_name), "::to_json_canonical: {}"),
+                        e
+                    ))
+                })
+            }
+
+            /**
+             * Structured JS object equivalent to JSON.parse(to_json()).
+             * Integers outside of the JS safe integer range are BigInts instead of losing precision.
+             */
             pub fn to_js_value(&self) -> Result<wasm_bindgen::JsValue, wasm_bindgen::JsError> {
-                serde_wasm_bindgen::to_value(&self.0).map_err(|e| {
+                $crate::js_value::to_js_value(&self.0).map_err(|e| {
                     wasm_bindgen::JsError::new(&format!(
                         concat!(stringify!($wasm_name), "::to_js_value: {}"),
                         e
@@ -561,6 +579,22 @@ macro_rules! impl_wasm_json_api {
                     ))
                 })
             }
+
+            /**
+             * Inverse of to_js_value(). Integer fields can be given as numbers or BigInts.
+             */
+            pub fn from_js_value(
+                js_value: wasm_bindgen::JsValue,
+            ) -> Result<$wasm_name, wasm_bindgen::JsError> {
+                $crate::js_value::from_js_value(js_value)
+                    .map(Self)
+                    .map_err(|e| {
+                        wasm_bindgen::JsError::new(&format!(
+                            concat!(stringify!($wasm_name), "::from_js_value: {}"),
+                            e
+                        ))
+                    })
+            }
         }
     };
 }
@@ -2,7 +2,7 @@
 use crate::chain_crypto::bech32::Bech32;
 pub use cml_core::{
     error::{DeserializeError, DeserializeFailure},
-    serialization::{Deserialize, RawBytesEncoding, Serialize, StringEncoding},
+    serialization::{Deserialize, RawBytesEncoding, Serialize, StringEncoding, StripEncodings},
 };
 use cryptoxide::blake2b::Blake2b;
 pub use derivative::Derivative;
@@ -50,6 +50,12 @@ impl From<bech32::Error> for CryptoError {
     }
 }
 
+pub fn blake2b160(data: &[u8]) -> [u8; 20] {
+    let mut out = [0; 20];
+    Blake2b::blake2b(&mut out, data, &[]);
+    out
+}
+
 pub fn blake2b224(data: &[u8]) -> [u8; 28] {
     let mut out = [0; 28];
     Blake2b::blake2b(&mut out, data, &[]);
@@ -154,6 +160,24 @@ impl Bip32PrivateKey {
         const XPRV_SIZE: usize = 96;
         self.0.as_ref()[ED25519_PRIVATE_KEY_LENGTH..XPRV_SIZE].to_vec()
     }
+
+    /// CIP-105 DRep key (role 3, index 0) when called on a CIP-1852 account key
+    /// i.e. m/1852'/1815'/account'/3/0
+    pub fn to_drep_key(&self) -> Bip32PrivateKey {
+        self.derive(3).derive(0)
+    }
+
+    /// CIP-105 constitutional committee cold key (role 4, index 0) when called on a CIP-1852 account key
+    /// i.e. m/1852'/1815'/account'/4/0
+    pub fn to_cc_cold_key(&self) -> Bip32PrivateKey {
+        self.derive(4).derive(0)
+    }
+
+    /// CIP-105 constitutional committee hot key (role 5, index 0) when called on a CIP-1852 account key
+    /// i.e. m/1852'/1815'/account'/5/0
+    pub fn to_cc_hot_key(&self) -> Bip32PrivateKey {
+        self.derive(5).derive(0)
+    }
 }
 
 impl RawBytesEncoding for Bip32PrivateKey {
@@ -173,6 +197,10 @@ impl RawBytesEncoding for Bip32PrivateKey {
 )]
 pub struct Bip32PublicKey(pub chain_crypto::PublicKey<chain_crypto::Ed25519Bip32>);
 
+impl StripEncodings for Bip32PublicKey {
+    fn strip_encodings(&mut self) {}
+}
+
 impl Bip32PublicKey {
     /// derive this public key with the given index.
     ///
@@ -221,6 +249,12 @@ impl Bip32PublicKey {
         const XPUB_SIZE: usize = 64;
         self.0.as_ref()[ED25519_PUBLIC_KEY_LENGTH..XPUB_SIZE].to_vec()
     }
+
+    /// Hash of the underlying ed25519 key e.g. the DRep key hash / committee credential
+    /// for keys obtained via Bip32PrivateKey::to_drep_key() / to_cc_cold_key() / to_cc_hot_key()
+    pub fn hash(&self) -> Ed25519KeyHash {
+        self.to_raw_key().hash()
+    }
 }
 
 impl RawBytesEncoding for Bip32PublicKey {
@@ -332,6 +366,10 @@ impl RawBytesEncoding for PrivateKey {
 )]
 pub struct PublicKey(pub chain_crypto::PublicKey<chain_crypto::Ed25519>);
 
+impl StripEncodings for PublicKey {
+    fn strip_encodings(&mut self) {}
+}
+
 impl From<chain_crypto::PublicKey<chain_crypto::Ed25519>> for PublicKey {
     fn from(key: chain_crypto::PublicKey<chain_crypto::Ed25519>) -> PublicKey {
         PublicKey(key)
@@ -381,6 +419,10 @@ macro_rules! impl_signature {
         #[derive(Debug, Clone, Eq, PartialEq)]
         pub struct $name(chain_crypto::Signature<$signee_type, $verifier_type>);
 
+        impl StripEncodings for $name {
+            fn strip_encodings(&mut self) {}
+        }
+
         impl $name {
             pub fn to_bech32(&self) -> String {
                 use crate::chain_crypto::bech32::Bech32;
@@ -490,6 +532,10 @@ macro_rules! impl_hash_type {
         #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
         pub struct $name([u8; $byte_count]);
 
+        impl StripEncodings for $name {
+            fn strip_encodings(&mut self) {}
+        }
+
         impl $name {
             pub const BYTE_COUNT: usize = $byte_count;
 
@@ -647,3 +693,40 @@ impl AsRef<chain_crypto::SecretKey<chain_crypto::LegacyDaedalus>> for LegacyDaed
         &self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account_key() -> Bip32PrivateKey {
+        fn harden(index: u32) -> u32 {
+            index | 0x80_00_00_00
+        }
+        // test walk nut penalty hip pave soap entry language right filter choice
+        let entropy = [
+            0xdf, 0x9e, 0xd2, 0x5e, 0xd1, 0x46, 0xbf, 0x43, 0x33, 0x6a, 0x5d, 0x7c, 0xf7, 0x39,
+            0x59, 0x94,
+        ];
+        Bip32PrivateKey::from_bip39_entropy(&entropy, &[])
+            .derive(harden(1852))
+            .derive(harden(1815))
+            .derive(harden(0))
+    }
+
+    #[test]
+    fn cip105_role_keys() {
+        let account = account_key();
+        assert_eq!(
+            account.to_drep_key().to_public().hash().to_hex(),
+            "a5b45515a3ff8cb7c02ce351834da324eb6dfc41b5779cb5e6b832aa"
+        );
+        assert_eq!(
+            account.to_cc_cold_key().to_public().hash().to_hex(),
+            "fefb9596ed670ad2c9978d78fe4eb36ba24cbba0a62fa4cdd0c2dcf5"
+        );
+        assert_eq!(
+            account.to_cc_hot_key().to_public().hash().to_hex(),
+            "f6d29c0f7164d37610cbf67b126a993beb24a076d0653f1fa069588f"
+        );
+    }
+}
@@ -91,6 +91,24 @@ impl Bip32PrivateKey {
     pub fn chaincode(&self) -> Vec<u8> {
         self.0.chaincode()
     }
+
+    /// CIP-105 DRep key (role 3, index 0) when called on a CIP-1852 account key
+    /// i.e. m/1852'/1815'/account'/3/0
+    pub fn to_drep_key(&self) -> Self {
+        Self(self.0.to_drep_key())
+    }
+
+    /// CIP-105 constitutional committee cold key (role 4, index 0) when called on a CIP-1852 account key
+    /// i.e. m/1852'/1815'/account'/4/0
+    pub fn to_cc_cold_key(&self) -> Self {
+        Self(self.0.to_cc_cold_key())
+    }
+
+    /// CIP-105 constitutional committee hot key (role 5, index 0) when called on a CIP-1852 account key
+    /// i.e. m/1852'/1815'/account'/5/0
+    pub fn to_cc_hot_key(&self) -> Self {
+        Self(self.0.to_cc_hot_key())
+    }
 }
 
 impl From<cml_crypto::Bip32PrivateKey> for Bip32PrivateKey {
@@ -172,6 +190,12 @@ impl Bip32PublicKey {
     pub fn chaincode(&self) -> Vec<u8> {
         self.0.chaincode()
     }
+
+    /// Hash of the underlying ed25519 key e.g. the DRep key hash / committee credential
+    /// for keys obtained via Bip32PrivateKey.to_drep_key() / to_cc_cold_key() / to_cc_hot_key()
+    pub fn hash(&self) -> Ed25519KeyHash {
+        Ed25519KeyHash(self.0.hash())
+    }
 }
 
 impl From<cml_crypto::Bip32PublicKey> for Bip32PublicKey {
@@ -114,3 +114,537 @@ describe('Transactions', () => {
     console.log(txHex);
   })
 });
+
+describe('Value', () => {
+  it('adds and compares token-bearing values', () => {
+    const policyId = CardanoWasm.ScriptHash.from_hex('aa'.repeat(28));
+    const tokenA = CardanoWasm.AssetName.from_str('tokenA');
+    const tokenB = CardanoWasm.AssetName.from_str('tokenB');
+
+    const assets1 = CardanoWasm.MultiAsset.new();
+    assets1.set(policyId, tokenA, BigInt(10));
+    const value1 = CardanoWasm.Value.new(BigInt(2000000), assets1);
+
+    const assets2 = CardanoWasm.MultiAsset.new();
+    assets2.set(policyId, tokenA, BigInt(5));
+    assets2.set(policyId, tokenB, BigInt(1));
+    const value2 = CardanoWasm.Value.new(BigInt(1500000), assets2);
+
+    const sum = value1.checked_add(value2);
+    expect(sum.coin()).to.equal(BigInt(3500000));
+    expect(sum.multi_asset().get(policyId, tokenA)).to.equal(BigInt(15));
+    expect(sum.multi_asset().get(policyId, tokenB)).to.equal(BigInt(1));
+
+    expect(sum.compare(value1)).to.equal(1);
+    expect(value1.compare(sum)).to.equal(-1);
+    expect(sum.compare(sum)).to.equal(0);
+    // value1 has more ADA but no tokenB
+    expect(value1.compare(value2)).to.equal(undefined);
+
+    const diff = sum.checked_sub(value2);
+    expect(diff.compare(value1)).to.equal(0);
+
+    sum.set_coin(BigInt(1));
+    expect(sum.coin()).to.equal(BigInt(1));
+  })
+});
+
+describe('Governance', () => {
+  it('builds and serializes one proposal of each action kind', () => {
+    const prevActionId = CardanoWasm.GovActionId.new(
+      CardanoWasm.TransactionHash.from_hex('11'.repeat(32)),
+      BigInt(2),
+    );
+    expect(prevActionId.transaction_id().to_hex()).to.equal('11'.repeat(32));
+    expect(prevActionId.gov_action_index()).to.equal(BigInt(2));
+
+    const rewardAccount = CardanoWasm.RewardAddress.new(
+      0,
+      CardanoWasm.Credential.new_pub_key(CardanoWasm.Ed25519KeyHash.from_hex('22'.repeat(28))),
+    );
+    const policyHash = CardanoWasm.ScriptHash.from_hex('33'.repeat(28));
+
+    const paramUpdate = CardanoWasm.ProtocolParamUpdate.new();
+    paramUpdate.set_key_deposit(BigInt(2000000));
+
+    const withdrawals = CardanoWasm.MapRewardAccountToCoin.new();
+    withdrawals.insert(rewardAccount, BigInt(1000000));
+
+    const coldCredential = CardanoWasm.Credential.new_script(policyHash);
+    const removed = CardanoWasm.CommitteeColdCredentialList.new();
+    removed.add(coldCredential);
+    const added = CardanoWasm.MapCommitteeColdCredentialToEpoch.new();
+    added.insert(CardanoWasm.Credential.new_pub_key(CardanoWasm.Ed25519KeyHash.from_hex('44'.repeat(28))), BigInt(500));
+
+    const anchor = CardanoWasm.Anchor.new(
+      CardanoWasm.Url.new('https://example.com/proposal.json'),
+      CardanoWasm.AnchorDocHash.from_hex('55'.repeat(32)),
+    );
+
+    const actions = [
+      CardanoWasm.GovAction.new_parameter_change_action(prevActionId, paramUpdate, policyHash),
+      CardanoWasm.GovAction.new_hard_fork_initiation_action(prevActionId, CardanoWasm.ProtocolVersion.new(BigInt(10), BigInt(0))),
+      CardanoWasm.GovAction.new_treasury_withdrawals_action(withdrawals, policyHash),
+      CardanoWasm.GovAction.new_no_confidence(prevActionId),
+      CardanoWasm.GovAction.new_update_committee(prevActionId, removed, added, CardanoWasm.UnitInterval.new(BigInt(2), BigInt(3))),
+      CardanoWasm.GovAction.new_new_constitution(prevActionId, CardanoWasm.Constitution.new(anchor, policyHash)),
+      CardanoWasm.GovAction.new_info_action(),
+    ];
+    expect(actions.map((action) => action.kind())).to.deep.equal([
+      CardanoWasm.GovActionKind.ParameterChangeAction,
+      CardanoWasm.GovActionKind.HardForkInitiationAction,
+      CardanoWasm.GovActionKind.TreasuryWithdrawalsAction,
+      CardanoWasm.GovActionKind.NoConfidence,
+      CardanoWasm.GovActionKind.UpdateCommittee,
+      CardanoWasm.GovActionKind.NewConstitution,
+      CardanoWasm.GovActionKind.InfoAction,
+    ]);
+    expect(actions[2].as_treasury_withdrawals_action()!.withdrawal().get(rewardAccount)).to.equal(BigInt(1000000));
+    expect(actions[4].as_update_committee()!.credentials().len()).to.equal(1);
+
+    const proposals = CardanoWasm.ProposalProcedureList.new();
+    actions.forEach((action) => proposals.add(
+      CardanoWasm.ProposalProcedure.new(BigInt(100000000000), rewardAccount, action, anchor),
+    ));
+    const roundTripped = CardanoWasm.ProposalProcedureList.from_cbor_bytes(proposals.to_cbor_bytes());
+    expect(roundTripped.len()).to.equal(actions.length);
+    expect(roundTripped.to_cbor_hex()).to.equal(proposals.to_cbor_hex());
+    expect(roundTripped.get(5).gov_action().as_new_constitution()!.action_id()!.gov_action_index()).to.equal(BigInt(2));
+    expect(roundTripped.get(5).gov_action().as_new_constitution()!.guardrail_script_hash()!.to_hex()).to.equal(policyHash.to_hex());
+
+    const docHash = CardanoWasm.AnchorDocHash.from_hex('55'.repeat(32));
+    expect(CardanoWasm.Anchor.from_url('https://example.com/proposal.json', docHash).to_cbor_hex()).to.equal(anchor.to_cbor_hex());
+    expect(() => CardanoWasm.Anchor.from_url('https://example.com/' + 'a'.repeat(109), docHash)).to.throw(/at most 128 bytes/);
+
+    const document = new TextEncoder().encode(JSON.stringify({
+      hashAlgorithm: 'blake2b-256',
+      authors: [],
+      body: { title: 'Title', abstract: 'Abstract' },
+    }));
+    CardanoWasm.validate_anchor_document(document);
+    const documentAnchor = CardanoWasm.Anchor.new(anchor.anchor_url(), CardanoWasm.Anchor.hash_document(document));
+    expect(documentAnchor.matches_document(document)).to.equal(true);
+    expect(anchor.matches_document(document)).to.equal(false);
+    expect(() => CardanoWasm.validate_anchor_document(new TextEncoder().encode('{"body": {}}'))).to.throw(/missing required fields/);
+  })
+});
+
+describe('Asset fingerprint', () => {
+  it('matches the CIP-14 test vector', () => {
+    const policyId = CardanoWasm.ScriptHash.from_hex('7eae28af2208be856f7a119668ae52a49b73725e326dc16579dcc373');
+    const assetName = CardanoWasm.AssetName.from_raw_bytes(Buffer.from('504154415445', 'hex'));
+    const fingerprint = CardanoWasm.asset_fingerprint(policyId, assetName);
+    expect(fingerprint).to.equal('asset13n25uv0yaf5kus35fm2k86cqy60z58d9xmde92');
+    expect(CardanoWasm.from_fingerprint(fingerprint).length).to.equal(20);
+  })
+});
+
+describe('CIP-67 labels', () => {
+  it('encodes and decodes CIP-68 asset names', () => {
+    expect(Buffer.from(CardanoWasm.AssetNameLabel.encode(222)).toString('hex')).to.equal('000de140');
+    const userNft = CardanoWasm.AssetName.new_cip68_user_nft(Buffer.from('SpaceBud'));
+    expect(userNft.to_hex()).to.equal('000de1405370616365427564');
+    const decoded = CardanoWasm.AssetNameLabel.decode(userNft);
+    expect(decoded?.label()).to.equal(222);
+    expect(Buffer.from(decoded!.content()).toString()).to.equal('SpaceBud');
+    expect(CardanoWasm.AssetNameLabel.decode(CardanoWasm.AssetName.from_str('SpaceBud'))).to.equal(undefined);
+  })
+});
+
+describe('JS values', () => {
+  // to_js_value() should have the same structure as JSON.parse(to_json())
+  function expectSameAsJson(obj: { to_json(): string, to_js_value(): any }) {
+    expect(obj.to_js_value()).to.deep.equal(JSON.parse(obj.to_json()));
+  }
+
+  it('converts high-traffic types directly to JS objects', () => {
+    const policyId = CardanoWasm.ScriptHash.from_hex('aa'.repeat(28));
+    const assets = CardanoWasm.MultiAsset.new();
+    assets.set(policyId, CardanoWasm.AssetName.from_str('token'), BigInt(10));
+    const value = CardanoWasm.Value.new(BigInt(2000000), assets);
+    expectSameAsJson(value);
+
+    const inputs = CardanoWasm.TransactionInputList.new();
+    inputs.add(CardanoWasm.TransactionInput.new(CardanoWasm.TransactionHash.from_hex('bb'.repeat(32)), BigInt(1)));
+    const outputs = CardanoWasm.TransactionOutputList.new();
+    outputs.add(CardanoWasm.TransactionOutput.new(
+      CardanoWasm.Address.from_bech32('addr1vx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzers66hrl8'),
+      value,
+    ));
+    const body = CardanoWasm.TransactionBody.new(inputs, outputs, BigInt(170000));
+    expectSameAsJson(body);
+    const tx = CardanoWasm.Transaction.new(body, CardanoWasm.TransactionWitnessSet.new(), true);
+    expectSameAsJson(tx);
+    expect(CardanoWasm.Transaction.from_js_value(tx.to_js_value()).to_cbor_hex()).to.equal(tx.to_cbor_hex());
+
+    const smallDatum = CardanoWasm.PlutusData.new_integer(CardanoWasm.BigInteger.from_str('42'));
+    expectSameAsJson(smallDatum);
+    // JSON.parse() would lose precision here but to_js_value() uses a BigInt for 64-bit integers
+    const datum = CardanoWasm.PlutusData.new_integer(CardanoWasm.BigInteger.from_str('12345678901234567890'));
+    expect(datum.to_js_value()).to.deep.equal({ int: BigInt('12345678901234567890') });
+    expect(CardanoWasm.PlutusData.from_js_value(datum.to_js_value()).to_cbor_hex()).to.equal(datum.to_cbor_hex());
+    // and a decimal string for anything bigger, same as in to_json()
+    const bigDatum = CardanoWasm.PlutusData.new_integer(CardanoWasm.BigInteger.from_str('123456789012345678901234567890'));
+    expect(bigDatum.to_js_value()).to.deep.equal({ int: '123456789012345678901234567890' });
+    expectSameAsJson(bigDatum);
+    expect(CardanoWasm.PlutusData.from_js_value(bigDatum.to_js_value()).to_cbor_hex()).to.equal(bigDatum.to_cbor_hex());
+  })
+});
+
+describe('Collateral', () => {
+  it('reports the exact collateral shortfall', () => {
+    const input = CardanoWasm.TransactionInput.new(CardanoWasm.TransactionHash.from_hex('cc'.repeat(32)), BigInt(0));
+    const output = CardanoWasm.TransactionOutput.new(
+      CardanoWasm.Address.from_bech32('addr1vx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzers66hrl8'),
+      CardanoWasm.Value.from_coin(BigInt(1500000)),
+    );
+    const collateral = CardanoWasm.TransactionInputList.new();
+    collateral.add(input);
+    const resolved = CardanoWasm.TransactionUnspentOutputList.new();
+    resolved.add(CardanoWasm.TransactionUnspentOutput.new(input, output));
+
+    const txWithFee = (fee: bigint) => {
+      const body = CardanoWasm.TransactionBody.new(CardanoWasm.TransactionInputList.new(), CardanoWasm.TransactionOutputList.new(), fee);
+      body.set_collateral_inputs(collateral);
+      return CardanoWasm.Transaction.new(body, CardanoWasm.TransactionWitnessSet.new(), true);
+    };
+    const report = CardanoWasm.check_collateral(txWithFee(BigInt(1000000)), resolved, BigInt(150), BigInt(3));
+    expect(report.collateral()).to.equal(BigInt(1500000));
+    expect(report.excess()).to.equal(BigInt(0));
+    // ceil(1000001 * 1.5) = 1500002
+    expect(() => CardanoWasm.check_collateral(txWithFee(BigInt(1000001)), resolved, BigInt(150), BigInt(3))).to.throw(/short by 2/);
+  })
+});
+
+describe('UTxO grouping', () => {
+  it('groups by stake credential and sums values', () => {
+    const payment = CardanoWasm.Credential.new_pub_key(CardanoWasm.Ed25519KeyHash.from_hex('11'.repeat(28)));
+    const stake = CardanoWasm.Credential.new_pub_key(CardanoWasm.Ed25519KeyHash.from_hex('22'.repeat(28)));
+    const addresses = [
+      CardanoWasm.BaseAddress.new(1, payment, stake).to_address(),
+      CardanoWasm.EnterpriseAddress.new(1, payment).to_address(),
+      CardanoWasm.ByronAddress.from_base58('Ae2tdPwUPEZLs4HtbuNey7tK4hTKrwNwYtGqp7bDfCy2WdR3P6735W5Yfpe').to_address(),
+    ];
+    const utxos = CardanoWasm.TransactionUnspentOutputList.new();
+    addresses.forEach((address, i) => utxos.add(CardanoWasm.TransactionUnspentOutput.new(
+      CardanoWasm.TransactionInput.new(CardanoWasm.TransactionHash.from_hex('ee'.repeat(32)), BigInt(i)),
+      CardanoWasm.TransactionOutput.new(address, CardanoWasm.Value.from_coin(BigInt(1000000 * (i + 1)))),
+    )));
+    const groups = CardanoWasm.group_utxos_by_stake_credential(utxos);
+    expect(groups.len()).to.equal(2);
+    expect(groups.credentials().len()).to.equal(1);
+    expect(groups.get(stake)?.len()).to.equal(1);
+    expect(groups.unstaked()?.len()).to.equal(2);
+    expect(CardanoWasm.summarize_utxos(utxos).coin()).to.equal(BigInt(6000000));
+  })
+});
+
+describe('UTxO ledger', () => {
+  it('chains a transaction spending an unsubmitted output', () => {
+    const address = CardanoWasm.EnterpriseAddress.new(0, CardanoWasm.Credential.new_pub_key(CardanoWasm.Ed25519KeyHash.from_hex('11'.repeat(28)))).to_address();
+    const utxos = CardanoWasm.TransactionUnspentOutputList.new();
+    [0, 1].forEach(i => utxos.add(CardanoWasm.TransactionUnspentOutput.new(
+      CardanoWasm.TransactionInput.new(CardanoWasm.TransactionHash.from_hex('ee'.repeat(32)), BigInt(i)),
+      CardanoWasm.TransactionOutput.new(address, CardanoWasm.Value.from_coin(BigInt(5000000))),
+    )));
+    const ledger = CardanoWasm.UtxoLedger.new(utxos);
+    ledger.reserve_collateral(utxos.get(1).input());
+    expect(ledger.available_utxos().len()).to.equal(1);
+    const outputs = CardanoWasm.TransactionOutputList.new();
+    outputs.add(CardanoWasm.TransactionOutput.new(address, CardanoWasm.Value.from_coin(BigInt(4800000))));
+    const inputs = CardanoWasm.TransactionInputList.new();
+    inputs.add(utxos.get(0).input());
+    const hash = ledger.apply(CardanoWasm.TransactionBody.new(inputs, outputs, BigInt(200000)));
+    const available = ledger.available_utxos();
+    expect(available.len()).to.equal(1);
+    expect(available.get(0).input().transaction_id().to_hex()).to.equal(hash.to_hex());
+    expect(ledger.pending_transactions().len()).to.equal(1);
+    expect(ledger.unapply()?.to_hex()).to.equal(hash.to_hex());
+    expect(ledger.get(utxos.get(0).input())).to.not.equal(undefined);
+  })
+});
+
+describe('Transaction diff', () => {
+  it('reports changed body fields', () => {
+    const txWithFee = (fee: bigint) => CardanoWasm.Transaction.new(
+      CardanoWasm.TransactionBody.new(CardanoWasm.TransactionInputList.new(), CardanoWasm.TransactionOutputList.new(), fee),
+      CardanoWasm.TransactionWitnessSet.new(),
+      true,
+    );
+    expect(txWithFee(BigInt(10)).diff(txWithFee(BigInt(10))).is_empty()).to.equal(true);
+    const diff = txWithFee(BigInt(10)).diff(txWithFee(BigInt(11)));
+    expect(diff.len()).to.equal(1);
+    expect(JSON.parse(diff.to_json())).to.deep.equal({
+      differences: [{ path: 'body.fee', left: '10', right: '11' }],
+    });
+  })
+});
+
+describe('Plutus language floor', () => {
+  it('warns about V1 scripts in a tx with reference inputs', () => {
+    const refInputs = CardanoWasm.TransactionInputList.new();
+    refInputs.add(CardanoWasm.TransactionInput.new(CardanoWasm.TransactionHash.from_hex('dd'.repeat(32)), BigInt(0)));
+    const body = CardanoWasm.TransactionBody.new(CardanoWasm.TransactionInputList.new(), CardanoWasm.TransactionOutputList.new(), BigInt(0));
+    body.set_reference_inputs(refInputs);
+    const script = CardanoWasm.PlutusV1Script.from_raw_bytes(new Uint8Array(10).fill(1));
+    const scripts = CardanoWasm.PlutusV1ScriptList.new();
+    scripts.add(script);
+    const witnessSet = CardanoWasm.TransactionWitnessSet.new();
+    witnessSet.set_plutus_v1_scripts(scripts);
+    const tx = CardanoWasm.Transaction.new(body, witnessSet, true);
+    expect(tx.required_plutus_language_floor()).to.equal(CardanoWasm.Language.PlutusV2);
+    expect(tx.plutus_language_warnings()).to.deep.equal([{
+      script_hash: script.hash().to_hex(),
+      language: 'PlutusV1',
+      unsupported_features: ['ReferenceInputs'],
+    }]);
+  })
+});
+
+describe('Transaction stats', () => {
+  it('counts a simple transaction', () => {
+    const inputs = CardanoWasm.TransactionInputList.new();
+    inputs.add(CardanoWasm.TransactionInput.new(CardanoWasm.TransactionHash.from_hex('dd'.repeat(32)), BigInt(0)));
+    const body = CardanoWasm.TransactionBody.new(inputs, CardanoWasm.TransactionOutputList.new(), BigInt(200000));
+    const tx = CardanoWasm.Transaction.new(body, CardanoWasm.TransactionWitnessSet.new(), true);
+    const stats = tx.stats_with_min_fee(
+      CardanoWasm.LinearFee.new(BigInt(44), BigInt(155381), BigInt(15)),
+      CardanoWasm.ExUnitPrices.new(CardanoWasm.Rational.new(BigInt(577), BigInt(10000)), CardanoWasm.Rational.new(BigInt(721), BigInt(10000000))),
+      BigInt(0),
+    );
+    expect(stats.size).to.equal(tx.to_cbor_bytes().length);
+    expect(stats.input_count).to.equal(1);
+    expect(stats.plutus_v3_scripts).to.deep.equal({ count: 0, size: 0 });
+    expect(stats.min_fee).to.equal(155381 + 44 * stats.size);
+    expect(tx.stats().min_fee).to.equal(null);
+  })
+});
+
+describe('Non-zero amounts', () => {
+  it('rejects zero mint amounts and donations', () => {
+    const assets = CardanoWasm.MapAssetNameToNonZeroInt64.new();
+    const name = CardanoWasm.AssetName.from_str('token');
+    expect(() => assets.insert(name, BigInt(0))).to.throw();
+    assets.insert(name, BigInt(-5));
+    expect(assets.get(name)).to.equal(BigInt(-5));
+    const body = CardanoWasm.TransactionBody.new(CardanoWasm.TransactionInputList.new(), CardanoWasm.TransactionOutputList.new(), BigInt(0));
+    expect(() => body.set_donation(BigInt(0))).to.throw();
+    body.set_donation(BigInt(1));
+    expect(body.donation()).to.equal(BigInt(1));
+  })
+});
+
+describe('Transaction feature flags', () => {
+  it('reports present body fields', () => {
+    const inputs = CardanoWasm.TransactionInputList.new();
+    inputs.add(CardanoWasm.TransactionInput.new(CardanoWasm.TransactionHash.from_hex('dd'.repeat(32)), BigInt(0)));
+    const body = CardanoWasm.TransactionBody.new(inputs, CardanoWasm.TransactionOutputList.new(), BigInt(200000));
+    body.set_ttl(BigInt(1000));
+    const features = body.feature_flags();
+    expect(features.has_ttl).to.equal(true);
+    expect(features.has_mint).to.equal(false);
+    expect(features.cert_count).to.equal(0);
+    const tx = CardanoWasm.Transaction.new(body, CardanoWasm.TransactionWitnessSet.new(), true);
+    expect(tx.feature_flags()).to.deep.equal(features);
+  })
+});
+
+describe('Plutus scripts', () => {
+  it('hashes the same regardless of the hex form given', () => {
+    // the always succeeds script from the plutus examples
+    const cborHex = '4e4d01000033222220051200120011';
+    const rawHex = '4d01000033222220051200120011';
+    const flatHex = '01000033222220051200120011';
+    const expectedHash = '67f33146617a5e61936081db3b2117cbf59bd2123748f58ac9678656';
+    expect(CardanoWasm.PlutusV1Script.from_cbor_hex(cborHex).hash().to_hex()).to.equal(expectedHash);
+    expect(CardanoWasm.PlutusV1Script.from_hex(rawHex).hash().to_hex()).to.equal(expectedHash);
+    // passing the CBOR form as raw bytes results in a different script
+    expect(CardanoWasm.PlutusV1Script.from_hex(cborHex).hash().to_hex()).to.not.equal(expectedHash);
+    for (const hex of [cborHex, rawHex, flatHex]) {
+      const script = CardanoWasm.PlutusV1Script.normalize(hex);
+      expect(script.hash().to_hex()).to.equal(expectedHash);
+      expect(script.to_hex()).to.equal(rawHex);
+      expect(script.to_cbor_hex()).to.equal(cborHex);
+    }
+  })
+});
+
+describe('Native script signers', () => {
+  it('only requires the signers needed within the validity interval', () => {
+    const keyA = 'aa'.repeat(28);
+    const keyB = 'bb'.repeat(28);
+    const script = CardanoWasm.NativeScript.from_cli_json(JSON.stringify({
+      type: 'atLeast',
+      required: 2,
+      scripts: [
+        { type: 'sig', keyHash: keyA },
+        { type: 'sig', keyHash: keyB },
+        { type: 'before', slot: 100 },
+      ],
+    }));
+    expect(script.min_signers_within(undefined, BigInt(50), 100)).to.deep.equal([[keyA], [keyB]]);
+    expect(script.min_signers_within(undefined, BigInt(100), 100)).to.deep.equal([[keyA, keyB]]);
+    const unsatisfiable = CardanoWasm.NativeScript.from_cli_json(JSON.stringify({ type: 'after', slot: 100 }));
+    expect(unsatisfiable.min_signers_within(BigInt(50), undefined, 100)).to.equal(null);
+  })
+});
+
+describe('Byron redeem addresses', () => {
+  it('signs and verifies AVVM redemptions', () => {
+    const key = CardanoWasm.PrivateKey.from_normal_bytes(Buffer.from('07'.repeat(32), 'hex'));
+    const mainnet = CardanoWasm.ProtocolMagic.new(764824073);
+    const addr = CardanoWasm.ByronAddress.new_redeem(key.to_public(), mainnet);
+    expect(CardanoWasm.ByronAddress.is_valid(addr.to_base58())).to.equal(true);
+    const txHash = addr.genesis_tx_hash();
+    const sig = CardanoWasm.make_redeem_signature(txHash, key, mainnet);
+    expect(CardanoWasm.verify_redeem_signature(txHash, key.to_public(), sig, mainnet)).to.equal(true);
+    const testnet = CardanoWasm.ProtocolMagic.new(1097911063);
+    expect(CardanoWasm.verify_redeem_signature(txHash, key.to_public(), sig, testnet)).to.equal(false);
+  })
+});
+
+describe('String wrapper accessors', () => {
+  // keeps every wasm type wrapping a string usable as one from JS
+  const stringWrappers = ['AssetName', 'DNSName', 'Ipv4', 'Ipv6', 'Url'];
+  for (const name of stringWrappers) {
+    it(`${name} exposes to_str()`, () => {
+      expect((CardanoWasm as any)[name].prototype.to_str).to.be.a('function');
+    })
+  }
+
+  it('returns plain strings for nested wrappers', () => {
+    const url = CardanoWasm.Url.new('https://example.com/pool.json');
+    expect(url.to_str()).to.equal(url.get());
+    const metadata = CardanoWasm.PoolMetadata.new(url, CardanoWasm.PoolMetadataHash.from_hex('00'.repeat(32)));
+    expect(metadata.url_str()).to.equal('https://example.com/pool.json');
+  })
+});
+
+describe('Relay strings', () => {
+  it('parses and renders relays', () => {
+    for (const relay of ['203.0.113.7:3001', '[2001:db8::1]:3001', 'relay.example.com:3001', '_srv.relays.example.com']) {
+      expect(CardanoWasm.Relay.from_str(relay).to_str()).to.equal(relay);
+    }
+    expect(() => CardanoWasm.Relay.from_str(`${'a'.repeat(130)}:3001`)).to.throw();
+  })
+});
+
+describe('Witness set builder', () => {
+  it('assembles externally produced signatures into a transaction', () => {
+    const account = getCip1852Account();
+    const keys = [account.derive(ChainDerivation.EXTERNAL).derive(0), account.derive(ChainDerivation.CHIMERIC).derive(0)]
+      .map((key) => key.to_raw_key());
+    const body = CardanoWasm.TransactionBody.new(CardanoWasm.TransactionInputList.new(), CardanoWasm.TransactionOutputList.new(), BigInt(200000));
+    const bodyHash = CardanoWasm.hash_transaction(body);
+
+    // e.g. signatures returned by an HSM / hardware wallet as raw bytes
+    const rawSignatures = keys.map((key) => key.sign(bodyHash.to_raw_bytes()).to_raw_bytes());
+    const builder = CardanoWasm.TransactionWitnessSetBuilder.new();
+    const required = CardanoWasm.RequiredWitnessSet.new();
+    keys.forEach((key) => required.add_vkey_key_hash(key.to_public().hash()));
+    builder.add_required_wits(required);
+    expect(builder.remaining_wits().vkeys().len()).to.equal(2);
+    keys.forEach((key, i) => builder.add_vkey(CardanoWasm.Vkeywitness.new(
+      key.to_public(),
+      CardanoWasm.Ed25519Signature.from_raw_bytes(rawSignatures[i]),
+    )));
+    expect(builder.remaining_wits().vkeys().len()).to.equal(0);
+
+    // merge into a tx that already has other witnesses
+    const datum = CardanoWasm.PlutusData.new_integer_from_u64(BigInt(42));
+    const existing = CardanoWasm.TransactionWitnessSetBuilder.new();
+    existing.add_plutus_datum(datum);
+    const tx = CardanoWasm.Transaction.new(body, existing.build(), true);
+    builder.add_existing(tx.witness_set());
+    const witnessSet = builder.try_build();
+    const signed = CardanoWasm.Transaction.new(tx.body(), witnessSet, true);
+
+    const vkeys = signed.witness_set().vkeywitnesses()!;
+    expect(vkeys.len()).to.equal(2);
+    for (let i = 0; i < vkeys.len(); ++i) {
+      expect(vkeys.get(i).vkey().verify(bodyHash.to_raw_bytes(), vkeys.get(i).ed25519_signature())).to.equal(true);
+    }
+    expect(signed.witness_set().plutus_datums()!.get(0).to_cbor_hex()).to.equal(datum.to_cbor_hex());
+  })
+});
+
+describe('Transaction parts', () => {
+  it('swaps the fee and re-signs', () => {
+    const key = getCip1852Account().derive(ChainDerivation.EXTERNAL).derive(0).to_raw_key();
+    const sign = (body: CardanoWasm.TransactionBody) => {
+      const builder = CardanoWasm.TransactionWitnessSetBuilder.new();
+      builder.add_vkey(CardanoWasm.make_vkey_witness(CardanoWasm.hash_transaction(body), key));
+      return builder.build();
+    };
+    const body = CardanoWasm.TransactionBody.new(CardanoWasm.TransactionInputList.new(), CardanoWasm.TransactionOutputList.new(), BigInt(200000));
+    const tx = CardanoWasm.Transaction.new(body, sign(body), true);
+
+    // body() returns a copy so the edited body has to be swapped back in
+    const bumped = tx.with_body(tx.body().with_fee(BigInt(210000)), true);
+    expect(bumped.body().fee()).to.equal(BigInt(210000));
+    expect(bumped.witness_set().vkeywitnesses()).to.equal(undefined);
+    expect(tx.body().fee()).to.equal(BigInt(200000));
+
+    const resigned = bumped.with_witness_set(sign(bumped.body()));
+    const vkey = resigned.witness_set().vkeywitnesses()!.get(0);
+    const bodyHash = CardanoWasm.hash_transaction(resigned.body());
+    expect(vkey.vkey().verify(bodyHash.to_raw_bytes(), vkey.ed25519_signature())).to.equal(true);
+    expect(resigned.with_body(body, false).with_witness_set(tx.witness_set()).to_cbor_hex()).to.equal(tx.to_cbor_hex());
+  })
+});
+
+describe('Enum kinds', () => {
+  // Conway preview block (without the network era wrapper) with a DRep registration
+  const conwayBlockHex = '85828a1a0006ddf41a0089a98c58205172bc8843f4b565ecf0f72f86944afb0103d126bd1b6940de33a6125988be665820149174cec3572f88abaa0e3c22aa992f2f254db15ebfea54f93b59c71151801a58207e7f6a8978acb0ed37219cc5a917f8cd5bf6fd53538254ac921790e159baf8ad825840438fe265be4b13d6e1f5af3a6fe8a7a4c0ef1b832b614f697b80def3467db19740640fc9aed4ef158230ad910af46e597de22030a2c47556d17805c13866d7ca5850ab4849c172cf814f266bab8ae5327902bd481a0bfab03141d00c2e4e810732bea4739f94e068621a0573870379c51e76229e8cc345163781f8cb43683e1a79805ed84576fc3bf3fb4b431074241dac0519010858200ea62bbbba0549c668a54579873e3c35e64a899007f191bdf17b43bd01b4c7a28458205f7a4914a82f0120d63e3e63a235ad20ed871d816ba28f032c75f69e3a7df3e20018445840a437a97e0dd7b08a7744107cc329a6a661d6c1c844d0f35e72906f5e5762260b4189bc49be29993aa0b64a338e5fa8462953c55a2b4b4299edf40c8617f1e8038209005901c0aba65daed3986c74218c9e2d026e9285fbc5276efcdab8f41358df8cd7879574257f95b76540de3ac02d24a5e00f05327621e9fd01c6cf8f302771fe069b3b03c7e3637ea1b36208c4ab40b97866d05055ee2d8254fb44f54e26bef97b9e412a1377bb2a7224f8ed5430a83bdaeb963a9c492f721cb499a42a9dc49957315aa7144f304fe3af8f28a619d9b48e57293fd18332d0a277b8beb1651f502f6617d400c63dc3c0f3de0fc2a52b6c05a0557d5f1bf907a15a85ef5bb6a91406f01f2d63801e68e3fb16a8cf654afc9c7f9da3958c79fca1806405802a743377c89bb933ac69598ce1153cc7dda40a504ae5555af78e9052e8b767c7d8dbb943bffbf7093896c7238aeb4031bd72e794c23c2710b973e351ac5004ebf014b7a0d3daaa1070e7ed0288f45e5c776f174f826c0c810cff417b2ce0767f6210dc26710e4958f68800addfe210f1f7388edd1b6b96c9ad9e2a615cdc6d472daa421449c33eedda2a193755b70258fca6a9ae1a4cc0cd16bf1e37664f9562ef82c458cd170a71c2c45ff0cba71736d78d60abb33febace57b324410260f179cb8bd837c80ce114339d89daecbe578a2518877dd2b4109624153c717f1f4c704386a88e3590c81a40081825820917aaf395181a0359ca8ee314fe8355c5e2239d66483b164c62cac2e41ea4a0d000181a200583900db1bc3c3f99ce68977ceaf27ab4dd917123ef9e73f85c304236eab2397a993b0c8166aa8c48345be19257a4025ab764c86e799beab15b303011b0000000253fcbcba021a00028bfd048184108200581c97a993b0c8166aa8c48345be19257a4025ab764c86e799beab15b30300f681a100818258205f89ea8c6ab580e2e7a32c3586869eb95fae54f42ac982639b6665359601f63e58401c012befc2a4d4e22e6c7be4483de4d7ac550050ac3ff7d481c503cef64ce234a76ea6dcbd70f9a79de6adb869b3599d28d2cf351643a5cc6e36205d39efc50da080';
+
+  it('switches over every certificate kind', () => {
+    const describeCert = (cert: CardanoWasm.Certificate): string => {
+      const kind = cert.kind();
+      switch (kind) {
+        case CardanoWasm.CertificateKind.StakeRegistration: return 'stake registration';
+        case CardanoWasm.CertificateKind.StakeDeregistration: return 'stake deregistration';
+        case CardanoWasm.CertificateKind.StakeDelegation: return 'stake delegation';
+        case CardanoWasm.CertificateKind.PoolRegistration: return 'pool registration';
+        case CardanoWasm.CertificateKind.PoolRetirement: return 'pool retirement';
+        case CardanoWasm.CertificateKind.RegCert: return 'registration';
+        case CardanoWasm.CertificateKind.UnregCert: return 'deregistration';
+        case CardanoWasm.CertificateKind.VoteDelegCert: return 'vote delegation';
+        case CardanoWasm.CertificateKind.StakeVoteDelegCert: return 'stake and vote delegation';
+        case CardanoWasm.CertificateKind.StakeRegDelegCert: return 'registration and stake delegation';
+        case CardanoWasm.CertificateKind.VoteRegDelegCert: return 'registration and vote delegation';
+        case CardanoWasm.CertificateKind.StakeVoteRegDelegCert: return 'registration and stake and vote delegation';
+        case CardanoWasm.CertificateKind.AuthCommitteeHotCert: return 'committee hot key authorization';
+        case CardanoWasm.CertificateKind.ResignCommitteeColdCert: return 'committee resignation';
+        case CardanoWasm.CertificateKind.RegDrepCert: return 'DRep registration';
+        case CardanoWasm.CertificateKind.UnregDrepCert: return 'DRep deregistration';
+        case CardanoWasm.CertificateKind.UpdateDrepCert: return 'DRep update';
+        default: {
+          // fails to compile if a kind isn't handled above
+          const unhandled: never = kind;
+          throw new Error(`unknown certificate kind ${unhandled}`);
+        }
+      }
+    };
+
+    const block = CardanoWasm.Block.from_cbor_hex(conwayBlockHex);
+    const bodies = block.transaction_bodies();
+    const described: string[] = [];
+    for (let i = 0; i < bodies.len(); i++) {
+      const certs = bodies.get(i).certs();
+      for (let j = 0; j < (certs?.len() ?? 0); j++) {
+        described.push(describeCert(certs!.get(j)));
+      }
+    }
+    expect(described).to.deep.equal(['DRep registration']);
+  })
+
+  it('has stable numeric values', () => {
+    expect(CardanoWasm.CertificateKind.StakeRegistration).to.equal(0);
+    expect(CardanoWasm.CertificateKind.RegDrepCert).to.equal(14);
+    expect(CardanoWasm.CertificateKind.UpdateDrepCert).to.equal(16);
+    expect(CardanoWasm.DRepKind.AlwaysNoConfidence).to.equal(3);
+    expect(CardanoWasm.RelayKind.MultiHostName).to.equal(2);
+    expect(CardanoWasm.GovActionKind.InfoAction).to.equal(6);
+    expect(CardanoWasm.NativeScriptKind.ScriptInvalidHereafter).to.equal(5);
+  })
+});
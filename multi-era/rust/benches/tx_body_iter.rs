@@ -0,0 +1,123 @@
+//! Compares the owned (cloning) MultiEraTransactionBody accessors with the borrowing iter_*() ones
+//! over a large Conway block. Besides criterion's timings, the number of heap allocations
+//! done by one pass over the block is printed for each approach.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use cml_chain::certs::{Certificate, StakeCredential};
+use cml_chain::transaction::{TransactionBody, TransactionInput, TransactionOutput};
+use cml_chain::Value;
+use cml_core::serialization::Serialize;
+use cml_crypto::{Ed25519KeyHash, TransactionHash};
+use cml_multi_era::{MultiEraBlock, MultiEraTransactionBody};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+// preview testnet block, whose header is reused for the large block
+const CONWAY_PREVIEW_BLOCK: &str = "820785828a1a0006ddf41a0089a98c58205172bc8843f4b565ecf0f72f86944afb0103d126bd1b6940de33a6125988be665820149174cec3572f88abaa0e3c22aa992f2f254db15ebfea54f93b59c71151801a58207e7f6a8978acb0ed37219cc5a917f8cd5bf6fd53538254ac921790e159baf8ad825840438fe265be4b13d6e1f5af3a6fe8a7a4c0ef1b832b614f697b80def3467db19740640fc9aed4ef158230ad910af46e597de22030a2c47556d17805c13866d7ca5850ab4849c172cf814f266bab8ae5327902bd481a0bfab03141d00c2e4e810732bea4739f94e068621a0573870379c51e76229e8cc345163781f8cb43683e1a79805ed84576fc3bf3fb4b431074241dac0519010858200ea62bbbba0549c668a54579873e3c35e64a899007f191bdf17b43bd01b4c7a28458205f7a4914a82f0120d63e3e63a235ad20ed871d816ba28f032c75f69e3a7df3e20018445840a437a97e0dd7b08a7744107cc329a6a661d6c1c844d0f35e72906f5e5762260b4189bc49be29993aa0b64a338e5fa8462953c55a2b4b4299edf40c8617f1e8038209005901c0aba65daed3986c74218c9e2d026e9285fbc5276efcdab8f41358df8cd7879574257f95b76540de3ac02d24a5e00f05327621e9fd01c6cf8f302771fe069b3b03c7e3637ea1b36208c4ab40b97866d05055ee2d8254fb44f54e26bef97b9e412a1377bb2a7224f8ed5430a83bdaeb963a9c492f721cb499a42a9dc49957315aa7144f304fe3af8f28a619d9b48e57293fd18332d0a277b8beb1651f502f6617d400c63dc3c0f3de0fc2a52b6c05a0557d5f1bf907a15a85ef5bb6a91406f01f2d63801e68e3fb16a8cf654afc9c7f9da3958c79fca1806405802a743377c89bb933ac69598ce1153cc7dda40a504ae5555af78e9052e8b767c7d8dbb943bffbf7093896c7238aeb4031bd72e794c23c2710b973e351ac5004ebf014b7a0d3daaa1070e7ed0288f45e5c776f174f826c0c810cff417b2ce0767f6210dc26710e4958f68800addfe210f1f7388edd1b6b96c9ad9e2a615cdc6d472daa421449c33eedda2a193755b70258fca6a9ae1a4cc0cd16bf1e37664f9562ef82c458cd170a71c2c45ff0cba71736d78d60abb33febace57b324410260f179cb8bd837c80ce114339d89daecbe578a2518877dd2b4109624153c717f1f4c704386a88e3590c81a40081825820917aaf395181a0359ca8ee314fe8355c5e2239d66483b164c62cac2e41ea4a0d000181a200583900db1bc3c3f99ce68977ceaf27ab4dd917123ef9e73f85c304236eab2397a993b0c8166aa8c48345be19257a4025ab764c86e799beab15b303011b0000000253fcbcba021a00028bfd048184108200581c97a993b0c8166aa8c48345be19257a4025ab764c86e799beab15b30300f681a100818258205f89ea8c6ab580e2e7a32c3586869eb95fae54f42ac982639b6665359601f63e58401c012befc2a4d4e22e6c7be4483de4d7ac550050ac3ff7d481c503cef64ce234a76ea6dcbd70f9a79de6adb869b3599d28d2cf351643a5cc6e36205d39efc50da080";
+
+const TXS: usize = 500;
+const ELEMS_PER_TX: u64 = 20;
+
+fn large_conway_block() -> Vec<MultiEraTransactionBody> {
+    let mut block = match MultiEraBlock::from_explicit_network_cbor_bytes(
+        &hex::decode(CONWAY_PREVIEW_BLOCK).unwrap(),
+    )
+    .unwrap()
+    {
+        MultiEraBlock::Conway(block) => block,
+        _ => panic!("expected conway block"),
+    };
+    let address = block.transaction_bodies[0].outputs[0].address().clone();
+    let mut body = TransactionBody::new(
+        (0..ELEMS_PER_TX)
+            .map(|index| TransactionInput::new(TransactionHash::from([1; 32]), index))
+            .collect::<Vec<_>>()
+            .into(),
+        (0..ELEMS_PER_TX)
+            .map(|coin| TransactionOutput::new(address.clone(), Value::from(coin), None, None))
+            .collect(),
+        0,
+    );
+    body.certs = Some(
+        (0..ELEMS_PER_TX)
+            .map(|i| {
+                Certificate::new_stake_registration(StakeCredential::new_pub_key(
+                    Ed25519KeyHash::from([i as u8; 28]),
+                ))
+            })
+            .collect::<Vec<_>>()
+            .into(),
+    );
+    block.transaction_bodies = vec![body; TXS];
+    let block = MultiEraBlock::Conway(block);
+    // round-trip so the bodies are exactly as if they were parsed from the chain
+    let bytes = match &block {
+        MultiEraBlock::Conway(block) => block.to_cbor_bytes(),
+        _ => unreachable!(),
+    };
+    MultiEraBlock::from_explicit_network_cbor_bytes(&[&[0x82, 0x07][..], &bytes].concat())
+        .unwrap()
+        .transaction_bodies()
+}
+
+fn scan_owned(bodies: &[MultiEraTransactionBody]) -> u64 {
+    let mut acc = 0;
+    for body in bodies {
+        acc += body.inputs().iter().filter_map(|i| i.index()).sum::<u64>();
+        acc += body.outputs().iter().map(|o| o.amount().coin).sum::<u64>();
+        acc += body.certs().map(|certs| certs.len() as u64).unwrap_or(0);
+    }
+    acc
+}
+
+fn scan_borrowed(bodies: &[MultiEraTransactionBody]) -> u64 {
+    let mut acc = 0;
+    for body in bodies {
+        acc += body.iter_inputs().filter_map(|i| i.index()).sum::<u64>();
+        acc += body.iter_outputs().map(|o| o.coin()).sum::<u64>();
+        acc += body.iter_certs().count() as u64;
+    }
+    acc
+}
+
+fn count_allocations(f: impl FnOnce() -> u64) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    black_box(f());
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+fn tx_body_iter(c: &mut Criterion) {
+    let bodies = large_conway_block();
+    assert_eq!(scan_owned(&bodies), scan_borrowed(&bodies));
+    println!(
+        "allocations per block scan: owned = {}, borrowed = {}",
+        count_allocations(|| scan_owned(&bodies)),
+        count_allocations(|| scan_borrowed(&bodies)),
+    );
+    let mut group = c.benchmark_group("conway_block_scan");
+    group.bench_function("owned", |b| b.iter(|| scan_owned(black_box(&bodies))));
+    group.bench_function("borrowed", |b| b.iter(|| scan_borrowed(black_box(&bodies))));
+    group.finish();
+}
+
+criterion_group!(benches, tx_body_iter);
+criterion_main!(benches);
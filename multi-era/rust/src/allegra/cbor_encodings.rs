@@ -36,6 +36,7 @@ pub struct AllegraTransactionBodyEncoding {
     pub auxiliary_data_hash_key_encoding: Option<cbor_event::Sz>,
     pub validity_interval_start_encoding: Option<cbor_event::Sz>,
     pub validity_interval_start_key_encoding: Option<cbor_event::Sz>,
+    pub original_bytes: Option<Vec<u8>>,
 }
 
 #[derive(Clone, Debug, Default)]
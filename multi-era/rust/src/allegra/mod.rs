@@ -28,13 +28,16 @@ use cml_chain::transaction::{NativeScript, TransactionInput};
 use cml_chain::Withdrawals;
 use cml_chain::{DeltaCoin, LenEncoding, TransactionIndex};
 use cml_core::ordered_hash_map::OrderedHashMap;
+use cml_core::serialization::StripEncodings;
 use cml_core::Epoch;
 use cml_crypto::{Ed25519KeyHash, GenesisDelegateHash, GenesisHash, VRFKeyHash};
 use std::collections::BTreeMap;
 
 use self::cbor_encodings::{MoveInstantaneousRewardEncoding, MoveInstantaneousRewardsCertEncoding};
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub enum AllegraAuxiliaryData {
     Shelley(ShelleyFormatAuxData),
     ShelleyMA(ShelleyMAFormatAuxData),
@@ -50,7 +53,9 @@ impl AllegraAuxiliaryData {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct AllegraBlock {
     pub header: ShelleyHeader,
     pub transaction_bodies: Vec<AllegraTransactionBody>,
@@ -78,7 +83,9 @@ impl AllegraBlock {
 }
 
 #[allow(clippy::large_enum_variant)]
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub enum AllegraCertificate {
     StakeRegistration(StakeRegistration),
     StakeDeregistration(StakeDeregistration),
@@ -134,7 +141,9 @@ impl AllegraCertificate {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct AllegraTransaction {
     pub body: AllegraTransactionBody,
     pub witness_set: AllegraTransactionWitnessSet,
@@ -158,7 +167,9 @@ impl AllegraTransaction {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct AllegraTransactionBody {
     pub inputs: Vec<TransactionInput>,
     pub outputs: Vec<ShelleyTransactionOutput>,
@@ -194,7 +205,9 @@ impl AllegraTransactionBody {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct AllegraTransactionWitnessSet {
     pub vkeywitnesses: Option<Vec<Vkeywitness>>,
     pub native_scripts: Option<Vec<NativeScript>>,
@@ -220,7 +233,9 @@ impl Default for AllegraTransactionWitnessSet {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub enum MIRAction {
     ToStakeCredentials {
         to_stake_credentials: OrderedHashMap<StakeCredential, DeltaCoin>,
@@ -263,6 +278,7 @@ impl MIRAction {
     serde::Deserialize,
     serde::Serialize,
     schemars::JsonSchema,
+    StripEncodings,
 )]
 #[wasm_bindgen]
 pub enum MIRPot {
@@ -270,7 +286,9 @@ pub enum MIRPot {
     Treasury,
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct MoveInstantaneousReward {
     pub pot: MIRPot,
     pub action: MIRAction,
@@ -288,7 +306,9 @@ impl MoveInstantaneousReward {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct MoveInstantaneousRewardsCert {
     pub move_instantaneous_reward: MoveInstantaneousReward,
     #[serde(skip)]
@@ -139,10 +139,7 @@ impl Serialize for AllegraBlock {
             .collect::<Result<Vec<(Vec<u8>, &_, &_)>, cbor_event::Error>>()?;
         if force_canonical {
             key_order.sort_by(|(lhs_bytes, _, _), (rhs_bytes, _, _)| {
-                match lhs_bytes.len().cmp(&rhs_bytes.len()) {
-                    std::cmp::Ordering::Equal => lhs_bytes.cmp(rhs_bytes),
-                    diff_ord => diff_ord,
-                }
+                canonical_key_cmp(lhs_bytes, rhs_bytes)
             });
         }
         for (key_bytes, _key, value) in key_order {
@@ -164,6 +161,15 @@ impl Serialize for AllegraBlock {
 
 impl Deserialize for AllegraBlock {
     fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
+        Self::deserialize_opt_keep_original(raw, false)
+    }
+}
+
+impl DeserializeKeepOriginal for AllegraBlock {
+    fn deserialize_opt_keep_original<R: BufRead + Seek>(
+        raw: &mut Deserializer<R>,
+        keep_original: bool,
+    ) -> Result<Self, DeserializeError> {
         let len = raw.array_sz()?;
         let len_encoding: LenEncoding = len.into();
         let mut read_len = CBORReadLen::new(len);
@@ -180,7 +186,7 @@ impl Deserialize for AllegraBlock {
                         assert_eq!(raw.special()?, cbor_event::Special::Break);
                         break;
                     }
-                    transaction_bodies_arr.push(AllegraTransactionBody::deserialize(raw)?);
+                    transaction_bodies_arr.push(AllegraTransactionBody::deserialize_maybe_keep_original(raw, keep_original)?);
                 }
                 Ok((transaction_bodies_arr, transaction_bodies_encoding))
             })().map_err(|e| e.annotate("transaction_bodies"))?;
@@ -763,15 +769,9 @@ impl Serialize for AllegraTransactionBody {
                             })
                             .collect::<Result<Vec<(Vec<u8>, &_, &_)>, cbor_event::Error>>()?;
                         if force_canonical {
-                            key_order.sort_by(
-                                |(lhs_bytes, _, _), (rhs_bytes, _, _)| match lhs_bytes
-                                    .len()
-                                    .cmp(&rhs_bytes.len())
-                                {
-                                    std::cmp::Ordering::Equal => lhs_bytes.cmp(rhs_bytes),
-                                    diff_ord => diff_ord,
-                                },
-                            );
+                            key_order.sort_by(|(lhs_bytes, _, _), (rhs_bytes, _, _)| {
+                                canonical_key_cmp(lhs_bytes, rhs_bytes)
+                            });
                         }
                         for (key_bytes, key, value) in key_order {
                             serializer.write_raw_bytes(&key_bytes)?;
@@ -1124,6 +1124,7 @@ impl Deserialize for AllegraTransactionBody {
                     auxiliary_data_hash_encoding,
                     validity_interval_start_key_encoding,
                     validity_interval_start_encoding,
+                    original_bytes: None,
                 }),
             })
         })().map_err(|e| e.annotate("AllegraTransactionBody"))
@@ -1461,10 +1462,7 @@ impl Serialize for MIRAction {
                     .collect::<Result<Vec<(Vec<u8>, &_, &_)>, cbor_event::Error>>()?;
                 if force_canonical {
                     key_order.sort_by(|(lhs_bytes, _, _), (rhs_bytes, _, _)| {
-                        match lhs_bytes.len().cmp(&rhs_bytes.len()) {
-                            std::cmp::Ordering::Equal => lhs_bytes.cmp(rhs_bytes),
-                            diff_ord => diff_ord,
-                        }
+                        canonical_key_cmp(lhs_bytes, rhs_bytes)
                     });
                 }
                 for (key_bytes, _key, value) in key_order {
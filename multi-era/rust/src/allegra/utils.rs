@@ -1,13 +1,40 @@
 use cml_chain::{auxdata::AuxiliaryData, transaction::TransactionWitnessSet};
 
-use super::{AllegraAuxiliaryData, AllegraTransactionBody, AllegraTransactionWitnessSet};
+use super::{
+    AllegraAuxiliaryData, AllegraCertificate, AllegraTransactionBody, AllegraTransactionWitnessSet,
+    MIRAction,
+};
+use crate::shelley::ShelleyPoolRegistration;
 
-use cml_core::serialization::Serialize;
+use cml_chain::{
+    certs::{Certificate, StakeCredential},
+    Coin, DeltaCoin, OrderedHashMap,
+};
+use cml_core::serialization::{OriginalBytes, Serialize};
 use cml_crypto::{blake2b256, TransactionHash};
+use std::convert::TryFrom;
 
 impl AllegraTransactionBody {
+    /// Uses the original bytes if they were kept (see OriginalBytes)
     pub fn hash(&self) -> TransactionHash {
-        blake2b256(&self.to_cbor_bytes()).into()
+        match self.original_bytes() {
+            Some(original_bytes) => blake2b256(original_bytes).into(),
+            None => blake2b256(&self.to_cbor_bytes()).into(),
+        }
+    }
+}
+
+impl OriginalBytes for AllegraTransactionBody {
+    fn original_bytes(&self) -> Option<&[u8]> {
+        self.encodings
+            .as_ref()
+            .and_then(|encs| encs.original_bytes.as_deref())
+    }
+
+    fn set_original_bytes(&mut self, original_bytes: Option<Vec<u8>>) {
+        self.encodings
+            .get_or_insert_with(Default::default)
+            .original_bytes = original_bytes;
     }
 }
 
@@ -29,3 +56,59 @@ impl From<AllegraTransactionWitnessSet> for TransactionWitnessSet {
         new_wits
     }
 }
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MIRActionKind {
+    ToStakeCredentials,
+    ToOtherPot,
+}
+
+impl TryFrom<Certificate> for AllegraCertificate {
+    /// Conway certificates (and pool registrations with relay DNS names over 64 bytes)
+    /// can't be represented before Conway so they are returned as-is
+    type Error = Certificate;
+
+    fn try_from(cert: Certificate) -> Result<Self, Self::Error> {
+        match cert {
+            Certificate::StakeRegistration(cert) => Ok(Self::StakeRegistration(cert)),
+            Certificate::StakeDeregistration(cert) => Ok(Self::StakeDeregistration(cert)),
+            Certificate::StakeDelegation(cert) => Ok(Self::StakeDelegation(cert)),
+            Certificate::PoolRegistration(cert) => {
+                match ShelleyPoolRegistration::try_from(cert.clone()) {
+                    Ok(cert) => Ok(Self::ShelleyPoolRegistration(cert)),
+                    Err(_) => Err(Certificate::PoolRegistration(cert)),
+                }
+            }
+            Certificate::PoolRetirement(cert) => Ok(Self::PoolRetirement(cert)),
+            cert => Err(cert),
+        }
+    }
+}
+
+impl MIRAction {
+    pub fn kind(&self) -> MIRActionKind {
+        match self {
+            Self::ToStakeCredentials { .. } => MIRActionKind::ToStakeCredentials,
+            Self::ToOtherPot { .. } => MIRActionKind::ToOtherPot,
+        }
+    }
+
+    /// Per-credential reward deltas. These can be negative (since Alonzo) to undo earlier MIRs.
+    pub fn as_to_stake_credentials(&self) -> Option<&OrderedHashMap<StakeCredential, DeltaCoin>> {
+        match self {
+            Self::ToStakeCredentials {
+                to_stake_credentials,
+                ..
+            } => Some(to_stake_credentials),
+            Self::ToOtherPot { .. } => None,
+        }
+    }
+
+    /// Amount moved from the certificate's pot to the other one (reserves <-> treasury)
+    pub fn as_to_other_pot(&self) -> Option<Coin> {
+        match self {
+            Self::ToOtherPot { to_other_pot, .. } => Some(*to_other_pot),
+            Self::ToStakeCredentials { .. } => None,
+        }
+    }
+}
@@ -114,6 +114,7 @@ pub struct AlonzoTransactionBodyEncoding {
     pub required_signers_elem_encodings: Vec<StringEncoding>,
     pub required_signers_key_encoding: Option<cbor_event::Sz>,
     pub network_id_key_encoding: Option<cbor_event::Sz>,
+    pub original_bytes: Option<Vec<u8>>,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -27,11 +27,14 @@ use cml_chain::transaction::{AlonzoFormatTxOut, NativeScript, TransactionInput};
 use cml_chain::TransactionIndex;
 use cml_chain::{Epoch, NetworkId, Rational, RequiredSigners, UnitInterval, Withdrawals};
 use cml_core::ordered_hash_map::OrderedHashMap;
+use cml_core::serialization::StripEncodings;
 use std::collections::BTreeMap;
 
 use self::cbor_encodings::AlonzoRedeemerEncoding;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub enum AlonzoAuxiliaryData {
     Shelley(ShelleyFormatAuxData),
     ShelleyMA(ShelleyMAFormatAuxData),
@@ -52,7 +55,9 @@ impl AlonzoAuxiliaryData {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct AlonzoBlock {
     pub header: ShelleyHeader,
     pub transaction_bodies: Vec<AlonzoTransactionBody>,
@@ -84,7 +89,9 @@ impl AlonzoBlock {
 
 pub type AlonzoCostModels = CostModels;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct AlonzoFormatAuxData {
     pub metadata: Option<Metadata>,
     pub native_scripts: Option<Vec<NativeScript>>,
@@ -113,7 +120,9 @@ impl Default for AlonzoFormatAuxData {
 pub type AlonzoProposedProtocolParameterUpdates =
     OrderedHashMap<GenesisHash, AlonzoProtocolParamUpdate>;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct AlonzoProtocolParamUpdate {
     pub minfee_a: Option<u64>,
     pub minfee_b: Option<u64>,
@@ -181,7 +190,9 @@ impl Default for AlonzoProtocolParamUpdate {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct AlonzoRedeemer {
     pub tag: AlonzoRedeemerTag,
     pub index: u64,
@@ -214,6 +225,7 @@ impl AlonzoRedeemer {
     serde::Deserialize,
     serde::Serialize,
     schemars::JsonSchema,
+    StripEncodings,
 )]
 #[wasm_bindgen]
 pub enum AlonzoRedeemerTag {
@@ -223,7 +235,9 @@ pub enum AlonzoRedeemerTag {
     Reward,
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct AlonzoTransaction {
     pub body: AlonzoTransactionBody,
     pub witness_set: AlonzoTransactionWitnessSet,
@@ -250,7 +264,9 @@ impl AlonzoTransaction {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct AlonzoTransactionBody {
     pub inputs: Vec<TransactionInput>,
     pub outputs: Vec<AlonzoFormatTxOut>,
@@ -292,7 +308,9 @@ impl AlonzoTransactionBody {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct AlonzoTransactionWitnessSet {
     pub vkeywitnesses: Option<Vec<Vkeywitness>>,
     pub native_scripts: Option<Vec<NativeScript>>,
@@ -324,7 +342,9 @@ impl Default for AlonzoTransactionWitnessSet {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct AlonzoUpdate {
     pub proposed_protocol_parameter_updates: AlonzoProposedProtocolParameterUpdates,
     pub epoch: Epoch,
@@ -147,10 +147,7 @@ impl Serialize for AlonzoBlock {
             .collect::<Result<Vec<(Vec<u8>, &_, &_)>, cbor_event::Error>>()?;
         if force_canonical {
             key_order.sort_by(|(lhs_bytes, _, _), (rhs_bytes, _, _)| {
-                match lhs_bytes.len().cmp(&rhs_bytes.len()) {
-                    std::cmp::Ordering::Equal => lhs_bytes.cmp(rhs_bytes),
-                    diff_ord => diff_ord,
-                }
+                canonical_key_cmp(lhs_bytes, rhs_bytes)
             });
         }
         for (key_bytes, _key, value) in key_order {
@@ -200,6 +197,15 @@ impl Serialize for AlonzoBlock {
 
 impl Deserialize for AlonzoBlock {
     fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
+        Self::deserialize_opt_keep_original(raw, false)
+    }
+}
+
+impl DeserializeKeepOriginal for AlonzoBlock {
+    fn deserialize_opt_keep_original<R: BufRead + Seek>(
+        raw: &mut Deserializer<R>,
+        keep_original: bool,
+    ) -> Result<Self, DeserializeError> {
         let len = raw.array_sz()?;
         let len_encoding: LenEncoding = len.into();
         let mut read_len = CBORReadLen::new(len);
@@ -216,7 +222,7 @@ impl Deserialize for AlonzoBlock {
                         assert_eq!(raw.special()?, cbor_event::Special::Break);
                         break;
                     }
-                    transaction_bodies_arr.push(AlonzoTransactionBody::deserialize(raw)?);
+                    transaction_bodies_arr.push(AlonzoTransactionBody::deserialize_maybe_keep_original(raw, keep_original)?);
                 }
                 Ok((transaction_bodies_arr, transaction_bodies_encoding))
             })().map_err(|e| e.annotate("transaction_bodies"))?;
@@ -2381,15 +2387,9 @@ impl Serialize for AlonzoTransactionBody {
                             })
                             .collect::<Result<Vec<(Vec<u8>, &_, &_)>, cbor_event::Error>>()?;
                         if force_canonical {
-                            key_order.sort_by(
-                                |(lhs_bytes, _, _), (rhs_bytes, _, _)| match lhs_bytes
-                                    .len()
-                                    .cmp(&rhs_bytes.len())
-                                {
-                                    std::cmp::Ordering::Equal => lhs_bytes.cmp(rhs_bytes),
-                                    diff_ord => diff_ord,
-                                },
-                            );
+                            key_order.sort_by(|(lhs_bytes, _, _), (rhs_bytes, _, _)| {
+                                canonical_key_cmp(lhs_bytes, rhs_bytes)
+                            });
                         }
                         for (key_bytes, key, value) in key_order {
                             serializer.write_raw_bytes(&key_bytes)?;
@@ -2517,15 +2517,9 @@ impl Serialize for AlonzoTransactionBody {
                             })
                             .collect::<Result<Vec<(Vec<u8>, &_, &_)>, cbor_event::Error>>()?;
                         if force_canonical {
-                            key_order.sort_by(
-                                |(lhs_bytes, _, _), (rhs_bytes, _, _)| match lhs_bytes
-                                    .len()
-                                    .cmp(&rhs_bytes.len())
-                                {
-                                    std::cmp::Ordering::Equal => lhs_bytes.cmp(rhs_bytes),
-                                    diff_ord => diff_ord,
-                                },
-                            );
+                            key_order.sort_by(|(lhs_bytes, _, _), (rhs_bytes, _, _)| {
+                                canonical_key_cmp(lhs_bytes, rhs_bytes)
+                            });
                         }
                         for (key_bytes, key, value) in key_order {
                             serializer.write_raw_bytes(&key_bytes)?;
@@ -2548,10 +2542,7 @@ impl Serialize for AlonzoTransactionBody {
                                 .collect::<Result<Vec<(Vec<u8>, &_, &_)>, cbor_event::Error>>()?;
                             if force_canonical {
                                 key_order.sort_by(|(lhs_bytes, _, _), (rhs_bytes, _, _)| {
-                                    match lhs_bytes.len().cmp(&rhs_bytes.len()) {
-                                        std::cmp::Ordering::Equal => lhs_bytes.cmp(rhs_bytes),
-                                        diff_ord => diff_ord,
-                                    }
+                                    canonical_key_cmp(lhs_bytes, rhs_bytes)
                                 });
                             }
                             for (key_bytes, key, value) in key_order {
@@ -3133,6 +3124,7 @@ impl Deserialize for AlonzoTransactionBody {
                     required_signers_encoding,
                     required_signers_elem_encodings,
                     network_id_key_encoding,
+                    original_bytes: None,
                 }),
             })
         })().map_err(|e| e.annotate("AlonzoTransactionBody"))
@@ -3709,10 +3701,7 @@ impl Serialize for AlonzoUpdate {
             .collect::<Result<Vec<(Vec<u8>, &_, &_)>, cbor_event::Error>>()?;
         if force_canonical {
             key_order.sort_by(|(lhs_bytes, _, _), (rhs_bytes, _, _)| {
-                match lhs_bytes.len().cmp(&rhs_bytes.len()) {
-                    std::cmp::Ordering::Equal => lhs_bytes.cmp(rhs_bytes),
-                    diff_ord => diff_ord,
-                }
+                canonical_key_cmp(lhs_bytes, rhs_bytes)
             });
         }
         for (key_bytes, _key, value) in key_order {
@@ -1,20 +1,91 @@
 use cml_chain::{
     auxdata::{AuxiliaryData, ConwayFormatAuxData},
-    plutus::{LegacyRedeemer, RedeemerTag, Redeemers},
-    transaction::TransactionWitnessSet,
+    plutus::{cbor_encodings::LegacyRedeemerEncoding, LegacyRedeemer, RedeemerTag, Redeemers},
+    transaction::{
+        utils::AlonzoFormatTxOutError, AlonzoFormatTxOut, TransactionBody, TransactionWitnessSet,
+    },
 };
 
 use super::{
     AlonzoAuxiliaryData, AlonzoRedeemer, AlonzoRedeemerTag, AlonzoTransactionBody,
     AlonzoTransactionWitnessSet,
 };
+use crate::utils::{check_no_conway_body_fields, downgrade_certs, Era, EraDowngradeError};
 
-use cml_core::serialization::Serialize;
+use cml_core::serialization::{OriginalBytes, Serialize};
 use cml_crypto::{blake2b256, TransactionHash};
+use std::convert::TryFrom;
 
 impl AlonzoTransactionBody {
+    /// Uses the original bytes if they were kept (see OriginalBytes)
     pub fn hash(&self) -> TransactionHash {
-        blake2b256(&self.to_cbor_bytes()).into()
+        match self.original_bytes() {
+            Some(original_bytes) => blake2b256(original_bytes).into(),
+            None => blake2b256(&self.to_cbor_bytes()).into(),
+        }
+    }
+}
+
+impl OriginalBytes for AlonzoTransactionBody {
+    fn original_bytes(&self) -> Option<&[u8]> {
+        self.encodings
+            .as_ref()
+            .and_then(|encs| encs.original_bytes.as_deref())
+    }
+
+    fn set_original_bytes(&mut self, original_bytes: Option<Vec<u8>>) {
+        self.encodings
+            .get_or_insert_with(Default::default)
+            .original_bytes = original_bytes;
+    }
+}
+
+/// Down-converts e.g. a body assembled with the TransactionBuilder for nodes pinned to Alonzo.
+/// Errors with the first field (in CBOR key order) that Alonzo can't represent.
+/// Encoding details are not kept and the update field is left empty.
+impl TryFrom<TransactionBody> for AlonzoTransactionBody {
+    type Error = EraDowngradeError;
+
+    fn try_from(body: TransactionBody) -> Result<Self, Self::Error> {
+        // checked up front as body gets consumed (but reported in CBOR key order)
+        let conway_fields = check_no_conway_body_fields(&body, Era::Alonzo);
+        let outputs = body
+            .outputs
+            .into_iter()
+            .enumerate()
+            .map(|(i, output)| {
+                AlonzoFormatTxOut::try_from(output).map_err(|e| {
+                    let field = match e {
+                        AlonzoFormatTxOutError::InlineDatum => "datum_option",
+                        AlonzoFormatTxOutError::ScriptReference => "script_reference",
+                    };
+                    EraDowngradeError::new(format!("outputs[{}].{}", i, field), Era::Alonzo)
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let certs = downgrade_certs(body.certs, Era::Alonzo)?;
+        for (is_set, field) in [
+            (body.collateral_return.is_some(), "collateral_return"),
+            (body.total_collateral.is_some(), "total_collateral"),
+            (body.reference_inputs.is_some(), "reference_inputs"),
+        ] {
+            if is_set {
+                return Err(EraDowngradeError::new(field, Era::Alonzo));
+            }
+        }
+        conway_fields?;
+        let mut alonzo = AlonzoTransactionBody::new(body.inputs.into(), outputs, body.fee);
+        alonzo.ttl = body.ttl;
+        alonzo.certs = certs;
+        alonzo.withdrawals = body.withdrawals;
+        alonzo.auxiliary_data_hash = body.auxiliary_data_hash;
+        alonzo.validity_interval_start = body.validity_interval_start;
+        alonzo.mint = body.mint;
+        alonzo.script_data_hash = body.script_data_hash;
+        alonzo.collateral_inputs = body.collateral_inputs.map(Into::into);
+        alonzo.required_signers = body.required_signers;
+        alonzo.network_id = body.network_id;
+        Ok(alonzo)
     }
 }
 
@@ -34,34 +105,59 @@ impl From<AlonzoAuxiliaryData> for AuxiliaryData {
     }
 }
 
+impl AlonzoTransactionWitnessSet {
+    /// Converts to the current (Conway) witness set format.
+    /// Redeemers are kept in the legacy array format along with their CBOR encoding details
+    /// so they serialize to the same bytes (e.g. for the script data hash).
+    pub fn to_conway_witness_set(&self) -> TransactionWitnessSet {
+        self.clone().into()
+    }
+}
+
 impl From<AlonzoTransactionWitnessSet> for TransactionWitnessSet {
     fn from(wits: AlonzoTransactionWitnessSet) -> Self {
         let mut new_wits = TransactionWitnessSet::new();
         new_wits.vkeywitnesses = wits.vkeywitnesses.map(Into::into);
         new_wits.native_scripts = wits.native_scripts.map(Into::into);
         new_wits.bootstrap_witnesses = wits.bootstrap_witnesses.map(Into::into);
-        new_wits.redeemers = wits
-            .redeemers
-            .map(|r| Redeemers::new_arr_legacy_redeemer(r.into_iter().map(Into::into).collect()));
+        let redeemers_encoding = wits
+            .encodings
+            .as_ref()
+            .map(|encs| encs.redeemers_encoding)
+            .unwrap_or_default();
+        new_wits.redeemers = wits.redeemers.map(|r| Redeemers::ArrLegacyRedeemer {
+            arr_legacy_redeemer: r.into_iter().map(Into::into).collect(),
+            arr_legacy_redeemer_encoding: redeemers_encoding,
+        });
         new_wits.plutus_datums = wits.plutus_datums.map(Into::into);
         new_wits.plutus_v1_scripts = wits.plutus_v1_scripts.map(Into::into);
         new_wits
     }
 }
 
+impl From<AlonzoRedeemerTag> for RedeemerTag {
+    fn from(tag: AlonzoRedeemerTag) -> Self {
+        match tag {
+            AlonzoRedeemerTag::Cert => RedeemerTag::Cert,
+            AlonzoRedeemerTag::Mint => RedeemerTag::Mint,
+            AlonzoRedeemerTag::Reward => RedeemerTag::Reward,
+            AlonzoRedeemerTag::Spend => RedeemerTag::Spend,
+        }
+    }
+}
+
 impl From<AlonzoRedeemer> for LegacyRedeemer {
     fn from(redeemer: AlonzoRedeemer) -> Self {
         Self {
-            tag: match redeemer.tag {
-                AlonzoRedeemerTag::Cert => RedeemerTag::Cert,
-                AlonzoRedeemerTag::Mint => RedeemerTag::Mint,
-                AlonzoRedeemerTag::Reward => RedeemerTag::Reward,
-                AlonzoRedeemerTag::Spend => RedeemerTag::Spend,
-            },
+            tag: redeemer.tag.into(),
             index: redeemer.index,
             data: redeemer.data,
             ex_units: redeemer.ex_units,
-            encodings: None,
+            encodings: redeemer.encodings.map(|encs| LegacyRedeemerEncoding {
+                len_encoding: encs.len_encoding,
+                tag_encoding: encs.tag_encoding,
+                index_encoding: encs.index_encoding,
+            }),
         }
     }
 }
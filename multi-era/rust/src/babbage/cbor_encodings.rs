@@ -121,6 +121,7 @@ pub struct BabbageTransactionBodyEncoding {
     pub total_collateral_key_encoding: Option<cbor_event::Sz>,
     pub reference_inputs_encoding: LenEncoding,
     pub reference_inputs_key_encoding: Option<cbor_event::Sz>,
+    pub original_bytes: Option<Vec<u8>>,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -27,14 +27,16 @@ use cml_chain::transaction::{AlonzoFormatTxOut, DatumOption, NativeScript, Trans
 use cml_chain::{Epoch, NetworkId, Rational, RequiredSigners, UnitInterval, Withdrawals};
 
 use cml_core::ordered_hash_map::OrderedHashMap;
-use cml_core::serialization::LenEncoding;
+use cml_core::serialization::{LenEncoding, StripEncodings};
 use cml_core::TransactionIndex;
 
 use std::collections::BTreeMap;
 
 use self::utils::BabbageMint;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub enum BabbageAuxiliaryData {
     Shelley(ShelleyFormatAuxData),
     ShelleyMA(ShelleyMAFormatAuxData),
@@ -55,7 +57,9 @@ impl BabbageAuxiliaryData {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct BabbageBlock {
     pub header: Header,
     pub transaction_bodies: Vec<BabbageTransactionBody>,
@@ -87,7 +91,9 @@ impl BabbageBlock {
 
 pub type BabbageCostModels = CostModels;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct BabbageFormatAuxData {
     pub metadata: Option<Metadata>,
     pub native_scripts: Option<Vec<NativeScript>>,
@@ -115,7 +121,9 @@ impl Default for BabbageFormatAuxData {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct BabbageFormatTxOut {
     pub address: Address,
     pub amount: Value,
@@ -140,7 +148,9 @@ impl BabbageFormatTxOut {
 pub type BabbageProposedProtocolParameterUpdates =
     OrderedHashMap<GenesisHash, BabbageProtocolParamUpdate>;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct BabbageProtocolParamUpdate {
     pub minfee_a: Option<u64>,
     pub minfee_b: Option<u64>,
@@ -204,7 +214,9 @@ impl Default for BabbageProtocolParamUpdate {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub enum BabbageScript {
     Native {
         script: NativeScript,
@@ -257,7 +269,9 @@ impl BabbageScript {
 
 pub type BabbageScriptRef = BabbageScript;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct BabbageTransaction {
     pub body: BabbageTransactionBody,
     pub witness_set: BabbageTransactionWitnessSet,
@@ -284,7 +298,9 @@ impl BabbageTransaction {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct BabbageTransactionBody {
     pub inputs: Vec<TransactionInput>,
     pub outputs: Vec<BabbageTransactionOutput>,
@@ -336,7 +352,9 @@ impl BabbageTransactionBody {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub enum BabbageTransactionOutput {
     AlonzoFormatTxOut(AlonzoFormatTxOut),
     BabbageFormatTxOut(BabbageFormatTxOut),
@@ -352,7 +370,9 @@ impl BabbageTransactionOutput {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct BabbageTransactionWitnessSet {
     pub vkeywitnesses: Option<Vec<Vkeywitness>>,
     pub native_scripts: Option<Vec<NativeScript>>,
@@ -386,7 +406,9 @@ impl Default for BabbageTransactionWitnessSet {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct BabbageUpdate {
     pub updates: BabbageProposedProtocolParameterUpdates,
     pub epoch: Epoch,
@@ -148,10 +148,7 @@ impl Serialize for BabbageBlock {
             .collect::<Result<Vec<(Vec<u8>, &_, &_)>, cbor_event::Error>>()?;
         if force_canonical {
             key_order.sort_by(|(lhs_bytes, _, _), (rhs_bytes, _, _)| {
-                match lhs_bytes.len().cmp(&rhs_bytes.len()) {
-                    std::cmp::Ordering::Equal => lhs_bytes.cmp(rhs_bytes),
-                    diff_ord => diff_ord,
-                }
+                canonical_key_cmp(lhs_bytes, rhs_bytes)
             });
         }
         for (key_bytes, _key, value) in key_order {
@@ -201,13 +198,22 @@ impl Serialize for BabbageBlock {
 
 impl Deserialize for BabbageBlock {
     fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
+        Self::deserialize_opt_keep_original(raw, false)
+    }
+}
+
+impl DeserializeKeepOriginal for BabbageBlock {
+    fn deserialize_opt_keep_original<R: BufRead + Seek>(
+        raw: &mut Deserializer<R>,
+        keep_original: bool,
+    ) -> Result<Self, DeserializeError> {
         let len = raw.array_sz()?;
         let len_encoding: LenEncoding = len.into();
         let mut read_len = CBORReadLen::new(len);
         read_len.read_elems(5)?;
         read_len.finish()?;
         (|| -> Result<_, DeserializeError> {
-            let header = Header::deserialize(raw).map_err(|e: DeserializeError| e.annotate("header"))?;
+            let header = Header::deserialize_maybe_keep_original(raw, keep_original).map_err(|e: DeserializeError| e.annotate("header"))?;
             let (transaction_bodies, transaction_bodies_encoding) = (|| -> Result<_, DeserializeError> {
                 let mut transaction_bodies_arr = Vec::new();
                 let len = raw.array_sz()?;
@@ -217,7 +223,7 @@ impl Deserialize for BabbageBlock {
                         assert_eq!(raw.special()?, cbor_event::Special::Break);
                         break;
                     }
-                    transaction_bodies_arr.push(BabbageTransactionBody::deserialize(raw)?);
+                    transaction_bodies_arr.push(BabbageTransactionBody::deserialize_maybe_keep_original(raw, keep_original)?);
                 }
                 Ok((transaction_bodies_arr, transaction_bodies_encoding))
             })().map_err(|e| e.annotate("transaction_bodies"))?;
@@ -2668,15 +2674,9 @@ impl Serialize for BabbageTransactionBody {
                             })
                             .collect::<Result<Vec<(Vec<u8>, &_, &_)>, cbor_event::Error>>()?;
                         if force_canonical {
-                            key_order.sort_by(
-                                |(lhs_bytes, _, _), (rhs_bytes, _, _)| match lhs_bytes
-                                    .len()
-                                    .cmp(&rhs_bytes.len())
-                                {
-                                    std::cmp::Ordering::Equal => lhs_bytes.cmp(rhs_bytes),
-                                    diff_ord => diff_ord,
-                                },
-                            );
+                            key_order.sort_by(|(lhs_bytes, _, _), (rhs_bytes, _, _)| {
+                                canonical_key_cmp(lhs_bytes, rhs_bytes)
+                            });
                         }
                         for (key_bytes, key, value) in key_order {
                             serializer.write_raw_bytes(&key_bytes)?;
@@ -3407,6 +3407,7 @@ impl Deserialize for BabbageTransactionBody {
                     total_collateral_encoding,
                     reference_inputs_key_encoding,
                     reference_inputs_encoding,
+                    original_bytes: None,
                 }),
             })
         })().map_err(|e| e.annotate("BabbageTransactionBody"))
@@ -4105,10 +4106,7 @@ impl Serialize for BabbageUpdate {
             .collect::<Result<Vec<(Vec<u8>, &_, &_)>, cbor_event::Error>>()?;
         if force_canonical {
             key_order.sort_by(|(lhs_bytes, _, _), (rhs_bytes, _, _)| {
-                match lhs_bytes.len().cmp(&rhs_bytes.len()) {
-                    std::cmp::Ordering::Equal => lhs_bytes.cmp(rhs_bytes),
-                    diff_ord => diff_ord,
-                }
+                canonical_key_cmp(lhs_bytes, rhs_bytes)
             });
         }
         for (key_bytes, _key, value) in key_order {
@@ -2,26 +2,51 @@ use std::io::{BufRead, Seek, Write};
 
 use cbor_event::{de::Deserializer, se::Serializer};
 use cml_chain::{
-    assets::{AssetName, Mint, NonZeroInt64},
+    address::Address,
+    assets::{AssetName, Mint, Value},
     auxdata::{AuxiliaryData, ConwayFormatAuxData},
     plutus::Redeemers,
-    transaction::TransactionWitnessSet,
+    transaction::{
+        cbor_encodings::ConwayFormatTxOutEncoding, ConwayFormatTxOut, TransactionBody,
+        TransactionOutput, TransactionWitnessSet,
+    },
     LenEncoding, PolicyId, Script, StringEncoding,
 };
+use std::convert::TryFrom;
 
 use super::{
-    BabbageAuxiliaryData, BabbageScript, BabbageTransactionBody, BabbageTransactionWitnessSet,
+    cbor_encodings::BabbageFormatTxOutEncoding, BabbageAuxiliaryData, BabbageFormatTxOut,
+    BabbageScript, BabbageTransactionBody, BabbageTransactionOutput, BabbageTransactionWitnessSet,
 };
+use crate::utils::{check_no_conway_body_fields, downgrade_certs, Era, EraDowngradeError};
 
 use cml_core::{
-    serialization::{fit_sz, Deserialize, Serialize},
+    serialization::{fit_sz, Deserialize, OriginalBytes, Serialize, StripEncodings},
     DeserializeError, DeserializeFailure,
 };
 use cml_crypto::{blake2b256, RawBytesEncoding, TransactionHash};
 
 impl BabbageTransactionBody {
+    /// Uses the original bytes if they were kept (see OriginalBytes)
     pub fn hash(&self) -> TransactionHash {
-        blake2b256(&self.to_cbor_bytes()).into()
+        match self.original_bytes() {
+            Some(original_bytes) => blake2b256(original_bytes).into(),
+            None => blake2b256(&self.to_cbor_bytes()).into(),
+        }
+    }
+}
+
+impl OriginalBytes for BabbageTransactionBody {
+    fn original_bytes(&self) -> Option<&[u8]> {
+        self.encodings
+            .as_ref()
+            .and_then(|encs| encs.original_bytes.as_deref())
+    }
+
+    fn set_original_bytes(&mut self, original_bytes: Option<Vec<u8>>) {
+        self.encodings
+            .get_or_insert_with(Default::default)
+            .original_bytes = original_bytes;
     }
 }
 
@@ -59,6 +84,213 @@ impl From<BabbageScript> for Script {
     }
 }
 
+impl TryFrom<Script> for BabbageScript {
+    /// Plutus V3 scripts didn't exist in Babbage so they are returned as-is
+    type Error = Script;
+
+    fn try_from(script: Script) -> Result<Self, Self::Error> {
+        match script {
+            Script::Native {
+                script,
+                len_encoding,
+                tag_encoding,
+            } => Ok(BabbageScript::Native {
+                script,
+                len_encoding,
+                tag_encoding,
+            }),
+            Script::PlutusV1 {
+                script,
+                len_encoding,
+                tag_encoding,
+            } => Ok(BabbageScript::PlutusV1 {
+                script,
+                len_encoding,
+                tag_encoding,
+            }),
+            Script::PlutusV2 {
+                script,
+                len_encoding,
+                tag_encoding,
+            } => Ok(BabbageScript::PlutusV2 {
+                script,
+                len_encoding,
+                tag_encoding,
+            }),
+            Script::PlutusV3 { .. } => Err(script),
+        }
+    }
+}
+
+impl From<BabbageFormatTxOutEncoding> for ConwayFormatTxOutEncoding {
+    fn from(encs: BabbageFormatTxOutEncoding) -> Self {
+        Self {
+            len_encoding: encs.len_encoding,
+            orig_deser_order: encs.orig_deser_order,
+            address_key_encoding: encs.address_key_encoding,
+            amount_key_encoding: encs.amount_key_encoding,
+            datum_option_key_encoding: encs.datum_option_key_encoding,
+            script_reference_tag_encoding: encs.script_reference_tag_encoding,
+            script_reference_bytes_encoding: encs.script_reference_bytes_encoding,
+            script_reference_key_encoding: encs.script_reference_key_encoding,
+        }
+    }
+}
+
+impl From<ConwayFormatTxOutEncoding> for BabbageFormatTxOutEncoding {
+    fn from(encs: ConwayFormatTxOutEncoding) -> Self {
+        Self {
+            len_encoding: encs.len_encoding,
+            orig_deser_order: encs.orig_deser_order,
+            address_key_encoding: encs.address_key_encoding,
+            amount_key_encoding: encs.amount_key_encoding,
+            datum_option_key_encoding: encs.datum_option_key_encoding,
+            script_reference_tag_encoding: encs.script_reference_tag_encoding,
+            script_reference_bytes_encoding: encs.script_reference_bytes_encoding,
+            script_reference_key_encoding: encs.script_reference_key_encoding,
+        }
+    }
+}
+
+/// Keeps all encoding details (incl. the script reference's) so both serialize identically
+impl From<BabbageFormatTxOut> for ConwayFormatTxOut {
+    fn from(out: BabbageFormatTxOut) -> Self {
+        Self {
+            address: out.address,
+            amount: out.amount,
+            datum_option: out.datum_option,
+            script_reference: out.script_reference.map(Into::into),
+            encodings: out.encodings.map(Into::into),
+        }
+    }
+}
+
+impl TryFrom<ConwayFormatTxOut> for BabbageFormatTxOut {
+    /// Outputs with a Plutus V3 script reference can't be represented in Babbage so they are returned as-is
+    type Error = ConwayFormatTxOut;
+
+    fn try_from(out: ConwayFormatTxOut) -> Result<Self, Self::Error> {
+        let script_reference = match out.script_reference.clone().map(BabbageScript::try_from) {
+            Some(Ok(script)) => Some(script),
+            Some(Err(_)) => return Err(out),
+            None => None,
+        };
+        Ok(Self {
+            address: out.address,
+            amount: out.amount,
+            datum_option: out.datum_option,
+            script_reference,
+            encodings: out.encodings.map(Into::into),
+        })
+    }
+}
+
+impl From<BabbageTransactionOutput> for TransactionOutput {
+    fn from(out: BabbageTransactionOutput) -> Self {
+        match out {
+            BabbageTransactionOutput::AlonzoFormatTxOut(alonzo) => {
+                TransactionOutput::AlonzoFormatTxOut(alonzo)
+            }
+            BabbageTransactionOutput::BabbageFormatTxOut(babbage) => {
+                TransactionOutput::ConwayFormatTxOut(babbage.into())
+            }
+        }
+    }
+}
+
+impl TryFrom<TransactionOutput> for BabbageTransactionOutput {
+    /// Outputs with a Plutus V3 script reference can't be represented in Babbage so they are returned as-is
+    type Error = TransactionOutput;
+
+    fn try_from(out: TransactionOutput) -> Result<Self, Self::Error> {
+        match out {
+            TransactionOutput::AlonzoFormatTxOut(alonzo) => {
+                Ok(BabbageTransactionOutput::AlonzoFormatTxOut(alonzo))
+            }
+            TransactionOutput::ConwayFormatTxOut(conway) => BabbageFormatTxOut::try_from(conway)
+                .map(BabbageTransactionOutput::BabbageFormatTxOut)
+                .map_err(TransactionOutput::ConwayFormatTxOut),
+        }
+    }
+}
+
+impl BabbageTransactionOutput {
+    pub fn address(&self) -> &Address {
+        match self {
+            Self::AlonzoFormatTxOut(alonzo) => &alonzo.address,
+            Self::BabbageFormatTxOut(babbage) => &babbage.address,
+        }
+    }
+
+    pub fn amount(&self) -> &Value {
+        match self {
+            Self::AlonzoFormatTxOut(alonzo) => &alonzo.amount,
+            Self::BabbageFormatTxOut(babbage) => &babbage.amount,
+        }
+    }
+
+    /// The exact bytes wrapped in the script reference's #6.24 tag i.e. the CBOR of the script itself.
+    /// Useful for computing hashes without having to convert the script.
+    pub fn script_ref_raw_bytes(&self) -> Option<Vec<u8>> {
+        match self {
+            Self::AlonzoFormatTxOut(_) => None,
+            Self::BabbageFormatTxOut(babbage) => babbage
+                .script_reference
+                .as_ref()
+                .map(|script| script.to_cbor_bytes()),
+        }
+    }
+}
+
+/// Down-converts e.g. a body assembled with the TransactionBuilder for nodes pinned to Babbage.
+/// Errors with the first field (in CBOR key order) that Babbage can't represent.
+/// Encoding details are not kept and the update field is left empty.
+impl TryFrom<TransactionBody> for BabbageTransactionBody {
+    type Error = EraDowngradeError;
+
+    fn try_from(body: TransactionBody) -> Result<Self, Self::Error> {
+        // checked up front as body gets consumed (but reported in CBOR key order)
+        let conway_fields = check_no_conway_body_fields(&body, Era::Babbage);
+        // only Plutus V3 script references can fail
+        let unsupported_script_ref = |field: String| {
+            EraDowngradeError::new(format!("{}.script_reference", field), Era::Babbage)
+        };
+        let outputs = body
+            .outputs
+            .into_iter()
+            .enumerate()
+            .map(|(i, output)| {
+                BabbageTransactionOutput::try_from(output)
+                    .map_err(|_| unsupported_script_ref(format!("outputs[{}]", i)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let certs = downgrade_certs(body.certs, Era::Babbage)?;
+        let collateral_return = body
+            .collateral_return
+            .map(|output| {
+                BabbageTransactionOutput::try_from(output)
+                    .map_err(|_| unsupported_script_ref("collateral_return".to_owned()))
+            })
+            .transpose()?;
+        conway_fields?;
+        let mut babbage = BabbageTransactionBody::new(body.inputs.into(), outputs, body.fee);
+        babbage.ttl = body.ttl;
+        babbage.certs = certs;
+        babbage.withdrawals = body.withdrawals;
+        babbage.auxiliary_data_hash = body.auxiliary_data_hash;
+        babbage.validity_interval_start = body.validity_interval_start;
+        babbage.mint = body.mint.map(Into::into);
+        babbage.script_data_hash = body.script_data_hash;
+        babbage.collateral_inputs = body.collateral_inputs.map(Into::into);
+        babbage.required_signers = body.required_signers;
+        babbage.network_id = body.network_id;
+        babbage.collateral_return = collateral_return;
+        babbage.total_collateral = body.total_collateral;
+        babbage.reference_inputs = body.reference_inputs.map(Into::into);
+        Ok(babbage)
+    }
+}
+
 impl From<BabbageAuxiliaryData> for AuxiliaryData {
     fn from(aux: BabbageAuxiliaryData) -> Self {
         match aux {
@@ -93,9 +325,17 @@ impl From<BabbageTransactionWitnessSet> for TransactionWitnessSet {
 }
 
 /// Babbage mints can have multiple maps resulting in different encodings so this works around it
-#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    StripEncodings,
+)]
 pub struct BabbageMint {
-    pub assets: Vec<(PolicyId, Vec<(AssetName, NonZeroInt64)>)>,
+    pub assets: Vec<(PolicyId, Vec<(AssetName, i64)>)>,
     #[serde(skip)]
     pub encodings: Option<BabbageMintEncoding>,
 }
@@ -292,9 +532,36 @@ impl Deserialize for BabbageMint {
 
 #[cfg(test)]
 mod tests {
-    use cml_chain::{Deserialize, Serialize};
+    use cml_chain::{
+        plutus::PlutusV3Script,
+        transaction::{ConwayFormatTxOut, TransactionOutput},
+        Deserialize, Script, Serialize, Value,
+    };
+    use std::convert::TryFrom;
 
-    use crate::babbage::BabbageBlock;
+    use crate::babbage::{BabbageBlock, BabbageTransactionOutput};
+
+    #[test]
+    fn babbage_output_script_ref_round_trip() {
+        // non-canonical map/tag/bytes encodings and field order with a PlutusV2 ref script
+        let bytes = hex::decode("bf03d81859000f82024c4a010000332222200512000100581d60a4d5f23d70af6e494237da29ebd3d0c13f8fde2c3c3b169980a4b63401821a001e8480a0ff").unwrap();
+        let output = BabbageTransactionOutput::from_cbor_bytes(&bytes).unwrap();
+        assert_eq!(output.to_cbor_bytes(), bytes);
+        let script_ref = output.script_ref_raw_bytes().unwrap();
+        assert_eq!(hex::encode(&script_ref), "82024c4a0100003322222005120001");
+
+        let unified = TransactionOutput::from(output.clone());
+        assert_eq!(unified.to_cbor_bytes(), bytes);
+        assert_eq!(unified.script_ref().unwrap().to_cbor_bytes(), script_ref);
+        let back = BabbageTransactionOutput::try_from(unified.clone()).unwrap();
+        assert_eq!(back.to_cbor_bytes(), bytes);
+
+        let mut v3 = ConwayFormatTxOut::new(unified.address().clone(), Value::from(1_000_000));
+        v3.script_reference = Some(Script::new_plutus_v3(PlutusV3Script::new(vec![0x4e])));
+        assert!(
+            BabbageTransactionOutput::try_from(TransactionOutput::ConwayFormatTxOut(v3)).is_err()
+        );
+    }
 
     #[test]
     fn babbage_mint_duplicate() {
@@ -2,6 +2,7 @@
 // https://github.com/dcSpark/cddl-codegen
 
 pub mod serialization;
+pub mod utils;
 
 use crate::byron::delegation::{
     ByronDelegation, ByronDelegationSignature, LightWeightDelegationSignature,
@@ -10,12 +11,15 @@ use crate::byron::mpc::{Ssc, SscProof};
 use crate::byron::transaction::{ByronAttributes, ByronTx, ByronTxProof, ByronTxWitness};
 use crate::byron::update::{ByronBlockVersion, ByronSoftwareVersion, ByronUpdate};
 use crate::byron::{Blake2b256, ByronBlockId, ByronPubKey, ByronSignature, ByronSlotId, EpochId};
+use cml_core::serialization::StripEncodings;
 
 use cml_chain::byron::StakeholderId;
 
 use std::collections::BTreeMap;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct BlockHeaderExtraData {
     pub block_version: ByronBlockVersion,
     pub software_version: ByronSoftwareVersion,
@@ -39,7 +43,9 @@ impl BlockHeaderExtraData {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub enum ByronBlock {
     EpochBoundary(ByronEbBlock),
     Main(ByronMainBlock),
@@ -55,7 +61,9 @@ impl ByronBlock {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ByronBlockBody {
     pub tx_payload: TxPayload,
     pub ssc_payload: Ssc,
@@ -79,7 +87,9 @@ impl ByronBlockBody {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ByronBlockConsensusData {
     pub byron_slot_id: ByronSlotId,
     pub byron_pub_key: ByronPubKey,
@@ -103,7 +113,9 @@ impl ByronBlockConsensusData {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ByronBlockHeader {
     pub protocol_magic: u32,
     pub prev_block: ByronBlockId,
@@ -130,7 +142,9 @@ impl ByronBlockHeader {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub enum ByronBlockSignature {
     Signature(ByronBlockSignatureNormal),
     ProxyLight(ByronBlockSignatureProxyLight),
@@ -151,7 +165,9 @@ impl ByronBlockSignature {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ByronBlockSignatureNormal {
     pub signature: ByronSignature,
 }
@@ -162,7 +178,9 @@ impl ByronBlockSignatureNormal {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ByronBlockSignatureProxyHeavy {
     pub signature: ByronDelegationSignature,
 }
@@ -173,7 +191,9 @@ impl ByronBlockSignatureProxyHeavy {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ByronBlockSignatureProxyLight {
     pub signature: LightWeightDelegationSignature,
 }
@@ -184,7 +204,9 @@ impl ByronBlockSignatureProxyLight {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ByronBodyProof {
     pub tx_proof: ByronTxProof,
     pub ssc_proof: SscProof,
@@ -208,7 +230,9 @@ impl ByronBodyProof {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ByronDifficulty {
     pub u64: u64,
 }
@@ -219,7 +243,9 @@ impl ByronDifficulty {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ByronEbBlock {
     pub header: EbbHead,
     pub body: Vec<StakeholderId>,
@@ -236,7 +262,9 @@ impl ByronEbBlock {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ByronMainBlock {
     pub header: ByronBlockHeader,
     pub body: ByronBlockBody,
@@ -257,7 +285,9 @@ impl ByronMainBlock {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct EbbConsensusData {
     pub epoch_id: EpochId,
     pub byron_difficulty: ByronDifficulty,
@@ -272,7 +302,9 @@ impl EbbConsensusData {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct EbbHead {
     pub protocol_magic: u32,
     pub prev_block: ByronBlockId,
@@ -299,7 +331,9 @@ impl EbbHead {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct TxAux {
     pub byron_tx: ByronTx,
     pub byron_tx_witnesss: Vec<ByronTxWitness>,
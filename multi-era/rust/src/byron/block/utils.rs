@@ -0,0 +1,30 @@
+use cml_core::serialization::ToBytes;
+
+use super::ByronMainBlock;
+use crate::byron::delegation::ByronDelegation;
+use crate::byron::update::{ByronUpdateProposal, ByronUpdateVote};
+
+impl ByronMainBlock {
+    /// The protocol update proposal submitted in this block, if any.
+    /// The CDDL allows at most one per block (`[? proposal]`).
+    pub fn update_proposal(&self) -> Option<&ByronUpdateProposal> {
+        self.body.upd_payload.proposal.first()
+    }
+
+    /// Votes on (possibly earlier) update proposals submitted in this block.
+    pub fn update_votes(&self) -> &[ByronUpdateVote] {
+        &self.body.upd_payload.votes
+    }
+
+    /// Heavyweight delegation certificates submitted in this block.
+    /// These change which keys can sign blocks on behalf of the genesis keys.
+    pub fn delegation_certificates(&self) -> &[ByronDelegation] {
+        &self.body.dlg_payload
+    }
+
+    /// CBOR bytes of the shared seed computation (SSC) payload.
+    /// See body.ssc_payload for the parsed version.
+    pub fn ssc_payload_bytes(&self) -> Vec<u8> {
+        self.body.ssc_payload.to_bytes()
+    }
+}
@@ -2,10 +2,14 @@
 // https://github.com/dcSpark/cddl-codegen
 
 pub mod serialization;
+pub mod utils;
 
 use crate::byron::{ByronPubKey, ByronSignature, EpochId};
+use cml_core::serialization::StripEncodings;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ByronDelegation {
     pub epoch: EpochId,
     pub issuer: ByronPubKey,
@@ -29,7 +33,9 @@ impl ByronDelegation {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ByronDelegationSignature {
     pub byron_delegation: ByronDelegation,
     pub byron_signature: ByronSignature,
@@ -44,7 +50,9 @@ impl ByronDelegationSignature {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct EpochRange {
     pub epoch_id: EpochId,
     pub epoch_id2: EpochId,
@@ -59,7 +67,9 @@ impl EpochRange {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct LightWeightDelegationSignature {
     pub light_weight_dlg: LightWeightDlg,
     pub byron_signature: ByronSignature,
@@ -74,7 +84,9 @@ impl LightWeightDelegationSignature {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct LightWeightDlg {
     pub epoch_range: EpochRange,
     pub issuer: ByronPubKey,
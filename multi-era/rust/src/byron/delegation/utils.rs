@@ -0,0 +1,27 @@
+use cml_chain::byron::StakeholderId;
+use cml_core::error::DeserializeError;
+use cml_crypto::{Bip32PublicKey, RawBytesEncoding};
+
+use super::ByronDelegation;
+
+impl ByronDelegation {
+    /// The key delegating its block signing rights (usually a genesis key)
+    pub fn issuer_key(&self) -> Result<Bip32PublicKey, DeserializeError> {
+        Bip32PublicKey::from_raw_bytes(&self.issuer)
+    }
+
+    /// The key allowed to sign blocks on behalf of the issuer starting at this delegation's epoch
+    pub fn delegate_key(&self) -> Result<Bip32PublicKey, DeserializeError> {
+        Bip32PublicKey::from_raw_bytes(&self.delegate)
+    }
+
+    /// Stakeholder id of the issuer i.e. its key in the genesis heavyDelegation map
+    pub fn issuer_stakeholder_id(&self) -> Result<StakeholderId, DeserializeError> {
+        self.issuer_key().map(|key| StakeholderId::new(&key))
+    }
+
+    /// Stakeholder id of the delegate
+    pub fn delegate_stakeholder_id(&self) -> Result<StakeholderId, DeserializeError> {
+        self.delegate_key().map(|key| StakeholderId::new(&key))
+    }
+}
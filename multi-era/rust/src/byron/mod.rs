@@ -9,6 +9,7 @@ pub mod transaction;
 pub mod update;
 pub mod utils;
 
+use cml_core::serialization::StripEncodings;
 use cml_crypto::TransactionHash;
 pub use utils::*;
 
@@ -18,7 +19,9 @@ pub type ByronPubKey = Vec<u8>;
 
 pub type ByronSignature = Vec<u8>;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ByronSlotId {
     pub epoch: EpochId,
     pub slot: u64,
@@ -5,9 +5,12 @@ pub mod serialization;
 
 use crate::byron::{Blake2b256, ByronPubKey, ByronSignature, EpochId};
 use cml_chain::byron::{AddressId, StakeholderId};
+use cml_core::serialization::StripEncodings;
 use std::collections::BTreeMap;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub enum Ssc {
     SscCommitmentsPayload(SscCommitmentsPayload),
     SscOpeningsPayload(SscOpeningsPayload),
@@ -33,7 +36,9 @@ impl Ssc {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct SscCert {
     pub vss_pub_key: VssPubKey,
     pub epoch_id: EpochId,
@@ -57,7 +62,9 @@ impl SscCert {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct SscCertificatesPayload {
     pub ssc_certs: SscCerts,
 }
@@ -68,7 +75,9 @@ impl SscCertificatesPayload {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct SscCertificatesProof {
     pub blake2b256: Blake2b256,
 }
@@ -81,7 +90,9 @@ impl SscCertificatesProof {
 
 pub type SscCerts = Vec<SscCert>;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct SscCommitment {
     pub vss_shares: VssShares,
     pub vss_proof: VssProof,
@@ -96,7 +107,9 @@ impl SscCommitment {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct SscCommitmentsPayload {
     pub ssc_signed_commitments: SscSignedCommitments,
     pub ssc_certs: SscCerts,
@@ -111,7 +124,9 @@ impl SscCommitmentsPayload {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct SscCommitmentsProof {
     pub blake2b256: Blake2b256,
     pub blake2b2562: Blake2b256,
@@ -126,7 +141,9 @@ impl SscCommitmentsProof {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct SscOpeningsPayload {
     pub ssc_opens: SscOpens,
     pub ssc_certs: SscCerts,
@@ -141,7 +158,9 @@ impl SscOpeningsPayload {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct SscOpeningsProof {
     pub blake2b256: Blake2b256,
     pub blake2b2562: Blake2b256,
@@ -158,7 +177,9 @@ impl SscOpeningsProof {
 
 pub type SscOpens = BTreeMap<StakeholderId, Vsssec>;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub enum SscProof {
     SscCommitmentsProof(SscCommitmentsProof),
     SscOpeningsProof(SscOpeningsProof),
@@ -186,7 +207,9 @@ impl SscProof {
 
 pub type SscShares = BTreeMap<AddressId, SscSharesSubmap>;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct SscSharesPayload {
     pub ssc_shares: SscShares,
     pub ssc_certs: SscCerts,
@@ -201,7 +224,9 @@ impl SscSharesPayload {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct SscSharesProof {
     pub blake2b256: Blake2b256,
     pub blake2b2562: Blake2b256,
@@ -218,7 +243,9 @@ impl SscSharesProof {
 
 pub type SscSharesSubmap = BTreeMap<AddressId, Vec<VssDecryptedShare>>;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct SscSignedCommitment {
     pub byron_pub_key: ByronPubKey,
     pub ssc_commitment: SscCommitment,
@@ -243,7 +270,9 @@ pub type SscSignedCommitments = Vec<SscSignedCommitment>;
 
 pub type VssDecryptedShare = Vec<u8>;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct VssEncryptedShare {
     pub index_0: Vec<u8>,
 }
@@ -254,7 +283,9 @@ impl VssEncryptedShare {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct VssProof {
     pub extra_gen: Vec<u8>,
     pub proof: Vec<u8>,
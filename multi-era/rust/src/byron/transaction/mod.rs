@@ -5,6 +5,7 @@ pub mod serialization;
 pub mod utils;
 
 use crate::byron::{Blake2b256, ByronPubKey, ByronSignature, ByronTxId};
+use cml_core::serialization::StripEncodings;
 
 use cml_chain::byron::ByronTxOut;
 use std::collections::BTreeMap;
@@ -13,7 +14,9 @@ use super::ByronAny;
 
 pub type ByronAttributes = BTreeMap<ByronAny, ByronAny>;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ByronPkWitness {
     pub index_1: ByronPkWitnessEntry,
 }
@@ -24,7 +27,9 @@ impl ByronPkWitness {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ByronPkWitnessEntry {
     pub byron_pub_key: ByronPubKey,
     pub byron_signature: ByronSignature,
@@ -39,7 +44,9 @@ impl ByronPkWitnessEntry {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ByronRedeemWitness {
     pub index_1: ByronRedeemerWitnessEntry,
 }
@@ -50,7 +57,9 @@ impl ByronRedeemWitness {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ByronRedeemerScript {
     pub u16: u16,
     pub index_1: Vec<u8>,
@@ -62,7 +71,9 @@ impl ByronRedeemerScript {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ByronRedeemerWitnessEntry {
     pub byron_pub_key: ByronPubKey,
     pub byron_signature: ByronSignature,
@@ -77,7 +88,9 @@ impl ByronRedeemerWitnessEntry {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ByronScriptWitness {
     pub index_1: ByronScriptWitnessEntry,
 }
@@ -88,7 +101,9 @@ impl ByronScriptWitness {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ByronScriptWitnessEntry {
     pub byron_validator_script: ByronValidatorScript,
     pub byron_redeemer_script: ByronRedeemerScript,
@@ -106,7 +121,9 @@ impl ByronScriptWitnessEntry {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ByronTx {
     pub inputs: Vec<ByronTxIn>,
     pub outputs: Vec<ByronTxOut>,
@@ -123,7 +140,19 @@ impl ByronTx {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    StripEncodings,
+)]
 pub enum ByronTxIn {
     ByronTxInRegular(ByronTxInRegular),
     ByronTxInGenesis(ByronTxInGenesis),
@@ -139,7 +168,19 @@ impl ByronTxIn {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    StripEncodings,
+)]
 pub struct ByronTxInGenesis {
     pub u8: u8,
     pub index_1: Vec<u8>,
@@ -151,7 +192,19 @@ impl ByronTxInGenesis {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    StripEncodings,
+)]
 pub struct ByronTxInRegular {
     pub index_1: ByronTxOutPtr,
 }
@@ -162,7 +215,19 @@ impl ByronTxInRegular {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+    StripEncodings,
+)]
 pub struct ByronTxOutPtr {
     pub byron_tx_id: ByronTxId,
     pub u32: u32,
@@ -174,7 +239,9 @@ impl ByronTxOutPtr {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ByronTxProof {
     pub u32: u32,
     pub blake2b256: Blake2b256,
@@ -191,7 +258,9 @@ impl ByronTxProof {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub enum ByronTxWitness {
     ByronPkWitness(ByronPkWitness),
     ByronScriptWitness(ByronScriptWitness),
@@ -212,7 +281,9 @@ impl ByronTxWitness {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ByronValidatorScript {
     pub u16: u16,
     pub index_1: Vec<u8>,
@@ -1,9 +1,37 @@
-use super::ByronTx;
-use cml_core::serialization::ToBytes;
-use cml_crypto::{blake2b256, TransactionHash};
+use super::{ByronRedeemerWitnessEntry, ByronTx};
+use cml_chain::byron::{verify_redeem_signature, ByronAddress, ProtocolMagic};
+use cml_core::{error::DeserializeError, serialization::ToBytes};
+use cml_crypto::{blake2b256, Ed25519Signature, PublicKey, RawBytesEncoding, TransactionHash};
 
 impl ByronTx {
     pub fn hash(&self) -> TransactionHash {
         blake2b256(&self.to_bytes()).into()
     }
 }
+
+impl ByronRedeemerWitnessEntry {
+    pub fn public_key(&self) -> Result<PublicKey, DeserializeError> {
+        PublicKey::from_raw_bytes(&self.byron_pub_key)
+    }
+
+    pub fn signature(&self) -> Result<Ed25519Signature, DeserializeError> {
+        Ed25519Signature::from_raw_bytes(&self.byron_signature)
+    }
+
+    /// The AVVM redeem address this witness is for i.e. the one the redemption tx spends from
+    pub fn address(&self, protocol_magic: ProtocolMagic) -> Result<ByronAddress, DeserializeError> {
+        self.public_key()
+            .map(|pubkey| ByronAddress::new_redeem(&pubkey, protocol_magic))
+    }
+
+    /// Whether this is a valid redeem signature over the redemption tx {tx_hash}
+    /// Malformed keys/signatures are reported as invalid.
+    pub fn verify(&self, tx_hash: &TransactionHash, protocol_magic: ProtocolMagic) -> bool {
+        match (self.public_key(), self.signature()) {
+            (Ok(pubkey), Ok(signature)) => {
+                verify_redeem_signature(tx_hash, &pubkey, &signature, protocol_magic)
+            }
+            _ => false,
+        }
+    }
+}
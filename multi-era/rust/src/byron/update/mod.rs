@@ -6,9 +6,12 @@ pub mod serialization;
 use crate::byron::transaction::ByronAttributes;
 use crate::byron::{Blake2b256, ByronPubKey, ByronSignature, ByronUpdateId, EpochId};
 use cml_chain::utils::BigInteger;
+use cml_core::serialization::StripEncodings;
 use std::collections::BTreeMap;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct Bvermod {
     pub script_version: Vec<u16>,
     pub slot_duration: Vec<BigInteger>,
@@ -62,7 +65,9 @@ impl Bvermod {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ByronBlockVersion {
     pub u16: u16,
     pub u162: u16,
@@ -75,7 +80,9 @@ impl ByronBlockVersion {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ByronSoftwareVersion {
     pub application_name: String,
     pub u32: u32,
@@ -90,7 +97,9 @@ impl ByronSoftwareVersion {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ByronTxFeePolicy {
     pub index_1: StdFeePolicy,
 }
@@ -101,7 +110,9 @@ impl ByronTxFeePolicy {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ByronUpdate {
     pub proposal: Vec<ByronUpdateProposal>,
     pub votes: Vec<ByronUpdateVote>,
@@ -113,7 +124,9 @@ impl ByronUpdate {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ByronUpdateData {
     pub blake2b256: Blake2b256,
     pub blake2b2562: Blake2b256,
@@ -137,7 +150,9 @@ impl ByronUpdateData {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ByronUpdateProposal {
     pub block_version: ByronBlockVersion,
     pub block_version_mod: Bvermod,
@@ -170,7 +185,9 @@ impl ByronUpdateProposal {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ByronUpdateVote {
     pub voter: ByronPubKey,
     pub proposal_id: ByronUpdateId,
@@ -196,7 +213,9 @@ impl ByronUpdateVote {
 
 pub type CoinPortion = u64;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct SoftForkRule {
     pub coin_portion: CoinPortion,
     pub coin_portion2: CoinPortion,
@@ -217,7 +236,9 @@ impl SoftForkRule {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct StdFeePolicy {
     pub big_integer: BigInteger,
     pub big_integer2: BigInteger,
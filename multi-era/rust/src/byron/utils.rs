@@ -4,7 +4,7 @@ use cbor_event::{de::Deserializer, se::Serializer};
 use cml_crypto::impl_hash_type;
 
 use cml_core::error::{DeserializeError, DeserializeFailure};
-use cml_core::serialization::Deserialize;
+use cml_core::serialization::{Deserialize, StripEncodings};
 use cml_crypto::chain_crypto;
 use cml_crypto::{CryptoError, RawBytesEncoding};
 use schemars::JsonSchema;
@@ -16,6 +16,11 @@ impl_hash_type!(Blake2b256, 32);
 #[derive(Debug, Clone)]
 pub struct ByronAny(cbor_event::Value);
 
+// cbor_event::Value doesn't remember any encoding details
+impl StripEncodings for ByronAny {
+    fn strip_encodings(&mut self) {}
+}
+
 // more methods on ByronAny to inspect it aren't offered as we don't encounter anything
 // useful on-chain for this. It's either not present or is an empty array
 
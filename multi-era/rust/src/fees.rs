@@ -0,0 +1,329 @@
+//! Min fee and min ADA rules of past eras e.g. for checking historical transactions.
+//! For building transactions in the current era use cml_chain::fees / cml_chain::min_ada instead.
+
+use std::collections::BTreeSet;
+
+use cml_chain::fees::{ex_units_fee, min_ref_script_fee, LinearFee};
+use cml_chain::min_ada::min_ada_required;
+use cml_chain::plutus::{ExUnitPrices, ExUnits};
+use cml_chain::transaction::TransactionOutput;
+use cml_chain::{Coin, Value};
+use cml_core::ArithmeticError;
+use cml_crypto::RawBytesEncoding;
+
+use crate::utils::{Era, MultiEraProtocolParamUpdate, MultiEraTransactionOutput};
+
+#[derive(Debug, thiserror::Error)]
+pub enum EraFeeError {
+    #[error("Byron fees and outputs follow different rules which aren't supported")]
+    Byron,
+    #[error("Protocol parameter {0} is needed but not set")]
+    MissingParameter(&'static str),
+    #[error("Arithmetic: {0}")]
+    Arithmetic(#[from] ArithmeticError),
+}
+
+/// The protocol parameters the min fee / min ADA rules depend on.
+/// Only the ones used by the era in question need to be set.
+/// These can be followed over the eras with apply_update() using the updates in blocks.
+#[derive(Clone, Debug, Default)]
+pub struct EraFeeParams {
+    pub minfee_a: Option<Coin>,
+    pub minfee_b: Option<Coin>,
+    /// Shelley to Mary
+    pub min_utxo_value: Option<Coin>,
+    /// Coins per UTxO word in Alonzo, coins per UTxO byte from Babbage onwards
+    pub ada_per_utxo_byte: Option<Coin>,
+    /// Alonzo onwards
+    pub execution_costs: Option<ExUnitPrices>,
+    /// Conway. On-chain this is a rational but all values so far have been whole numbers.
+    pub min_fee_ref_script_cost_per_byte: Option<Coin>,
+}
+
+impl EraFeeParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overwrites the parameters that {update} changes
+    pub fn apply_update(&mut self, update: &MultiEraProtocolParamUpdate) {
+        if let Some(minfee_a) = update.minfee_a() {
+            self.minfee_a = Some(minfee_a);
+        }
+        if let Some(minfee_b) = update.minfee_b() {
+            self.minfee_b = Some(minfee_b);
+        }
+        if let Some(min_utxo_value) = update.min_utxo_value() {
+            self.min_utxo_value = Some(min_utxo_value);
+        }
+        if let Some(ada_per_utxo_byte) = update.ada_per_utxo_byte() {
+            self.ada_per_utxo_byte = Some(ada_per_utxo_byte);
+        }
+        if let Some(execution_costs) = update.execution_costs() {
+            self.execution_costs = Some(execution_costs.clone());
+        }
+        if let Some(cost) = update.min_fee_ref_script_cost_per_byte() {
+            self.min_fee_ref_script_cost_per_byte = Some(cost.numerator / cost.denominator.max(1));
+        }
+    }
+}
+
+fn param<T: Clone>(value: &Option<T>, name: &'static str) -> Result<T, EraFeeError> {
+    value.clone().ok_or(EraFeeError::MissingParameter(name))
+}
+
+// Mary/Alonzo min UTxO rules, see:
+// https://github.com/IntersectMBO/cardano-ledger/blob/master/doc/explanations/min-utxo-mary.rst
+// https://github.com/IntersectMBO/cardano-ledger/blob/master/doc/explanations/min-utxo-alonzo.rst
+const UTXO_ENTRY_SIZE_WITHOUT_VAL: u64 = 27;
+const DATA_HASH_SIZE: u64 = 10;
+
+/// Size in words of a Value with tokens as per the Mary/Alonzo min UTxO rules
+fn multiasset_value_size(value: &Value) -> u64 {
+    let policies = value
+        .multiasset
+        .iter()
+        .filter(|(_, assets)| !assets.is_empty())
+        .count() as u64;
+    let assets = value
+        .multiasset
+        .values()
+        .map(|assets| assets.len() as u64)
+        .sum::<u64>();
+    let asset_name_lengths = value
+        .multiasset
+        .values()
+        .flat_map(|assets| assets.keys().map(|name| name.to_raw_bytes()))
+        .collect::<BTreeSet<_>>()
+        .iter()
+        .map(|name| name.len() as u64)
+        .sum::<u64>();
+    // rounded up to whole words (8 bytes)
+    6 + (assets * 12 + asset_name_lengths + policies * 28).div_ceil(8)
+}
+
+/// Min ADA required for {output} under the rules of {era}:
+/// * Shelley / Allegra: min_utxo_value
+/// * Mary: min_utxo_value scaled by the size of the tokens
+/// * Alonzo: ada_per_utxo_byte (per word) times the UTxO entry size in words
+/// * Babbage / Conway: ada_per_utxo_byte times the serialized size plus overhead
+pub fn min_ada_required_for_era(
+    output: &MultiEraTransactionOutput,
+    era: Era,
+    params: &EraFeeParams,
+) -> Result<Coin, EraFeeError> {
+    let output = TransactionOutput::from(output.clone());
+    match era {
+        Era::Byron => Err(EraFeeError::Byron),
+        Era::Shelley | Era::Allegra => param(&params.min_utxo_value, "min_utxo_value"),
+        Era::Mary => {
+            let min_utxo_value = param(&params.min_utxo_value, "min_utxo_value")?;
+            if !output.amount().has_multiassets() {
+                return Ok(min_utxo_value);
+            }
+            // the size of an ADA-only UTxO entry as the coin size was 0 in Mary
+            let scaled = (min_utxo_value / UTXO_ENTRY_SIZE_WITHOUT_VAL)
+                .checked_mul(UTXO_ENTRY_SIZE_WITHOUT_VAL + multiasset_value_size(output.amount()))
+                .ok_or(ArithmeticError::IntegerOverflow)?;
+            Ok(std::cmp::max(min_utxo_value, scaled))
+        }
+        Era::Alonzo => {
+            let coins_per_utxo_word = param(&params.ada_per_utxo_byte, "ada_per_utxo_byte")?;
+            let value_size = if output.amount().has_multiassets() {
+                multiasset_value_size(output.amount())
+            } else {
+                // coin size
+                2
+            };
+            let data_hash_size = if output.datum_hash().is_some() {
+                DATA_HASH_SIZE
+            } else {
+                0
+            };
+            (UTXO_ENTRY_SIZE_WITHOUT_VAL + value_size + data_hash_size)
+                .checked_mul(coins_per_utxo_word)
+                .ok_or_else(|| ArithmeticError::IntegerOverflow.into())
+        }
+        Era::Babbage | Era::Conway => {
+            let coins_per_utxo_byte = param(&params.ada_per_utxo_byte, "ada_per_utxo_byte")?;
+            min_ada_required(&output, coins_per_utxo_byte).map_err(Into::into)
+        }
+    }
+}
+
+/// Min fee under the rules of {era} for a tx of {tx_size} bytes using {ex_units} in total:
+/// * Shelley to Mary: minfee_a * tx_size + minfee_b
+/// * Alonzo / Babbage: plus the script fee for {ex_units} at execution_costs
+/// * Conway: plus the tiered fee for the {total_ref_script_size} bytes of reference scripts
+///
+/// {ex_units} are ignored before Alonzo and {total_ref_script_size} before Conway.
+pub fn min_fee_for_era(
+    tx_size: u64,
+    ex_units: &ExUnits,
+    total_ref_script_size: u64,
+    era: Era,
+    params: &EraFeeParams,
+) -> Result<Coin, EraFeeError> {
+    if era == Era::Byron {
+        return Err(EraFeeError::Byron);
+    }
+    let minfee_a = param(&params.minfee_a, "minfee_a")?;
+    let minfee_b = param(&params.minfee_b, "minfee_b")?;
+    let mut fee = tx_size
+        .checked_mul(minfee_a)
+        .and_then(|fee| fee.checked_add(minfee_b))
+        .ok_or(ArithmeticError::IntegerOverflow)?;
+    if matches!(era, Era::Alonzo | Era::Babbage | Era::Conway)
+        && (ex_units.mem > 0 || ex_units.steps > 0)
+    {
+        let execution_costs = param(&params.execution_costs, "execution_costs")?;
+        fee = fee
+            .checked_add(ex_units_fee(ex_units, &execution_costs)?)
+            .ok_or(ArithmeticError::IntegerOverflow)?;
+    }
+    if era == Era::Conway && total_ref_script_size > 0 {
+        let cost_per_byte = param(
+            &params.min_fee_ref_script_cost_per_byte,
+            "min_fee_ref_script_cost_per_byte",
+        )?;
+        let ref_script_fee = min_ref_script_fee(
+            &LinearFee::new(minfee_a, minfee_b, cost_per_byte),
+            total_ref_script_size,
+        )?;
+        fee = fee
+            .checked_add(ref_script_fee)
+            .ok_or(ArithmeticError::IntegerOverflow)?;
+    }
+    Ok(fee)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cml_chain::address::{Address, EnterpriseAddress};
+    use cml_chain::assets::{AssetName, MultiAsset};
+    use cml_chain::certs::StakeCredential;
+    use cml_chain::transaction::AlonzoFormatTxOut;
+    use cml_chain::{PolicyId, SubCoin};
+    use cml_crypto::{DatumHash, Ed25519KeyHash};
+    use std::convert::TryFrom;
+
+    fn address() -> Address {
+        EnterpriseAddress::new(
+            1,
+            StakeCredential::new_pub_key(Ed25519KeyHash::from([0; 28])),
+        )
+        .to_address()
+    }
+
+    fn output(value: Value) -> MultiEraTransactionOutput {
+        MultiEraTransactionOutput::Shelley(AlonzoFormatTxOut::new(address(), value).into())
+    }
+
+    fn tokens(policies: u8, asset_name: &str) -> Value {
+        let mut multiasset = MultiAsset::new();
+        for policy in 0..policies {
+            multiasset.set(
+                PolicyId::from([policy; 28]),
+                AssetName::try_from(asset_name).unwrap(),
+                1,
+            );
+        }
+        Value::new(0, multiasset)
+    }
+
+    #[test]
+    fn mary_min_ada() {
+        // mainnet had a min_utxo_value of 1 ADA for all of Mary
+        let params = EraFeeParams {
+            min_utxo_value: Some(1_000_000),
+            ..EraFeeParams::default()
+        };
+        let min_ada = |value: Value, era: Era| {
+            min_ada_required_for_era(&output(value), era, &params).unwrap()
+        };
+        assert_eq!(min_ada(Value::from(5_000_000), Era::Mary), 1_000_000);
+        // examples from the Mary min UTxO explanation in the ledger repo
+        assert_eq!(min_ada(tokens(1, ""), Era::Mary), 1_407_406);
+        assert_eq!(min_ada(tokens(1, "a"), Era::Mary), 1_444_443);
+        // no token rules before Mary
+        assert_eq!(min_ada(tokens(1, "a"), Era::Allegra), 1_000_000);
+        assert!(matches!(
+            min_ada_required_for_era(&output(Value::from(1)), Era::Alonzo, &params),
+            Err(EraFeeError::MissingParameter("ada_per_utxo_byte"))
+        ));
+        assert!(matches!(
+            min_ada_required_for_era(&output(Value::from(1)), Era::Byron, &params),
+            Err(EraFeeError::Byron)
+        ));
+    }
+
+    #[test]
+    fn alonzo_min_ada() {
+        let params = EraFeeParams {
+            ada_per_utxo_byte: Some(34_482),
+            ..EraFeeParams::default()
+        };
+        assert_eq!(
+            min_ada_required_for_era(&output(Value::from(1)), Era::Alonzo, &params).unwrap(),
+            999_978
+        );
+        let mut with_datum_hash = AlonzoFormatTxOut::new(address(), Value::from(1));
+        with_datum_hash.datum_hash = Some(DatumHash::from([0; 32]));
+        assert_eq!(
+            min_ada_required_for_era(
+                &MultiEraTransactionOutput::Shelley(with_datum_hash.into()),
+                Era::Alonzo,
+                &params
+            )
+            .unwrap(),
+            1_344_798
+        );
+        assert_eq!(
+            min_ada_required_for_era(&output(tokens(1, "")), Era::Alonzo, &params).unwrap(),
+            1_310_316
+        );
+    }
+
+    #[test]
+    fn min_fee_per_era() {
+        let mut params = EraFeeParams {
+            minfee_a: Some(44),
+            minfee_b: Some(155_381),
+            ..EraFeeParams::default()
+        };
+        let ex_units = ExUnits::new(1_000_000, 500_000_000);
+        assert_eq!(
+            min_fee_for_era(300, &ExUnits::new(0, 0), 0, Era::Shelley, &params).unwrap(),
+            168_581
+        );
+        // ex units don't cost anything before Alonzo
+        assert_eq!(
+            min_fee_for_era(300, &ex_units, 0, Era::Mary, &params).unwrap(),
+            168_581
+        );
+        assert!(matches!(
+            min_fee_for_era(300, &ex_units, 0, Era::Alonzo, &params),
+            Err(EraFeeError::MissingParameter("execution_costs"))
+        ));
+        params.execution_costs = Some(ExUnitPrices::new(
+            SubCoin::new(577, 10_000),
+            SubCoin::new(721, 10_000_000),
+        ));
+        // 1_000_000 * 577 / 10_000 + 500_000_000 * 721 / 10_000_000
+        assert_eq!(
+            min_fee_for_era(300, &ex_units, 0, Era::Babbage, &params).unwrap(),
+            168_581 + 57_700 + 36_050
+        );
+        // reference scripts only cost extra from Conway
+        params.min_fee_ref_script_cost_per_byte = Some(15);
+        assert_eq!(
+            min_fee_for_era(300, &ex_units, 1_000, Era::Babbage, &params).unwrap(),
+            168_581 + 57_700 + 36_050
+        );
+        assert_eq!(
+            min_fee_for_era(300, &ex_units, 1_000, Era::Conway, &params).unwrap(),
+            168_581 + 57_700 + 36_050 + 15_000
+        );
+    }
+}
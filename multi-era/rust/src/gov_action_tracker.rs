@@ -0,0 +1,315 @@
+use std::collections::BTreeMap;
+
+use cml_chain::governance::{GovActionId, ProposalProcedure, Vote, Voter};
+use cml_core::Epoch;
+use cml_crypto::TransactionHash;
+
+use crate::{MultiEraBlock, MultiEraTransactionBody};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GovActionStatus {
+    /// Proposed and still open for votes
+    Proposed,
+    /// No longer open for votes as of this epoch (expires_after + 1)
+    Expired(Epoch),
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct VoteTally {
+    pub yes: u64,
+    pub no: u64,
+    pub abstain: u64,
+}
+
+impl VoteTally {
+    fn add(&mut self, vote: Vote) {
+        match vote {
+            Vote::Yes => self.yes += 1,
+            Vote::No => self.no += 1,
+            Vote::Abstain => self.abstain += 1,
+        }
+    }
+}
+
+/// Vote counts per voter role. These are head counts, not stake-weighted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GovActionVotes {
+    pub committee: VoteTally,
+    pub dreps: VoteTally,
+    pub pools: VoteTally,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GovActionState {
+    pub status: GovActionStatus,
+    pub votes: GovActionVotes,
+}
+
+#[derive(Clone, Debug)]
+pub struct TrackedGovAction {
+    pub proposal: ProposalProcedure,
+    pub proposed_in: Epoch,
+    /// Last epoch in which this action can be voted on
+    pub expires_after: Epoch,
+    votes: BTreeMap<Voter, Vote>,
+}
+
+impl TrackedGovAction {
+    /// Latest vote of each voter on this action
+    pub fn votes(&self) -> &BTreeMap<Voter, Vote> {
+        &self.votes
+    }
+
+    pub fn tally(&self) -> GovActionVotes {
+        let mut tally = GovActionVotes::default();
+        for (voter, vote) in self.votes.iter() {
+            match voter {
+                Voter::ConstitutionalCommitteeHotKeyHash { .. }
+                | Voter::ConstitutionalCommitteeHotScriptHash { .. } => tally.committee.add(*vote),
+                Voter::DRepKeyHash { .. } | Voter::DRepScriptHash { .. } => tally.dreps.add(*vote),
+                Voter::StakingPoolKeyHash { .. } => tally.pools.add(*vote),
+            }
+        }
+        tally
+    }
+}
+
+/// Follows the lifecycle of governance actions from the blocks of a chain e.g. for indexers.
+///
+/// Proposals are recorded under their GovActionId when seen and votes are accumulated
+/// (a later vote by the same voter replaces their earlier one) until the action expires
+/// governance_action_validity_period epochs after the one it was proposed in.
+///
+/// Limitations: the tracker has no access to the ledger state so it never reports
+/// actions as ratified/enacted (they show as Proposed until they expire), does not weigh
+/// votes by stake and ignores votes on actions proposed before tracking started.
+/// Blocks must be applied in chain order and rollbacks are not supported.
+#[derive(Clone, Debug)]
+pub struct GovActionTracker {
+    governance_action_validity_period: Epoch,
+    current_epoch: Epoch,
+    actions: BTreeMap<GovActionId, TrackedGovAction>,
+}
+
+impl GovActionTracker {
+    pub fn new(governance_action_validity_period: Epoch) -> Self {
+        Self {
+            governance_action_validity_period,
+            current_epoch: 0,
+            actions: BTreeMap::new(),
+        }
+    }
+
+    /// Records the proposals and votes of all valid transactions in {block}.
+    /// {epoch} is the epoch {block} belongs to.
+    pub fn apply_block(&mut self, block: &MultiEraBlock, epoch: Epoch) {
+        let invalid_transactions = block.invalid_transactions();
+        for (index, body) in block.transaction_bodies().iter().enumerate() {
+            if !invalid_transactions.contains(&(index as u16)) {
+                self.apply_transaction(body, epoch);
+            }
+        }
+    }
+
+    /// Records the proposals and votes of a (phase-2 valid) transaction in {epoch}
+    pub fn apply_transaction(&mut self, body: &MultiEraTransactionBody, epoch: Epoch) {
+        self.advance_to(epoch);
+        if let Some(proposals) = body.proposal_procedures() {
+            let tx_hash = body.hash();
+            for (index, proposal) in proposals.iter().enumerate() {
+                self.propose(tx_hash, index as u64, proposal.clone());
+            }
+        }
+        if let Some(voting_procedures) = body.voting_procedures() {
+            for (voter, votes) in voting_procedures.iter() {
+                for (action_id, procedure) in votes.iter() {
+                    if let Some(action) = self.actions.get_mut(action_id) {
+                        if action.expires_after >= self.current_epoch {
+                            action.votes.insert(voter.clone(), procedure.vote);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Moves to {epoch}, expiring any actions whose validity period ended.
+    /// Only needed to expire actions when no blocks are applied for that epoch.
+    pub fn advance_to(&mut self, epoch: Epoch) {
+        self.current_epoch = self.current_epoch.max(epoch);
+    }
+
+    fn propose(
+        &mut self,
+        transaction_id: TransactionHash,
+        index: u64,
+        proposal: ProposalProcedure,
+    ) {
+        self.actions.insert(
+            GovActionId::new(transaction_id, index),
+            TrackedGovAction {
+                proposal,
+                proposed_in: self.current_epoch,
+                expires_after: self.current_epoch + self.governance_action_validity_period,
+                votes: BTreeMap::new(),
+            },
+        );
+    }
+
+    pub fn current_epoch(&self) -> Epoch {
+        self.current_epoch
+    }
+
+    pub fn get(&self, action_id: &GovActionId) -> Option<&TrackedGovAction> {
+        self.actions.get(action_id)
+    }
+
+    /// None if {action_id} was never seen proposed
+    pub fn status(&self, action_id: &GovActionId) -> Option<GovActionState> {
+        self.actions.get(action_id).map(|action| GovActionState {
+            status: if action.expires_after < self.current_epoch {
+                GovActionStatus::Expired(action.expires_after + 1)
+            } else {
+                GovActionStatus::Proposed
+            },
+            votes: action.tally(),
+        })
+    }
+
+    /// All actions that are still open for votes
+    pub fn active_actions(&self) -> impl Iterator<Item = (&GovActionId, &TrackedGovAction)> {
+        let current_epoch = self.current_epoch;
+        self.actions
+            .iter()
+            .filter(move |(_, action)| action.expires_after >= current_epoch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test::CONWAY_PREVIEW_BLOCK;
+    use cml_chain::address::RewardAddress;
+    use cml_chain::certs::{StakeCredential, Url};
+    use cml_chain::governance::{Anchor, GovAction, VotingProcedure, VotingProcedures};
+    use cml_crypto::{AnchorDocHash, Ed25519KeyHash};
+
+    fn proposal() -> ProposalProcedure {
+        ProposalProcedure::new(
+            100_000_000_000,
+            RewardAddress::new(
+                0,
+                StakeCredential::new_pub_key(Ed25519KeyHash::from([4; 28])),
+            ),
+            GovAction::new_info_action(),
+            Anchor::new(
+                Url::new("https://example.com".to_owned()).unwrap(),
+                AnchorDocHash::from([5; 32]),
+            ),
+        )
+    }
+
+    fn block(
+        proposals: Vec<ProposalProcedure>,
+        votes: Vec<(Voter, GovActionId, Vote)>,
+        valid: bool,
+    ) -> (MultiEraBlock, TransactionHash) {
+        let mut block = match MultiEraBlock::from_explicit_network_cbor_bytes(
+            &hex::decode(CONWAY_PREVIEW_BLOCK).unwrap(),
+        )
+        .unwrap()
+        {
+            MultiEraBlock::Conway(block) => block,
+            _ => panic!("expected conway block"),
+        };
+        let body = &mut block.transaction_bodies[0];
+        if !proposals.is_empty() {
+            body.proposal_procedures = Some(proposals.into());
+        }
+        if !votes.is_empty() {
+            let mut voting_procedures = VotingProcedures::new();
+            for (voter, action_id, vote) in votes {
+                voting_procedures
+                    .entry(voter)
+                    .or_default()
+                    .insert(action_id, VotingProcedure::new(vote, None));
+            }
+            body.voting_procedures = Some(voting_procedures);
+        }
+        let tx_hash = body.hash();
+        if !valid {
+            block.invalid_transactions = vec![0];
+        }
+        (MultiEraBlock::Conway(block), tx_hash)
+    }
+
+    #[test]
+    fn gov_action_lifecycle() {
+        let drep = Voter::new_d_rep_key_hash(Ed25519KeyHash::from([1; 28]));
+        let pool = Voter::new_staking_pool_key_hash(Ed25519KeyHash::from([2; 28]));
+        let committee =
+            Voter::new_constitutional_committee_hot_key_hash(Ed25519KeyHash::from([3; 28]));
+        let mut tracker = GovActionTracker::new(3);
+
+        let (proposal_block, tx_hash) = block(vec![proposal(), proposal()], vec![], true);
+        tracker.apply_block(&proposal_block, 10);
+        let first = GovActionId::new(tx_hash, 0);
+        let second = GovActionId::new(tx_hash, 1);
+        let unknown = GovActionId::new(TransactionHash::from([9; 32]), 0);
+        assert_eq!(tracker.get(&first).unwrap().expires_after, 13);
+        assert_eq!(tracker.status(&unknown), None);
+
+        let (votes_block, _) = block(
+            vec![],
+            vec![
+                (drep.clone(), first.clone(), Vote::Yes),
+                (pool.clone(), first.clone(), Vote::No),
+                (committee.clone(), second.clone(), Vote::Abstain),
+                (drep.clone(), unknown.clone(), Vote::Yes),
+            ],
+            true,
+        );
+        tracker.apply_block(&votes_block, 11);
+        // phase-2 invalid transactions don't count
+        let (invalid_block, _) = block(
+            vec![],
+            vec![(pool.clone(), first.clone(), Vote::Yes)],
+            false,
+        );
+        tracker.apply_block(&invalid_block, 12);
+        // a later vote replaces the earlier one
+        let (change_block, _) = block(vec![], vec![(drep.clone(), first.clone(), Vote::No)], true);
+        tracker.apply_block(&change_block, 13);
+        assert_eq!(
+            tracker.status(&first),
+            Some(GovActionState {
+                status: GovActionStatus::Proposed,
+                votes: GovActionVotes {
+                    dreps: VoteTally {
+                        no: 1,
+                        ..Default::default()
+                    },
+                    pools: VoteTally {
+                        no: 1,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            })
+        );
+        assert_eq!(tracker.status(&second).unwrap().votes.committee.abstain, 1);
+        assert_eq!(tracker.active_actions().count(), 2);
+
+        // votes after the validity period are ignored
+        let (late_block, _) = block(
+            vec![],
+            vec![(drep.clone(), second.clone(), Vote::Yes)],
+            true,
+        );
+        tracker.apply_block(&late_block, 14);
+        let status = tracker.status(&second).unwrap();
+        assert_eq!(status.status, GovActionStatus::Expired(14));
+        assert_eq!(status.votes.dreps, VoteTally::default());
+        assert_eq!(tracker.active_actions().count(), 0);
+    }
+}
@@ -5,10 +5,14 @@ pub mod allegra;
 pub mod alonzo;
 pub mod babbage;
 pub mod byron;
+pub mod fees;
+pub mod gov_action_tracker;
 pub mod mary;
+pub mod pool_registry;
 pub mod serialization;
 pub mod shelley;
 pub mod utils;
+pub mod utxo_snapshot;
 // This file was code-generated using an experimental CDDL to rust tool:
 // https://github.com/dcSpark/cddl-codegen
 
@@ -23,12 +27,15 @@ use crate::{
 use cml_chain::block::Block;
 use cml_chain::crypto::GenesisHash;
 use cml_chain::{address::RewardAccount, transaction::TransactionBody};
+use cml_core::serialization::StripEncodings;
 
 pub type GenesisHashList = Vec<GenesisHash>;
 
 pub type RewardAccountList = Vec<RewardAccount>;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub enum MultiEraBlock {
     Byron(ByronBlock),
     Shelley(ShelleyBlock),
@@ -69,7 +76,9 @@ impl MultiEraBlock {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub enum MultiEraTransactionBody {
     Byron(ByronTx),
     Shelley(ShelleyTransactionBody),
@@ -41,6 +41,7 @@ pub struct MaryTransactionBodyEncoding {
     pub mint_value_encodings:
         BTreeMap<PolicyId, (LenEncoding, BTreeMap<AssetName, Option<cbor_event::Sz>>)>,
     pub mint_key_encoding: Option<cbor_event::Sz>,
+    pub original_bytes: Option<Vec<u8>>,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -16,11 +16,14 @@ use cml_chain::transaction::TransactionInput;
 use cml_chain::TransactionIndex;
 use cml_chain::Withdrawals;
 use cml_core::ordered_hash_map::OrderedHashMap;
+use cml_core::serialization::StripEncodings;
 use std::collections::BTreeMap;
 
 use self::cbor_encodings::MaryTransactionOutputEncoding;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct MaryBlock {
     pub header: ShelleyHeader,
     pub transaction_bodies: Vec<MaryTransactionBody>,
@@ -47,7 +50,9 @@ impl MaryBlock {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct MaryTransaction {
     pub body: MaryTransactionBody,
     pub witness_set: AllegraTransactionWitnessSet,
@@ -71,7 +76,9 @@ impl MaryTransaction {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct MaryTransactionBody {
     pub inputs: Vec<TransactionInput>,
     pub outputs: Vec<MaryTransactionOutput>,
@@ -109,7 +116,9 @@ impl MaryTransactionBody {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct MaryTransactionOutput {
     pub address: Address,
     pub amount: Value,
@@ -85,10 +85,7 @@ impl Serialize for MaryBlock {
             .collect::<Result<Vec<(Vec<u8>, &_, &_)>, cbor_event::Error>>()?;
         if force_canonical {
             key_order.sort_by(|(lhs_bytes, _, _), (rhs_bytes, _, _)| {
-                match lhs_bytes.len().cmp(&rhs_bytes.len()) {
-                    std::cmp::Ordering::Equal => lhs_bytes.cmp(rhs_bytes),
-                    diff_ord => diff_ord,
-                }
+                canonical_key_cmp(lhs_bytes, rhs_bytes)
             });
         }
         for (key_bytes, _key, value) in key_order {
@@ -110,6 +107,15 @@ impl Serialize for MaryBlock {
 
 impl Deserialize for MaryBlock {
     fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
+        Self::deserialize_opt_keep_original(raw, false)
+    }
+}
+
+impl DeserializeKeepOriginal for MaryBlock {
+    fn deserialize_opt_keep_original<R: BufRead + Seek>(
+        raw: &mut Deserializer<R>,
+        keep_original: bool,
+    ) -> Result<Self, DeserializeError> {
         let len = raw.array_sz()?;
         let len_encoding: LenEncoding = len.into();
         let mut read_len = CBORReadLen::new(len);
@@ -126,7 +132,7 @@ impl Deserialize for MaryBlock {
                         assert_eq!(raw.special()?, cbor_event::Special::Break);
                         break;
                     }
-                    transaction_bodies_arr.push(MaryTransactionBody::deserialize(raw)?);
+                    transaction_bodies_arr.push(MaryTransactionBody::deserialize_maybe_keep_original(raw, keep_original)?);
                 }
                 Ok((transaction_bodies_arr, transaction_bodies_encoding))
             })().map_err(|e| e.annotate("transaction_bodies"))?;
@@ -490,15 +496,9 @@ impl Serialize for MaryTransactionBody {
                             })
                             .collect::<Result<Vec<(Vec<u8>, &_, &_)>, cbor_event::Error>>()?;
                         if force_canonical {
-                            key_order.sort_by(
-                                |(lhs_bytes, _, _), (rhs_bytes, _, _)| match lhs_bytes
-                                    .len()
-                                    .cmp(&rhs_bytes.len())
-                                {
-                                    std::cmp::Ordering::Equal => lhs_bytes.cmp(rhs_bytes),
-                                    diff_ord => diff_ord,
-                                },
-                            );
+                            key_order.sort_by(|(lhs_bytes, _, _), (rhs_bytes, _, _)| {
+                                canonical_key_cmp(lhs_bytes, rhs_bytes)
+                            });
                         }
                         for (key_bytes, key, value) in key_order {
                             serializer.write_raw_bytes(&key_bytes)?;
@@ -626,15 +626,9 @@ impl Serialize for MaryTransactionBody {
                             })
                             .collect::<Result<Vec<(Vec<u8>, &_, &_)>, cbor_event::Error>>()?;
                         if force_canonical {
-                            key_order.sort_by(
-                                |(lhs_bytes, _, _), (rhs_bytes, _, _)| match lhs_bytes
-                                    .len()
-                                    .cmp(&rhs_bytes.len())
-                                {
-                                    std::cmp::Ordering::Equal => lhs_bytes.cmp(rhs_bytes),
-                                    diff_ord => diff_ord,
-                                },
-                            );
+                            key_order.sort_by(|(lhs_bytes, _, _), (rhs_bytes, _, _)| {
+                                canonical_key_cmp(lhs_bytes, rhs_bytes)
+                            });
                         }
                         for (key_bytes, key, value) in key_order {
                             serializer.write_raw_bytes(&key_bytes)?;
@@ -657,10 +651,7 @@ impl Serialize for MaryTransactionBody {
                                 .collect::<Result<Vec<(Vec<u8>, &_, &_)>, cbor_event::Error>>()?;
                             if force_canonical {
                                 key_order.sort_by(|(lhs_bytes, _, _), (rhs_bytes, _, _)| {
-                                    match lhs_bytes.len().cmp(&rhs_bytes.len()) {
-                                        std::cmp::Ordering::Equal => lhs_bytes.cmp(rhs_bytes),
-                                        diff_ord => diff_ord,
-                                    }
+                                    canonical_key_cmp(lhs_bytes, rhs_bytes)
                                 });
                             }
                             for (key_bytes, key, value) in key_order {
@@ -1032,6 +1023,7 @@ impl Deserialize for MaryTransactionBody {
                     mint_encoding,
                     mint_key_encodings,
                     mint_value_encodings,
+                    original_bytes: None,
                 }),
             })
         })().map_err(|e| e.annotate("MaryTransactionBody"))
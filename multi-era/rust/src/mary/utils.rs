@@ -1,9 +1,27 @@
 use super::MaryTransactionBody;
-use cml_core::serialization::Serialize;
+use cml_core::serialization::{OriginalBytes, Serialize};
 use cml_crypto::{blake2b256, TransactionHash};
 
 impl MaryTransactionBody {
+    /// Uses the original bytes if they were kept (see OriginalBytes)
     pub fn hash(&self) -> TransactionHash {
-        blake2b256(&self.to_cbor_bytes()).into()
+        match self.original_bytes() {
+            Some(original_bytes) => blake2b256(original_bytes).into(),
+            None => blake2b256(&self.to_cbor_bytes()).into(),
+        }
+    }
+}
+
+impl OriginalBytes for MaryTransactionBody {
+    fn original_bytes(&self) -> Option<&[u8]> {
+        self.encodings
+            .as_ref()
+            .and_then(|encs| encs.original_bytes.as_deref())
+    }
+
+    fn set_original_bytes(&mut self, original_bytes: Option<Vec<u8>>) {
+        self.encodings
+            .get_or_insert_with(Default::default)
+            .original_bytes = original_bytes;
     }
 }
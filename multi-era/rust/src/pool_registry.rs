@@ -0,0 +1,167 @@
+use std::collections::BTreeMap;
+
+use cml_chain::certs::PoolParams;
+use cml_crypto::{Ed25519KeyHash, VRFKeyHash};
+
+use crate::utils::{MultiEraBlockHeader, MultiEraCertificate};
+use crate::{MultiEraBlock, MultiEraTransactionBody};
+
+/// Registered stake pools, fed from PoolRegistration certificates, to attribute blocks to the
+/// pools that produced them e.g. for indexers (see block_producer()).
+///
+/// Pools are looked up by operator (cold key hash, i.e. the pool id) or by VRF key hash.
+/// Re-registrations replace the pool's params right away instead of from the next epoch as
+/// the ledger does and retired pools are kept so that their past blocks can still be attributed.
+#[derive(Clone, Debug, Default)]
+pub struct PoolRegistry {
+    pools: BTreeMap<Ed25519KeyHash, PoolParams>,
+    by_vrf: BTreeMap<VRFKeyHash, Ed25519KeyHash>,
+}
+
+impl PoolRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the pool or replaces its params if it was already registered
+    pub fn register(&mut self, params: PoolParams) {
+        if let Some(previous) = self.pools.get(&params.operator) {
+            if self.by_vrf.get(&previous.vrf_keyhash) == Some(&params.operator) {
+                self.by_vrf.remove(&previous.vrf_keyhash);
+            }
+        }
+        self.by_vrf.insert(params.vrf_keyhash, params.operator);
+        self.pools.insert(params.operator, params);
+    }
+
+    /// Registers the pool of a PoolRegistration certificate, ignoring all other certificates
+    pub fn apply_cert(&mut self, cert: &MultiEraCertificate) {
+        if let MultiEraCertificate::PoolRegistration(registration) = cert {
+            self.register(registration.pool_params.clone());
+        }
+    }
+
+    /// Applies the certificates of a (phase-2 valid) transaction
+    pub fn apply_transaction(&mut self, body: &MultiEraTransactionBody) {
+        if let Some(certs) = body.certs() {
+            certs.iter().for_each(|cert| self.apply_cert(cert));
+        }
+    }
+
+    /// Applies the certificates of all valid transactions in {block}
+    pub fn apply_block(&mut self, block: &MultiEraBlock) {
+        let invalid_transactions = block.invalid_transactions();
+        for (index, body) in block.transaction_bodies().iter().enumerate() {
+            if !invalid_transactions.contains(&(index as u16)) {
+                self.apply_transaction(body);
+            }
+        }
+    }
+
+    pub fn get(&self, operator: &Ed25519KeyHash) -> Option<&PoolParams> {
+        self.pools.get(operator)
+    }
+
+    /// The pool currently registered with this VRF key hash
+    pub fn lookup_by_vrf(&self, vrf_key_hash: &VRFKeyHash) -> Option<&PoolParams> {
+        self.by_vrf
+            .get(vrf_key_hash)
+            .and_then(|operator| self.pools.get(operator))
+    }
+
+    /// The pool that produced the block with this header, by the header's issuer key hash
+    /// or failing that its VRF key hash. None for Byron blocks and unknown pools.
+    pub fn block_producer(&self, header: &MultiEraBlockHeader) -> Option<&PoolParams> {
+        header
+            .issuer_key_hash()
+            .and_then(|operator| self.get(&operator))
+            .or_else(|| {
+                header
+                    .vrf_key_hash()
+                    .and_then(|vrf_key_hash| self.lookup_by_vrf(&vrf_key_hash))
+            })
+    }
+
+    pub fn pools(&self) -> impl Iterator<Item = (&Ed25519KeyHash, &PoolParams)> {
+        self.pools.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.pools.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pools.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test::SHELLEY_NETWORK_BLOCK;
+    use cml_chain::address::RewardAddress;
+    use cml_chain::certs::{PoolRegistration, StakeCredential};
+    use cml_chain::UnitInterval;
+
+    fn pool_params(operator: Ed25519KeyHash, vrf_keyhash: VRFKeyHash) -> PoolParams {
+        PoolParams::new(
+            operator,
+            vrf_keyhash,
+            500_000_000,
+            340_000_000,
+            UnitInterval::new(1, 100),
+            RewardAddress::new(0, StakeCredential::new_pub_key(operator)),
+            vec![operator].into(),
+            vec![],
+            None,
+        )
+    }
+
+    #[test]
+    fn shelley_block_producer() {
+        let header = MultiEraBlock::from_explicit_network_cbor_bytes(
+            &hex::decode(SHELLEY_NETWORK_BLOCK).unwrap(),
+        )
+        .unwrap()
+        .header();
+        let operator =
+            Ed25519KeyHash::from_hex("aae9293510344ddd636364c2673e34e03e79e3eefa8dbaa70e326f7d")
+                .unwrap();
+        let vrf_keyhash = VRFKeyHash::from_hex(
+            "227116365af2ed943f1a8b5e6557bfaa34996f1578eec667a5e2b361c51e4ce7",
+        )
+        .unwrap();
+
+        let mut registry = PoolRegistry::new();
+        registry.apply_cert(&MultiEraCertificate::PoolRegistration(
+            PoolRegistration::new(pool_params(
+                Ed25519KeyHash::from([1; 28]),
+                VRFKeyHash::from([1; 32]),
+            )),
+        ));
+        assert!(registry.block_producer(&header).is_none());
+
+        registry.apply_cert(&MultiEraCertificate::PoolRegistration(
+            PoolRegistration::new(pool_params(operator, vrf_keyhash)),
+        ));
+        assert_eq!(registry.len(), 2);
+        assert_eq!(registry.block_producer(&header).unwrap().operator, operator);
+        assert_eq!(
+            registry.lookup_by_vrf(&vrf_keyhash).unwrap().operator,
+            operator
+        );
+
+        // re-registering with another VRF key moves the VRF lookup over
+        registry.register(pool_params(operator, VRFKeyHash::from([2; 32])));
+        assert!(registry.lookup_by_vrf(&vrf_keyhash).is_none());
+        assert_eq!(
+            registry
+                .lookup_by_vrf(&VRFKeyHash::from([2; 32]))
+                .unwrap()
+                .operator,
+            operator
+        );
+        assert_eq!(registry.len(), 2);
+        assert_eq!(registry.block_producer(&header).unwrap().operator, operator);
+    }
+}
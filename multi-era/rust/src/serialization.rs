@@ -25,6 +25,15 @@ impl Serialize for MultiEraBlock {
 
 impl Deserialize for MultiEraBlock {
     fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
+        Self::deserialize_opt_keep_original(raw, false)
+    }
+}
+
+impl DeserializeKeepOriginal for MultiEraBlock {
+    fn deserialize_opt_keep_original<R: BufRead + Seek>(
+        raw: &mut Deserializer<R>,
+        keep_original: bool,
+    ) -> Result<Self, DeserializeError> {
         (|| -> Result<_, DeserializeError> {
             let initial_position = raw.as_mut_ref().stream_position().unwrap();
             let mut errs = Vec::new();
@@ -38,7 +47,8 @@ impl Deserialize for MultiEraBlock {
                         .unwrap();
                 }
             };
-            let deser_variant: Result<_, DeserializeError> = ShelleyBlock::deserialize(raw);
+            let deser_variant: Result<_, DeserializeError> =
+                ShelleyBlock::deserialize_opt_keep_original(raw, keep_original);
             match deser_variant {
                 Ok(shelley) => return Ok(Self::Shelley(shelley)),
                 Err(e) => {
@@ -48,7 +58,8 @@ impl Deserialize for MultiEraBlock {
                         .unwrap();
                 }
             };
-            let deser_variant: Result<_, DeserializeError> = AllegraBlock::deserialize(raw);
+            let deser_variant: Result<_, DeserializeError> =
+                AllegraBlock::deserialize_opt_keep_original(raw, keep_original);
             match deser_variant {
                 Ok(allegra) => return Ok(Self::Allegra(allegra)),
                 Err(e) => {
@@ -58,7 +69,8 @@ impl Deserialize for MultiEraBlock {
                         .unwrap();
                 }
             };
-            let deser_variant: Result<_, DeserializeError> = MaryBlock::deserialize(raw);
+            let deser_variant: Result<_, DeserializeError> =
+                MaryBlock::deserialize_opt_keep_original(raw, keep_original);
             match deser_variant {
                 Ok(mary) => return Ok(Self::Mary(mary)),
                 Err(e) => {
@@ -68,7 +80,8 @@ impl Deserialize for MultiEraBlock {
                         .unwrap();
                 }
             };
-            let deser_variant: Result<_, DeserializeError> = AlonzoBlock::deserialize(raw);
+            let deser_variant: Result<_, DeserializeError> =
+                AlonzoBlock::deserialize_opt_keep_original(raw, keep_original);
             match deser_variant {
                 Ok(alonzo) => return Ok(Self::Alonzo(alonzo)),
                 Err(e) => {
@@ -78,7 +91,8 @@ impl Deserialize for MultiEraBlock {
                         .unwrap();
                 }
             };
-            let deser_variant: Result<_, DeserializeError> = BabbageBlock::deserialize(raw);
+            let deser_variant: Result<_, DeserializeError> =
+                BabbageBlock::deserialize_opt_keep_original(raw, keep_original);
             match deser_variant {
                 Ok(babbage) => return Ok(Self::Babbage(babbage)),
                 Err(e) => {
@@ -88,7 +102,8 @@ impl Deserialize for MultiEraBlock {
                         .unwrap();
                 }
             };
-            let deser_variant: Result<_, DeserializeError> = Block::deserialize(raw);
+            let deser_variant: Result<_, DeserializeError> =
+                Block::deserialize_opt_keep_original(raw, keep_original);
             match deser_variant {
                 Ok(conway) => return Ok(Self::Conway(conway)),
                 Err(e) => {
@@ -176,6 +176,7 @@ pub struct ShelleyTransactionBodyEncoding {
     pub update_key_encoding: Option<cbor_event::Sz>,
     pub auxiliary_data_hash_encoding: StringEncoding,
     pub auxiliary_data_hash_key_encoding: Option<cbor_event::Sz>,
+    pub original_bytes: Option<Vec<u8>>,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -28,6 +28,7 @@ use cml_chain::crypto::{
 use cml_chain::transaction::TransactionInput;
 use cml_chain::{Epoch, Port, Rational, UnitInterval, Withdrawals};
 use cml_core::ordered_hash_map::OrderedHashMap;
+use cml_core::serialization::StripEncodings;
 use cml_core::{DeserializeError, DeserializeFailure, TransactionIndex};
 use cml_crypto::{GenesisDelegateHash, VRFKeyHash};
 use std::collections::BTreeMap;
@@ -40,7 +41,9 @@ use self::cbor_encodings::{
     ShelleyMoveInstantaneousRewardEncoding, ShelleyMoveInstantaneousRewardsCertEncoding,
     ShelleyPoolParamsEncoding,
 };
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct GenesisKeyDelegation {
     pub genesis_hash: GenesisHash,
     pub genesis_delegate_hash: GenesisDelegateHash,
@@ -64,7 +67,9 @@ impl GenesisKeyDelegation {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct MultisigAll {
     pub multisig_scripts: Vec<MultisigScript>,
     #[serde(skip)]
@@ -80,7 +85,9 @@ impl MultisigAll {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct MultisigAny {
     pub multisig_scripts: Vec<MultisigScript>,
     #[serde(skip)]
@@ -96,7 +103,9 @@ impl MultisigAny {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct MultisigNOfK {
     pub n: u64,
     pub multisig_scripts: Vec<MultisigScript>,
@@ -114,7 +123,9 @@ impl MultisigNOfK {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct MultisigPubkey {
     pub ed25519_key_hash: Ed25519KeyHash,
     #[serde(skip)]
@@ -130,7 +141,9 @@ impl MultisigPubkey {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub enum MultisigScript {
     MultisigPubkey(MultisigPubkey),
     MultisigAll(MultisigAll),
@@ -156,7 +169,9 @@ impl MultisigScript {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ProtocolVersionStruct {
     pub protocol_version: ProtocolVersion,
     #[serde(skip)]
@@ -172,7 +187,9 @@ impl ProtocolVersionStruct {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ShelleyBlock {
     pub header: ShelleyHeader,
     pub transaction_bodies: Vec<ShelleyTransactionBody>,
@@ -200,7 +217,9 @@ impl ShelleyBlock {
 }
 
 #[allow(clippy::large_enum_variant)]
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub enum ShelleyCertificate {
     StakeRegistration(StakeRegistration),
     StakeDeregistration(StakeDeregistration),
@@ -256,7 +275,7 @@ impl ShelleyCertificate {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, StripEncodings)]
 pub struct ShelleyDNSName {
     pub inner: String,
     pub encodings: Option<ShelleyDNSNameEncoding>,
@@ -331,7 +350,9 @@ impl schemars::JsonSchema for ShelleyDNSName {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ShelleyHeader {
     pub body: ShelleyHeaderBody,
     pub signature: KESSignature,
@@ -349,7 +370,9 @@ impl ShelleyHeader {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ShelleyHeaderBody {
     pub block_number: u64,
     pub slot: u64,
@@ -397,7 +420,9 @@ impl ShelleyHeaderBody {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ShelleyMoveInstantaneousReward {
     pub pot: MIRPot,
     pub to_stake_credentials: OrderedHashMap<StakeCredential, Coin>,
@@ -415,7 +440,9 @@ impl ShelleyMoveInstantaneousReward {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ShelleyMoveInstantaneousRewardsCert {
     pub shelley_move_instantaneous_reward: ShelleyMoveInstantaneousReward,
     #[serde(skip)]
@@ -431,7 +458,9 @@ impl ShelleyMoveInstantaneousRewardsCert {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ShelleyMultiHostName {
     /// A SRV DNS record
     pub shelley_dns_name: ShelleyDNSName,
@@ -449,7 +478,9 @@ impl ShelleyMultiHostName {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ShelleyPoolParams {
     pub operator: Ed25519KeyHash,
     pub vrf_keyhash: VRFKeyHash,
@@ -491,7 +522,9 @@ impl ShelleyPoolParams {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ShelleyPoolRegistration {
     pub pool_params: ShelleyPoolParams,
     #[serde(skip)]
@@ -510,7 +543,9 @@ impl ShelleyPoolRegistration {
 pub type ShelleyProposedProtocolParameterUpdates =
     OrderedHashMap<GenesisHash, ShelleyProtocolParamUpdate>;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ShelleyProtocolParamUpdate {
     pub minfee_a: Option<u64>,
     pub minfee_b: Option<u64>,
@@ -562,7 +597,9 @@ impl Default for ShelleyProtocolParamUpdate {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub enum ShelleyRelay {
     SingleHostAddr(SingleHostAddr),
     ShelleySingleHostName(ShelleySingleHostName),
@@ -590,7 +627,9 @@ impl ShelleyRelay {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ShelleySingleHostName {
     pub port: Option<Port>,
     /// An A or AAAA DNS record
@@ -610,7 +649,9 @@ impl ShelleySingleHostName {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ShelleyTransaction {
     pub body: ShelleyTransactionBody,
     pub witness_set: ShelleyTransactionWitnessSet,
@@ -634,7 +675,9 @@ impl ShelleyTransaction {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ShelleyTransactionBody {
     pub inputs: Vec<TransactionInput>,
     pub outputs: Vec<ShelleyTransactionOutput>,
@@ -671,7 +714,9 @@ impl ShelleyTransactionBody {
 
 pub type ShelleyTransactionIndex = u16;
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ShelleyTransactionOutput {
     pub address: Address,
     pub amount: Coin,
@@ -689,7 +734,9 @@ impl ShelleyTransactionOutput {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ShelleyTransactionWitnessSet {
     pub vkeywitnesses: Option<Vec<Vkeywitness>>,
     pub native_scripts: Option<Vec<MultisigScript>>,
@@ -715,7 +762,9 @@ impl Default for ShelleyTransactionWitnessSet {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+#[derive(
+    Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema, StripEncodings,
+)]
 pub struct ShelleyUpdate {
     pub shelley_proposed_protocol_parameter_updates: ShelleyProposedProtocolParameterUpdates,
     pub epoch: Epoch,
@@ -1,19 +1,53 @@
 use cml_chain::{
     certs::{DNSName, PoolParams, PoolRegistration, Relay},
-    transaction::{NativeScript, TransactionWitnessSet},
+    transaction::{
+        cbor_encodings::{
+            ScriptAllEncoding, ScriptAnyEncoding, ScriptNOfKEncoding, ScriptPubkeyEncoding,
+        },
+        NativeScript, ScriptAll, ScriptAny, ScriptNOfK, ScriptPubkey, TransactionWitnessSet,
+    },
 };
 
 use super::{
-    MultisigScript, ShelleyPoolRegistration, ShelleyRelay, ShelleyTransactionBody,
-    ShelleyTransactionWitnessSet,
+    MultisigScript, ShelleyDNSName, ShelleyHeaderBody, ShelleyPoolParams, ShelleyPoolRegistration,
+    ShelleyRelay, ShelleyTransactionBody, ShelleyTransactionWitnessSet,
 };
 
-use cml_core::serialization::Serialize;
-use cml_crypto::{blake2b256, TransactionHash};
+use cml_core::serialization::{OriginalBytes, Serialize};
+use cml_core::DeserializeError;
+use cml_crypto::{blake2b224, blake2b256, NonceHash, ScriptHash, TransactionHash};
+use std::convert::TryFrom;
+
+impl ShelleyHeaderBody {
+    /// This block's contribution to the evolving epoch nonce.
+    /// TPraos (Shelley through Alonzo) headers have a dedicated `nonce_vrf` certificate
+    /// whose output is hashed directly. See VRFCert::tpraos_nonce()
+    pub fn vrf_output_to_nonce(&self) -> NonceHash {
+        self.nonce_vrf.tpraos_nonce()
+    }
+}
 
 impl ShelleyTransactionBody {
+    /// Uses the original bytes if they were kept (see OriginalBytes)
     pub fn hash(&self) -> TransactionHash {
-        blake2b256(&self.to_cbor_bytes()).into()
+        match self.original_bytes() {
+            Some(original_bytes) => blake2b256(original_bytes).into(),
+            None => blake2b256(&self.to_cbor_bytes()).into(),
+        }
+    }
+}
+
+impl OriginalBytes for ShelleyTransactionBody {
+    fn original_bytes(&self) -> Option<&[u8]> {
+        self.encodings
+            .as_ref()
+            .and_then(|encs| encs.original_bytes.as_deref())
+    }
+
+    fn set_original_bytes(&mut self, original_bytes: Option<Vec<u8>>) {
+        self.encodings
+            .get_or_insert_with(Default::default)
+            .original_bytes = original_bytes;
     }
 }
 
@@ -33,31 +67,70 @@ impl From<ShelleyTransactionWitnessSet> for TransactionWitnessSet {
     }
 }
 
+impl MultisigScript {
+    /// Shelley multisig scripts are the subset of native scripts without timelocks
+    /// and are hashed the same way (with the native script tag byte).
+    /// Uses this script's encoding details so this matches the on-chain script hash.
+    pub fn hash(&self) -> ScriptHash {
+        let mut bytes = vec![NATIVE_SCRIPT_TAG];
+        bytes.extend(self.to_cbor_bytes());
+        ScriptHash::from(blake2b224(&bytes))
+    }
+}
+
+/// Script hash namespace byte of native scripts (and so also Shelley multisig scripts)
+const NATIVE_SCRIPT_TAG: u8 = 0x00;
+
+/// Keeps all encoding details so the NativeScript has the same bytes (and hash) as the MultisigScript
 impl From<MultisigScript> for NativeScript {
     fn from(script: MultisigScript) -> Self {
         match script {
-            MultisigScript::MultisigPubkey(key) => {
-                NativeScript::new_script_pubkey(key.ed25519_key_hash)
-            }
-            MultisigScript::MultisigAll(all) => NativeScript::new_script_all(
-                all.multisig_scripts
+            MultisigScript::MultisigPubkey(key) => NativeScript::ScriptPubkey(ScriptPubkey {
+                ed25519_key_hash: key.ed25519_key_hash,
+                encodings: key.encodings.map(|encs| ScriptPubkeyEncoding {
+                    len_encoding: encs.len_encoding,
+                    tag_encoding: encs.tag_encoding,
+                    ed25519_key_hash_encoding: encs.ed25519_key_hash_encoding,
+                }),
+            }),
+            MultisigScript::MultisigAll(all) => NativeScript::ScriptAll(ScriptAll {
+                native_scripts: all
+                    .multisig_scripts
                     .into_iter()
                     .map(NativeScript::from)
                     .collect(),
-            ),
-            MultisigScript::MultisigAny(any) => NativeScript::new_script_any(
-                any.multisig_scripts
+                encodings: all.encodings.map(|encs| ScriptAllEncoding {
+                    len_encoding: encs.len_encoding,
+                    tag_encoding: encs.tag_encoding,
+                    native_scripts_encoding: encs.multisig_scripts_encoding,
+                }),
+            }),
+            MultisigScript::MultisigAny(any) => NativeScript::ScriptAny(ScriptAny {
+                native_scripts: any
+                    .multisig_scripts
                     .into_iter()
                     .map(NativeScript::from)
                     .collect(),
-            ),
-            MultisigScript::MultisigNOfK(nok) => NativeScript::new_script_n_of_k(
-                nok.n,
-                nok.multisig_scripts
+                encodings: any.encodings.map(|encs| ScriptAnyEncoding {
+                    len_encoding: encs.len_encoding,
+                    tag_encoding: encs.tag_encoding,
+                    native_scripts_encoding: encs.multisig_scripts_encoding,
+                }),
+            }),
+            MultisigScript::MultisigNOfK(nok) => NativeScript::ScriptNOfK(ScriptNOfK {
+                n: nok.n,
+                native_scripts: nok
+                    .multisig_scripts
                     .into_iter()
                     .map(NativeScript::from)
                     .collect(),
-            ),
+                encodings: nok.encodings.map(|encs| ScriptNOfKEncoding {
+                    len_encoding: encs.len_encoding,
+                    tag_encoding: encs.tag_encoding,
+                    n_encoding: encs.n_encoding,
+                    native_scripts_encoding: encs.multisig_scripts_encoding,
+                }),
+            }),
         }
     }
 }
@@ -99,3 +172,152 @@ impl From<ShelleyRelay> for Relay {
         }
     }
 }
+
+impl TryFrom<PoolRegistration> for ShelleyPoolRegistration {
+    /// Relay DNS names longer than 64 bytes can't be represented before Conway
+    type Error = DeserializeError;
+
+    fn try_from(pool_reg: PoolRegistration) -> Result<Self, Self::Error> {
+        let params = pool_reg.pool_params;
+        Ok(Self::new(ShelleyPoolParams::new(
+            params.operator,
+            params.vrf_keyhash,
+            params.pledge,
+            params.cost,
+            params.margin,
+            params.reward_account,
+            params.pool_owners.into(),
+            params
+                .relays
+                .into_iter()
+                .map(ShelleyRelay::try_from)
+                .collect::<Result<_, _>>()?,
+            params.pool_metadata,
+        )))
+    }
+}
+
+impl TryFrom<Relay> for ShelleyRelay {
+    /// Relay DNS names longer than 64 bytes can't be represented before Conway
+    type Error = DeserializeError;
+
+    fn try_from(relay: Relay) -> Result<Self, Self::Error> {
+        Ok(match relay {
+            Relay::SingleHostAddr(host) => Self::SingleHostAddr(host),
+            Relay::SingleHostName(host) => Self::new_shelley_single_host_name(
+                host.port,
+                ShelleyDNSName::new(host.dns_name.inner)?,
+            ),
+            Relay::MultiHostName(host) => {
+                Self::new_shelley_multi_host_name(ShelleyDNSName::new(host.dns_name.inner)?)
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cml_chain::address::EnterpriseAddress;
+    use cml_chain::certs::StakeCredential;
+    use cml_core::serialization::Deserialize;
+
+    #[test]
+    fn multisig_script_hash() {
+        // all [] (definite and indefinite list) and an any [pubkey] script
+        for script_hex in [
+            "820180",
+            "82019fff",
+            &format!("8202818200581c{}", "ab".repeat(28)),
+        ] {
+            let bytes = hex::decode(script_hex).unwrap();
+            let multisig = MultisigScript::from_cbor_bytes(&bytes).unwrap();
+            let native_script = NativeScript::from(multisig.clone());
+            assert_eq!(native_script.to_cbor_bytes(), bytes);
+            assert_eq!(multisig.hash(), native_script.hash());
+            assert_eq!(
+                multisig.hash(),
+                NativeScript::from_cbor_bytes(&bytes).unwrap().hash()
+            );
+        }
+
+        // the well-known hash of the all [] script and its mainnet enterprise address
+        let multisig = MultisigScript::new_multisig_all(vec![]);
+        assert_eq!(
+            multisig.hash().to_hex(),
+            "d441227553a0f1a965fee7d60a0f724b368dd1bddbc208730fccebcf"
+        );
+        let address = EnterpriseAddress::new(1, StakeCredential::new_script(multisig.hash()));
+        assert_eq!(
+            address.to_address().to_bech32(None).unwrap(),
+            "addr1w82yzgn42ws0r2t9lmnavzs0wf9ndrw3hhduyzrnplxwhncxvfgxz"
+        );
+    }
+
+    #[test]
+    fn protocol_param_update_rational_tags() {
+        use crate::shelley::ShelleyProtocolParamUpdate;
+        use crate::utils::test::ALLEGRA_NETWORK_BLOCK;
+        use crate::MultiEraBlock;
+        use cml_chain::{Rational, UnitInterval};
+
+        // every update in the block is { 12: 30([0, 1]), 14: [4, 0] } with d tagged as the CDDL requires
+        let tagged = hex::decode("a20cd81e8200010e820400").unwrap();
+        // the same block with tag 30 removed from d as some historical updates encode it.
+        // arrays aren't length-prefixed in bytes so only the (unchecked) body hash/size differ
+        let untagged_block = ALLEGRA_NETWORK_BLOCK.replace("0cd81e820001", "0c820001");
+        assert_ne!(untagged_block, ALLEGRA_NETWORK_BLOCK);
+        let untagged = hex::decode("a20c8200010e820400").unwrap();
+        for (block_hex, update_bytes) in [
+            (ALLEGRA_NETWORK_BLOCK, &tagged),
+            (untagged_block.as_str(), &untagged),
+        ] {
+            let block_bytes = hex::decode(block_hex).unwrap();
+            let block = MultiEraBlock::from_explicit_network_cbor_bytes(&block_bytes).unwrap();
+            let allegra = match &block {
+                MultiEraBlock::Allegra(allegra) => allegra,
+                _ => panic!("not an allegra block"),
+            };
+            // [era, block]
+            assert_eq!(allegra.to_cbor_bytes(), block_bytes[2..]);
+            let updates = allegra
+                .transaction_bodies
+                .iter()
+                .filter_map(|body| body.update.as_ref())
+                .flat_map(|update| {
+                    update
+                        .shelley_proposed_protocol_parameter_updates
+                        .values()
+                        .cloned()
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(updates.len(), 7);
+            for update in updates.iter() {
+                assert_eq!(update.to_cbor_bytes(), *update_bytes);
+                assert_eq!(update.to_canonical_cbor_bytes(), tagged);
+                let d = update.decentralization_constant.as_ref().unwrap();
+                assert_eq!((d.start, d.end), (0, 1));
+                assert_eq!(d.to_f64(), 0.0);
+            }
+        }
+        // other tags are still rejected
+        assert!(ShelleyProtocolParamUpdate::from_cbor_bytes(
+            &hex::decode("a20cd81f8200010e820400").unwrap()
+        )
+        .is_err());
+        // newly created values are tagged
+        let mut update = ShelleyProtocolParamUpdate::from_cbor_bytes(&untagged).unwrap();
+        update.decentralization_constant = Some(UnitInterval::new(0, 1));
+        assert_eq!(update.to_cbor_bytes(), tagged);
+        // none of the fixture updates set a0 but Rational is handled the same way
+        for rational_hex in ["d81e82030a", "82030a"] {
+            let bytes = hex::decode(rational_hex).unwrap();
+            let a0 = Rational::from_cbor_bytes(&bytes).unwrap();
+            assert_eq!(a0.to_cbor_bytes(), bytes);
+            assert_eq!(
+                a0.to_canonical_cbor_bytes(),
+                Rational::new(3, 10).to_cbor_bytes()
+            );
+        }
+    }
+}
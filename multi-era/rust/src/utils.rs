@@ -1,16 +1,26 @@
 use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
 
+use crate::allegra::cbor_encodings::{
+    MoveInstantaneousRewardEncoding, MoveInstantaneousRewardsCertEncoding,
+};
 use crate::allegra::{
-    AllegraCertificate, MIRAction, MoveInstantaneousReward, MoveInstantaneousRewardsCert,
+    AllegraCertificate, AllegraTransaction, MIRAction, MoveInstantaneousReward,
+    MoveInstantaneousRewardsCert,
+};
+use crate::alonzo::{AlonzoProtocolParamUpdate, AlonzoTransaction, AlonzoUpdate};
+use crate::babbage::{
+    BabbageProtocolParamUpdate, BabbageTransaction, BabbageTransactionOutput, BabbageUpdate,
 };
-use crate::alonzo::AlonzoProtocolParamUpdate;
-use crate::babbage::{BabbageProtocolParamUpdate, BabbageTransactionOutput};
-use crate::byron::block::{ByronBlockHeader, ByronEbBlock, ByronMainBlock, EbbHead};
-use crate::byron::transaction::ByronTxIn;
-use crate::mary::MaryTransactionOutput;
+use crate::byron::block::{ByronBlockHeader, ByronEbBlock, ByronMainBlock, EbbHead, TxAux};
+use crate::byron::delegation::ByronDelegation;
+use crate::byron::transaction::{ByronTxIn, ByronTxWitness};
+use crate::byron::update::ByronUpdate;
+use crate::mary::{MaryTransaction, MaryTransactionOutput};
 use crate::shelley::{
     GenesisKeyDelegation, ProtocolVersionStruct, ShelleyCertificate, ShelleyHeader,
-    ShelleyProtocolParamUpdate, ShelleyTransactionOutput,
+    ShelleyProtocolParamUpdate, ShelleyTransaction, ShelleyTransactionOutput, ShelleyUpdate,
 };
 use crate::{
     allegra::AllegraBlock, alonzo::AlonzoBlock, babbage::BabbageBlock, byron::block::ByronBlock,
@@ -19,35 +29,85 @@ use crate::{
 use crate::{MultiEraBlock, MultiEraTransactionBody};
 
 use cbor_event::de::Deserializer;
+use cbor_event::se::Serializer;
 use cml_chain::address::Address;
 use cml_chain::assets::{Mint, PositiveCoin};
 use cml_chain::auxdata::AuxiliaryData;
-use cml_chain::block::{Block, Header, OperationalCert, ProtocolVersion};
+use cml_chain::block::{Block, Header, OperationalCert, ProtocolVersion, UnparsedAuxiliaryData};
 use cml_chain::byron::ByronTxOut;
 use cml_chain::certs::{
-    AuthCommitteeHotCert, Certificate, PoolRegistration, PoolRetirement, RegCert, RegDrepCert,
-    ResignCommitteeColdCert, StakeDelegation, StakeDeregistration, StakeRegDelegCert,
-    StakeRegistration, StakeVoteDelegCert, StakeVoteRegDelegCert, UnregCert, UnregDrepCert,
-    UpdateDrepCert, VoteDelegCert, VoteRegDelegCert,
+    AuthCommitteeHotCert, Certificate, DRep, PoolRegistration, PoolRetirement, RegCert,
+    RegDrepCert, ResignCommitteeColdCert, StakeCredential, StakeDelegation, StakeDeregistration,
+    StakeRegDelegCert, StakeRegistration, StakeVoteDelegCert, StakeVoteRegDelegCert, UnregCert,
+    UnregDrepCert, UpdateDrepCert, VoteDelegCert, VoteRegDelegCert,
 };
 use cml_chain::crypto::{Nonce, VRFCert, Vkey};
 use cml_chain::governance::{ProposalProcedure, VotingProcedures};
-use cml_chain::plutus::{CostModels, ExUnitPrices, ExUnits};
+use cml_chain::plutus::{CostModels, ExUnitPrices, ExUnits, Redeemers};
 use cml_chain::transaction::{
-    AlonzoFormatTxOut, TransactionInput, TransactionOutput, TransactionWitnessSet,
+    AlonzoFormatTxOut, Transaction, TransactionBody, TransactionInput, TransactionOutput,
+    TransactionWitnessSet,
 };
 use cml_chain::{
-    Coin, DRepVotingThresholds, NetworkId, OrderedHashMap, PoolVotingThresholds,
-    ProtocolParamUpdate, Rational, UnitInterval, Value, Withdrawals,
+    Coin, DRepVotingThresholds, NetworkId, NonemptySetCertificate, OrderedHashMap,
+    PoolVotingThresholds, ProtocolParamUpdate, Rational, UnitInterval, Value, Withdrawals,
 };
 use cml_core::error::{DeserializeError, DeserializeFailure};
 use cml_core::serialization::*;
 use cml_core::{Epoch, Int, TransactionIndex};
 use cml_crypto::{
     blake2b256, AuxiliaryDataHash, BlockBodyHash, BlockHeaderHash, Ed25519KeyHash, GenesisHash,
-    RawBytesEncoding, ScriptDataHash, TransactionHash, VRFVkey,
+    NonceHash, RawBytesEncoding, ScriptDataHash, TransactionHash, VRFKeyHash, VRFVkey,
 };
 
+/// Options for MultiEraBlock::from_explicit_network_cbor_bytes_with_options()
+#[derive(Clone, Debug, Default)]
+pub struct NetworkBlockOptions {
+    allow_unknown_era: bool,
+    era_tag_aliases: BTreeMap<u64, Era>,
+    keep_original_bytes: bool,
+}
+
+impl NetworkBlockOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return blocks with unknown era tags as NetworkBlock::Unknown instead of failing.
+    /// Useful for forward-compatible pipelines that must not stop when a new era ships.
+    pub fn allow_unknown_era(mut self, allow_unknown_era: bool) -> Self {
+        self.allow_unknown_era = allow_unknown_era;
+        self
+    }
+
+    /// Parse blocks with the era tag {tag} as {era} e.g. if the network uses a new tag for an intra-era fork.
+    /// Aliasing to Byron parses Byron main blocks (not epoch boundary blocks).
+    pub fn era_tag_alias(mut self, tag: u64, era: Era) -> Self {
+        self.era_tag_aliases.insert(tag, era);
+        self
+    }
+
+    /// Have the header and transaction bodies keep the exact bytes they were parsed from
+    /// for hashing. Off by default. See OriginalBytes.
+    pub fn keep_original_bytes(mut self, keep_original_bytes: bool) -> Self {
+        self.keep_original_bytes = keep_original_bytes;
+        self
+    }
+}
+
+/// A block in the network format as returned by MultiEraBlock::from_explicit_network_cbor_bytes_with_options()
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug)]
+pub enum NetworkBlock {
+    Known(MultiEraBlock),
+    /// Block with an era tag CML doesn't know about. Only returned when allowed in NetworkBlockOptions.
+    Unknown {
+        era: u64,
+        /// The CBOR bytes of the block inside the network wrapper
+        bytes: Vec<u8>,
+    },
+}
+
 impl MultiEraBlock {
     /**
      * Parses a block given the network block format with explicit era tag
@@ -59,40 +119,98 @@ impl MultiEraBlock {
      * then you should use this function instead of the regular from_cbor_bytes().
      */
     pub fn from_explicit_network_cbor_bytes(bytes: &[u8]) -> Result<Self, DeserializeError> {
+        match Self::from_explicit_network_cbor_bytes_with_options(
+            bytes,
+            &NetworkBlockOptions::default(),
+        )? {
+            NetworkBlock::Known(block) => Ok(block),
+            // only possible with allow_unknown_era
+            NetworkBlock::Unknown { .. } => unreachable!(),
+        }
+    }
+
+    /// Same as from_explicit_network_cbor_bytes() but with control over how era tags are handled.
+    /// See NetworkBlockOptions.
+    pub fn from_explicit_network_cbor_bytes_with_options(
+        bytes: &[u8],
+        options: &NetworkBlockOptions,
+    ) -> Result<NetworkBlock, DeserializeError> {
         let mut raw = Deserializer::from(std::io::Cursor::new(bytes));
         let len = raw.array()?;
         let mut read_len = CBORReadLen::from(len);
         read_len.read_elems(2)?;
         read_len.finish()?;
-        let era = raw
+        let era_tag = raw
             .unsigned_integer()
             .map_err(|e| DeserializeError::from(e).annotate("block_era_tag"))?;
+        let era = match era_tag {
+            0 | 1 => Some(Era::Byron),
+            2 => Some(Era::Shelley),
+            3 => Some(Era::Allegra),
+            4 => Some(Era::Mary),
+            5 => Some(Era::Alonzo),
+            6 => Some(Era::Babbage),
+            7 => Some(Era::Conway),
+            _ => options.era_tag_aliases.get(&era_tag).copied(),
+        };
         let block = match era {
-            0 => ByronEbBlock::deserialize(&mut raw)
+            Some(Era::Byron) if era_tag == 0 => ByronEbBlock::deserialize(&mut raw)
                 .map(|ebb| Self::Byron(ByronBlock::EpochBoundary(ebb)))
-                .map_err(|e| e.annotate("Byron EBB")),
-            1 => ByronMainBlock::deserialize(&mut raw)
+                .map_err(|e| e.annotate("Byron EBB"))
+                .map(NetworkBlock::Known),
+            Some(Era::Byron) => ByronMainBlock::deserialize(&mut raw)
                 .map(|mb| Self::Byron(ByronBlock::Main(mb)))
-                .map_err(|e| e.annotate("Byron")),
-            2 => ShelleyBlock::deserialize(&mut raw)
-                .map(Self::Shelley)
-                .map_err(|e| e.annotate("Shelley")),
-            3 => AllegraBlock::deserialize(&mut raw)
-                .map(Self::Allegra)
-                .map_err(|e| e.annotate("Allegra")),
-            4 => MaryBlock::deserialize(&mut raw)
-                .map(Self::Mary)
-                .map_err(|e| e.annotate("Mary")),
-            5 => AlonzoBlock::deserialize(&mut raw)
-                .map(Self::Alonzo)
-                .map_err(|e| e.annotate("Alonzo")),
-            6 => BabbageBlock::deserialize(&mut raw)
-                .map(Self::Babbage)
-                .map_err(|e| e.annotate("Babbage")),
-            7 => Block::deserialize(&mut raw)
-                .map(Self::Conway)
-                .map_err(|e| e.annotate("Conway")),
-            _ => Err(DeserializeFailure::NoVariantMatched.into()),
+                .map_err(|e| e.annotate("Byron"))
+                .map(NetworkBlock::Known),
+            Some(Era::Shelley) => {
+                ShelleyBlock::deserialize_opt_keep_original(&mut raw, options.keep_original_bytes)
+                    .map(|block| NetworkBlock::Known(Self::Shelley(block)))
+                    .map_err(|e| e.annotate("Shelley"))
+            }
+            Some(Era::Allegra) => {
+                AllegraBlock::deserialize_opt_keep_original(&mut raw, options.keep_original_bytes)
+                    .map(|block| NetworkBlock::Known(Self::Allegra(block)))
+                    .map_err(|e| e.annotate("Allegra"))
+            }
+            Some(Era::Mary) => {
+                MaryBlock::deserialize_opt_keep_original(&mut raw, options.keep_original_bytes)
+                    .map(|block| NetworkBlock::Known(Self::Mary(block)))
+                    .map_err(|e| e.annotate("Mary"))
+            }
+            Some(Era::Alonzo) => {
+                AlonzoBlock::deserialize_opt_keep_original(&mut raw, options.keep_original_bytes)
+                    .map(|block| NetworkBlock::Known(Self::Alonzo(block)))
+                    .map_err(|e| e.annotate("Alonzo"))
+            }
+            Some(Era::Babbage) => {
+                BabbageBlock::deserialize_opt_keep_original(&mut raw, options.keep_original_bytes)
+                    .map(|block| NetworkBlock::Known(Self::Babbage(block)))
+                    .map_err(|e| e.annotate("Babbage"))
+            }
+            Some(Era::Conway) => {
+                Block::deserialize_opt_keep_original(&mut raw, options.keep_original_bytes)
+                    .map(|block| NetworkBlock::Known(Self::Conway(block)))
+                    .map_err(|e| e.annotate("Conway"))
+            }
+            None if options.allow_unknown_era => {
+                // skip over the block to find where it ends without knowing its format
+                let start = raw.as_mut_ref().position() as usize;
+                <cbor_event::Value as cbor_event::de::Deserialize>::deserialize(&mut raw)
+                    .map_err(|e| DeserializeError::from(e).annotate("unknown era block"))?;
+                let end = raw.as_mut_ref().position() as usize;
+                Ok(NetworkBlock::Unknown {
+                    era: era_tag,
+                    bytes: bytes[start..end].to_vec(),
+                })
+            }
+            None => Err(DeserializeError::new(
+                "block_era_tag",
+                DeserializeFailure::RangeCheck {
+                    found: isize::try_from(era_tag).unwrap_or(isize::MAX),
+                    min: Some(0),
+                    max: Some(7),
+                },
+            )),
         }?;
         match len {
             cbor_event::Len::Len(_) => (),
@@ -104,6 +222,18 @@ impl MultiEraBlock {
         Ok(block)
     }
 
+    pub fn era(&self) -> Era {
+        match self {
+            Self::Byron(_) => Era::Byron,
+            Self::Shelley(_) => Era::Shelley,
+            Self::Allegra(_) => Era::Allegra,
+            Self::Mary(_) => Era::Mary,
+            Self::Alonzo(_) => Era::Alonzo,
+            Self::Babbage(_) => Era::Babbage,
+            Self::Conway(_) => Era::Conway,
+        }
+    }
+
     pub fn header(&self) -> MultiEraBlockHeader {
         match self {
             Self::Byron(block) => match block {
@@ -184,7 +314,7 @@ impl MultiEraBlock {
             Self::Alonzo(block) => block
                 .transaction_witness_sets
                 .iter()
-                .map(|wits| wits.clone().into())
+                .map(|wits| wits.to_conway_witness_set())
                 .collect(),
             Self::Babbage(block) => block
                 .transaction_witness_sets
@@ -227,6 +357,16 @@ impl MultiEraBlock {
         }
     }
 
+    /// Auxiliary data entries that are well-formed CBOR but couldn't be deserialized
+    /// (e.g. pathological mainnet metadata). The rest of the block is still available and the
+    /// entries are re-serialized from their original bytes. Only Conway blocks tolerate these.
+    pub fn auxiliary_data_errors(&self) -> &[UnparsedAuxiliaryData] {
+        match self {
+            Self::Conway(block) => &block.unparsed_auxiliary_data,
+            _ => &[],
+        }
+    }
+
     pub fn invalid_transactions(&self) -> Vec<TransactionIndex> {
         match self {
             Self::Byron(_block) => vec![],
@@ -239,6 +379,338 @@ impl MultiEraBlock {
         }
     }
 
+    /// The standalone transaction at {index} reassembled from the block's body, witness set
+    /// and auxiliary data in this block's era's format e.g. for replaying or resubmitting it.
+    /// Encoding details are preserved so its body hashes the same as transaction_bodies()[index].
+    /// Byron transactions are given as [tx, witnesses] (TxAux).
+    pub fn transaction_cbor(&self, index: usize) -> Result<Vec<u8>, TransactionIndexOutOfRange> {
+        fn components<'a, B, W>(
+            bodies: &'a [B],
+            witness_sets: &'a [W],
+            index: usize,
+        ) -> Result<(&'a B, &'a W), TransactionIndexOutOfRange> {
+            bodies
+                .get(index)
+                .zip(witness_sets.get(index))
+                .ok_or(TransactionIndexOutOfRange {
+                    index,
+                    count: bodies.len().min(witness_sets.len()),
+                })
+        }
+        // a block can't contain more transactions than TransactionIndex can index
+        let tx_index = index as TransactionIndex;
+        let is_valid = !self.invalid_transactions().contains(&tx_index);
+        let bytes = match self {
+            Self::Byron(block) => {
+                let txs: &[TxAux] = match block {
+                    ByronBlock::EpochBoundary(_) => &[],
+                    ByronBlock::Main(main) => &main.body.tx_payload,
+                };
+                txs.get(index)
+                    .ok_or(TransactionIndexOutOfRange {
+                        index,
+                        count: txs.len(),
+                    })?
+                    .to_bytes()
+            }
+            Self::Shelley(block) => {
+                let (body, witness_set) = components(
+                    &block.transaction_bodies,
+                    &block.transaction_witness_sets,
+                    index,
+                )?;
+                ShelleyTransaction::new(
+                    body.clone(),
+                    witness_set.clone(),
+                    block.transaction_metadata_set.get(&tx_index).cloned(),
+                )
+                .to_cbor_bytes()
+            }
+            Self::Allegra(block) => {
+                let (body, witness_set) = components(
+                    &block.transaction_bodies,
+                    &block.transaction_witness_sets,
+                    index,
+                )?;
+                AllegraTransaction::new(
+                    body.clone(),
+                    witness_set.clone(),
+                    block.auxiliary_data_set.get(&tx_index).cloned(),
+                )
+                .to_cbor_bytes()
+            }
+            Self::Mary(block) => {
+                let (body, witness_set) = components(
+                    &block.transaction_bodies,
+                    &block.transaction_witness_sets,
+                    index,
+                )?;
+                MaryTransaction::new(
+                    body.clone(),
+                    witness_set.clone(),
+                    block.auxiliary_data_set.get(&tx_index).cloned(),
+                )
+                .to_cbor_bytes()
+            }
+            Self::Alonzo(block) => {
+                let (body, witness_set) = components(
+                    &block.transaction_bodies,
+                    &block.transaction_witness_sets,
+                    index,
+                )?;
+                AlonzoTransaction::new(
+                    body.clone(),
+                    witness_set.clone(),
+                    is_valid,
+                    block.auxiliary_data_set.get(&tx_index).cloned(),
+                )
+                .to_cbor_bytes()
+            }
+            Self::Babbage(block) => {
+                let (body, witness_set) = components(
+                    &block.transaction_bodies,
+                    &block.transaction_witness_sets,
+                    index,
+                )?;
+                BabbageTransaction::new(
+                    body.clone(),
+                    witness_set.clone(),
+                    is_valid,
+                    block.auxiliary_data_set.get(&tx_index).cloned(),
+                )
+                .to_cbor_bytes()
+            }
+            Self::Conway(block) => {
+                let (body, witness_set) = components(
+                    &block.transaction_bodies,
+                    &block.transaction_witness_sets,
+                    index,
+                )?;
+                match block
+                    .unparsed_auxiliary_data
+                    .iter()
+                    .find(|unparsed| unparsed.transaction_index == tx_index)
+                {
+                    // Transaction can't hold these so its array is written by hand with the kept bytes
+                    Some(unparsed) => {
+                        let mut serializer = Serializer::new_vec();
+                        serializer
+                            .write_array(cbor_event::Len::Len(4))
+                            .and_then(|s| body.serialize(s, false))
+                            .and_then(|s| witness_set.serialize(s, false))
+                            .and_then(|s| s.write_special(cbor_event::Special::Bool(is_valid)))
+                            .and_then(|s| s.write_raw_bytes(&unparsed.bytes))
+                            .expect("writing to a Vec can't fail");
+                        serializer.finalize()
+                    }
+                    None => Transaction::new(
+                        body.clone(),
+                        witness_set.clone(),
+                        is_valid,
+                        block.auxiliary_data_set.get(&tx_index).cloned(),
+                    )
+                    .to_cbor_bytes(),
+                }
+            }
+        };
+        Ok(bytes)
+    }
+
+    /// Every output created by this block paired with its output reference i.e. the
+    /// TransactionInput that would spend it.
+    /// Outputs of phase-2 invalid transactions (see invalid_transactions()) are skipped as the ledger
+    /// never creates them. Their collateral return is created instead, with the index the ledger
+    /// gives it: the number of regular outputs of that transaction.
+    pub fn all_outputs(&self) -> Vec<(TransactionInput, MultiEraTransactionOutput)> {
+        let invalid_transactions = self.invalid_transactions();
+        let mut all_outputs = Vec::new();
+        for (tx_index, body) in self.transaction_bodies().into_iter().enumerate() {
+            let tx_hash = body.hash();
+            let outputs = body.outputs();
+            if invalid_transactions.contains(&(tx_index as TransactionIndex)) {
+                if let Some(collateral_return) = body.collateral_return() {
+                    all_outputs.push((
+                        TransactionInput::new(tx_hash, outputs.len() as u64),
+                        collateral_return,
+                    ));
+                }
+            } else {
+                all_outputs.extend(
+                    outputs.into_iter().enumerate().map(|(index, output)| {
+                        (TransactionInput::new(tx_hash, index as u64), output)
+                    }),
+                );
+            }
+        }
+        all_outputs
+    }
+
+    /// Heavyweight delegation certificates of Byron main blocks, which decide which keys may
+    /// sign blocks for the genesis keys. Empty for epoch boundary blocks and all later eras.
+    pub fn byron_delegation_certificates(&self) -> &[ByronDelegation] {
+        match self {
+            Self::Byron(ByronBlock::Main(block)) => block.delegation_certificates(),
+            _ => &[],
+        }
+    }
+
+    /// All protocol update payloads in this block.
+    /// Byron main blocks carry theirs in the block body (returned if it has a proposal or votes),
+    /// while Shelley to Babbage carry them inside transaction bodies (see MultiEraTransactionBody::update()).
+    /// Conway replaced these with governance proposals so it never has any.
+    pub fn update_payloads(&self) -> Vec<MultiEraUpdatePayload> {
+        match self {
+            Self::Byron(ByronBlock::Main(block)) => {
+                let upd = &block.body.upd_payload;
+                if upd.proposal.is_empty() && upd.votes.is_empty() {
+                    vec![]
+                } else {
+                    vec![MultiEraUpdatePayload::Byron(upd.clone())]
+                }
+            }
+            Self::Byron(ByronBlock::EpochBoundary(_)) => vec![],
+            _ => self
+                .transaction_bodies()
+                .iter()
+                .filter_map(MultiEraTransactionBody::update)
+                .map(MultiEraUpdatePayload::Shelley)
+                .collect(),
+        }
+    }
+
+    /// Per-transaction witness statistics for the whole block, computed without cloning
+    /// or converting any witness sets.
+    /// Byron has no vkey witnesses or scripts in the Shelley sense: its public key and
+    /// redeem witnesses are counted as bootstrap witnesses.
+    pub fn witness_summary(&self) -> BlockWitnessSummary {
+        fn len<T, C: AsRef<[T]>>(elems: &Option<C>) -> u64 {
+            elems.as_ref().map(|c| c.as_ref().len() as u64).unwrap_or(0)
+        }
+        let transactions: Vec<TransactionWitnessSummary> = match self {
+            Self::Byron(ByronBlock::EpochBoundary(_)) => vec![],
+            Self::Byron(ByronBlock::Main(block)) => block
+                .body
+                .tx_payload
+                .iter()
+                .map(|tx_aux| TransactionWitnessSummary {
+                    bootstrap_witnesses: tx_aux
+                        .byron_tx_witnesss
+                        .iter()
+                        .filter(|wit| !matches!(wit, ByronTxWitness::ByronScriptWitness(_)))
+                        .count() as u64,
+                    ..Default::default()
+                })
+                .collect(),
+            Self::Shelley(block) => block
+                .transaction_witness_sets
+                .iter()
+                .map(|wits| TransactionWitnessSummary {
+                    vkey_witnesses: len(&wits.vkeywitnesses),
+                    bootstrap_witnesses: len(&wits.bootstrap_witnesses),
+                    native_scripts: len(&wits.native_scripts),
+                    ..Default::default()
+                })
+                .collect(),
+            Self::Allegra(block) => block
+                .transaction_witness_sets
+                .iter()
+                .map(|wits| TransactionWitnessSummary {
+                    vkey_witnesses: len(&wits.vkeywitnesses),
+                    bootstrap_witnesses: len(&wits.bootstrap_witnesses),
+                    native_scripts: len(&wits.native_scripts),
+                    ..Default::default()
+                })
+                .collect(),
+            Self::Mary(block) => block
+                .transaction_witness_sets
+                .iter()
+                .map(|wits| TransactionWitnessSummary {
+                    vkey_witnesses: len(&wits.vkeywitnesses),
+                    bootstrap_witnesses: len(&wits.bootstrap_witnesses),
+                    native_scripts: len(&wits.native_scripts),
+                    ..Default::default()
+                })
+                .collect(),
+            Self::Alonzo(block) => block
+                .transaction_witness_sets
+                .iter()
+                .map(|wits| TransactionWitnessSummary {
+                    vkey_witnesses: len(&wits.vkeywitnesses),
+                    bootstrap_witnesses: len(&wits.bootstrap_witnesses),
+                    native_scripts: len(&wits.native_scripts),
+                    plutus_v1_scripts: len(&wits.plutus_v1_scripts),
+                    datums: len(&wits.plutus_datums),
+                    redeemers: len(&wits.redeemers),
+                    ..Default::default()
+                })
+                .collect(),
+            Self::Babbage(block) => block
+                .transaction_witness_sets
+                .iter()
+                .map(|wits| TransactionWitnessSummary {
+                    vkey_witnesses: len(&wits.vkeywitnesses),
+                    bootstrap_witnesses: len(&wits.bootstrap_witnesses),
+                    native_scripts: len(&wits.native_scripts),
+                    plutus_v1_scripts: len(&wits.plutus_v1_scripts),
+                    plutus_v2_scripts: len(&wits.plutus_v2_scripts),
+                    datums: len(&wits.plutus_datums),
+                    redeemers: len(&wits.redeemers),
+                    ..Default::default()
+                })
+                .collect(),
+            Self::Conway(block) => block
+                .transaction_witness_sets
+                .iter()
+                .map(|wits| TransactionWitnessSummary {
+                    vkey_witnesses: len(&wits.vkeywitnesses),
+                    bootstrap_witnesses: len(&wits.bootstrap_witnesses),
+                    native_scripts: len(&wits.native_scripts),
+                    plutus_v1_scripts: len(&wits.plutus_v1_scripts),
+                    plutus_v2_scripts: len(&wits.plutus_v2_scripts),
+                    plutus_v3_scripts: len(&wits.plutus_v3_scripts),
+                    datums: len(&wits.plutus_datums),
+                    redeemers: wits
+                        .redeemers
+                        .as_ref()
+                        .map(|redeemers| redeemers.len() as u64)
+                        .unwrap_or(0),
+                    ..Default::default()
+                })
+                .collect(),
+        };
+        let invalid_transactions = self.invalid_transactions();
+        BlockWitnessSummary {
+            transactions: transactions
+                .into_iter()
+                .enumerate()
+                .map(|(index, summary)| TransactionWitnessSummary {
+                    index: index as TransactionIndex,
+                    is_invalid: invalid_transactions.contains(&(index as TransactionIndex)),
+                    ..summary
+                })
+                .collect(),
+        }
+    }
+
+    /// Representational variations found in the block that the CDDL allows but that
+    /// are unusual for its era. These never cause deserialization to fail.
+    pub fn warnings(&self) -> Vec<MultiEraBlockWarning> {
+        match self {
+            Self::Conway(block) => block
+                .transaction_witness_sets
+                .iter()
+                .enumerate()
+                .filter_map(|(tx_index, wits)| match wits.redeemers {
+                    Some(Redeemers::ArrLegacyRedeemer { .. }) => {
+                        Some(MultiEraBlockWarning::LegacyRedeemers { tx_index })
+                    }
+                    _ => None,
+                })
+                .collect(),
+            _ => vec![],
+        }
+    }
+
     pub fn hash(&self) -> [u8; 32] {
         let bytes = match self {
             Self::Byron(block) => {
@@ -264,8 +736,16 @@ impl MultiEraBlock {
             Self::Allegra(block) => block.header.to_cbor_bytes(),
             Self::Mary(block) => block.header.to_cbor_bytes(),
             Self::Alonzo(block) => block.header.to_cbor_bytes(),
-            Self::Babbage(block) => block.header.to_cbor_bytes(),
-            Self::Conway(block) => block.header.to_cbor_bytes(),
+            Self::Babbage(block) => block
+                .header
+                .original_bytes()
+                .map(<[u8]>::to_vec)
+                .unwrap_or_else(|| block.header.to_cbor_bytes()),
+            Self::Conway(block) => block
+                .header
+                .original_bytes()
+                .map(<[u8]>::to_vec)
+                .unwrap_or_else(|| block.header.to_cbor_bytes()),
         };
 
         blake2b256(&bytes)
@@ -287,6 +767,128 @@ impl MultiEraBlock {
     }
 }
 
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+)]
+pub enum Era {
+    Byron,
+    Shelley,
+    Allegra,
+    Mary,
+    Alonzo,
+    Babbage,
+    Conway,
+}
+
+/// Returned by MultiEraBlock::transaction_cbor() for an index past the block's transactions
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("transaction index {index} is out of range for a block with {count} transactions")]
+pub struct TransactionIndexOutOfRange {
+    pub index: usize,
+    pub count: usize,
+}
+
+/// Returned when down-converting current (Conway) era types e.g. a transaction body assembled
+/// with the TransactionBuilder to an older era's types which can't represent {field}
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("{field} is not supported in the {era:?} era")]
+pub struct EraDowngradeError {
+    /// The first unsupported field (in CBOR key order) e.g. "reference_inputs" or "outputs[1].datum_option"
+    pub field: String,
+    pub era: Era,
+}
+
+impl EraDowngradeError {
+    pub fn new(field: impl Into<String>, era: Era) -> Self {
+        Self {
+            field: field.into(),
+            era,
+        }
+    }
+}
+
+/// certs of a tx body down-converted for pre-Conway eras
+pub(crate) fn downgrade_certs(
+    certs: Option<NonemptySetCertificate>,
+    era: Era,
+) -> Result<Option<Vec<AllegraCertificate>>, EraDowngradeError> {
+    certs
+        .map(|certs| {
+            Vec::from(certs)
+                .into_iter()
+                .enumerate()
+                .map(|(i, cert)| {
+                    AllegraCertificate::try_from(cert)
+                        .map_err(|_| EraDowngradeError::new(format!("certs[{}]", i), era))
+                })
+                .collect()
+        })
+        .transpose()
+}
+
+/// Errors on the first of the Conway-only (governance) tx body fields that is set
+pub(crate) fn check_no_conway_body_fields(
+    body: &TransactionBody,
+    era: Era,
+) -> Result<(), EraDowngradeError> {
+    let field = if body.voting_procedures.is_some() {
+        "voting_procedures"
+    } else if body.proposal_procedures.is_some() {
+        "proposal_procedures"
+    } else if body.current_treasury_value.is_some() {
+        "current_treasury_value"
+    } else if body.donation.is_some() {
+        "donation"
+    } else {
+        return Ok(());
+    };
+    Err(EraDowngradeError::new(field, era))
+}
+
+/// Witness statistics of a single transaction. See MultiEraBlock::witness_summary()
+#[derive(
+    Clone, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+pub struct TransactionWitnessSummary {
+    /// Index of the transaction within the block
+    pub index: TransactionIndex,
+    pub vkey_witnesses: u64,
+    pub bootstrap_witnesses: u64,
+    pub native_scripts: u64,
+    pub plutus_v1_scripts: u64,
+    pub plutus_v2_scripts: u64,
+    pub plutus_v3_scripts: u64,
+    pub datums: u64,
+    pub redeemers: u64,
+    /// Whether the transaction failed phase-2 validation (see MultiEraBlock::invalid_transactions())
+    pub is_invalid: bool,
+}
+
+/// See MultiEraBlock::witness_summary()
+#[derive(
+    Clone, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+pub struct BlockWitnessSummary {
+    pub transactions: Vec<TransactionWitnessSummary>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MultiEraBlockWarning {
+    /// A Conway witness set used the pre-Conway redeemer array format
+    /// instead of the map format
+    LegacyRedeemers { tx_index: usize },
+}
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
 pub enum MultiEraBlockHeader {
     ByronEB(EbbHead),
@@ -296,6 +898,23 @@ pub enum MultiEraBlockHeader {
 }
 
 impl MultiEraBlockHeader {
+    /// The first era that used this header format.
+    /// Headers are shared between eras (Shelley through Alonzo, Babbage and Conway)
+    /// so use MultiEraBlock::era() when the exact era is needed.
+    pub fn era(&self) -> Era {
+        match self {
+            Self::ByronEB(_) => Era::Byron,
+            Self::Byron(_) => Era::Byron,
+            Self::Shelley(_) => Era::Shelley,
+            Self::Babbage(_) => Era::Babbage,
+        }
+    }
+
+    /// Whether this is the header of a Byron epoch boundary block (EBB)
+    pub fn is_epoch_boundary(&self) -> bool {
+        matches!(self, Self::ByronEB(_))
+    }
+
     pub fn block_number(&self) -> u64 {
         match self {
             Self::ByronEB(eb) => eb.consensus_data.byron_difficulty.u64,
@@ -348,6 +967,19 @@ impl MultiEraBlockHeader {
         }
     }
 
+    /// Hash of the block producer's cold key (issuer_vkey) i.e. the pool id of the pool that made
+    /// this block. None for Byron blocks.
+    pub fn issuer_key_hash(&self) -> Option<Ed25519KeyHash> {
+        self.issuer_vkey().map(|vkey| vkey.hash())
+    }
+
+    /// Hash of the block producer's VRF key as registered in its pool params (vrf_keyhash).
+    /// None for Byron blocks.
+    pub fn vrf_key_hash(&self) -> Option<VRFKeyHash> {
+        self.vrf_vkey()
+            .map(|vkey| VRFKeyHash::from(blake2b256(vkey.to_raw_bytes())))
+    }
+
     pub fn nonce_vrf(&self) -> Option<&VRFCert> {
         match self {
             Self::ByronEB(_) => None,
@@ -368,13 +1000,26 @@ impl MultiEraBlockHeader {
 
     pub fn vrf_result(&self) -> Option<&VRFCert> {
         match self {
-            Self::ByronEB(_) => todo!(),
-            Self::Byron(_) => todo!(),
+            Self::ByronEB(_) => None,
+            Self::Byron(_) => None,
             Self::Shelley(_header) => None,
             Self::Babbage(header) => Some(&header.header_body.vrf_result),
         }
     }
 
+    /// This block's contribution to the evolving epoch nonce (see cml_chain::crypto::utils::evolve_nonce)
+    /// * Byron - None as Ouroboros Classic did not use VRF-based nonces
+    /// * Shelley through Alonzo (TPraos) - blake2b256 of the dedicated nonce_vrf output
+    /// * Babbage onwards (Praos) - derived from the "N" domain-separated vrf_result output
+    pub fn vrf_output_to_nonce(&self) -> Option<NonceHash> {
+        match self {
+            Self::ByronEB(_) => None,
+            Self::Byron(_) => None,
+            Self::Shelley(header) => Some(header.body.vrf_output_to_nonce()),
+            Self::Babbage(header) => Some(header.header_body.vrf_output_to_nonce()),
+        }
+    }
+
     pub fn block_body_size(&self) -> Option<u64> {
         match self {
             Self::ByronEB(_) => None,
@@ -498,6 +1143,51 @@ impl MultiEraTransactionBody {
         }
     }
 
+    /// Borrowing version of inputs() that doesn't clone anything
+    pub fn iter_inputs(&self) -> impl Iterator<Item = MultiEraTransactionInputRef<'_>> {
+        let (byron, shelley): (&[ByronTxIn], &[TransactionInput]) = match self {
+            Self::Byron(tx) => (&tx.inputs, &[]),
+            Self::Shelley(tx) => (&[], &tx.inputs),
+            Self::Allegra(tx) => (&[], &tx.inputs),
+            Self::Mary(tx) => (&[], &tx.inputs),
+            Self::Alonzo(tx) => (&[], &tx.inputs),
+            Self::Babbage(tx) => (&[], &tx.inputs),
+            Self::Conway(tx) => (&[], &tx.inputs),
+        };
+        byron
+            .iter()
+            .map(MultiEraTransactionInputRef::Byron)
+            .chain(shelley.iter().map(MultiEraTransactionInputRef::Shelley))
+    }
+
+    /// Borrowing version of outputs() that doesn't clone anything.
+    /// Outputs are kept in their era's format (see MultiEraTransactionOutputRef)
+    pub fn iter_outputs(&self) -> impl Iterator<Item = MultiEraTransactionOutputRef<'_>> {
+        let mut byron: &[ByronTxOut] = &[];
+        let mut shelley: &[ShelleyTransactionOutput] = &[];
+        let mut mary: &[MaryTransactionOutput] = &[];
+        let mut alonzo: &[AlonzoFormatTxOut] = &[];
+        let mut babbage: &[BabbageTransactionOutput] = &[];
+        let mut conway: &[TransactionOutput] = &[];
+        match self {
+            Self::Byron(tx) => byron = &tx.outputs,
+            Self::Shelley(tx) => shelley = &tx.outputs,
+            Self::Allegra(tx) => shelley = &tx.outputs,
+            Self::Mary(tx) => mary = &tx.outputs,
+            Self::Alonzo(tx) => alonzo = &tx.outputs,
+            Self::Babbage(tx) => babbage = &tx.outputs,
+            Self::Conway(tx) => conway = &tx.outputs,
+        }
+        byron
+            .iter()
+            .map(MultiEraTransactionOutputRef::Byron)
+            .chain(shelley.iter().map(MultiEraTransactionOutputRef::Shelley))
+            .chain(mary.iter().map(MultiEraTransactionOutputRef::Mary))
+            .chain(alonzo.iter().map(MultiEraTransactionOutputRef::Alonzo))
+            .chain(babbage.iter().map(MultiEraTransactionOutputRef::Babbage))
+            .chain(conway.iter().map(MultiEraTransactionOutputRef::Conway))
+    }
+
     pub fn fee(&self) -> Option<Coin> {
         match self {
             Self::Byron(_tx) => None,
@@ -564,6 +1254,41 @@ impl MultiEraTransactionBody {
         }
     }
 
+    /// Borrowing version of certs() that doesn't clone anything. Empty if there are no certs.
+    pub fn iter_certs(&self) -> impl Iterator<Item = MultiEraCertRef<'_>> {
+        let mut shelley: &[ShelleyCertificate] = &[];
+        let mut allegra: &[AllegraCertificate] = &[];
+        let mut conway: &[Certificate] = &[];
+        match self {
+            Self::Byron(_tx) => (),
+            Self::Shelley(tx) => shelley = tx.certs.as_deref().unwrap_or_default(),
+            Self::Allegra(tx) => allegra = tx.certs.as_deref().unwrap_or_default(),
+            Self::Mary(tx) => allegra = tx.certs.as_deref().unwrap_or_default(),
+            Self::Alonzo(tx) => allegra = tx.certs.as_deref().unwrap_or_default(),
+            Self::Babbage(tx) => allegra = tx.certs.as_deref().unwrap_or_default(),
+            Self::Conway(tx) => {
+                if let Some(certs) = &tx.certs {
+                    conway = certs
+                }
+            }
+        }
+        shelley
+            .iter()
+            .map(MultiEraCertRef::Shelley)
+            .chain(allegra.iter().map(MultiEraCertRef::Allegra))
+            .chain(conway.iter().map(MultiEraCertRef::Conway))
+    }
+
+    /// The stake registration/delegation effects of this tx's certificates, in certificate order.
+    /// Certificates that don't affect stake delegation (e.g. pool or DRep registrations) are skipped.
+    pub fn delegation_changes(&self) -> Vec<DelegationChange> {
+        self.certs()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(MultiEraCertificate::delegation_change)
+            .collect()
+    }
+
     pub fn withdrawals(&self) -> Option<&Withdrawals> {
         match self {
             Self::Byron(_tx) => None,
@@ -579,46 +1304,11 @@ impl MultiEraTransactionBody {
     pub fn update(&self) -> Option<MultiEraUpdate> {
         match self {
             Self::Byron(_tx) => None,
-            Self::Shelley(tx) => tx.update.as_ref().map(|u| MultiEraUpdate {
-                epoch: u.epoch,
-                proposed_protocol_parameter_updates: u
-                    .shelley_proposed_protocol_parameter_updates
-                    .iter()
-                    .map(|(gh, ppu)| (*gh, MultiEraProtocolParamUpdate::Shelley(ppu.clone())))
-                    .collect(),
-            }),
-            Self::Allegra(tx) => tx.update.as_ref().map(|u| MultiEraUpdate {
-                epoch: u.epoch,
-                proposed_protocol_parameter_updates: u
-                    .shelley_proposed_protocol_parameter_updates
-                    .iter()
-                    .map(|(gh, ppu)| (*gh, MultiEraProtocolParamUpdate::Shelley(ppu.clone())))
-                    .collect(),
-            }),
-            Self::Mary(tx) => tx.update.as_ref().map(|u| MultiEraUpdate {
-                epoch: u.epoch,
-                proposed_protocol_parameter_updates: u
-                    .shelley_proposed_protocol_parameter_updates
-                    .iter()
-                    .map(|(gh, ppu)| (*gh, MultiEraProtocolParamUpdate::Shelley(ppu.clone())))
-                    .collect(),
-            }),
-            Self::Alonzo(tx) => tx.update.as_ref().map(|u| MultiEraUpdate {
-                epoch: u.epoch,
-                proposed_protocol_parameter_updates: u
-                    .proposed_protocol_parameter_updates
-                    .iter()
-                    .map(|(gh, ppu)| (*gh, MultiEraProtocolParamUpdate::Alonzo(ppu.clone())))
-                    .collect(),
-            }),
-            Self::Babbage(tx) => tx.update.as_ref().map(|u| MultiEraUpdate {
-                epoch: u.epoch,
-                proposed_protocol_parameter_updates: u
-                    .updates
-                    .iter()
-                    .map(|(gh, ppu)| (*gh, MultiEraProtocolParamUpdate::Babbage(ppu.clone())))
-                    .collect(),
-            }),
+            Self::Shelley(tx) => tx.update.as_ref().map(MultiEraUpdate::from),
+            Self::Allegra(tx) => tx.update.as_ref().map(MultiEraUpdate::from),
+            Self::Mary(tx) => tx.update.as_ref().map(MultiEraUpdate::from),
+            Self::Alonzo(tx) => tx.update.as_ref().map(MultiEraUpdate::from),
+            Self::Babbage(tx) => tx.update.as_ref().map(MultiEraUpdate::from),
             Self::Conway(_tx) => None,
         }
     }
@@ -791,6 +1481,22 @@ impl MultiEraTransactionBody {
         }
     }
 
+    /// The exact bytes this body was deserialized from if they were kept.
+    /// Bodies parsed as part of a block only keep them (except for Byron) when the block was
+    /// parsed with DeserializeKeepOriginal or NetworkBlockOptions::keep_original_bytes().
+    /// See OriginalBytes.
+    pub fn original_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::Byron(_tx) => None,
+            Self::Shelley(tx) => tx.original_bytes(),
+            Self::Allegra(tx) => tx.original_bytes(),
+            Self::Mary(tx) => tx.original_bytes(),
+            Self::Alonzo(tx) => tx.original_bytes(),
+            Self::Babbage(tx) => tx.original_bytes(),
+            Self::Conway(tx) => tx.original_bytes(),
+        }
+    }
+
     pub fn hash(&self) -> TransactionHash {
         match self {
             MultiEraTransactionBody::Byron(tx) => tx.hash(),
@@ -828,6 +1534,110 @@ pub enum MultiEraCertificate {
     UpdateDrepCert(UpdateDrepCert),
 }
 
+impl MultiEraCertificate {
+    /// The effect this certificate has on its stake credential's registration/delegation.
+    /// None for certificates that don't involve a stake credential's delegation.
+    pub fn delegation_change(&self) -> Option<DelegationChange> {
+        let (credential, kind, pool, drep) = match self {
+            Self::StakeRegistration(cert) => (
+                &cert.stake_credential,
+                DelegationChangeKind::Register,
+                None,
+                None,
+            ),
+            Self::RegCert(cert) => (
+                &cert.stake_credential,
+                DelegationChangeKind::Register,
+                None,
+                None,
+            ),
+            Self::StakeDeregistration(cert) => (
+                &cert.stake_credential,
+                DelegationChangeKind::Deregister,
+                None,
+                None,
+            ),
+            Self::UnregCert(cert) => (
+                &cert.stake_credential,
+                DelegationChangeKind::Deregister,
+                None,
+                None,
+            ),
+            Self::StakeDelegation(cert) => (
+                &cert.stake_credential,
+                DelegationChangeKind::Delegate,
+                Some(cert.pool),
+                None,
+            ),
+            Self::VoteDelegCert(cert) => (
+                &cert.stake_credential,
+                DelegationChangeKind::Delegate,
+                None,
+                Some(&cert.d_rep),
+            ),
+            Self::StakeVoteDelegCert(cert) => (
+                &cert.stake_credential,
+                DelegationChangeKind::Delegate,
+                Some(cert.pool),
+                Some(&cert.d_rep),
+            ),
+            Self::StakeRegDelegCert(cert) => (
+                &cert.stake_credential,
+                DelegationChangeKind::Register,
+                Some(cert.pool),
+                None,
+            ),
+            Self::VoteRegDelegCert(cert) => (
+                &cert.stake_credential,
+                DelegationChangeKind::Register,
+                None,
+                Some(&cert.d_rep),
+            ),
+            Self::StakeVoteRegDelegCert(cert) => (
+                &cert.stake_credential,
+                DelegationChangeKind::Register,
+                Some(cert.pool),
+                Some(&cert.d_rep),
+            ),
+            Self::PoolRegistration(_)
+            | Self::PoolRetirement(_)
+            | Self::GenesisKeyDelegation(_)
+            | Self::MoveInstantaneousRewardsCert(_)
+            | Self::AuthCommitteeHotCert(_)
+            | Self::ResignCommitteeColdCert(_)
+            | Self::RegDrepCert(_)
+            | Self::UnregDrepCert(_)
+            | Self::UpdateDrepCert(_) => return None,
+        };
+        Some(DelegationChange {
+            credential: credential.clone(),
+            kind,
+            pool,
+            drep: drep.cloned(),
+        })
+    }
+}
+
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+pub enum DelegationChangeKind {
+    Register,
+    Deregister,
+    Delegate,
+}
+
+/// Summary of a single certificate's effect on a stake credential.
+/// The combined Conway registration certs (e.g. StakeVoteRegDelegCert) are a single
+/// Register entry that also has the pool and/or drep delegated to set.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+pub struct DelegationChange {
+    pub credential: StakeCredential,
+    pub kind: DelegationChangeKind,
+    pub pool: Option<Ed25519KeyHash>,
+    pub drep: Option<DRep>,
+}
+
 impl From<ShelleyCertificate> for MultiEraCertificate {
     fn from(cert: ShelleyCertificate) -> Self {
         match cert {
@@ -840,18 +1650,47 @@ impl From<ShelleyCertificate> for MultiEraCertificate {
             ShelleyCertificate::PoolRetirement(cert) => Self::PoolRetirement(cert),
             ShelleyCertificate::GenesisKeyDelegation(cert) => Self::GenesisKeyDelegation(cert),
             ShelleyCertificate::ShelleyMoveInstantaneousRewardsCert(cert) => {
-                Self::MoveInstantaneousRewardsCert(MoveInstantaneousRewardsCert::new(
-                    MoveInstantaneousReward::new(
-                        cert.shelley_move_instantaneous_reward.pot,
-                        MIRAction::new_to_stake_credentials(
-                            cert.shelley_move_instantaneous_reward
-                                .to_stake_credentials
-                                .iter()
-                                .map(|(k, v)| (k.clone(), Int::from(*v)))
-                                .collect(),
-                        ),
-                    ),
-                ))
+                // Shelley MIRs can only pay to stake credentials (no pot transfers).
+                // Encodings are kept so the converted cert serializes to the same bytes.
+                let mir = cert.shelley_move_instantaneous_reward;
+                let mir_encodings = mir.encodings.unwrap_or_default();
+                let to_stake_credentials = mir
+                    .to_stake_credentials
+                    .iter()
+                    .map(|(cred, coin)| {
+                        let encoding = mir_encodings
+                            .to_stake_credentials_value_encodings
+                            .get(cred)
+                            .cloned()
+                            .flatten();
+                        (
+                            cred.clone(),
+                            Int::Uint {
+                                value: *coin,
+                                encoding,
+                            },
+                        )
+                    })
+                    .collect();
+                let mut move_instantaneous_reward = MoveInstantaneousReward::new(
+                    mir.pot,
+                    MIRAction::ToStakeCredentials {
+                        to_stake_credentials,
+                        to_stake_credentials_encoding: mir_encodings.to_stake_credentials_encoding,
+                    },
+                );
+                move_instantaneous_reward.encodings = Some(MoveInstantaneousRewardEncoding {
+                    len_encoding: mir_encodings.len_encoding,
+                    pot_encoding: mir_encodings.pot_encoding,
+                });
+                let mut mir_cert = MoveInstantaneousRewardsCert::new(move_instantaneous_reward);
+                mir_cert.encodings =
+                    cert.encodings
+                        .map(|encs| MoveInstantaneousRewardsCertEncoding {
+                            len_encoding: encs.len_encoding,
+                            tag_encoding: encs.tag_encoding,
+                        });
+                Self::MoveInstantaneousRewardsCert(mir_cert)
             }
         }
     }
@@ -1211,24 +2050,187 @@ impl MultiEraProtocolParamUpdate {
             Self::Conway(update) => update.d_rep_inactivity_period,
         }
     }
+
+    pub fn min_fee_ref_script_cost_per_byte(&self) -> Option<&Rational> {
+        match self {
+            Self::Shelley(_update) => None,
+            Self::Alonzo(_update) => None,
+            Self::Babbage(_update) => None,
+            Self::Conway(update) => update.min_fee_ref_script_cost_per_byte.as_ref(),
+        }
+    }
+
+    /// The first era this update format was used in (e.g. Shelley also covers Allegra/Mary)
+    pub fn era(&self) -> Era {
+        match self {
+            Self::Shelley(_) => Era::Shelley,
+            Self::Alonzo(_) => Era::Alonzo,
+            Self::Babbage(_) => Era::Babbage,
+            Self::Conway(_) => Era::Conway,
+        }
+    }
+
+    /// JSON object with every protocol parameter known up to Conway (named as the accessors here)
+    /// plus an "era" field. Parameters that aren't updated, or don't exist in this update's era,
+    /// are null so the shape is the same regardless of era.
+    pub fn to_unified_json(&self) -> Result<serde_json::Value, serde_json::Error> {
+        fn field<T: serde::Serialize>(
+            value: Option<T>,
+        ) -> Result<serde_json::Value, serde_json::Error> {
+            value
+                .map(serde_json::to_value)
+                .transpose()
+                .map(Option::unwrap_or_default)
+        }
+        let mut json = serde_json::Map::new();
+        json.insert("era".to_owned(), serde_json::to_value(self.era())?);
+        json.insert("minfee_a".to_owned(), field(self.minfee_a())?);
+        json.insert("minfee_b".to_owned(), field(self.minfee_b())?);
+        json.insert(
+            "max_block_body_size".to_owned(),
+            field(self.max_block_body_size())?,
+        );
+        json.insert(
+            "max_transaction_size".to_owned(),
+            field(self.max_transaction_size())?,
+        );
+        json.insert(
+            "max_block_header_size".to_owned(),
+            field(self.max_block_header_size())?,
+        );
+        json.insert("key_deposit".to_owned(), field(self.key_deposit())?);
+        json.insert("pool_deposit".to_owned(), field(self.pool_deposit())?);
+        json.insert("maximum_epoch".to_owned(), field(self.maximum_epoch())?);
+        json.insert("n_opt".to_owned(), field(self.n_opt())?);
+        json.insert(
+            "pool_pledge_influence".to_owned(),
+            field(self.pool_pledge_influence())?,
+        );
+        json.insert("expansion_rate".to_owned(), field(self.expansion_rate())?);
+        json.insert(
+            "treasury_growth_rate".to_owned(),
+            field(self.treasury_growth_rate())?,
+        );
+        json.insert(
+            "decentralization_constant".to_owned(),
+            field(self.decentralization_constant())?,
+        );
+        json.insert("extra_entropy".to_owned(), field(self.extra_entropy())?);
+        json.insert(
+            "protocol_version".to_owned(),
+            field(self.protocol_version())?,
+        );
+        json.insert("min_utxo_value".to_owned(), field(self.min_utxo_value())?);
+        json.insert("min_pool_cost".to_owned(), field(self.min_pool_cost())?);
+        json.insert(
+            "ada_per_utxo_byte".to_owned(),
+            field(self.ada_per_utxo_byte())?,
+        );
+        json.insert(
+            "cost_models_for_script_languages".to_owned(),
+            field(self.cost_models_for_script_languages())?,
+        );
+        json.insert("execution_costs".to_owned(), field(self.execution_costs())?);
+        json.insert("max_tx_ex_units".to_owned(), field(self.max_tx_ex_units())?);
+        json.insert(
+            "max_block_ex_units".to_owned(),
+            field(self.max_block_ex_units())?,
+        );
+        json.insert("max_value_size".to_owned(), field(self.max_value_size())?);
+        json.insert(
+            "collateral_percentage".to_owned(),
+            field(self.collateral_percentage())?,
+        );
+        json.insert(
+            "max_collateral_inputs".to_owned(),
+            field(self.max_collateral_inputs())?,
+        );
+        json.insert(
+            "pool_voting_thresholds".to_owned(),
+            field(self.pool_voting_thresholds())?,
+        );
+        json.insert(
+            "d_rep_voting_thresholds".to_owned(),
+            field(self.d_rep_voting_thresholds())?,
+        );
+        json.insert(
+            "min_committee_size".to_owned(),
+            field(self.min_committee_size())?,
+        );
+        json.insert(
+            "committee_term_limit".to_owned(),
+            field(self.committee_term_limit())?,
+        );
+        json.insert(
+            "governance_action_validity_period".to_owned(),
+            field(self.governance_action_validity_period())?,
+        );
+        json.insert(
+            "governance_action_deposit".to_owned(),
+            field(self.governance_action_deposit())?,
+        );
+        json.insert("d_rep_deposit".to_owned(), field(self.d_rep_deposit())?);
+        json.insert(
+            "d_rep_inactivity_period".to_owned(),
+            field(self.d_rep_inactivity_period())?,
+        );
+        json.insert(
+            "min_fee_ref_script_cost_per_byte".to_owned(),
+            field(self.min_fee_ref_script_cost_per_byte())?,
+        );
+        Ok(serde_json::Value::Object(json))
+    }
+
+    /// The fields of to_unified_json() (including "era") whose value differs in {other},
+    /// mapped to {other}'s value (null if {other} doesn't update it).
+    pub fn diff(&self, other: &Self) -> Result<serde_json::Value, serde_json::Error> {
+        let before = self.to_unified_json()?;
+        let after = other.to_unified_json()?;
+        let changed = match (before, after) {
+            (serde_json::Value::Object(before), serde_json::Value::Object(after)) => after
+                .into_iter()
+                .filter(|(key, value)| before.get(key) != Some(value))
+                .collect(),
+            _ => unreachable!("to_unified_json() always returns an object"),
+        };
+        Ok(serde_json::Value::Object(changed))
+    }
+}
+
+/// Borrowed certificate in its era's format. See MultiEraTransactionBody::iter_certs()
+#[derive(Clone, Copy, Debug)]
+pub enum MultiEraCertRef<'a> {
+    Shelley(&'a ShelleyCertificate),
+    /// Allegra through Babbage
+    Allegra(&'a AllegraCertificate),
+    Conway(&'a Certificate),
+}
+
+impl<'a> MultiEraCertRef<'a> {
+    pub fn cloned(&self) -> MultiEraCertificate {
+        match self {
+            Self::Shelley(cert) => MultiEraCertificate::from((*cert).clone()),
+            Self::Allegra(cert) => MultiEraCertificate::from((*cert).clone()),
+            Self::Conway(cert) => MultiEraCertificate::from((*cert).clone()),
+        }
+    }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
-pub enum MultiEraTransactionInput {
-    Byron(ByronTxIn),
+/// Borrowed transaction input. See MultiEraTransactionBody::iter_inputs()
+#[derive(Clone, Copy, Debug)]
+pub enum MultiEraTransactionInputRef<'a> {
+    Byron(&'a ByronTxIn),
     /// All eras from Shelley onward have the same tx in format
-    Shelley(TransactionInput),
+    Shelley(&'a TransactionInput),
 }
 
-impl MultiEraTransactionInput {
+impl<'a> MultiEraTransactionInputRef<'a> {
     /// Transaction hash this input was created in
     /// Will return None only for Byron Genesis inputs
-    pub fn hash(&self) -> Option<&TransactionHash> {
+    pub fn hash(&self) -> Option<&'a TransactionHash> {
         match self {
-            Self::Byron(input) => match input {
-                ByronTxIn::ByronTxInRegular(reg) => Some(&reg.index_1.byron_tx_id),
-                ByronTxIn::ByronTxInGenesis(_gen) => None,
-            },
+            Self::Byron(ByronTxIn::ByronTxInRegular(reg)) => Some(&reg.index_1.byron_tx_id),
+            Self::Byron(ByronTxIn::ByronTxInGenesis(_gen)) => None,
             Self::Shelley(input) => Some(&input.transaction_id),
         }
     }
@@ -1237,7 +2239,117 @@ impl MultiEraTransactionInput {
     /// Will return None for only Byron Genesis inputs
     pub fn index(&self) -> Option<u64> {
         match self {
-            Self::Byron(input) => match input {
+            Self::Byron(ByronTxIn::ByronTxInRegular(reg)) => Some(reg.index_1.u32.into()),
+            Self::Byron(ByronTxIn::ByronTxInGenesis(_gen)) => None,
+            Self::Shelley(input) => Some(input.index),
+        }
+    }
+
+    pub fn cloned(&self) -> MultiEraTransactionInput {
+        match self {
+            Self::Byron(input) => MultiEraTransactionInput::Byron((*input).clone()),
+            Self::Shelley(input) => MultiEraTransactionInput::Shelley((*input).clone()),
+        }
+    }
+}
+
+/// Borrowed transaction output in its era's format. See MultiEraTransactionBody::iter_outputs()
+#[derive(Clone, Copy, Debug)]
+pub enum MultiEraTransactionOutputRef<'a> {
+    Byron(&'a ByronTxOut),
+    /// Shelley and Allegra
+    Shelley(&'a ShelleyTransactionOutput),
+    Mary(&'a MaryTransactionOutput),
+    Alonzo(&'a AlonzoFormatTxOut),
+    Babbage(&'a BabbageTransactionOutput),
+    Conway(&'a TransactionOutput),
+}
+
+impl<'a> MultiEraTransactionOutputRef<'a> {
+    /// Only Byron addresses need to be converted (and thus allocated)
+    pub fn address(&self) -> Cow<'a, Address> {
+        match self {
+            Self::Byron(output) => Cow::Owned(output.address.clone().to_address()),
+            Self::Shelley(output) => Cow::Borrowed(&output.address),
+            Self::Mary(output) => Cow::Borrowed(&output.address),
+            Self::Alonzo(output) => Cow::Borrowed(&output.address),
+            Self::Babbage(output) => Cow::Borrowed(output.address()),
+            Self::Conway(output) => Cow::Borrowed(output.address()),
+        }
+    }
+
+    /// ADA amount (excluding any tokens)
+    pub fn coin(&self) -> Coin {
+        match self {
+            Self::Byron(output) => output.amount,
+            Self::Shelley(output) => output.amount,
+            Self::Mary(output) => output.amount.coin,
+            Self::Alonzo(output) => output.amount.coin,
+            Self::Babbage(output) => output.amount().coin,
+            Self::Conway(output) => output.amount().coin,
+        }
+    }
+
+    /// Pre-Mary outputs only contain ADA so their Value is created on the fly
+    pub fn amount(&self) -> Cow<'a, Value> {
+        match self {
+            Self::Byron(output) => Cow::Owned(output.amount.into()),
+            Self::Shelley(output) => Cow::Owned(output.amount.into()),
+            Self::Mary(output) => Cow::Borrowed(&output.amount),
+            Self::Alonzo(output) => Cow::Borrowed(&output.amount),
+            Self::Babbage(output) => Cow::Borrowed(output.amount()),
+            Self::Conway(output) => Cow::Borrowed(output.amount()),
+        }
+    }
+
+    pub fn cloned(&self) -> MultiEraTransactionOutput {
+        match self {
+            Self::Byron(output) => MultiEraTransactionOutput::Byron((*output).clone()),
+            Self::Shelley(output) => (*output).clone().into(),
+            Self::Mary(output) => (*output).clone().into(),
+            Self::Alonzo(output) => (*output).clone().into(),
+            Self::Babbage(output) => (*output).clone().into(),
+            Self::Conway(output) => (*output).clone().into(),
+        }
+    }
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+)]
+pub enum MultiEraTransactionInput {
+    Byron(ByronTxIn),
+    /// All eras from Shelley onward have the same tx in format
+    Shelley(TransactionInput),
+}
+
+impl MultiEraTransactionInput {
+    /// Transaction hash this input was created in
+    /// Will return None only for Byron Genesis inputs
+    pub fn hash(&self) -> Option<&TransactionHash> {
+        match self {
+            Self::Byron(input) => match input {
+                ByronTxIn::ByronTxInRegular(reg) => Some(&reg.index_1.byron_tx_id),
+                ByronTxIn::ByronTxInGenesis(_gen) => None,
+            },
+            Self::Shelley(input) => Some(&input.transaction_id),
+        }
+    }
+
+    /// Transaction index into the tx that this input was created in
+    /// Will return None for only Byron Genesis inputs
+    pub fn index(&self) -> Option<u64> {
+        match self {
+            Self::Byron(input) => match input {
                 ByronTxIn::ByronTxInRegular(reg) => Some(reg.index_1.u32.into()),
                 ByronTxIn::ByronTxInGenesis(_gen) => None,
             },
@@ -1268,13 +2380,117 @@ impl MultiEraTransactionOutput {
     }
 }
 
-#[derive(Debug, Clone)]
+impl From<MultiEraTransactionOutput> for TransactionOutput {
+    fn from(output: MultiEraTransactionOutput) -> Self {
+        match output {
+            MultiEraTransactionOutput::Byron(output) => TransactionOutput::new(
+                output.address.to_address(),
+                output.amount.into(),
+                None,
+                None,
+            ),
+            MultiEraTransactionOutput::Shelley(output) => output,
+        }
+    }
+}
+
+/// A protocol update payload from any pre-Conway era. See MultiEraBlock::update_payloads()
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+pub enum MultiEraUpdatePayload {
+    Byron(ByronUpdate),
+    Shelley(MultiEraUpdate),
+}
+
+/// Protocol parameter update proposal (Shelley to Babbage).
+/// Proposals are kept in the order they appeared on-chain, except when created from JSON
+/// in which case (as with all JSON maps) they are ordered by genesis hash.
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
 pub struct MultiEraUpdate {
     pub epoch: u64,
     pub proposed_protocol_parameter_updates:
         OrderedHashMap<GenesisHash, MultiEraProtocolParamUpdate>,
 }
 
+impl MultiEraUpdate {
+    /// Encodes as the era-specific update structure that the proposals are from.
+    /// Returns None if this is ambiguous i.e. the proposals are from different eras (or Conway)
+    /// The encoding details of the individual proposals are kept but the outer structure is canonical.
+    pub fn to_cbor_bytes(&self) -> Option<Vec<u8>> {
+        match self.proposed_protocol_parameter_updates.values().next() {
+            // all eras encode an empty update the same
+            None | Some(MultiEraProtocolParamUpdate::Shelley(_)) => self
+                .era_updates(|update| match update {
+                    MultiEraProtocolParamUpdate::Shelley(update) => Some(update),
+                    _ => None,
+                })
+                .map(|updates| ShelleyUpdate::new(updates, self.epoch).to_cbor_bytes()),
+            Some(MultiEraProtocolParamUpdate::Alonzo(_)) => self
+                .era_updates(|update| match update {
+                    MultiEraProtocolParamUpdate::Alonzo(update) => Some(update),
+                    _ => None,
+                })
+                .map(|updates| AlonzoUpdate::new(updates, self.epoch).to_cbor_bytes()),
+            Some(MultiEraProtocolParamUpdate::Babbage(_)) => self
+                .era_updates(|update| match update {
+                    MultiEraProtocolParamUpdate::Babbage(update) => Some(update),
+                    _ => None,
+                })
+                .map(|updates| BabbageUpdate::new(updates, self.epoch).to_cbor_bytes()),
+            Some(MultiEraProtocolParamUpdate::Conway(_)) => None,
+        }
+    }
+
+    fn era_updates<T: Clone>(
+        &self,
+        era_update: impl Fn(&MultiEraProtocolParamUpdate) -> Option<&T>,
+    ) -> Option<OrderedHashMap<GenesisHash, T>> {
+        self.proposed_protocol_parameter_updates
+            .iter()
+            .map(|(genesis_hash, update)| era_update(update).map(|u| (*genesis_hash, u.clone())))
+            .collect()
+    }
+}
+
+impl From<&ShelleyUpdate> for MultiEraUpdate {
+    fn from(update: &ShelleyUpdate) -> Self {
+        Self {
+            epoch: update.epoch,
+            proposed_protocol_parameter_updates: update
+                .shelley_proposed_protocol_parameter_updates
+                .iter()
+                .map(|(gh, ppu)| (*gh, MultiEraProtocolParamUpdate::Shelley(ppu.clone())))
+                .collect(),
+        }
+    }
+}
+
+impl From<&AlonzoUpdate> for MultiEraUpdate {
+    fn from(update: &AlonzoUpdate) -> Self {
+        Self {
+            epoch: update.epoch,
+            proposed_protocol_parameter_updates: update
+                .proposed_protocol_parameter_updates
+                .iter()
+                .map(|(gh, ppu)| (*gh, MultiEraProtocolParamUpdate::Alonzo(ppu.clone())))
+                .collect(),
+        }
+    }
+}
+
+impl From<&BabbageUpdate> for MultiEraUpdate {
+    fn from(update: &BabbageUpdate) -> Self {
+        Self {
+            epoch: update.epoch,
+            proposed_protocol_parameter_updates: update
+                .updates
+                .iter()
+                .map(|(gh, ppu)| (*gh, MultiEraProtocolParamUpdate::Babbage(ppu.clone())))
+                .collect(),
+        }
+    }
+}
+
 impl From<ShelleyTransactionOutput> for MultiEraTransactionOutput {
     fn from(o: ShelleyTransactionOutput) -> Self {
         MultiEraTransactionOutput::Shelley(TransactionOutput::new(
@@ -1305,17 +2521,7 @@ impl From<AlonzoFormatTxOut> for MultiEraTransactionOutput {
 
 impl From<BabbageTransactionOutput> for MultiEraTransactionOutput {
     fn from(o: BabbageTransactionOutput) -> Self {
-        MultiEraTransactionOutput::Shelley(match o {
-            BabbageTransactionOutput::AlonzoFormatTxOut(alonzo) => {
-                TransactionOutput::AlonzoFormatTxOut(alonzo.clone())
-            }
-            BabbageTransactionOutput::BabbageFormatTxOut(babbage) => TransactionOutput::new(
-                babbage.address.clone(),
-                babbage.amount.clone(),
-                babbage.datum_option.clone(),
-                babbage.script_reference.clone().map(Into::into),
-            ),
-        })
+        MultiEraTransactionOutput::Shelley(o.into())
     }
 }
 
@@ -1333,8 +2539,10 @@ fn byron_epoch_slot_to_absolute(epoch: u64, sub_epoch_slot: u64) -> u64 {
 }
 
 #[cfg(test)]
-mod test {
+pub(crate) mod test {
     use super::*;
+    use cml_chain::plutus::{LegacyRedeemer, PlutusData, RedeemerTag};
+    use cml_core::serialization::DeserializeLimits;
 
     #[test]
     fn byron_network_block_parse() {
@@ -1342,21 +2550,70 @@ mod test {
         let _block = MultiEraBlock::from_explicit_network_cbor_bytes(&bytes).unwrap();
     }
 
+    // shelley block in the network format
+    pub(crate) const SHELLEY_NETWORK_BLOCK: &str = "820284828f182e1a00015180582045899e8002b27df291e09188bfe3aeb5397ac03546a7d0ead93aa2500860f1af5820d1a8de6caa8fd9b175c59862ecdd5abcd0477b84b82a0e52faecc6b3c85100a4582051995f616f8a025f974b20330a53c0c81e8ea95973d73d15fff7bab57589311d8258406ef0be9293f5a307037b60b7252eae99bb045d58a521d5ba7c8d3aa129292a012a9391eefff8843f3558b35265ec432501b24688a2e0a21964fc71cf69c25dae58503d43c14357cd83b0f941f8c7e7a573fc361b8702bfee211bd7adb1a59f9576fe1bd11e3fa32b5a982e7b246e18058b099f147362c9c18acc43e1e4e8b5b7c660ea3ed9ac5b7a7ec0feb59ccfb64729048258407b4043c26958ea012568b14b0b95ae50d0bd589ae50d58ca5a7190375854084292f87b7f8878887269aaa59251d02032bf1bef5f5fe8fdafd1217164f0d77d885850f68678b7d56dc4178307ba00737cb3339c41f0bf77a894d2156c6cb4ce2047597b2b23dce04a7e1b9abd07a1b870bcf9b18aee0ce27d1fc3c4f2f73cf91f0ca3167e3f67dbe90838da2e8bf15234cb060358201033376be025cb705fd8dd02eda11cc73975a062b5d14ffd74d6ff69e69a2ff758202b9a5add912f3edc5c325d6250b9cc154de8f35e2924f5b1c707a4123808d064000058407fb060b885ffc7b55bb6e095ea6999eaa5608b6b4e92f5cc64bc34ba85000ebe42839432f15d86df07740374f69ce60f72feb16a0d47f5cf2c43158caf37ad0303005901c0f1f0c338a257b27351cf337c4c56961e67235b93cfa351f9d5f348911c7870cb2e084ff7c1f3d93719245fdbba539165d8eea9a510251e5cc3210b3bae3bb40034d42d5ecf181026f436173ad3036d5be2ba595f5facf920bcb48e8fd8b7b5fbf4f8fad5e652fd99be5d322fe920e702cc4afd218d76bd6800812155d8012c8fd57538a7b9d64f2defee3e32879e36db649a934b00784e6223023bdfffa59f4e54609d63a6f5ad04850c419a3556db8b291b90467fadfc67194a3069ef6ff4c0f7d6677145ceb51be68d6d0c20d0e92f80313c48dabf5ae8e3acd9fc43f450874848221f71d2f895c18790082d17467de32ff047a22cee1799db7e77e651a35c15b32d4f838133cc80d467308587ff5cea12be5b3b8b7d2d0d2eadf066b67cd965100555f96457d0d70988ffc2a7c212afa73338df3ece84ee7de2170aadec1dafc360580432193ab2a25c9c4555e57bc0d88cf50d7036378b4dabde79e5f858539a464e0a547660374da91d7d19acd753e219a8fee41a43bd4190db235dc0b1224bcfb9a760fb2b39063dccce88453043c0297cb6c93bca145a9ebbd6bc3a916ed9439343ac3510c47886d17a9187e833b9149e5ac2854c4d88a7c4b4ee68828080a0";
+
     #[test]
     fn shelley_network_block_parse() {
-        let bytes = hex::decode("820284828f182e1a00015180582045899e8002b27df291e09188bfe3aeb5397ac03546a7d0ead93aa2500860f1af5820d1a8de6caa8fd9b175c59862ecdd5abcd0477b84b82a0e52faecc6b3c85100a4582051995f616f8a025f974b20330a53c0c81e8ea95973d73d15fff7bab57589311d8258406ef0be9293f5a307037b60b7252eae99bb045d58a521d5ba7c8d3aa129292a012a9391eefff8843f3558b35265ec432501b24688a2e0a21964fc71cf69c25dae58503d43c14357cd83b0f941f8c7e7a573fc361b8702bfee211bd7adb1a59f9576fe1bd11e3fa32b5a982e7b246e18058b099f147362c9c18acc43e1e4e8b5b7c660ea3ed9ac5b7a7ec0feb59ccfb64729048258407b4043c26958ea012568b14b0b95ae50d0bd589ae50d58ca5a7190375854084292f87b7f8878887269aaa59251d02032bf1bef5f5fe8fdafd1217164f0d77d885850f68678b7d56dc4178307ba00737cb3339c41f0bf77a894d2156c6cb4ce2047597b2b23dce04a7e1b9abd07a1b870bcf9b18aee0ce27d1fc3c4f2f73cf91f0ca3167e3f67dbe90838da2e8bf15234cb060358201033376be025cb705fd8dd02eda11cc73975a062b5d14ffd74d6ff69e69a2ff758202b9a5add912f3edc5c325d6250b9cc154de8f35e2924f5b1c707a4123808d064000058407fb060b885ffc7b55bb6e095ea6999eaa5608b6b4e92f5cc64bc34ba85000ebe42839432f15d86df07740374f69ce60f72feb16a0d47f5cf2c43158caf37ad0303005901c0f1f0c338a257b27351cf337c4c56961e67235b93cfa351f9d5f348911c7870cb2e084ff7c1f3d93719245fdbba539165d8eea9a510251e5cc3210b3bae3bb40034d42d5ecf181026f436173ad3036d5be2ba595f5facf920bcb48e8fd8b7b5fbf4f8fad5e652fd99be5d322fe920e702cc4afd218d76bd6800812155d8012c8fd57538a7b9d64f2defee3e32879e36db649a934b00784e6223023bdfffa59f4e54609d63a6f5ad04850c419a3556db8b291b90467fadfc67194a3069ef6ff4c0f7d6677145ceb51be68d6d0c20d0e92f80313c48dabf5ae8e3acd9fc43f450874848221f71d2f895c18790082d17467de32ff047a22cee1799db7e77e651a35c15b32d4f838133cc80d467308587ff5cea12be5b3b8b7d2d0d2eadf066b67cd965100555f96457d0d70988ffc2a7c212afa73338df3ece84ee7de2170aadec1dafc360580432193ab2a25c9c4555e57bc0d88cf50d7036378b4dabde79e5f858539a464e0a547660374da91d7d19acd753e219a8fee41a43bd4190db235dc0b1224bcfb9a760fb2b39063dccce88453043c0297cb6c93bca145a9ebbd6bc3a916ed9439343ac3510c47886d17a9187e833b9149e5ac2854c4d88a7c4b4ee68828080a0").unwrap();
+        let bytes = hex::decode(SHELLEY_NETWORK_BLOCK).unwrap();
         let _block = MultiEraBlock::from_explicit_network_cbor_bytes(&bytes).unwrap();
     }
 
+    #[test]
+    fn shelley_header_producer_hashes() {
+        let bytes = hex::decode(SHELLEY_NETWORK_BLOCK).unwrap();
+        let header = MultiEraBlock::from_explicit_network_cbor_bytes(&bytes)
+            .unwrap()
+            .header();
+        assert_eq!(
+            header.issuer_key_hash().unwrap().to_hex(),
+            "aae9293510344ddd636364c2673e34e03e79e3eefa8dbaa70e326f7d"
+        );
+        assert_eq!(
+            header.vrf_key_hash().unwrap().to_hex(),
+            "227116365af2ed943f1a8b5e6557bfaa34996f1578eec667a5e2b361c51e4ce7"
+        );
+        let byron = MultiEraBlock::from_explicit_network_cbor_bytes(
+            &hex::decode(BYRON_MAIN_BLOCK).unwrap(),
+        )
+        .unwrap();
+        assert!(byron.header().issuer_key_hash().is_none());
+        assert!(byron.header().vrf_key_hash().is_none());
+    }
+
+    #[test]
+    fn block_with_limits() {
+        let block = MultiEraBlock::from_explicit_network_cbor_bytes(
+            &hex::decode(SHELLEY_NETWORK_BLOCK).unwrap(),
+        )
+        .unwrap();
+        let bytes = block.to_cbor_bytes();
+        let limited =
+            MultiEraBlock::from_cbor_bytes_with_limits(&bytes, &DeserializeLimits::default())
+                .unwrap();
+        assert_eq!(limited.hash(), block.hash());
+        let too_small = DeserializeLimits {
+            max_size: bytes.len() - 1,
+            ..DeserializeLimits::default()
+        };
+        assert!(MultiEraBlock::from_cbor_bytes_with_limits(&bytes, &too_small).is_err());
+    }
+
+    // allegra block in the network format with an update proposal (d = 30([0, 1]), protocol version 4.0)
+    pub(crate) const ALLEGRA_NETWORK_BLOCK: &str = "820384828f1954971a0007e9c85820cacf5da6b8d81bbdf77b5ce4f5ea7f7b6714a29b1e81dbf541b01e92d8e1a3215820618b625df30de53895ff29e7a3770dca56c2ff066d4aa05a6971905deecef6db5820707a5e99ceec213eb56768da310566da8f4ff56cbdd90431ebd0ae17f6c8cc8b82584091b1c2d55cc491732a4cfa591a4e9bfd1aada7610d25e0fb9bb62176a0daf709485271c911c275b007005a0cf17e41e6639dff95d59319bf96270ec1515c161958506195ed4ddd4efd642b1810aa5ff92f91cb25082f07a61be35c7b82f06c9b8dc3a2fb7f9f1d40ff5779e63d02b09253716971018f8dfc0e4aa07bbeaa0e26f3fb235e0de00f60ba879c8a52744e8d470f825840ed8ac2d394a4a8022224b6f4b4b859bb748e6af00b8daa998c2aad2a9f42f8f4dc4f3eba29e323b426099805d02a7daf79ba262b51191b26bf07fce07f3effb75850e58bd3d0326bf69fb3ed652a556f16fb61e4835f6766d92965ddeea69a7000fcff6d98fa5f5cae9f5c3cf99b5606a76319180eaaff4af81aea358077e4363237579c9078dfce08a72a0b5ca90c5d140e1904a958206f970389c3de6fedb3d8d981a32e9bd3791f6e3230cdf02632394f0f7115a54b582005424ee48b0616cdbd5bc631ed25a628518575912c22c6dfea7e2778aac12bba000058404fa969b5356abab0a3c8a42007a3ab177d17aebdf4bedd93a541f545544a01dbb6e2696ef58ee8cf96c214717a4ebd35f2fa992d5815db01382f1bd516a38c0504005901c076acf26e9c06a576578d9977e791cf29017ebee3ec38d341c33c858b4e4fa0a9546e26033bab39b59947c4f25a0a2539dae2ed8a114675e72105df7449fab404088e270b2f1d3ff6c7b6c9f3b8150ec67d7ce24c6732cec4cec8de0b932b3e60507a6d639ab6ba44468039bc4c2f67b7952eaf125e9e11e8df871cfd2ba5316cbc4bd8e012158d56011100489b821ec29c3b9bdc28ed00911a529e46a83dd50faa7c35e2e59af061766144b53289d730787a1575153e9b3622733134443656dc9ba5091ce00397cd56ed509a875c177cc8b8b52b5e1bdba6aa414d966c5c6fd20b05a932284ca9902735bf350c0eda9af447beaad02703960b427a7368bb73b38fe90d56d3364c761b9a3ae0acb285054cf7ce0573d05464e1ea1b298ed8e876442244df9e3f19c4b36f8b4e09e0c63aeb48ac8f1c16af7897aa2a7846983f9d58ad4f84a5fa579f48028b66b9bc0617a2e9c67625cf98fc0b2df820393c63cf8b35c98874f8592752a1c8b34b09ed2d08d3ffc97c567152af96a1044485d66316c4ba224361e8ce16423fb537346f6cb4a9e1c2b3cf496dffe8e5a59cdd274d40d8f7d1a2ba6fc0aa3ce682e635272b9e57bac008586e14b67926c5bb24124781a40081825820a00696a0c2d70c381a265a845e43c55e1d00f96b27c06defc015dc92eb20624000018182581d609e5614893238cf85e284c61ec56d5efd9f9cdc4863ba7e1bf00c2c7d1b006983fdc40382dd021a00032bd50682a7581c637f2e950b0fd8f8e3e811c5fbeb19e411e7a2bf37272b84b29c1a0ba20cd81e8200010e820400581c8a4b77c4f534f8b8cc6f269e5ebb7ba77fa63a476e50e05e66d7051ca20cd81e8200010e820400581cb00470cd193d67aac47c373602fccd4195aad3002c169b5570de1126a20cd81e8200010e820400581cb260ffdb6eba541fcf18601923457307647dce807851b9d19da133aba20cd81e8200010e820400581cced1599fd821a39593e00592e5292bdc1437ae0f7af388ef5257344aa20cd81e8200010e820400581cdd2a7d71a05bed11db61555ba4c658cb1ce06c8024193d064f2a66aea20cd81e8200010e820400581cf3b9e74f7d0f24d2314ea5dfbca94b65b2059d1ff94d97436b82d5b4a20cd81e8200010e8204000581a100888258208b0960d234bda67d52432c5d1a26aca2bfb5b9a09f966d9592a7bf0c728a1ecd584011a439a7391e34bd1bd4829f669a630276deb8cbe59f2a5ccca5190d19963bef9477e6f61e8d47438323ce9424befec3357c88908473fd332a7633ab2882c006825820618b625df30de53895ff29e7a3770dca56c2ff066d4aa05a6971905deecef6db58405cde79e14b9c033276fb503aaf6ae84fd0142d63e01c0a81ec1fb0794874184c2e3ac0fca64274f01be1ff3b7a93d2e7df60b485deb71fa8549a8ad879b0cb0782582069a14b724409e0ceef671c76ec4f8bce7509b5919bb971b3855bf92ca56532225840ecd0ea504800f96b34cc42742b1bd45990fa0068161c9cce3fb0703568c7dfe2a9283c02e63d0593bab15fa34fe9b732ad1915019d0f2d05a0fd0a570aa14205825820d1a8de6caa8fd9b175c59862ecdd5abcd0477b84b82a0e52faecc6b3c85100a4584060a4389a2a3ef54f7060c638a4268b5c7e2042bde1d1c7dc9ae9d29ffbe8bb9170fc929f27e3b0b298d42f34035fd3c149c1ede0fce7ec2981c3c882123f180e8258209aae625d4d15bcb3733d420e064f1cd338f386e0af049fcd42b455a69d28ad3658407e986eef76c9dcfb2483ca3fbe299f224c51a58da94b85ba1fcba41b384691b4cde236ca0d72237a2a21fe373a0d68c69ec490f0628cb6523b0263ca3338fc0a825820942bb3aaab0f6442b906b65ba6ddbf7969caa662d90968926211a3d56532f11d584090b5745d1007bfc524ffc53dfa17e58483ff74e9d37275f0b9e9ca084e180e2c2799b7947dcdb34774836719ea897ee4bd3e38b7e52513084ef61dfd1ead3809825820d4dd69a41071bc2dc8e64a97f4bd6379524ce0c2b665728043a067e34d3e218a5840d9b5a70f1f14b084385930fa47ed66ed0c8237812825f6c3923bdc702ab1f219cc4583b8c0e5d291cfd3e0ae586f4e98d5e87d251304ed3afd1c088c129a190f8258208ef320c2df6654a6188c45e9c639c0a686bf5a865295587d399dfeb05fe74ab65840a59197afd5188eba40323d57246103eda1bb231a4df0879e6b1c3ce512978af0c6e33355f53bb9db0e6f85cc8d835355b6b30af9dde11a94c8c7ed2c635a7603a0";
+
     #[test]
     fn allegra_network_block_parse() {
-        let bytes = hex::decode("820384828f1954971a0007e9c85820cacf5da6b8d81bbdf77b5ce4f5ea7f7b6714a29b1e81dbf541b01e92d8e1a3215820618b625df30de53895ff29e7a3770dca56c2ff066d4aa05a6971905deecef6db5820707a5e99ceec213eb56768da310566da8f4ff56cbdd90431ebd0ae17f6c8cc8b82584091b1c2d55cc491732a4cfa591a4e9bfd1aada7610d25e0fb9bb62176a0daf709485271c911c275b007005a0cf17e41e6639dff95d59319bf96270ec1515c161958506195ed4ddd4efd642b1810aa5ff92f91cb25082f07a61be35c7b82f06c9b8dc3a2fb7f9f1d40ff5779e63d02b09253716971018f8dfc0e4aa07bbeaa0e26f3fb235e0de00f60ba879c8a52744e8d470f825840ed8ac2d394a4a8022224b6f4b4b859bb748e6af00b8daa998c2aad2a9f42f8f4dc4f3eba29e323b426099805d02a7daf79ba262b51191b26bf07fce07f3effb75850e58bd3d0326bf69fb3ed652a556f16fb61e4835f6766d92965ddeea69a7000fcff6d98fa5f5cae9f5c3cf99b5606a76319180eaaff4af81aea358077e4363237579c9078dfce08a72a0b5ca90c5d140e1904a958206f970389c3de6fedb3d8d981a32e9bd3791f6e3230cdf02632394f0f7115a54b582005424ee48b0616cdbd5bc631ed25a628518575912c22c6dfea7e2778aac12bba000058404fa969b5356abab0a3c8a42007a3ab177d17aebdf4bedd93a541f545544a01dbb6e2696ef58ee8cf96c214717a4ebd35f2fa992d5815db01382f1bd516a38c0504005901c076acf26e9c06a576578d9977e791cf29017ebee3ec38d341c33c858b4e4fa0a9546e26033bab39b59947c4f25a0a2539dae2ed8a114675e72105df7449fab404088e270b2f1d3ff6c7b6c9f3b8150ec67d7ce24c6732cec4cec8de0b932b3e60507a6d639ab6ba44468039bc4c2f67b7952eaf125e9e11e8df871cfd2ba5316cbc4bd8e012158d56011100489b821ec29c3b9bdc28ed00911a529e46a83dd50faa7c35e2e59af061766144b53289d730787a1575153e9b3622733134443656dc9ba5091ce00397cd56ed509a875c177cc8b8b52b5e1bdba6aa414d966c5c6fd20b05a932284ca9902735bf350c0eda9af447beaad02703960b427a7368bb73b38fe90d56d3364c761b9a3ae0acb285054cf7ce0573d05464e1ea1b298ed8e876442244df9e3f19c4b36f8b4e09e0c63aeb48ac8f1c16af7897aa2a7846983f9d58ad4f84a5fa579f48028b66b9bc0617a2e9c67625cf98fc0b2df820393c63cf8b35c98874f8592752a1c8b34b09ed2d08d3ffc97c567152af96a1044485d66316c4ba224361e8ce16423fb537346f6cb4a9e1c2b3cf496dffe8e5a59cdd274d40d8f7d1a2ba6fc0aa3ce682e635272b9e57bac008586e14b67926c5bb24124781a40081825820a00696a0c2d70c381a265a845e43c55e1d00f96b27c06defc015dc92eb20624000018182581d609e5614893238cf85e284c61ec56d5efd9f9cdc4863ba7e1bf00c2c7d1b006983fdc40382dd021a00032bd50682a7581c637f2e950b0fd8f8e3e811c5fbeb19e411e7a2bf37272b84b29c1a0ba20cd81e8200010e820400581c8a4b77c4f534f8b8cc6f269e5ebb7ba77fa63a476e50e05e66d7051ca20cd81e8200010e820400581cb00470cd193d67aac47c373602fccd4195aad3002c169b5570de1126a20cd81e8200010e820400581cb260ffdb6eba541fcf18601923457307647dce807851b9d19da133aba20cd81e8200010e820400581cced1599fd821a39593e00592e5292bdc1437ae0f7af388ef5257344aa20cd81e8200010e820400581cdd2a7d71a05bed11db61555ba4c658cb1ce06c8024193d064f2a66aea20cd81e8200010e820400581cf3b9e74f7d0f24d2314ea5dfbca94b65b2059d1ff94d97436b82d5b4a20cd81e8200010e8204000581a100888258208b0960d234bda67d52432c5d1a26aca2bfb5b9a09f966d9592a7bf0c728a1ecd584011a439a7391e34bd1bd4829f669a630276deb8cbe59f2a5ccca5190d19963bef9477e6f61e8d47438323ce9424befec3357c88908473fd332a7633ab2882c006825820618b625df30de53895ff29e7a3770dca56c2ff066d4aa05a6971905deecef6db58405cde79e14b9c033276fb503aaf6ae84fd0142d63e01c0a81ec1fb0794874184c2e3ac0fca64274f01be1ff3b7a93d2e7df60b485deb71fa8549a8ad879b0cb0782582069a14b724409e0ceef671c76ec4f8bce7509b5919bb971b3855bf92ca56532225840ecd0ea504800f96b34cc42742b1bd45990fa0068161c9cce3fb0703568c7dfe2a9283c02e63d0593bab15fa34fe9b732ad1915019d0f2d05a0fd0a570aa14205825820d1a8de6caa8fd9b175c59862ecdd5abcd0477b84b82a0e52faecc6b3c85100a4584060a4389a2a3ef54f7060c638a4268b5c7e2042bde1d1c7dc9ae9d29ffbe8bb9170fc929f27e3b0b298d42f34035fd3c149c1ede0fce7ec2981c3c882123f180e8258209aae625d4d15bcb3733d420e064f1cd338f386e0af049fcd42b455a69d28ad3658407e986eef76c9dcfb2483ca3fbe299f224c51a58da94b85ba1fcba41b384691b4cde236ca0d72237a2a21fe373a0d68c69ec490f0628cb6523b0263ca3338fc0a825820942bb3aaab0f6442b906b65ba6ddbf7969caa662d90968926211a3d56532f11d584090b5745d1007bfc524ffc53dfa17e58483ff74e9d37275f0b9e9ca084e180e2c2799b7947dcdb34774836719ea897ee4bd3e38b7e52513084ef61dfd1ead3809825820d4dd69a41071bc2dc8e64a97f4bd6379524ce0c2b665728043a067e34d3e218a5840d9b5a70f1f14b084385930fa47ed66ed0c8237812825f6c3923bdc702ab1f219cc4583b8c0e5d291cfd3e0ae586f4e98d5e87d251304ed3afd1c088c129a190f8258208ef320c2df6654a6188c45e9c639c0a686bf5a865295587d399dfeb05fe74ab65840a59197afd5188eba40323d57246103eda1bb231a4df0879e6b1c3ce512978af0c6e33355f53bb9db0e6f85cc8d835355b6b30af9dde11a94c8c7ed2c635a7603a0").unwrap();
+        let bytes = hex::decode(ALLEGRA_NETWORK_BLOCK).unwrap();
         let _block = MultiEraBlock::from_explicit_network_cbor_bytes(&bytes).unwrap();
     }
 
+    // mary block in the network format
+    const MARY_NETWORK_BLOCK: &str = "820484828f1a0052801c1a016001265820a650a3f398ba4a9427ec8c293e9f7156d81fd2f7ca849014d8d2c1156c359b3a582089c29f8c4af27b7accbe589747820134ebbaa1caf3ce949270a3d0c7dcfd541b5820a90a190462fc24c92f5adb29cf3bca2aa060c6d3ae5d7842b4e9248ed19c1a3a825840d2faeb342bc9ac044821d9e314459d88dfd77a707aaeaa353ab274b532a21c6c071f9ad8521367e7233ca85c52eedb9a119d741645952dff57c1253cced7872458501b0f56358eb58e2e6f92eb2ce894d2e938a39d7502315526460b9b7ca8edbec4e37e0c0261cb0e878e1a88a6aa1425e026d28efd8b899ce8920663068ed499bbfa5438bfdb668634e295caa5264154038258408b048dd6774460ba3413d5fdd94fcab36dd1fe1452035ed8dbd3a551eade6f137415a15e68039af8393253a60e2eeb43b1cfb548897512f38fdadf2836e23ace5850809bf118c20c5d1b656a54ec6605e30b305f58ad9110a3fabc4d1ce0961448f0b5c10a0fe6a2132cbc36e90041280812bd8744a64a75e10a48a47a90aaeb8729655ecd978fb89c5966ce4d861161b80a193f1c5820669896257fb55857bf716873c9a4b6cca319aacafd9680f482fec6f8666f119f58208cada7e2b5f6745ad1365a84a077309a1224be0fcd72a1797aa6837160b74c520218ac5840d342d671680b51f4b6d41d329d320db58c5ba02cea9ce68eafc8a998724c5187f8599f0915b89cf13e7294b101af482f21ffc52e4247a3f2a02db8bd5853c60804005901c0b59b277aa9c4e678274a6dfe4d8adf76b1d80c0122940c38b6f6d13e65e0c0816f2bbe29831620034a5c904d814e3508d3f1fad63f6ba46c46da36844a320e0e0f7df1a6d6fd3180c1ddd147a526e52485797c575a43885f7d6476745587b53bbb450683e07e2d3d7bb70cb468cf04aa8a497367cfdab2fb62ebd72769894f8baa3e1ae4f30ad5b6fc0a5f256c90623182d673862b8e4a969dae96d6868593e9ebdf144ea940b17b994a2a4c4f0d807cf9ae96354e44cb57ed8cc4007969d4848ef1f32da01f5f74c598cb871a1dfab37bc8a676333e3a28b85a329a022b7dde840b4416b7f93db15850150d311576e0fd4c750544b25fc7f2a3a049b4be53e7b9e35f0046edef313073c7a63d8c90685c519431d8798aeb8b418360de4a8a52d00cb1e6a672016f41706471e3c729e462eebd4d102878bc5568b4345f8a15772dbcd704fe70293a91485b2b9f436682bb7813ba73a754042f0faed80392e7eabf5e2c4150cda9e13e518b89e0e15689f2e6e8f75228266527d02f747509d5b47a85313697dfce521fb4781888ff88dd6075128f8955ef5f2364bb006c86424d902581dcf384fd5a5f8ca6c56e1cb6cac4e0099c9c1cd3529b20acf1176f2a0a9fa50082825820cea49c588ff5c48394741f8f6ab9f5441f0cd5cd7d09103a1e435ec9c6f4987900825820cea49c588ff5c48394741f8f6ab9f5441f0cd5cd7d09103a1e435ec9c6f498790101828258390131c44f81799a6550aa93224ccb95de995ea0c210daa346907ecd744531c44f81799a6550aa93224ccb95de995ea0c210daa346907ecd74451a000f42408258390131c44f81799a6550aa93224ccb95de995ea0c210daa346907ecd744531c44f81799a6550aa93224ccb95de995ea0c210daa346907ecd74451a3a57f33b021a0003096c031a05f5e100048182018200581c31c44f81799a6550aa93224ccb95de995ea0c210daa346907ecd7445a40081825820c7993fb38a504159edeb4c5d256034af8f6844e1bac1bbfe6a3dfa174edda64101018282583901d1e9744c80ad49dbbe6e1025571955c9585f0f05ff3245113123ee18b5cd29eb688d274dd12e80ccb3e9024577ad381640217f419e62d3e21a3b9aca0082584c82d818584283581c17037e3f8f2d06e5971ad9bd6e067b3934ebbf7bb75c1f90a67a1858a101581e581c13354432f02c83669eab035130755a29ef6e6956a5f94b2917e7dac1001a01ebb7bb1ae86ceee6021a0002a56d031a01601cb3a40081825820962ec940b10ff86b3a7b888c2290c5f889e8d0bba10dd6441b1fe7c9b988295d01018282583901e87d31beb81288e70fd7c5f7289eb63e32842ddfd6e8832483b1c1dac411474c6d6095c5f5bcd1dec91ce5e5de9dc5b04eff330a9389a0e21b000000028257646082581d6182200447d8bab38065e5b0cc2161a195e15a8e46009292e56c5205511b000000795e56e97e021a00028d31031a01e13380a50081825820205275e9b2dc141b2974230ac9f5d742c08f71aeec34ef8cbddbc9b9ac7c811e05018182583901b0afa1daa28b7b8ebfd03bdc3143aa4fb080e9bb30f0ae4e05a7e81abcb1d2a6776790d3d161032e2701a57a5239db241a83ccd1b9a007471a13a3a597021a0002ac21031a01601cb8048282008200581cbcb1d2a6776790d3d161032e2701a57a5239db241a83ccd1b9a0074783028200581cbcb1d2a6776790d3d161032e2701a57a5239db241a83ccd1b9a00747581c92fcdd76d1d9d13910cbe1930765543984e6f4ef9b7efed3cc6536a1a5008182582084bfc702d8530383ba64eba7a46883512f27d2042de6da21d28e0f430273e51a01018182583901e28e7c8bea3c201f7083aedf848b6cf2573360f9c841c547675e45f9a06792f94c463a823cadf5fe8276b5fb2746b5116970052b42b626f41a007765df021a0002ac21031a01601cbc048282008200581ca06792f94c463a823cadf5fe8276b5fb2746b5116970052b42b626f483028200581ca06792f94c463a823cadf5fe8276b5fb2746b5116970052b42b626f4581cd5b90a198d2411b5c82fbdfee5f94b86de49a8589bfa7720b9de358fa50081825820fe223726527c92a5a1b5787d8b38c8c1393e52d57156a5cce80f7453bf131fae000181825839017e3b076b30e6c3823829edba5c997036164f66f05b5d9b6c16c7aaec425aff7bbe040f54c7379d84ff176827bfb9a3ec848778d3efe243541a0794d316021a0002a649031a01601cc0048183028200581c425aff7bbe040f54c7379d84ff176827bfb9a3ec848778d3efe24354581cd5b90a198d2411b5c82fbdfee5f94b86de49a8589bfa7720b9de358fa500818258204fc7dacccc18cac885db1e6af59731a3e341eef8cbc5ead540e4bfb5687e70e40001818258390185a2c9f1f1b7de2ac3dbcc4a4f52280ee56f222ca903f6bd3864ff8e212bc6c241fc8d39bebff6a0befdf7c138c3409bb1a2f4958b5c723d1a02d71996021a0002a649031a01601ccf048183028200581c212bc6c241fc8d39bebff6a0befdf7c138c3409bb1a2f4958b5c723d581cff069b3ecf738d4adfe83f7c20c64b82f94f7285ee41eedb2bfd297ba5008182582018ef74bba9688ce8462c9454a9e5bc7a76e7617b909c46f81dc4dbbec1e552ee0001818258390115fd6f69054f0c262c40bafc34e114e0f7e7fcc31ca3933768ed42224e682ff9ed90c336e070535254552dc9688343e337d629b6728ea17b1b0000000129d2de56021a0002a389031a01601cbb048183028200581c4e682ff9ed90c336e070535254552dc9688343e337d629b6728ea17b581c490353aa6b85efb28922acd9e0ee1dcf6d0c269b9f0583718b0274baa50081825820d9a8ae2194e2e25e8079a04a4694e2679464a4f51512863a0008a35a85762ff00001818258390187be75696aea59b41b36415d0b7c8b7a0c21fe3dd5a63939acfbc3a0285f7ef46037e7fd61f04d19b4cabbc7ff7c2a7f8279a11f50422a95821a003d0900a1581c00000002df633853f6a47465c9496721d2d5b1291b8398016c0e87aea1476e7574636f696e01021a000f4240031a017290bf09a1581c00000002df633853f6a47465c9496721d2d5b1291b8398016c0e87aea1476e7574636f696e01a50081825820717ada10af06716ba68f6289396079a70abe95768fce36bf79c3ca6195a6b9300001818258390147d265cf01a2f56fe8506f3c33ea576db7c225b5d9d8d7a527b937f4f25eb703e8d3c42abc7ceb73e664aead7ccd3cb69e9c805a3be583d9821a004986e3a1581c3a9241cd79895e3a8d65261b40077d4437ce71e9d7c8c6c00e3f658ea1494669727374636f696e01021a0002c45d031a0160029f09a1581c3a9241cd79895e3a8d65261b40077d4437ce71e9d7c8c6c00e3f658ea1494669727374636f696e01a40081825820bec130129321240bc13ed66180a4e9d6b02d8484092d253db96df7ff6dcc8daf01018282583901566761ba7978aeddc6563246b72484a7daa234e5a2be38c111337a9ceffd4195b60e4b3c4887004c216805e8cc92a661533741332b4e75c41aacda7d0082584c82d818584283581ce627a88961c4397b233b92265eb8cdcbd5b99f21671ab0e794b7d824a101581e581c3083349abf53ead6314f8c5024331084c69650e4671c4f4f93e3f3f8001ada0a01ed1b000000f3e5242a86021a0002a56d031a01601cdea50081825820fadf5b93f8c4eb070a1a61b2b4e90db5f8ff469e30032d079c02797e98d8b1fc00018182583901afe3fc534a6fefb90fb878a21344fe5009cb1e46903d26f5df47b6069965122454f8bc00cfe28233b9ef1fa74d70ce37e87a22a336b0c8311a03da7fd6021a0002a649031a01601ce8048183028200581c9965122454f8bc00cfe28233b9ef1fa74d70ce37e87a22a336b0c831581c42c3740db23c3fc36cb9f63aa8070ec01417b4d22893463c8c8a21d4a50081825820966380d07c594d78a1a0b413de89d411409ea84fac9e3bed1e67cde49fcc362e181b018182583901537e62a502a70427e04a7897f5bd83321f6a1d81661f39c555536609cb6633dd90135e13a7e92001f79ac10645f719c90b3e13ef55a2b0671a23b15d77021a0002a649031a01601cef048183028200581ccb6633dd90135e13a7e92001f79ac10645f719c90b3e13ef55a2b067581c000000f66e28b0f18aef20555f4c4954234e3270dfbbdcc13f54e799a50081825820aa3462c62eaf606034a9da1829c2bcc487ea78fab9c27ff68e25c813c27ec96b01018182581d615383c9659d70a3c8f70c9809b94f3bcea3594c2abbf9b3bdf3dcb22d821a0020bf46a1581c02f68378e37af4545d027d0a9fa5581ac682897a3fc1f6d8f936ed2ba14541544144411a000f4240021a0002be2d031a0161872009a1581c02f68378e37af4545d027d0a9fa5581ac682897a3fc1f6d8f936ed2ba14541544144411a000f4240a50081825820fad00fddbf64b68bc6001a0a7f3d02b2ae98bdc75c09ff93af058a3bdf0d780700018182583901f992fc53ddc5a14748e2b232ed73ec48703f4575371af8ff8df6c28888aaaa1c15fce4ffada3ef15dd73a2c9f6368ff26f091778902de9c51ab2916075021a0002a649031a01601cf0048183028200581c88aaaa1c15fce4ffada3ef15dd73a2c9f6368ff26f091778902de9c5581c86a19d5418cd07294af7d71386cf14a84e0478fbfde5b89fe4d98a58a50081825820aa3462c62eaf606034a9da1829c2bcc487ea78fab9c27ff68e25c813c27ec96b00018182581d61a12e3551371670d5d44a5fe3c319d1f8f55ff06404fff9cc99a7a675821a002368d3a1581ce8e62d329e73190190c3e323fb5c9fb98ee55f0676332ba949f29d72a145464952535401021a0002bccd031a0161872009a1581ce8e62d329e73190190c3e323fb5c9fb98ee55f0676332ba949f29d72a145464952535401a50081825820dd0619f4d7773d96b4a34b8a514f89c6df82b978da73bcf6fe973516bc0ae43800018182581d61ba91d25d8a27dc19a3cf37f8226ba215bc21bc199b18eff513b11fa9821a002b08eba1581cac3f4224723e2ed9d166478662f6e48bae9ddf0fc5ee58f54f6c3229a14443454e541a00989680021a0002bdd5031a016002d809a1581cac3f4224723e2ed9d166478662f6e48bae9ddf0fc5ee58f54f6c3229a14443454e541a00989680a50081825820d0d07a3ecbc6ecb9b14d7211683805e82961403ddb8a416dcfc9caf231c6be4700018182583901b3dd7745334afca9aed1422975fc56dbf02d0dca784f0354c48e09d519cd43b2cb25daf869335fc988eeae8914859525a6fac0bbd1c6c3f31a06e4b296021a0002a649031a01601ced048183028200581c19cd43b2cb25daf869335fc988eeae8914859525a6fac0bbd1c6c3f3581c42c3740db23c3fc36cb9f63aa8070ec01417b4d22893463c8c8a21d4a40081825820ddc7c5fccc3f5a401b84bf76e8b2ee733bacbb55d1348e9643f2356c5857b7be0101828258390171aa776aa9070a4f464e2177f714922a2e200a9ee8d3a6fa8298d752cd0815b4fb0047f18ca403069546f672bb782e341085a9c534878be91a00b8bbf582584c82d818584283581c956364006fab28cdf4128a494589460750d980246d5b926ae5bf70d8a101581e581cd8d97175289f3a1d7513cd61b4dac6ad9324abd85fa1f563e44c3538001a1e828a601b000000013985db1a021a0002a56d031a01601d07a40081825820f757d89498b8cdfbed2f0c309b2a3253d94c18c0f586823f0864ef6a82803c3a01018282583901166347f6af974dc4a27f537891a91e17117524737ec3933eafbfeaf8d57703b78a21c95990c537df886d990cdabd223361bf4a1f6bfee80d1a447dbc2382584c82d818584283581cd213ed895eaccb222835a0e5dbb2122d7ba216690767ad526523aa7aa101581e581cd8d97175289f3a44642b7761173cbe9cfa81a2bc0a18ea4c8d642774001a7cd9bcf81b00000001498c3a85021a0002a56d031a01601d08a400888258202d5609e8b9db3466002110c218194f56fe925ad28a7bca415af5d3e133e14cdb008258206714429f3418b637106e6c5379a9825bde2c87303ca2860b4d5dabe2615103e9008258206bb277e875aede63feceed2db8f2f13f5aef438ea856ace877fa888f686446ed0182582081eefee68c890a4e078286e06362d16c266d37337fc8023043de1669d6164e7f0082582091fe40cbab55d1a60bba9653bd4ec9fc438a41dc8ee79b5f520835b603945fe500825820d98b4fec924debfe4e876fb261619c881cc80e7387347be426c4071660e7dc5600825820db1cc1172030e556a79865c9626649788e98fad289beed5ebdbc7cc9f830e5f400825820dc8f14f65efd45361365f05e358577e203df26465f58142d8bc95a66fe12168700018282584c82d818584283581c422e36209e2c33d240c71c85360f2305486fae2833344b97596bf9dea101581e581cd8d97175289f3a14d655b2617089ce27d2df668f2901bc633cf7fcf4001ae4ee58001a8e33932382584c82d818584283581c694e4bd2a5c3efe1f01a18b8d3552a17270918def72b2a5bdff1e9a6a101581e581cc9a4c68c636468073fa4ab51d4929ad5d81a53926f36130227f6d96f001ac1af646e1a71a5d5e1021a0003b161031a01601d09a50081825820aa3deb58e2bc6e239daac2026bf61f4501f027d9a4c093ee4d06470cc01bdf7e0101818258390121107d5b4400bdfca0c9c059192ac30aeff89c41e4686508227815ae2ca29e25bdd48979b0c887a45bfb7e73b472bca2baa96c848ed0d24a821a04453bfea1581c12e65fa3585d80cba39dcf4f59363bb68b77f9d3c0784734427b1517a14454534c411a1961cb09021a0002bdd5031a01601af309a1581c12e65fa3585d80cba39dcf4f59363bb68b77f9d3c0784734427b1517a14454534c411a1961cb09a40081825820225b984b3573c95a24470387c6fba632a000e364b06ffd5da34a3a82870a3d58000181825839013780f8f1bcfd0ea42ead706430a5d80b395ef2ca5154051b00893c25fdead883eddc968d0f8247b53b1c518503d6fd172994a6c1fc9effcf821a0095ec43a1581ce12ab5cf12f95cd57b739282d06af9dd61e1b1dde1e06f0c31f02511a14667696d62616c182a021a0002aa3d09a1581ce12ab5cf12f95cd57b739282d06af9dd61e1b1dde1e06f0c31f02511a14667696d62616c182aa50081825820e770f1bb246fa6873f78c6f8c947a591d4d4173b27529b8e5df074137d66be190001818258390141201fc5df296def03b966aa58b1050c13cdc296e119db8ebc33b80f09e99f03d267b383bb0559fdfc9115d10173b2d416e837ee63fc9b3d1a21cb22f2021a0002a389031a01601cef048183028200581c09e99f03d267b383bb0559fdfc9115d10173b2d416e837ee63fc9b3d581c50a251d84927d932b9393cb462f300d215faedd5e8a5e791e869f32ca500828258206a9c72ca6229c756d6527f8abe2784d76fc8f95a2d7dfb918a0272fdc5e09583018258200c111a4ff5e61e2a7ebfe30139b0ba1995e072386f2372db15831e1f318af6160c01828258390190eb605dc7a6c460afc560592eb1ef0d1bb274252f00a6492d9ef25a90eb605dc7a6c460afc560592eb1ef0d1bb274252f00a6492d9ef25a1a000f42408258390190eb605dc7a6c460afc560592eb1ef0d1bb274252f00a6492d9ef25a90eb605dc7a6c460afc560592eb1ef0d1bb274252f00a6492d9ef25a1a3fd220b1021a00032dfc031a05f5e100048282008200581c90eb605dc7a6c460afc560592eb1ef0d1bb274252f00a6492d9ef25a83028200581c90eb605dc7a6c460afc560592eb1ef0d1bb274252f00a6492d9ef25a581c9a22c008779c455a124d86191047d35690a0ca2c094a34d90cd29542a500818258206ed4a57078193bc208f9b7ad5040f3c666e89a14c268893490359f872c273ea40301828258390145504eba43c81ae5d52d913cbe9296bdb99f9be26ca04d7c5c772cb07e6c95ee0ce36bac4915d1844fb6a26825c10735d79fada8e382fb6f1a000f424082583901f4c5adab179dcbb06314633643132559b421346805a0f38020af69737e6c95ee0ce36bac4915d1844fb6a26825c10735d79fada8e382fb6f1a159aa257021a0002bca1031a01601d100758201f40e487af7997c67a6a995e527b7d1927b4087ab797df436e360e8ea5e2ee9da50081825820f73e2d9c031e73f28fcc8f9c0ea37fb7201856b99e96f73a804b46b8b5059fe0000181825839018b86dd71fe43380954a2572c0bb1e708588fd63d6b6bc881b6e6bd939a07e9543e94c9898da2f45263d8c874584b1f29d02297ec9dd506dc1a381b5e46021a0002a649031a01601cff048183028200581c9a07e9543e94c9898da2f45263d8c874584b1f29d02297ec9dd506dc581ca89b86373838360143aba3911eaae54bf9420868965d48d447e4510aa600818258205fdc3570b9e1669d08ca01b7deaf005fc57a710fba68d5665e2572c9ce07bb2b00018182581d6129fd280480232d72b2839fcf47275e790a1a3a45dafafd8f64180612821a0696e523a1581cda8c30857834c6ae7203935b89278c532b3995245295456f993e1d24a1424c511b00001319718a5000021a0002d535031a016002d807582012acb16d984dd3c6bacc700f9b3a1416e2ed82270549d4ea62c6cd4d744d723109a1581cda8c30857834c6ae7203935b89278c532b3995245295456f993e1d24a1424c511b00001319718a5000a500818258200ea7635bc09fa06f28272f7d04a4a2edbb92def0c3414984008dfa3dd4b904a500018182583901aed1f8c7b32b85f3f52585be924ddf41ae5ac66c04b02d36c6f3fb1f9d79c247f12202e2f1c84c879435579d559c664951eadd57fac729a81a04dc25a3021a0002a649031a01601cf3048183028200581c9d79c247f12202e2f1c84c879435579d559c664951eadd57fac729a8581cd5b90a198d2411b5c82fbdfee5f94b86de49a8589bfa7720b9de358fa4008182582081bc69b2580f46681c7b2009e5d7ecae6b748eb3fb24379175293239238bd2cb0101818258390132fc37625b3ba6b080a8e4b69c124ee6f97194d8c7cabfbf9a3eda844585a49e9d3a5fb1ce7ba15eb49a5e1791dd73ec7c2ee7dc23f7c92d1b00000001043fb9d4021a0005d8e0031a01601c99a50081825820ee46783cc668263b76bb20a60e7d2b3c7174f70d2d23e4a60ac5dd528d50cdc900018182583901921b91c4faa9e77472f6d8e4cddffbe18dfbf3a0dee2ccf27d606cb04a75a86cd544df6cc79fb212713acac422570caed537e14c1d6cc0821ae66072c5021a0002a649031a01601d02048183028200581c4a75a86cd544df6cc79fb212713acac422570caed537e14c1d6cc082581ce0bfd78fd14b0336186ff6ca79a45f3619102b5bb484f7e0124dfdc0a5008182582025869369a73f07dfc6653f3271dfb6902d9e2233d98aee64a752fe4a5a070bfe0101818258390183bf1e4dce6e2a14b20b911c00f531ea0ed610e35f3a51b837c7883da0a141f63f69a266b657e285ee53f3ef6accdb1c5cd463408e35a5421a007765df021a0002ac21031a01601d35048282008200581ca0a141f63f69a266b657e285ee53f3ef6accdb1c5cd463408e35a54283028200581ca0a141f63f69a266b657e285ee53f3ef6accdb1c5cd463408e35a542581c9dfeca095f80562460252072fd8438f08936ef5a2077583a9722bd97a400818258203daadb9446b4a53d95462d727a42d517fcdd92a175486d03a40f5891324c71fc0001828258390115acbe7cfe2ce324b0a71214f7e19efa271e25462de521f41e92c8c3b694cf6923a844158166febc2d391c262f239577c1f521c8139ee7771a0098968082583901caf238555ab52df3659f6c71baec4fc76974dcc6d5d9120cbf4ac780fdccb0cb3af2a1ab6f4d39aa2f56b31b24148145602085deb4141ee41b00000001110a3e0a021a00029361031a01601d3da500818258208d2bce88c994dec05ab0052efd58d79619c2ee5b126be8313d94e14ef5bed155000182825839019f857b5feff2b13e90142bb4ac20b95063747e1df8ea5cb8b827f8b13cc658afae01d34253a65ba78b47f1641dd81c01e9e7971da00588c61a000f424082583901fc8684d555c76d67bb319003dcff7bea8ec1bde809605c45e9f218af3cc658afae01d34253a65ba78b47f1641dd81c01e9e7971da00588c61b000000047699b9cb021a0002bca1031a01601d3d07582076eaf2130c41de3827d3adbaa9e32ca3871694c4ad06695ad059b6c65dbd2925a500818258208091d82d809278eba8f7c106e484222fd17ccca4d6dd06918010f7a725eb675c03018182583901238c5a04f5fe82895cd8ba8ec0f97c5f7df2831ace50c3ff6e9a866ca836d3b75929f3165a76fe83994ece87604a3a54332ba1b72c8f55991a7355ec35021a0002a649031a01601d44048183028200581ca836d3b75929f3165a76fe83994ece87604a3a54332ba1b72c8f5599581cd4405171c1595c338c7ce5dcad8b4ed676aa6eeb1b28749e622f48e9ff9fa10083825820b578ad44d4b6dc61a2155a90fdf469fb22fd13428204b38f873ebe830c447ac35840750b4b9b81201e1113a5245156918733a8471ce83f5627ddcafcca9b7328e40b308e66762cce1c1ae4c60b37f50a6f54a995b0bf54c18800a3dd73e3ee03f203825820b578ad44d4b6dc61a2155a90fdf469fb22fd13428204b38f873ebe830c447ac35840750b4b9b81201e1113a5245156918733a8471ce83f5627ddcafcca9b7328e40b308e66762cce1c1ae4c60b37f50a6f54a995b0bf54c18800a3dd73e3ee03f203825820b578ad44d4b6dc61a2155a90fdf469fb22fd13428204b38f873ebe830c447ac35840750b4b9b81201e1113a5245156918733a8471ce83f5627ddcafcca9b7328e40b308e66762cce1c1ae4c60b37f50a6f54a995b0bf54c18800a3dd73e3ee03f203a1028184582028c7cb101f30cd81ff06f70b4df7f6f166f49449377081d07cad661562a23e2d58409c82b395b8720a15abca4efe36f5df6d0d3811e2088f980dac5cbfffa66c47697c5e9e556bc0a7bf422f038041e893b400104fa3fc81a74e3ff1721c3c5305025820eb49dbf247b0fea00b40ef47034b8931f57a6384971b631374616b73436715bb5822a101581e581c13354432f02c8338aaa56251a8ee28f360eaae7a060dec8a8b11bf6fa100818258202ba92a9b10742d8f4b04c9b3a5a282a3e4214698ef8bee30c4d689566c6a8dd0584039368d754835ad2067bb93e00948e643be47a0ca68674a5b39db6677f60871bfd8ee3612b1fb8156cbada416092784bec6d43aac0991c0b67c0f6280b133f707a10082825820b1b1c2ae30c9861bfbbc51db4a78169cc1c8f7dd5a81749d4d1b6a037a033a3658402032d883b5801c34b2564909f140498b8a77e8c2851ea35301160947364322a31954fddc3458f0b4677656b4895edcb08de614b5711f8c3b153214247497250c8258209d556eac9b29811592f4406d6005c16bf21e1405943f2643fd0754d13a0aa194584083399f7211d58dcea512f91b9dfd64e642fe70e7c2e2dd0c9b88fc51bc859e40e59f51714e1c9435dd78bd0d4b389b8ca7fd636732ed7b1f29d5de933dad7904a10082825820e2870f5b73495d7f7c8db1307ed93ed54459e074b4b55a4abdba1683fe1a5a4c5840ec1e7f11860778e705f0c023aa03738dc13b550da9837009b5d166e99ea2d609a8403de57dbb9003869e57b2a791420c183cbbac7e0ec3ffdb563364f6a0fc06825820ba474effb0b033fbae49905c08a51c55e384b3b8bb8089734ed4cf1ec88222b3584057318c5a2e7e5228c2b849cd9b141ed322bc04ee3533d82987ecc0957c57cfab646e2be1df7f60488a22b849379f569f7fb4f2e6201f3d22ebd8617670b3b901a10082825820469981c33fdc46274aeb96e344a2c8bcf0a57cdd328c3a67da8043b3bf491fc35840a9b31dbe11a5d25f6f64ca5e669f44eab13deef745202ac5a33c913947a2f768319b45e1c65b3469cf6055201f5bd17f93d2602262ac813c8df497cb9f41de0d825820d0165aab74c2263fc0dec5e5d6e4b36247b9fa8d9b5953ad796d3bf84c9d8c535840375e888c44c704aea99e28311c88820c1055eb3a8c4b3da9e74744c622d14404295b2a1b6613c38b86609e3a629eb3e6202397b18a440b51b02747b125527706a1008282582018652291879bec2d0844ee0956592b130805d61847faf89f431839c9a96dc1545840ed4558b9d054044061160af53ceb2557e2e2bf2606f1175e0dd51c491b6693bd1ceeb5a78d1147c2b5dfbb43c5f2eec7c3a4b5ec8406202b1bda4615278f1d0a825820db7125531f7eafed4aa8836e71bddc711ddbcf5279c44eb584d977914c2b66f75840b1e9156985dd52a881c28c0612fb35219963b41597c5c3c1b069d93058771aa078dcc6da0a5df4e82b2a8a4fcd5ae218f805f440cab75be95effa23345727401a10082825820130a7df57db7a74d4da0f0a98a2705f42e83f9d2d6b89ed09b08f1b85d27c0d0584051bba58eab5c549b7e71333f4666121c92571788f6f5582ae2adbeebbc41a6c3ccd9156b56326329d432a6f0f18fc3189f8ce2902809ae2c6de52a54a136f5048258201b44e7c87d8ac2de8d0bc266329d6b90349690d47d6363d984121e2152af119658404ae4ee3a586a2d6740e50e447e5606fcbd302a6edfe5c11a4bcc7e097d1d497ea4b8c0f5dafc9c934ae8ae64d39fd70ebeec9b0602d9e033f9d904d94ddd4307a20082825820f89a1eb82e913f8622f7891cd7c41bae2cd0f1279f290ca4825b9c58e9f407ff584061e4e5821b852ecffaadc664bbfdd905b54520c11e76f04e684b6702a3204797a85b4d3f42cb66669a66623972d102da6f2a78f55a0a5862b0c090a1675a880782582008c2ca6654c9e43b41b0b1560ee6a7bb4997629c2646575982934a51ecd71900584034cb5fb5b8f9f9fd339611a2d82ca35468863cd205684534e104841dca0632f8b3a350e91c6696576252128f338f8636925dd66e398a9e2df25614a89c2c5d0d018182018282051a017290bf8200581ce97316c52c85eab276fd40feacf78bc5eff74e225e744567140070c3a200828258202d31a953490b46557fbf04e0fb0e7705f9dab3cee0ce2fa22f03963971f900c758406610cb2766768795a580f3f8628cc5d5d2338adadacd26ea80839e6b909063c568cfef935742912ba70fa9d40823d67ca1311f4934d3c95f6fe4c558681cf50b82582030ba983cf6c77721a902ce28ad3caf6a26225dcee076b7c67a588f2c8b1bcac65840a42aef6cbab6ddaf37015bbd15edf398899013a547db021b4ec34c76b95fc59892a1716b16e78c7e6fa615683736bdb02e542803d73476fd5b126576f7bc5a0a01818201828200581c26bacc7b88e2b40701387c521cd0c50d5c0cfa4c6c6d7f090139575782051a0160029fa102818458204ccc48fd09bddc4d6e7d10ed604070d9892860a94775edcf2e58f2498ca20d3a5840563eef662d135c58221adf635b9528baa1bd1094b6eab711d6bb50e74276ae2a8dad8df90aa0f2818d7b6aac328d84a702d63ebd4c3147b840063b01296b0c005820675f15246fea49f6be73a4ce77d75a6a89758ba3f688698c423aaf301643e3655822a101581e581c3083349abf53eab0296b1250821d55ddaabd554a052bd9c1e7121aafa10082825820f227d91107250f417d8d08e8b0848dd551e976806f47693485fa79bcedb00e0d5840e3dd7a742684c8e9251e758a7987c476de67ae6e9b163b4de71179b779a270c3405e56ca591535c4bc60433b7d16108ec121bb68c251c6d50b99b1e8b15e940c825820c78ed7745cc8fd8d86a656ad54c66dfaf81cd197cfd3322b776818f79c3d42d75840ab24a626f435e7d7f780f7e6354d98eaf478a057b93fdc06830f47208cfd6a2bfb97a7f421a6bcc5485f90353377ccdff9440eba465f67edca79840740317e0da10082825820b38ee7ae9e93e96a67f9944549d6f6ee11958641312f08db0f2544a056c316ed5840239456ee7392721a8930fe3777541d016d960ef6f245462b957ea8e37aaeaabf8dd5205c3f3eda934c03c8e972ef42d95e9c0062beb0d6997efadc137ed0d80582582005dedfe50237facb1bd47e682560527ade3954156f4d2e5d64ba42a72661399c5840424eb36d43bde5ba3d42cd58e07c89d42c2b53ef909e35054514b3a6a8d433e130e778bf0e6bd68dbb06e88d214ebd5307cbf46425fcdc1fe51ffeb44aad8d0ba200828258204dcb549d62f4393daff6854dd2a28f3eb543d8d487ceaafec1f06db1280297145840955762edb9b09a88d0578e7c8a672dacaace39e35bc00abf87d0e32322e0c7f5b3c083520ce24128dd99af51abc8922193ec90ced47764d55a7e8a3442eb5f05825820ccb2a8e0155835fe7edcf1c35f779b1cc52c42af3529d11724a466ccfad422a358403bc918737723aeb333e69ea1cfca95f4ad92ad390ccd115ae5b3fe34ea2d2af390afb7503a944299885493a745cc7f60574bd17af598cc73a613cf5e0052e00301818200581c89d555c7a028bc560ea44e52a81c866088566f3a99c9989a5a183940a100828258201b811fa49076407607ef4b9e87bd359da13f6ed112419762256a7b2518eca7d15840b2668e06894a593fdd12493fc759054fb651d011dc407fa2328ad16a57eb9ce5ab489c20480b15267d2ea8438b996ee314d00dbcedb651cdf27592a555760400825820e77d357e39485ed9589d32c3a63d33850f9cefc314d340c1d53171e486ab21155840ab12182cafc4019032de1fbd0529e899cc1eb33555aca63e1615085b38f6519ecdfb71c03204c49e9956a442199c638844d01c6edca643a44b3c0c3066847708a2008282582008f696fcb5bf1592fa015eb7cd590c91b99e2a756a76ff608d0e717934c4a94a584000e2d9c20be4fbf547ecf7c2a6d8dd352241916bf19d62fc4ce9838d4aed1ba7bb0919c93e568453dc25aff5644ea8d2252e5b03c05d5191849e1d9e5c17d8058258209ee527fd46eb12e792272f8374d24166007b57fc2eb48faf44f6fc2dd5fc0fd15840ae768c24f4f4378ce27312aa91645aff04c58b9bf3b906e1dfc6a82f76decec1ab0015c6c5b8d8ef43ccdc5947903b43b1f20b724a57acebaf6bcd511480ca0101818200581c8a2da46df46082c69076335093c8062f9f1413f452ec43357f4f2f74a2008282582078ea7b4063f315d8e63fcee59d5cb174dee92530de2829324f37e0e798d0eb2058408bae6afbc4dd6610456fae075bb2e8ed1d2eef8318fd0d95f02f4e0bcb9c33bfc6ca3b1fc3c613d3f7993e47cfc7b4607e6ec45fea5752dd4221a35676869309825820898774aae3d21e44f456a6564dffc3ecf79db05e6048665bbddcfa047619191558405f0e2633b9aeeaa43bd447bf61980d17c6f0844a904fed7f4f812171055479abd0bd0d8b60ec54bd42cf331376c3c0c7e0717ab8c38fa95cc3531d3b7cf0fc0d018182018282051a016002d88200581c24a24be012b30b349f231a99280537f6216dd39568d6f9490bbc68afa10082825820ed753bf31e69892aadc7449863739438a6c65c0f5780e8af49fa2346f5f682af584022ac5d97daba0c0a91e3e2ffcaa11fc8f44a98fa0086b668d7ac6f6bf6169965b1262287859ae96862465f89c79c7bb0a20fe5e839c4d5225e22881333461c098258209826cf9e87fbedc7a5511329bc43f90134aecbe3961b8eb713076daccfba43b3584074d3f44dea445b8a2177a62d36097fe58ae2064d6e6a147643c1a0cf60e1d8be9e763368204f2a622ec835e692fc8eeaba6a1170a6a6031f6d5a1cc946947b0aa10281845820e4f5e0bce508be77652f050aaff468256db19610002882c021ebe98e4bd53f0c5840d92aa8d2092e1242a22004a51357921a296d17a2ad6ec4b256fb03331eddeeb46526f51ba2ae5656930c1bc1ea6410927c524cff6413c4f8d3af856e1c10250d5820267138883ed4f3a2f9204556597c00081d8e63efb8cfe61adaa71885f79381635822a101581e581cd8d97175289f3a4a354f2761ab97c9b2e00f6cbec6e4a38ff141b1f4a10281845820be5fef8d21addb5bdee84bb7eb39f3fb88f7d965b733155cb77deefbb011bf0e58401d8378d6fbf6774f9b2a24a5c1e002e0b7305af7079fc4d63986a04333a0327d9e52140e3e66bed0481d00a04d9d076bb4e38a2ff686235bd578a89c59475c0558202b2e17b3d36a369710e9c06e2c324047b9b800aed84c9e8468a4be492156ff0a5822a101581e581cd8d97175289f3a3053dd116121025a8883dcf8a137d5be07877840e4a10288845820a8e28ca1f0d2347b29d356f7b07971f2ac186e840afdec76602756a1bce5521c5840203d4657dc3794a4ea5133b2d95624a2485448864efe8dba2ffc07b952fd2fe7ffbba48ca6085bd1cd35d1f0af623e340280377dd630cbc4459cd54e3bed960558203f904efc08fe0c918e9a5df3f00a2f0234ebc8a90c5895fc0ed56d2a454739bc5822a101581e581cc9a4c68c6364682423f1545190173b86b84e2868d46f267b0b1e8fe0845820eb54c1c6073ced10be5313ff4cb771fb6306797fa4df4af09435e9f591cd97ee5840ceb3e1683a7d96061d48670470fa4487d213d8e9b5e57803e4ece8e589f5bd73416a2305c9e42ca863319ba758c6ee6c2865e24ba3546490b88ff499ad9c140b5820545084c11a5b28a706bfe75b31e88cf2309a24caffe8fb3bd8ff2277ee2a54785822a101581e581cc9a4c68c6364684c3cc39951df558b6bcc33ce72308b38b6bab88f86845820c858f21a704e4b5a60edde8425af7740dc6d5cf5323530f57ee4be627adb902b58407cbefb2016e2cebaefdbdd4428bdbee5edf74cf7a8c3fbed28987d767a4578996f262280db308b0a3c09898fb82ebd0eba71273b32fd9ae58d79b1db4c1b0f065820d5c265fce9714724f6c0ce8ccd5c0f43f2870323cd5c7ebf9dd5d6b8483c30ea5822a101581e581cc9a4c68c6364685f0392ff5133d2734f45aee261d2df93124dec753e8458205c3fe16d840ece3f698e5227e8711959d940a5a7ce5b8d97e4809875576154a75840269acd199d9d37a23b27f29601d7b54546364e85eccf1d1020d99d79e84f1df3d4b50a6bfc96ee9ac59b9384b882b6f46b1841760080d1708c1026ba256d1a00582045a7e755b9d830da12b309c12af6eecdc0ff50b0509d9217499474a8f0927e005822a101581e581cc9a4c68c636468719a4ba4511c4e348eba058563eac2859a0fead098845820fa256e041ba2d9b952d2906b37d1a12c6c831da2b508a11dbaa6957720dff3645840652cf665d74bc504cc823bbb7988638c789d083500cec4356271939c70ae23626e4c492a912ee3907e2e1fc7fc46c3d7debdc0a0b1b16d1c758dad0c8c184d035820dffce74909e33d00449db037fe758c430bbb79cd02d266257af5008f946e2e8b5822a101581e581cc9a4c68c636468726d933b519f0f1f57f51252c1121352f1275e074d845820ec7ca1ac472a76c5b815ab7bc9fe1826fe0df427b8c52f303c067a93b9f87ed958406779e08868ca456f3918eca343c3879d20f98c41da51396deaa058e6f611914978aa537c815d406188c87eac46c6b8a690740ff775851a96079a9283905b9a0a5820c9414ad96552aa45428d4a5f06cbce6c996f96cc6b8b349f854dadfec3f3a7f05822a101581e581cc9a4c68c63646805845d3251968049f4adbe7640106b0978e18d8d608458207a7745b26a1cfe5bdc99a2824c51e74f593339a1372ad876735cff1c751c5bda584046826bd4533fff11b80f1a3d302b782282253074ea44d8917a054320cf1bc66e5f0f2345b2d193b44bb3b43a6256e5c647c6165150a413107f5adcab962edd09582057f99e2553adab78c940831ffd43577dd2f0eac163ec2ab47c9e963968b18f125822a101581e581cc9a4c68c636468754868a4515b4434e3d50a38ea26e4ad20b6c3af61845820b5711a1e77783c41d00914902fdf1e1071bf5630e3531217b055db2350539dff5840f3d45617a985e0c5578380768f1ced09d7b04f1609b44e4f5498eb0430c527e7d1e30cd4c7f72a1035986032a70436580d0d75e1443bf98033e3a612515d840f5820bc9af5a13a266ab5d3b9516de27ef16a93cff7281fcb187d935cdbdc3707171b5822a101581e581cc9a4c68c636468352b66c1516fe689a3abb33464f690e32881d87b4fa2008282582050c17fe55e0e68fd94b3c2d6034fc3cff03e1228462ffded2c8ad04a816c73795840667c9678704afd1b3a1c3cb424816a223084f803de7942ecaea5ae893505324db6c0977e0b1fe0972fcd917ad7927369a109e0ba9b4cf1f46caea556f5068d05825820db69102d6cb3bfe0c8e08ec9ac127c478efdc4502ba95beaed37c5afd5161c7a5840636cf2854653b55aa61832d75904e957fbb671f3bcc5c6776fc4e25b6c62bdedc420444a24b352e496c2953f599f1a8bdacb8f3682b0b79321ab100af4f89c03018182018282051a01601af38200581c76cb8de7db67a0cf13ba419e7b571d479a92211ef1a51e3174f85b55a20082825820cdd4860d4805b8aba5b46482ca8443f48b09306d6ad363af6c2d16c3c71805d458402d3c2d5d485abf7c05f4e22713dc0cd73b1c1082616f3b008a4e610c08115ac8be399b5ed028605e20c058910f56e0f1b2538139101cdbddac5b4126571c0c08825820440fd71debdf48bf8b4c4ec9857cf2e58b622e75d9e9de43b1145165be2de8205840f1552bef6e910a3bd1056ed84cccd5ae0b55f38b33241607b936ec1c57d6b02e5363ba2add9225887eac3990886629fa91e28d2344ac3c20814cd7d11e7c4b0301818200581c7280bfc12cc21c024a3e8155d3d77227d7bf8f66ab2fa35d5799c163a1008282582021cb753a2984835ce919e28b61503736020d4c9560624c89b9b46c73194e60e15840f1562aae4e8f2bb0af5e584c086a095c16617cbd9a7e58dfbd7aa01aa87419553cfa5297041e5ef640bf0c446a719d25afe3ec908f209b357b4b289f6eb9060882582023bdf259b7ead310df7861c7a07bb5bb2bdb604fa6a830f3d611f46735a53c5a58409920b63962b8fcf0a91f514354696c26079778e584af55cabd1b8240ce05f77187b95fbb3f76205e12f899e3b32cc5d7bf208e3db43acd0347f9dc58bac58705a10084825820fce03faca39047cb29c9898d0ec776578c760cc2f85178dd1c0ec472b771f2695840b88d417d28e23eeccf6df921693546d14dce8b3de0c49d38c8d846091baa01de82c370a0ba6c4a1d1d6a418e50c37f5eff08df81b9d4a15b5ebe9ffe54e89508825820fce03faca39047cb29c9898d0ec776578c760cc2f85178dd1c0ec472b771f2695840b88d417d28e23eeccf6df921693546d14dce8b3de0c49d38c8d846091baa01de82c370a0ba6c4a1d1d6a418e50c37f5eff08df81b9d4a15b5ebe9ffe54e89508825820fce03faca39047cb29c9898d0ec776578c760cc2f85178dd1c0ec472b771f2695840b88d417d28e23eeccf6df921693546d14dce8b3de0c49d38c8d846091baa01de82c370a0ba6c4a1d1d6a418e50c37f5eff08df81b9d4a15b5ebe9ffe54e89508825820fce03faca39047cb29c9898d0ec776578c760cc2f85178dd1c0ec472b771f2695840b88d417d28e23eeccf6df921693546d14dce8b3de0c49d38c8d846091baa01de82c370a0ba6c4a1d1d6a418e50c37f5eff08df81b9d4a15b5ebe9ffe54e89508a100818258209d4c62563c9d49063d58654589c0cede283dc06e435c54f2597a4faf9b30b60258409541aa4f72c2bde14af175154da053b9b84e252a931228da32c7ca176122e361be6378ae107ecce95435194c0e3dac7c8d240e82ca85ced70d4ca566564dda0ba100828258207c27fa62b817fb421deb2aed9c76a9deced7d03d2b32c0419e5e7fc90b858513584078c97069282cc4e00efb1003c686441984405bf40dcc15a3ccc707e6849d523f773db3bdacf7e768b9cc26b1d78e9059cafa3925c3d9940e011711251b37700f825820375ffb7fe50482cfeda9d3a2d26d184e06cbe246935e3d98b609e4c68472fa195840e4a75ed875b291d6c2c918305ccff3198f52d55e11faa1ff74e522fdd7f82b9cd0e119664b107431e3bcf8819da17ac54e975dee36f7355facca9fb79e20230ea20082825820ef47ad69c3eafd8730b72528d067c0aebb5ad435132115db32870a75841d293b58403e334468bc1f6b4cdda952d2239201b9145f24b55d8f04929ec22de4ecb4ca2f034305f23a95ead8d59bcda67c3e6edb4143f0cb22ea59995c0fb6d1341a5902825820a18585026186b87151b1904fc92814ac123381a51b95d0eb7e63bf2cb90fda5e584021772d357b738f132f325b75494d96c1a2ce4b67e09f14e23dc424580f91934044e1b082a321c3255118f7953733e2992319fef5be4c5e41563c5f5b87c12109018182018282051a016002d88200581c3aa01dba02b37245f31d099806af04bb883214181fa899f5e8d34c37a10082825820fd0516dc3af9a6abfb58d34bd7f1f61a3e3985dbc1c961261729ad2565cbc64958405a14afd8f15e3c82119742870326dd10bc7896f2c109b23ca2a60e8122f58aeefe097b97ac4fa607ec932e9f26d8f0426a26589f091529db7cdc506bd320c4038258208e8ca43e788560e538f4466e8e6ac2c5561601240d74add435139a01894978e458404c72d48e6194f5380cc9964c6e35c1a20ae1be4d4da0ee8b572aa7ba788d5ff3fe3295537e2a86197682b46c56398ef5fae2dfad153d7434ed2fe88e1c99670ea10082825820ccec76e927c17585fb12a438afa5e8af7979514bb5a6a0399abb912a68dc6a895840518de30a8ea8f1c517b3e65b6599d14aefdf4f16761a0cbfb0f797c37c71518140aedfe53551c0e594164a180c84b1867351e73162076f87eca426ae6fc2fa008258203afde5867e367af6280f61713e14882fb9965ac99e5ae9e260d2e905294a62d8584037b174ef648db16331ee3c5a337338a94f5051609544a2530952660275c39c63bfadd4eaa86b8b02c1f59d6705ece3f76a86aba8b26e24c709b92a27054b9f0ea10082825820d63992be99ce336eb25748063d781f6a22bf4f1d13dfc401e609d015ea09837f58405ad8d1e444f7a19020557f2563f244ccadf0550ac31dfbaab3d8bfa6ea173fd99b9301ecd64c372995a17ba060f0181e2633d64f09d36ebebf057d712745350f8258202c726f5f75eb413f1bdeb572c7a7f328bcae6afa7b6de20d54ad31ee5f928ac65840cdeb471a5b6a0e077cb5c388d9b9f49d9ecccb8282f70c26b4c15391cde3f19181ee0c7b210e1de06b8540174c31b8d46b72f390b5615ca85e31b09b7957530ea100828258203ce587600b2a7370ee206b9d08f18ca9747493b6f2988e5db3b7ae445757fabd584054e26e2b04d2a0926bc999d3f72d6a88dd328cbd4a69b05f7018e9827db615ee0450bacc606740ec9b1e1f51cac61e73625c6ec27e326775927bc0209384420a8258203bc6300d4164c47737e8f67adf30755ac6bd72280f4ad7c9082492828e28c1b85840bad030e1e24a18863586c4fcd0f15ede43a24194a455a6b28e57857535d14ff45e94a458f05575f7a3bc92a16408cfb91b10774a83d03fbbc54bdc667714ac07a10081825820e13369244c8ac7cb81676de7e36850aae32e509803ccbd577b0ead0fd1a58fb658402eb22bff087d4d7cc5a7174863488a1d4ed50e009174ef256db73e36b54ddf8f6924e837568546624b873978978704ecc5696fdef468b42f02f21f91bc29290da100818258205fd9b2c7614d0e874ef2391728f5a72433cf9cba3c41af219cb1738ed219ceee5840257626783e4e7d22a43eadca34e763ff7c78489b62f9ab46cbb23e00c240c54f7b759585fb273a270c54583cfb9d43f111a4fae1564f3ba069e324504c17de0ea100828258208d1651b48e72722ddd7d7f60986b75e6f282967380df9962896071fd54cba4d058406b3cd308cae001c31110a5641e716444159aa21662ab9c8d1810ac5feb3d306411277519977181c69b643fac8b79d8176eb45cb6c4772f53d231177e0989d20b825820d4e9af2b16ef0dc54bf16c9a4c400d73ead7f483963cdc49c361e2dc831ef7f1584010014ff505276d178f44b95be18ee408abc01008b66528884efd8d2593ab6da568c81c0a22905668974d4e1852b263423070fa56546e6109cef7294ffca19b04ffa3181982a219ef64a3015820f2cdb189a3b0fe5ef9672cda9150138d4cce341fef699a50c1ceeed2858e41b5025820cd69f4dc88aae3bd93425b732d02ce70e02be137a5b602d51b3bd271ae779f450358390145504eba43c81ae5d52d913cbe9296bdb99f9be26ca04d7c5c772cb07e6c95ee0ce36bac4915d1844fb6a26825c10735d79fada8e382fb6f19ef65a10158400a8f985f591e3e1b6c733cb2a8e3e5bba7743639cb93acb0ecc328f19057e6ec2369a2cecf0599284aab759cf635c638368266bce4457105d9d1a5009f46cb0980181b82a119019b8378295468652054696d65732030332f4a616e2f32303039204368616e63656c6c6f72206f6e206272696e6b781c6f66207365636f6e64206261696c6f757420666f722062616e6b732e715468616e6b20796f75205361746f73686980182182a219ef64a301582015f305f200182cd51c2d6b21c145f867d5a1b48f1af064470284853183157147025820f57b7bd8b3473347b31fe904684b7c3e30c686610e976f0e29de2621097358c1035839019f857b5feff2b13e90142bb4ac20b95063747e1df8ea5cb8b827f8b13cc658afae01d34253a65ba78b47f1641dd81c01e9e7971da00588c619ef65a1015840b9a388a8ca0d6d9242f8968a77c5c5215a5738c5c3a02e8a74656c3a2ce6fb3dbf47e3e00a0d82d7f6c4db5ece29b2ca506d31218f23a58020b6f7ff68cb0d0380";
+
     #[test]
     fn mary_network_block_parse() {
-        let bytes = hex::decode("820484828f1a0052801c1a016001265820a650a3f398ba4a9427ec8c293e9f7156d81fd2f7ca849014d8d2c1156c359b3a582089c29f8c4af27b7accbe589747820134ebbaa1caf3ce949270a3d0c7dcfd541b5820a90a190462fc24c92f5adb29cf3bca2aa060c6d3ae5d7842b4e9248ed19c1a3a825840d2faeb342bc9ac044821d9e314459d88dfd77a707aaeaa353ab274b532a21c6c071f9ad8521367e7233ca85c52eedb9a119d741645952dff57c1253cced7872458501b0f56358eb58e2e6f92eb2ce894d2e938a39d7502315526460b9b7ca8edbec4e37e0c0261cb0e878e1a88a6aa1425e026d28efd8b899ce8920663068ed499bbfa5438bfdb668634e295caa5264154038258408b048dd6774460ba3413d5fdd94fcab36dd1fe1452035ed8dbd3a551eade6f137415a15e68039af8393253a60e2eeb43b1cfb548897512f38fdadf2836e23ace5850809bf118c20c5d1b656a54ec6605e30b305f58ad9110a3fabc4d1ce0961448f0b5c10a0fe6a2132cbc36e90041280812bd8744a64a75e10a48a47a90aaeb8729655ecd978fb89c5966ce4d861161b80a193f1c5820669896257fb55857bf716873c9a4b6cca319aacafd9680f482fec6f8666f119f58208cada7e2b5f6745ad1365a84a077309a1224be0fcd72a1797aa6837160b74c520218ac5840d342d671680b51f4b6d41d329d320db58c5ba02cea9ce68eafc8a998724c5187f8599f0915b89cf13e7294b101af482f21ffc52e4247a3f2a02db8bd5853c60804005901c0b59b277aa9c4e678274a6dfe4d8adf76b1d80c0122940c38b6f6d13e65e0c0816f2bbe29831620034a5c904d814e3508d3f1fad63f6ba46c46da36844a320e0e0f7df1a6d6fd3180c1ddd147a526e52485797c575a43885f7d6476745587b53bbb450683e07e2d3d7bb70cb468cf04aa8a497367cfdab2fb62ebd72769894f8baa3e1ae4f30ad5b6fc0a5f256c90623182d673862b8e4a969dae96d6868593e9ebdf144ea940b17b994a2a4c4f0d807cf9ae96354e44cb57ed8cc4007969d4848ef1f32da01f5f74c598cb871a1dfab37bc8a676333e3a28b85a329a022b7dde840b4416b7f93db15850150d311576e0fd4c750544b25fc7f2a3a049b4be53e7b9e35f0046edef313073c7a63d8c90685c519431d8798aeb8b418360de4a8a52d00cb1e6a672016f41706471e3c729e462eebd4d102878bc5568b4345f8a15772dbcd704fe70293a91485b2b9f436682bb7813ba73a754042f0faed80392e7eabf5e2c4150cda9e13e518b89e0e15689f2e6e8f75228266527d02f747509d5b47a85313697dfce521fb4781888ff88dd6075128f8955ef5f2364bb006c86424d902581dcf384fd5a5f8ca6c56e1cb6cac4e0099c9c1cd3529b20acf1176f2a0a9fa50082825820cea49c588ff5c48394741f8f6ab9f5441f0cd5cd7d09103a1e435ec9c6f4987900825820cea49c588ff5c48394741f8f6ab9f5441f0cd5cd7d09103a1e435ec9c6f498790101828258390131c44f81799a6550aa93224ccb95de995ea0c210daa346907ecd744531c44f81799a6550aa93224ccb95de995ea0c210daa346907ecd74451a000f42408258390131c44f81799a6550aa93224ccb95de995ea0c210daa346907ecd744531c44f81799a6550aa93224ccb95de995ea0c210daa346907ecd74451a3a57f33b021a0003096c031a05f5e100048182018200581c31c44f81799a6550aa93224ccb95de995ea0c210daa346907ecd7445a40081825820c7993fb38a504159edeb4c5d256034af8f6844e1bac1bbfe6a3dfa174edda64101018282583901d1e9744c80ad49dbbe6e1025571955c9585f0f05ff3245113123ee18b5cd29eb688d274dd12e80ccb3e9024577ad381640217f419e62d3e21a3b9aca0082584c82d818584283581c17037e3f8f2d06e5971ad9bd6e067b3934ebbf7bb75c1f90a67a1858a101581e581c13354432f02c83669eab035130755a29ef6e6956a5f94b2917e7dac1001a01ebb7bb1ae86ceee6021a0002a56d031a01601cb3a40081825820962ec940b10ff86b3a7b888c2290c5f889e8d0bba10dd6441b1fe7c9b988295d01018282583901e87d31beb81288e70fd7c5f7289eb63e32842ddfd6e8832483b1c1dac411474c6d6095c5f5bcd1dec91ce5e5de9dc5b04eff330a9389a0e21b000000028257646082581d6182200447d8bab38065e5b0cc2161a195e15a8e46009292e56c5205511b000000795e56e97e021a00028d31031a01e13380a50081825820205275e9b2dc141b2974230ac9f5d742c08f71aeec34ef8cbddbc9b9ac7c811e05018182583901b0afa1daa28b7b8ebfd03bdc3143aa4fb080e9bb30f0ae4e05a7e81abcb1d2a6776790d3d161032e2701a57a5239db241a83ccd1b9a007471a13a3a597021a0002ac21031a01601cb8048282008200581cbcb1d2a6776790d3d161032e2701a57a5239db241a83ccd1b9a0074783028200581cbcb1d2a6776790d3d161032e2701a57a5239db241a83ccd1b9a00747581c92fcdd76d1d9d13910cbe1930765543984e6f4ef9b7efed3cc6536a1a5008182582084bfc702d8530383ba64eba7a46883512f27d2042de6da21d28e0f430273e51a01018182583901e28e7c8bea3c201f7083aedf848b6cf2573360f9c841c547675e45f9a06792f94c463a823cadf5fe8276b5fb2746b5116970052b42b626f41a007765df021a0002ac21031a01601cbc048282008200581ca06792f94c463a823cadf5fe8276b5fb2746b5116970052b42b626f483028200581ca06792f94c463a823cadf5fe8276b5fb2746b5116970052b42b626f4581cd5b90a198d2411b5c82fbdfee5f94b86de49a8589bfa7720b9de358fa50081825820fe223726527c92a5a1b5787d8b38c8c1393e52d57156a5cce80f7453bf131fae000181825839017e3b076b30e6c3823829edba5c997036164f66f05b5d9b6c16c7aaec425aff7bbe040f54c7379d84ff176827bfb9a3ec848778d3efe243541a0794d316021a0002a649031a01601cc0048183028200581c425aff7bbe040f54c7379d84ff176827bfb9a3ec848778d3efe24354581cd5b90a198d2411b5c82fbdfee5f94b86de49a8589bfa7720b9de358fa500818258204fc7dacccc18cac885db1e6af59731a3e341eef8cbc5ead540e4bfb5687e70e40001818258390185a2c9f1f1b7de2ac3dbcc4a4f52280ee56f222ca903f6bd3864ff8e212bc6c241fc8d39bebff6a0befdf7c138c3409bb1a2f4958b5c723d1a02d71996021a0002a649031a01601ccf048183028200581c212bc6c241fc8d39bebff6a0befdf7c138c3409bb1a2f4958b5c723d581cff069b3ecf738d4adfe83f7c20c64b82f94f7285ee41eedb2bfd297ba5008182582018ef74bba9688ce8462c9454a9e5bc7a76e7617b909c46f81dc4dbbec1e552ee0001818258390115fd6f69054f0c262c40bafc34e114e0f7e7fcc31ca3933768ed42224e682ff9ed90c336e070535254552dc9688343e337d629b6728ea17b1b0000000129d2de56021a0002a389031a01601cbb048183028200581c4e682ff9ed90c336e070535254552dc9688343e337d629b6728ea17b581c490353aa6b85efb28922acd9e0ee1dcf6d0c269b9f0583718b0274baa50081825820d9a8ae2194e2e25e8079a04a4694e2679464a4f51512863a0008a35a85762ff00001818258390187be75696aea59b41b36415d0b7c8b7a0c21fe3dd5a63939acfbc3a0285f7ef46037e7fd61f04d19b4cabbc7ff7c2a7f8279a11f50422a95821a003d0900a1581c00000002df633853f6a47465c9496721d2d5b1291b8398016c0e87aea1476e7574636f696e01021a000f4240031a017290bf09a1581c00000002df633853f6a47465c9496721d2d5b1291b8398016c0e87aea1476e7574636f696e01a50081825820717ada10af06716ba68f6289396079a70abe95768fce36bf79c3ca6195a6b9300001818258390147d265cf01a2f56fe8506f3c33ea576db7c225b5d9d8d7a527b937f4f25eb703e8d3c42abc7ceb73e664aead7ccd3cb69e9c805a3be583d9821a004986e3a1581c3a9241cd79895e3a8d65261b40077d4437ce71e9d7c8c6c00e3f658ea1494669727374636f696e01021a0002c45d031a0160029f09a1581c3a9241cd79895e3a8d65261b40077d4437ce71e9d7c8c6c00e3f658ea1494669727374636f696e01a40081825820bec130129321240bc13ed66180a4e9d6b02d8484092d253db96df7ff6dcc8daf01018282583901566761ba7978aeddc6563246b72484a7daa234e5a2be38c111337a9ceffd4195b60e4b3c4887004c216805e8cc92a661533741332b4e75c41aacda7d0082584c82d818584283581ce627a88961c4397b233b92265eb8cdcbd5b99f21671ab0e794b7d824a101581e581c3083349abf53ead6314f8c5024331084c69650e4671c4f4f93e3f3f8001ada0a01ed1b000000f3e5242a86021a0002a56d031a01601cdea50081825820fadf5b93f8c4eb070a1a61b2b4e90db5f8ff469e30032d079c02797e98d8b1fc00018182583901afe3fc534a6fefb90fb878a21344fe5009cb1e46903d26f5df47b6069965122454f8bc00cfe28233b9ef1fa74d70ce37e87a22a336b0c8311a03da7fd6021a0002a649031a01601ce8048183028200581c9965122454f8bc00cfe28233b9ef1fa74d70ce37e87a22a336b0c831581c42c3740db23c3fc36cb9f63aa8070ec01417b4d22893463c8c8a21d4a50081825820966380d07c594d78a1a0b413de89d411409ea84fac9e3bed1e67cde49fcc362e181b018182583901537e62a502a70427e04a7897f5bd83321f6a1d81661f39c555536609cb6633dd90135e13a7e92001f79ac10645f719c90b3e13ef55a2b0671a23b15d77021a0002a649031a01601cef048183028200581ccb6633dd90135e13a7e92001f79ac10645f719c90b3e13ef55a2b067581c000000f66e28b0f18aef20555f4c4954234e3270dfbbdcc13f54e799a50081825820aa3462c62eaf606034a9da1829c2bcc487ea78fab9c27ff68e25c813c27ec96b01018182581d615383c9659d70a3c8f70c9809b94f3bcea3594c2abbf9b3bdf3dcb22d821a0020bf46a1581c02f68378e37af4545d027d0a9fa5581ac682897a3fc1f6d8f936ed2ba14541544144411a000f4240021a0002be2d031a0161872009a1581c02f68378e37af4545d027d0a9fa5581ac682897a3fc1f6d8f936ed2ba14541544144411a000f4240a50081825820fad00fddbf64b68bc6001a0a7f3d02b2ae98bdc75c09ff93af058a3bdf0d780700018182583901f992fc53ddc5a14748e2b232ed73ec48703f4575371af8ff8df6c28888aaaa1c15fce4ffada3ef15dd73a2c9f6368ff26f091778902de9c51ab2916075021a0002a649031a01601cf0048183028200581c88aaaa1c15fce4ffada3ef15dd73a2c9f6368ff26f091778902de9c5581c86a19d5418cd07294af7d71386cf14a84e0478fbfde5b89fe4d98a58a50081825820aa3462c62eaf606034a9da1829c2bcc487ea78fab9c27ff68e25c813c27ec96b00018182581d61a12e3551371670d5d44a5fe3c319d1f8f55ff06404fff9cc99a7a675821a002368d3a1581ce8e62d329e73190190c3e323fb5c9fb98ee55f0676332ba949f29d72a145464952535401021a0002bccd031a0161872009a1581ce8e62d329e73190190c3e323fb5c9fb98ee55f0676332ba949f29d72a145464952535401a50081825820dd0619f4d7773d96b4a34b8a514f89c6df82b978da73bcf6fe973516bc0ae43800018182581d61ba91d25d8a27dc19a3cf37f8226ba215bc21bc199b18eff513b11fa9821a002b08eba1581cac3f4224723e2ed9d166478662f6e48bae9ddf0fc5ee58f54f6c3229a14443454e541a00989680021a0002bdd5031a016002d809a1581cac3f4224723e2ed9d166478662f6e48bae9ddf0fc5ee58f54f6c3229a14443454e541a00989680a50081825820d0d07a3ecbc6ecb9b14d7211683805e82961403ddb8a416dcfc9caf231c6be4700018182583901b3dd7745334afca9aed1422975fc56dbf02d0dca784f0354c48e09d519cd43b2cb25daf869335fc988eeae8914859525a6fac0bbd1c6c3f31a06e4b296021a0002a649031a01601ced048183028200581c19cd43b2cb25daf869335fc988eeae8914859525a6fac0bbd1c6c3f3581c42c3740db23c3fc36cb9f63aa8070ec01417b4d22893463c8c8a21d4a40081825820ddc7c5fccc3f5a401b84bf76e8b2ee733bacbb55d1348e9643f2356c5857b7be0101828258390171aa776aa9070a4f464e2177f714922a2e200a9ee8d3a6fa8298d752cd0815b4fb0047f18ca403069546f672bb782e341085a9c534878be91a00b8bbf582584c82d818584283581c956364006fab28cdf4128a494589460750d980246d5b926ae5bf70d8a101581e581cd8d97175289f3a1d7513cd61b4dac6ad9324abd85fa1f563e44c3538001a1e828a601b000000013985db1a021a0002a56d031a01601d07a40081825820f757d89498b8cdfbed2f0c309b2a3253d94c18c0f586823f0864ef6a82803c3a01018282583901166347f6af974dc4a27f537891a91e17117524737ec3933eafbfeaf8d57703b78a21c95990c537df886d990cdabd223361bf4a1f6bfee80d1a447dbc2382584c82d818584283581cd213ed895eaccb222835a0e5dbb2122d7ba216690767ad526523aa7aa101581e581cd8d97175289f3a44642b7761173cbe9cfa81a2bc0a18ea4c8d642774001a7cd9bcf81b00000001498c3a85021a0002a56d031a01601d08a400888258202d5609e8b9db3466002110c218194f56fe925ad28a7bca415af5d3e133e14cdb008258206714429f3418b637106e6c5379a9825bde2c87303ca2860b4d5dabe2615103e9008258206bb277e875aede63feceed2db8f2f13f5aef438ea856ace877fa888f686446ed0182582081eefee68c890a4e078286e06362d16c266d37337fc8023043de1669d6164e7f0082582091fe40cbab55d1a60bba9653bd4ec9fc438a41dc8ee79b5f520835b603945fe500825820d98b4fec924debfe4e876fb261619c881cc80e7387347be426c4071660e7dc5600825820db1cc1172030e556a79865c9626649788e98fad289beed5ebdbc7cc9f830e5f400825820dc8f14f65efd45361365f05e358577e203df26465f58142d8bc95a66fe12168700018282584c82d818584283581c422e36209e2c33d240c71c85360f2305486fae2833344b97596bf9dea101581e581cd8d97175289f3a14d655b2617089ce27d2df668f2901bc633cf7fcf4001ae4ee58001a8e33932382584c82d818584283581c694e4bd2a5c3efe1f01a18b8d3552a17270918def72b2a5bdff1e9a6a101581e581cc9a4c68c636468073fa4ab51d4929ad5d81a53926f36130227f6d96f001ac1af646e1a71a5d5e1021a0003b161031a01601d09a50081825820aa3deb58e2bc6e239daac2026bf61f4501f027d9a4c093ee4d06470cc01bdf7e0101818258390121107d5b4400bdfca0c9c059192ac30aeff89c41e4686508227815ae2ca29e25bdd48979b0c887a45bfb7e73b472bca2baa96c848ed0d24a821a04453bfea1581c12e65fa3585d80cba39dcf4f59363bb68b77f9d3c0784734427b1517a14454534c411a1961cb09021a0002bdd5031a01601af309a1581c12e65fa3585d80cba39dcf4f59363bb68b77f9d3c0784734427b1517a14454534c411a1961cb09a40081825820225b984b3573c95a24470387c6fba632a000e364b06ffd5da34a3a82870a3d58000181825839013780f8f1bcfd0ea42ead706430a5d80b395ef2ca5154051b00893c25fdead883eddc968d0f8247b53b1c518503d6fd172994a6c1fc9effcf821a0095ec43a1581ce12ab5cf12f95cd57b739282d06af9dd61e1b1dde1e06f0c31f02511a14667696d62616c182a021a0002aa3d09a1581ce12ab5cf12f95cd57b739282d06af9dd61e1b1dde1e06f0c31f02511a14667696d62616c182aa50081825820e770f1bb246fa6873f78c6f8c947a591d4d4173b27529b8e5df074137d66be190001818258390141201fc5df296def03b966aa58b1050c13cdc296e119db8ebc33b80f09e99f03d267b383bb0559fdfc9115d10173b2d416e837ee63fc9b3d1a21cb22f2021a0002a389031a01601cef048183028200581c09e99f03d267b383bb0559fdfc9115d10173b2d416e837ee63fc9b3d581c50a251d84927d932b9393cb462f300d215faedd5e8a5e791e869f32ca500828258206a9c72ca6229c756d6527f8abe2784d76fc8f95a2d7dfb918a0272fdc5e09583018258200c111a4ff5e61e2a7ebfe30139b0ba1995e072386f2372db15831e1f318af6160c01828258390190eb605dc7a6c460afc560592eb1ef0d1bb274252f00a6492d9ef25a90eb605dc7a6c460afc560592eb1ef0d1bb274252f00a6492d9ef25a1a000f42408258390190eb605dc7a6c460afc560592eb1ef0d1bb274252f00a6492d9ef25a90eb605dc7a6c460afc560592eb1ef0d1bb274252f00a6492d9ef25a1a3fd220b1021a00032dfc031a05f5e100048282008200581c90eb605dc7a6c460afc560592eb1ef0d1bb274252f00a6492d9ef25a83028200581c90eb605dc7a6c460afc560592eb1ef0d1bb274252f00a6492d9ef25a581c9a22c008779c455a124d86191047d35690a0ca2c094a34d90cd29542a500818258206ed4a57078193bc208f9b7ad5040f3c666e89a14c268893490359f872c273ea40301828258390145504eba43c81ae5d52d913cbe9296bdb99f9be26ca04d7c5c772cb07e6c95ee0ce36bac4915d1844fb6a26825c10735d79fada8e382fb6f1a000f424082583901f4c5adab179dcbb06314633643132559b421346805a0f38020af69737e6c95ee0ce36bac4915d1844fb6a26825c10735d79fada8e382fb6f1a159aa257021a0002bca1031a01601d100758201f40e487af7997c67a6a995e527b7d1927b4087ab797df436e360e8ea5e2ee9da50081825820f73e2d9c031e73f28fcc8f9c0ea37fb7201856b99e96f73a804b46b8b5059fe0000181825839018b86dd71fe43380954a2572c0bb1e708588fd63d6b6bc881b6e6bd939a07e9543e94c9898da2f45263d8c874584b1f29d02297ec9dd506dc1a381b5e46021a0002a649031a01601cff048183028200581c9a07e9543e94c9898da2f45263d8c874584b1f29d02297ec9dd506dc581ca89b86373838360143aba3911eaae54bf9420868965d48d447e4510aa600818258205fdc3570b9e1669d08ca01b7deaf005fc57a710fba68d5665e2572c9ce07bb2b00018182581d6129fd280480232d72b2839fcf47275e790a1a3a45dafafd8f64180612821a0696e523a1581cda8c30857834c6ae7203935b89278c532b3995245295456f993e1d24a1424c511b00001319718a5000021a0002d535031a016002d807582012acb16d984dd3c6bacc700f9b3a1416e2ed82270549d4ea62c6cd4d744d723109a1581cda8c30857834c6ae7203935b89278c532b3995245295456f993e1d24a1424c511b00001319718a5000a500818258200ea7635bc09fa06f28272f7d04a4a2edbb92def0c3414984008dfa3dd4b904a500018182583901aed1f8c7b32b85f3f52585be924ddf41ae5ac66c04b02d36c6f3fb1f9d79c247f12202e2f1c84c879435579d559c664951eadd57fac729a81a04dc25a3021a0002a649031a01601cf3048183028200581c9d79c247f12202e2f1c84c879435579d559c664951eadd57fac729a8581cd5b90a198d2411b5c82fbdfee5f94b86de49a8589bfa7720b9de358fa4008182582081bc69b2580f46681c7b2009e5d7ecae6b748eb3fb24379175293239238bd2cb0101818258390132fc37625b3ba6b080a8e4b69c124ee6f97194d8c7cabfbf9a3eda844585a49e9d3a5fb1ce7ba15eb49a5e1791dd73ec7c2ee7dc23f7c92d1b00000001043fb9d4021a0005d8e0031a01601c99a50081825820ee46783cc668263b76bb20a60e7d2b3c7174f70d2d23e4a60ac5dd528d50cdc900018182583901921b91c4faa9e77472f6d8e4cddffbe18dfbf3a0dee2ccf27d606cb04a75a86cd544df6cc79fb212713acac422570caed537e14c1d6cc0821ae66072c5021a0002a649031a01601d02048183028200581c4a75a86cd544df6cc79fb212713acac422570caed537e14c1d6cc082581ce0bfd78fd14b0336186ff6ca79a45f3619102b5bb484f7e0124dfdc0a5008182582025869369a73f07dfc6653f3271dfb6902d9e2233d98aee64a752fe4a5a070bfe0101818258390183bf1e4dce6e2a14b20b911c00f531ea0ed610e35f3a51b837c7883da0a141f63f69a266b657e285ee53f3ef6accdb1c5cd463408e35a5421a007765df021a0002ac21031a01601d35048282008200581ca0a141f63f69a266b657e285ee53f3ef6accdb1c5cd463408e35a54283028200581ca0a141f63f69a266b657e285ee53f3ef6accdb1c5cd463408e35a542581c9dfeca095f80562460252072fd8438f08936ef5a2077583a9722bd97a400818258203daadb9446b4a53d95462d727a42d517fcdd92a175486d03a40f5891324c71fc0001828258390115acbe7cfe2ce324b0a71214f7e19efa271e25462de521f41e92c8c3b694cf6923a844158166febc2d391c262f239577c1f521c8139ee7771a0098968082583901caf238555ab52df3659f6c71baec4fc76974dcc6d5d9120cbf4ac780fdccb0cb3af2a1ab6f4d39aa2f56b31b24148145602085deb4141ee41b00000001110a3e0a021a00029361031a01601d3da500818258208d2bce88c994dec05ab0052efd58d79619c2ee5b126be8313d94e14ef5bed155000182825839019f857b5feff2b13e90142bb4ac20b95063747e1df8ea5cb8b827f8b13cc658afae01d34253a65ba78b47f1641dd81c01e9e7971da00588c61a000f424082583901fc8684d555c76d67bb319003dcff7bea8ec1bde809605c45e9f218af3cc658afae01d34253a65ba78b47f1641dd81c01e9e7971da00588c61b000000047699b9cb021a0002bca1031a01601d3d07582076eaf2130c41de3827d3adbaa9e32ca3871694c4ad06695ad059b6c65dbd2925a500818258208091d82d809278eba8f7c106e484222fd17ccca4d6dd06918010f7a725eb675c03018182583901238c5a04f5fe82895cd8ba8ec0f97c5f7df2831ace50c3ff6e9a866ca836d3b75929f3165a76fe83994ece87604a3a54332ba1b72c8f55991a7355ec35021a0002a649031a01601d44048183028200581ca836d3b75929f3165a76fe83994ece87604a3a54332ba1b72c8f5599581cd4405171c1595c338c7ce5dcad8b4ed676aa6eeb1b28749e622f48e9ff9fa10083825820b578ad44d4b6dc61a2155a90fdf469fb22fd13428204b38f873ebe830c447ac35840750b4b9b81201e1113a5245156918733a8471ce83f5627ddcafcca9b7328e40b308e66762cce1c1ae4c60b37f50a6f54a995b0bf54c18800a3dd73e3ee03f203825820b578ad44d4b6dc61a2155a90fdf469fb22fd13428204b38f873ebe830c447ac35840750b4b9b81201e1113a5245156918733a8471ce83f5627ddcafcca9b7328e40b308e66762cce1c1ae4c60b37f50a6f54a995b0bf54c18800a3dd73e3ee03f203825820b578ad44d4b6dc61a2155a90fdf469fb22fd13428204b38f873ebe830c447ac35840750b4b9b81201e1113a5245156918733a8471ce83f5627ddcafcca9b7328e40b308e66762cce1c1ae4c60b37f50a6f54a995b0bf54c18800a3dd73e3ee03f203a1028184582028c7cb101f30cd81ff06f70b4df7f6f166f49449377081d07cad661562a23e2d58409c82b395b8720a15abca4efe36f5df6d0d3811e2088f980dac5cbfffa66c47697c5e9e556bc0a7bf422f038041e893b400104fa3fc81a74e3ff1721c3c5305025820eb49dbf247b0fea00b40ef47034b8931f57a6384971b631374616b73436715bb5822a101581e581c13354432f02c8338aaa56251a8ee28f360eaae7a060dec8a8b11bf6fa100818258202ba92a9b10742d8f4b04c9b3a5a282a3e4214698ef8bee30c4d689566c6a8dd0584039368d754835ad2067bb93e00948e643be47a0ca68674a5b39db6677f60871bfd8ee3612b1fb8156cbada416092784bec6d43aac0991c0b67c0f6280b133f707a10082825820b1b1c2ae30c9861bfbbc51db4a78169cc1c8f7dd5a81749d4d1b6a037a033a3658402032d883b5801c34b2564909f140498b8a77e8c2851ea35301160947364322a31954fddc3458f0b4677656b4895edcb08de614b5711f8c3b153214247497250c8258209d556eac9b29811592f4406d6005c16bf21e1405943f2643fd0754d13a0aa194584083399f7211d58dcea512f91b9dfd64e642fe70e7c2e2dd0c9b88fc51bc859e40e59f51714e1c9435dd78bd0d4b389b8ca7fd636732ed7b1f29d5de933dad7904a10082825820e2870f5b73495d7f7c8db1307ed93ed54459e074b4b55a4abdba1683fe1a5a4c5840ec1e7f11860778e705f0c023aa03738dc13b550da9837009b5d166e99ea2d609a8403de57dbb9003869e57b2a791420c183cbbac7e0ec3ffdb563364f6a0fc06825820ba474effb0b033fbae49905c08a51c55e384b3b8bb8089734ed4cf1ec88222b3584057318c5a2e7e5228c2b849cd9b141ed322bc04ee3533d82987ecc0957c57cfab646e2be1df7f60488a22b849379f569f7fb4f2e6201f3d22ebd8617670b3b901a10082825820469981c33fdc46274aeb96e344a2c8bcf0a57cdd328c3a67da8043b3bf491fc35840a9b31dbe11a5d25f6f64ca5e669f44eab13deef745202ac5a33c913947a2f768319b45e1c65b3469cf6055201f5bd17f93d2602262ac813c8df497cb9f41de0d825820d0165aab74c2263fc0dec5e5d6e4b36247b9fa8d9b5953ad796d3bf84c9d8c535840375e888c44c704aea99e28311c88820c1055eb3a8c4b3da9e74744c622d14404295b2a1b6613c38b86609e3a629eb3e6202397b18a440b51b02747b125527706a1008282582018652291879bec2d0844ee0956592b130805d61847faf89f431839c9a96dc1545840ed4558b9d054044061160af53ceb2557e2e2bf2606f1175e0dd51c491b6693bd1ceeb5a78d1147c2b5dfbb43c5f2eec7c3a4b5ec8406202b1bda4615278f1d0a825820db7125531f7eafed4aa8836e71bddc711ddbcf5279c44eb584d977914c2b66f75840b1e9156985dd52a881c28c0612fb35219963b41597c5c3c1b069d93058771aa078dcc6da0a5df4e82b2a8a4fcd5ae218f805f440cab75be95effa23345727401a10082825820130a7df57db7a74d4da0f0a98a2705f42e83f9d2d6b89ed09b08f1b85d27c0d0584051bba58eab5c549b7e71333f4666121c92571788f6f5582ae2adbeebbc41a6c3ccd9156b56326329d432a6f0f18fc3189f8ce2902809ae2c6de52a54a136f5048258201b44e7c87d8ac2de8d0bc266329d6b90349690d47d6363d984121e2152af119658404ae4ee3a586a2d6740e50e447e5606fcbd302a6edfe5c11a4bcc7e097d1d497ea4b8c0f5dafc9c934ae8ae64d39fd70ebeec9b0602d9e033f9d904d94ddd4307a20082825820f89a1eb82e913f8622f7891cd7c41bae2cd0f1279f290ca4825b9c58e9f407ff584061e4e5821b852ecffaadc664bbfdd905b54520c11e76f04e684b6702a3204797a85b4d3f42cb66669a66623972d102da6f2a78f55a0a5862b0c090a1675a880782582008c2ca6654c9e43b41b0b1560ee6a7bb4997629c2646575982934a51ecd71900584034cb5fb5b8f9f9fd339611a2d82ca35468863cd205684534e104841dca0632f8b3a350e91c6696576252128f338f8636925dd66e398a9e2df25614a89c2c5d0d018182018282051a017290bf8200581ce97316c52c85eab276fd40feacf78bc5eff74e225e744567140070c3a200828258202d31a953490b46557fbf04e0fb0e7705f9dab3cee0ce2fa22f03963971f900c758406610cb2766768795a580f3f8628cc5d5d2338adadacd26ea80839e6b909063c568cfef935742912ba70fa9d40823d67ca1311f4934d3c95f6fe4c558681cf50b82582030ba983cf6c77721a902ce28ad3caf6a26225dcee076b7c67a588f2c8b1bcac65840a42aef6cbab6ddaf37015bbd15edf398899013a547db021b4ec34c76b95fc59892a1716b16e78c7e6fa615683736bdb02e542803d73476fd5b126576f7bc5a0a01818201828200581c26bacc7b88e2b40701387c521cd0c50d5c0cfa4c6c6d7f090139575782051a0160029fa102818458204ccc48fd09bddc4d6e7d10ed604070d9892860a94775edcf2e58f2498ca20d3a5840563eef662d135c58221adf635b9528baa1bd1094b6eab711d6bb50e74276ae2a8dad8df90aa0f2818d7b6aac328d84a702d63ebd4c3147b840063b01296b0c005820675f15246fea49f6be73a4ce77d75a6a89758ba3f688698c423aaf301643e3655822a101581e581c3083349abf53eab0296b1250821d55ddaabd554a052bd9c1e7121aafa10082825820f227d91107250f417d8d08e8b0848dd551e976806f47693485fa79bcedb00e0d5840e3dd7a742684c8e9251e758a7987c476de67ae6e9b163b4de71179b779a270c3405e56ca591535c4bc60433b7d16108ec121bb68c251c6d50b99b1e8b15e940c825820c78ed7745cc8fd8d86a656ad54c66dfaf81cd197cfd3322b776818f79c3d42d75840ab24a626f435e7d7f780f7e6354d98eaf478a057b93fdc06830f47208cfd6a2bfb97a7f421a6bcc5485f90353377ccdff9440eba465f67edca79840740317e0da10082825820b38ee7ae9e93e96a67f9944549d6f6ee11958641312f08db0f2544a056c316ed5840239456ee7392721a8930fe3777541d016d960ef6f245462b957ea8e37aaeaabf8dd5205c3f3eda934c03c8e972ef42d95e9c0062beb0d6997efadc137ed0d80582582005dedfe50237facb1bd47e682560527ade3954156f4d2e5d64ba42a72661399c5840424eb36d43bde5ba3d42cd58e07c89d42c2b53ef909e35054514b3a6a8d433e130e778bf0e6bd68dbb06e88d214ebd5307cbf46425fcdc1fe51ffeb44aad8d0ba200828258204dcb549d62f4393daff6854dd2a28f3eb543d8d487ceaafec1f06db1280297145840955762edb9b09a88d0578e7c8a672dacaace39e35bc00abf87d0e32322e0c7f5b3c083520ce24128dd99af51abc8922193ec90ced47764d55a7e8a3442eb5f05825820ccb2a8e0155835fe7edcf1c35f779b1cc52c42af3529d11724a466ccfad422a358403bc918737723aeb333e69ea1cfca95f4ad92ad390ccd115ae5b3fe34ea2d2af390afb7503a944299885493a745cc7f60574bd17af598cc73a613cf5e0052e00301818200581c89d555c7a028bc560ea44e52a81c866088566f3a99c9989a5a183940a100828258201b811fa49076407607ef4b9e87bd359da13f6ed112419762256a7b2518eca7d15840b2668e06894a593fdd12493fc759054fb651d011dc407fa2328ad16a57eb9ce5ab489c20480b15267d2ea8438b996ee314d00dbcedb651cdf27592a555760400825820e77d357e39485ed9589d32c3a63d33850f9cefc314d340c1d53171e486ab21155840ab12182cafc4019032de1fbd0529e899cc1eb33555aca63e1615085b38f6519ecdfb71c03204c49e9956a442199c638844d01c6edca643a44b3c0c3066847708a2008282582008f696fcb5bf1592fa015eb7cd590c91b99e2a756a76ff608d0e717934c4a94a584000e2d9c20be4fbf547ecf7c2a6d8dd352241916bf19d62fc4ce9838d4aed1ba7bb0919c93e568453dc25aff5644ea8d2252e5b03c05d5191849e1d9e5c17d8058258209ee527fd46eb12e792272f8374d24166007b57fc2eb48faf44f6fc2dd5fc0fd15840ae768c24f4f4378ce27312aa91645aff04c58b9bf3b906e1dfc6a82f76decec1ab0015c6c5b8d8ef43ccdc5947903b43b1f20b724a57acebaf6bcd511480ca0101818200581c8a2da46df46082c69076335093c8062f9f1413f452ec43357f4f2f74a2008282582078ea7b4063f315d8e63fcee59d5cb174dee92530de2829324f37e0e798d0eb2058408bae6afbc4dd6610456fae075bb2e8ed1d2eef8318fd0d95f02f4e0bcb9c33bfc6ca3b1fc3c613d3f7993e47cfc7b4607e6ec45fea5752dd4221a35676869309825820898774aae3d21e44f456a6564dffc3ecf79db05e6048665bbddcfa047619191558405f0e2633b9aeeaa43bd447bf61980d17c6f0844a904fed7f4f812171055479abd0bd0d8b60ec54bd42cf331376c3c0c7e0717ab8c38fa95cc3531d3b7cf0fc0d018182018282051a016002d88200581c24a24be012b30b349f231a99280537f6216dd39568d6f9490bbc68afa10082825820ed753bf31e69892aadc7449863739438a6c65c0f5780e8af49fa2346f5f682af584022ac5d97daba0c0a91e3e2ffcaa11fc8f44a98fa0086b668d7ac6f6bf6169965b1262287859ae96862465f89c79c7bb0a20fe5e839c4d5225e22881333461c098258209826cf9e87fbedc7a5511329bc43f90134aecbe3961b8eb713076daccfba43b3584074d3f44dea445b8a2177a62d36097fe58ae2064d6e6a147643c1a0cf60e1d8be9e763368204f2a622ec835e692fc8eeaba6a1170a6a6031f6d5a1cc946947b0aa10281845820e4f5e0bce508be77652f050aaff468256db19610002882c021ebe98e4bd53f0c5840d92aa8d2092e1242a22004a51357921a296d17a2ad6ec4b256fb03331eddeeb46526f51ba2ae5656930c1bc1ea6410927c524cff6413c4f8d3af856e1c10250d5820267138883ed4f3a2f9204556597c00081d8e63efb8cfe61adaa71885f79381635822a101581e581cd8d97175289f3a4a354f2761ab97c9b2e00f6cbec6e4a38ff141b1f4a10281845820be5fef8d21addb5bdee84bb7eb39f3fb88f7d965b733155cb77deefbb011bf0e58401d8378d6fbf6774f9b2a24a5c1e002e0b7305af7079fc4d63986a04333a0327d9e52140e3e66bed0481d00a04d9d076bb4e38a2ff686235bd578a89c59475c0558202b2e17b3d36a369710e9c06e2c324047b9b800aed84c9e8468a4be492156ff0a5822a101581e581cd8d97175289f3a3053dd116121025a8883dcf8a137d5be07877840e4a10288845820a8e28ca1f0d2347b29d356f7b07971f2ac186e840afdec76602756a1bce5521c5840203d4657dc3794a4ea5133b2d95624a2485448864efe8dba2ffc07b952fd2fe7ffbba48ca6085bd1cd35d1f0af623e340280377dd630cbc4459cd54e3bed960558203f904efc08fe0c918e9a5df3f00a2f0234ebc8a90c5895fc0ed56d2a454739bc5822a101581e581cc9a4c68c6364682423f1545190173b86b84e2868d46f267b0b1e8fe0845820eb54c1c6073ced10be5313ff4cb771fb6306797fa4df4af09435e9f591cd97ee5840ceb3e1683a7d96061d48670470fa4487d213d8e9b5e57803e4ece8e589f5bd73416a2305c9e42ca863319ba758c6ee6c2865e24ba3546490b88ff499ad9c140b5820545084c11a5b28a706bfe75b31e88cf2309a24caffe8fb3bd8ff2277ee2a54785822a101581e581cc9a4c68c6364684c3cc39951df558b6bcc33ce72308b38b6bab88f86845820c858f21a704e4b5a60edde8425af7740dc6d5cf5323530f57ee4be627adb902b58407cbefb2016e2cebaefdbdd4428bdbee5edf74cf7a8c3fbed28987d767a4578996f262280db308b0a3c09898fb82ebd0eba71273b32fd9ae58d79b1db4c1b0f065820d5c265fce9714724f6c0ce8ccd5c0f43f2870323cd5c7ebf9dd5d6b8483c30ea5822a101581e581cc9a4c68c6364685f0392ff5133d2734f45aee261d2df93124dec753e8458205c3fe16d840ece3f698e5227e8711959d940a5a7ce5b8d97e4809875576154a75840269acd199d9d37a23b27f29601d7b54546364e85eccf1d1020d99d79e84f1df3d4b50a6bfc96ee9ac59b9384b882b6f46b1841760080d1708c1026ba256d1a00582045a7e755b9d830da12b309c12af6eecdc0ff50b0509d9217499474a8f0927e005822a101581e581cc9a4c68c636468719a4ba4511c4e348eba058563eac2859a0fead098845820fa256e041ba2d9b952d2906b37d1a12c6c831da2b508a11dbaa6957720dff3645840652cf665d74bc504cc823bbb7988638c789d083500cec4356271939c70ae23626e4c492a912ee3907e2e1fc7fc46c3d7debdc0a0b1b16d1c758dad0c8c184d035820dffce74909e33d00449db037fe758c430bbb79cd02d266257af5008f946e2e8b5822a101581e581cc9a4c68c636468726d933b519f0f1f57f51252c1121352f1275e074d845820ec7ca1ac472a76c5b815ab7bc9fe1826fe0df427b8c52f303c067a93b9f87ed958406779e08868ca456f3918eca343c3879d20f98c41da51396deaa058e6f611914978aa537c815d406188c87eac46c6b8a690740ff775851a96079a9283905b9a0a5820c9414ad96552aa45428d4a5f06cbce6c996f96cc6b8b349f854dadfec3f3a7f05822a101581e581cc9a4c68c63646805845d3251968049f4adbe7640106b0978e18d8d608458207a7745b26a1cfe5bdc99a2824c51e74f593339a1372ad876735cff1c751c5bda584046826bd4533fff11b80f1a3d302b782282253074ea44d8917a054320cf1bc66e5f0f2345b2d193b44bb3b43a6256e5c647c6165150a413107f5adcab962edd09582057f99e2553adab78c940831ffd43577dd2f0eac163ec2ab47c9e963968b18f125822a101581e581cc9a4c68c636468754868a4515b4434e3d50a38ea26e4ad20b6c3af61845820b5711a1e77783c41d00914902fdf1e1071bf5630e3531217b055db2350539dff5840f3d45617a985e0c5578380768f1ced09d7b04f1609b44e4f5498eb0430c527e7d1e30cd4c7f72a1035986032a70436580d0d75e1443bf98033e3a612515d840f5820bc9af5a13a266ab5d3b9516de27ef16a93cff7281fcb187d935cdbdc3707171b5822a101581e581cc9a4c68c636468352b66c1516fe689a3abb33464f690e32881d87b4fa2008282582050c17fe55e0e68fd94b3c2d6034fc3cff03e1228462ffded2c8ad04a816c73795840667c9678704afd1b3a1c3cb424816a223084f803de7942ecaea5ae893505324db6c0977e0b1fe0972fcd917ad7927369a109e0ba9b4cf1f46caea556f5068d05825820db69102d6cb3bfe0c8e08ec9ac127c478efdc4502ba95beaed37c5afd5161c7a5840636cf2854653b55aa61832d75904e957fbb671f3bcc5c6776fc4e25b6c62bdedc420444a24b352e496c2953f599f1a8bdacb8f3682b0b79321ab100af4f89c03018182018282051a01601af38200581c76cb8de7db67a0cf13ba419e7b571d479a92211ef1a51e3174f85b55a20082825820cdd4860d4805b8aba5b46482ca8443f48b09306d6ad363af6c2d16c3c71805d458402d3c2d5d485abf7c05f4e22713dc0cd73b1c1082616f3b008a4e610c08115ac8be399b5ed028605e20c058910f56e0f1b2538139101cdbddac5b4126571c0c08825820440fd71debdf48bf8b4c4ec9857cf2e58b622e75d9e9de43b1145165be2de8205840f1552bef6e910a3bd1056ed84cccd5ae0b55f38b33241607b936ec1c57d6b02e5363ba2add9225887eac3990886629fa91e28d2344ac3c20814cd7d11e7c4b0301818200581c7280bfc12cc21c024a3e8155d3d77227d7bf8f66ab2fa35d5799c163a1008282582021cb753a2984835ce919e28b61503736020d4c9560624c89b9b46c73194e60e15840f1562aae4e8f2bb0af5e584c086a095c16617cbd9a7e58dfbd7aa01aa87419553cfa5297041e5ef640bf0c446a719d25afe3ec908f209b357b4b289f6eb9060882582023bdf259b7ead310df7861c7a07bb5bb2bdb604fa6a830f3d611f46735a53c5a58409920b63962b8fcf0a91f514354696c26079778e584af55cabd1b8240ce05f77187b95fbb3f76205e12f899e3b32cc5d7bf208e3db43acd0347f9dc58bac58705a10084825820fce03faca39047cb29c9898d0ec776578c760cc2f85178dd1c0ec472b771f2695840b88d417d28e23eeccf6df921693546d14dce8b3de0c49d38c8d846091baa01de82c370a0ba6c4a1d1d6a418e50c37f5eff08df81b9d4a15b5ebe9ffe54e89508825820fce03faca39047cb29c9898d0ec776578c760cc2f85178dd1c0ec472b771f2695840b88d417d28e23eeccf6df921693546d14dce8b3de0c49d38c8d846091baa01de82c370a0ba6c4a1d1d6a418e50c37f5eff08df81b9d4a15b5ebe9ffe54e89508825820fce03faca39047cb29c9898d0ec776578c760cc2f85178dd1c0ec472b771f2695840b88d417d28e23eeccf6df921693546d14dce8b3de0c49d38c8d846091baa01de82c370a0ba6c4a1d1d6a418e50c37f5eff08df81b9d4a15b5ebe9ffe54e89508825820fce03faca39047cb29c9898d0ec776578c760cc2f85178dd1c0ec472b771f2695840b88d417d28e23eeccf6df921693546d14dce8b3de0c49d38c8d846091baa01de82c370a0ba6c4a1d1d6a418e50c37f5eff08df81b9d4a15b5ebe9ffe54e89508a100818258209d4c62563c9d49063d58654589c0cede283dc06e435c54f2597a4faf9b30b60258409541aa4f72c2bde14af175154da053b9b84e252a931228da32c7ca176122e361be6378ae107ecce95435194c0e3dac7c8d240e82ca85ced70d4ca566564dda0ba100828258207c27fa62b817fb421deb2aed9c76a9deced7d03d2b32c0419e5e7fc90b858513584078c97069282cc4e00efb1003c686441984405bf40dcc15a3ccc707e6849d523f773db3bdacf7e768b9cc26b1d78e9059cafa3925c3d9940e011711251b37700f825820375ffb7fe50482cfeda9d3a2d26d184e06cbe246935e3d98b609e4c68472fa195840e4a75ed875b291d6c2c918305ccff3198f52d55e11faa1ff74e522fdd7f82b9cd0e119664b107431e3bcf8819da17ac54e975dee36f7355facca9fb79e20230ea20082825820ef47ad69c3eafd8730b72528d067c0aebb5ad435132115db32870a75841d293b58403e334468bc1f6b4cdda952d2239201b9145f24b55d8f04929ec22de4ecb4ca2f034305f23a95ead8d59bcda67c3e6edb4143f0cb22ea59995c0fb6d1341a5902825820a18585026186b87151b1904fc92814ac123381a51b95d0eb7e63bf2cb90fda5e584021772d357b738f132f325b75494d96c1a2ce4b67e09f14e23dc424580f91934044e1b082a321c3255118f7953733e2992319fef5be4c5e41563c5f5b87c12109018182018282051a016002d88200581c3aa01dba02b37245f31d099806af04bb883214181fa899f5e8d34c37a10082825820fd0516dc3af9a6abfb58d34bd7f1f61a3e3985dbc1c961261729ad2565cbc64958405a14afd8f15e3c82119742870326dd10bc7896f2c109b23ca2a60e8122f58aeefe097b97ac4fa607ec932e9f26d8f0426a26589f091529db7cdc506bd320c4038258208e8ca43e788560e538f4466e8e6ac2c5561601240d74add435139a01894978e458404c72d48e6194f5380cc9964c6e35c1a20ae1be4d4da0ee8b572aa7ba788d5ff3fe3295537e2a86197682b46c56398ef5fae2dfad153d7434ed2fe88e1c99670ea10082825820ccec76e927c17585fb12a438afa5e8af7979514bb5a6a0399abb912a68dc6a895840518de30a8ea8f1c517b3e65b6599d14aefdf4f16761a0cbfb0f797c37c71518140aedfe53551c0e594164a180c84b1867351e73162076f87eca426ae6fc2fa008258203afde5867e367af6280f61713e14882fb9965ac99e5ae9e260d2e905294a62d8584037b174ef648db16331ee3c5a337338a94f5051609544a2530952660275c39c63bfadd4eaa86b8b02c1f59d6705ece3f76a86aba8b26e24c709b92a27054b9f0ea10082825820d63992be99ce336eb25748063d781f6a22bf4f1d13dfc401e609d015ea09837f58405ad8d1e444f7a19020557f2563f244ccadf0550ac31dfbaab3d8bfa6ea173fd99b9301ecd64c372995a17ba060f0181e2633d64f09d36ebebf057d712745350f8258202c726f5f75eb413f1bdeb572c7a7f328bcae6afa7b6de20d54ad31ee5f928ac65840cdeb471a5b6a0e077cb5c388d9b9f49d9ecccb8282f70c26b4c15391cde3f19181ee0c7b210e1de06b8540174c31b8d46b72f390b5615ca85e31b09b7957530ea100828258203ce587600b2a7370ee206b9d08f18ca9747493b6f2988e5db3b7ae445757fabd584054e26e2b04d2a0926bc999d3f72d6a88dd328cbd4a69b05f7018e9827db615ee0450bacc606740ec9b1e1f51cac61e73625c6ec27e326775927bc0209384420a8258203bc6300d4164c47737e8f67adf30755ac6bd72280f4ad7c9082492828e28c1b85840bad030e1e24a18863586c4fcd0f15ede43a24194a455a6b28e57857535d14ff45e94a458f05575f7a3bc92a16408cfb91b10774a83d03fbbc54bdc667714ac07a10081825820e13369244c8ac7cb81676de7e36850aae32e509803ccbd577b0ead0fd1a58fb658402eb22bff087d4d7cc5a7174863488a1d4ed50e009174ef256db73e36b54ddf8f6924e837568546624b873978978704ecc5696fdef468b42f02f21f91bc29290da100818258205fd9b2c7614d0e874ef2391728f5a72433cf9cba3c41af219cb1738ed219ceee5840257626783e4e7d22a43eadca34e763ff7c78489b62f9ab46cbb23e00c240c54f7b759585fb273a270c54583cfb9d43f111a4fae1564f3ba069e324504c17de0ea100828258208d1651b48e72722ddd7d7f60986b75e6f282967380df9962896071fd54cba4d058406b3cd308cae001c31110a5641e716444159aa21662ab9c8d1810ac5feb3d306411277519977181c69b643fac8b79d8176eb45cb6c4772f53d231177e0989d20b825820d4e9af2b16ef0dc54bf16c9a4c400d73ead7f483963cdc49c361e2dc831ef7f1584010014ff505276d178f44b95be18ee408abc01008b66528884efd8d2593ab6da568c81c0a22905668974d4e1852b263423070fa56546e6109cef7294ffca19b04ffa3181982a219ef64a3015820f2cdb189a3b0fe5ef9672cda9150138d4cce341fef699a50c1ceeed2858e41b5025820cd69f4dc88aae3bd93425b732d02ce70e02be137a5b602d51b3bd271ae779f450358390145504eba43c81ae5d52d913cbe9296bdb99f9be26ca04d7c5c772cb07e6c95ee0ce36bac4915d1844fb6a26825c10735d79fada8e382fb6f19ef65a10158400a8f985f591e3e1b6c733cb2a8e3e5bba7743639cb93acb0ecc328f19057e6ec2369a2cecf0599284aab759cf635c638368266bce4457105d9d1a5009f46cb0980181b82a119019b8378295468652054696d65732030332f4a616e2f32303039204368616e63656c6c6f72206f6e206272696e6b781c6f66207365636f6e64206261696c6f757420666f722062616e6b732e715468616e6b20796f75205361746f73686980182182a219ef64a301582015f305f200182cd51c2d6b21c145f867d5a1b48f1af064470284853183157147025820f57b7bd8b3473347b31fe904684b7c3e30c686610e976f0e29de2621097358c1035839019f857b5feff2b13e90142bb4ac20b95063747e1df8ea5cb8b827f8b13cc658afae01d34253a65ba78b47f1641dd81c01e9e7971da00588c619ef65a1015840b9a388a8ca0d6d9242f8968a77c5c5215a5738c5c3a02e8a74656c3a2ce6fb3dbf47e3e00a0d82d7f6c4db5ece29b2ca506d31218f23a58020b6f7ff68cb0d0380").unwrap();
+        let bytes = hex::decode(MARY_NETWORK_BLOCK).unwrap();
         let _block = MultiEraBlock::from_explicit_network_cbor_bytes(&bytes).unwrap();
     }
 
@@ -1366,21 +2623,226 @@ mod test {
         let _block = MultiEraBlock::from_explicit_network_cbor_bytes(&bytes).unwrap();
     }
 
+    // babbage block in the network format
+    const BABBAGE_NETWORK_BLOCK: &str = "820685828a1a0002a1d21a00360d985820f93e682d5b91a94d8660e748aef229c19cb285bfb9830db48941d6a78183d81f58209691ed9d98a5b79d5bc46c4496a6dba7e103f668f525c8349b6b92676cb3eae45820ea49e4652c460b9ee6daafefc999ca667fbe5eb5d7a7aeabbdff6fe19c1a3c9f825840c448c14556645bfb1c2de174b4f375bd8e5c27a41f26661b17d05ab8d30e9f2527681555316ce65a7e4dfb3cf83f57144a95df44d1fa67293c31f0ab15c4f4b55850c15e1be17e969159dd7ea5df78af4154767e6c13e4f41cf67d9bc305f6f4d0e1ec56df71df7e00ab51d9f7de9bdefc88e60a155f4af7225007146c38cff4d5ca7a8067214baba73aa5a2f726da72360904582029571d16f081709b3c48651860077bebf9340abb3fc7133443c54f1f5a5edcf18458200481896957dda8d02d825448eb22aad5e106114d924f49923b2fd68e6b3254d3000058403b0db934233f93c43de54a63f8be99bd21da431dcea996a01213ada37e66c6a3930a95399ce083875ba95ffa58cb8ca9b83c92f7d440adb968d8569d74fe90018207005901c0c8e3bb3da1d72fa8a736a6a66bec894083a7f74b388181c54b8faaaf7ccd020acd7a7fbde4a2505f6fbfa907a8bca491219713841720e122a94a057ad065850e88894f7b7985335bd2e3c6bbed5a3fa7fd526a8afafd78fd04dfaef371ad55d0d3d0f1707db5e6773f3ccb896d9f81b1428c3dbcf693c54580f91bb93364272349c08980f71e74e74f8d7863a29cee3fbd821642c14285d8198c68fdbc0a193bf63d97c7a5481ce7da17c97f43a7b98d0cad8c3af26ae4f9ac695fab06ca34685ec57339673d084e0417f844747025f4f5b42cbd883159a3d52dab9dafdfb37b0136757b67ec4e1a6768c43cee5937b81e03224aa6909a9a3254cdf33887914d67723f9a5a4505ac41b658cec10d6c2949e3d3c58bc7a27b64eb8407cbdad7806db704e7ee172405ddaa634119f878541d2f19674dcd0aebf4e6e1091635ce177489742c28d61a4698edaea3364b92b03a73838ca24ce83913727154dae3c2ee4a331997dacd155753f014bc98cfa25b8d84c808654852b5e1e74e251651e30063a6451a676795aa421ea59daa711a7ff0d4140de86bc89969f717d461690c341da4cd440818205961ed9cc9970f52b10ac1b1c3ed3e0d0170582daec84ef2068080a080";
+
     #[test]
     fn babbage_network_block_parse() {
-        let bytes = hex::decode("820685828a1a0002a1d21a00360d985820f93e682d5b91a94d8660e748aef229c19cb285bfb9830db48941d6a78183d81f58209691ed9d98a5b79d5bc46c4496a6dba7e103f668f525c8349b6b92676cb3eae45820ea49e4652c460b9ee6daafefc999ca667fbe5eb5d7a7aeabbdff6fe19c1a3c9f825840c448c14556645bfb1c2de174b4f375bd8e5c27a41f26661b17d05ab8d30e9f2527681555316ce65a7e4dfb3cf83f57144a95df44d1fa67293c31f0ab15c4f4b55850c15e1be17e969159dd7ea5df78af4154767e6c13e4f41cf67d9bc305f6f4d0e1ec56df71df7e00ab51d9f7de9bdefc88e60a155f4af7225007146c38cff4d5ca7a8067214baba73aa5a2f726da72360904582029571d16f081709b3c48651860077bebf9340abb3fc7133443c54f1f5a5edcf18458200481896957dda8d02d825448eb22aad5e106114d924f49923b2fd68e6b3254d3000058403b0db934233f93c43de54a63f8be99bd21da431dcea996a01213ada37e66c6a3930a95399ce083875ba95ffa58cb8ca9b83c92f7d440adb968d8569d74fe90018207005901c0c8e3bb3da1d72fa8a736a6a66bec894083a7f74b388181c54b8faaaf7ccd020acd7a7fbde4a2505f6fbfa907a8bca491219713841720e122a94a057ad065850e88894f7b7985335bd2e3c6bbed5a3fa7fd526a8afafd78fd04dfaef371ad55d0d3d0f1707db5e6773f3ccb896d9f81b1428c3dbcf693c54580f91bb93364272349c08980f71e74e74f8d7863a29cee3fbd821642c14285d8198c68fdbc0a193bf63d97c7a5481ce7da17c97f43a7b98d0cad8c3af26ae4f9ac695fab06ca34685ec57339673d084e0417f844747025f4f5b42cbd883159a3d52dab9dafdfb37b0136757b67ec4e1a6768c43cee5937b81e03224aa6909a9a3254cdf33887914d67723f9a5a4505ac41b658cec10d6c2949e3d3c58bc7a27b64eb8407cbdad7806db704e7ee172405ddaa634119f878541d2f19674dcd0aebf4e6e1091635ce177489742c28d61a4698edaea3364b92b03a73838ca24ce83913727154dae3c2ee4a331997dacd155753f014bc98cfa25b8d84c808654852b5e1e74e251651e30063a6451a676795aa421ea59daa711a7ff0d4140de86bc89969f717d461690c341da4cd440818205961ed9cc9970f52b10ac1b1c3ed3e0d0170582daec84ef2068080a080").unwrap();
+        let bytes = hex::decode(BABBAGE_NETWORK_BLOCK).unwrap();
         let _block = MultiEraBlock::from_explicit_network_cbor_bytes(&bytes).unwrap();
     }
 
+    // preview block containing a DRep registration
+    pub(crate) const CONWAY_PREVIEW_BLOCK: &str = "820785828a1a0006ddf41a0089a98c58205172bc8843f4b565ecf0f72f86944afb0103d126bd1b6940de33a6125988be665820149174cec3572f88abaa0e3c22aa992f2f254db15ebfea54f93b59c71151801a58207e7f6a8978acb0ed37219cc5a917f8cd5bf6fd53538254ac921790e159baf8ad825840438fe265be4b13d6e1f5af3a6fe8a7a4c0ef1b832b614f697b80def3467db19740640fc9aed4ef158230ad910af46e597de22030a2c47556d17805c13866d7ca5850ab4849c172cf814f266bab8ae5327902bd481a0bfab03141d00c2e4e810732bea4739f94e068621a0573870379c51e76229e8cc345163781f8cb43683e1a79805ed84576fc3bf3fb4b431074241dac0519010858200ea62bbbba0549c668a54579873e3c35e64a899007f191bdf17b43bd01b4c7a28458205f7a4914a82f0120d63e3e63a235ad20ed871d816ba28f032c75f69e3a7df3e20018445840a437a97e0dd7b08a7744107cc329a6a661d6c1c844d0f35e72906f5e5762260b4189bc49be29993aa0b64a338e5fa8462953c55a2b4b4299edf40c8617f1e8038209005901c0aba65daed3986c74218c9e2d026e9285fbc5276efcdab8f41358df8cd7879574257f95b76540de3ac02d24a5e00f05327621e9fd01c6cf8f302771fe069b3b03c7e3637ea1b36208c4ab40b97866d05055ee2d8254fb44f54e26bef97b9e412a1377bb2a7224f8ed5430a83bdaeb963a9c492f721cb499a42a9dc49957315aa7144f304fe3af8f28a619d9b48e57293fd18332d0a277b8beb1651f502f6617d400c63dc3c0f3de0fc2a52b6c05a0557d5f1bf907a15a85ef5bb6a91406f01f2d63801e68e3fb16a8cf654afc9c7f9da3958c79fca1806405802a743377c89bb933ac69598ce1153cc7dda40a504ae5555af78e9052e8b767c7d8dbb943bffbf7093896c7238aeb4031bd72e794c23c2710b973e351ac5004ebf014b7a0d3daaa1070e7ed0288f45e5c776f174f826c0c810cff417b2ce0767f6210dc26710e4958f68800addfe210f1f7388edd1b6b96c9ad9e2a615cdc6d472daa421449c33eedda2a193755b70258fca6a9ae1a4cc0cd16bf1e37664f9562ef82c458cd170a71c2c45ff0cba71736d78d60abb33febace57b324410260f179cb8bd837c80ce114339d89daecbe578a2518877dd2b4109624153c717f1f4c704386a88e3590c81a40081825820917aaf395181a0359ca8ee314fe8355c5e2239d66483b164c62cac2e41ea4a0d000181a200583900db1bc3c3f99ce68977ceaf27ab4dd917123ef9e73f85c304236eab2397a993b0c8166aa8c48345be19257a4025ab764c86e799beab15b303011b0000000253fcbcba021a00028bfd048184108200581c97a993b0c8166aa8c48345be19257a4025ab764c86e799beab15b30300f681a100818258205f89ea8c6ab580e2e7a32c3586869eb95fae54f42ac982639b6665359601f63e58401c012befc2a4d4e22e6c7be4483de4d7ac550050ac3ff7d481c503cef64ce234a76ea6dcbd70f9a79de6adb869b3599d28d2cf351643a5cc6e36205d39efc50da080";
+
     #[test]
     fn conway_network_block_parse() {
-        let bytes = hex::decode("820785828a1a0006ddf41a0089a98c58205172bc8843f4b565ecf0f72f86944afb0103d126bd1b6940de33a6125988be665820149174cec3572f88abaa0e3c22aa992f2f254db15ebfea54f93b59c71151801a58207e7f6a8978acb0ed37219cc5a917f8cd5bf6fd53538254ac921790e159baf8ad825840438fe265be4b13d6e1f5af3a6fe8a7a4c0ef1b832b614f697b80def3467db19740640fc9aed4ef158230ad910af46e597de22030a2c47556d17805c13866d7ca5850ab4849c172cf814f266bab8ae5327902bd481a0bfab03141d00c2e4e810732bea4739f94e068621a0573870379c51e76229e8cc345163781f8cb43683e1a79805ed84576fc3bf3fb4b431074241dac0519010858200ea62bbbba0549c668a54579873e3c35e64a899007f191bdf17b43bd01b4c7a28458205f7a4914a82f0120d63e3e63a235ad20ed871d816ba28f032c75f69e3a7df3e20018445840a437a97e0dd7b08a7744107cc329a6a661d6c1c844d0f35e72906f5e5762260b4189bc49be29993aa0b64a338e5fa8462953c55a2b4b4299edf40c8617f1e8038209005901c0aba65daed3986c74218c9e2d026e9285fbc5276efcdab8f41358df8cd7879574257f95b76540de3ac02d24a5e00f05327621e9fd01c6cf8f302771fe069b3b03c7e3637ea1b36208c4ab40b97866d05055ee2d8254fb44f54e26bef97b9e412a1377bb2a7224f8ed5430a83bdaeb963a9c492f721cb499a42a9dc49957315aa7144f304fe3af8f28a619d9b48e57293fd18332d0a277b8beb1651f502f6617d400c63dc3c0f3de0fc2a52b6c05a0557d5f1bf907a15a85ef5bb6a91406f01f2d63801e68e3fb16a8cf654afc9c7f9da3958c79fca1806405802a743377c89bb933ac69598ce1153cc7dda40a504ae5555af78e9052e8b767c7d8dbb943bffbf7093896c7238aeb4031bd72e794c23c2710b973e351ac5004ebf014b7a0d3daaa1070e7ed0288f45e5c776f174f826c0c810cff417b2ce0767f6210dc26710e4958f68800addfe210f1f7388edd1b6b96c9ad9e2a615cdc6d472daa421449c33eedda2a193755b70258fca6a9ae1a4cc0cd16bf1e37664f9562ef82c458cd170a71c2c45ff0cba71736d78d60abb33febace57b324410260f179cb8bd837c80ce114339d89daecbe578a2518877dd2b4109624153c717f1f4c704386a88e3590c81a40081825820917aaf395181a0359ca8ee314fe8355c5e2239d66483b164c62cac2e41ea4a0d000181a200583900db1bc3c3f99ce68977ceaf27ab4dd917123ef9e73f85c304236eab2397a993b0c8166aa8c48345be19257a4025ab764c86e799beab15b303011b0000000253fcbcba021a00028bfd048184108200581c97a993b0c8166aa8c48345be19257a4025ab764c86e799beab15b30300f681a100818258205f89ea8c6ab580e2e7a32c3586869eb95fae54f42ac982639b6665359601f63e58401c012befc2a4d4e22e6c7be4483de4d7ac550050ac3ff7d481c503cef64ce234a76ea6dcbd70f9a79de6adb869b3599d28d2cf351643a5cc6e36205d39efc50da080").unwrap();
-        let _block = MultiEraBlock::from_explicit_network_cbor_bytes(&bytes).unwrap();
+        let bytes = hex::decode(CONWAY_PREVIEW_BLOCK).unwrap();
+        let block = MultiEraBlock::from_explicit_network_cbor_bytes(&bytes).unwrap();
+        assert!(block.warnings().is_empty());
+        let certs = block
+            .transaction_bodies()
+            .iter()
+            .flat_map(|body| body.certs().unwrap_or_default())
+            .collect::<Vec<_>>();
+        assert_eq!(certs.len(), 1);
+        match &certs[0] {
+            MultiEraCertificate::RegDrepCert(reg_drep_cert) => {
+                assert_eq!(reg_drep_cert.deposit, 0);
+                assert!(reg_drep_cert.anchor.is_none());
+            }
+            cert => panic!("expected RegDrepCert, found {:?}", cert),
+        }
+        // by default bodies parsed from a block don't keep their original bytes so modifying
+        // one is reflected in its hash
+        let mut conway = match block {
+            MultiEraBlock::Conway(conway) => conway,
+            _ => panic!("expected conway block"),
+        };
+        let tx_body = &mut conway.transaction_bodies[0];
+        assert_eq!(tx_body.original_bytes(), None);
+        let parsed_hash = tx_body.hash();
+        tx_body.fee += 1;
+        assert_ne!(tx_body.hash(), parsed_hash);
+
+        // unless asked to keep them for hashing
+        let block = match MultiEraBlock::from_explicit_network_cbor_bytes_with_options(
+            &bytes,
+            &NetworkBlockOptions::new().keep_original_bytes(true),
+        )
+        .unwrap()
+        {
+            NetworkBlock::Known(block) => block,
+            NetworkBlock::Unknown { .. } => panic!("expected a known era"),
+        };
+        for tx_body in block.transaction_bodies() {
+            let original_bytes = tx_body.original_bytes().unwrap();
+            assert!(bytes
+                .windows(original_bytes.len())
+                .any(|window| window == original_bytes));
+            match &tx_body {
+                MultiEraTransactionBody::Conway(body) => {
+                    assert_eq!(original_bytes, body.to_cbor_bytes());
+                }
+                _ => panic!("expected conway tx body"),
+            }
+            assert_eq!(
+                tx_body.hash(),
+                TransactionHash::from(blake2b256(original_bytes))
+            );
+            assert_eq!(tx_body.hash(), parsed_hash);
+        }
+        let conway = Block::from_cbor_bytes_keep_original(&conway.to_cbor_bytes()).unwrap();
+        assert!(conway.header.original_bytes().is_some());
+        assert!(conway.transaction_bodies[0].original_bytes().is_some());
+    }
+
+    #[test]
+    fn conway_block_malformed_auxiliary_data() {
+        let mut block = match MultiEraBlock::from_explicit_network_cbor_bytes(
+            &hex::decode(CONWAY_PREVIEW_BLOCK).unwrap(),
+        )
+        .unwrap()
+        {
+            MultiEraBlock::Conway(block) => block,
+            _ => panic!("expected conway block"),
+        };
+        block.auxiliary_data_set = OrderedHashMap::new();
+        block.invalid_transactions = vec![];
+        block.encodings = None;
+        let mut bytes = block.to_cbor_bytes();
+        // swap the trailing empty auxiliary_data_set and invalid_transactions for a crafted
+        // auxiliary_data_set of 0 => {674: "hi"}, 2 => {1: <text that isn't UTF-8>}, 1 => {674: "ok"}
+        assert!(bytes.ends_with(&[0xa0, 0x80]));
+        bytes.truncate(bytes.len() - 2);
+        let malformed = hex::decode("a10161ff").unwrap();
+        bytes.extend(hex::decode("a300a11902a262686902").unwrap());
+        bytes.extend(&malformed);
+        bytes.extend(hex::decode("01a11902a2626f6b80").unwrap());
+
+        let block = MultiEraBlock::Conway(Block::from_cbor_bytes(&bytes).unwrap());
+        let auxiliary_data_set = block.auxiliary_data_set();
+        assert_eq!(
+            auxiliary_data_set.keys().copied().collect::<Vec<_>>(),
+            vec![0, 1]
+        );
+        let errors = block.auxiliary_data_errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].transaction_index, 2);
+        assert_eq!(errors[0].bytes, malformed);
+        // the raw bytes are emitted back in their original position
+        assert_eq!(block.to_cbor_bytes(), bytes);
+        // while canonical CBOR sorts them by transaction index along with the parsed entries
+        let canonical = block.to_canonical_cbor_bytes();
+        let canonical_tail = [
+            hex::decode("a300a11902a262686901a11902a2626f6b02").unwrap(),
+            malformed.clone(),
+            vec![0x80],
+        ]
+        .concat();
+        assert!(canonical.ends_with(&canonical_tail));
+        let reparsed = MultiEraBlock::Conway(Block::from_cbor_bytes(&canonical).unwrap());
+        assert_eq!(reparsed.auxiliary_data_errors()[0].bytes, malformed);
+    }
+
+    // byron main block in the network format
+    const BYRON_MAIN_BLOCK: &str = "820183851a2d964a095820d667b66b3234630d7c43a29d8ac1431cc74929218456688c4833664563d7f3af84830058200e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a85820afc0da64183bf2664f3d4eec7238d524ba607faeeab24fc100eb861dba69971b83025820d36a2619a672494604e11bb447cbcf5231e9f2ba25c2169177edc941bd50ad6c5820d36a2619a672494604e11bb447cbcf5231e9f2ba25c2169177edc941bd50ad6c5820afc0da64183bf2664f3d4eec7238d524ba607faeeab24fc100eb861dba69971b58204e66280cd94d591072349bec0a3090a53aa945562efb6d08d56e53654b0e40988482071945b358400bdb1f5ef3d994037593f2266255f134a564658bb2df814b3b9cefb96da34fa9c888591c85b770fd36726d5f3d991c668828affc7bbe0872fd699136e664d9d8811a00029434820282840058400bdb1f5ef3d994037593f2266255f134a564658bb2df814b3b9cefb96da34fa9c888591c85b770fd36726d5f3d991c668828affc7bbe0872fd699136e664d9d858405fddeedade2714d6db2f9e1104743d2d8d818ecddc306e176108db14caadd441b457d5840c60f8840b99c8f78c290ae229d4f8431e678ba7a545c35607b94ddb5840552741f728196e62f218047b944b24ce4d374300d04b9b281426f55aa000d53ded66989ad5ea0908e6ff6492001ff18ece6c7040a934060759e9ae09863bf20358407ac79f732d0a4956c5214c59d36ce7cd3a7887784c825caea7f424fd0b0984b5a0d5edee4257a8de1c56591b623ff266a64bf3d64d5c63f46120abb1f3ef24038483000000826a63617264616e6f2d736c02a058204ba92aa320c60acc9ad7b9a64f2eda55c4d2ec28e604faf186708b4f0c4e8edf849fff8302a0d90102809fff82809fff81a0";
+
+    #[test]
+    fn unknown_era_network_block() {
+        assert!(CONWAY_PREVIEW_BLOCK.starts_with("8207"));
+        let inner = hex::decode(&CONWAY_PREVIEW_BLOCK[4..]).unwrap();
+        let bytes = hex::decode(format!("8208{}", &CONWAY_PREVIEW_BLOCK[4..])).unwrap();
+
+        let err = MultiEraBlock::from_explicit_network_cbor_bytes(&bytes).unwrap_err();
+        assert!(matches!(
+            err.failure(),
+            DeserializeFailure::RangeCheck { found: 8, .. }
+        ));
+        assert_eq!(err.location(), Some("block_era_tag"));
+
+        let options = NetworkBlockOptions::new().allow_unknown_era(true);
+        match MultiEraBlock::from_explicit_network_cbor_bytes_with_options(&bytes, &options)
+            .unwrap()
+        {
+            NetworkBlock::Unknown { era, bytes } => {
+                assert_eq!(era, 8);
+                assert_eq!(bytes, inner);
+            }
+            NetworkBlock::Known(_) => panic!(),
+        }
+        // known eras are still parsed
+        assert!(matches!(
+            MultiEraBlock::from_explicit_network_cbor_bytes_with_options(
+                &hex::decode(CONWAY_PREVIEW_BLOCK).unwrap(),
+                &options
+            ),
+            Ok(NetworkBlock::Known(MultiEraBlock::Conway(_)))
+        ));
+
+        let options = NetworkBlockOptions::new().era_tag_alias(8, Era::Conway);
+        assert!(matches!(
+            MultiEraBlock::from_explicit_network_cbor_bytes_with_options(&bytes, &options),
+            Ok(NetworkBlock::Known(MultiEraBlock::Conway(_)))
+        ));
+    }
+
+    #[test]
+    fn byron_delegation_and_ssc_payloads() {
+        use crate::byron::mpc::Ssc;
+        use cml_chain::byron::StakeholderId;
+        use cml_crypto::Bip32PrivateKey;
+
+        let bytes = hex::decode(BYRON_MAIN_BLOCK).unwrap();
+        let block = MultiEraBlock::from_explicit_network_cbor_bytes(&bytes).unwrap();
+        let mut main_block = match block {
+            MultiEraBlock::Byron(ByronBlock::Main(main_block)) => main_block,
+            _ => panic!("expected byron main block"),
+        };
+        // shares payload without any shares or certificates
+        assert_eq!(
+            hex::encode(main_block.ssc_payload_bytes()),
+            "8302a0d9010280"
+        );
+        assert!(matches!(
+            main_block.body.ssc_payload,
+            Ssc::SscSharesPayload(_)
+        ));
+        assert!(main_block.delegation_certificates().is_empty());
+
+        let issuer = Bip32PrivateKey::from_bip39_entropy(&[0x11; 16], &[]).to_public();
+        let delegate = Bip32PrivateKey::from_bip39_entropy(&[0x22; 16], &[]).to_public();
+        main_block.body.dlg_payload.push(ByronDelegation::new(
+            4,
+            issuer.to_raw_bytes().to_vec(),
+            delegate.to_raw_bytes().to_vec(),
+            vec![0; 64],
+        ));
+        let block = MultiEraBlock::Byron(ByronBlock::Main(main_block));
+        let certs = block.byron_delegation_certificates();
+        assert_eq!(certs.len(), 1);
+        assert_eq!(certs[0].epoch, 4);
+        assert_eq!(certs[0].issuer_key().unwrap(), issuer);
+        assert_eq!(certs[0].delegate_key().unwrap(), delegate);
+        assert_eq!(
+            certs[0].issuer_stakeholder_id().unwrap(),
+            StakeholderId::new(&issuer)
+        );
+        assert_eq!(
+            certs[0].delegate_stakeholder_id().unwrap(),
+            StakeholderId::new(&delegate)
+        );
+        assert!(MultiEraBlock::from_explicit_network_cbor_bytes(
+            &hex::decode(CONWAY_PREVIEW_BLOCK).unwrap()
+        )
+        .unwrap()
+        .byron_delegation_certificates()
+        .is_empty());
     }
 
     #[test]
     fn byron_block_hash() {
-        let bytes = hex::decode("820183851a2d964a095820d667b66b3234630d7c43a29d8ac1431cc74929218456688c4833664563d7f3af84830058200e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a85820afc0da64183bf2664f3d4eec7238d524ba607faeeab24fc100eb861dba69971b83025820d36a2619a672494604e11bb447cbcf5231e9f2ba25c2169177edc941bd50ad6c5820d36a2619a672494604e11bb447cbcf5231e9f2ba25c2169177edc941bd50ad6c5820afc0da64183bf2664f3d4eec7238d524ba607faeeab24fc100eb861dba69971b58204e66280cd94d591072349bec0a3090a53aa945562efb6d08d56e53654b0e40988482071945b358400bdb1f5ef3d994037593f2266255f134a564658bb2df814b3b9cefb96da34fa9c888591c85b770fd36726d5f3d991c668828affc7bbe0872fd699136e664d9d8811a00029434820282840058400bdb1f5ef3d994037593f2266255f134a564658bb2df814b3b9cefb96da34fa9c888591c85b770fd36726d5f3d991c668828affc7bbe0872fd699136e664d9d858405fddeedade2714d6db2f9e1104743d2d8d818ecddc306e176108db14caadd441b457d5840c60f8840b99c8f78c290ae229d4f8431e678ba7a545c35607b94ddb5840552741f728196e62f218047b944b24ce4d374300d04b9b281426f55aa000d53ded66989ad5ea0908e6ff6492001ff18ece6c7040a934060759e9ae09863bf20358407ac79f732d0a4956c5214c59d36ce7cd3a7887784c825caea7f424fd0b0984b5a0d5edee4257a8de1c56591b623ff266a64bf3d64d5c63f46120abb1f3ef24038483000000826a63617264616e6f2d736c02a058204ba92aa320c60acc9ad7b9a64f2eda55c4d2ec28e604faf186708b4f0c4e8edf849fff8302a0d90102809fff82809fff81a0").unwrap();
+        let bytes = hex::decode(BYRON_MAIN_BLOCK).unwrap();
         let block = MultiEraBlock::from_explicit_network_cbor_bytes(&bytes).unwrap();
         assert_eq!(
             hex::encode(block.hash()),
@@ -1388,15 +2850,1146 @@ mod test {
         );
     }
 
+    #[test]
+    fn byron_update_payloads() {
+        use crate::byron::update::{
+            Bvermod, ByronBlockVersion, ByronSoftwareVersion, ByronTxFeePolicy, ByronUpdateData,
+            ByronUpdateProposal, ByronUpdateVote, SoftForkRule, StdFeePolicy,
+        };
+        use crate::byron::{Blake2b256, ByronAny};
+        use cml_chain::utils::BigInteger;
+
+        // there is no byron block with an update payload in the fixtures so one is spliced into
+        // BYRON_MAIN_BLOCK: a sparse block version modification, a system tag -> hashes map,
+        // non-empty attributes and a vote
+        let hash = |b: u8| Blake2b256::from([b; 32]);
+        let attributes = [("00", "4102"), ("1818", "82a0f6")]
+            .iter()
+            .map(|(k, v)| {
+                (
+                    ByronAny::from_cbor_bytes(&hex::decode(k).unwrap()).unwrap(),
+                    ByronAny::from_cbor_bytes(&hex::decode(v).unwrap()).unwrap(),
+                )
+            })
+            .collect();
+        let proposal = ByronUpdateProposal::new(
+            ByronBlockVersion::new(0, 1, 0),
+            Bvermod::new(
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![BigInteger::from(65536u64)],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                vec![SoftForkRule::new(
+                    900000000000000,
+                    600000000000000,
+                    50000000000000,
+                )],
+                vec![ByronTxFeePolicy::new(StdFeePolicy::new(
+                    BigInteger::from(155381000000000u64),
+                    BigInteger::from(43946000000u64),
+                ))],
+                vec![u64::MAX],
+            ),
+            ByronSoftwareVersion::new("cardano-sl".to_owned(), 1),
+            vec![(
+                "linux".to_owned(),
+                ByronUpdateData::new(hash(1), hash(2), hash(3), hash(4)),
+            )]
+            .into_iter()
+            .collect(),
+            attributes,
+            vec![0xaa; 64],
+            vec![0xbb; 64],
+        );
+        let vote = ByronUpdateVote::new(vec![0xcc; 64], hash(5), true, vec![0xdd; 64]);
+        // the block ends with an empty update payload [[], [_ ]] followed by the extra data
+        assert!(BYRON_MAIN_BLOCK.ends_with("82809fff81a0"));
+        let bytes = hex::decode(format!(
+            "{}8281{}81{}81a0",
+            &BYRON_MAIN_BLOCK[..BYRON_MAIN_BLOCK.len() - 12],
+            hex::encode(proposal.to_bytes()),
+            hex::encode(vote.to_bytes()),
+        ))
+        .unwrap();
+
+        let block = MultiEraBlock::from_explicit_network_cbor_bytes(&bytes).unwrap();
+        let main = match &block {
+            MultiEraBlock::Byron(ByronBlock::Main(main)) => main,
+            _ => panic!("expected byron main block"),
+        };
+        let parsed = main.update_proposal().unwrap();
+        let version = &parsed.block_version;
+        assert_eq!((version.u16, version.u162, version.u8), (0, 1, 0));
+        assert_eq!(parsed.software_version.application_name, "cardano-sl");
+        assert_eq!(parsed.software_version.u32, 1);
+        let bvermod = &parsed.block_version_mod;
+        assert_eq!(bvermod.max_tx_size, vec![BigInteger::from(65536u64)]);
+        assert!(bvermod.max_block_size.is_empty());
+        assert_eq!(bvermod.soft_fork_rule[0].coin_portion, 900000000000000);
+        assert_eq!(
+            bvermod.tx_fee_policy[0].index_1.big_integer,
+            BigInteger::from(155381000000000u64)
+        );
+        assert_eq!(
+            bvermod.tx_fee_policy[0].index_1.big_integer2,
+            BigInteger::from(43946000000u64)
+        );
+        assert_eq!(bvermod.unlock_stake_epoch, vec![u64::MAX]);
+        assert_eq!(parsed.byron_attributes.len(), 2);
+        let linux = parsed.data.get("linux").unwrap();
+        assert_eq!(linux.blake2b256, hash(1));
+        assert_eq!(linux.blake2b2564, hash(4));
+        assert_eq!(parsed.from, vec![0xaa; 64]);
+        let votes = main.update_votes();
+        assert_eq!(votes.len(), 1);
+        assert_eq!(votes[0].voter, vec![0xcc; 64]);
+        assert_eq!(votes[0].proposal_id, hash(5));
+        assert!(votes[0].vote);
+        match block.update_payloads().as_slice() {
+            [MultiEraUpdatePayload::Byron(update)] => {
+                assert_eq!(update.proposal.len(), 1);
+                assert_eq!(update.votes.len(), 1);
+            }
+            payloads => panic!("expected one byron payload, found {:?}", payloads),
+        }
+
+        // the original block has an empty update payload
+        let block = MultiEraBlock::from_explicit_network_cbor_bytes(
+            &hex::decode(BYRON_MAIN_BLOCK).unwrap(),
+        )
+        .unwrap();
+        assert!(block.update_payloads().is_empty());
+
+        // the sancho alonzo block's only tx is the genesis delegates' proposal for protocol 7.0
+        let block = MultiEraBlock::from_explicit_network_cbor_bytes(
+            &hex::decode(SANCHO_ALONZO_NETWORK_BLOCK).unwrap(),
+        )
+        .unwrap();
+        let update = match block.update_payloads().as_slice() {
+            [MultiEraUpdatePayload::Shelley(update)] => update.clone(),
+            payloads => panic!("expected one shelley payload, found {:?}", payloads),
+        };
+        assert_eq!(update.epoch, 1);
+        let delegates = update
+            .proposed_protocol_parameter_updates
+            .keys()
+            .map(|hash| hash.to_hex())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            delegates,
+            vec![
+                "c1ad22cabb342cbb83ce3859708232f4945ccb669e9b5f932cffc0ed",
+                "c264bca994a3a5deee5a1d9b92a3d7e9d6cbdb81f2f6989bb7f7b437",
+                "d4bf7eb45b72dffa5ac33d5c902fe409e4e611f2e9a52fb0d09784c3",
+            ]
+        );
+        for params in update.proposed_protocol_parameter_updates.values() {
+            let version = &params.protocol_version().unwrap().protocol_version;
+            assert_eq!((version.major, version.minor), (7, 0));
+            assert_eq!(params.max_block_body_size(), Some(90112));
+            assert_eq!(params.n_opt(), Some(500));
+            let cost_models = params.cost_models_for_script_languages().unwrap();
+            assert_eq!(cost_models.inner.get(&0).map(Vec::len), Some(166));
+            assert_eq!(cost_models.inner.get(&1).map(Vec::len), Some(175));
+            let max_tx_ex_units = params.max_tx_ex_units().unwrap();
+            assert_eq!(
+                (max_tx_ex_units.mem, max_tx_ex_units.steps),
+                (14000000, 10000000000)
+            );
+        }
+    }
+
+    // sancho testnet alonzo block with one tx
+    const SANCHO_ALONZO_NETWORK_BLOCK: &str = "820585828f1910e21a000151bc5820ab3a121e39d38cd844ed40988b435d5776d2be3e1a3ff15ee8b6f6099b6eefbc58201aba5fea24ea77d696737416bdfb6136fda5f0fa745f6b4aa682283e7d3e0f2d58200bd8f3ceb4057507f67c7c5730a62d691359983cae18d02f94ca80a020c9a6e682584014bc82eb1ada0c58bf1458140a81d569e9889dc303b9ef71afaa27615bd6b1ef5583ed74a14203b195404977edf201ce92f72bbb915ae5e1a187b74adf1e009f5850bb6a6709fe6fecf871c1f2dd3a657d3d2a8afc1f76f60d7d4d64706396f7b15ed9a628bc9a15a5dd444b316872a9d8bcbe90c01a826ad15f4c8b7f647aaed832d18efa5607b27a17ad432773fbc8430a8258409428e60adabd462ceba6c7ad8a68e8c444b1e66c31ec3e498baddf7f58a7a57b663db391f9b181457148d3704d6fc62ab71615ebf3acf89e90336f2974e72bc8585011bdede95b5b188d5649467ad62ff4df95068ae5d9462f54211d4522dc812cddba98bc7f9e0187e550e4c424d31651a63c1692b6ef335d7346d7e3f499f496beb7f402356510c4b00ab3ed932069ee03190d9158201d39330aaf40049b703f7c1a9b2eb6c9f84f11cc8d8f456aafb6266f757d685a58201dc1cddf59c04f8744d412c5490f6b87367fa109a22140b4a2987edd1433f75f00005840556655ab6e794c101e840475c9fba24c75ee06c7b6932ca0b596e05ea973d7c77d0bb0af98ed08717fec6d365b6462817567dbbe484c1623cdee61c27e5dcd0107025901c04fbcd1a7d282498822e5ce754b2cf976551678ab7984b02d1a6c66556329667b4ac9a702c44e17dd68e678825afb2696d8c56cb94a1619edde2b5b570f818008060b62df089138b25a9130eaf11ce9467830b9194e4cd0fc50a61c190e50011297fdefb16849ab3c53191a8d254eee51109417c3be4605ffe795b6ff96aa0778a949a342a53e93ba4143be6b10df056eb80b60911490a48c54710081f9f2d21d6a93b21952ee892bd3a70954ecc7d3f733321a572df04c1b163b74c7d4280e00e4784b35077b8bac624644de3d4fe3dbf82fb4ff22a8e5ee9f30ac67caef4b8cef2021206c6f2395109fa34d164c926380fd88385a8d166ea7d0de46e179bdfcbf6c201aa8de5a44f211900909910b8add25494f26994ccde53b4812a9ec329e682507a3e272dcb6ebc3973ccb4a23e9dd0eedf3a5d3b713568c4ae02dbeaa9e5b4637ca5c1a03ae030c2b504cfa932476383a790c91e79ee05321113e01447a0783deedd9b6d0a14ea781719f7c2a90f09f400d52621234c40803a3c30ed3c4c0acedd89180822a9fdf6147faee7458f8feb3334878c569f2503925f147948066f34f1b7cd783bec633a5df03bd11f4d5a99b21450b5bfbc13cd29339fc1bbe81a4008182582064ed7c9676c3b2954c188b60def3950bd750f8af90a233b077b6ff434539530803018182581d604da890918cb29f4446d07602a7c3877887326eb9e98394b8d6ce57b31b006983fdc40520ce021a0004b3890682a3581cc1ad22cabb342cbb83ce3859708232f4945ccb669e9b5f932cffc0eda7021a00016000081901f40cd81e8200010e82070012a2009f1a0003236119032c01011903e819023b00011903e8195e7104011903e818201a0001ca761928eb041959d818641959d818641959d818641959d818641959d818641959d81864186418641959d81864194c5118201a0002acfa182019b551041a000363151901ff00011a00015c3518201a000797751936f404021a0002ff941a0006ea7818dc0001011903e8196ff604021a0003bd081a00034ec5183e011a00102e0f19312a011a00032e801901a5011a0002da781903e819cf06011a00013a34182019a8f118201903e818201a00013aac0119e143041903e80a1a00030219189c011a00030219189c011a0003207c1901d9011a000330001901ff0119ccf3182019fd40182019ffd5182019581e18201940b318201a00012adf18201a0002ff941a0006ea7818dc0001011a00010f92192da7000119eabb18201a0002ff941a0006ea7818dc0001011a0002ff941a0006ea7818dc0001011a000c504e197712041a001d6af61a0001425b041a00040c660004001a00014fab18201a0003236119032c010119a0de18201a00033d7618201979f41820197fb8182019a95d1820197df718201995aa18201a0374f693194a1f0aff019f1a0003236119032c01011903e819023b00011903e8195e7104011903e818201a0001ca761928eb041959d818641959d818641959d818641959d818641959d818641959d81864186418641959d81864194c5118201a0002acfa182019b551041a000363151901ff00011a00015c3518201a000797751936f404021a0002ff941a0006ea7818dc0001011903e8196ff604021a0003bd081a00034ec5183e011a00102e0f19312a011a00032e801901a5011a0002da781903e819cf06011a00013a34182019a8f118201903e818201a00013aac0119e143041903e80a1a00030219189c011a00030219189c011a0003207c1901d9011a000330001901ff0119ccf3182019fd40182019ffd5182019581e18201940b318201a00012adf18201a0002ff941a0006ea7818dc0001011a00010f92192da7000119eabb18201a0002ff941a0006ea7818dc0001011a0002ff941a0006ea7818dc0001011a0011b22c1a0005fdde00021a000c504e197712041a001d6af61a0001425b041a00040c660004001a00014fab18201a0003236119032c010119a0de18201a00033d7618201979f41820197fb8182019a95d1820197df718201995aa18201a0223accc0a1a0374f693194a1f0a1a02515e841980b30aff14821a00d59f801b00000002540be40015821a03b20b801b00000004a817c800581cc264bca994a3a5deee5a1d9b92a3d7e9d6cbdb81f2f6989bb7f7b437a7021a00016000081901f40cd81e8200010e82070012a2009f1a0003236119032c01011903e819023b00011903e8195e7104011903e818201a0001ca761928eb041959d818641959d818641959d818641959d818641959d818641959d81864186418641959d81864194c5118201a0002acfa182019b551041a000363151901ff00011a00015c3518201a000797751936f404021a0002ff941a0006ea7818dc0001011903e8196ff604021a0003bd081a00034ec5183e011a00102e0f19312a011a00032e801901a5011a0002da781903e819cf06011a00013a34182019a8f118201903e818201a00013aac0119e143041903e80a1a00030219189c011a00030219189c011a0003207c1901d9011a000330001901ff0119ccf3182019fd40182019ffd5182019581e18201940b318201a00012adf18201a0002ff941a0006ea7818dc0001011a00010f92192da7000119eabb18201a0002ff941a0006ea7818dc0001011a0002ff941a0006ea7818dc0001011a000c504e197712041a001d6af61a0001425b041a00040c660004001a00014fab18201a0003236119032c010119a0de18201a00033d7618201979f41820197fb8182019a95d1820197df718201995aa18201a0374f693194a1f0aff019f1a0003236119032c01011903e819023b00011903e8195e7104011903e818201a0001ca761928eb041959d818641959d818641959d818641959d818641959d818641959d81864186418641959d81864194c5118201a0002acfa182019b551041a000363151901ff00011a00015c3518201a000797751936f404021a0002ff941a0006ea7818dc0001011903e8196ff604021a0003bd081a00034ec5183e011a00102e0f19312a011a00032e801901a5011a0002da781903e819cf06011a00013a34182019a8f118201903e818201a00013aac0119e143041903e80a1a00030219189c011a00030219189c011a0003207c1901d9011a000330001901ff0119ccf3182019fd40182019ffd5182019581e18201940b318201a00012adf18201a0002ff941a0006ea7818dc0001011a00010f92192da7000119eabb18201a0002ff941a0006ea7818dc0001011a0002ff941a0006ea7818dc0001011a0011b22c1a0005fdde00021a000c504e197712041a001d6af61a0001425b041a00040c660004001a00014fab18201a0003236119032c010119a0de18201a00033d7618201979f41820197fb8182019a95d1820197df718201995aa18201a0223accc0a1a0374f693194a1f0a1a02515e841980b30aff14821a00d59f801b00000002540be40015821a03b20b801b00000004a817c800581cd4bf7eb45b72dffa5ac33d5c902fe409e4e611f2e9a52fb0d09784c3a7021a00016000081901f40cd81e8200010e82070012a2009f1a0003236119032c01011903e819023b00011903e8195e7104011903e818201a0001ca761928eb041959d818641959d818641959d818641959d818641959d818641959d81864186418641959d81864194c5118201a0002acfa182019b551041a000363151901ff00011a00015c3518201a000797751936f404021a0002ff941a0006ea7818dc0001011903e8196ff604021a0003bd081a00034ec5183e011a00102e0f19312a011a00032e801901a5011a0002da781903e819cf06011a00013a34182019a8f118201903e818201a00013aac0119e143041903e80a1a00030219189c011a00030219189c011a0003207c1901d9011a000330001901ff0119ccf3182019fd40182019ffd5182019581e18201940b318201a00012adf18201a0002ff941a0006ea7818dc0001011a00010f92192da7000119eabb18201a0002ff941a0006ea7818dc0001011a0002ff941a0006ea7818dc0001011a000c504e197712041a001d6af61a0001425b041a00040c660004001a00014fab18201a0003236119032c010119a0de18201a00033d7618201979f41820197fb8182019a95d1820197df718201995aa18201a0374f693194a1f0aff019f1a0003236119032c01011903e819023b00011903e8195e7104011903e818201a0001ca761928eb041959d818641959d818641959d818641959d818641959d818641959d81864186418641959d81864194c5118201a0002acfa182019b551041a000363151901ff00011a00015c3518201a000797751936f404021a0002ff941a0006ea7818dc0001011903e8196ff604021a0003bd081a00034ec5183e011a00102e0f19312a011a00032e801901a5011a0002da781903e819cf06011a00013a34182019a8f118201903e818201a00013aac0119e143041903e80a1a00030219189c011a00030219189c011a0003207c1901d9011a000330001901ff0119ccf3182019fd40182019ffd5182019581e18201940b318201a00012adf18201a0002ff941a0006ea7818dc0001011a00010f92192da7000119eabb18201a0002ff941a0006ea7818dc0001011a0002ff941a0006ea7818dc0001011a0011b22c1a0005fdde00021a000c504e197712041a001d6af61a0001425b041a00040c660004001a00014fab18201a0003236119032c010119a0de18201a00033d7618201979f41820197fb8182019a95d1820197df718201995aa18201a0223accc0a1a0374f693194a1f0a1a02515e841980b30aff14821a00d59f801b00000002540be40015821a03b20b801b00000004a817c8000181a100848258201aba5fea24ea77d696737416bdfb6136fda5f0fa745f6b4aa682283e7d3e0f2d5840545819f889b346c17b95aafb6a6f81cf63d1149e8571727c3b682e21ce0308562176a2f2d628a6b4680c6d4da80a91631f5028533cf2fb2519bb739ee04e3f0b825820b33fb2984d0a104491afeecdcc327dc1fb0d72fd8bcb2a819999a3a6ec1f38395840e531d044d7c36361ded8e8fb90ce0cb590d2fbe67eb8a581d26e8064a87fde7e720329594f541df0734ca85f18ec3b44bf1731dad406fa7cc73171940215dc09825820d7361dba245e89a9f1e87247810aad8ab6bb5d8e8cfce978b458ff3bb4f34cb75840b0ad3e36ae6d04218cdb44f0849210fb9b52ca4208dd1fa97f5a7970021179faf86959661390afc2c2d70ee7517d6fa22ab0e21c00f2752ab252ea9e883dde0e825820f442e8c87378195b57ac5cc02590290158eb8707eda84155a035e5f5e6ed4f0a58408b8a4fffc01fa067f0d3c9afc2223499528e0f1e6e6f50ff28ba731c3923be96f7c8e4a15419b5abec02a45c96c195d8077d7340a2c1d59e5cf05c22219da508a080";
+
     #[test]
     fn sancho_testnet_alonzo_block_with_babbage_costmodels() {
-        let bytes = hex::decode("820585828f1910e21a000151bc5820ab3a121e39d38cd844ed40988b435d5776d2be3e1a3ff15ee8b6f6099b6eefbc58201aba5fea24ea77d696737416bdfb6136fda5f0fa745f6b4aa682283e7d3e0f2d58200bd8f3ceb4057507f67c7c5730a62d691359983cae18d02f94ca80a020c9a6e682584014bc82eb1ada0c58bf1458140a81d569e9889dc303b9ef71afaa27615bd6b1ef5583ed74a14203b195404977edf201ce92f72bbb915ae5e1a187b74adf1e009f5850bb6a6709fe6fecf871c1f2dd3a657d3d2a8afc1f76f60d7d4d64706396f7b15ed9a628bc9a15a5dd444b316872a9d8bcbe90c01a826ad15f4c8b7f647aaed832d18efa5607b27a17ad432773fbc8430a8258409428e60adabd462ceba6c7ad8a68e8c444b1e66c31ec3e498baddf7f58a7a57b663db391f9b181457148d3704d6fc62ab71615ebf3acf89e90336f2974e72bc8585011bdede95b5b188d5649467ad62ff4df95068ae5d9462f54211d4522dc812cddba98bc7f9e0187e550e4c424d31651a63c1692b6ef335d7346d7e3f499f496beb7f402356510c4b00ab3ed932069ee03190d9158201d39330aaf40049b703f7c1a9b2eb6c9f84f11cc8d8f456aafb6266f757d685a58201dc1cddf59c04f8744d412c5490f6b87367fa109a22140b4a2987edd1433f75f00005840556655ab6e794c101e840475c9fba24c75ee06c7b6932ca0b596e05ea973d7c77d0bb0af98ed08717fec6d365b6462817567dbbe484c1623cdee61c27e5dcd0107025901c04fbcd1a7d282498822e5ce754b2cf976551678ab7984b02d1a6c66556329667b4ac9a702c44e17dd68e678825afb2696d8c56cb94a1619edde2b5b570f818008060b62df089138b25a9130eaf11ce9467830b9194e4cd0fc50a61c190e50011297fdefb16849ab3c53191a8d254eee51109417c3be4605ffe795b6ff96aa0778a949a342a53e93ba4143be6b10df056eb80b60911490a48c54710081f9f2d21d6a93b21952ee892bd3a70954ecc7d3f733321a572df04c1b163b74c7d4280e00e4784b35077b8bac624644de3d4fe3dbf82fb4ff22a8e5ee9f30ac67caef4b8cef2021206c6f2395109fa34d164c926380fd88385a8d166ea7d0de46e179bdfcbf6c201aa8de5a44f211900909910b8add25494f26994ccde53b4812a9ec329e682507a3e272dcb6ebc3973ccb4a23e9dd0eedf3a5d3b713568c4ae02dbeaa9e5b4637ca5c1a03ae030c2b504cfa932476383a790c91e79ee05321113e01447a0783deedd9b6d0a14ea781719f7c2a90f09f400d52621234c40803a3c30ed3c4c0acedd89180822a9fdf6147faee7458f8feb3334878c569f2503925f147948066f34f1b7cd783bec633a5df03bd11f4d5a99b21450b5bfbc13cd29339fc1bbe81a4008182582064ed7c9676c3b2954c188b60def3950bd750f8af90a233b077b6ff434539530803018182581d604da890918cb29f4446d07602a7c3877887326eb9e98394b8d6ce57b31b006983fdc40520ce021a0004b3890682a3581cc1ad22cabb342cbb83ce3859708232f4945ccb669e9b5f932cffc0eda7021a00016000081901f40cd81e8200010e82070012a2009f1a0003236119032c01011903e819023b00011903e8195e7104011903e818201a0001ca761928eb041959d818641959d818641959d818641959d818641959d818641959d81864186418641959d81864194c5118201a0002acfa182019b551041a000363151901ff00011a00015c3518201a000797751936f404021a0002ff941a0006ea7818dc0001011903e8196ff604021a0003bd081a00034ec5183e011a00102e0f19312a011a00032e801901a5011a0002da781903e819cf06011a00013a34182019a8f118201903e818201a00013aac0119e143041903e80a1a00030219189c011a00030219189c011a0003207c1901d9011a000330001901ff0119ccf3182019fd40182019ffd5182019581e18201940b318201a00012adf18201a0002ff941a0006ea7818dc0001011a00010f92192da7000119eabb18201a0002ff941a0006ea7818dc0001011a0002ff941a0006ea7818dc0001011a000c504e197712041a001d6af61a0001425b041a00040c660004001a00014fab18201a0003236119032c010119a0de18201a00033d7618201979f41820197fb8182019a95d1820197df718201995aa18201a0374f693194a1f0aff019f1a0003236119032c01011903e819023b00011903e8195e7104011903e818201a0001ca761928eb041959d818641959d818641959d818641959d818641959d818641959d81864186418641959d81864194c5118201a0002acfa182019b551041a000363151901ff00011a00015c3518201a000797751936f404021a0002ff941a0006ea7818dc0001011903e8196ff604021a0003bd081a00034ec5183e011a00102e0f19312a011a00032e801901a5011a0002da781903e819cf06011a00013a34182019a8f118201903e818201a00013aac0119e143041903e80a1a00030219189c011a00030219189c011a0003207c1901d9011a000330001901ff0119ccf3182019fd40182019ffd5182019581e18201940b318201a00012adf18201a0002ff941a0006ea7818dc0001011a00010f92192da7000119eabb18201a0002ff941a0006ea7818dc0001011a0002ff941a0006ea7818dc0001011a0011b22c1a0005fdde00021a000c504e197712041a001d6af61a0001425b041a00040c660004001a00014fab18201a0003236119032c010119a0de18201a00033d7618201979f41820197fb8182019a95d1820197df718201995aa18201a0223accc0a1a0374f693194a1f0a1a02515e841980b30aff14821a00d59f801b00000002540be40015821a03b20b801b00000004a817c800581cc264bca994a3a5deee5a1d9b92a3d7e9d6cbdb81f2f6989bb7f7b437a7021a00016000081901f40cd81e8200010e82070012a2009f1a0003236119032c01011903e819023b00011903e8195e7104011903e818201a0001ca761928eb041959d818641959d818641959d818641959d818641959d818641959d81864186418641959d81864194c5118201a0002acfa182019b551041a000363151901ff00011a00015c3518201a000797751936f404021a0002ff941a0006ea7818dc0001011903e8196ff604021a0003bd081a00034ec5183e011a00102e0f19312a011a00032e801901a5011a0002da781903e819cf06011a00013a34182019a8f118201903e818201a00013aac0119e143041903e80a1a00030219189c011a00030219189c011a0003207c1901d9011a000330001901ff0119ccf3182019fd40182019ffd5182019581e18201940b318201a00012adf18201a0002ff941a0006ea7818dc0001011a00010f92192da7000119eabb18201a0002ff941a0006ea7818dc0001011a0002ff941a0006ea7818dc0001011a000c504e197712041a001d6af61a0001425b041a00040c660004001a00014fab18201a0003236119032c010119a0de18201a00033d7618201979f41820197fb8182019a95d1820197df718201995aa18201a0374f693194a1f0aff019f1a0003236119032c01011903e819023b00011903e8195e7104011903e818201a0001ca761928eb041959d818641959d818641959d818641959d818641959d818641959d81864186418641959d81864194c5118201a0002acfa182019b551041a000363151901ff00011a00015c3518201a000797751936f404021a0002ff941a0006ea7818dc0001011903e8196ff604021a0003bd081a00034ec5183e011a00102e0f19312a011a00032e801901a5011a0002da781903e819cf06011a00013a34182019a8f118201903e818201a00013aac0119e143041903e80a1a00030219189c011a00030219189c011a0003207c1901d9011a000330001901ff0119ccf3182019fd40182019ffd5182019581e18201940b318201a00012adf18201a0002ff941a0006ea7818dc0001011a00010f92192da7000119eabb18201a0002ff941a0006ea7818dc0001011a0002ff941a0006ea7818dc0001011a0011b22c1a0005fdde00021a000c504e197712041a001d6af61a0001425b041a00040c660004001a00014fab18201a0003236119032c010119a0de18201a00033d7618201979f41820197fb8182019a95d1820197df718201995aa18201a0223accc0a1a0374f693194a1f0a1a02515e841980b30aff14821a00d59f801b00000002540be40015821a03b20b801b00000004a817c800581cd4bf7eb45b72dffa5ac33d5c902fe409e4e611f2e9a52fb0d09784c3a7021a00016000081901f40cd81e8200010e82070012a2009f1a0003236119032c01011903e819023b00011903e8195e7104011903e818201a0001ca761928eb041959d818641959d818641959d818641959d818641959d818641959d81864186418641959d81864194c5118201a0002acfa182019b551041a000363151901ff00011a00015c3518201a000797751936f404021a0002ff941a0006ea7818dc0001011903e8196ff604021a0003bd081a00034ec5183e011a00102e0f19312a011a00032e801901a5011a0002da781903e819cf06011a00013a34182019a8f118201903e818201a00013aac0119e143041903e80a1a00030219189c011a00030219189c011a0003207c1901d9011a000330001901ff0119ccf3182019fd40182019ffd5182019581e18201940b318201a00012adf18201a0002ff941a0006ea7818dc0001011a00010f92192da7000119eabb18201a0002ff941a0006ea7818dc0001011a0002ff941a0006ea7818dc0001011a000c504e197712041a001d6af61a0001425b041a00040c660004001a00014fab18201a0003236119032c010119a0de18201a00033d7618201979f41820197fb8182019a95d1820197df718201995aa18201a0374f693194a1f0aff019f1a0003236119032c01011903e819023b00011903e8195e7104011903e818201a0001ca761928eb041959d818641959d818641959d818641959d818641959d818641959d81864186418641959d81864194c5118201a0002acfa182019b551041a000363151901ff00011a00015c3518201a000797751936f404021a0002ff941a0006ea7818dc0001011903e8196ff604021a0003bd081a00034ec5183e011a00102e0f19312a011a00032e801901a5011a0002da781903e819cf06011a00013a34182019a8f118201903e818201a00013aac0119e143041903e80a1a00030219189c011a00030219189c011a0003207c1901d9011a000330001901ff0119ccf3182019fd40182019ffd5182019581e18201940b318201a00012adf18201a0002ff941a0006ea7818dc0001011a00010f92192da7000119eabb18201a0002ff941a0006ea7818dc0001011a0002ff941a0006ea7818dc0001011a0011b22c1a0005fdde00021a000c504e197712041a001d6af61a0001425b041a00040c660004001a00014fab18201a0003236119032c010119a0de18201a00033d7618201979f41820197fb8182019a95d1820197df718201995aa18201a0223accc0a1a0374f693194a1f0a1a02515e841980b30aff14821a00d59f801b00000002540be40015821a03b20b801b00000004a817c8000181a100848258201aba5fea24ea77d696737416bdfb6136fda5f0fa745f6b4aa682283e7d3e0f2d5840545819f889b346c17b95aafb6a6f81cf63d1149e8571727c3b682e21ce0308562176a2f2d628a6b4680c6d4da80a91631f5028533cf2fb2519bb739ee04e3f0b825820b33fb2984d0a104491afeecdcc327dc1fb0d72fd8bcb2a819999a3a6ec1f38395840e531d044d7c36361ded8e8fb90ce0cb590d2fbe67eb8a581d26e8064a87fde7e720329594f541df0734ca85f18ec3b44bf1731dad406fa7cc73171940215dc09825820d7361dba245e89a9f1e87247810aad8ab6bb5d8e8cfce978b458ff3bb4f34cb75840b0ad3e36ae6d04218cdb44f0849210fb9b52ca4208dd1fa97f5a7970021179faf86959661390afc2c2d70ee7517d6fa22ab0e21c00f2752ab252ea9e883dde0e825820f442e8c87378195b57ac5cc02590290158eb8707eda84155a035e5f5e6ed4f0a58408b8a4fffc01fa067f0d3c9afc2223499528e0f1e6e6f50ff28ba731c3923be96f7c8e4a15419b5abec02a45c96c195d8077d7340a2c1d59e5cf05c22219da508a080").unwrap();
+        let bytes = hex::decode(SANCHO_ALONZO_NETWORK_BLOCK).unwrap();
         let _block = MultiEraBlock::from_explicit_network_cbor_bytes(&bytes).unwrap();
     }
 
+    #[test]
+    fn alonzo_witness_summary() {
+        let bytes = hex::decode(SANCHO_ALONZO_NETWORK_BLOCK).unwrap();
+        let block = MultiEraBlock::from_explicit_network_cbor_bytes(&bytes).unwrap();
+        let summary = block.witness_summary();
+        assert_eq!(
+            summary.transactions,
+            vec![TransactionWitnessSummary {
+                index: 0,
+                vkey_witnesses: 4,
+                ..Default::default()
+            }]
+        );
+        // consistent with the (cloning) witness set accessor
+        for (summary, wits) in summary
+            .transactions
+            .iter()
+            .zip(block.transaction_witness_sets())
+        {
+            assert_eq!(
+                summary.vkey_witnesses,
+                wits.vkeywitnesses.map(|w| w.len() as u64).unwrap_or(0)
+            );
+        }
+        let json = serde_json::to_value(&summary).unwrap();
+        assert_eq!(json["transactions"][0]["vkey_witnesses"], 4);
+        assert_eq!(json["transactions"][0]["is_invalid"], false);
+
+        let byron = MultiEraBlock::from_explicit_network_cbor_bytes(
+            &hex::decode(BYRON_MAIN_BLOCK).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            byron.witness_summary().transactions.len(),
+            byron.transaction_bodies().len()
+        );
+    }
+
+    #[test]
+    fn mary_witness_summary() {
+        let bytes = hex::decode(MARY_NETWORK_BLOCK).unwrap();
+        let block = MultiEraBlock::from_explicit_network_cbor_bytes(&bytes).unwrap();
+        let summary = block.witness_summary();
+        assert_eq!(summary.transactions.len(), 35);
+        // byron address spends are witnessed by bootstrap witnesses only
+        assert_eq!(
+            summary.transactions[20],
+            TransactionWitnessSummary {
+                index: 20,
+                bootstrap_witnesses: 8,
+                ..Default::default()
+            }
+        );
+        // native script (multisig) spends
+        assert_eq!(
+            summary.transactions[8],
+            TransactionWitnessSummary {
+                index: 8,
+                vkey_witnesses: 2,
+                native_scripts: 1,
+                ..Default::default()
+            }
+        );
+        let count = |f: fn(&TransactionWitnessSummary) -> u64| {
+            summary.transactions.iter().map(f).sum::<u64>()
+        };
+        assert_eq!(count(|tx| tx.bootstrap_witnesses), 12);
+        assert_eq!(count(|tx| tx.native_scripts), 8);
+    }
+
+    #[test]
+    fn alonzo_plutus_witness_summary() {
+        // mainnet tx 18565ab3c960c000531e5b359432397907d663c0ac5f5dbae80e1bf88d25c8a0 which spends
+        // a plutus v1 script output
+        let tx = AlonzoTransaction::from_cbor_bytes(&hex::decode("84a70081825820473899cb48414442ea107735f7fc3e020f0293122e9d05e4be6f03ffafde5a0c00018283581d71aba3c2914116298a146af57d8156b1583f183fc05c0aa48ee95bec71821a001c41caa1581c6bec713b08a2d7c64baa3596d200b41b560850919d72e634944f2d52a14f537061636542756442696433303533015820f7f2f57c58b5e4872201ab678928b0d63935e82d022d385e1bad5bfe347e89d8825839015627217786eb781fbfb51911a253f4d250fdbfdcf1198e70d35985a9a013112333b21ec5063ae54f31b0ea883635b64530b70785a49c95041a040228dd021a000db2d907582029ed935cc80249c4de9f3e96fdcea6b7da123a543bbe75fffe9e2c66119e426d0b582039249ec62e53b77ff197bf6821548157b14d56ef63ec3a0b233180e3ae4241740d81825820a90a895d07049afc725a0d6a38c6b82218b8d1de60e7bd70ecdd58f1d9e1218b000e81581c5627217786eb781fbfb51911a253f4d250fdbfdcf1198e70d35985a9a40081825820c9b539dea76713f036285a9c89d164ad929597367a5572c9911832f12fffe0235840bb7d26b65a15f9aa917663178d27e2f16a59bbd4aafe067090dcb60826a585d2b81bf6f25136f5c74fdf78fefcd1928ac6e03d28d13da10de1c03b185e697301038159194059193d010000332332233223232333332222233332222332232333222323332223233333333222222223233322232333322223232332232333222323332223232332233223232333332222233223322332233223322332222323223223232533530343330093333573466e1d401920042304e3055357426aae7940208cccd5cd19b875007480088c140c158d5d09aab9e500923333573466e1d40212000204f235058353059335738921035054310005a49926499263333573466e1d40112006205223333573466e1d40152004205523333573466e1d40192002205323333573466e1d401d2000205623505935305a3357389201035054310005b4992649926498cccd5cd19b8735573aa004900011980619191919191919191919191999ab9a3370e6aae75402920002333333333301a335028232323333573466e1cd55cea8012400046604060766ae854008c0b4d5d09aba25002235066353067335738921035054310006849926135573ca00226ea8004d5d0a80519a8140149aba150093335502f75ca05c6ae854020ccd540bdd728171aba1500733502804435742a00c66a05066aa0aa09aeb4d5d0a8029919191999ab9a3370e6aae754009200023350223232323333573466e1cd55cea80124000466a05466a086eb4d5d0a80118241aba135744a00446a0d46a60d666ae712401035054310006c49926135573ca00226ea8004d5d0a8011919191999ab9a3370e6aae7540092000233502833504375a6ae854008c120d5d09aba2500223506a35306b3357389201035054310006c49926135573ca00226ea8004d5d09aba250022350663530673357389201035054310006849926135573ca00226ea8004d5d0a80219a8143ae35742a00666a05066aa0aaeb88004d5d0a801181d1aba135744a00446a0c46a60c666ae71241035054310006449926135744a00226ae8940044d5d1280089aba25001135744a00226ae8940044d5d1280089aba25001135573ca00226ea8004d5d0a8011919191999ab9a3370ea00290031180f981e1aba135573ca00646666ae68cdc3a801240084603c608c6ae84d55cf280211999ab9a3370ea00690011180f18189aba135573ca00a46666ae68cdc3a80224000460426eb8d5d09aab9e500623505d35305e3357389201035054310005f49926499264984d55cea80089baa001357426ae8940088d4158d4c15ccd5ce2490350543100058499261057135055353056335738920103505435000574984d55cf280089baa001135573a6ea80044d55cea80089baa0012212330010030022001222222222212333333333300100b00a00900800700600500400300220012212330010030022001122123300100300212001122123300100300212001122123300100300212001212222300400521222230030052122223002005212222300100520011232230023758002640026aa080446666aae7c004940388cd4034c010d5d080118019aba200203f23232323333573466e1cd55cea801a4000466600e6464646666ae68cdc39aab9d5002480008cc034c0c4d5d0a80119a8098169aba135744a00446a0846a608666ae712401035054310004449926135573ca00226ea8004d5d0a801999aa805bae500a35742a00466a01eeb8d5d09aba2500223503e35303f335738921035054310004049926135744a00226aae7940044dd50009110919980080200180110009109198008018011000899aa800bae75a224464460046eac004c8004d540e888c8cccd55cf80112804919a80419aa81718031aab9d5002300535573ca00460086ae8800c0e84d5d08008891001091091198008020018900089119191999ab9a3370ea002900011a80418029aba135573ca00646666ae68cdc3a801240044a01046a06a6a606c66ae7124010350543100037499264984d55cea80089baa001121223002003112200112001232323333573466e1cd55cea8012400046600c600e6ae854008dd69aba135744a00446a05e6a606066ae71241035054310003149926135573ca00226ea80048848cc00400c00880048c8cccd5cd19b8735573aa002900011bae357426aae7940088d40acd4c0b0cd5ce2481035054310002d499261375400224464646666ae68cdc3a800a40084a00e46666ae68cdc3a8012400446a014600c6ae84d55cf280211999ab9a3370ea00690001280511a8171a981799ab9c490103505431000304992649926135573aa00226ea8004484888c00c0104488800844888004480048c8cccd5cd19b8750014800880188cccd5cd19b8750024800080188d4098d4c09ccd5ce2490350543100028499264984d55ce9baa0011220021220012001232323232323333573466e1d4005200c200b23333573466e1d4009200a200d23333573466e1d400d200823300b375c6ae854014dd69aba135744a00a46666ae68cdc3a8022400c46601a6eb8d5d0a8039bae357426ae89401c8cccd5cd19b875005480108cc048c050d5d0a8049bae357426ae8940248cccd5cd19b875006480088c050c054d5d09aab9e500b23333573466e1d401d2000230133016357426aae7940308d40acd4c0b0cd5ce2481035054310002d49926499264992649926135573aa00826aae79400c4d55cf280109aab9e500113754002424444444600e01044244444446600c012010424444444600a010244444440082444444400644244444446600401201044244444446600201201040024646464646666ae68cdc3a800a400446660106eb4d5d0a8021bad35742a0066eb4d5d09aba2500323333573466e1d400920002300a300b357426aae7940188d4070d4c074cd5ce249035054310001e499264984d55cea80189aba25001135573ca00226ea80048488c00800c888488ccc00401401000c80048c8c8cccd5cd19b875001480088c018dd71aba135573ca00646666ae68cdc3a80124000460106eb8d5d09aab9e500423501635301733573892010350543100018499264984d55cea80089baa001212230020032122300100320011122232323333573466e1cd55cea80124000466aa010600c6ae854008c014d5d09aba25002235013353014335738921035054310001549926135573ca00226ea8004448848cc00400c00844800484888c00c01084888c00801048880048004488880104888800c488880084888800480048c8c8c8cccd5cd19b8735573aa006900011999111998068018010009bae35742a0066eb8d5d0a8011bad357426ae8940088d4018d4c01ccd5ce2481035054310000849926135744a00226aae7940044dd5000893090009000911091998008020018011000889191800800911980198010010009991999111919191991199991111991199911191919191919991119911919191919199999111119191919191999111999111999999991111111199119999911111991191919191991199119911919999111199119911991199119911919191919191991199119191919191919191919999111199119191191919191919111191919191919192999a983d80510a9999a9831805099835199a8342839183f8009a9aa83d280311000998351991199ab9a3371200400211202110026603860bea00460506a604802444444444400260bea00a660d46601aa00a60c4002a66a610a026603aa010603e002210e0226605260be66026a010603e00260bea0042c2660d46603aa010603e002660d4666a0d0a0e46a6aa0f4a00c440020fa6601aa00a60c40022660d4666a0d0a0e46a6aa0f4a00c440020fa660d46601aa00a60c4002660d46601866026a010603e00260c4002660086a05460bea004a00642a6666a60c60142c2660d46601866026a010a00660c4002660d46605260420026046024660086042002603e00226603aa010603e0022c2a6666a60c40122a66a6108026644666ae68cdc4801000843808440098082800a40042a66a6a0ec605401026102022c442a66a6a0f000226106022c46442a66a6a0f600226a6aa0fc6a6aa0fca0044400444a666a61040200242660e26602800660d2002660e2660606a06260cc0066054032660e2666a0de0ca605000290011a9aa840809a9aa84080a80291000912999a98428080090b10b0999a83883399814980d2805a4004603400442c2660e0666a0dc0c86604c602ea0109001180b8011a9aa840009a9aa84000a80211000912999a98420080090998399980b001983580099839998191a8199834001981600d999a8388339815000a400442c2c4426110022c266aa0fa601200660120022a66a6a0ec605401026104022c4646442a66a6a0f40022a666a60fe6a6aa0faa0064400242660dc66022a00660cc002660dc6605a6a05c60c6a006604e02c666a0d80c4604a002900110b0b1109844008b09a9aa83da80091001098038008b0b0b0a99a9a8369a9816003911a981800111111111111982300500590980e9a981e000910008b0a99a9a83a191a98170009111111111001a802898390b110a99a9a83b0008801110983b0b1191919191299a98438099815803241012179fa042660d86605660c26602aa014a0226054a004660d86605660c26602aa0146a6aa0f8a020440046054a0066605660c26602aa014002605466044660446604400ca004a0066a6aaa050a0084440022660d86605660c26602aa014a0226054a00a6605660c26602aa01400260546604400ca00a26a6aaa04ca00444400626a6aaa04aa0024440042666aaa04a660e40046a6aaa048a01c444002660e40046a6aa0f0a01844002660e40046a60440204444444440062660e20026a6aaa046a01a44400426a6aa0eaa002440042a66a6a0e2604a006260e02c442a66a6a0e60022600600444260e82c46a60766a60720024440064466a60ae0044c4a66a6a0d86a607800844400242a66a6a0da646a605e0024444444444a66a6a0f0666aa609824002a09e46a6aa1080200244a66a612202666ae68cdc7801007849808490089a83e8018a83e001109a83d9a9aa84200800910008a83ca80311919190a99a9a8389999999aba400423333573466e1d40092004233335573ea0084a0ea46666aae7cd5d128029299a9a83a98389aba150062135078308501001150762507607307223333573466e1d400d2002233335573ea00a4a0ec46666aae7cd5d128031299a9a83b18391aba150072135079308701001150772507707407323333573466e1d40112000233335573ea00c46a0f0108024a0ee0e84a0ec9324c93128399283992839928398381099aa83f18108050008b09aab9d5002135573ca00226ea800458584d4c0980048800888cc07cccc158008d4c068020888888888024ccd417dc51a980d004111111111003800a4004446603c6660aa004602e00e666a0bce28d4c06401c8888888880180052002135301600422222222200413535550175001222003135301400222222222200523322300200132233200132001333550023233503b22333503a0030010023503700133503a22230033002001200122337000029001000a400060662400266466aa603a2400244a66a60f06006004266a0d60040022002a0d446a6aaa02e002444660bc666a0b8042602c00c006666a0b80a400290011919a800a834a835091199aa829911a9aa83700111199aa82b911a9aa83900111299a983f999ab9a3370e002900004080840008801899805199aaa81080300100080180180080080191199aa980d890009119aa98060900091a9aa8360009119aa83780119aa98078900091a9aa8378009119aa839001199a9aa80700091980a24000002446602a004002466028002900000099aa98060900091a9aa8360009119aa837801199a9aa805800919aa98080900091a9aa8380009119aa8398011aa80900080091199aaa805011801000919aa98080900091a9aa8380009119aa8398011aa808000800999aaa80280f001000a8341a980f8011111111111199aa981289000911a981d0011111a981f8019119a982d8011299a984300999ab9a3371e0260021100210e02266a0f200a00e200e400ea0e4012222444666aa603624002a0ce66aa60142400246a6aa0d40024466aa0da0046aa018002666aa603624002446a6aa0d600444a66a60f0666aa606c240026466a07844666a6a016006440040040026a6a0120024400266a01244a66a60f400420f820020f246a6aa0dc002446601400400a00c2006266a0d6008006a0d000266aa60142400246a6aa0d4002446466aa0dc006600200a640026aa0f444a66a6a0d600226aa0180064426a6aa0e000444a66a60fa66018004010266aa02200e0022600c00600424424660020060042400222424446006008224424446600400a00822424446002008224002640026aa0da442244a66a6a0c00022a0c444266a0c6600800466aa600c240020080024466e0000800488d4c05400888888888894cd4d4178ccd54c0c84800540d494cd4c1d4ccd5cd19b8f00c0010770761350610011506000321077107523530220012220022353062001222003223370200400246a60c000244400246a600600244444444401046a60040024444444440044444444442466666666600201401201000e00c00a0080060044002222444246660020080060042224002400244666ae68cdc400100082f8300900091a9802000911a98040011111111111299a9a8289980f005005909a9810000911a9812000911199aa980a09000911a98148011111a9817004111a98180029119299a983b99a9826802919a98270021299a983c999ab9a3371e0040020f60f42a00620f440f4466a609c00840f44a66a60f2666ae68cdc780100083d83d0a801883d099a83500500488048a99a9a83000190a99a9a8308011099a9825801119a9826001119a9828001119a9828801119812001000903e919a9828801103e91981200100091103e91119a9827002103e911299a983f199ab9a3370e00c006100020fe2a66a60fc666ae68cdc38028010400083f89982b802000883f883f883c0a99a9a8300009083c083c283080789931a982799ab9c4901024c6600050498c8004d5417088448894cd4d41400044008884cc014008ccd54c01c4800401401000488ccd5cd19b8f00200105c05b2212330010030022001222222222212333333333300100b00a0090080070060050040030022001122123300100300212001122123300100300212001122123300100300212001121222300300411222002112220011200122533335300f0012150372150372150372133355300a12001500d2353005001225335304f5335304f333573466e3cd4c06000888008d4c060010880081441404ccd5cd19b873530180022200135301800422001051050105013503b0031503a003221233001003002200122212333001004003002200122123300100300220013200135504522112225335350390011350060032213335009005300400233355300712001005004001123535004001220011235350030012200213350022253353502b002210031001502a12212330010030021200121222230040052122223003005212222300200521222230010052001221233001003002200121222222230070082212222222330060090082122222223005008122222220041222222200322122222223300200900822122222223300100900820012122300200322212233300100500400320012122300200321223001003200122333573466e1c0080040ac0a88ccc00800522100488100222323230010053200135502c223353501d0014800088d4d54088008894cd4c0bcccd5cd19b8f00200903103013007001130060033200135502b223353501c0014800088d4d54084008894cd4c0b8ccd5cd19b8f00200703002f100113006003112232001320013550292253353501a0011003221330060023004001235301f0012220021222200412222003122220021222200120011200112001225335301d0021001101e2323232323333333574800a46666ae68cdc39aab9d5005480008cccd55cfa8029280691999aab9f50052500e233335573ea00a4a01e46666aae7cd5d128031299a9a807a99a9a807a99a9a80798061aba150092135012223330240030020011501021533535010300d35742a012426a02660040022a0222a02042a66a6a020646666666ae900049404c9404c9404c8d4050dd6801128098081aba150082135013300200115011150102501000d00c00b00a2500c4989402c9402c9402c9402c0204d5d1280089aba25001135573ca00226ea80048ccccccd5d20009280312803128031280311a8039bae00200312001200112122300200311220011200112253335300c0022153335300d00221330050020012130161613015162153335300d0022130161621330050020011301516153335300c001213015162130151610172253353014333573466e3cd4c03c008888008d4c03c0048880080580544ccd5cd19b8735300f00222200135300f00122200101601510152233223370600400266e080092014001262611220021221223300100400312001112212330010030021120012122230030042122230020041222001200122212333001004003002200126262612200212200120011123230010012233003300200200133223322332233333333300248811cd5e6bf0500378d4f0da4e8dde6becec7621cd8cbf5cbb9b87013d4cc0048811c6bec713b08a2d7c64baa3596d200b41b560850919d72e634944f2d520048810853706163654275640048810b5370616365427564426964003335550044891c826d9fafe1b3acf15bd250de69c04e3fc92c4493785939e069932e8900483001920e209335500648811c88269f8b051a739300fe743a7b315026f4614ce1216a4bb45d7fd0f500482209d20882748203db810920a09c012222222221233333333300100a00900800700600500400300220011112221233300100400300211120011122123300100300211200110482d866820181d866820083581c5627217786eb781fbfb51911a253f4d250fdbfdcf1198e70d35985a9443330353301d8668200800581840000d866820380821a004c4b401a77359400f5a1190195a10045d866820080").unwrap()).unwrap();
+        let mut block = match MultiEraBlock::from_explicit_network_cbor_bytes(
+            &hex::decode(SANCHO_ALONZO_NETWORK_BLOCK).unwrap(),
+        )
+        .unwrap()
+        {
+            MultiEraBlock::Alonzo(block) => block,
+            _ => panic!("expected alonzo block"),
+        };
+        block.transaction_witness_sets[0] = tx.witness_set;
+        assert_eq!(
+            MultiEraBlock::Alonzo(block).witness_summary().transactions,
+            vec![TransactionWitnessSummary {
+                index: 0,
+                vkey_witnesses: 1,
+                plutus_v1_scripts: 1,
+                datums: 2,
+                redeemers: 1,
+                ..Default::default()
+            }]
+        );
+    }
+
+    #[test]
+    fn alonzo_redeemers_to_conway_witness_set() {
+        // { 5: [_ [0, 0 (2 bytes), 121([_ ]), [1000000, 100000000]],
+        //        [3, 1, 121([_ ]), [1000000, 100000000]]] }
+        let redeemers_bytes = hex::decode(
+            "9f84001900 00d8799fff821a000f42401a05f5e10084030 1d8799fff821a000f42401a05f5e100ff"
+                .replace(' ', ""),
+        )
+        .unwrap();
+        let mut wits_bytes = vec![0xa1, 0x05];
+        wits_bytes.extend(&redeemers_bytes);
+        let wits =
+            crate::alonzo::AlonzoTransactionWitnessSet::from_cbor_bytes(&wits_bytes).unwrap();
+        let conway_wits = wits.to_conway_witness_set();
+        let redeemers = conway_wits.redeemers.as_ref().unwrap();
+        assert_eq!(redeemers.to_cbor_bytes(), redeemers_bytes);
+        assert_eq!(conway_wits.to_cbor_bytes(), wits_bytes);
+        let tags = redeemers
+            .clone()
+            .to_flat_format()
+            .iter()
+            .map(|r| r.tag)
+            .collect::<Vec<_>>();
+        assert_eq!(tags, vec![RedeemerTag::Spend, RedeemerTag::Reward]);
+
+        // and through the multi-era block accessor
+        let mut block = match MultiEraBlock::from_explicit_network_cbor_bytes(
+            &hex::decode(SANCHO_ALONZO_NETWORK_BLOCK).unwrap(),
+        )
+        .unwrap()
+        {
+            MultiEraBlock::Alonzo(block) => block,
+            _ => panic!("expected alonzo block"),
+        };
+        block.transaction_witness_sets[0] = wits;
+        let mut bytes = vec![0x82, 0x05];
+        bytes.extend(block.to_cbor_bytes());
+        let parsed = MultiEraBlock::from_explicit_network_cbor_bytes(&bytes).unwrap();
+        assert_eq!(
+            parsed.transaction_witness_sets()[0]
+                .redeemers
+                .as_ref()
+                .unwrap()
+                .to_cbor_bytes(),
+            redeemers_bytes
+        );
+    }
+
     #[test]
     fn mary_block_nonce() {
         let bytes = hex::decode("84828f1a0054dca41a01908b2f582008ecdb54a80c81073ddee790f3cf4f8d4ff4422eb369b41cd5e0e18c13de6bbe5820d498647f2b7a481e6207ee3dfe02f6292ae85224621c5aabf9c0bf84d097390f58203492992128b6388ed9b56ab0329e42c57df5d1ead48436ea26908195f33daf90825840a38e20ecfa89e9804837f88fe8f8cd13b6a9b4eafca581fdceebf545eea0ea7e4caf36af4d7549a36d15598f67dfbed9840b02b4469d37552a9212669a79096458509370fce20873730ca9fdce508a3a9b0d3350a153c0942f6c8bf5dccd4c699aa80a635ceccb965c6327d6ca25ca32727de977c81f9143438e963df7db2d0165423809360fc744a34a099f1f59f01603008258400005b23bdc51c75f8eeb21f83eacac3d0605891801471c6d61515b27810a1bb8094f4c0ae560ba418ef35bba28e09ed64ca671bdea2139087822455f83ac451258504d90ab3807ad8c84b48460ae154e460826c6af5f3f18d05abdb05ea2c478248428c7d4cb9e9a6d82d94df170c1cabead6fa6b7774e0017dbc5ede4d1cd7cbe34cb08a7af450ff449ff9f68b1cf14e8091924c858203d4f6c99d53160a58c879c9ca04a2bde019377456384aa90b92cc00bcdb3671958206c1a25de95c89fe2df4af81e8440ac621b723fcc06c0ddfc0f2c7dd900067c1f0418ad5840d21ef35d27287c1172ae7d7f593f9f84ea925260e8ca9dd913746085cbd2843d10926b87044bedbdaed4f923e543898e3f7f97f6c780ff01270fdf1e604c4a0a04005901c01bb9f5e7ee8f37a4b499ed7abe8114e76c3d6d453c228004103840798ec2c0b85fbc8de65cf68bf154834679cd9bdd90a849afaf9c950c43a30cac0b4eb504077d34b1c4bbdff197ba64266affc385d9a2bcedc6f08559fe93f2cb61d6c70af41f7582c74cbb7866a6c881b139832e250325bbe15674165a2b06e158ba91341427033f9ce3b813a2bff5451bdc3cc9b3ab7952920383d8f2aba9772251db7d6e8135c58df613036c908e405c9277193a36b1d9a4d40bb263696a3de374ab695ce2fd4401bf30e707ee43de4c05d7839f2bb1e8bed4d4e6b0d9cf0906807b4a6b0520b992825d0643add63168848f3fea1fe2ae147a174d5e7939161ba46a293838ab8cda64dc5a6989aea414b4e502d1edb8dd0c5d1421ab7fa92d4581d0b669d1114a5f340568d67c68ed1b07bb3beea0acedbd7ddb9c79478f6adcda27ff31d704c5a998839750d5ac084ce81e05766c4ae18f4f783063b6f3b76be050be55896ed7202695427b3e9c333319150e0dd993f3ba314ef89f53a8689939425c648060a58a5310efb311c6b3f7835e0f1a662f9f2e867a389e3394c7a1cc2f74de2c0330ebf40aab2cf4efbacaac3fed373b4d64d9c56d3cfcec7f28c9bf18926894a500818258209ea2ac72bc4e082b1a47c6914d9d953d73aff9792174cc1f08cf7215544dd1b901018182583901d42900731c039a877000445f88be659e574632707b056fd2960994c5b652ad81db60f5d8605dcec6e73d1ed932009651d9f3e8580ee07d221a1e1b9461021a0002b06d031a0190a6cb0758209a19f90d9ef5545280368b5503eeaa52c1da650f68fb2209255313a34c19a1d6a400818258200682aeb19e9a31ea5f7f5d4078c91ec94831b1498fcebcd30bc37b6800c17f7001018282582b82d818582183581ce03866945a566d9e8e32f27273877d026385a8e7d54d9494906ed530a0001ab897305f1a1fb8358082581d61d91ef01b73f3010bb173945cf5417257c00c002715a13052015ab54f1a26908966021a00029cd5031a01908ee6a4008182582048926e334cd6b9469a3540cfca974230f9fa25d0e90fa043566abef18d2049aa01018282584c82d818584283581c76ddc0051984f84c6be230066710c1aead629f632e718bb3f9c2c145a101581e581c73a2075d4789f0d61873b4c2d309310949ca41e434d97c21388727c8001a37285aec1a08b11eea82581d6179e67550b2ff311da1883ad0ccc6fb2bb7c75e5489acff735fcc68781ad17d4c43021a00029cd5031a01908ee6a40082825820df07e64b7151d6c6c4c15ae3b2b1944d501df2cf54ee5ae98feaf97e49e0067e00825820df07e64b7151d6c6c4c15ae3b2b1944d501df2cf54ee5ae98feaf97e49e0067e01018182581d614926b3e631270b5d5310b5481e0fc82b1a1c706ad278c4bc091e29211a2aab95ba021a0002d644031a05f5e100a40081825820c0cf68370b852bfed85b0a39ce4644c8195511149ab70620a9158206d08e05ce01018282581d61483f3120cfe28f4220f4b96228f8d110e5f2100285b914218e927c251b000000012a70c1c08258390111e921395d6c4ad0c7004ef928d9d0dcf5b33983749a14b59a0e6ee22aeb420cce4b576d6833d513fbb1aaf4b2396a427c9d96aee6d608df1b00000001b8a9ec0b021a00028de1031a0190a71ca400818258200b5dd952875af5c7ff5a251febd31e607ee1ff3be911e93d27a35f94c9e96cbd01018282584c82d818584283581c2be129a2509494c81b8fced13d9eb21529b6c1ef88f5ea3b578c27c7a101581e581c633c7e9a7d0419aaf93a78e0cf2fc2bcbc54a2a12371fd9d00645c8d001a6429c6be1a195edd7082584c82d818584283581c7cd9baffad4c847dce58c53bfaa0ae4d6f19ed9c0182585d3ca54bb9a101581e581cd4688480c96f8332739757b76b843a8c681328ec34c9b5b38d6898fb001a6ebfa4dc1b0000005286323835021a0002a8b1031a0190a72ba50081825820b2bdbe07d5cd4da8b8dbef9eee67ff6fb1877055d0b8309fbd4718591389eac5000181825839016445bb08465c26cf435a4274b8dadff753b3b9a87f4aa8f88479431d4eb646b86f18f6f4266684f37fbcf4027c650747dc45278ab0bf8c8e1a0048f96f021a000351d1031a01909ccd0682a7581c162f94554ac8c225383a2248c245659eda870eaa82d0ef25fc7dcd82a10d82015820d982e06fd33e7440b43cefad529b7ecafbaa255e38178ad4189a37e4ce9bf1fa581c2075a095b3c844a29c24317a94a643ab8e22d54a3a3a72a420260af6a10d82015820d982e06fd33e7440b43cefad529b7ecafbaa255e38178ad4189a37e4ce9bf1fa581c268cfc0b89e910ead22e0ade91493d8212f53f3e2164b2e4bef0819ba10d82015820d982e06fd33e7440b43cefad529b7ecafbaa255e38178ad4189a37e4ce9bf1fa581c60baee25cbc90047e83fd01e1e57dc0b06d3d0cb150d0ab40bbfead1a10d82015820d982e06fd33e7440b43cefad529b7ecafbaa255e38178ad4189a37e4ce9bf1fa581cad5463153dc3d24b9ff133e46136028bdc1edbb897f5a7cf1b37950ca10d82015820d982e06fd33e7440b43cefad529b7ecafbaa255e38178ad4189a37e4ce9bf1fa581cb9547b8a57656539a8d9bc42c008e38d9c8bd9c8adbb1e73ad529497a10d82015820d982e06fd33e7440b43cefad529b7ecafbaa255e38178ad4189a37e4ce9bf1fa581cf7b341c14cd58fca4195a9b278cce1ef402dc0e06deb77e543cd1757a10d82015820d982e06fd33e7440b43cefad529b7ecafbaa255e38178ad4189a37e4ce9bf1fa190102a40081825820a3d07b77d09868b01d07b4c53e40b71200639266b1776512e0981cd66f48eb5d0101828258390134d388e0c5251d087232b3dcc67caa6cce2937c97a270fb4f4a2499999b8e6a0a8c8e173ddd63478e982846ffbbcd165028a1cef78ae9ee71a078ca24982581d616904e8b2c26f3dda6c4a5db4b3ec9e31d581c9960977cfe9c6917a431a2d0faa34021a00029cd5031a01908ee6a40081825820ac9ceff2de86d2387950ed7d1197a517e772c5ddfecb5b94767fae888820b3d801018282584c82d818584283581c15c97f0866ba4f390e283a268b53e180cfdb5e34bcc464a357e36710a101581e581c83d3e2df30edf951c5ba0b8582a1a303432081a4295f18ba44e9a548001a6b9405c71a1392fc8082584c82d818584283581cde349abef27bb239d4ae5e7629c1400d32165097f684148808da3c97a101581e581c2b0b011ba3683d776e9d872a85987bd6e6942a6b8c30d6d7dc3dfb14001a18ff53f01b0000000107647432021a0002a8b1031a0190a729a40082825820bb6baeb11c7472779c3b3acc35db7897f2d36d11846909ba6dd5979239fd067000825820bb6baeb11c7472779c3b3acc35db7897f2d36d11846909ba6dd5979239fd067001018182584c82d818584283581c9c9490179ae2288142fcc6fc0f6aa8ff8c49531ed384740d5c73622ea101581e581c5981b261ab5ccd7bf7f2c1225ad7eefe385edcc839ba55113b3e95db001a0122212c1a0aba8850021a0002d644031a05f5e100a40082825820d4b800d689d733f523fa78361ffb5fade29d9bb13fcfbc344aa458bd0b0b976b018258203b7bfa5f182b349e13c0e1c56c6c81ca8c5cf40d7b72b416a3563783fc0ff134010182825839012d8b354447efe987387f329b63a3c86b6007371699ed5db4ac5cd88e2d8b354447efe987387f329b63a3c86b6007371699ed5db4ac5cd88e1a584abd6e82581d610237be10f5ec0ccb6cbd226b112f0940fed44ae0466d9b53962ba8b11a184eefca021a0002bf35031a01908ee6a400818258201cc75901ee9df32dbc50ce820052038faba13b465b25fe64f887d940c9f9458e0101828258390117c7c19c81dbf1187a5bdf387a320f46bf80246e30d5b97efd59ab9fdd7986e3b08ab70333fa1e4183b8735cf3474a31108fa0943de6b8cb1a034f468282584c82d818584283581c776eb96b96e2cbce528a47278a736ab725dd13ce98c027ccb548ed70a101581e581c1afbc57540db1561adeaccd7f4894065965cd230f6bdc70722aecd8b001a6a14bf9a1b0000000f31da326b021a0004623d031a0190a71ea400818258202c6cca35e5e4458cc35ebd173a6471b3ae29668fd06bd4ba5114955339f219e501018282584c82d818584283581cad8f02b7134781a28997cb23f2a78215322f6c1d8a58e9e47c9f445ea101581e581cc9a4c68c63646852d93de951824def94b774fd58431f7a5618115a19001a389631ac1a060871738258390181bfa0e759547ebf226bbd51a844095d56890ba05aea3a73665cbe881cdeaf2c1028e67b5b360171975136b41c9a58d9220bdd65fd6ac0791b00000003218293c1021a0005f9b0031a0190a71ea40081825820c306f318e9721757d3252cdc5dd31eac6bd6aee236beeb9601a772fa681e554301018282583901a6c024231f3bf4de746511675994db25b664aecc25789de65462eff4739f0761691131d31bd714501d8a6f9f44ac9b5b3a74932753c29efc821a00160a5ba1581c59960c8ca8871af59f70474cbb2c11e3d782614a4c4be7f25e6c783ea148616c666f6e7a6f731913888258390170a84cf34da92bfe2c4da8689f358caeb4d1715c73710aed235be3d71537f3dbdcfedc8079eb6e8c1af93563a6cc2a7ecfbe5b4ba92750ab821a9c11edf0a2581c36e8f59542d444e3afca00cecca881cbdf8257c1b07a2fd84ed25730a1574570737465696e4469646e744b696c6c48696d73656c661a000f4240581c59960c8ca8871af59f70474cbb2c11e3d782614a4c4be7f25e6c783ea148616c666f6e7a6f731984d0021a0002b041031a0190a73ba40081825820e71f446e150a646a1982fce26c0239395dfc52bf029d3b653e06232925148aa10101828258390126141bac8f8fde24eec91011bcffd28b7f221b52aaaddcb24b3b421f265b2a3a895d6d9ebf67b1d1f1b06927caf752d13e40bf4ea11c622a1a004c4b4082584c82d818584283581cfafa1e6485be8a43275f37446d9ec9268682dff27678956c1a01dffca101581e581cd8d97175289f3a7dd17a4b6107633ed785ce2d33a1ee00f20ef4d7fa001a9e288d2c1a3eff155d021a0002a56d031a0190a73ca4008182582020a32c2967acf50e42efa21bc6f2582f26def86152f15b66a0fffc61b1397d0100018282583901a0c0fed7b1d4288835dc2334aba0240d27e10dedb73badb27457ba91ec17628c4600f1d941b206a557581d904584da48ba972d554d3aa4891a03d8312082584c82d818584283581c505f6f99e8b2a44bc02753d04e1745910a28205187e4b743b6be8904a101581e581cd8d97175289f3a326c6a29619d56eb6e22830bd40ee474aea3ea120d001aa030fe341a02048ce2021a0002a56d031a0190a73da40083825820150ae07532b9e62de190ac6955cde4c33ec42ee86c7d8415c7f820d6561f280d08825820293f1fb4adaeded6aa8f3230d6075df6e8ea657fe3f36e3c78bfb71524d3a712098258204dbaa5d17d37aadddce5409a0aa4c299c9468f0cda7f672b3d9553713216e8290b018c82584c82d818584283581c0fea43d9bfa899adfbbf88e57e6798e53466219143130576b4122a44a101581e581c9b55b8bf594b59974d9edc197b6a68a1d5d10327348c8f5c20278339001a2c181f7a1a3b9aca0082584c82d818584283581c7d1d116c1bd0b92d8f307dcfdde3282316105c6249c995c5b4c96f48a101581e581c8f802fa39db6a972b8e0b1c4f7f9649c381f6af5dd53cc3658668cf1001aca1390591a01c52fa082584c82d818584283581c0fea43d9bfa899adfbbf88e57e6798e53466219143130576b4122a44a101581e581c9b55b8bf594b59974d9edc197b6a68a1d5d10327348c8f5c20278339001a2c181f7a1a01c9c38082584c82d818584283581cc5f21c78dba1146cb869e7c243165ea5841970045d4dd3cbec118f01a101581e581c4c119dd2b2270328df974203bca1f8e6d3bb601baebda45374871f21001ae0b305381b000000022ee0828082584c82d818584283581c90438a11280f3692dc611cb03d8d7cb1c3688ad8519b2c52b4acd791a101581e581c4c119dd2b2270357d09868034a444ba63688d21110f3a142d428c203001ae70673711a96d76ae082584c82d818584283581c23cf87cf3f4008fc1f26fd7667e3340325df267dfdb86b22234a11dfa101581e581c633c7e9a7d04199e20a1cae0b6a1c282c0310dba20b93265e8d2dbb9001a00cc23491a6e5404c082584c82d818584283581c7899f9c5244e1b64109325ae19e7f7920eb19f118fcc4cb525400b82a101581e581c84b2ff88d08bc4143a01f5c9795a72f04f627169d47c46873e86096b001a7919a0ea1a1a848ac882584c82d818584283581c700af7d911f9383b9a3011b68fb725dc43ff6b1884627c3e1007a20ba101581e581c84b2ff88d08bc4254f49a9c959dde14cccd5a314ef5d04631b2fc5e6001a1bfa4f131a00d86ce182584c82d818584283581cbe60727988fa9476b309f78e41f74035cfeb53c2c1ae16b3f5562b01a101581e581c84b2ff88d08bc477eb6000c9b770b219910c269ff575e1d78b74a975001aef4865821a00da751182584c82d818584283581cfc8a85f9862b26e153aae414ced247a0767a7e6003cec68d257833f8a101581e581c84b2ff88d08bc45186803ac9607c3c0743e23a1c18866c40919dd748001a58de0f8b1af82445c682584c82d818584283581c8449e1e8e805543715cc807d922f5fedf6c0437e5f410e7eb8e28055a101581e581c84b2ff88d08bc479e9e19dc90ee5e975b064ddf7bfacc41424d8ad82001ae306c9341a4304682b82584c82d818584283581c77970d82501e8dbbe906fdf9f208fbcce238fa93ee6331328d64bd8ca101581e581c84b2ff88d08bc4359d75e8c9aea1d751cd013be81d1b54cc374ed3c5001a4c8fcc631a31089b31021a0003908d031a0190a73fa400818258207c072edb52db7aefc6881f6ced60b7aad9b8fabf590dae34db09b5a4db81702105018282583901ad915af56530a5d452bcd4a88b87b0e1ca375feaab4fe399a1105312ad915af56530a5d452bcd4a88b87b0e1ca375feaab4fe399a11053121a02ec88cf82582b82d818582183581cbc14e6b9754d7eef691230ae09e4c52b03d1d824c80e22b65432d7a8a0001afaf343d61b0000000185309742021a0003656f031a3b9aca00a4008282582069572bee1b68d01c50b619de76461edbb90dd25c66e862c79fa9fa124efa92300082582069572bee1b68d01c50b619de76461edbb90dd25c66e862c79fa9fa124efa923001018282584c82d818584283581cfbfbb9e7f7844a40b2a6465af5ec17b8da45974c738d60d1cb21ba8fa101581e581ce378ee30d568143c4b061b6dfd1875fe5b27cd41917b30ce0107790b001a31db91811a832156008258390114ef538b66f44052ae6dc8c545250eebedf65c491327a9b8c7102e0c14ef538b66f44052ae6dc8c545250eebedf65c491327a9b8c7102e0c1a036cd490021a0002e4db031a05f5e100a4008182582089be624b89aa349ccd11c415695e0421d00fd3106b6334e877961437e51191c00101818258390119690bdc2289666c814de06b1095ca10ecf2092ed237669df9e3ee0619690bdc2289666c814de06b1095ca10ecf2092ed237669df9e3ee061a110c0380021a000add40031a0190a74d94a100818258202669028f7c59934d13efa39435215fc2486e389e35ba6c6b05dda7c65213257e5840b3cd2e84cae259105b7330bf4c871ff8796d42935ef822e3028f4e3128d660d04619443006f87971c3ec2fdd3f0ad9a63070e2d8037b88682c7c91b327c55b0ba100818258200d197b7eeef1b961962a2911d4b553f0d9c3114b8d3e61de3beb4f39dc348eb058407d6f5b2c1cce03a45c5f6d754da293936d7d3a013ef5822e9a36954b635fc0ed148da41a7ad85438d6fe7e32ff6e3a7c2e611a96ea891d08e3d570b14386a906a100818258206687a36f0a4b8abf2671f8affb7febeb8940e9ee83548d2a2924dbc74679c4665840289d23202301208dce8e594b84de249e4e6a77153ef66c7171431df21645f336ed1b37e5786c8e2e8c41b790b1deffa093ddd0cd6937121809abc327b45cc904a10082825820276601a019b13a328b7306c0faac593c064476af30beed0380aa035d4bc1fe315840331b8f8dd2c470f1318a87fb51fc1ab030448166aa61bcfea8052420658836e59036c52befdabb2cf4acb8637bc63f0bd822f21646b35ce1e167269c233bf800825820276601a019b13a328b7306c0faac593c064476af30beed0380aa035d4bc1fe315840331b8f8dd2c470f1318a87fb51fc1ab030448166aa61bcfea8052420658836e59036c52befdabb2cf4acb8637bc63f0bd822f21646b35ce1e167269c233bf800a10081825820783b5406ddd311ad3640afd90eb5bc45f85ff0b57fc5937ee365c8313636239f584070f587d9c080c8a76c5b26059bf3ec4b8dec9a98c955710303038636187aa76c01b386f87192cb2b2e4bb768b6c4a10cef33a631c9236799f034849c1508590aa1028184582063e3a4107a043aa1c7253612aa6e12e3c6231fa11f454aa06046f184cbf49fd758405d946178128a00dd003121c481a5d8f729e9b96b7dbf0bddbe57c94adcf000a83b68d7fc9f64a88d96fa87e1fc6c9cc1af2e1b86719b0d45febb7518459c970a58205bd2b0767d1f7de98f74403718cab3262e11105be4883947ed06785a3b3626825822a101581e581cd4688480c96f83055933ffb71bd2a85ea5ec4f45c5ccc0c98e8ee2bba1008882582061261a95b7613ee6bf2067dad77b70349729b0c50d57bc1cf30de0db4a1e73a85840375bfc568d815bc5d6778d3e2ec6287529a88602dee06e5c694ff8d727d8dc8d325e9c8967ebf992d8df9fd862f7b03a57c9c721a563c783a9847296484135028258209180d818e69cd997e34663c418a648c076f2e19cd4194e486e159d8580bc6cda5840c9664b3f484faa30ebed8308bc009ba2261b4826a6306b1a943cced6f0e00aa2fcbc8da3156db5d7d644196c74d44f81dfe477658ca20733272e4b1a9490fd0f82582089c29f8c4af27b7accbe589747820134ebbaa1caf3ce949270a3d0c7dcfd541b5840e46527dea5301bcb69d943dc3f0d7e97798b4b93addd28907cb08b0fd9741b285069476500407020249b28db467b16a782510e4bac318c1780f562a5a95ccd0e825820f14f712dc600d793052d4842d50cefa4e65884ea6cf83707079eb8ce302efc8558406bd6d8e0538963107baac0977e2b3ec8e3baab8dacf77851d8a7433412d0f3ac2a631cb4b1819e4d735e04ed4fc2ff89843d05de2847fc1a7a6756e19940910c8258208b53207629f9a30e4b2015044f337c01735abe67243c19470c9dae8c7b732798584059854e89e94fb2d6a363884aaf22d117e5fa22f77948d5a15f0aa551c4f97189d3a039467a046c8ead26612619c4ecc48ec88144ff077910891b1d95dd89a20c8258205fddeedade2714d6db2f9e1104743d2d8d818ecddc306e176108db14caadd4415840620c64cdf8d662028fb1ae1da2e9ccaaa81b79f40fae2ac8e425d174f8b6dc72383c61eb4a9ec1c5d10ecc1d643968e2fc00ad9e3d8e317faf3f3c261033f60a825820cbc6b506e94fbefe442eecee376f3b3ebaf89415ef5cd2efb666e06ddae483935840b74f2b37390269a78d45685d3197b4640c5cc9cf82b1c1c53d3f88e2117ce33bc7c6da056a9701818a37b8d657f4c4f25d39573a33fd8f57ceb6e228dd3e0f07825820e8c03a03c0b2ddbea4195caf39f41e669f7d251ecf221fbb2f275c0a5d7e05d158402d8fefb1305c16245613adeee72004c31840267dd01dbfaaa177dbe0da5f542ce52252b3d3da24adc17587443be4d8715d772ca0d9b4af8c97d38678b0eb440da10081825820393b3d3cab6be0897cbc87143cc3a24825dc1d8249df4d37969502931cf856ff584072e1e68e093e0511cd74666cbd04dc085f51824ecd699ccec34b65f67cd455172320e5dff108b5698a90599e95ac97ce29223e8eeb0341c93fc73cb01757ac06a10281845820d1f77134edfa56f240847ead978ca98e8bb5f43f476b6b71ef3e50ceb852b2135840df7a4a2e50bac5285264b9174f51d3f93ad0c309141737d3eef6a884db658ae63ad3e770cab31c93c11f873071fcfbdf0989081cc5a878fa11516e9325f271015820abbd19a5be197b7f66196c02a7a5fd714c3f95e2b280fd85dad81683b995b6cb5822a101581e581c2b0b011ba3683d58dd9d722a2f2cb9679b356ada972029c6b5f2ae61a10082825820ea5dd58ee48288c53c2ab95776da5e35357db5833f8ab3c420baa88dd920e11d58405bd3d606ca8810f5e50aabc09bbb69bd460bd90e6b68e016dd553b32da20ee1ec20ea79826ead8b52b7ca73b673ae5a59b132bb64fe565fcc462187b53f0a807825820ea5dd58ee48288c53c2ab95776da5e35357db5833f8ab3c420baa88dd920e11d58405bd3d606ca8810f5e50aabc09bbb69bd460bd90e6b68e016dd553b32da20ee1ec20ea79826ead8b52b7ca73b673ae5a59b132bb64fe565fcc462187b53f0a807a100818258206c0180db688a9e477b10a7792e3c4efd749cddbe0e05068b6cb586dee54056975840e4f95636842e596b03aaf3379907a96b48e4545ad591246df8ccb7c6c254c30e514f9135e7178d3a7badb521cc583ae458e137195c41ce4f0b2fe19920955000a102818458208b66d7f3bf0629a3cf791984bf0fa4568b83106c9257818e63edf63142e9bf2a58400a13b559254d21194e8fcf77662d0b73b778dc91f0f12aeb639becbcbcc2322df45bd9b2cdc85a6d3b842056191c2903dd9afcdfb234a62a9e0a8eff2f3fd10d582022cc1a6495cb1639e562a07c9aa86a4cf54e3dbb6839aa4ee6f0b6a20dc349ad5822a101581e581c1afbc57540db1561adeaccd7f4894065965cd230f6bdc70722aecd8ba100828258204db5d63d4561edc2b565f50c9be435ffb0bd606030aefba4fe0cd9ff86cbf473584082a9975d71dfd55cf3be8b1fa9da9b07737143e0a2f36aa8e43f793844c4448ed9a07a500e914a0e629dcaede71df676218143a324186c2a1b5e2224e114620f825820e68a43be6ae384bc3d33b2921c89914f5dd99bf088a45c5a31d17734e408d78e584076e9eb5c4a18bc8a5aad2ef4e4db2810fda3b76a6b8639cf82b2ad0141b80a97c0f568f3292fdc67bb378c91301fb06cd8ee4c543509cdb4dda4d1aef9d31a02a10081825820c9ed44b3f86f0beaa05d2e8e611488f3f369ecafbcf2754cfea82c84e248528b5840f7b52620cff9f200274014fca350d74a84d17b819e9cc6d23eb54a51078b7a59750a6c01e3f2b5a05bebe8520d0a80f14f78a32854ce889f99e7eae9c886540ba10281845820d02732f50b7c1c1b7a4e946d8d280195c2c96577f1ba2b90474ad509e35812055840a856245cd143457faabef30d675d0c45d2b63f16cf97c62d50f53d03e221d8e20869caf8f61982d29af7b3082f2e62d47484598495e95ac70a8c1b442aed97015820c227781775746f69bf6644f59dcfa6ac64864e307193ba95c2ea4514babd32d05822a101581e581cd8d97175289f3a7a3431a461b982088fca713f245b33843eeba6ace1a10281845820a0e38b160bd1292273c027fc08fca940abf54ecf3331dca01a5f195dc6b0ea1e5840617a03af56821787ae7a5e9ef0f28455157ee825a7913e1c0f27f35261e05d9916573fdce741d4ff419326c34aed1c02f0c998fbe33c247e1e800c5288e6d7015820937737a634d6ea5a0a2ef0f7658bb5337a21467e5ec565216a56bad4bd602a415822a101581e581cd8d97175289f3a14d655b2617089ce27d2df668f2901bc633cf7fcf4a102838458204a8e7f58407fdd312c4b093fbd30a69e1f6977b741d232e83e0fb99aed0e4cbd5840707b40421b8aa92605e34084d54e30643c253171bca2f5c2d3c56816a0295c025c870d9d0e3fa8df5c21764e33fbbd29d52bcdd77d7e6464f2948baa4e550d0a58203f043217f97312094c938259e57e9fb4d62b5ea373d00db3964b34ff898ac38f5822a101581e581c84b2ff88d08bc4408857d0c933f73033a3c62ced32e46b133ccbabef845820f80b356bd8105ebf65a3ca11b8ec2f8a179ac969b3bd6e9dcabf0e98bcb95a405840ff6e845dedeb967a24b042d839b52fadd4ba9855f13a76540b4de6818b016427b6e8a5512242142d732a5bc3d9df60d6e892293fdf5d33adde9bd22f69d1f6025820e7f9e3a251b0465dd169e2af1eac21512411370c42d5901670c9b6fb31658dc05822a101581e581c84b2ff88d08bc45e1dfb07c906b614497ca6cfe4ef27fdd314676c708458202ed3b6973c6f7fb567a9fefc01b351cbb06998c758c827fd34ff3d4512c55465584082351b0b888fd4854317304dc9e9865d75ab207a2c2bad439ca694a76e49a0224461f5291ee84db1a2377c2258ef8339ae9671e596218e1fd4383fe6ab984f0a5820dde71e8188d0e60442a9241ca91a5026c784677ed7b550d2ee49f12478076a365822a101581e581c84b2ff88d08bc4638430e8c9c992b98178c9a3a86159f877ff48e1f2a1028184582087fe94fa4925fcaa0948ac6b3a4ea590d11710fc999bfb3939864be95a30da8f584022093273e4658d71cf5cb737d1869247e39782e985e236c5b732996c1c6660528df5507fd272f5f15bdbf3578782d2dab8ab74d5b3b0fd48b0d8b8a26589bf005820250cd474ce3d2459197ee7ce25265f41eefc7b0fc37da633d10e4b02e87ad4ad41a0a1008282582086e3337f52290bc4f455da7e83a055c93e2a96b63fb3b7233f20f1a9c46e80a4584039db8d78698986021fc4cbfbe84665e1056dfaf91563a42841520ef5f03f573731bf6afb2ece1c85d8e2808cccc6d2b3a5587bfc72c9d88033d325bd7b6fb90282582086e3337f52290bc4f455da7e83a055c93e2a96b63fb3b7233f20f1a9c46e80a4584039db8d78698986021fc4cbfbe84665e1056dfaf91563a42841520ef5f03f573731bf6afb2ece1c85d8e2808cccc6d2b3a5587bfc72c9d88033d325bd7b6fb902a10281845820f0cd529bf77540b1211aa75a233601e0627951ccd4270d812c3add3c444bb0985840d9ed324cb3e74aec5baa40f3913856a015f78ab4b746b808268020bf2d293beb599eb390165c8299ce4e1a6ab0deece23c4401dc63fc6ad5a7f3c808a72f6f025820a7c5165bf99697dab666d48be507bbc475e282ae9743e72715f6e6b084234e835822a101581e581cba5f3d73c6d17e2e7d13dd396f0a74bf5cd02fd63ec68d0f09d9898fa100a219ef64a4015820ac05963babda59adb08a6ce3ab590199c0baaabd2763484f0bf5a7e72335ef25025820d1a756633fe50049d7cac880d793289e2b16ac4ef31abd411f730770d63c29e903583901d42900731c039a877000445f88be659e574632707b056fd2960994c5b652ad81db60f5d8605dcec6e73d1ed932009651d9f3e8580ee07d22041a01908adb19ef65a1015840787a36aa14eed279069272dd3ade19ea4e304a67da4524098619195e974b3be409fa6aee63a784c9fe9fac77b4718c84c3523c756b0701d10fa2171bec93db01").unwrap();
         let _block = MaryBlock::from_cbor_bytes(&bytes).unwrap();
     }
+
+    #[test]
+    fn header_nonce_evolution() {
+        use cml_chain::crypto::{utils::evolve_nonce, Nonce};
+        // headers taken from the shelley/alonzo/babbage/conway network block tests above.
+        // The expected nonces were computed outside of this crate: the VRF outputs were pulled
+        // out of the header CBOR with a separate decoder and hashed with b2sum -l 256.
+        let shelley_header = ShelleyHeader::from_cbor_bytes(&hex::decode("828f182e1a00015180582045899e8002b27df291e09188bfe3aeb5397ac03546a7d0ead93aa2500860f1af5820d1a8de6caa8fd9b175c59862ecdd5abcd0477b84b82a0e52faecc6b3c85100a4582051995f616f8a025f974b20330a53c0c81e8ea95973d73d15fff7bab57589311d8258406ef0be9293f5a307037b60b7252eae99bb045d58a521d5ba7c8d3aa129292a012a9391eefff8843f3558b35265ec432501b24688a2e0a21964fc71cf69c25dae58503d43c14357cd83b0f941f8c7e7a573fc361b8702bfee211bd7adb1a59f9576fe1bd11e3fa32b5a982e7b246e18058b099f147362c9c18acc43e1e4e8b5b7c660ea3ed9ac5b7a7ec0feb59ccfb64729048258407b4043c26958ea012568b14b0b95ae50d0bd589ae50d58ca5a7190375854084292f87b7f8878887269aaa59251d02032bf1bef5f5fe8fdafd1217164f0d77d885850f68678b7d56dc4178307ba00737cb3339c41f0bf77a894d2156c6cb4ce2047597b2b23dce04a7e1b9abd07a1b870bcf9b18aee0ce27d1fc3c4f2f73cf91f0ca3167e3f67dbe90838da2e8bf15234cb060358201033376be025cb705fd8dd02eda11cc73975a062b5d14ffd74d6ff69e69a2ff758202b9a5add912f3edc5c325d6250b9cc154de8f35e2924f5b1c707a4123808d064000058407fb060b885ffc7b55bb6e095ea6999eaa5608b6b4e92f5cc64bc34ba85000ebe42839432f15d86df07740374f69ce60f72feb16a0d47f5cf2c43158caf37ad0303005901c0f1f0c338a257b27351cf337c4c56961e67235b93cfa351f9d5f348911c7870cb2e084ff7c1f3d93719245fdbba539165d8eea9a510251e5cc3210b3bae3bb40034d42d5ecf181026f436173ad3036d5be2ba595f5facf920bcb48e8fd8b7b5fbf4f8fad5e652fd99be5d322fe920e702cc4afd218d76bd6800812155d8012c8fd57538a7b9d64f2defee3e32879e36db649a934b00784e6223023bdfffa59f4e54609d63a6f5ad04850c419a3556db8b291b90467fadfc67194a3069ef6ff4c0f7d6677145ceb51be68d6d0c20d0e92f80313c48dabf5ae8e3acd9fc43f450874848221f71d2f895c18790082d17467de32ff047a22cee1799db7e77e651a35c15b32d4f838133cc80d467308587ff5cea12be5b3b8b7d2d0d2eadf066b67cd965100555f96457d0d70988ffc2a7c212afa73338df3ece84ee7de2170aadec1dafc360580432193ab2a25c9c4555e57bc0d88cf50d7036378b4dabde79e5f858539a464e0a547660374da91d7d19acd753e219a8fee41a43bd4190db235dc0b1224bcfb9a760fb2b39063dccce88453043c0297cb6c93bca145a9ebbd6bc3a916ed9439343ac3510c47886d17a9187e833b9149e5ac2854c4d88a7c4b4ee6882").unwrap()).unwrap();
+        let alonzo_header = ShelleyHeader::from_cbor_bytes(&hex::decode("828f19fd871a001518165820af5fddc7d16a349e1a2af8ba89f4f5d3273955a13095b3709ef6e3db576a0b335820a9d974fd26bfaf385749113f260271430276bed6ef4dad6968535de6778471ce582009e142413e6a20c48dcf0bc1e1604d22ec6c1682802212c130bd8b0888fa925a825840e41904a15f3af92d9284737d2c30c2d3358bbac4bf759076b979fea06c1e39b1e81917fc5fc2b5630cbb89a381fb9141ec13757febde945eb5f9f3f7a73f7e7c5850c6e01289e3ec8b3e33c126eaa7bc0430eeaa54a632f1af449dd6d36edb308747333100f3fa9ca9953d0fe3b5b13ee032ce3023d65843d562b2fd3eccd8dbbd9f05247874e3e286312a530db6ab34bf0c8258400172d43b873ad7d44e9f675f9e07ee10ee17d660fecf53cb82c25a370206068cfc50f7d42b1b7dc6393c5d73dd3da1e005de6890d0675a9af4a53b50e54085335850bb00468778899c124a548ff45196cb903ca814abff3c34d40eb6d8f5e1d7891e9deaee26b67f05ee32942f2d7c2eba1f072c2a30601b4e4df944783e9d4f1c19f213ba0b25ae7b474f76cb8fa73f630604582029571d16f081709b3c48651860077bebf9340abb3fc7133443c54f1f5a5edcf15820ac442a832ec9084839a88cfe8ffc99bd22003f6a8cf6bd5b34bb309d0166e82d00005840d2c4345086faa2c56d503d773b49dddc239c599bddf41afabf0ee5410dd8ead37dd547bde8ce918580dd18721afa5f66fd954f5b35be8c24ad696089d222070806005901c02cffe4eb8a1f390974820aae493ac554edda85ce985505854bf382fa402a3ab24e73d28edb5ad6eda3e99b51de20f541ea1d7aecd2afe90608052f12aea0e90f5bab1593fcd77c4419476e27180477fd5f63a494d4914c1116b4278796ff1326a039ffdd789e2ab6943391a0ed144fcba0d521d48f26fd9666b7343731e7d0dbcaa78a1c25750c47bc5802b12ecb084b9558a0b9c6f04155e1fb7448a8a9d090989c20a173e318ebe1cfb6bfe8f701075ef10feb150cbfd9d21aac71a1e09e9d483c45aee59d66e04d4004729b0b2160d40b87e1e83dd394fbf7cd0fbe681865b240d053b80c17920f03ff09feae44700a1fe2b44cc5b6867e56c3fa1597ca952f875b148c493c0b2d358cb70f53ba036c688985b241929ccb10c39243b242d854bf46a36a1ed766c0b935f2bdb49004d9f383b8790d5abebaf5a42e0e1517a726b1efbb04dc051c66c3105437fd1992826ec790623843d026bc3c7827331ed1cea274c1de9b0b293c47e0c664e4d25414d23fc4fc9441c860d46e72e3739c6280d76e7e9752d362f98998eca3fdf032f55bad3125e528acca2fcc24a62cd30fe45889e058430eca4766499f404836687282a875a39922b46ef8795387574d52").unwrap()).unwrap();
+        let babbage_header = Header::from_cbor_bytes(&hex::decode("828a1a0002a1d21a00360d985820f93e682d5b91a94d8660e748aef229c19cb285bfb9830db48941d6a78183d81f58209691ed9d98a5b79d5bc46c4496a6dba7e103f668f525c8349b6b92676cb3eae45820ea49e4652c460b9ee6daafefc999ca667fbe5eb5d7a7aeabbdff6fe19c1a3c9f825840c448c14556645bfb1c2de174b4f375bd8e5c27a41f26661b17d05ab8d30e9f2527681555316ce65a7e4dfb3cf83f57144a95df44d1fa67293c31f0ab15c4f4b55850c15e1be17e969159dd7ea5df78af4154767e6c13e4f41cf67d9bc305f6f4d0e1ec56df71df7e00ab51d9f7de9bdefc88e60a155f4af7225007146c38cff4d5ca7a8067214baba73aa5a2f726da72360904582029571d16f081709b3c48651860077bebf9340abb3fc7133443c54f1f5a5edcf18458200481896957dda8d02d825448eb22aad5e106114d924f49923b2fd68e6b3254d3000058403b0db934233f93c43de54a63f8be99bd21da431dcea996a01213ada37e66c6a3930a95399ce083875ba95ffa58cb8ca9b83c92f7d440adb968d8569d74fe90018207005901c0c8e3bb3da1d72fa8a736a6a66bec894083a7f74b388181c54b8faaaf7ccd020acd7a7fbde4a2505f6fbfa907a8bca491219713841720e122a94a057ad065850e88894f7b7985335bd2e3c6bbed5a3fa7fd526a8afafd78fd04dfaef371ad55d0d3d0f1707db5e6773f3ccb896d9f81b1428c3dbcf693c54580f91bb93364272349c08980f71e74e74f8d7863a29cee3fbd821642c14285d8198c68fdbc0a193bf63d97c7a5481ce7da17c97f43a7b98d0cad8c3af26ae4f9ac695fab06ca34685ec57339673d084e0417f844747025f4f5b42cbd883159a3d52dab9dafdfb37b0136757b67ec4e1a6768c43cee5937b81e03224aa6909a9a3254cdf33887914d67723f9a5a4505ac41b658cec10d6c2949e3d3c58bc7a27b64eb8407cbdad7806db704e7ee172405ddaa634119f878541d2f19674dcd0aebf4e6e1091635ce177489742c28d61a4698edaea3364b92b03a73838ca24ce83913727154dae3c2ee4a331997dacd155753f014bc98cfa25b8d84c808654852b5e1e74e251651e30063a6451a676795aa421ea59daa711a7ff0d4140de86bc89969f717d461690c341da4cd440818205961ed9cc9970f52b10ac1b1c3ed3e0d0170582daec84ef206").unwrap()).unwrap();
+        let conway_header = Header::from_cbor_bytes(&hex::decode("828a1a0006ddf41a0089a98c58205172bc8843f4b565ecf0f72f86944afb0103d126bd1b6940de33a6125988be665820149174cec3572f88abaa0e3c22aa992f2f254db15ebfea54f93b59c71151801a58207e7f6a8978acb0ed37219cc5a917f8cd5bf6fd53538254ac921790e159baf8ad825840438fe265be4b13d6e1f5af3a6fe8a7a4c0ef1b832b614f697b80def3467db19740640fc9aed4ef158230ad910af46e597de22030a2c47556d17805c13866d7ca5850ab4849c172cf814f266bab8ae5327902bd481a0bfab03141d00c2e4e810732bea4739f94e068621a0573870379c51e76229e8cc345163781f8cb43683e1a79805ed84576fc3bf3fb4b431074241dac0519010858200ea62bbbba0549c668a54579873e3c35e64a899007f191bdf17b43bd01b4c7a28458205f7a4914a82f0120d63e3e63a235ad20ed871d816ba28f032c75f69e3a7df3e20018445840a437a97e0dd7b08a7744107cc329a6a661d6c1c844d0f35e72906f5e5762260b4189bc49be29993aa0b64a338e5fa8462953c55a2b4b4299edf40c8617f1e8038209005901c0aba65daed3986c74218c9e2d026e9285fbc5276efcdab8f41358df8cd7879574257f95b76540de3ac02d24a5e00f05327621e9fd01c6cf8f302771fe069b3b03c7e3637ea1b36208c4ab40b97866d05055ee2d8254fb44f54e26bef97b9e412a1377bb2a7224f8ed5430a83bdaeb963a9c492f721cb499a42a9dc49957315aa7144f304fe3af8f28a619d9b48e57293fd18332d0a277b8beb1651f502f6617d400c63dc3c0f3de0fc2a52b6c05a0557d5f1bf907a15a85ef5bb6a91406f01f2d63801e68e3fb16a8cf654afc9c7f9da3958c79fca1806405802a743377c89bb933ac69598ce1153cc7dda40a504ae5555af78e9052e8b767c7d8dbb943bffbf7093896c7238aeb4031bd72e794c23c2710b973e351ac5004ebf014b7a0d3daaa1070e7ed0288f45e5c776f174f826c0c810cff417b2ce0767f6210dc26710e4958f68800addfe210f1f7388edd1b6b96c9ad9e2a615cdc6d472daa421449c33eedda2a193755b70258fca6a9ae1a4cc0cd16bf1e37664f9562ef82c458cd170a71c2c45ff0cba71736d78d60abb33febace57b324410260f179cb8bd837c80ce114339d89daecbe578a2518877dd2b4109624153c717f1f4c704386a88e3590c").unwrap()).unwrap();
+        let headers = [
+            MultiEraBlockHeader::Shelley(shelley_header),
+            MultiEraBlockHeader::Shelley(alonzo_header),
+            MultiEraBlockHeader::Babbage(babbage_header),
+            MultiEraBlockHeader::Babbage(conway_header),
+        ];
+        let header_nonces = headers
+            .iter()
+            .map(|header| header.vrf_output_to_nonce().unwrap().to_hex())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            header_nonces,
+            vec![
+                // TPraos: blake2b256(nonce_vrf.output)
+                "4ed8b9d91c82015168627d3432cbd9ccd73211c618171248e364955ac163a2c8",
+                "465f30539eaa06ec226fbbaa368cd45e34050a69333a5c41f475d0bfae23ff8a",
+                // Praos: blake2b256(blake2b256("N" || vrf_result.output))
+                "8424e7cdf03a955fb8639e6765e318d72d9f2ca657dcf73ee7e3583dfa84e9a1",
+                "0403d94ef16e695826afda9f6cd0b8aee679a74602ef01becc8b475e3182d362",
+            ]
+        );
+        // the neutral nonce is the identity so the first step is just the first header's nonce
+        match evolve_nonce(
+            &Nonce::new_identity(),
+            &headers[0].vrf_output_to_nonce().unwrap(),
+        ) {
+            Nonce::Hash { hash, .. } => assert_eq!(hash.to_hex(), header_nonces[0]),
+            Nonce::Identity { .. } => panic!("nonce should have evolved"),
+        }
+        // blake2b256(previous || header nonce) at every step
+        let evolved = headers.iter().fold(Nonce::new_identity(), |nonce, header| {
+            evolve_nonce(&nonce, &header.vrf_output_to_nonce().unwrap())
+        });
+        match evolved {
+            Nonce::Hash { hash, .. } => assert_eq!(
+                hash.to_hex(),
+                "2c03abb30fb3e0d4770c9adfec35edc089cfbc508231b1c61fa545d57d2b5f54"
+            ),
+            Nonce::Identity { .. } => panic!("nonce should have evolved"),
+        }
+    }
+
+    #[test]
+    fn alonzo_update_json_round_trip() {
+        let mut ppu = AlonzoProtocolParamUpdate::new();
+        ppu.minfee_a = Some(44);
+        ppu.decentralization_constant = Some(UnitInterval::new(1, 2));
+        ppu.max_tx_ex_units = Some(ExUnits::new(14000000, 10000000000));
+        let mut updates = OrderedHashMap::new();
+        updates.insert(
+            GenesisHash::from([0xBB; GenesisHash::BYTE_COUNT]),
+            ppu.clone(),
+        );
+        updates.insert(GenesisHash::from([0xAA; GenesisHash::BYTE_COUNT]), ppu);
+        let alonzo_update = AlonzoUpdate::new(updates, 290);
+
+        let update = MultiEraUpdate::from(&alonzo_update);
+        assert_eq!(update.to_cbor_bytes(), Some(alonzo_update.to_cbor_bytes()));
+        let json = serde_json::to_string(&update).unwrap();
+        let from_json: MultiEraUpdate = serde_json::from_str(&json).unwrap();
+        assert_eq!(from_json.epoch, 290);
+        // JSON is ordered by genesis hash
+        assert_eq!(
+            from_json
+                .proposed_protocol_parameter_updates
+                .keys()
+                .map(|gh| gh.to_raw_bytes()[0])
+                .collect::<Vec<_>>(),
+            vec![0xAA, 0xBB]
+        );
+        assert_eq!(serde_json::to_string(&from_json).unwrap(), json);
+        let reencoded = AlonzoUpdate::from_cbor_bytes(&from_json.to_cbor_bytes().unwrap()).unwrap();
+        assert_eq!(
+            serde_json::to_string(&MultiEraUpdate::from(&reencoded)).unwrap(),
+            json
+        );
+
+        // can't tell which era's format to use
+        let mut mixed = from_json;
+        mixed.proposed_protocol_parameter_updates.insert(
+            GenesisHash::from([0xCC; GenesisHash::BYTE_COUNT]),
+            MultiEraProtocolParamUpdate::Shelley(ShelleyProtocolParamUpdate::new()),
+        );
+        assert_eq!(mixed.to_cbor_bytes(), None);
+    }
+
+    #[test]
+    fn conway_block_legacy_redeemers() {
+        let mut block = match MultiEraBlock::from_explicit_network_cbor_bytes(
+            &hex::decode(CONWAY_PREVIEW_BLOCK).unwrap(),
+        )
+        .unwrap()
+        {
+            MultiEraBlock::Conway(block) => block,
+            _ => panic!("expected conway block"),
+        };
+        // pre-Conway array format redeemers inside an otherwise Conway tx
+        block.transaction_witness_sets[0].redeemers =
+            Some(Redeemers::new_arr_legacy_redeemer(vec![
+                LegacyRedeemer::new(
+                    RedeemerTag::Spend,
+                    0,
+                    PlutusData::new_integer(0u64.into()),
+                    ExUnits::new(1000, 1000),
+                ),
+            ]));
+        let mut bytes = vec![0x82, 0x07];
+        bytes.extend(block.to_cbor_bytes());
+        let parsed = MultiEraBlock::from_explicit_network_cbor_bytes(&bytes).unwrap();
+        assert_eq!(
+            parsed.warnings(),
+            vec![MultiEraBlockWarning::LegacyRedeemers { tx_index: 0 }]
+        );
+        assert_eq!(
+            parsed.transaction_bodies().len(),
+            block.transaction_bodies.len()
+        );
+        match &parsed.transaction_witness_sets()[0].redeemers {
+            Some(Redeemers::ArrLegacyRedeemer {
+                arr_legacy_redeemer,
+                ..
+            }) => assert_eq!(arr_legacy_redeemer.len(), 1),
+            _ => panic!("expected array format redeemers"),
+        }
+    }
+
+    #[test]
+    fn header_accessors_all_variants() {
+        use crate::byron::block::{ByronDifficulty, EbbConsensusData};
+        use crate::byron::Blake2b256;
+        let ebb_head = EbbHead::new(
+            764824073,
+            Blake2b256::from([0u8; 32]),
+            Blake2b256::from([1u8; 32]),
+            EbbConsensusData::new(1, ByronDifficulty::new(21600)),
+            vec![],
+        );
+        let ebb = MultiEraBlock::Byron(ByronBlock::EpochBoundary(ByronEbBlock::new(
+            ebb_head,
+            vec![],
+            vec![],
+        )));
+        let blocks = [
+            (ebb, Era::Byron),
+            (
+                MultiEraBlock::from_explicit_network_cbor_bytes(
+                    &hex::decode(BYRON_MAIN_BLOCK).unwrap(),
+                )
+                .unwrap(),
+                Era::Byron,
+            ),
+            (
+                MultiEraBlock::from_explicit_network_cbor_bytes(
+                    &hex::decode(SHELLEY_NETWORK_BLOCK).unwrap(),
+                )
+                .unwrap(),
+                Era::Shelley,
+            ),
+            (
+                MultiEraBlock::from_explicit_network_cbor_bytes(
+                    &hex::decode(BABBAGE_NETWORK_BLOCK).unwrap(),
+                )
+                .unwrap(),
+                Era::Babbage,
+            ),
+            (
+                MultiEraBlock::from_explicit_network_cbor_bytes(
+                    &hex::decode(CONWAY_PREVIEW_BLOCK).unwrap(),
+                )
+                .unwrap(),
+                Era::Conway,
+            ),
+        ];
+        for (block, era) in blocks.iter() {
+            assert_eq!(block.era(), *era);
+            let header = block.header();
+            let is_byron = *era == Era::Byron;
+            assert_eq!(header.era() == Era::Byron, is_byron);
+            assert!(header.era() <= block.era());
+            assert_eq!(
+                header.is_epoch_boundary(),
+                matches!(block, MultiEraBlock::Byron(ByronBlock::EpochBoundary(_)))
+            );
+            header.block_number();
+            header.slot();
+            assert!(header.prev_hash().is_some());
+            assert_eq!(header.issuer_vkey().is_none(), is_byron);
+            assert_eq!(header.vrf_vkey().is_none(), is_byron);
+            assert_eq!(header.nonce_vrf().is_some(), *era == Era::Shelley);
+            assert_eq!(header.leader_vrf().is_some(), *era == Era::Shelley);
+            assert_eq!(header.vrf_result().is_some(), *era >= Era::Babbage);
+            assert_eq!(header.vrf_output_to_nonce().is_none(), is_byron);
+            assert_eq!(header.block_body_size().is_none(), is_byron);
+            assert_eq!(header.block_body_hash().is_none(), is_byron);
+            assert_eq!(header.operational_cert().is_none(), is_byron);
+            assert_eq!(header.protocol_version().is_none(), is_byron);
+        }
+    }
+
+    #[test]
+    fn delegation_changes_combined_certs() {
+        use cml_chain::certs::Credential;
+        use cml_chain::transaction::TransactionBody;
+        let cred = StakeCredential::new_pub_key(Ed25519KeyHash::from([0x11; 28]));
+        let pool = Ed25519KeyHash::from([0x22; 28]);
+        let drep_hash = Ed25519KeyHash::from([0x33; 28]);
+        let certs = vec![
+            Certificate::new_reg_drep_cert(Credential::new_pub_key(drep_hash), 0, None),
+            Certificate::new_stake_reg_deleg_cert(cred.clone(), pool, 2_000_000),
+            Certificate::new_vote_reg_deleg_cert(cred.clone(), DRep::new_key(drep_hash), 2_000_000),
+            Certificate::new_stake_vote_reg_deleg_cert(
+                cred.clone(),
+                pool,
+                DRep::new_key(drep_hash),
+                2_000_000,
+            ),
+            Certificate::new_stake_vote_deleg_cert(cred.clone(), pool, DRep::new_always_abstain()),
+            Certificate::new_vote_deleg_cert(cred.clone(), DRep::new_always_no_confidence()),
+            Certificate::new_stake_delegation(cred.clone(), pool),
+            Certificate::new_unreg_cert(cred.clone(), 2_000_000),
+        ];
+        let mut body = TransactionBody::new(vec![].into(), vec![], 0);
+        body.certs = Some(certs.into());
+        let changes = MultiEraTransactionBody::Conway(body).delegation_changes();
+        let summary = changes
+            .iter()
+            .map(|change| {
+                assert_eq!(change.credential, cred);
+                (
+                    change.kind,
+                    change.pool,
+                    change.drep.as_ref().map(|drep| drep.to_cbor_bytes()),
+                )
+            })
+            .collect::<Vec<_>>();
+        let key_drep = Some(DRep::new_key(drep_hash).to_cbor_bytes());
+        assert_eq!(
+            summary,
+            vec![
+                (DelegationChangeKind::Register, Some(pool), None),
+                (DelegationChangeKind::Register, None, key_drep.clone()),
+                (DelegationChangeKind::Register, Some(pool), key_drep),
+                (
+                    DelegationChangeKind::Delegate,
+                    Some(pool),
+                    Some(DRep::new_always_abstain().to_cbor_bytes())
+                ),
+                (
+                    DelegationChangeKind::Delegate,
+                    None,
+                    Some(DRep::new_always_no_confidence().to_cbor_bytes())
+                ),
+                (DelegationChangeKind::Delegate, Some(pool), None),
+                (DelegationChangeKind::Deregister, None, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn borrowing_iterators_match_owned() {
+        use cml_chain::transaction::TransactionBody;
+        fn to_json<T: serde::Serialize>(x: T) -> serde_json::Value {
+            serde_json::to_value(x).unwrap()
+        }
+        let mut bodies = vec![];
+        for block in [
+            BYRON_MAIN_BLOCK,
+            SANCHO_ALONZO_NETWORK_BLOCK,
+            CONWAY_PREVIEW_BLOCK,
+        ]
+        .iter()
+        {
+            let block =
+                MultiEraBlock::from_explicit_network_cbor_bytes(&hex::decode(block).unwrap())
+                    .unwrap();
+            bodies.extend(block.transaction_bodies());
+        }
+        let cred = StakeCredential::new_pub_key(Ed25519KeyHash::from([0x11; 28]));
+        let mut body = TransactionBody::new(vec![].into(), vec![], 0);
+        body.certs = Some(vec![Certificate::new_stake_registration(cred)].into());
+        bodies.push(MultiEraTransactionBody::Conway(body));
+        for body in bodies.iter() {
+            assert_eq!(
+                body.iter_inputs()
+                    .map(|input| to_json(input.cloned()))
+                    .collect::<Vec<_>>(),
+                body.inputs().into_iter().map(to_json).collect::<Vec<_>>()
+            );
+            let outputs = body.outputs();
+            assert_eq!(body.iter_outputs().count(), outputs.len());
+            for (output_ref, output) in body.iter_outputs().zip(outputs) {
+                assert_eq!(*output_ref.address(), output.address());
+                assert_eq!(*output_ref.amount(), output.amount());
+                assert_eq!(output_ref.coin(), output.amount().coin);
+                assert_eq!(to_json(output_ref.cloned()), to_json(output));
+            }
+            assert_eq!(
+                body.iter_certs()
+                    .map(|cert| to_json(cert.cloned()))
+                    .collect::<Vec<_>>(),
+                body.certs()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(to_json)
+                    .collect::<Vec<_>>()
+            );
+        }
+        assert!(bodies.iter().any(|body| body.iter_outputs().count() > 0));
+        assert_eq!(bodies.last().unwrap().iter_certs().count(), 1);
+    }
+    #[test]
+    fn all_outputs_of_invalid_babbage_tx() {
+        use crate::babbage::{BabbageTransactionBody, BabbageTransactionWitnessSet};
+        use cml_chain::address::EnterpriseAddress;
+        let output = |x: u8, coin: Coin| {
+            let address = EnterpriseAddress::new(
+                0,
+                StakeCredential::new_pub_key(Ed25519KeyHash::from([x; 28])),
+            )
+            .to_address();
+            BabbageTransactionOutput::new_alonzo_format_tx_out(AlonzoFormatTxOut::new(
+                address,
+                Value::from(coin),
+            ))
+        };
+        let input = |x: u8| TransactionInput::new(TransactionHash::from([x; 32]), 0);
+        let mut babbage = match MultiEraBlock::from_explicit_network_cbor_bytes(
+            &hex::decode(BABBAGE_NETWORK_BLOCK).unwrap(),
+        )
+        .unwrap()
+        {
+            MultiEraBlock::Babbage(block) => block,
+            _ => panic!("expected a babbage block"),
+        };
+        // phase-2 invalid: 2 regular outputs + collateral return
+        let mut invalid = BabbageTransactionBody::new(
+            vec![input(1)],
+            vec![output(1, 1_000_000), output(2, 2_000_000)],
+            200_000,
+        );
+        invalid.collateral_inputs = Some(vec![input(2)]);
+        invalid.collateral_return = Some(output(3, 3_000_000));
+        // valid: its collateral return must not be created
+        let mut valid = BabbageTransactionBody::new(
+            vec![input(3)],
+            vec![output(4, 4_000_000), output(5, 5_000_000)],
+            200_000,
+        );
+        valid.collateral_return = Some(output(6, 6_000_000));
+        babbage.transaction_bodies = vec![invalid, valid];
+        babbage.transaction_witness_sets = vec![
+            BabbageTransactionWitnessSet::new(),
+            BabbageTransactionWitnessSet::new(),
+        ];
+        babbage.invalid_transactions = vec![0];
+        let block = MultiEraBlock::Babbage(
+            crate::babbage::BabbageBlock::from_cbor_bytes(&babbage.to_cbor_bytes()).unwrap(),
+        );
+        let bodies = block.transaction_bodies();
+
+        let all_outputs = block
+            .all_outputs()
+            .into_iter()
+            .map(|(input, output)| (input, output.amount().coin))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            all_outputs,
+            vec![
+                (TransactionInput::new(bodies[0].hash(), 2), 3_000_000),
+                (TransactionInput::new(bodies[1].hash(), 0), 4_000_000),
+                (TransactionInput::new(bodies[1].hash(), 1), 5_000_000),
+            ]
+        );
+    }
+
+    #[test]
+    fn mir_certificates() {
+        use crate::allegra::utils::MIRActionKind;
+        use crate::allegra::MIRPot;
+
+        fn mir(cert: MultiEraCertificate) -> MoveInstantaneousReward {
+            match cert {
+                MultiEraCertificate::MoveInstantaneousRewardsCert(cert) => {
+                    cert.move_instantaneous_reward
+                }
+                _ => panic!("expected a MIR cert"),
+            }
+        }
+
+        // reserves -> treasury transfer of 1M ADA
+        let transfer = AllegraCertificate::from_cbor_bytes(
+            &hex::decode("820682001b000000e8d4a51000").unwrap(),
+        )
+        .unwrap();
+        let transfer = mir(transfer.into());
+        assert_eq!(transfer.pot, MIRPot::Reserve);
+        assert_eq!(transfer.action.kind(), MIRActionKind::ToOtherPot);
+        assert_eq!(transfer.action.as_to_other_pot(), Some(1_000_000_000_000));
+        assert!(transfer.action.as_to_stake_credentials().is_none());
+
+        // Shelley treasury -> 1 credential with a non-canonically encoded amount
+        let shelley_bytes = hex::decode(
+            "82068201a18200581c0000000000000000000000000000000000000000000000000000000a1b00000000000f4240",
+        )
+        .unwrap();
+        let shelley = ShelleyCertificate::from_cbor_bytes(&shelley_bytes).unwrap();
+        let converted = match MultiEraCertificate::from(shelley) {
+            MultiEraCertificate::MoveInstantaneousRewardsCert(cert) => cert,
+            _ => panic!("expected a MIR cert"),
+        };
+        assert_eq!(converted.to_cbor_bytes(), shelley_bytes);
+        let action = &converted.move_instantaneous_reward.action;
+        assert_eq!(action.kind(), MIRActionKind::ToStakeCredentials);
+        assert_eq!(action.as_to_other_pot(), None);
+        let deltas = action.as_to_stake_credentials().unwrap();
+        assert_eq!(
+            deltas
+                .values()
+                .map(|delta| delta.to_string())
+                .collect::<Vec<_>>(),
+            vec!["1000000"]
+        );
+    }
+
+    #[test]
+    fn avvm_redemption_tx() {
+        use crate::byron::block::TxAux;
+        use crate::byron::transaction::{
+            ByronRedeemerWitnessEntry, ByronTx, ByronTxIn, ByronTxInRegular, ByronTxOutPtr,
+        };
+        use cml_chain::byron::{make_redeem_signature, ByronAddress};
+        use cml_chain::genesis::network_info::NetworkInfo;
+        use cml_crypto::{Bip32PrivateKey, PrivateKey, RawBytesEncoding};
+
+        let mainnet = NetworkInfo::mainnet().protocol_magic();
+        let redeem_key = PrivateKey::from_normal_bytes(&[3; 32]).unwrap();
+        let redeem_addr = ByronAddress::new_redeem(&redeem_key.to_public(), mainnet);
+        let to = cml_chain::byron::AddressContent::icarus_from_key(
+            Bip32PrivateKey::from_bip39_entropy(&[0; 16], &[]).to_public(),
+            mainnet,
+        )
+        .to_address();
+        // a redemption tx spends the genesis UTxO of the redeem address to a regular one
+        let tx = ByronTx::new(
+            vec![ByronTxIn::ByronTxInRegular(ByronTxInRegular::new(
+                ByronTxOutPtr::new(redeem_addr.genesis_tx_hash(), 0),
+            ))],
+            vec![ByronTxOut::new(to, 1_000_000)],
+            Default::default(),
+        );
+        let sig = make_redeem_signature(&tx.hash(), &redeem_key, mainnet);
+        let tx_aux = TxAux::new(
+            tx,
+            vec![ByronTxWitness::new_byron_redeem_witness(
+                ByronRedeemerWitnessEntry::new(
+                    redeem_key.to_public().to_raw_bytes().to_vec(),
+                    sig.to_raw_bytes().to_vec(),
+                ),
+            )],
+        );
+
+        let parsed = TxAux::from_cbor_bytes(&tx_aux.to_bytes()).unwrap();
+        assert_eq!(parsed.to_bytes(), tx_aux.to_bytes());
+        let entry = match &parsed.byron_tx_witnesss[..] {
+            [ByronTxWitness::ByronRedeemWitness(wit)] => &wit.index_1,
+            wits => panic!("expected a single redeem witness, found {:?}", wits),
+        };
+        let tx_hash = parsed.byron_tx.hash();
+        assert!(entry.verify(&tx_hash, mainnet));
+        assert!(!entry.verify(&tx_hash, NetworkInfo::testnet().protocol_magic()));
+        assert_eq!(
+            entry.address(mainnet).unwrap().genesis_tx_hash(),
+            match &parsed.byron_tx.inputs[0] {
+                ByronTxIn::ByronTxInRegular(input) => input.index_1.byron_tx_id,
+                _ => unreachable!(),
+            }
+        );
+    }
+
+    #[test]
+    fn protocol_param_update_unified_json() {
+        let mut shelley = ShelleyProtocolParamUpdate::new();
+        shelley.minfee_a = Some(44);
+        let shelley = MultiEraProtocolParamUpdate::Shelley(shelley);
+        let mut conway = ProtocolParamUpdate::new();
+        conway.minfee_a = Some(44);
+        let conway = MultiEraProtocolParamUpdate::Conway(conway);
+        let shelley_json = shelley.to_unified_json().unwrap();
+        let conway_json = conway.to_unified_json().unwrap();
+        let shelley_fields = shelley_json.as_object().unwrap();
+        let conway_fields = conway_json.as_object().unwrap();
+        assert_eq!(shelley_fields.len(), 35);
+        assert_eq!(
+            shelley_fields.keys().collect::<Vec<_>>(),
+            conway_fields.keys().collect::<Vec<_>>()
+        );
+        assert_eq!(shelley_json["era"], "Shelley");
+        assert_eq!(conway_json["era"], "Conway");
+        assert_eq!(shelley_json["minfee_a"], 44);
+        assert_eq!(conway_json["minfee_a"], 44);
+        for (key, value) in shelley_fields.iter() {
+            if key != "era" && key != "minfee_a" {
+                assert!(value.is_null(), "{} should be null", key);
+                assert!(conway_fields[key].is_null(), "{} should be null", key);
+            }
+        }
+        assert_eq!(
+            shelley.diff(&conway).unwrap(),
+            serde_json::json!({ "era": "Conway" })
+        );
+
+        let mut changed = ProtocolParamUpdate::new();
+        changed.minfee_a = Some(45);
+        changed.d_rep_deposit = Some(500_000_000);
+        let changed = MultiEraProtocolParamUpdate::Conway(changed);
+        assert_eq!(
+            conway.diff(&changed).unwrap(),
+            serde_json::json!({ "minfee_a": 45, "d_rep_deposit": 500_000_000 })
+        );
+        assert_eq!(
+            changed.diff(&conway).unwrap(),
+            serde_json::json!({ "minfee_a": 44, "d_rep_deposit": null })
+        );
+        assert_eq!(conway.diff(&conway).unwrap(), serde_json::json!({}));
+    }
+
+    // which Conway features keep transactions from being down-converted to which older era
+    #[test]
+    fn conway_body_downgrade_errors() {
+        use crate::alonzo::AlonzoTransactionBody;
+        use crate::babbage::BabbageTransactionBody;
+        use cml_chain::address::{EnterpriseAddress, RewardAddress};
+        use cml_chain::certs::{DNSName, PoolParams, Relay};
+        use cml_chain::governance::{
+            Anchor, GovAction, GovActionId, ProposalProcedure, Vote, Voter, VotingProcedure,
+        };
+        use cml_chain::plutus::{PlutusV2Script, PlutusV3Script};
+        use cml_chain::transaction::DatumOption;
+        use cml_chain::Script;
+        use cml_crypto::{AnchorDocHash, DatumHash, VRFKeyHash};
+
+        let key_hash = Ed25519KeyHash::from([1; 28]);
+        let cred = StakeCredential::new_pub_key(key_hash);
+        let address = EnterpriseAddress::new(0, cred.clone()).to_address();
+        let reward_account = RewardAddress::new(0, cred.clone());
+        let input = TransactionInput::new(TransactionHash::from([2; 32]), 0);
+        let output = |datum_option, script_reference| {
+            TransactionOutput::new(
+                address.clone(),
+                Value::from(2_000_000),
+                datum_option,
+                script_reference,
+            )
+        };
+        let pool_registration = |dns_name: String| {
+            Certificate::new_pool_registration(PoolParams::new(
+                key_hash,
+                VRFKeyHash::from([3; 32]),
+                0,
+                340_000_000,
+                UnitInterval::new(1, 100),
+                reward_account.clone(),
+                vec![key_hash].into(),
+                vec![Relay::new_single_host_name(
+                    Some(3001),
+                    DNSName::new(dns_name).unwrap(),
+                )],
+                None,
+            ))
+        };
+        let base = || {
+            let mut body = TransactionBody::new(
+                vec![input.clone()].into(),
+                vec![output(
+                    Some(DatumOption::new_hash(DatumHash::from([4; 32]))),
+                    None,
+                )],
+                200_000,
+            );
+            body.ttl = Some(1000);
+            body.certs = Some(
+                vec![
+                    Certificate::new_stake_registration(cred.clone()),
+                    pool_registration("relay.example.com".to_owned()),
+                ]
+                .into(),
+            );
+            body.collateral_inputs = Some(vec![input.clone()].into());
+            body
+        };
+
+        // everything pre-Babbage converts to both eras
+        let alonzo = AlonzoTransactionBody::try_from(base()).unwrap();
+        assert_eq!(alonzo.outputs[0].datum_hash, Some(DatumHash::from([4; 32])));
+        assert_eq!(alonzo.certs.as_ref().map(Vec::len), Some(2));
+        assert_eq!(alonzo.collateral_inputs, Some(vec![input.clone()]));
+        let babbage = BabbageTransactionBody::try_from(base()).unwrap();
+        assert_eq!(babbage.ttl, Some(1000));
+        assert_eq!(babbage.certs.as_ref().map(Vec::len), Some(2));
+
+        let v2_script = Script::new_plutus_v2(PlutusV2Script::new(vec![0x4d, 0x01]));
+        let v3_script = Script::new_plutus_v3(PlutusV3Script::new(vec![0x4d, 0x01]));
+        let inline_datum = DatumOption::new_datum(PlutusData::new_integer(0u64.into()));
+        let mut voting_procedures = VotingProcedures::new();
+        voting_procedures.insert(Voter::new_d_rep_key_hash(key_hash), {
+            let mut votes = OrderedHashMap::new();
+            votes.insert(
+                GovActionId::new(TransactionHash::from([5; 32]), 0),
+                VotingProcedure::new(Vote::Yes, None),
+            );
+            votes
+        });
+        let anchor = Anchor::from_url(
+            "https://example.com".to_owned(),
+            AnchorDocHash::from([6; 32]),
+        )
+        .unwrap();
+        let proposal = ProposalProcedure::new(
+            100_000_000_000,
+            reward_account.clone(),
+            GovAction::new_info_action(),
+            anchor,
+        );
+        // (Conway feature, Alonzo error field, Babbage error field)
+        type Case<'a> = (
+            Box<dyn Fn(&mut TransactionBody) + 'a>,
+            &'a str,
+            Option<&'a str>,
+        );
+        let cases: Vec<Case> = vec![
+            (
+                Box::new(|body| body.outputs[0] = output(Some(inline_datum.clone()), None)),
+                "outputs[0].datum_option",
+                None,
+            ),
+            (
+                Box::new(|body| body.outputs.push(output(None, Some(v2_script.clone())))),
+                "outputs[1].script_reference",
+                None,
+            ),
+            (
+                Box::new(|body| body.outputs.push(output(None, Some(v3_script.clone())))),
+                "outputs[1].script_reference",
+                Some("outputs[1].script_reference"),
+            ),
+            (
+                Box::new(|body| {
+                    body.certs =
+                        Some(vec![Certificate::new_reg_cert(cred.clone(), 2_000_000)].into())
+                }),
+                "certs[0]",
+                Some("certs[0]"),
+            ),
+            (
+                Box::new(|body| {
+                    body.certs = Some(
+                        vec![
+                            Certificate::new_stake_registration(cred.clone()),
+                            Certificate::new_vote_deleg_cert(
+                                cred.clone(),
+                                DRep::new_always_abstain(),
+                            ),
+                        ]
+                        .into(),
+                    )
+                }),
+                "certs[1]",
+                Some("certs[1]"),
+            ),
+            // relay DNS names over 64 bytes
+            (
+                Box::new(|body| body.certs = Some(vec![pool_registration("a".repeat(65))].into())),
+                "certs[0]",
+                Some("certs[0]"),
+            ),
+            (
+                Box::new(|body| body.collateral_return = Some(output(None, None))),
+                "collateral_return",
+                None,
+            ),
+            (
+                Box::new(|body| {
+                    body.collateral_return = Some(output(None, Some(v3_script.clone())))
+                }),
+                "collateral_return",
+                Some("collateral_return.script_reference"),
+            ),
+            (
+                Box::new(|body| body.total_collateral = Some(1_000_000)),
+                "total_collateral",
+                None,
+            ),
+            (
+                Box::new(|body| body.reference_inputs = Some(vec![input.clone()].into())),
+                "reference_inputs",
+                None,
+            ),
+            (
+                Box::new(|body| body.voting_procedures = Some(voting_procedures.clone())),
+                "voting_procedures",
+                Some("voting_procedures"),
+            ),
+            (
+                Box::new(|body| body.proposal_procedures = Some(vec![proposal.clone()].into())),
+                "proposal_procedures",
+                Some("proposal_procedures"),
+            ),
+            (
+                Box::new(|body| body.current_treasury_value = Some(1_000_000_000)),
+                "current_treasury_value",
+                Some("current_treasury_value"),
+            ),
+            (
+                Box::new(|body| body.donation = Some(PositiveCoin::try_new(1_000_000).unwrap())),
+                "donation",
+                Some("donation"),
+            ),
+            // the first unsupported field in CBOR key order is the one reported
+            (
+                Box::new(|body| {
+                    body.donation = Some(PositiveCoin::try_new(1_000_000).unwrap());
+                    body.reference_inputs = Some(vec![input.clone()].into());
+                    body.outputs[0] = output(Some(inline_datum.clone()), None);
+                }),
+                "outputs[0].datum_option",
+                Some("donation"),
+            ),
+        ];
+        for (apply, alonzo_field, babbage_field) in cases {
+            let mut body = base();
+            apply(&mut body);
+            assert_eq!(
+                AlonzoTransactionBody::try_from(body.clone()).unwrap_err(),
+                EraDowngradeError::new(alonzo_field, Era::Alonzo)
+            );
+            match babbage_field {
+                Some(field) => assert_eq!(
+                    BabbageTransactionBody::try_from(body).unwrap_err(),
+                    EraDowngradeError::new(field, Era::Babbage)
+                ),
+                None => assert!(BabbageTransactionBody::try_from(body).is_ok()),
+            }
+        }
+    }
+
+    #[test]
+    fn transaction_cbor_alonzo() {
+        use crate::alonzo::AlonzoTransaction;
+        let block = MultiEraBlock::from_explicit_network_cbor_bytes(
+            &hex::decode(SANCHO_ALONZO_NETWORK_BLOCK).unwrap(),
+        )
+        .unwrap();
+        let bodies = block.transaction_bodies();
+        assert!(!bodies.is_empty());
+        for (i, body) in bodies.iter().enumerate() {
+            let bytes = block.transaction_cbor(i).unwrap();
+            let tx = AlonzoTransaction::from_cbor_bytes(&bytes).unwrap();
+            assert_eq!(tx.body.hash(), body.hash());
+            assert!(tx.is_valid);
+            // nothing is lost or re-encoded when reassembling
+            assert_eq!(tx.to_cbor_bytes(), bytes);
+            assert_eq!(
+                tx.witness_set.to_cbor_bytes(),
+                match &block {
+                    MultiEraBlock::Alonzo(alonzo) =>
+                        alonzo.transaction_witness_sets[i].to_cbor_bytes(),
+                    _ => panic!("expected an alonzo block"),
+                }
+            );
+        }
+        assert_eq!(
+            block.transaction_cbor(bodies.len()),
+            Err(TransactionIndexOutOfRange {
+                index: bodies.len(),
+                count: bodies.len(),
+            })
+        );
+    }
+
+    #[test]
+    fn transaction_cbor_conway() {
+        let block = MultiEraBlock::from_explicit_network_cbor_bytes(
+            &hex::decode(CONWAY_PREVIEW_BLOCK).unwrap(),
+        )
+        .unwrap();
+        for (i, body) in block.transaction_bodies().iter().enumerate() {
+            let tx = Transaction::from_cbor_bytes(&block.transaction_cbor(i).unwrap()).unwrap();
+            assert_eq!(tx.body.hash(), body.hash());
+        }
+
+        // auxiliary data that failed to parse is written back as-is
+        let mut conway = match block {
+            MultiEraBlock::Conway(block) => block,
+            _ => panic!("expected conway block"),
+        };
+        let malformed = hex::decode("a10161ff").unwrap();
+        conway.auxiliary_data_set = OrderedHashMap::new();
+        conway.unparsed_auxiliary_data = vec![UnparsedAuxiliaryData {
+            transaction_index: 0,
+            bytes: malformed.clone(),
+            error: String::new(),
+        }];
+        let mut expected = Transaction::new(
+            conway.transaction_bodies[0].clone(),
+            conway.transaction_witness_sets[0].clone(),
+            true,
+            None,
+        )
+        .to_cbor_bytes();
+        assert_eq!(expected.pop(), Some(0xf6));
+        expected.extend(malformed);
+        assert_eq!(
+            MultiEraBlock::Conway(conway).transaction_cbor(0).unwrap(),
+            expected
+        );
+    }
 }
@@ -0,0 +1,393 @@
+use std::collections::{BTreeSet, VecDeque};
+use std::io::{BufRead, Seek, Write};
+
+use cbor_event::{de::Deserializer, se::Serializer};
+use cml_chain::builders::tx_builder::TransactionUnspentOutput;
+use cml_chain::transaction::TransactionInput;
+use cml_core::error::{DeserializeError, DeserializeFailure, Key};
+use cml_core::serialization::{CBORReadLen, Deserialize};
+use cml_core::{Slot, TransactionIndex};
+use cml_crypto::{BlockHeaderHash, RawBytesEncoding};
+
+use crate::MultiEraBlock;
+
+#[derive(Debug, thiserror::Error)]
+pub enum UtxoSnapshotError {
+    #[error(
+        "Block does not follow the snapshot's tip: its previous hash is {} but the tip is {}",
+        found.to_hex(),
+        expected.to_hex()
+    )]
+    NotOnTip {
+        expected: BlockHeaderHash,
+        found: BlockHeaderHash,
+    },
+    #[error(
+        "Can't roll back to slot {slot}: the oldest slot that can be rolled back to is {oldest}"
+    )]
+    RollbackTooDeep { slot: Slot, oldest: Slot },
+}
+
+/// What apply_block() changed, kept to be able to undo it on a rollback
+#[derive(Clone, Debug)]
+struct AppliedBlock {
+    slot: Slot,
+    prev_slot: Slot,
+    prev_block_hash: BlockHeaderHash,
+    spent: Vec<TransactionUnspentOutput>,
+    created: BTreeSet<TransactionInput>,
+}
+
+/// A set of unspent outputs as of a given block e.g. to persist and restore a wallet's state.
+///
+/// Blocks are applied in chain order with apply_block() and the last rollback_buffer of them
+/// can be undone with rollback_to(). This undo history is not serialized so a restored
+/// snapshot can't be rolled back past the point it was saved at. Saving only snapshots
+/// that are at least k (the security parameter) blocks deep avoids this.
+///
+/// The CBOR format is a versioned envelope: [version, slot, block_hash, [* [input, output]]]
+#[derive(Clone, Debug)]
+pub struct UtxoSnapshot {
+    pub utxos: Vec<TransactionUnspentOutput>,
+    pub slot: Slot,
+    pub block_hash: BlockHeaderHash,
+    rollback_buffer: usize,
+    history: VecDeque<AppliedBlock>,
+}
+
+impl UtxoSnapshot {
+    /// Version written in the serialized envelope
+    pub const VERSION: u64 = 1;
+
+    /// Default number of blocks that can be rolled back (mainnet's security parameter k)
+    pub const DEFAULT_ROLLBACK_BUFFER: usize = 2160;
+
+    pub fn new(
+        utxos: Vec<TransactionUnspentOutput>,
+        slot: Slot,
+        block_hash: BlockHeaderHash,
+    ) -> Self {
+        Self {
+            utxos,
+            slot,
+            block_hash,
+            rollback_buffer: Self::DEFAULT_ROLLBACK_BUFFER,
+            history: VecDeque::new(),
+        }
+    }
+
+    /// How many of the most recently applied blocks can be rolled back (default DEFAULT_ROLLBACK_BUFFER)
+    pub fn rollback_buffer(&self) -> usize {
+        self.rollback_buffer
+    }
+
+    pub fn set_rollback_buffer(&mut self, rollback_buffer: usize) {
+        self.rollback_buffer = rollback_buffer;
+        self.trim_history();
+    }
+
+    /// Spends the inputs and adds the outputs of {block}, which must follow the current tip.
+    /// Phase-2 invalid transactions only spend their collateral and create their collateral return.
+    pub fn apply_block(&mut self, block: &MultiEraBlock) -> Result<(), UtxoSnapshotError> {
+        self.apply_block_filtered(block, |_| true)
+    }
+
+    /// Like apply_block() but only adds created outputs for which {filter} returns true
+    /// e.g. to only keep track of a wallet's own addresses.
+    pub fn apply_block_filtered(
+        &mut self,
+        block: &MultiEraBlock,
+        filter: impl Fn(&TransactionUnspentOutput) -> bool,
+    ) -> Result<(), UtxoSnapshotError> {
+        let header = block.header();
+        if let Some(prev_hash) = header.prev_hash() {
+            if prev_hash != self.block_hash {
+                return Err(UtxoSnapshotError::NotOnTip {
+                    expected: self.block_hash,
+                    found: prev_hash,
+                });
+            }
+        }
+
+        let invalid_transactions = block.invalid_transactions();
+        let mut spent_inputs = BTreeSet::new();
+        for (index, body) in block.transaction_bodies().iter().enumerate() {
+            if invalid_transactions.contains(&(index as TransactionIndex)) {
+                spent_inputs.extend(body.collateral_inputs().unwrap_or_default().iter().cloned());
+            } else {
+                // Byron genesis (redeem) inputs don't reference an output
+                spent_inputs.extend(body.iter_inputs().filter_map(|input| {
+                    Some(TransactionInput::new(*input.hash()?, input.index()?))
+                }));
+            }
+        }
+
+        let (spent, unspent) = std::mem::take(&mut self.utxos)
+            .into_iter()
+            .partition(|utxo| spent_inputs.contains(&utxo.input));
+        self.utxos = unspent;
+        // outputs spent later in the same block never make it into the snapshot
+        let created = block
+            .all_outputs()
+            .into_iter()
+            .filter(|(input, _)| !spent_inputs.contains(input))
+            .map(|(input, output)| TransactionUnspentOutput::new(input, output.into()))
+            .filter(filter)
+            .collect::<Vec<_>>();
+
+        self.history.push_back(AppliedBlock {
+            slot: header.slot(),
+            prev_slot: self.slot,
+            prev_block_hash: self.block_hash,
+            spent,
+            created: created.iter().map(|utxo| utxo.input.clone()).collect(),
+        });
+        self.trim_history();
+        self.utxos.extend(created);
+        self.slot = header.slot();
+        self.block_hash = BlockHeaderHash::from(block.hash());
+        Ok(())
+    }
+
+    /// Undoes all applied blocks after {slot}, leaving the snapshot at the last block at or before it.
+    /// Nothing is changed if that would require undoing more blocks than are kept (see rollback_buffer())
+    pub fn rollback_to(&mut self, slot: Slot) -> Result<(), UtxoSnapshotError> {
+        let undo_count = self
+            .history
+            .iter()
+            .rev()
+            .take_while(|applied| applied.slot > slot)
+            .count();
+        let rolled_back_slot = match undo_count {
+            0 => self.slot,
+            _ => self.history[self.history.len() - undo_count].prev_slot,
+        };
+        if rolled_back_slot > slot {
+            return Err(UtxoSnapshotError::RollbackTooDeep {
+                slot,
+                oldest: self
+                    .history
+                    .front()
+                    .map_or(self.slot, |applied| applied.prev_slot),
+            });
+        }
+        for applied in self
+            .history
+            .split_off(self.history.len() - undo_count)
+            .into_iter()
+            .rev()
+        {
+            self.utxos
+                .retain(|utxo| !applied.created.contains(&utxo.input));
+            self.utxos.extend(applied.spent);
+            self.slot = applied.prev_slot;
+            self.block_hash = applied.prev_block_hash;
+        }
+        Ok(())
+    }
+
+    /// The unspent output created at {input}, if it's in the snapshot
+    pub fn get(&self, input: &TransactionInput) -> Option<&TransactionUnspentOutput> {
+        self.utxos.iter().find(|utxo| utxo.input == *input)
+    }
+
+    fn trim_history(&mut self) {
+        while self.history.len() > self.rollback_buffer {
+            self.history.pop_front();
+        }
+    }
+}
+
+// like TransactionUnspentOutput this isn't on-chain so uses cbor_event's Serialize
+impl cbor_event::se::Serialize for UtxoSnapshot {
+    fn serialize<'se, W: Write>(
+        &self,
+        serializer: &'se mut Serializer<W>,
+    ) -> cbor_event::Result<&'se mut Serializer<W>> {
+        serializer.write_array(cbor_event::Len::Len(4))?;
+        serializer.write_unsigned_integer(Self::VERSION)?;
+        serializer.write_unsigned_integer(self.slot)?;
+        serializer.write_bytes(self.block_hash.to_raw_bytes())?;
+        serializer.write_array(cbor_event::Len::Len(self.utxos.len() as u64))?;
+        for utxo in self.utxos.iter() {
+            utxo.serialize(serializer)?;
+        }
+        Ok(serializer)
+    }
+}
+
+impl Deserialize for UtxoSnapshot {
+    fn deserialize<R: BufRead + Seek>(raw: &mut Deserializer<R>) -> Result<Self, DeserializeError> {
+        let len = raw.array_sz()?;
+        let mut read_len = CBORReadLen::new(len);
+        read_len.read_elems(4)?;
+        read_len.finish()?;
+        (|| -> Result<_, DeserializeError> {
+            let version = raw.unsigned_integer()?;
+            if version != Self::VERSION {
+                return Err(DeserializeFailure::FixedValueMismatch {
+                    found: Key::Uint(version),
+                    expected: Key::Uint(Self::VERSION),
+                }
+                .into());
+            }
+            let slot = raw
+                .unsigned_integer()
+                .map_err(|e| DeserializeError::from(e).annotate("slot"))?;
+            let block_hash = raw
+                .bytes()
+                .map_err(Into::<DeserializeError>::into)
+                .and_then(|bytes| {
+                    BlockHeaderHash::from_raw_bytes(&bytes)
+                        .map_err(|e| DeserializeFailure::InvalidStructure(Box::new(e)).into())
+                })
+                .map_err(|e: DeserializeError| e.annotate("block_hash"))?;
+            let utxos = (|| -> Result<_, DeserializeError> {
+                let mut utxos = Vec::new();
+                let len = raw.array_sz()?;
+                while match len {
+                    cbor_event::LenSz::Len(n, _) => (utxos.len() as u64) < n,
+                    cbor_event::LenSz::Indefinite => true,
+                } {
+                    if raw.cbor_type()? == cbor_event::Type::Special {
+                        assert_eq!(raw.special()?, cbor_event::Special::Break);
+                        break;
+                    }
+                    utxos.push(TransactionUnspentOutput::deserialize(raw)?);
+                }
+                Ok(utxos)
+            })()
+            .map_err(|e| e.annotate("utxos"))?;
+            match len {
+                cbor_event::LenSz::Len(_, _) => (),
+                cbor_event::LenSz::Indefinite => match raw.special()? {
+                    cbor_event::Special::Break => (),
+                    _ => return Err(DeserializeFailure::EndingBreakMissing.into()),
+                },
+            }
+            Ok(Self::new(utxos, slot, block_hash))
+        })()
+        .map_err(|e| e.annotate("UtxoSnapshot"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test::CONWAY_PREVIEW_BLOCK;
+    use cml_chain::address::EnterpriseAddress;
+    use cml_chain::certs::StakeCredential;
+    use cml_chain::transaction::TransactionOutput;
+    use cml_core::serialization::{OriginalBytes, ToBytes};
+    use cml_crypto::{Ed25519KeyHash, TransactionHash};
+
+    fn fixture_block() -> cml_chain::block::Block {
+        match MultiEraBlock::from_explicit_network_cbor_bytes(
+            &hex::decode(CONWAY_PREVIEW_BLOCK).unwrap(),
+        )
+        .unwrap()
+        {
+            MultiEraBlock::Conway(block) => block,
+            _ => panic!("expected conway block"),
+        }
+    }
+
+    fn utxo(input: TransactionInput, coin: u64) -> TransactionUnspentOutput {
+        let address = EnterpriseAddress::new(
+            0,
+            StakeCredential::new_pub_key(Ed25519KeyHash::from([4; 28])),
+        )
+        .to_address();
+        TransactionUnspentOutput::new(
+            input,
+            TransactionOutput::new(address, coin.into(), None, None),
+        )
+    }
+
+    #[test]
+    fn apply_and_rollback() {
+        // block 1 spends 917aaf39...#0 and creates tx1#0
+        let block1 = fixture_block();
+        let tx1_hash = block1.transaction_bodies[0].hash();
+        let block1_slot = block1.header.header_body.slot;
+        let genesis_input = block1.transaction_bodies[0].inputs[0].clone();
+        let unrelated = utxo(TransactionInput::new(TransactionHash::from([1; 32]), 3), 5);
+        let mut snapshot = UtxoSnapshot::new(
+            vec![
+                utxo(genesis_input.clone(), 10_000_000_000),
+                unrelated.clone(),
+            ],
+            block1_slot - 10,
+            block1.header.header_body.prev_hash.unwrap(),
+        );
+        let block1 = MultiEraBlock::Conway(block1);
+        snapshot.apply_block(&block1).unwrap();
+        let tx1_out = TransactionInput::new(tx1_hash, 0);
+        assert_eq!(snapshot.utxos.len(), 2);
+        assert!(snapshot.get(&genesis_input).is_none());
+        assert_eq!(
+            snapshot.get(&tx1_out).unwrap().output.amount().coin,
+            9_999_006_906
+        );
+        assert_eq!(snapshot.slot, block1_slot);
+        assert_eq!(snapshot.block_hash.to_raw_bytes(), block1.hash());
+
+        // block 2 spends tx1#0 and creates tx2#0
+        let mut block2 = fixture_block();
+        block2.header.header_body.slot = block1_slot + 20;
+        block2.header.header_body.prev_hash = Some(BlockHeaderHash::from(block1.hash()));
+        block2.header.set_original_bytes(None);
+        let body = &mut block2.transaction_bodies[0];
+        body.inputs = vec![tx1_out.clone()].into();
+        body.set_original_bytes(None);
+        let tx2_out = TransactionInput::new(body.hash(), 0);
+        // not following the tip
+        assert!(matches!(
+            snapshot.apply_block(&MultiEraBlock::Conway(fixture_block())),
+            Err(UtxoSnapshotError::NotOnTip { .. })
+        ));
+        snapshot
+            .apply_block(&MultiEraBlock::Conway(block2))
+            .unwrap();
+        assert!(snapshot.get(&tx1_out).is_none());
+        assert!(snapshot.get(&tx2_out).is_some());
+        assert_eq!(snapshot.slot, block1_slot + 20);
+
+        // persisting keeps the state but not the undo history
+        let bytes = snapshot.to_bytes();
+        let restored = UtxoSnapshot::from_cbor_bytes(&bytes).unwrap();
+        assert_eq!(restored.to_bytes(), bytes);
+        assert_eq!(restored.slot, snapshot.slot);
+        assert_eq!(restored.block_hash, snapshot.block_hash);
+        assert!(matches!(
+            restored.clone().rollback_to(block1_slot),
+            Err(UtxoSnapshotError::RollbackTooDeep { .. })
+        ));
+
+        // roll block 2 back
+        snapshot.rollback_to(block1_slot + 19).unwrap();
+        assert_eq!(snapshot.slot, block1_slot);
+        assert_eq!(snapshot.block_hash.to_raw_bytes(), block1.hash());
+        assert!(snapshot.get(&tx2_out).is_none());
+        assert!(snapshot.get(&tx1_out).is_some());
+        assert!(snapshot.get(&unrelated.input).is_some());
+        assert_eq!(snapshot.utxos.len(), 2);
+
+        // only 1 block kept
+        snapshot.set_rollback_buffer(0);
+        assert!(matches!(
+            snapshot.rollback_to(block1_slot - 10),
+            Err(UtxoSnapshotError::RollbackTooDeep { oldest, .. }) if oldest == block1_slot
+        ));
+        assert_eq!(snapshot.utxos.len(), 2);
+    }
+
+    #[test]
+    fn unknown_version() {
+        let snapshot = UtxoSnapshot::new(vec![], 7, BlockHeaderHash::from([2; 32]));
+        let mut bytes = snapshot.to_bytes();
+        assert_eq!(bytes[1], 0x01);
+        bytes[1] = 0x02;
+        assert!(UtxoSnapshot::from_cbor_bytes(&bytes).is_err());
+    }
+}
@@ -0,0 +1,36 @@
+use std::convert::TryFrom;
+
+use cml_chain_wasm::{
+    plutus::LegacyRedeemer,
+    transaction::{TransactionBody, TransactionWitnessSet},
+};
+use wasm_bindgen::prelude::{wasm_bindgen, JsError};
+
+use super::{AlonzoRedeemer, AlonzoTransactionBody, AlonzoTransactionWitnessSet};
+
+#[wasm_bindgen]
+impl AlonzoTransactionBody {
+    /// Down-converts e.g. a body assembled with the TransactionBuilder for nodes pinned to Alonzo.
+    /// Throws naming the first field Alonzo can't represent (e.g. reference_inputs).
+    pub fn from_transaction_body(body: &TransactionBody) -> Result<AlonzoTransactionBody, JsError> {
+        cml_multi_era::alonzo::AlonzoTransactionBody::try_from(body.as_ref().clone())
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+}
+
+#[wasm_bindgen]
+impl AlonzoTransactionWitnessSet {
+    /// Converts to the current (Conway) witness set format.
+    /// Redeemers are kept in the legacy array format along with their CBOR encoding details.
+    pub fn to_conway_witness_set(&self) -> TransactionWitnessSet {
+        self.0.to_conway_witness_set().into()
+    }
+}
+
+#[wasm_bindgen]
+impl AlonzoRedeemer {
+    pub fn to_legacy_redeemer(&self) -> LegacyRedeemer {
+        cml_chain::plutus::LegacyRedeemer::from(self.0.clone()).into()
+    }
+}
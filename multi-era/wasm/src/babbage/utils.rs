@@ -0,0 +1,46 @@
+use std::convert::TryFrom;
+
+use super::{BabbageTransactionBody, BabbageTransactionOutput};
+use cml_chain_wasm::transaction::{TransactionBody, TransactionOutput};
+use wasm_bindgen::prelude::{wasm_bindgen, JsError};
+
+#[wasm_bindgen]
+impl BabbageTransactionBody {
+    /**
+     * Down-converts e.g. a body assembled with the TransactionBuilder for nodes pinned to Babbage.
+     * Throws naming the first field Babbage can't represent (e.g. voting_procedures).
+     */
+    pub fn from_transaction_body(
+        body: &TransactionBody,
+    ) -> Result<BabbageTransactionBody, JsError> {
+        cml_multi_era::babbage::BabbageTransactionBody::try_from(body.as_ref().clone())
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+}
+
+#[wasm_bindgen]
+impl BabbageTransactionOutput {
+    /**
+     * The exact bytes wrapped in the script reference's #6.24 tag i.e. the CBOR of the script itself.
+     */
+    pub fn script_ref_raw_bytes(&self) -> Option<Vec<u8>> {
+        self.0.script_ref_raw_bytes()
+    }
+
+    /**
+     * Converts to the era-agnostic output keeping all encoding details so both serialize identically.
+     */
+    pub fn to_transaction_output(&self) -> TransactionOutput {
+        cml_chain::transaction::TransactionOutput::from(self.0.clone()).into()
+    }
+
+    /**
+     * Inverse of to_transaction_output(). Returns undefined if it has a Plutus V3 script reference.
+     */
+    pub fn from_transaction_output(output: &TransactionOutput) -> Option<BabbageTransactionOutput> {
+        cml_multi_era::babbage::BabbageTransactionOutput::try_from(output.as_ref().clone())
+            .ok()
+            .map(Into::into)
+    }
+}
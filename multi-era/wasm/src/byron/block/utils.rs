@@ -0,0 +1,36 @@
+use super::ByronMainBlock;
+use crate::byron::update::ByronUpdateProposal;
+use crate::byron::{ByronDelegationList, ByronUpdateVoteList};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+#[wasm_bindgen]
+impl ByronMainBlock {
+    /**
+     * The protocol update proposal submitted in this block, if any.
+     */
+    pub fn update_proposal(&self) -> Option<ByronUpdateProposal> {
+        self.0.update_proposal().cloned().map(Into::into)
+    }
+
+    /**
+     * Votes on (possibly earlier) update proposals submitted in this block.
+     */
+    pub fn update_votes(&self) -> ByronUpdateVoteList {
+        self.0.update_votes().to_vec().into()
+    }
+
+    /**
+     * Heavyweight delegation certificates submitted in this block.
+     * These change which keys can sign blocks on behalf of the genesis keys.
+     */
+    pub fn delegation_certificates(&self) -> ByronDelegationList {
+        self.0.delegation_certificates().to_vec().into()
+    }
+
+    /**
+     * CBOR bytes of the shared seed computation (SSC) payload.
+     */
+    pub fn ssc_payload_bytes(&self) -> Vec<u8> {
+        self.0.ssc_payload_bytes()
+    }
+}
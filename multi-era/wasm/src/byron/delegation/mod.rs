@@ -1,6 +1,8 @@
 // This file was code-generated using an experimental CDDL to rust tool:
 // https://github.com/dcSpark/cddl-codegen
 
+pub mod utils;
+
 use crate::byron::{ByronPubKey, ByronSignature, EpochId};
 use cml_core_wasm::{impl_wasm_cbor_json_api_cbor_event_serialize, impl_wasm_conversions};
 use wasm_bindgen::prelude::wasm_bindgen;
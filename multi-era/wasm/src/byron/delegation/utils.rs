@@ -0,0 +1,42 @@
+use cml_chain_wasm::byron::StakeholderId;
+use cml_crypto_wasm::Bip32PublicKey;
+use wasm_bindgen::prelude::{wasm_bindgen, JsError};
+
+use super::ByronDelegation;
+
+#[wasm_bindgen]
+impl ByronDelegation {
+    /**
+     * The key delegating its block signing rights (usually a genesis key)
+     */
+    pub fn issuer_key(&self) -> Result<Bip32PublicKey, JsError> {
+        self.0.issuer_key().map(Into::into).map_err(Into::into)
+    }
+
+    /**
+     * The key allowed to sign blocks on behalf of the issuer starting at this delegation's epoch
+     */
+    pub fn delegate_key(&self) -> Result<Bip32PublicKey, JsError> {
+        self.0.delegate_key().map(Into::into).map_err(Into::into)
+    }
+
+    /**
+     * Stakeholder id of the issuer i.e. its key in the genesis heavyDelegation map
+     */
+    pub fn issuer_stakeholder_id(&self) -> Result<StakeholderId, JsError> {
+        self.0
+            .issuer_stakeholder_id()
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
+    /**
+     * Stakeholder id of the delegate
+     */
+    pub fn delegate_stakeholder_id(&self) -> Result<StakeholderId, JsError> {
+        self.0
+            .delegate_stakeholder_id()
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+}
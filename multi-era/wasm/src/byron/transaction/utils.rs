@@ -0,0 +1,40 @@
+use super::{ByronRedeemerWitnessEntry, ByronTx};
+use cml_chain_wasm::byron::{ByronAddress, ProtocolMagic};
+use cml_crypto_wasm::{Ed25519Signature, PublicKey, TransactionHash};
+use wasm_bindgen::{prelude::wasm_bindgen, JsError};
+
+#[wasm_bindgen]
+impl ByronTx {
+    pub fn hash(&self) -> TransactionHash {
+        self.0.hash().into()
+    }
+}
+
+#[wasm_bindgen]
+impl ByronRedeemerWitnessEntry {
+    pub fn public_key(&self) -> Result<PublicKey, JsError> {
+        self.0.public_key().map(Into::into).map_err(Into::into)
+    }
+
+    pub fn signature(&self) -> Result<Ed25519Signature, JsError> {
+        self.0.signature().map(Into::into).map_err(Into::into)
+    }
+
+    /**
+     * The AVVM redeem address this witness is for i.e. the one the redemption tx spends from
+     */
+    pub fn address(&self, protocol_magic: &ProtocolMagic) -> Result<ByronAddress, JsError> {
+        self.0
+            .address((*protocol_magic).into())
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
+    /**
+     * Whether this is a valid redeem signature over the redemption tx {tx_hash}
+     * Malformed keys/signatures are reported as invalid.
+     */
+    pub fn verify(&self, tx_hash: &TransactionHash, protocol_magic: &ProtocolMagic) -> bool {
+        self.0.verify(tx_hash.as_ref(), (*protocol_magic).into())
+    }
+}
@@ -0,0 +1,120 @@
+use cml_chain_wasm::governance::GovActionId;
+use cml_core::Epoch;
+use cml_core_wasm::impl_wasm_conversions;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::{MultiEraBlock, MultiEraTransactionBody};
+
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug)]
+pub struct VoteTally(cml_multi_era::gov_action_tracker::VoteTally);
+
+impl_wasm_conversions!(cml_multi_era::gov_action_tracker::VoteTally, VoteTally);
+
+#[wasm_bindgen]
+impl VoteTally {
+    pub fn yes(&self) -> u64 {
+        self.0.yes
+    }
+
+    pub fn no(&self) -> u64 {
+        self.0.no
+    }
+
+    pub fn abstain(&self) -> u64 {
+        self.0.abstain
+    }
+}
+
+/// Vote counts per voter role. These are head counts, not stake-weighted.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug)]
+pub struct GovActionVotes(cml_multi_era::gov_action_tracker::GovActionVotes);
+
+impl_wasm_conversions!(
+    cml_multi_era::gov_action_tracker::GovActionVotes,
+    GovActionVotes
+);
+
+#[wasm_bindgen]
+impl GovActionVotes {
+    pub fn committee(&self) -> VoteTally {
+        self.0.committee.into()
+    }
+
+    pub fn dreps(&self) -> VoteTally {
+        self.0.dreps.into()
+    }
+
+    pub fn pools(&self) -> VoteTally {
+        self.0.pools.into()
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct GovActionState(cml_multi_era::gov_action_tracker::GovActionState);
+
+impl_wasm_conversions!(
+    cml_multi_era::gov_action_tracker::GovActionState,
+    GovActionState
+);
+
+#[wasm_bindgen]
+impl GovActionState {
+    /// The epoch as of which the action is no longer open for votes, or None if it still is
+    pub fn expired_in(&self) -> Option<Epoch> {
+        match self.0.status {
+            cml_multi_era::gov_action_tracker::GovActionStatus::Proposed => None,
+            cml_multi_era::gov_action_tracker::GovActionStatus::Expired(epoch) => Some(epoch),
+        }
+    }
+
+    pub fn votes(&self) -> GovActionVotes {
+        self.0.votes.into()
+    }
+}
+
+/// Follows the lifecycle of governance actions from the blocks of a chain e.g. for indexers.
+/// Actions are never reported as enacted (see the rust docs for the limitations).
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct GovActionTracker(cml_multi_era::gov_action_tracker::GovActionTracker);
+
+impl_wasm_conversions!(
+    cml_multi_era::gov_action_tracker::GovActionTracker,
+    GovActionTracker
+);
+
+#[wasm_bindgen]
+impl GovActionTracker {
+    pub fn new(governance_action_validity_period: Epoch) -> Self {
+        cml_multi_era::gov_action_tracker::GovActionTracker::new(governance_action_validity_period)
+            .into()
+    }
+
+    /// Records the proposals and votes of all valid transactions in {block}.
+    /// {epoch} is the epoch {block} belongs to.
+    pub fn apply_block(&mut self, block: &MultiEraBlock, epoch: Epoch) {
+        self.0.apply_block(block.as_ref(), epoch)
+    }
+
+    /// Records the proposals and votes of a (phase-2 valid) transaction in {epoch}
+    pub fn apply_transaction(&mut self, body: &MultiEraTransactionBody, epoch: Epoch) {
+        self.0.apply_transaction(body.as_ref(), epoch)
+    }
+
+    /// Moves to {epoch}, expiring any actions whose validity period ended.
+    pub fn advance_to(&mut self, epoch: Epoch) {
+        self.0.advance_to(epoch)
+    }
+
+    pub fn current_epoch(&self) -> Epoch {
+        self.0.current_epoch()
+    }
+
+    /// None if {action_id} was never seen proposed
+    pub fn status(&self, action_id: &GovActionId) -> Option<GovActionState> {
+        self.0.status(action_id.as_ref()).map(Into::into)
+    }
+}
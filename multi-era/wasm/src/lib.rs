@@ -7,9 +7,12 @@ pub mod allegra;
 pub mod alonzo;
 pub mod babbage;
 pub mod byron;
+pub mod gov_action_tracker;
 pub mod mary;
+pub mod pool_registry;
 pub mod shelley;
 pub mod utils;
+pub mod utxo_snapshot;
 
 // We export types from cip25/cip36 in order to have them be exported to WASM
 // to allow this crate to be like cml + multi-era (e.g. all functionality).
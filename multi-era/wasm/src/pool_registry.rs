@@ -0,0 +1,71 @@
+use cml_chain_wasm::certs::PoolParams;
+use cml_core_wasm::impl_wasm_conversions;
+use cml_crypto_wasm::{Ed25519KeyHash, VRFKeyHash};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::utils::{MultiEraBlockHeader, MultiEraCertificate};
+use crate::{MultiEraBlock, MultiEraTransactionBody};
+
+/// Registered stake pools, fed from PoolRegistration certificates, to attribute blocks to the
+/// pools that produced them. Registrations take effect immediately (see the rust docs).
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct PoolRegistry(cml_multi_era::pool_registry::PoolRegistry);
+
+impl_wasm_conversions!(cml_multi_era::pool_registry::PoolRegistry, PoolRegistry);
+
+#[wasm_bindgen]
+impl PoolRegistry {
+    pub fn new() -> Self {
+        cml_multi_era::pool_registry::PoolRegistry::new().into()
+    }
+
+    /// Adds the pool or replaces its params if it was already registered
+    pub fn register(&mut self, params: &PoolParams) {
+        self.0.register(params.clone().into())
+    }
+
+    /// Registers the pool of a PoolRegistration certificate, ignoring all other certificates
+    pub fn apply_cert(&mut self, cert: &MultiEraCertificate) {
+        self.0.apply_cert(cert.as_ref())
+    }
+
+    /// Applies the certificates of a (phase-2 valid) transaction
+    pub fn apply_transaction(&mut self, body: &MultiEraTransactionBody) {
+        self.0.apply_transaction(body.as_ref())
+    }
+
+    /// Applies the certificates of all valid transactions in {block}
+    pub fn apply_block(&mut self, block: &MultiEraBlock) {
+        self.0.apply_block(block.as_ref())
+    }
+
+    pub fn get(&self, operator: &Ed25519KeyHash) -> Option<PoolParams> {
+        self.0.get(operator.as_ref()).cloned().map(Into::into)
+    }
+
+    /// The pool currently registered with this VRF key hash
+    pub fn lookup_by_vrf(&self, vrf_key_hash: &VRFKeyHash) -> Option<PoolParams> {
+        self.0
+            .lookup_by_vrf(vrf_key_hash.as_ref())
+            .cloned()
+            .map(Into::into)
+    }
+
+    /// The pool that produced the block with this header, by the header's issuer key hash
+    /// or failing that its VRF key hash. None for Byron blocks and unknown pools.
+    pub fn block_producer(&self, header: &MultiEraBlockHeader) -> Option<PoolParams> {
+        self.0
+            .block_producer(header.as_ref())
+            .cloned()
+            .map(Into::into)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
@@ -0,0 +1,25 @@
+use super::{MultisigScript, ShelleyDNSName};
+use cml_chain_wasm::transaction::NativeScript;
+use cml_crypto_wasm::ScriptHash;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+#[wasm_bindgen]
+impl ShelleyDNSName {
+    /// Same as get()
+    pub fn to_str(&self) -> String {
+        self.0.get().clone()
+    }
+}
+
+#[wasm_bindgen]
+impl MultisigScript {
+    /// Hashed the same way as native scripts (which these are a subset of)
+    pub fn hash(&self) -> ScriptHash {
+        self.0.hash().into()
+    }
+
+    /// Converts to the equivalent native script, keeping the encoding details (and so the hash)
+    pub fn to_native_script(&self) -> NativeScript {
+        cml_chain::transaction::NativeScript::from(self.0.clone()).into()
+    }
+}
@@ -1,13 +1,15 @@
-use cml_chain::{assets::PositiveCoin, Coin};
+use cml_chain::Coin;
+use cml_chain_wasm::assets::PositiveCoin;
+use cml_chain_wasm::transaction::TransactionInput;
 use cml_chain_wasm::{
     address::Address,
     assets::{Mint, Value},
-    block::{OperationalCert, ProtocolVersion},
+    block::{utils::UnparsedAuxiliaryDataList, OperationalCert, ProtocolVersion},
     certs::{
-        AuthCommitteeHotCert, PoolRegistration, PoolRetirement, RegCert, RegDrepCert,
-        ResignCommitteeColdCert, StakeDelegation, StakeDeregistration, StakeRegDelegCert,
-        StakeRegistration, StakeVoteDelegCert, StakeVoteRegDelegCert, UnregCert, UnregDrepCert,
-        UpdateDrepCert, VoteDelegCert, VoteRegDelegCert,
+        AuthCommitteeHotCert, DRep, PoolRegistration, PoolRetirement, RegCert, RegDrepCert,
+        ResignCommitteeColdCert, StakeCredential, StakeDelegation, StakeDeregistration,
+        StakeRegDelegCert, StakeRegistration, StakeVoteDelegCert, StakeVoteRegDelegCert, UnregCert,
+        UnregDrepCert, UpdateDrepCert, VoteDelegCert, VoteRegDelegCert,
     },
     crypto::{GenesisHash, Nonce, VRFCert, Vkey},
     governance::VotingProcedures,
@@ -16,16 +18,22 @@ use cml_chain_wasm::{
     ProposalProcedureList, Rational, RequiredSigners, TransactionInputList,
     TransactionWitnessSetList, UnitInterval, Withdrawals,
 };
-use cml_core::{Epoch, TransactionIndex};
-use cml_core_wasm::{impl_wasm_conversions, impl_wasm_json_api, impl_wasm_list, impl_wasm_map};
+use cml_core::{serialization::Deserialize, Epoch, TransactionIndex};
+use cml_core_wasm::{
+    impl_wasm_conversions, impl_wasm_json_api, impl_wasm_list, impl_wasm_map, DeserializeLimits,
+};
 use cml_crypto_wasm::{
-    AuxiliaryDataHash, BlockBodyHash, BlockHeaderHash, ScriptDataHash, TransactionHash, VRFVkey,
+    AuxiliaryDataHash, BlockBodyHash, BlockHeaderHash, Ed25519KeyHash, NonceHash, ScriptDataHash,
+    TransactionHash, VRFKeyHash, VRFVkey,
 };
 use wasm_bindgen::{prelude::wasm_bindgen, JsError};
 
 use crate::{
-    allegra::MoveInstantaneousRewardsCert, shelley::GenesisKeyDelegation,
-    shelley::ProtocolVersionStruct, GenesisHashList, MultiEraBlock, MultiEraTransactionBody,
+    allegra::MoveInstantaneousRewardsCert,
+    byron::{update::ByronUpdate, ByronDelegationList},
+    shelley::GenesisKeyDelegation,
+    shelley::ProtocolVersionStruct,
+    GenesisHashList, MultiEraBlock, MultiEraBlockKind, MultiEraTransactionBody,
 };
 
 #[wasm_bindgen]
@@ -45,6 +53,39 @@ impl MultiEraBlock {
             .map_err(Into::into)
     }
 
+    /**
+     * Same as from_explicit_network_cbor_bytes() but with control over how era tags are handled.
+     * See NetworkBlockOptions.
+     */
+    pub fn from_explicit_network_cbor_bytes_with_options(
+        bytes: &[u8],
+        options: &NetworkBlockOptions,
+    ) -> Result<NetworkBlock, JsError> {
+        cml_multi_era::MultiEraBlock::from_explicit_network_cbor_bytes_with_options(
+            bytes,
+            options.as_ref(),
+        )
+        .map(Into::into)
+        .map_err(Into::into)
+    }
+
+    /**
+     * Same as from_cbor_bytes() but rejects CBOR exceeding {limits} before deserializing it.
+     * Use this for untrusted input e.g. blocks relayed from untrusted peers.
+     */
+    pub fn from_cbor_bytes_with_limits(
+        cbor_bytes: &[u8],
+        limits: &DeserializeLimits,
+    ) -> Result<MultiEraBlock, JsError> {
+        cml_multi_era::MultiEraBlock::from_cbor_bytes_with_limits(cbor_bytes, limits.as_ref())
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
+    pub fn header(&self) -> MultiEraBlockHeader {
+        self.0.header().into()
+    }
+
     pub fn transaction_bodies(&self) -> MultiEraTransactionBodyList {
         self.0.transaction_bodies().into()
     }
@@ -57,11 +98,120 @@ impl MultiEraBlock {
         self.0.auxiliary_data_set().into()
     }
 
+    /**
+     * Auxiliary data entries that are well-formed CBOR but couldn't be deserialized.
+     * The rest of the block is still available. Only Conway blocks tolerate these.
+     */
+    pub fn auxiliary_data_errors(&self) -> UnparsedAuxiliaryDataList {
+        self.0.auxiliary_data_errors().to_vec().into()
+    }
+
     pub fn invalid_transactions(&self) -> Vec<TransactionIndex> {
         self.0.invalid_transactions()
     }
+
+    /**
+     * The standalone transaction at {index} reassembled from the block's body, witness set
+     * and auxiliary data in this block's era's format e.g. for replaying or resubmitting it.
+     * Its body hashes the same as transaction_bodies().get(index).
+     */
+    pub fn transaction_cbor(&self, index: usize) -> Result<Vec<u8>, JsError> {
+        self.0.transaction_cbor(index).map_err(Into::into)
+    }
+
+    /**
+     * Every output created by this block paired with its output reference.
+     * Outputs of phase-2 invalid transactions are skipped, but their collateral return is
+     * included with the ledger's index for it: the number of regular outputs of that transaction.
+     */
+    pub fn all_outputs(&self) -> MultiEraBlockOutputList {
+        self.0.all_outputs().into()
+    }
+
+    /**
+     * Heavyweight delegation certificates of Byron main blocks, which decide which keys may
+     * sign blocks for the genesis keys. Empty for epoch boundary blocks and all later eras.
+     */
+    pub fn byron_delegation_certificates(&self) -> ByronDelegationList {
+        self.0.byron_delegation_certificates().to_vec().into()
+    }
+
+    /**
+     * All protocol update payloads in this block.
+     * Byron main blocks carry theirs in the block body while Shelley to Babbage carry them
+     * inside transaction bodies (see MultiEraTransactionBody.update()). Conway never has any.
+     */
+    pub fn update_payloads(&self) -> MultiEraUpdatePayloadList {
+        self.0.update_payloads().into()
+    }
+
+    /**
+     * Per-transaction witness statistics as a JS object of the shape:
+     * { transactions: [{ index, vkey_witnesses, bootstrap_witnesses, native_scripts,
+     *   plutus_v1_scripts, plutus_v2_scripts, plutus_v3_scripts, datums, redeemers,
+     *   is_invalid }] }
+     */
+    pub fn witness_summary(&self) -> Result<wasm_bindgen::JsValue, JsError> {
+        cml_core_wasm::js_value::to_js_value(&self.0.witness_summary())
+            .map_err(|e| JsError::new(&format!("MultiEraBlock::witness_summary: {}", e)))
+    }
 }
 
+#[derive(Clone, Debug)]
+#[wasm_bindgen]
+pub struct MultiEraUpdatePayload(cml_multi_era::utils::MultiEraUpdatePayload);
+
+impl_wasm_json_api!(MultiEraUpdatePayload);
+
+impl_wasm_conversions!(
+    cml_multi_era::utils::MultiEraUpdatePayload,
+    MultiEraUpdatePayload
+);
+
+#[wasm_bindgen]
+impl MultiEraUpdatePayload {
+    pub fn kind(&self) -> MultiEraUpdatePayloadKind {
+        match &self.0 {
+            cml_multi_era::utils::MultiEraUpdatePayload::Byron(_) => {
+                MultiEraUpdatePayloadKind::Byron
+            }
+            cml_multi_era::utils::MultiEraUpdatePayload::Shelley(_) => {
+                MultiEraUpdatePayloadKind::Shelley
+            }
+        }
+    }
+
+    pub fn as_byron(&self) -> Option<ByronUpdate> {
+        match &self.0 {
+            cml_multi_era::utils::MultiEraUpdatePayload::Byron(update) => {
+                Some(update.clone().into())
+            }
+            _ => None,
+        }
+    }
+
+    pub fn as_shelley(&self) -> Option<MultiEraUpdate> {
+        match &self.0 {
+            cml_multi_era::utils::MultiEraUpdatePayload::Shelley(update) => {
+                Some(update.clone().into())
+            }
+            _ => None,
+        }
+    }
+}
+
+#[wasm_bindgen]
+pub enum MultiEraUpdatePayloadKind {
+    Byron,
+    Shelley,
+}
+
+impl_wasm_list!(
+    cml_multi_era::utils::MultiEraUpdatePayload,
+    MultiEraUpdatePayload,
+    MultiEraUpdatePayloadList
+);
+
 impl_wasm_list!(
     cml_multi_era::utils::MultiEraCertificate,
     MultiEraCertificate,
@@ -99,6 +249,22 @@ impl_wasm_conversions!(
 
 #[wasm_bindgen]
 impl MultiEraBlockHeader {
+    /**
+     * The first era that used this header format.
+     * Headers are shared between eras (Shelley through Alonzo, Babbage and Conway)
+     * so use MultiEraBlock.kind() when the exact era is needed.
+     */
+    pub fn era(&self) -> MultiEraBlockKind {
+        self.0.era().into()
+    }
+
+    /**
+     * Whether this is the header of a Byron epoch boundary block (EBB)
+     */
+    pub fn is_epoch_boundary(&self) -> bool {
+        self.0.is_epoch_boundary()
+    }
+
     pub fn block_number(&self) -> u64 {
         self.0.block_number()
     }
@@ -119,6 +285,20 @@ impl MultiEraBlockHeader {
         self.0.vrf_vkey().map(|vkey| (*vkey).into())
     }
 
+    /**
+     * Hash of issuer_vkey i.e. the pool id of the pool that produced this block. None for Byron.
+     */
+    pub fn issuer_key_hash(&self) -> Option<Ed25519KeyHash> {
+        self.0.issuer_key_hash().map(Into::into)
+    }
+
+    /**
+     * Hash of vrf_vkey as registered in the producing pool's PoolParams. None for Byron.
+     */
+    pub fn vrf_key_hash(&self) -> Option<VRFKeyHash> {
+        self.0.vrf_key_hash().map(Into::into)
+    }
+
     pub fn nonce_vrf(&self) -> Option<VRFCert> {
         self.0.nonce_vrf().map(|vrf| vrf.clone().into())
     }
@@ -131,6 +311,14 @@ impl MultiEraBlockHeader {
         self.0.vrf_result().map(|res| res.clone().into())
     }
 
+    /**
+     * This block's contribution to the evolving epoch nonce.
+     * None for Byron, nonce_vrf-based for Shelley through Alonzo, vrf_result-based from Babbage onwards.
+     */
+    pub fn vrf_output_to_nonce(&self) -> Option<NonceHash> {
+        self.0.vrf_output_to_nonce().map(Into::into)
+    }
+
     pub fn block_body_size(&self) -> Option<u64> {
         self.0.block_body_size()
     }
@@ -148,6 +336,117 @@ impl MultiEraBlockHeader {
     }
 }
 
+/**
+ * Options for MultiEraBlock.from_explicit_network_cbor_bytes_with_options()
+ */
+#[derive(Clone, Debug, Default)]
+#[wasm_bindgen]
+pub struct NetworkBlockOptions(cml_multi_era::utils::NetworkBlockOptions);
+
+impl_wasm_conversions!(
+    cml_multi_era::utils::NetworkBlockOptions,
+    NetworkBlockOptions
+);
+
+#[wasm_bindgen]
+impl NetworkBlockOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /**
+     * Return blocks with unknown era tags as unknown NetworkBlocks instead of failing.
+     */
+    pub fn allow_unknown_era(&self, allow_unknown_era: bool) -> Self {
+        self.0.clone().allow_unknown_era(allow_unknown_era).into()
+    }
+
+    /**
+     * Parse blocks with the era tag tag as era e.g. if the network uses a new tag for an intra-era fork.
+     */
+    pub fn era_tag_alias(&self, tag: u64, era: MultiEraBlockKind) -> Self {
+        self.0.clone().era_tag_alias(tag, era.into()).into()
+    }
+
+    /**
+     * Have the header and transaction bodies keep the exact bytes they were parsed from for hashing.
+     * Off by default as the bytes aren't updated if the parsed structures are modified.
+     */
+    pub fn keep_original_bytes(&self, keep_original_bytes: bool) -> Self {
+        self.0
+            .clone()
+            .keep_original_bytes(keep_original_bytes)
+            .into()
+    }
+}
+
+/**
+ * A block in the network format. Either a known MultiEraBlock or the raw bytes of a block of an unknown era.
+ */
+#[derive(Clone, Debug)]
+#[wasm_bindgen]
+pub struct NetworkBlock(cml_multi_era::utils::NetworkBlock);
+
+impl_wasm_conversions!(cml_multi_era::utils::NetworkBlock, NetworkBlock);
+
+#[wasm_bindgen]
+impl NetworkBlock {
+    pub fn as_known(&self) -> Option<MultiEraBlock> {
+        match &self.0 {
+            cml_multi_era::utils::NetworkBlock::Known(block) => Some(block.clone().into()),
+            cml_multi_era::utils::NetworkBlock::Unknown { .. } => None,
+        }
+    }
+
+    /**
+     * Era tag of the block if its era is unknown
+     */
+    pub fn unknown_era(&self) -> Option<u64> {
+        match &self.0 {
+            cml_multi_era::utils::NetworkBlock::Known(_) => None,
+            cml_multi_era::utils::NetworkBlock::Unknown { era, .. } => Some(*era),
+        }
+    }
+
+    /**
+     * CBOR bytes of the block inside the network wrapper if its era is unknown
+     */
+    pub fn unknown_bytes(&self) -> Option<Vec<u8>> {
+        match &self.0 {
+            cml_multi_era::utils::NetworkBlock::Known(_) => None,
+            cml_multi_era::utils::NetworkBlock::Unknown { bytes, .. } => Some(bytes.clone()),
+        }
+    }
+}
+
+impl From<MultiEraBlockKind> for cml_multi_era::utils::Era {
+    fn from(kind: MultiEraBlockKind) -> Self {
+        match kind {
+            MultiEraBlockKind::Byron => Self::Byron,
+            MultiEraBlockKind::Shelley => Self::Shelley,
+            MultiEraBlockKind::Allegra => Self::Allegra,
+            MultiEraBlockKind::Mary => Self::Mary,
+            MultiEraBlockKind::Alonzo => Self::Alonzo,
+            MultiEraBlockKind::Babbage => Self::Babbage,
+            MultiEraBlockKind::Conway => Self::Conway,
+        }
+    }
+}
+
+impl From<cml_multi_era::utils::Era> for MultiEraBlockKind {
+    fn from(era: cml_multi_era::utils::Era) -> Self {
+        match era {
+            cml_multi_era::utils::Era::Byron => Self::Byron,
+            cml_multi_era::utils::Era::Shelley => Self::Shelley,
+            cml_multi_era::utils::Era::Allegra => Self::Allegra,
+            cml_multi_era::utils::Era::Mary => Self::Mary,
+            cml_multi_era::utils::Era::Alonzo => Self::Alonzo,
+            cml_multi_era::utils::Era::Babbage => Self::Babbage,
+            cml_multi_era::utils::Era::Conway => Self::Conway,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 #[wasm_bindgen]
 pub struct MultiEraCertificate(cml_multi_era::utils::MultiEraCertificate);
@@ -393,6 +692,14 @@ impl MultiEraCertificate {
             _ => None,
         }
     }
+
+    /**
+     * The effect this certificate has on its stake credential's registration/delegation.
+     * None for certificates that don't involve a stake credential's delegation.
+     */
+    pub fn delegation_change(&self) -> Option<DelegationChange> {
+        self.0.delegation_change().map(Into::into)
+    }
 }
 
 #[wasm_bindgen]
@@ -418,6 +725,57 @@ pub enum MultiEraCertificateKind {
     UpdateDrepCert,
 }
 
+/**
+ * Summary of a single certificate's effect on a stake credential.
+ * The combined Conway registration certs (e.g. StakeVoteRegDelegCert) are a single
+ * Register entry that also has the pool and/or drep delegated to set.
+ */
+#[derive(Clone, Debug)]
+#[wasm_bindgen]
+pub struct DelegationChange(cml_multi_era::utils::DelegationChange);
+
+impl_wasm_json_api!(DelegationChange);
+
+impl_wasm_conversions!(cml_multi_era::utils::DelegationChange, DelegationChange);
+
+#[wasm_bindgen]
+impl DelegationChange {
+    pub fn credential(&self) -> StakeCredential {
+        self.0.credential.clone().into()
+    }
+
+    pub fn kind(&self) -> DelegationChangeKind {
+        match self.0.kind {
+            cml_multi_era::utils::DelegationChangeKind::Register => DelegationChangeKind::Register,
+            cml_multi_era::utils::DelegationChangeKind::Deregister => {
+                DelegationChangeKind::Deregister
+            }
+            cml_multi_era::utils::DelegationChangeKind::Delegate => DelegationChangeKind::Delegate,
+        }
+    }
+
+    pub fn pool(&self) -> Option<Ed25519KeyHash> {
+        self.0.pool.map(Into::into)
+    }
+
+    pub fn drep(&self) -> Option<DRep> {
+        self.0.drep.clone().map(Into::into)
+    }
+}
+
+#[wasm_bindgen]
+pub enum DelegationChangeKind {
+    Register,
+    Deregister,
+    Delegate,
+}
+
+impl_wasm_list!(
+    cml_multi_era::utils::DelegationChange,
+    DelegationChange,
+    DelegationChangeList
+);
+
 #[derive(Clone, Debug)]
 #[wasm_bindgen]
 pub struct MultiEraProtocolParamUpdate(cml_multi_era::utils::MultiEraProtocolParamUpdate);
@@ -568,6 +926,50 @@ impl MultiEraProtocolParamUpdate {
     pub fn d_rep_inactivity_period(&self) -> Option<Epoch> {
         self.0.d_rep_inactivity_period()
     }
+
+    pub fn min_fee_ref_script_cost_per_byte(&self) -> Option<Rational> {
+        self.0
+            .min_fee_ref_script_cost_per_byte()
+            .map(|rate| rate.clone().into())
+    }
+
+    /**
+     * The first era this update format was used in (e.g. Shelley also covers Allegra/Mary)
+     */
+    pub fn era(&self) -> MultiEraBlockKind {
+        self.0.era().into()
+    }
+
+    /**
+     * JS object with every protocol parameter known up to Conway (named as the getters here)
+     * plus an era field. Parameters that aren't updated or don't exist in this era are null.
+     */
+    pub fn to_unified_json(&self) -> Result<wasm_bindgen::JsValue, JsError> {
+        self.0
+            .to_unified_json()
+            .map_err(|e| e.to_string())
+            .and_then(|json| cml_core_wasm::js_value::to_js_value(&json).map_err(|e| e.to_string()))
+            .map_err(|e| {
+                JsError::new(&format!(
+                    "MultiEraProtocolParamUpdate::to_unified_json: {}",
+                    e
+                ))
+            })
+    }
+
+    /**
+     * The fields of to_unified_json() whose value differs in {other}, mapped to {other}'s value
+     */
+    pub fn diff(
+        &self,
+        other: &MultiEraProtocolParamUpdate,
+    ) -> Result<wasm_bindgen::JsValue, JsError> {
+        self.0
+            .diff(&other.0)
+            .map_err(|e| e.to_string())
+            .and_then(|json| cml_core_wasm::js_value::to_js_value(&json).map_err(|e| e.to_string()))
+            .map_err(|e| JsError::new(&format!("MultiEraProtocolParamUpdate::diff: {}", e)))
+    }
 }
 
 #[wasm_bindgen]
@@ -592,6 +994,13 @@ impl MultiEraTransactionBody {
         self.0.certs().map(Into::into)
     }
 
+    /**
+     * The stake registration/delegation effects of this tx's certificates, in certificate order.
+     */
+    pub fn delegation_changes(&self) -> DelegationChangeList {
+        self.0.delegation_changes().into()
+    }
+
     pub fn withdrawals(&self) -> Option<Withdrawals> {
         self.0.withdrawals().map(|wd| wd.clone().into())
     }
@@ -659,7 +1068,7 @@ impl MultiEraTransactionBody {
     }
 
     pub fn donation(&self) -> Option<PositiveCoin> {
-        self.0.donation()
+        self.0.donation().map(Into::into)
     }
 }
 
@@ -707,12 +1116,52 @@ impl MultiEraTransactionOutput {
     }
 }
 
+/// An output created by a block along with the reference to spend it. See MultiEraBlock.all_outputs()
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct MultiEraBlockOutput(
+    (
+        cml_chain::transaction::TransactionInput,
+        cml_multi_era::utils::MultiEraTransactionOutput,
+    ),
+);
+
+impl_wasm_conversions!(
+    (
+        cml_chain::transaction::TransactionInput,
+        cml_multi_era::utils::MultiEraTransactionOutput
+    ),
+    MultiEraBlockOutput
+);
+
+#[wasm_bindgen]
+impl MultiEraBlockOutput {
+    pub fn input(&self) -> TransactionInput {
+        self.0 .0.clone().into()
+    }
+
+    pub fn output(&self) -> MultiEraTransactionOutput {
+        self.0 .1.clone().into()
+    }
+}
+
+impl_wasm_list!(
+    (
+        cml_chain::transaction::TransactionInput,
+        cml_multi_era::utils::MultiEraTransactionOutput
+    ),
+    MultiEraBlockOutput,
+    MultiEraBlockOutputList
+);
+
 #[wasm_bindgen]
 #[derive(Clone, Debug)]
 pub struct MultiEraUpdate(cml_multi_era::utils::MultiEraUpdate);
 
 impl_wasm_conversions!(cml_multi_era::utils::MultiEraUpdate, MultiEraUpdate);
 
+impl_wasm_json_api!(MultiEraUpdate);
+
 #[wasm_bindgen]
 impl MultiEraUpdate {
     pub fn epoch(&self) -> u64 {
@@ -724,6 +1173,12 @@ impl MultiEraUpdate {
     ) -> MapGenesisHashToMultiEraProtocolParamUpdate {
         self.0.proposed_protocol_parameter_updates.clone().into()
     }
+
+    /// Encodes using the era-specific update format (Shelley, Alonzo or Babbage).
+    /// Returns None if the era can't be determined (e.g. Conway or mixed-era proposals).
+    pub fn to_cbor_bytes(&self) -> Option<Vec<u8>> {
+        self.0.to_cbor_bytes()
+    }
 }
 
 impl_wasm_map!(
@@ -0,0 +1,72 @@
+use cml_chain_wasm::builders::{
+    evaluator::TransactionUnspentOutputList, tx_builder::TransactionUnspentOutput,
+};
+use cml_chain_wasm::transaction::TransactionInput;
+use cml_core::Slot;
+use cml_core_wasm::{impl_wasm_cbor_event_serialize_api, impl_wasm_conversions};
+use cml_crypto_wasm::BlockHeaderHash;
+use wasm_bindgen::prelude::{wasm_bindgen, JsError};
+
+use crate::MultiEraBlock;
+
+/// A set of unspent outputs as of a given block e.g. to persist and restore a wallet's state.
+/// The undo history used by rollback_to() is not serialized (see the rust docs).
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct UtxoSnapshot(cml_multi_era::utxo_snapshot::UtxoSnapshot);
+
+impl_wasm_conversions!(cml_multi_era::utxo_snapshot::UtxoSnapshot, UtxoSnapshot);
+
+impl_wasm_cbor_event_serialize_api!(UtxoSnapshot);
+
+#[wasm_bindgen]
+impl UtxoSnapshot {
+    pub fn new(
+        utxos: &TransactionUnspentOutputList,
+        slot: Slot,
+        block_hash: &BlockHeaderHash,
+    ) -> Self {
+        cml_multi_era::utxo_snapshot::UtxoSnapshot::new(
+            utxos.clone().into(),
+            slot,
+            block_hash.clone().into(),
+        )
+        .into()
+    }
+
+    pub fn utxos(&self) -> TransactionUnspentOutputList {
+        self.0.utxos.clone().into()
+    }
+
+    pub fn slot(&self) -> Slot {
+        self.0.slot
+    }
+
+    pub fn block_hash(&self) -> BlockHeaderHash {
+        self.0.block_hash.into()
+    }
+
+    /// How many of the most recently applied blocks can be rolled back
+    pub fn rollback_buffer(&self) -> usize {
+        self.0.rollback_buffer()
+    }
+
+    pub fn set_rollback_buffer(&mut self, rollback_buffer: usize) {
+        self.0.set_rollback_buffer(rollback_buffer)
+    }
+
+    /// Spends the inputs and adds the outputs of {block}, which must follow the current tip.
+    pub fn apply_block(&mut self, block: &MultiEraBlock) -> Result<(), JsError> {
+        self.0.apply_block(block.as_ref()).map_err(Into::into)
+    }
+
+    /// Undoes all applied blocks after {slot}, leaving the snapshot at the last block at or before it.
+    pub fn rollback_to(&mut self, slot: Slot) -> Result<(), JsError> {
+        self.0.rollback_to(slot).map_err(Into::into)
+    }
+
+    /// The unspent output created at {input}, if it's in the snapshot
+    pub fn get(&self, input: &TransactionInput) -> Option<TransactionUnspentOutput> {
+        self.0.get(input.as_ref()).cloned().map(Into::into)
+    }
+}
@@ -46,6 +46,13 @@ pub struct Cli {
     #[clap(long, value_parser, action = clap::ArgAction::Set, default_value_t = false)]
     pub json_schema_export: bool,
 
+    /// Directory of hand-written code to include in the generated crates e.g. the functions
+    /// referred to by @custom_serialize/@custom_deserialize annotations.
+    /// Files in its rust/ and wasm/ subdirectories are copied verbatim into the respective src/ dirs
+    /// (without the cml_chain -> cml_chain_wasm import rewriting) and declared as modules in lib.rs
+    #[clap(long, value_parser, value_name = "CUSTOM_IMPLS_DIR")]
+    pub custom_impls_dir: Option<std::path::PathBuf>,
+
     /// Generates a npm package.json along with build scripts
     #[clap(long, value_parser, action = clap::ArgAction::Set, default_value_t = false)]
     pub package_json: bool,
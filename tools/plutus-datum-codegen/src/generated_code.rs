@@ -0,0 +1,254 @@
+//! Reads back the rust/wasm code generated by cddl-codegen (parsed with syn) so that we can
+//! generate any wasm field accessors it didn't.
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use quote::ToTokens;
+
+/// Fields of a generated rust struct as (name, type) with the type as written e.g. `Option<Foo>`
+pub type StructFields = Vec<(String, String)>;
+
+fn type_to_string(ty: &syn::Type) -> String {
+    // token streams print with spaces between every token e.g. `Vec < u8 >`
+    ty.to_token_stream()
+        .to_string()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect()
+}
+
+/// Calls {f} with the parsed contents of every *.rs file in {dir} (recursively) except the ones named {skip}
+fn for_each_rust_file(
+    dir: &Path,
+    skip: &[&str],
+    f: &mut impl FnMut(syn::File),
+) -> Result<(), Box<dyn std::error::Error>> {
+    for entry_res in std::fs::read_dir(dir)? {
+        let entry = entry_res?;
+        let path = entry.path();
+        if entry.file_type()?.is_dir() {
+            for_each_rust_file(&path, skip, f)?;
+        } else if path.extension().is_some_and(|ext| ext == "rs")
+            && !skip.iter().any(|name| path.ends_with(name))
+        {
+            let file = syn::parse_file(&std::fs::read_to_string(&path)?)
+                .map_err(|e| format!("could not parse {}: {}", path.display(), e))?;
+            f(file);
+        }
+    }
+    Ok(())
+}
+
+/// Items of {items} including the ones inside of inline modules
+fn flatten_items(items: Vec<syn::Item>) -> Vec<syn::Item> {
+    let mut flattened = Vec::new();
+    for item in items {
+        match item {
+            syn::Item::Mod(syn::ItemMod {
+                content: Some((_, mod_items)),
+                ..
+            }) => flattened.extend(flatten_items(mod_items)),
+            item => flattened.push(item),
+        }
+    }
+    flattened
+}
+
+/// The public named fields of all structs in the generated rust code.
+/// Encoding details fields are skipped as they are not part of the datum.
+pub fn rust_structs(
+    src_dir: impl AsRef<Path>,
+) -> Result<BTreeMap<String, StructFields>, Box<dyn std::error::Error>> {
+    let mut structs = BTreeMap::new();
+    for_each_rust_file(src_dir.as_ref(), &[], &mut |file| {
+        structs.extend(rust_structs_in_file(file));
+    })?;
+    Ok(structs)
+}
+
+fn rust_structs_in_file(file: syn::File) -> Vec<(String, StructFields)> {
+    flatten_items(file.items)
+        .into_iter()
+        .filter_map(|item| match item {
+            syn::Item::Struct(syn::ItemStruct {
+                ident,
+                fields: syn::Fields::Named(fields),
+                ..
+            }) => Some((
+                ident.to_string(),
+                fields
+                    .named
+                    .iter()
+                    .filter(|field| matches!(field.vis, syn::Visibility::Public(_)))
+                    .filter_map(|field| {
+                        let name = field.ident.as_ref()?.to_string();
+                        (name != "encodings").then(|| (name, type_to_string(&field.ty)))
+                    })
+                    .collect(),
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The wasm wrapper types (tuple structs) in the generated wasm code along with the names of
+/// all methods cddl-codegen already generated for each of them.
+/// utils.rs is skipped as that is our own output from a previous run.
+pub fn wasm_methods(
+    src_dir: impl AsRef<Path>,
+) -> Result<BTreeMap<String, BTreeSet<String>>, Box<dyn std::error::Error>> {
+    let mut wrappers = BTreeSet::new();
+    let mut impls = BTreeMap::new();
+    for_each_rust_file(src_dir.as_ref(), &["utils.rs"], &mut |file| {
+        wasm_items_in_file(file, &mut wrappers, &mut impls);
+    })?;
+    Ok(wrapper_methods(wrappers, impls))
+}
+
+/// Collects the tuple structs of {file} into {wrappers} and the methods of its inherent impls into {impls}
+fn wasm_items_in_file(
+    file: syn::File,
+    wrappers: &mut BTreeSet<String>,
+    impls: &mut BTreeMap<String, BTreeSet<String>>,
+) {
+    for item in flatten_items(file.items) {
+        match item {
+            syn::Item::Struct(syn::ItemStruct {
+                ident,
+                fields: syn::Fields::Unnamed(_),
+                ..
+            }) => {
+                wrappers.insert(ident.to_string());
+            }
+            syn::Item::Impl(syn::ItemImpl {
+                trait_: None,
+                self_ty,
+                items,
+                ..
+            }) => {
+                impls
+                    .entry(type_to_string(&self_ty))
+                    .or_default()
+                    .extend(items.iter().filter_map(|impl_item| match impl_item {
+                        syn::ImplItem::Fn(method) => Some(method.sig.ident.to_string()),
+                        _ => None,
+                    }));
+            }
+            _ => (),
+        }
+    }
+}
+
+fn wrapper_methods(
+    wrappers: BTreeSet<String>,
+    mut impls: BTreeMap<String, BTreeSet<String>>,
+) -> BTreeMap<String, BTreeSet<String>> {
+    wrappers
+        .into_iter()
+        .map(|wrapper| {
+            let methods = impls.remove(&wrapper).unwrap_or_default();
+            (wrapper, methods)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_rust_structs() {
+        let file = syn::parse_file(
+            r#"
+            #[derive(Clone, Debug)]
+            pub struct Abc {
+                pub x: Vec<u8>,
+                pub y: Vec<String>,
+                pub z: Option<Bar>,
+                #[serde(skip)]
+                pub encodings: Option<AbcEncoding>,
+            }
+
+            pub struct Baz {
+                pub list: Vec<Abc>,
+                private: u64,
+            }
+
+            pub enum NotAStruct {
+                Foo(Foo),
+            }
+
+            pub struct Tuple(pub u64);
+            "#,
+        )
+        .unwrap();
+        let structs = rust_structs_in_file(file)
+            .into_iter()
+            .collect::<BTreeMap<_, _>>();
+        let field = |name: &str, ty: &str| (name.to_owned(), ty.to_owned());
+        assert_eq!(
+            structs.get("Abc").unwrap(),
+            &vec![
+                field("x", "Vec<u8>"),
+                field("y", "Vec<String>"),
+                field("z", "Option<Bar>")
+            ]
+        );
+        assert_eq!(
+            structs.get("Baz").unwrap(),
+            &vec![field("list", "Vec<Abc>")]
+        );
+        assert_eq!(structs.len(), 2);
+    }
+
+    #[test]
+    fn parses_wasm_methods() {
+        let file = syn::parse_file(
+            r#"
+            #[derive(Clone, Debug)]
+            #[wasm_bindgen]
+            pub struct Abc(cml_lib::Abc);
+
+            cml_core_wasm::impl_wasm_conversions!(cml_lib::Abc, Abc);
+
+            #[wasm_bindgen]
+            impl Abc {
+                pub fn x(&self) -> Vec<u8> {
+                    self.0.x.clone()
+                }
+
+                pub fn new(x: Vec<u8>) -> Self {
+                    Self(cml_lib::Abc::new(x))
+                }
+            }
+
+            impl From<Abc> for cml_lib::Abc {
+                fn from(wasm: Abc) -> Self {
+                    wasm.0
+                }
+            }
+
+            #[wasm_bindgen]
+            pub struct AbcList(Vec<cml_lib::Abc>);
+
+            pub struct NotAWrapper {
+                pub x: u64,
+            }
+
+            impl NotAWrapper {
+                pub fn x(&self) -> u64 {
+                    self.x
+                }
+            }
+            "#,
+        )
+        .unwrap();
+        let (mut wrappers, mut impls) = (BTreeSet::new(), BTreeMap::new());
+        wasm_items_in_file(file, &mut wrappers, &mut impls);
+        let methods = wrapper_methods(wrappers, impls);
+        let names = |names: &[&str]| names.iter().map(|name| name.to_string()).collect();
+        assert_eq!(methods.get("Abc").unwrap(), &names(&["new", "x"]));
+        assert_eq!(methods.get("AbcList").unwrap(), &names(&[]));
+        assert_eq!(methods.len(), 2);
+    }
+}
@@ -10,6 +10,7 @@ use utils::*;
 
 mod cli;
 mod dep_graph;
+mod generated_code;
 mod utils;
 
 const MERGED_INPUT_DIR: &str = "MERGED_INPUT_DIR";
@@ -55,6 +56,16 @@ enum PlutusType {
     Ctor,
 }
 
+fn any_plutus_type() -> BTreeSet<PlutusType> {
+    BTreeSet::from([
+        PlutusType::Bytes,
+        PlutusType::Map,
+        PlutusType::Array,
+        PlutusType::Ctor,
+        PlutusType::Int,
+    ])
+}
+
 fn create_base_idents<'a>() -> BTreeMap<&'a str, BTreeSet<PlutusType>> {
     BTreeMap::from([
         ("uint", BTreeSet::from([PlutusType::Int])),
@@ -264,14 +275,7 @@ fn verify_type2(
         Type2::Typename { ident, .. } => match ident.ident {
             CDDL_CODEGEN_RAW_BYTES_MARKER => Ok([PlutusType::Bytes].into()),
             // we can't know what this is
-            CDDL_CODEGEN_EXTERN_MARKER => Ok([
-                PlutusType::Bytes,
-                PlutusType::Map,
-                PlutusType::Array,
-                PlutusType::Ctor,
-                PlutusType::Int,
-            ]
-            .into()),
+            CDDL_CODEGEN_EXTERN_MARKER => Ok(any_plutus_type()),
             _ => verify_ident(ident, false).and_then(|()| {
                 types
                     .get(ident.ident)
@@ -340,6 +344,14 @@ fn verify(cddl: &CDDL) -> Result<(), Box<dyn std::error::Error>> {
         if !custom_serialize && !custom_deserialize {
             verify_rule(&mut types, cddl_rule)
                 .map_err(|e| format!("type {} not valid plutus datum: {}", cddl_rule.name(), e))?;
+        } else {
+            // we can't know what the user's (de)serialization code does
+            // but it must still be known so other rules can refer to it
+            let ident = match cddl_rule {
+                Rule::Type { rule, .. } => rule.name.ident,
+                Rule::Group { rule, .. } => rule.name.ident,
+            };
+            types.insert(ident, any_plutus_type());
         }
     }
     Ok(())
@@ -450,7 +462,8 @@ fn generate_wasm_utils(
         .push_import("cml_chain_wasm::plutus", "PlutusData", None)
         .push_import("cml_core::serialization", "Serialize", None)
         .push_import("cml_core::serialization", "Deserialize", None)
-        .push_import("wasm_bindgen", "JsError", None);
+        .push_import("wasm_bindgen", "JsError", None)
+        .push_import("wasm_bindgen::prelude", "wasm_bindgen", None);
     for cddl_rule in &cddl.rules {
         let is_struct = match cddl_rule {
             Rule::Type { rule, .. } => is_struct(&rule.value),
@@ -461,6 +474,7 @@ fn generate_wasm_utils(
             let rust_rule_name = convert_to_camel_case(&cddl_rule.name());
             utils.push_import("super", &rust_rule_name, None);
             let mut util_impl = codegen::Impl::new(&rust_rule_name);
+            util_impl.r#macro("#[wasm_bindgen]");
 
             let mut from_datum = codegen::Function::new("try_from_datum");
             from_datum
@@ -486,9 +500,141 @@ fn generate_wasm_utils(
             utils.push_impl(util_impl);
         }
     }
+    generate_wasm_field_accessors(cli, &mut utils)?;
     Ok(utils)
 }
 
+/// How a rust field type is exposed over wasm
+enum WasmFieldConversion {
+    /// Copy types e.g. u64
+    Copy(String),
+    /// Types wasm_bindgen supports directly but that must be cloned e.g. String or Vec<u8>
+    Clone(String),
+    /// Types that have a wasm wrapper generated by cddl-codegen e.g. Foo or FooList
+    Wrapper(String),
+}
+
+impl WasmFieldConversion {
+    fn from_rust_type(rust_type: &str, wasm_types: &BTreeSet<String>) -> Option<Self> {
+        match rust_type {
+            "u8" | "u16" | "u32" | "u64" | "i8" | "i16" | "i32" | "i64" | "bool" => {
+                Some(Self::Copy(rust_type.to_owned()))
+            }
+            "String" | "Vec<u8>" | "Vec<u16>" | "Vec<u32>" | "Vec<u64>" | "Vec<i32>"
+            | "Vec<i64>" | "Vec<String>" => Some(Self::Clone(rust_type.to_owned())),
+            _ => {
+                let wasm_type = match rust_type
+                    .strip_prefix("Vec<")
+                    .and_then(|inner| inner.strip_suffix('>'))
+                {
+                    Some(elem) => format!("{}List", elem),
+                    None => rust_type.to_owned(),
+                };
+                if wasm_types.contains(&wasm_type) {
+                    Some(Self::Wrapper(wasm_type))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+/// cddl-codegen does not always generate wasm getters/setters for struct fields (e.g. map-keyed
+/// structs) so this generates `field()` / `set_field()` for any that are missing so that datum
+/// contents can be read from JS without going via JSON.
+fn generate_wasm_field_accessors(
+    cli: &Cli,
+    utils: &mut codegen::Scope,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let rust_structs = generated_code::rust_structs(cli.output.join("rust").join("src"))?;
+    let existing_methods = generated_code::wasm_methods(cli.output.join("wasm").join("src"))?;
+    let wasm_types = existing_methods.keys().cloned().collect::<BTreeSet<_>>();
+    for (name, fields) in rust_structs.iter() {
+        if !wasm_types.contains(name) {
+            continue;
+        }
+        let existing = existing_methods.get(name).cloned().unwrap_or_default();
+        let mut accessors = codegen::Impl::new(name);
+        accessors.r#macro("#[wasm_bindgen]");
+        let mut has_accessors = false;
+        for (field, rust_type) in fields.iter() {
+            let (optional, inner_type) = match rust_type
+                .strip_prefix("Option<")
+                .and_then(|inner| inner.strip_suffix('>'))
+            {
+                Some(inner) => (true, inner),
+                None => (false, rust_type.as_str()),
+            };
+            let conversion = match WasmFieldConversion::from_rust_type(inner_type, &wasm_types) {
+                Some(conversion) => conversion,
+                None => {
+                    println!(
+                        "Skipping wasm accessors for {}::{}: unsupported type {}",
+                        name, field, rust_type
+                    );
+                    continue;
+                }
+            };
+            let (wasm_type, get_expr, set_arg, set_expr) = match &conversion {
+                WasmFieldConversion::Copy(ty) => (
+                    ty.clone(),
+                    format!("self.0.{}", field),
+                    ty.clone(),
+                    field.clone(),
+                ),
+                WasmFieldConversion::Clone(ty) => (
+                    ty.clone(),
+                    format!("self.0.{}.clone()", field),
+                    ty.clone(),
+                    field.clone(),
+                ),
+                WasmFieldConversion::Wrapper(ty) => (
+                    ty.clone(),
+                    if optional {
+                        format!("self.0.{}.clone().map(std::convert::Into::into)", field)
+                    } else {
+                        format!("self.0.{}.clone().into()", field)
+                    },
+                    format!("&{}", ty),
+                    format!("{}.clone().into()", field),
+                ),
+            };
+            if !existing.contains(field) {
+                let mut getter = codegen::Function::new(field);
+                getter.vis("pub").arg_ref_self().line(get_expr);
+                if optional {
+                    getter.ret(format!("Option<{}>", wasm_type));
+                } else {
+                    getter.ret(wasm_type);
+                }
+                accessors.push_fn(getter);
+                has_accessors = true;
+            }
+            let setter_name = format!("set_{}", field);
+            if !existing.contains(&setter_name) {
+                let mut setter = codegen::Function::new(&setter_name);
+                setter
+                    .vis("pub")
+                    .arg_mut_self()
+                    .arg(field, set_arg)
+                    .line(if optional {
+                        format!("self.0.{} = Some({})", field, set_expr)
+                    } else {
+                        format!("self.0.{} = {}", field, set_expr)
+                    });
+                accessors.push_fn(setter);
+                has_accessors = true;
+            }
+        }
+        if has_accessors {
+            utils.push_import("super", name, None);
+            utils.push_impl(accessors);
+        }
+    }
+    Ok(())
+}
+
 fn run_cddl_codegen(cli: &Cli) -> Result<(), Box<dyn std::error::Error>> {
     let mut cddl_codegen_run = if cli.cddl_codegen.is_dir() {
         let mut run = std::process::Command::new("cargo");
@@ -653,6 +799,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .open(cli.output.join("rust").join("src").join("lib.rs"))
         .unwrap();
     rust_lib.write_all("pub mod utils;\n".as_bytes())?;
+    if let Some(custom_impls_dir) = cli.custom_impls_dir.as_ref() {
+        let custom_rust_dir = custom_impls_dir.join("rust");
+        if custom_rust_dir.is_dir() {
+            for module in
+                utils::copy_custom_impls(&custom_rust_dir, &cli.output.join("rust").join("src"))?
+            {
+                rust_lib.write_all(format!("pub mod {};\n", module).as_bytes())?;
+            }
+        }
+    }
     if cli.wasm {
         // we need to change all imports from cml_chain to cml_chain_wasm
         fn swap_to_wasm_imports(dir: impl AsRef<Path>) -> std::io::Result<()> {
@@ -683,6 +839,19 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             .open(cli.output.join("wasm").join("src").join("lib.rs"))
             .unwrap();
         wasm_lib.write_all("pub mod utils;\n".as_bytes())?;
+
+        // copied after swap_to_wasm_imports() as these can legitimately need cml_chain (e.g. for conversions)
+        if let Some(custom_impls_dir) = cli.custom_impls_dir.as_ref() {
+            let custom_wasm_dir = custom_impls_dir.join("wasm");
+            if custom_wasm_dir.is_dir() {
+                for module in utils::copy_custom_impls(
+                    &custom_wasm_dir,
+                    &cli.output.join("wasm").join("src"),
+                )? {
+                    wasm_lib.write_all(format!("pub mod {};\n", module).as_bytes())?;
+                }
+            }
+        }
     }
 
     // hook into CML
@@ -702,7 +871,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         wasm_cargo.write_all("cml-core-wasm = \"6.0.1\"\n".as_bytes())?;
         wasm_cargo.write_all("cml-chain = \"6.0.1\"\n".as_bytes())?;
         wasm_cargo.write_all("cml-chain-wasm = \"6.0.1\"\n".as_bytes())?;
-        // needed for cml-core's cbor/json macros
+        // needed for cml-core's cbor/json macros (custom wasm impls can rely on it too)
         wasm_cargo.write_all("hex = \"0.4.3\"\n".as_bytes())?;
     }
     Ok(())
@@ -37,6 +37,42 @@ pub fn copy_dir_all(from: &Path, to: &Path) -> std::io::Result<()> {
     Ok(())
 }
 
+/// Copies the user's custom code in {from} into the generated crate's {to_src} dir.
+/// Returns the names of the modules to declare in lib.rs: one per *.rs file and one per
+/// directory (which must then contain a mod.rs). Other files are copied but not declared.
+/// Fails instead of overwriting any generated file.
+pub fn copy_custom_impls(from: &Path, to_src: &Path) -> std::io::Result<Vec<String>> {
+    let mut modules = Vec::new();
+    let mut entries = std::fs::read_dir(from)?.collect::<Result<Vec<_>, _>>()?;
+    entries.sort_by_key(|entry| entry.file_name());
+    for entry in entries {
+        let dest = to_src.join(entry.file_name());
+        if dest.exists() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::AlreadyExists,
+                format!(
+                    "custom impl {} would overwrite generated {}",
+                    entry.path().display(),
+                    dest.display()
+                ),
+            ));
+        }
+        let path = entry.path();
+        if entry.file_type()?.is_dir() {
+            copy_dir_all(&path, &dest)?;
+            modules.push(entry.file_name().to_string_lossy().into_owned());
+        } else {
+            std::fs::copy(&path, &dest)?;
+            if path.extension().map_or(false, |ext| ext == "rs") {
+                if let Some(stem) = path.file_stem() {
+                    modules.push(stem.to_string_lossy().into_owned());
+                }
+            }
+        }
+    }
+    Ok(modules)
+}
+
 pub fn read_dir_to_string_map(
     map: &mut Vec<(PathBuf, String)>,
     dir: impl AsRef<Path>,
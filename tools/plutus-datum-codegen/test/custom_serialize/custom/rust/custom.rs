@@ -0,0 +1,34 @@
+use cbor_event::{de::Deserializer, se::Serializer};
+use cml_chain::utils::{read_bounded_bytes, write_bounded_bytes};
+use cml_core::{serialization::StringEncoding, DeserializeError};
+use std::io::{BufRead, Seek, Write};
+
+fn decode_hex(text: &str) -> Option<Vec<u8>> {
+    if text.len() % 2 != 0 {
+        return None;
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(text.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+pub fn serialize_hex_bytes<'se, W: Write>(
+    serializer: &'se mut Serializer<W>,
+    text: &str,
+    enc: StringEncoding,
+    force_canonical: bool,
+) -> cbor_event::Result<&'se mut Serializer<W>> {
+    let bytes = decode_hex(text)
+        .ok_or_else(|| cbor_event::Error::CustomError(format!("invalid hex: {}", text)))?;
+    // plutus datum bytes must still be chunked like bounded_bytes
+    write_bounded_bytes(serializer, &bytes, &enc, force_canonical)
+}
+
+pub fn deserialize_hex_bytes<R: BufRead + Seek>(
+    raw: &mut Deserializer<R>,
+) -> Result<(String, StringEncoding), DeserializeError> {
+    let (bytes, enc) = read_bounded_bytes(raw)?;
+    let text = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+    Ok((text, enc))
+}
@@ -0,0 +1,12 @@
+// this must keep using cml_chain (not cml_chain_wasm) to build the datum
+// hex is always a dependency of the generated wasm crate (see the README)
+use cml_chain::plutus::PlutusData;
+use wasm_bindgen::prelude::{wasm_bindgen, JsError};
+
+/// The datum a hex_text field is serialized as
+#[wasm_bindgen]
+pub fn hex_text_to_datum(text: &str) -> Result<cml_chain_wasm::plutus::PlutusData, JsError> {
+    hex::decode(text)
+        .map(|bytes| PlutusData::new_bytes(bytes).into())
+        .map_err(|e| JsError::new(&format!("hex_text_to_datum: {}", e)))
+}
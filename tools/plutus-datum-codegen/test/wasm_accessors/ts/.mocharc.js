@@ -0,0 +1,6 @@
+module.exports = {
+  spec: "./*.spec.ts",
+  require: ["ts-node/register"],
+  "watch-extensions": ['ts'],
+  exit: true,
+};
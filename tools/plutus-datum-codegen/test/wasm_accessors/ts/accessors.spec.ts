@@ -0,0 +1,37 @@
+// pkg/ is the generated wasm crate built with wasm-pack (see the README)
+import Datum = require('./pkg/plutus_datum_accessors_test_wasm')
+import { expect } from 'chai'
+import 'mocha';
+
+// bar { variant: 1, fields: [abc { x: h'0102', y: ["hi"] }] }
+const BAR_HEX = 'd8668201818242010281426869';
+
+describe('WASM accessors', () => {
+  it('reads the fields of a datum', () => {
+    const bar = Datum.Bar.try_from_datum(Datum.PlutusData.from_cbor_hex(BAR_HEX));
+    expect(bar.variant()).to.equal(BigInt(1));
+    const fields = bar.fields();
+    expect(fields.len()).to.equal(1);
+    const abc = fields.get(0);
+    expect(Array.from(abc.x())).to.deep.equal([1, 2]);
+    expect(abc.y()).to.deep.equal(['hi']);
+  })
+
+  it('writes the fields of a datum', () => {
+    const bar = Datum.Bar.from_cbor_hex(BAR_HEX);
+    const abc = bar.fields().get(0);
+    abc.set_x(new Uint8Array([3]));
+    abc.set_y(['a', 'b']);
+    const fields = Datum.AbcList.new();
+    fields.add(abc);
+    bar.set_fields(fields);
+    bar.set_variant(BigInt(2));
+    // utf8_text is bytes on-chain
+    expect(bar.to_datum().to_cbor_hex()).to.equal('d8668202818241038241614162');
+  })
+
+  it('rejects datums that do not match the spec', () => {
+    // abc instead of bar
+    expect(() => Datum.Bar.try_from_datum(Datum.PlutusData.from_cbor_hex('8242010281426869'))).to.throw();
+  })
+});